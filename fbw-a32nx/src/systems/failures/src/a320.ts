@@ -48,6 +48,22 @@ export const A320Failure = Object.freeze({
     YellowEdpOverheat: 29011,
     YellowEpumpOverheat: 29012,
 
+    CaptPitotHeat: 30000,
+    CaptStaticHeat: 30001,
+    CaptAoaHeat: 30002,
+    CaptTatHeat: 30003,
+    FoPitotHeat: 30010,
+    FoStaticHeat: 30011,
+    FoAoaHeat: 30012,
+    FoTatHeat: 30013,
+    StbyPitotHeat: 30020,
+    StbyStaticHeat: 30021,
+    StbyAoaHeat: 30022,
+    CaptWindshieldHeat: 30100,
+    CaptWindowHeat: 30101,
+    FoWindshieldHeat: 30110,
+    FoWindowHeat: 30111,
+
     LeftPfdDisplay: 31000,
     RightPfdDisplay: 31001,
 
@@ -81,6 +97,36 @@ export const A320Failure = Object.freeze({
     YellowBrakeHydraulicLeak: 32101,
     YellowBrakeAccumulatorGasLeak: 32150,
 
+    TyreSlowLeakMainLeftOuter: 32200,
+    TyreSlowLeakMainLeftInner: 32201,
+    TyreSlowLeakMainRightInner: 32202,
+    TyreSlowLeakMainRightOuter: 32203,
+    TyreSlowLeakNoseLeft: 32204,
+    TyreSlowLeakNoseRight: 32205,
+
+    TyreBurstMainLeftOuter: 32250,
+    TyreBurstMainLeftInner: 32251,
+    TyreBurstMainRightInner: 32252,
+    TyreBurstMainRightOuter: 32253,
+    TyreBurstNoseLeft: 32254,
+    TyreBurstNoseRight: 32255,
+
     RadioAltimeter1: 34000,
     RadioAltimeter2: 34001,
+
+    CaptPitotBlockage: 34100,
+    FoPitotBlockage: 34101,
+    StbyPitotBlockage: 34102,
+    CaptPitotDrainBlockage: 34110,
+    FoPitotDrainBlockage: 34111,
+    StbyPitotDrainBlockage: 34112,
+    CaptStaticPortBlockage: 34120,
+    FoStaticPortBlockage: 34121,
+    StbyStaticPortBlockage: 34122,
+    CaptAoaVaneJam: 34130,
+    FoAoaVaneJam: 34131,
+    StbyAoaVaneJam: 34132,
+
+    GpsReceiver1: 34200,
+    GpsReceiver2: 34201,
 });
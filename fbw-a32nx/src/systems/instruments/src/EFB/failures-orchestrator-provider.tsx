@@ -62,6 +62,22 @@ const createOrchestrator = () => new FailuresOrchestrator('A32NX', [
     [29, A320Failure.YellowEdpOverheat, 'Yellow engine pump overheat'],
     [29, A320Failure.YellowEpumpOverheat, 'Yellow electric pump overheat'],
 
+    [30, A320Failure.CaptPitotHeat, 'CAPT pitot heat'],
+    [30, A320Failure.CaptStaticHeat, 'CAPT static heat'],
+    [30, A320Failure.CaptAoaHeat, 'CAPT AOA heat'],
+    [30, A320Failure.CaptTatHeat, 'CAPT TAT heat'],
+    [30, A320Failure.FoPitotHeat, 'F/O pitot heat'],
+    [30, A320Failure.FoStaticHeat, 'F/O static heat'],
+    [30, A320Failure.FoAoaHeat, 'F/O AOA heat'],
+    [30, A320Failure.FoTatHeat, 'F/O TAT heat'],
+    [30, A320Failure.StbyPitotHeat, 'STBY pitot heat'],
+    [30, A320Failure.StbyStaticHeat, 'STBY static heat'],
+    [30, A320Failure.StbyAoaHeat, 'STBY AOA heat'],
+    [30, A320Failure.CaptWindshieldHeat, 'CAPT windshield heat'],
+    [30, A320Failure.CaptWindowHeat, 'CAPT window heat'],
+    [30, A320Failure.FoWindshieldHeat, 'F/O windshield heat'],
+    [30, A320Failure.FoWindowHeat, 'F/O window heat'],
+
     [31, A320Failure.LeftPfdDisplay, 'Captain PFD display'],
     [31, A320Failure.RightPfdDisplay, 'F/O PFD display'],
 
@@ -94,8 +110,35 @@ const createOrchestrator = () => new FailuresOrchestrator('A32NX', [
     [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
     [32, A320Failure.YellowBrakeAccumulatorGasLeak, 'Yellow brake accumulator gas leak'],
 
+    [32, A320Failure.TyreSlowLeakMainLeftOuter, 'Main left outer tyre slow leak'],
+    [32, A320Failure.TyreSlowLeakMainLeftInner, 'Main left inner tyre slow leak'],
+    [32, A320Failure.TyreSlowLeakMainRightInner, 'Main right inner tyre slow leak'],
+    [32, A320Failure.TyreSlowLeakMainRightOuter, 'Main right outer tyre slow leak'],
+    [32, A320Failure.TyreSlowLeakNoseLeft, 'Nose left tyre slow leak'],
+    [32, A320Failure.TyreSlowLeakNoseRight, 'Nose right tyre slow leak'],
+    [32, A320Failure.TyreBurstMainLeftOuter, 'Main left outer tyre burst'],
+    [32, A320Failure.TyreBurstMainLeftInner, 'Main left inner tyre burst'],
+    [32, A320Failure.TyreBurstMainRightInner, 'Main right inner tyre burst'],
+    [32, A320Failure.TyreBurstMainRightOuter, 'Main right outer tyre burst'],
+    [32, A320Failure.TyreBurstNoseLeft, 'Nose left tyre burst'],
+    [32, A320Failure.TyreBurstNoseRight, 'Nose right tyre burst'],
+
     [34, A320Failure.RadioAltimeter1, 'RA 1'],
     [34, A320Failure.RadioAltimeter2, 'RA 2'],
+    [34, A320Failure.CaptPitotBlockage, 'CAPT pitot blockage'],
+    [34, A320Failure.FoPitotBlockage, 'F/O pitot blockage'],
+    [34, A320Failure.StbyPitotBlockage, 'STBY pitot blockage'],
+    [34, A320Failure.CaptPitotDrainBlockage, 'CAPT pitot drain hole blockage'],
+    [34, A320Failure.FoPitotDrainBlockage, 'F/O pitot drain hole blockage'],
+    [34, A320Failure.StbyPitotDrainBlockage, 'STBY pitot drain hole blockage'],
+    [34, A320Failure.CaptStaticPortBlockage, 'CAPT static port blockage'],
+    [34, A320Failure.FoStaticPortBlockage, 'F/O static port blockage'],
+    [34, A320Failure.StbyStaticPortBlockage, 'STBY static port blockage'],
+    [34, A320Failure.CaptAoaVaneJam, 'CAPT AOA vane jam'],
+    [34, A320Failure.FoAoaVaneJam, 'F/O AOA vane jam'],
+    [34, A320Failure.StbyAoaVaneJam, 'STBY AOA vane jam'],
+    [34, A320Failure.GpsReceiver1, 'GPS 1'],
+    [34, A320Failure.GpsReceiver2, 'GPS 2'],
 ]);
 
 const Context = React.createContext<FailuresOrchestratorContext>({
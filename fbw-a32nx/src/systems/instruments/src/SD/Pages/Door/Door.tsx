@@ -3,16 +3,25 @@
 // SPDX-License-Identifier: GPL-3.0
 
 import React from 'react';
-import { useSimVar } from '@flybywiresim/fbw-sdk';
+import { useSimVar, useArinc429Var } from '@flybywiresim/fbw-sdk';
 
 import './Door.scss';
 
 export const DoorPage = () => {
-    const [cabin] = useSimVar('INTERACTIVE POINT OPEN:0', 'percent', 1000);
-    const [catering] = useSimVar('INTERACTIVE POINT OPEN:3', 'percent', 1000);
-    const [cargoLocked] = useSimVar('L:A32NX_FWD_DOOR_CARGO_LOCKED', 'bool', 1000);
+    // One bit per door, see A32NX_DOOR_STATUS_WORD_{number} in a320-simvars.md.
+    const doorOpenWord = useArinc429Var('L:A32NX_DOOR_STATUS_WORD_1', 1000);
+    const slideArmedWord = useArinc429Var('L:A32NX_DOOR_STATUS_WORD_2', 1000);
     const [oxygen] = useSimVar('L:PUSH_OVHD_OXYGEN_CREW', 'bool', 1000);
-    const [slides] = useSimVar('L:A32NX_SLIDES_ARMED', 'bool', 1000);
+
+    const isOpen = (bit: number) => doorOpenWord.getBitValueOr(bit, false);
+    const isSlideArmed = (bit: number) => slideArmedWord.getBitValueOr(bit, false);
+
+    const frontLeftOpen = isOpen(11);
+    const frontRightOpen = isOpen(12);
+    const backLeftOpen = isOpen(13);
+    const backRightOpen = isOpen(14);
+    const fwdCargoOpen = isOpen(15);
+    const aftCargoOpen = isOpen(16);
 
     return (
         <>
@@ -31,42 +40,48 @@ export const DoorPage = () => {
                     <path className="DoorShape" d="M317 102 l0 -15 l-9 0 l0 15Z" />
 
                     <path className="DoorShape" d="M300 181 l0 10 l16 0 l0 -10Z" />
-                    <path id="DoorFwdCargo" className={cargoLocked ? 'DoorShape' : 'WarningShape'} d="M336 221 l0 -20 l-18 0 l0 20Z" />
-                    <path className="DoorShape" d="M336 384 l0 -20 l-18 0 l0 20Z" />
+                    <path id="DoorFwdCargo" className={fwdCargoOpen ? 'WarningShape' : 'DoorShape'} d="M336 221 l0 -20 l-18 0 l0 20Z" />
+                    <path id="DoorAftCargo" className={aftCargoOpen ? 'WarningShape' : 'DoorShape'} d="M336 384 l0 -20 l-18 0 l0 20Z" />
                     <path className="DoorShape" d="M328 414 l0 -22 l-8 0 l0 22Z" />
                 </g>
 
                 <g id="slides">
-                    <path id="DoorFrontLeft" className={cabin > 20 ? 'WarningShape' : 'DoorShape'} d="M264 145 l0 -20 l12 0 l0 20Z" />
-                    <path className="DoorShape" d="M336 145 l0 -20 l-12 0 l0 20Z" />
+                    <path id="DoorFrontLeft" className={frontLeftOpen ? 'WarningShape' : 'DoorShape'} d="M264 145 l0 -20 l12 0 l0 20Z" />
+                    <path id="DoorFrontRight" className={frontRightOpen ? 'WarningShape' : 'DoorShape'} d="M336 145 l0 -20 l-12 0 l0 20Z" />
                     <path className="DoorShape" d="M264 310 l0 -20 l12 0 l0 20Z" />
                     <path className="DoorShape" d="M336 310 l0 -20 l-12 0 l0 20Z" />
                     <path className="DoorShape" d="M264 344 l0 -20 l12 0 l0 20Z" />
                     <path className="DoorShape" d="M336 344 l0 -20 l-12 0 l0 20Z" />
 
-                    <path className="DoorShape" d="M264 445 l0 -20 l12 0 l0 20Z" />
-                    <path id="DoorBackRight" className={catering > 20 ? 'WarningShape' : 'DoorShape'} d="M336 445 l0 -20 l-12 0 l0 20Z" />
+                    <path id="DoorBackLeft" className={backLeftOpen ? 'WarningShape' : 'DoorShape'} d="M264 445 l0 -20 l12 0 l0 20Z" />
+                    <path id="DoorBackRight" className={backRightOpen ? 'WarningShape' : 'DoorShape'} d="M336 445 l0 -20 l-12 0 l0 20Z" />
                 </g>
 
                 <g id="dashes">
-                    <path id="cabin1dash" className={cabin > 20 ? 'WarningShape' : 'Hide'} strokeDasharray="7,4" d="M138, 136 l121 0" />
-                    <path id="cabin4dash" className={catering > 20 ? 'WarningShape' : 'Hide'} strokeDasharray="7,4" d="M346, 438 l77 0" />
-                    <path id="cargo1dash" className={cargoLocked ? 'Hide' : 'WarningShape'} strokeDasharray="7,4" d="M346, 210 l77 0" />
+                    <path id="cabin1dash" className={frontLeftOpen ? 'WarningShape' : 'Hide'} strokeDasharray="7,4" d="M138, 136 l121 0" />
+                    <path id="cabin2dash" className={frontRightOpen ? 'WarningShape' : 'Hide'} strokeDasharray="7,4" d="M346, 136 l77 0" />
+                    <path id="cabin3dash" className={backLeftOpen ? 'WarningShape' : 'Hide'} strokeDasharray="7,4" d="M138, 438 l121 0" />
+                    <path id="cabin4dash" className={backRightOpen ? 'WarningShape' : 'Hide'} strokeDasharray="7,4" d="M346, 438 l77 0" />
+                    <path id="cargo1dash" className={fwdCargoOpen ? 'WarningShape' : 'Hide'} strokeDasharray="7,4" d="M346, 210 l77 0" />
+                    <path id="cargo2dash" className={aftCargoOpen ? 'WarningShape' : 'Hide'} strokeDasharray="7,4" d="M346, 374 l77 0" />
                 </g>
 
                 {/* Texts */}
                 <g id="texts">
                     <text id="PageTitle" className="Title" x="300" y="16" textAnchor="middle" alignmentBaseline="central" textDecoration="underline">DOOR/OXY</text>
-                    <text id="slide1" className={slides ? 'Slide' : 'Hide'} x="232" y="136" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
-                    <text id="slide2" className={slides ? 'Slide' : 'Hide'} x="368" y="136" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
+                    <text id="slide1" className={isSlideArmed(11) ? 'Slide' : 'Hide'} x="232" y="136" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
+                    <text id="slide2" className={isSlideArmed(12) ? 'Slide' : 'Hide'} x="368" y="136" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
                     <text id="slide3" className="Slide" x="232" y="320" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
                     <text id="slide4" className="Slide" x="368" y="320" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
-                    <text id="slide5" className={slides ? 'Slide' : 'Hide'} x="232" y="438" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
-                    <text id="slide6" className={slides ? 'Slide' : 'Hide'} x="368" y="438" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
+                    <text id="slide5" className={isSlideArmed(13) ? 'Slide' : 'Hide'} x="232" y="438" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
+                    <text id="slide6" className={isSlideArmed(14) ? 'Slide' : 'Hide'} x="368" y="438" textAnchor="middle" alignmentBaseline="central">SLIDE</text>
 
-                    <text id="cabin1" className={cabin > 20 ? 'Warning' : 'Hide'} x="103" y="136" textAnchor="middle" alignmentBaseline="central">CABIN</text>
-                    <text id="cabin4" className={catering > 20 ? 'Warning' : 'Hide'} x="455" y="438" textAnchor="middle" alignmentBaseline="central">CABIN</text>
-                    <text id="cargo1" className={cargoLocked ? 'Hide' : 'Warning'} x="455" y="211" textAnchor="middle" alignmentBaseline="central">CARGO</text>
+                    <text id="cabin1" className={frontLeftOpen ? 'Warning' : 'Hide'} x="103" y="136" textAnchor="middle" alignmentBaseline="central">CABIN</text>
+                    <text id="cabin2" className={frontRightOpen ? 'Warning' : 'Hide'} x="455" y="136" textAnchor="middle" alignmentBaseline="central">CABIN</text>
+                    <text id="cabin3" className={backLeftOpen ? 'Warning' : 'Hide'} x="103" y="438" textAnchor="middle" alignmentBaseline="central">CABIN</text>
+                    <text id="cabin4" className={backRightOpen ? 'Warning' : 'Hide'} x="455" y="438" textAnchor="middle" alignmentBaseline="central">CABIN</text>
+                    <text id="cargo1" className={fwdCargoOpen ? 'Warning' : 'Hide'} x="455" y="211" textAnchor="middle" alignmentBaseline="central">CARGO</text>
+                    <text id="cargo2" className={aftCargoOpen ? 'Warning' : 'Hide'} x="455" y="375" textAnchor="middle" alignmentBaseline="central">CARGO</text>
 
                     <text
                         id="oxy"
@@ -0,0 +1,40 @@
+//! Prints the time spent per simulation tick in each of the A320 systems.
+//!
+//! `cargo run --release --features profiling --example tick_profile -- [ticks]`
+//!
+//! The aircraft is simulated in cruise at 30 frames per second. Compare the output against
+//! a previous build to find performance regressions.
+use a320_systems::A320;
+use std::{env, process, time::Duration};
+use systems::simulation::{profiling, test::SimulationTestBed, StartState};
+
+const WARM_UP_TICKS: usize = 100;
+const FRAME_TIME: Duration = Duration::from_millis(33);
+
+fn main() {
+    if !profiling::is_enabled() {
+        eprintln!("profiling is disabled, run with --features profiling");
+        process::exit(1);
+    }
+
+    let ticks = match env::args().nth(1).map(|ticks| ticks.parse()) {
+        None => 2000,
+        Some(Ok(ticks)) => ticks,
+        Some(Err(_)) => {
+            eprintln!("the number of ticks must be a positive integer");
+            process::exit(1);
+        }
+    };
+
+    let mut test_bed = SimulationTestBed::new_with_start_state(StartState::Cruise, A320::new);
+    for _ in 0..WARM_UP_TICKS {
+        test_bed.run_with_delta(FRAME_TIME);
+    }
+
+    profiling::reset();
+    for _ in 0..ticks {
+        test_bed.run_with_delta(FRAME_TIME);
+    }
+
+    print!("{}", profiling::profile());
+}
@@ -0,0 +1,28 @@
+//! Prints the catalogue of all variables used by the A320 systems simulation.
+//!
+//! `cargo run --example variable_catalogue -- [markdown|json|unwritten]`
+//!
+//! `unwritten` lists the variables which are read, but never written by the simulation
+//! nor declared as written externally.
+use a320_systems::A320;
+use std::{env, process};
+use systems::simulation::{StartState, VariableCatalogue};
+
+fn main() {
+    let catalogue = VariableCatalogue::of(StartState::Cruise, A320::new);
+
+    match env::args().nth(1).as_deref() {
+        None | Some("markdown") => print!("{}", catalogue.to_markdown()),
+        Some("json") => print!("{}", catalogue.to_json()),
+        Some("unwritten") => catalogue
+            .read_but_never_written()
+            .for_each(|entry| println!("{}", entry.name())),
+        Some(format) => {
+            eprintln!(
+                "unknown format '{}', expected markdown, json or unwritten",
+                format
+            );
+            process::exit(1);
+        }
+    }
+}
@@ -23,7 +23,7 @@ use systems::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
     },
 };
 
@@ -66,6 +66,7 @@ impl A320AirConditioning {
         engines: [&impl EngineCorrectedN1; 2],
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         number_of_passengers: &impl NumberOfPassengers,
+        door_open_ratios: &[Ratio],
         pneumatic: &(impl EngineStartState + PackFlowValveState + PneumaticBleed),
         pneumatic_overhead: &impl EngineBleedPushbutton<2>,
         pressurization_overhead: &A320PressurizationOverheadPanel,
@@ -95,6 +96,7 @@ impl A320AirConditioning {
                 &self.a320_air_conditioning_system,
                 lgciu,
                 number_of_passengers,
+                door_open_ratios,
                 &self.a320_pressurization_system,
             );
 
@@ -124,6 +126,20 @@ impl A320AirConditioning {
     }
 }
 
+impl CabinSimulation for A320AirConditioning {
+    fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+        self.a320_cabin.cabin_temperature()
+    }
+
+    fn exterior_pressure(&self) -> Pressure {
+        self.a320_cabin.exterior_pressure()
+    }
+
+    fn cabin_pressure(&self) -> Pressure {
+        self.a320_cabin.cabin_pressure()
+    }
+}
+
 impl PackFlowControllers for A320AirConditioning {
     type PackFlowControllerSignal =
         <A320AirConditioningSystem as PackFlowControllers>::PackFlowControllerSignal;
@@ -145,26 +161,13 @@ impl SimulationElement for A320AirConditioning {
 }
 
 struct A320Cabin {
-    fwd_door_id: VariableIdentifier,
-    rear_door_id: VariableIdentifier,
-
-    fwd_door_is_open: bool,
-    rear_door_is_open: bool,
     number_of_passengers: [u8; 3],
     cabin_air_simulation: CabinAirSimulation<A320PressurizationConstants, 3>,
 }
 
 impl A320Cabin {
-    const FWD_DOOR: &'static str = "INTERACTIVE POINT OPEN:0";
-    const REAR_DOOR: &'static str = "INTERACTIVE POINT OPEN:3";
-
     fn new(context: &mut InitContext) -> Self {
         Self {
-            fwd_door_id: context.get_identifier(Self::FWD_DOOR.to_owned()),
-            rear_door_id: context.get_identifier(Self::REAR_DOOR.to_owned()),
-
-            fwd_door_is_open: false,
-            rear_door_is_open: false,
             number_of_passengers: [2, 0, 0],
             cabin_air_simulation: CabinAirSimulation::new(
                 context,
@@ -179,12 +182,12 @@ impl A320Cabin {
         air_conditioning_system: &(impl OutletAir + DuctTemperature),
         lgciu: [&impl LgciuWeightOnWheels; 2],
         number_of_passengers: &impl NumberOfPassengers,
+        door_open_ratios: &[Ratio],
         pressurization: &A320PressurizationSystem,
     ) {
         let lgciu_gears_compressed = lgciu
             .iter()
             .all(|&a| a.left_and_right_gear_compressed(true));
-        let number_of_open_doors = self.fwd_door_is_open as u8 + self.rear_door_is_open as u8;
 
         self.update_number_of_passengers(number_of_passengers);
 
@@ -195,7 +198,7 @@ impl A320Cabin {
             pressurization.safety_valve_open_amount(),
             lgciu_gears_compressed,
             self.number_of_passengers,
-            number_of_open_doors,
+            door_open_ratios,
         );
     }
 
@@ -224,13 +227,6 @@ impl CabinSimulation for A320Cabin {
 }
 
 impl SimulationElement for A320Cabin {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        let rear_door_read: Ratio = reader.read(&self.rear_door_id);
-        self.rear_door_is_open = rear_door_read > Ratio::default();
-        let fwd_door_read: Ratio = reader.read(&self.fwd_door_id);
-        self.fwd_door_is_open = fwd_door_read > Ratio::default();
-    }
-
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.cabin_air_simulation.accept(visitor);
 
@@ -389,8 +385,13 @@ pub(crate) struct A320AirConditioningSystemOverhead<const ZONES: usize> {
 impl<const ZONES: usize> A320AirConditioningSystemOverhead<ZONES> {
     pub fn new(context: &mut InitContext, cabin_zone_ids: &[ZoneType; ZONES]) -> Self {
         let mut overhead = Self {
-            flow_selector_id: context
-                .get_identifier("KNOB_OVHD_AIRCOND_PACKFLOW_Position".to_owned()),
+            flow_selector_id: context.get_identifier_with_metadata(
+                "KNOB_OVHD_AIRCOND_PACKFLOW_Position".to_owned(),
+                VariableMetadata::new()
+                    .unit("number")
+                    .description("Pack flow selector position")
+                    .written_externally(),
+            ),
 
             pack_pbs: [
                 OnOffFaultPushButton::new_on(context, "COND_PACK_1"),
@@ -1224,6 +1225,7 @@ mod tests {
         engine_2: TestEngine,
         engine_fire_push_buttons: TestEngineFirePushButtons,
         payload: TestPayload,
+        door_open_ratios: [Ratio; 2],
         pneumatic: TestPneumatic,
         pneumatic_overhead: TestPneumaticOverhead,
         pressurization_overhead: A320PressurizationOverheadPanel,
@@ -1257,6 +1259,7 @@ mod tests {
                 engine_2: TestEngine::new(Ratio::default()),
                 engine_fire_push_buttons: TestEngineFirePushButtons::new(),
                 payload: TestPayload {},
+                door_open_ratios: [Ratio::default(); 2],
                 pneumatic: TestPneumatic::new(context),
                 pneumatic_overhead: TestPneumaticOverhead::new(context),
                 pressurization_overhead: A320PressurizationOverheadPanel::new(context),
@@ -1357,6 +1360,7 @@ mod tests {
                 [&self.engine_1, &self.engine_2],
                 &self.engine_fire_push_buttons,
                 &self.payload,
+                &self.door_open_ratios,
                 &self.pneumatic,
                 &self.pneumatic_overhead,
                 &self.pressurization_overhead,
@@ -1587,7 +1591,7 @@ mod tests {
         }
 
         fn command_open_door(mut self) -> Self {
-            self.write_by_name("INTERACTIVE POINT OPEN:0", Ratio::new::<percent>(100.));
+            self.command(|a| a.door_open_ratios[0] = Ratio::new::<percent>(100.));
             self
         }
 
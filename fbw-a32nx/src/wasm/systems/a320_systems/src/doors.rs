@@ -0,0 +1,314 @@
+use systems::{
+    accept_iterable,
+    doors::{Door, DoorType},
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        CabinSimulation, EngineCorrectedN2,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, VariableIdentifier, VariableMetadata, Write,
+    },
+};
+use uom::si::f64::Ratio;
+
+/// The doors of the A320. The SD DOOR page receives the state of all doors through the status
+/// words, in which every door is represented by one bit, starting at bit 11 in the order of
+/// [`A320Doors::DOORS`] followed by [`A320Doors::CARGO_DOORS`]. The slides of all doors are armed
+/// together, following the slide arming computed by the instruments. The cargo doors are moved by
+/// the hydraulic system, which is given their opening inhibition.
+pub(super) struct A320Doors {
+    slides_armed_id: VariableIdentifier,
+    door_status_word_1_id: VariableIdentifier,
+    door_status_word_2_id: VariableIdentifier,
+    door_status_word_3_id: VariableIdentifier,
+
+    slides_armed: bool,
+    doors: Vec<Door>,
+}
+impl A320Doors {
+    const FIRST_DOOR_BIT: u8 = 11;
+
+    // The overwing emergency exits and the bulk cargo door can't be opened in the simulator.
+    const DOORS: [(&'static str, DoorType, &'static str); 4] = [
+        ("L1", DoorType::Passenger, "INTERACTIVE POINT OPEN:0"),
+        ("R1", DoorType::Service, "INTERACTIVE POINT OPEN:1"),
+        ("L2", DoorType::Passenger, "INTERACTIVE POINT OPEN:2"),
+        ("R2", DoorType::Service, "INTERACTIVE POINT OPEN:3"),
+    ];
+    const CARGO_DOORS: [&'static str; 2] = ["CARGO_FWD", "CARGO_AFT"];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        let mut doors: Vec<Door> = Self::DOORS
+            .iter()
+            .map(|(id, door_type, position_variable)| {
+                Door::new(context, id, *door_type, position_variable)
+            })
+            .collect();
+        doors.extend(
+            Self::CARGO_DOORS
+                .iter()
+                .map(|id| Door::new_actuated(context, id, DoorType::Cargo)),
+        );
+
+        Self {
+            slides_armed_id: context.get_identifier_with_metadata(
+                "SLIDES_ARMED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The door escape slides are armed")
+                    .written_externally(),
+            ),
+            door_status_word_1_id: context.get_identifier("DOOR_STATUS_WORD_1".to_owned()),
+            door_status_word_2_id: context.get_identifier("DOOR_STATUS_WORD_2".to_owned()),
+            door_status_word_3_id: context.get_identifier("DOOR_STATUS_WORD_3".to_owned()),
+
+            slides_armed: false,
+            doors,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        cabin: &impl CabinSimulation,
+        engines: [&impl EngineCorrectedN2; 2],
+        cargo_door_positions: [Ratio; 2],
+    ) {
+        for door in &mut self.doors {
+            if self.slides_armed {
+                door.arm_slide();
+            } else {
+                door.disarm_slide();
+            }
+        }
+
+        let (doors, cargo_doors) = self.doors.split_at_mut(Self::DOORS.len());
+        for door in doors {
+            door.update(cabin, &engines);
+        }
+
+        for (door, position) in cargo_doors.iter_mut().zip(cargo_door_positions) {
+            door.update_actuated(cabin, &engines, position);
+        }
+    }
+
+    /// Whether the differential pressure inhibits the opening of the forward and aft cargo doors.
+    pub fn cargo_doors_opening_inhibited(&self) -> [bool; 2] {
+        let cargo_doors = &self.doors[Self::DOORS.len()..];
+        [
+            cargo_doors[0].is_opening_inhibited(),
+            cargo_doors[1].is_opening_inhibited(),
+        ]
+    }
+
+    /// The opening of every door, through which the cabin exchanges air with the exterior.
+    pub fn open_ratios(&self) -> Vec<Ratio> {
+        self.doors.iter().map(|door| door.open_ratio()).collect()
+    }
+
+    /// The doors which aren't closed.
+    pub fn status_word_1(&self) -> Arinc429Word<u32> {
+        self.status_word(|door| !door.is_closed())
+    }
+
+    /// The doors with an armed escape slide.
+    pub fn status_word_2(&self) -> Arinc429Word<u32> {
+        self.status_word(|door| door.is_slide_armed())
+    }
+
+    /// The doors with a residual cabin pressure warning.
+    pub fn status_word_3(&self) -> Arinc429Word<u32> {
+        self.status_word(|door| door.has_cabin_pressure_warning())
+    }
+
+    fn status_word(&self, door_bit: impl Fn(&Door) -> bool) -> Arinc429Word<u32> {
+        let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+        for (index, door) in self.doors.iter().enumerate() {
+            word.set_bit(Self::FIRST_DOOR_BIT + index as u8, door_bit(door));
+        }
+
+        word
+    }
+}
+impl SimulationElement for A320Doors {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.doors, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.slides_armed = reader.read(&self.slides_armed_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.door_status_word_1_id, self.status_word_1());
+        writer.write(&self.door_status_word_2_id, self.status_word_2());
+        writer.write(&self.door_status_word_3_id, self.status_word_3());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::{
+        electrical::Electricity,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, UpdateContext,
+        },
+    };
+    use uom::si::{
+        f64::{Pressure, ThermodynamicTemperature},
+        pressure::hectopascal,
+        ratio::percent,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    struct TestCabin {
+        differential_pressure: Pressure,
+    }
+    impl CabinSimulation for TestCabin {
+        fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+            vec![ThermodynamicTemperature::new::<degree_celsius>(24.)]
+        }
+
+        fn cabin_pressure(&self) -> Pressure {
+            self.exterior_pressure() + self.differential_pressure
+        }
+    }
+
+    struct TestEngine;
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            Ratio::default()
+        }
+    }
+
+    struct TestAircraft {
+        doors: A320Doors,
+        cargo_door_positions: [Ratio; 2],
+        cabin: TestCabin,
+        engine: TestEngine,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                doors: A320Doors::new(context),
+                cargo_door_positions: [Ratio::default(); 2],
+                cabin: TestCabin {
+                    differential_pressure: Pressure::default(),
+                },
+                engine: TestEngine,
+            }
+        }
+
+        fn set_differential_pressure(&mut self, differential_pressure_hpa: f64) {
+            self.cabin.differential_pressure =
+                Pressure::new::<hectopascal>(differential_pressure_hpa);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(&mut self, _: &UpdateContext, _: &mut Electricity) {
+            self.doors.update(
+                &self.cabin,
+                [&self.engine, &self.engine],
+                self.cargo_door_positions,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.doors.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn status_word_bits(test_bed: &mut SimulationTestBed<TestAircraft>, name: &str) -> u32 {
+        let word: Arinc429Word<u32> = test_bed.read_arinc429_by_name(name);
+        word.value() >> 10
+    }
+
+    #[test]
+    fn all_doors_closed_by_default() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert_eq!(status_word_bits(&mut test_bed, "DOOR_STATUS_WORD_1"), 0);
+        assert!(test_bed
+            .query(|a| a.doors.open_ratios())
+            .iter()
+            .all(|ratio| *ratio == Ratio::default()));
+    }
+
+    #[test]
+    fn open_door_is_indicated_in_its_bit() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("INTERACTIVE POINT OPEN:3", Ratio::new::<percent>(100.));
+        test_bed.command(|a| a.cargo_door_positions[0] = Ratio::new::<percent>(30.));
+        test_bed.run();
+
+        assert_eq!(
+            status_word_bits(&mut test_bed, "DOOR_STATUS_WORD_1"),
+            1 << 3 | 1 << 4
+        );
+    }
+
+    #[test]
+    fn armed_slides_are_indicated_for_slide_doors_only() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("SLIDES_ARMED", true);
+        test_bed.run();
+
+        assert_eq!(status_word_bits(&mut test_bed, "DOOR_STATUS_WORD_2"), 0x0f);
+    }
+
+    #[test]
+    fn residual_pressure_is_indicated_on_disarmed_doors() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_differential_pressure(5.));
+        test_bed.run();
+
+        assert_eq!(status_word_bits(&mut test_bed, "DOOR_STATUS_WORD_3"), 0x0f);
+
+        test_bed.write_by_name("SLIDES_ARMED", true);
+        test_bed.run();
+
+        assert_eq!(status_word_bits(&mut test_bed, "DOOR_STATUS_WORD_3"), 0);
+    }
+
+    #[test]
+    fn cargo_door_opening_is_inhibited_by_residual_pressure_but_not_masked() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.set_differential_pressure(30.);
+            a.cargo_door_positions[1] = Ratio::new::<percent>(30.);
+        });
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.doors.cargo_doors_opening_inhibited()),
+            [true, true]
+        );
+        assert_eq!(
+            status_word_bits(&mut test_bed, "DOOR_STATUS_WORD_1"),
+            1 << 5
+        );
+    }
+
+    #[test]
+    fn disarming_the_slides_disarms_all_doors() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("SLIDES_ARMED", true);
+        test_bed.run();
+        test_bed.write_by_name("SLIDES_ARMED", false);
+        test_bed.run();
+
+        assert_eq!(status_word_bits(&mut test_bed, "DOOR_STATUS_WORD_2"), 0);
+    }
+}
@@ -427,7 +427,8 @@ mod a320_electrical_circuit_tests {
         electrical::{
             ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
             Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+            ProvideLoad, ProvidePotential,
+            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
         },
         failures::FailureType,
         shared::{
@@ -2147,6 +2148,15 @@ mod a320_electrical_circuit_tests {
             self.is_available
         }
     }
+    impl ProvideLoad for TestApuGenerator {
+        fn load(&self) -> Ratio {
+            Ratio::new::<percent>(0.)
+        }
+
+        fn load_normal(&self) -> bool {
+            true
+        }
+    }
     impl ProvideFrequency for TestApuGenerator {
         fn frequency(&self) -> Frequency {
             if self.is_available {
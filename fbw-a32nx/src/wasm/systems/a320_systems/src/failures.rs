@@ -0,0 +1,275 @@
+use systems::{
+    failures::FailureType,
+    shared::{
+        AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
+        HeatedProbe, HeatedWindow, HydraulicColor, LgciuId, ProximityDetectorId,
+    },
+};
+
+/// The failures which can be activated on the A320, by the identifiers used within MSFS.
+pub const FAILURES: &[(u64, FailureType)] = &[
+    (24_000, FailureType::TransformerRectifier(1)),
+    (24_001, FailureType::TransformerRectifier(2)),
+    (24_002, FailureType::TransformerRectifier(3)),
+    (24_004, FailureType::StaticInverter),
+    (24_020, FailureType::Generator(1)),
+    (24_021, FailureType::Generator(2)),
+    (24_030, FailureType::ApuGenerator(1)),
+    (
+        24_100,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
+    ),
+    (
+        24_101,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
+    ),
+    (
+        24_102,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
+    ),
+    (
+        24_103,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
+    ),
+    (
+        24_104,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentStaticInverter),
+    ),
+    (
+        24_105,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
+    ),
+    (
+        24_106,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
+    ),
+    (
+        24_107,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
+    ),
+    (
+        24_108,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
+    ),
+    (
+        24_109,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssentialShed),
+    ),
+    (
+        24_110,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentBattery),
+    ),
+    (
+        24_111,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
+    ),
+    (
+        24_112,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
+    ),
+    (
+        24_113,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
+    ),
+    (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+    (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
+    (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+    (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+    (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
+    (
+        29_005,
+        FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_006,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+    ),
+    (
+        29_007,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
+    ),
+    (
+        29_008,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_009,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
+    ),
+    (
+        29_010,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
+    ),
+    (
+        29_011,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
+    ),
+    (
+        29_012,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
+    ),
+    (30_000, FailureType::ProbeHeater(1, HeatedProbe::Pitot)),
+    (
+        30_001,
+        FailureType::ProbeHeater(1, HeatedProbe::StaticPorts),
+    ),
+    (
+        30_002,
+        FailureType::ProbeHeater(1, HeatedProbe::AngleOfAttack),
+    ),
+    (
+        30_003,
+        FailureType::ProbeHeater(1, HeatedProbe::TotalAirTemperature),
+    ),
+    (30_010, FailureType::ProbeHeater(2, HeatedProbe::Pitot)),
+    (
+        30_011,
+        FailureType::ProbeHeater(2, HeatedProbe::StaticPorts),
+    ),
+    (
+        30_012,
+        FailureType::ProbeHeater(2, HeatedProbe::AngleOfAttack),
+    ),
+    (
+        30_013,
+        FailureType::ProbeHeater(2, HeatedProbe::TotalAirTemperature),
+    ),
+    (30_020, FailureType::ProbeHeater(3, HeatedProbe::Pitot)),
+    (
+        30_021,
+        FailureType::ProbeHeater(3, HeatedProbe::StaticPorts),
+    ),
+    (
+        30_022,
+        FailureType::ProbeHeater(3, HeatedProbe::AngleOfAttack),
+    ),
+    (
+        30_100,
+        FailureType::WindowHeater(1, HeatedWindow::Windshield),
+    ),
+    (
+        30_101,
+        FailureType::WindowHeater(1, HeatedWindow::SideWindow),
+    ),
+    (
+        30_110,
+        FailureType::WindowHeater(2, HeatedWindow::Windshield),
+    ),
+    (
+        30_111,
+        FailureType::WindowHeater(2, HeatedWindow::SideWindow),
+    ),
+    (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+    (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+    (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+    (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+    (
+        32_004,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+    ),
+    (
+        32_005,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+    ),
+    (
+        32_006,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+    ),
+    (
+        32_007,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+    ),
+    (
+        32_008,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+    ),
+    (
+        32_009,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+    ),
+    (
+        32_010,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+    ),
+    (
+        32_011,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+    ),
+    (
+        32_012,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+    ),
+    (
+        32_013,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+    ),
+    (
+        32_014,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+    ),
+    (
+        32_015,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+    ),
+    (
+        32_020,
+        FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+    ),
+    (
+        32_021,
+        FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+    ),
+    (
+        32_022,
+        FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+    ),
+    (
+        32_023,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+    ),
+    (
+        32_024,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+    ),
+    (
+        32_025,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+    ),
+    (
+        32_100,
+        FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
+    ),
+    (
+        32_101,
+        FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
+    ),
+    (32_150, FailureType::BrakeAccumulatorGasLeak),
+    (32_200, FailureType::TyreSlowLeak(1)),
+    (32_201, FailureType::TyreSlowLeak(2)),
+    (32_202, FailureType::TyreSlowLeak(3)),
+    (32_203, FailureType::TyreSlowLeak(4)),
+    (32_204, FailureType::TyreSlowLeak(5)),
+    (32_205, FailureType::TyreSlowLeak(6)),
+    (32_250, FailureType::TyreBurst(1)),
+    (32_251, FailureType::TyreBurst(2)),
+    (32_252, FailureType::TyreBurst(3)),
+    (32_253, FailureType::TyreBurst(4)),
+    (32_254, FailureType::TyreBurst(5)),
+    (32_255, FailureType::TyreBurst(6)),
+    (34_000, FailureType::RadioAltimeter(1)),
+    (34_001, FailureType::RadioAltimeter(2)),
+    (34_100, FailureType::PitotBlockage(1)),
+    (34_101, FailureType::PitotBlockage(2)),
+    (34_102, FailureType::PitotBlockage(3)),
+    (34_110, FailureType::PitotDrainBlockage(1)),
+    (34_111, FailureType::PitotDrainBlockage(2)),
+    (34_112, FailureType::PitotDrainBlockage(3)),
+    (34_120, FailureType::StaticPortBlockage(1)),
+    (34_121, FailureType::StaticPortBlockage(2)),
+    (34_122, FailureType::StaticPortBlockage(3)),
+    (34_130, FailureType::AngleOfAttackVaneJam(1)),
+    (34_131, FailureType::AngleOfAttackVaneJam(2)),
+    (34_132, FailureType::AngleOfAttackVaneJam(3)),
+    (34_200, FailureType::GpsReceiver(1)),
+    (34_201, FailureType::GpsReceiver(2)),
+];
@@ -1,34 +1,102 @@
-use systems::simulation::{
-    InitContext, Read, SimulationElement, SimulatorReader, VariableIdentifier,
+use systems::{
+    fuel::FuelTank,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier, VariableMetadata,
+    },
 };
 use uom::si::{f64::*, mass::kilogram};
 
 pub struct A320Fuel {
-    unlimited_fuel_id: VariableIdentifier,
-    fuel_tank_left_main_quantity_id: VariableIdentifier,
+    fuel_tank_center_quantity_id: VariableIdentifier,
+    fuel_tank_right_main_quantity_id: VariableIdentifier,
+    fuel_tank_left_aux_quantity_id: VariableIdentifier,
+    fuel_tank_right_aux_quantity_id: VariableIdentifier,
 
-    unlimited_fuel: bool,
-    left_inner_tank_fuel_quantity: Mass,
+    left_inner_tank: FuelTank,
+    center_tank_fuel_quantity: Mass,
+    right_inner_tank_fuel_quantity: Mass,
+    left_outer_tank_fuel_quantity: Mass,
+    right_outer_tank_fuel_quantity: Mass,
 }
 impl A320Fuel {
     pub fn new(context: &mut InitContext) -> Self {
         A320Fuel {
-            unlimited_fuel_id: context.get_identifier("UNLIMITED FUEL".to_owned()),
-            fuel_tank_left_main_quantity_id: context
-                .get_identifier("FUEL TANK LEFT MAIN QUANTITY".to_owned()),
+            fuel_tank_center_quantity_id: context.get_identifier_with_metadata(
+                "FUEL TANK CENTER QUANTITY".to_owned(),
+                VariableMetadata::new()
+                    .unit("pounds")
+                    .description("Fuel quantity of the center tank")
+                    .written_externally(),
+            ),
+            fuel_tank_right_main_quantity_id: context.get_identifier_with_metadata(
+                "FUEL TANK RIGHT MAIN QUANTITY".to_owned(),
+                VariableMetadata::new()
+                    .unit("pounds")
+                    .description("Fuel quantity of the right inner tank")
+                    .written_externally(),
+            ),
+            fuel_tank_left_aux_quantity_id: context.get_identifier_with_metadata(
+                "FUEL TANK LEFT AUX QUANTITY".to_owned(),
+                VariableMetadata::new()
+                    .unit("pounds")
+                    .description("Fuel quantity of the left outer tank")
+                    .written_externally(),
+            ),
+            fuel_tank_right_aux_quantity_id: context.get_identifier_with_metadata(
+                "FUEL TANK RIGHT AUX QUANTITY".to_owned(),
+                VariableMetadata::new()
+                    .unit("pounds")
+                    .description("Fuel quantity of the right outer tank")
+                    .written_externally(),
+            ),
 
-            unlimited_fuel: false,
-            left_inner_tank_fuel_quantity: Mass::new::<kilogram>(0.),
+            left_inner_tank: FuelTank::new(
+                context,
+                "FUEL TANK LEFT MAIN QUANTITY",
+                "FUEL_TANK_LEFT_MAIN_QUANTITY_REQ",
+            ),
+            center_tank_fuel_quantity: Mass::new::<kilogram>(0.),
+            right_inner_tank_fuel_quantity: Mass::new::<kilogram>(0.),
+            left_outer_tank_fuel_quantity: Mass::new::<kilogram>(0.),
+            right_outer_tank_fuel_quantity: Mass::new::<kilogram>(0.),
         }
     }
 
+    /// The APU is fed from the left inner tank.
+    pub fn update(&mut self, context: &UpdateContext, apu_fuel_flow: MassRate) {
+        self.left_inner_tank.update(context, apu_fuel_flow);
+    }
+
     pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
-        self.unlimited_fuel || self.left_inner_tank_fuel_quantity > Mass::new::<kilogram>(0.)
+        self.left_inner_tank.has_fuel_remaining()
+    }
+
+    pub fn center_tank_fuel_quantity(&self) -> Mass {
+        self.center_tank_fuel_quantity
+    }
+
+    /// The combined quantity of the left and right inner tanks.
+    pub fn inner_tanks_fuel_quantity(&self) -> Mass {
+        self.left_inner_tank.quantity() + self.right_inner_tank_fuel_quantity
+    }
+
+    /// The combined quantity of the left and right outer tanks.
+    pub fn outer_tanks_fuel_quantity(&self) -> Mass {
+        self.left_outer_tank_fuel_quantity + self.right_outer_tank_fuel_quantity
     }
 }
 impl SimulationElement for A320Fuel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.left_inner_tank.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.unlimited_fuel = reader.read(&self.unlimited_fuel_id);
-        self.left_inner_tank_fuel_quantity = reader.read(&self.fuel_tank_left_main_quantity_id);
+        self.center_tank_fuel_quantity = reader.read(&self.fuel_tank_center_quantity_id);
+        self.right_inner_tank_fuel_quantity = reader.read(&self.fuel_tank_right_main_quantity_id);
+        self.left_outer_tank_fuel_quantity = reader.read(&self.fuel_tank_left_aux_quantity_id);
+        self.right_outer_tank_fuel_quantity = reader.read(&self.fuel_tank_right_aux_quantity_id);
     }
 }
@@ -3,7 +3,7 @@ use systems::shared::FeedbackPositionPickoffUnit;
 
 use systems::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
 };
 
 use std::panic;
@@ -43,7 +43,13 @@ struct FlapsHandle {
 impl FlapsHandle {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            handle_position_id: context.get_identifier("FLAPS_HANDLE_INDEX".to_owned()),
+            handle_position_id: context.get_identifier_with_metadata(
+                "FLAPS_HANDLE_INDEX".to_owned(),
+                VariableMetadata::new()
+                    .unit("number")
+                    .description("Position of the flaps lever")
+                    .written_externally(),
+            ),
             position: 0,
             previous_position: 0,
         }
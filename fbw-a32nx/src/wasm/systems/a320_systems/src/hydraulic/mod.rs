@@ -54,7 +54,10 @@ use systems::{
         PowerTransferUnitCharacteristics, PowerTransferUnitController, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, RamAirTurbine, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet},
+    landing_gear::{
+        tyre_pressure::{Tyre, TyreCondition, TyrePressureIndicatingSystem},
+        GearSystemSensors, LandingGearControlInterfaceUnitSet,
+    },
     overhead::{
         AutoOffFaultPushButton, AutoOnFaultPushButton, MomentaryOnPushButton, MomentaryPushButton,
     },
@@ -70,7 +73,7 @@ use systems::{
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, StartState, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, StartState, UpdateContext, VariableIdentifier, VariableMetadata, Write,
     },
 };
 
@@ -1483,6 +1486,8 @@ pub(super) struct A320Hydraulic {
     braking_circuit_altn: BrakeCircuit,
     braking_force: A320BrakingForce,
 
+    tyre_pressure_indicating_system: TyrePressureIndicatingSystem<6>,
+
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
     slats_flaps_complex: SlatFlapComplex,
@@ -1569,6 +1574,15 @@ impl A320Hydraulic {
 
     const ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE: f64 = 1000.0; // Nitrogen PSI
 
+    const TYRE_PRESSURE_INDICATING_SYSTEM_POWER_BUS: ElectricalBusType =
+        ElectricalBusType::DirectCurrent(1);
+    const MAIN_GEAR_TYRE_COLD_PRESSURE_PSI: f64 = 200.;
+    const NOSE_GEAR_TYRE_COLD_PRESSURE_PSI: f64 = 180.;
+
+    // Main gear tyres 1 to 4 share their number with their brake, nose tyres are 5 and 6
+    const TYRE_BRAKE_NUMBERS: [Option<usize>; 6] = [Some(1), Some(2), Some(3), Some(4), None, None];
+    const FIRST_NOSE_GEAR_TYRE_NUMBER: usize = 5;
+
     // Refresh rate of core hydraulic simulation
     const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(10);
 
@@ -1580,6 +1594,22 @@ impl A320Hydraulic {
             Ratio::new::<ratio>(0.03),
         );
 
+        let tyres = std::array::from_fn(|index| {
+            let number = index + 1;
+            let cold_inflation_pressure = if number >= Self::FIRST_NOSE_GEAR_TYRE_NUMBER {
+                Self::NOSE_GEAR_TYRE_COLD_PRESSURE_PSI
+            } else {
+                Self::MAIN_GEAR_TYRE_COLD_PRESSURE_PSI
+            };
+
+            Tyre::new(
+                context,
+                number,
+                Pressure::new::<psi>(cold_inflation_pressure),
+                Self::TYRE_BRAKE_NUMBERS[index],
+            )
+        });
+
         A320Hydraulic {
             hyd_ptu_ecam_memo_id: context.get_identifier("HYD_PTU_ON_ECAM_MEMO".to_owned()),
             ptu_high_pitch_sound_id: context.get_identifier("HYD_PTU_HIGH_PITCH_SOUND".to_owned()),
@@ -1704,6 +1734,12 @@ impl A320Hydraulic {
 
             braking_force: A320BrakingForce::new(context),
 
+            tyre_pressure_indicating_system: TyrePressureIndicatingSystem::new(
+                context,
+                Self::TYRE_PRESSURE_INDICATING_SYSTEM_POWER_BUS,
+                tyres,
+            ),
+
             flap_system: FlapSlatAssembly::new(
                 context,
                 "FLAPS",
@@ -1813,6 +1849,7 @@ impl A320Hydraulic {
         emergency_elec: &(impl EmergencyElectricalState + EmergencyGeneratorPower),
         reservoir_pneumatics: &impl ReservoirAirPressure,
         adirs: &impl AdirsDiscreteOutputs,
+        cargo_doors_opening_inhibited: [bool; 2],
     ) {
         self.core_hydraulic_updater.update(context);
 
@@ -1826,6 +1863,7 @@ impl A320Hydraulic {
             lgcius.lgciu2(),
             engine1,
             engine2,
+            cargo_doors_opening_inhibited,
         );
 
         for cur_time_step in self.core_hydraulic_updater {
@@ -1915,6 +1953,11 @@ impl A320Hydraulic {
         self.reversers_assembly.reversers_position()
     }
 
+    /// The forward and aft cargo doors.
+    pub fn cargo_doors(&self) -> [&CargoDoor; 2] {
+        [&self.forward_cargo_door, &self.aft_cargo_door]
+    }
+
     #[cfg(test)]
     fn should_pressurise_yellow_pump_for_cargo_door_operation(&self) -> bool {
         self.yellow_electric_pump_controller
@@ -1950,6 +1993,22 @@ impl A320Hydraulic {
         lgcius: &LandingGearControlInterfaceUnitSet,
         adirs: &impl AdirsDiscreteOutputs,
     ) {
+        self.flap_system.update(
+            context,
+            self.slats_flaps_complex.flap_demand(),
+            self.slats_flaps_complex.flap_demand(),
+            self.green_circuit.system_section(),
+            self.yellow_circuit.system_section(),
+        );
+
+        self.slat_system.update(
+            context,
+            self.slats_flaps_complex.slat_demand(),
+            self.slats_flaps_complex.slat_demand(),
+            self.blue_circuit.system_section(),
+            self.green_circuit.system_section(),
+        );
+
         self.forward_cargo_door.update(
             context,
             &self.forward_cargo_door_controller,
@@ -2073,7 +2132,10 @@ impl A320Hydraulic {
         lgciu2: &impl LgciuInterface,
         engine1: &impl Engine,
         engine2: &impl Engine,
+        cargo_doors_opening_inhibited: [bool; 2],
     ) {
+        self.tyre_pressure_indicating_system.update(context);
+
         self.nose_steering.update(
             context,
             self.yellow_circuit.system_section(),
@@ -2091,6 +2153,7 @@ impl A320Hydraulic {
             autobrake_panel,
             engine1,
             engine2,
+            &self.tyre_pressure_indicating_system,
         );
 
         // Updating rat stowed pos on all frames in case it's used for graphics
@@ -2113,37 +2176,24 @@ impl A320Hydraulic {
             engine1,
             engine2,
             &self.pushback_tug,
+            &self.tyre_pressure_indicating_system,
         );
 
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
 
-        self.flap_system.update(
-            context,
-            self.slats_flaps_complex.flap_demand(),
-            self.slats_flaps_complex.flap_demand(),
-            self.green_circuit.system_section(),
-            self.yellow_circuit.system_section(),
-        );
-
-        self.slat_system.update(
-            context,
-            self.slats_flaps_complex.slat_demand(),
-            self.slats_flaps_complex.slat_demand(),
-            self.blue_circuit.system_section(),
-            self.green_circuit.system_section(),
-        );
-
         self.forward_cargo_door_controller.update(
             context,
             &self.forward_cargo_door,
             self.yellow_circuit.system_section(),
+            cargo_doors_opening_inhibited[0],
         );
 
         self.aft_cargo_door_controller.update(
             context,
             &self.aft_cargo_door,
             self.yellow_circuit.system_section(),
+            cargo_doors_opening_inhibited[1],
         );
 
         self.slats_flaps_complex
@@ -2571,6 +2621,8 @@ impl SimulationElement for A320Hydraulic {
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
 
+        self.tyre_pressure_indicating_system.accept(visitor);
+
         self.emergency_gen.accept(visitor);
         self.nose_steering.accept(visitor);
         self.slats_flaps_complex.accept(visitor);
@@ -3303,11 +3355,27 @@ impl A320PowerTransferUnitController {
 
     fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
         Self {
-            park_brake_lever_pos_id: context.get_identifier("PARK_BRAKE_LEVER_POS".to_owned()),
-            general_eng_1_starter_active_id: context
-                .get_identifier("GENERAL ENG STARTER ACTIVE:1".to_owned()),
-            general_eng_2_starter_active_id: context
-                .get_identifier("GENERAL ENG STARTER ACTIVE:2".to_owned()),
+            park_brake_lever_pos_id: context.get_identifier_with_metadata(
+                "PARK_BRAKE_LEVER_POS".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The parking brake lever is set")
+                    .written_externally(),
+            ),
+            general_eng_1_starter_active_id: context.get_identifier_with_metadata(
+                "GENERAL ENG STARTER ACTIVE:1".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The master switch of engine 1 is on")
+                    .written_externally(),
+            ),
+            general_eng_2_starter_active_id: context.get_identifier_with_metadata(
+                "GENERAL ENG STARTER ACTIVE:2".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The master switch of engine 2 is on")
+                    .written_externally(),
+            ),
 
             is_powered: false,
             powered_by,
@@ -3588,6 +3656,8 @@ impl A320HydraulicBrakeSteerComputerUnit {
 
     const MAX_STEERING_ANGLE_DEMAND_DEGREES: f64 = 74.;
 
+    const NOSE_TYRES: [usize; 2] = [5, 6];
+
     // Minimum pressure hysteresis on green until main switched on ALTN brakes
     // Feedback by Cpt. Chaos — 25/04/2021 #pilot-feedback
     const MIN_PRESSURE_BRAKE_ALTN_HYST_LO: f64 = 1305.;
@@ -3603,19 +3673,70 @@ impl A320HydraulicBrakeSteerComputerUnit {
 
     fn new(context: &mut InitContext) -> Self {
         Self {
-            park_brake_lever_pos_id: context.get_identifier("PARK_BRAKE_LEVER_POS".to_owned()),
-            antiskid_brakes_active_id: context.get_identifier("ANTISKID BRAKES ACTIVE".to_owned()),
-            left_brake_pedal_input_id: context.get_identifier("LEFT_BRAKE_PEDAL_INPUT".to_owned()),
-            right_brake_pedal_input_id: context
-                .get_identifier("RIGHT_BRAKE_PEDAL_INPUT".to_owned()),
-
-            ground_speed_id: context.get_identifier("GPS GROUND SPEED".to_owned()),
-            rudder_pedal_input_id: context.get_identifier("RUDDER_PEDAL_POSITION_RATIO".to_owned()),
-            tiller_handle_input_id: context.get_identifier("TILLER_HANDLE_POSITION".to_owned()),
-            tiller_pedal_disconnect_id: context
-                .get_identifier("TILLER_PEDAL_DISCONNECT".to_owned()),
-            autopilot_nosewheel_demand_id: context
-                .get_identifier("AUTOPILOT_NOSEWHEEL_DEMAND".to_owned()),
+            park_brake_lever_pos_id: context.get_identifier_with_metadata(
+                "PARK_BRAKE_LEVER_POS".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The parking brake lever is set")
+                    .written_externally(),
+            ),
+            antiskid_brakes_active_id: context.get_identifier_with_metadata(
+                "ANTISKID BRAKES ACTIVE".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The anti skid switch is on")
+                    .written_externally(),
+            ),
+            left_brake_pedal_input_id: context.get_identifier_with_metadata(
+                "LEFT_BRAKE_PEDAL_INPUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("percent")
+                    .description("Deflection of the left brake pedals")
+                    .written_externally(),
+            ),
+            right_brake_pedal_input_id: context.get_identifier_with_metadata(
+                "RIGHT_BRAKE_PEDAL_INPUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("percent")
+                    .description("Deflection of the right brake pedals")
+                    .written_externally(),
+            ),
+
+            ground_speed_id: context.get_identifier_with_metadata(
+                "GPS GROUND SPEED".to_owned(),
+                VariableMetadata::new()
+                    .unit("knots")
+                    .description("Ground speed")
+                    .written_externally(),
+            ),
+            rudder_pedal_input_id: context.get_identifier_with_metadata(
+                "RUDDER_PEDAL_POSITION_RATIO".to_owned(),
+                VariableMetadata::new()
+                    .unit("ratio")
+                    .description("Deflection of the rudder pedals, positive to the right")
+                    .written_externally(),
+            ),
+            tiller_handle_input_id: context.get_identifier_with_metadata(
+                "TILLER_HANDLE_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("ratio")
+                    .description("Deflection of the steering tiller, positive to the right")
+                    .written_externally(),
+            ),
+            tiller_pedal_disconnect_id: context.get_identifier_with_metadata(
+                "TILLER_PEDAL_DISCONNECT".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The rudder pedals are disconnected from the nose wheel steering")
+                    .written_externally(),
+            ),
+            autopilot_nosewheel_demand_id: context.get_identifier_with_metadata(
+                "AUTOPILOT_NOSEWHEEL_DEMAND".to_owned(),
+                VariableMetadata::new()
+                    .unit("ratio")
+                    .description("Nose wheel steering demand of the autopilot")
+                    .written_externally(),
+            ),
 
             autobrake_controller: A320AutobrakeController::new(context),
 
@@ -3717,8 +3838,9 @@ impl A320HydraulicBrakeSteerComputerUnit {
         autobrake_panel: &AutobrakePanel,
         engine1: &impl Engine,
         engine2: &impl Engine,
+        tyres: &impl TyreCondition,
     ) {
-        self.update_steering_demands(lgciu1, engine1, engine2);
+        self.update_steering_demands(lgciu1, engine1, engine2, tyres);
 
         self.update_normal_braking_availability(current_pressure.pressure());
         self.update_brake_pressure_limitation();
@@ -3799,6 +3921,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
         lgciu1: &impl LgciuInterface,
         engine1: &impl Engine,
         engine2: &impl Engine,
+        tyres: &impl TyreCondition,
     ) {
         let steer_angle_from_autopilot = Angle::new::<degree>(
             self.autopilot_nosewheel_demand.get::<ratio>() * Self::AUTOPILOT_STEERING_INPUT_GAIN,
@@ -3832,17 +3955,19 @@ impl A320HydraulicBrakeSteerComputerUnit {
         let is_both_engine_low_oil_pressure =
             engine1.oil_pressure_is_low() && engine2.oil_pressure_is_low();
 
+        // A deflated nose tyre loses its cornering force so steering authority is reduced
+        let max_steering_angle_demand = Angle::new::<degree>(
+            Self::MAX_STEERING_ANGLE_DEMAND_DEGREES
+                * tyres.inflated_ratio(&Self::NOSE_TYRES).get::<ratio>(),
+        );
+
         self.final_steering_position_request = if !is_both_engine_low_oil_pressure
             && self.anti_skid_activated
             && lgciu1.nose_gear_compressed(false)
         {
             (final_steer_rudder_plus_autopilot + steer_angle_from_tiller)
-                .min(Angle::new::<degree>(
-                    Self::MAX_STEERING_ANGLE_DEMAND_DEGREES,
-                ))
-                .max(Angle::new::<degree>(
-                    -Self::MAX_STEERING_ANGLE_DEMAND_DEGREES,
-                ))
+                .min(max_steering_angle_demand)
+                .max(-max_steering_angle_demand)
         } else {
             Angle::new::<degree>(0.)
         };
@@ -3910,6 +4035,9 @@ impl A320BrakingForce {
     const FLAPS_BREAKPOINTS: [f64; 3] = [0., 50., 100.];
     const FLAPS_PENALTY_PERCENT: [f64; 3] = [5., 5., 0.];
 
+    const LEFT_BRAKED_TYRES: [usize; 2] = [1, 2];
+    const RIGHT_BRAKED_TYRES: [usize; 2] = [3, 4];
+
     pub fn new(context: &mut InitContext) -> Self {
         A320BrakingForce {
             brake_left_force_factor_id: context
@@ -3921,8 +4049,20 @@ impl A320BrakingForce {
             trailing_edge_flaps_right_percent_id: context
                 .get_identifier("RIGHT_FLAPS_POSITION_PERCENT".to_owned()),
 
-            enabled_chocks_id: context.get_identifier("MODEL_WHEELCHOCKS_ENABLED".to_owned()),
-            light_beacon_on_id: context.get_identifier("LIGHT BEACON".to_owned()),
+            enabled_chocks_id: context.get_identifier_with_metadata(
+                "MODEL_WHEELCHOCKS_ENABLED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The wheel chocks are in place")
+                    .written_externally(),
+            ),
+            light_beacon_on_id: context.get_identifier_with_metadata(
+                "LIGHT BEACON".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The beacon light is on")
+                    .written_externally(),
+            ),
 
             left_braking_force: 0.,
             right_braking_force: 0.,
@@ -3942,6 +4082,7 @@ impl A320BrakingForce {
         engine1: &impl Engine,
         engine2: &impl Engine,
         pushback_tug: &PushbackTug,
+        tyres: &impl TyreCondition,
     ) {
         // Base formula for output force is output_force[0:1] = 50 * sqrt(current_pressure) / Max_brake_pressure
         // This formula gives a bit more punch for lower brake pressures (like 1000 psi alternate braking), as linear formula
@@ -3962,10 +4103,21 @@ impl A320BrakingForce {
         self.right_braking_force = self.right_braking_force.max(0.).min(1.);
 
         self.correct_with_flaps_state(context);
+        self.correct_with_tyres_state(tyres);
 
         self.update_chocks_braking(context, engine1, engine2, pushback_tug);
     }
 
+    // A wheel running on a deflated tyre cannot transmit its share of braking force
+    fn correct_with_tyres_state(&mut self, tyres: &impl TyreCondition) {
+        self.left_braking_force *= tyres
+            .inflated_ratio(&Self::LEFT_BRAKED_TYRES)
+            .get::<ratio>();
+        self.right_braking_force *= tyres
+            .inflated_ratio(&Self::RIGHT_BRAKED_TYRES)
+            .get::<ratio>();
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -4076,13 +4228,34 @@ impl A320AutobrakeController {
             armed_mode_id_set: context.get_identifier("AUTOBRAKES_ARMED_MODE_SET".to_owned()),
             decel_light_id: context.get_identifier("AUTOBRAKES_DECEL_LIGHT".to_owned()),
             active_id: context.get_identifier("AUTOBRAKES_ACTIVE".to_owned()),
-            ground_spoilers_out_sec1_id: context
-                .get_identifier("SEC_1_GROUND_SPOILER_OUT".to_owned()),
-            ground_spoilers_out_sec2_id: context
-                .get_identifier("SEC_2_GROUND_SPOILER_OUT".to_owned()),
-            ground_spoilers_out_sec3_id: context
-                .get_identifier("SEC_3_GROUND_SPOILER_OUT".to_owned()),
-            external_disarm_event_id: context.get_identifier("AUTOBRAKE_DISARM".to_owned()),
+            ground_spoilers_out_sec1_id: context.get_identifier_with_metadata(
+                "SEC_1_GROUND_SPOILER_OUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("SEC 1 has extended the ground spoilers")
+                    .written_externally(),
+            ),
+            ground_spoilers_out_sec2_id: context.get_identifier_with_metadata(
+                "SEC_2_GROUND_SPOILER_OUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("SEC 2 has extended the ground spoilers")
+                    .written_externally(),
+            ),
+            ground_spoilers_out_sec3_id: context.get_identifier_with_metadata(
+                "SEC_3_GROUND_SPOILER_OUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("SEC 3 has extended the ground spoilers")
+                    .written_externally(),
+            ),
+            external_disarm_event_id: context.get_identifier_with_metadata(
+                "AUTOBRAKE_DISARM".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The flight controls request the autobrake to disarm")
+                    .written_externally(),
+            ),
 
             deceleration_governor: AutobrakeDecelerationGovernor::new(),
             target: Acceleration::new::<meter_per_second_squared>(0.),
@@ -4486,23 +4659,71 @@ struct AileronSystemHydraulicController {
 impl AileronSystemHydraulicController {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            left_aileron_blue_actuator_solenoid_id: context
-                .get_identifier("LEFT_AIL_BLUE_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            right_aileron_blue_actuator_solenoid_id: context
-                .get_identifier("RIGHT_AIL_BLUE_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            left_aileron_green_actuator_solenoid_id: context
-                .get_identifier("LEFT_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            right_aileron_green_actuator_solenoid_id: context
-                .get_identifier("RIGHT_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
-
-            left_aileron_blue_actuator_position_demand_id: context
-                .get_identifier("LEFT_AIL_BLUE_COMMANDED_POSITION".to_owned()),
-            right_aileron_blue_actuator_position_demand_id: context
-                .get_identifier("RIGHT_AIL_BLUE_COMMANDED_POSITION".to_owned()),
-            left_aileron_green_actuator_position_demand_id: context
-                .get_identifier("LEFT_AIL_GREEN_COMMANDED_POSITION".to_owned()),
-            right_aileron_green_actuator_position_demand_id: context
-                .get_identifier("RIGHT_AIL_GREEN_COMMANDED_POSITION".to_owned()),
+            left_aileron_blue_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_AIL_BLUE_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The servo solenoid of the left aileron blue actuator is energized",
+                    )
+                    .written_externally(),
+            ),
+            right_aileron_blue_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "RIGHT_AIL_BLUE_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The servo solenoid of the right aileron blue actuator is energized",
+                    )
+                    .written_externally(),
+            ),
+            left_aileron_green_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The servo solenoid of the left aileron green actuator is energized",
+                    )
+                    .written_externally(),
+            ),
+            right_aileron_green_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "RIGHT_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The servo solenoid of the right aileron green actuator is energized",
+                    )
+                    .written_externally(),
+            ),
+
+            left_aileron_blue_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "LEFT_AIL_BLUE_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Left aileron position commanded to the blue actuator")
+                    .written_externally(),
+            ),
+            right_aileron_blue_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "RIGHT_AIL_BLUE_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Right aileron position commanded to the blue actuator")
+                    .written_externally(),
+            ),
+            left_aileron_green_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "LEFT_AIL_GREEN_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Left aileron position commanded to the green actuator")
+                    .written_externally(),
+            ),
+            right_aileron_green_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "RIGHT_AIL_GREEN_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Right aileron position commanded to the green actuator")
+                    .written_externally(),
+            ),
 
             // Controllers are in outward->inward order, so for aileron [Blue circuit, Green circuit]
             left_aileron_controllers: [AileronController::new(), AileronController::new()],
@@ -4653,23 +4874,72 @@ struct ElevatorSystemHydraulicController {
 impl ElevatorSystemHydraulicController {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            left_elevator_blue_actuator_solenoid_id: context
-                .get_identifier("LEFT_ELEV_BLUE_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            right_elevator_blue_actuator_solenoid_id: context
-                .get_identifier("RIGHT_ELEV_BLUE_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            left_elevator_green_actuator_solenoid_id: context
-                .get_identifier("LEFT_ELEV_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            right_elevator_yellow_actuator_solenoid_id: context
-                .get_identifier("RIGHT_ELEV_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned()),
-
-            left_elevator_blue_actuator_position_demand_id: context
-                .get_identifier("LEFT_ELEV_BLUE_COMMANDED_POSITION".to_owned()),
-            right_elevator_blue_actuator_position_demand_id: context
-                .get_identifier("RIGHT_ELEV_BLUE_COMMANDED_POSITION".to_owned()),
-            left_elevator_green_actuator_position_demand_id: context
-                .get_identifier("LEFT_ELEV_GREEN_COMMANDED_POSITION".to_owned()),
+            left_elevator_blue_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_ELEV_BLUE_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The servo solenoid of the left elevator blue actuator is energized",
+                    )
+                    .written_externally(),
+            ),
+            right_elevator_blue_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "RIGHT_ELEV_BLUE_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The servo solenoid of the right elevator blue actuator is energized",
+                    )
+                    .written_externally(),
+            ),
+            left_elevator_green_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_ELEV_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The servo solenoid of the left elevator green actuator is energized",
+                    )
+                    .written_externally(),
+            ),
+            right_elevator_yellow_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "RIGHT_ELEV_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The servo solenoid of the right elevator yellow actuator is energized",
+                    )
+                    .written_externally(),
+            ),
+
+            left_elevator_blue_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "LEFT_ELEV_BLUE_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Left elevator position commanded to the blue actuator")
+                    .written_externally(),
+            ),
+            right_elevator_blue_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "RIGHT_ELEV_BLUE_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Right elevator position commanded to the blue actuator")
+                    .written_externally(),
+            ),
+            left_elevator_green_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "LEFT_ELEV_GREEN_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Left elevator position commanded to the green actuator")
+                    .written_externally(),
+            ),
             right_elevator_yellow_actuator_position_demand_id: context
-                .get_identifier("RIGHT_ELEV_YELLOW_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_ELEV_YELLOW_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right elevator position commanded to the yellow actuator")
+                        .written_externally(),
+                ),
 
             // Controllers are in outboard->inboard order
             left_controllers: [AileronController::new(), AileronController::new()],
@@ -4812,13 +5082,22 @@ impl A320YawDamperController {
     fn new(context: &mut InitContext, hyd_circuit: HydraulicColor) -> Self {
         Self {
             id_position_request: context
-                .get_identifier(format!("YAW_DAMPER_{}_COMMANDED_POSITION", hyd_circuit))
+                .get_identifier_with_metadata(
+                    format!("YAW_DAMPER_{}_COMMANDED_POSITION", hyd_circuit),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Rudder position commanded to the yaw damper actuator")
+                        .written_externally(),
+                )
                 .to_owned(),
             id_energized: context
-                .get_identifier(format!(
-                    "YAW_DAMPER_{}_SERVO_SOLENOID_ENERGIZED",
-                    hyd_circuit
-                ))
+                .get_identifier_with_metadata(
+                    format!("YAW_DAMPER_{}_SERVO_SOLENOID_ENERGIZED", hyd_circuit),
+                    VariableMetadata::new()
+                        .unit("bool")
+                        .description("The servo solenoid of the yaw damper actuator is energized")
+                        .written_externally(),
+                )
                 .to_owned(),
 
             angle_demand: Angle::default(),
@@ -4875,22 +5154,62 @@ struct A320RudderTrimTravelLimiterController {
 impl A320RudderTrimTravelLimiterController {
     fn new(context: &mut InitContext, component_type: RudderComponent) -> Self {
         Self {
-            id_active_mode_1: context
-                .get_identifier(format!("RUDDER_{}_1_ACTIVE_MODE_COMMANDED", component_type)),
-            id_active_mode_2: context
-                .get_identifier(format!("RUDDER_{}_2_ACTIVE_MODE_COMMANDED", component_type)),
-            id_commanded_position_1: context
-                .get_identifier(format!("RUDDER_{}_1_COMMANDED_POSITION", component_type)),
-            id_commanded_position_2: context
-                .get_identifier(format!("RUDDER_{}_2_COMMANDED_POSITION", component_type)),
+            id_active_mode_1: context.get_identifier_with_metadata(
+                format!("RUDDER_{}_1_ACTIVE_MODE_COMMANDED", component_type),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The rudder trim or travel limiter motor 1 is commanded active")
+                    .written_externally(),
+            ),
+            id_active_mode_2: context.get_identifier_with_metadata(
+                format!("RUDDER_{}_2_ACTIVE_MODE_COMMANDED", component_type),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The rudder trim or travel limiter motor 2 is commanded active")
+                    .written_externally(),
+            ),
+            id_commanded_position_1: context.get_identifier_with_metadata(
+                format!("RUDDER_{}_1_COMMANDED_POSITION", component_type),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Position commanded to the rudder trim or travel limiter motor 1")
+                    .written_externally(),
+            ),
+            id_commanded_position_2: context.get_identifier_with_metadata(
+                format!("RUDDER_{}_2_COMMANDED_POSITION", component_type),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Position commanded to the rudder trim or travel limiter motor 2")
+                    .written_externally(),
+            ),
 
             id_emergency_reset_1: if component_type == RudderComponent::Limiter {
-                Some(context.get_identifier("FAC_1_RTL_EMER_RESET".to_owned()))
+                Some(
+                    context.get_identifier_with_metadata(
+                        "FAC_1_RTL_EMER_RESET".to_owned(),
+                        VariableMetadata::new()
+                            .unit("bool")
+                            .description(
+                                "FAC 1 requests the emergency reset of the rudder travel limiter",
+                            )
+                            .written_externally(),
+                    ),
+                )
             } else {
                 None
             },
             id_emergency_reset_2: if component_type == RudderComponent::Limiter {
-                Some(context.get_identifier("FAC_2_RTL_EMER_RESET".to_owned()))
+                Some(
+                    context.get_identifier_with_metadata(
+                        "FAC_2_RTL_EMER_RESET".to_owned(),
+                        VariableMetadata::new()
+                            .unit("bool")
+                            .description(
+                                "FAC 2 requests the emergency reset of the rudder travel limiter",
+                            )
+                            .written_externally(),
+                    ),
+                )
             } else {
                 None
             },
@@ -4963,8 +5282,13 @@ impl RudderSystemHydraulicController {
 
     fn new(context: &mut InitContext) -> Self {
         Self {
-            rudder_pedal_control_input_id: context
-                .get_identifier("RUDDER_PEDAL_POSITION".to_owned()),
+            rudder_pedal_control_input_id: context.get_identifier_with_metadata(
+                "RUDDER_PEDAL_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Rudder position demanded through the rudder pedals")
+                    .written_externally(),
+            ),
             rudder_pedal_position_id: context
                 .get_identifier("RUDDER_PEDAL_ANIMATION_POSITION".to_owned()),
 
@@ -5526,10 +5850,16 @@ struct SpoilerController {
 impl SpoilerController {
     fn new(context: &mut InitContext, spoiler_side: &str, spoiler_id_number: usize) -> Self {
         Self {
-            position_demand_id: context.get_identifier(format!(
-                "{}_SPOILER_{}_COMMANDED_POSITION",
-                spoiler_side, spoiler_id_number
-            )),
+            position_demand_id: context.get_identifier_with_metadata(
+                format!(
+                    "{}_SPOILER_{}_COMMANDED_POSITION",
+                    spoiler_side, spoiler_id_number
+                ),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Spoiler position commanded to its actuator")
+                    .written_externally(),
+            ),
 
             requested_position: Ratio::new::<ratio>(0.),
         }
@@ -5575,8 +5905,13 @@ struct A320GravityExtension {
 impl A320GravityExtension {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            gear_gravity_extension_handle_position_id: context
-                .get_identifier("GRAVITYGEAR_ROTATE_PCT".to_owned()),
+            gear_gravity_extension_handle_position_id: context.get_identifier_with_metadata(
+                "GRAVITYGEAR_ROTATE_PCT".to_owned(),
+                VariableMetadata::new()
+                    .unit("percent")
+                    .description("Rotation of the landing gear gravity extension handle")
+                    .written_externally(),
+            ),
 
             handle_angle: Angle::default(),
         }
@@ -5618,17 +5953,64 @@ struct A320TrimInputController {
 impl A320TrimInputController {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            motor1_active_id: context.get_identifier("THS_1_ACTIVE_MODE_COMMANDED".to_owned()),
-            motor2_active_id: context.get_identifier("THS_2_ACTIVE_MODE_COMMANDED".to_owned()),
-            motor3_active_id: context.get_identifier("THS_3_ACTIVE_MODE_COMMANDED".to_owned()),
+            motor1_active_id: context.get_identifier_with_metadata(
+                "THS_1_ACTIVE_MODE_COMMANDED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The THS motor 1 is commanded active")
+                    .written_externally(),
+            ),
+            motor2_active_id: context.get_identifier_with_metadata(
+                "THS_2_ACTIVE_MODE_COMMANDED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The THS motor 2 is commanded active")
+                    .written_externally(),
+            ),
+            motor3_active_id: context.get_identifier_with_metadata(
+                "THS_3_ACTIVE_MODE_COMMANDED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The THS motor 3 is commanded active")
+                    .written_externally(),
+            ),
 
-            motor1_position_id: context.get_identifier("THS_1_COMMANDED_POSITION".to_owned()),
-            motor2_position_id: context.get_identifier("THS_2_COMMANDED_POSITION".to_owned()),
-            motor3_position_id: context.get_identifier("THS_3_COMMANDED_POSITION".to_owned()),
+            motor1_position_id: context.get_identifier_with_metadata(
+                "THS_1_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("THS position commanded to motor 1")
+                    .written_externally(),
+            ),
+            motor2_position_id: context.get_identifier_with_metadata(
+                "THS_2_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("THS position commanded to motor 2")
+                    .written_externally(),
+            ),
+            motor3_position_id: context.get_identifier_with_metadata(
+                "THS_3_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("THS position commanded to motor 3")
+                    .written_externally(),
+            ),
 
-            manual_control_active_id: context
-                .get_identifier("THS_MANUAL_CONTROL_ACTIVE".to_owned()),
-            manual_control_speed_id: context.get_identifier("THS_MANUAL_CONTROL_SPEED".to_owned()),
+            manual_control_active_id: context.get_identifier_with_metadata(
+                "THS_MANUAL_CONTROL_ACTIVE".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The pitch trim wheel is moved manually")
+                    .written_externally(),
+            ),
+            manual_control_speed_id: context.get_identifier_with_metadata(
+                "THS_MANUAL_CONTROL_SPEED".to_owned(),
+                VariableMetadata::new()
+                    .unit("rpm")
+                    .description("Speed at which the pitch trim wheel is moved manually")
+                    .written_externally(),
+            ),
 
             motor_active: [false; 3],
             motor_position: [Angle::default(); 3],
@@ -5698,8 +6080,13 @@ struct A320ReverserController {
 impl A320ReverserController {
     fn new(context: &mut InitContext, engine_number: usize) -> Self {
         Self {
-            throttle_lever_angle_id: context
-                .get_identifier(format!("AUTOTHRUST_TLA:{}", engine_number)),
+            throttle_lever_angle_id: context.get_identifier_with_metadata(
+                format!("AUTOTHRUST_TLA:{}", engine_number),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Thrust lever angle")
+                    .written_externally(),
+            ),
 
             throttle_lever_angle: Angle::default(),
             state: ReverserControlState::StowedOff,
@@ -5997,6 +6384,7 @@ mod tests {
                 EmergencyElectricalState, EmergencyGeneratorControlUnit, LgciuId, PotentialOrigin,
             },
             simulation::{
+                frame_rate::{FrameRateHarness, FrameSchedule},
                 test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
                 Aircraft, InitContext,
             },
@@ -6151,6 +6539,7 @@ mod tests {
             adirus: A320TestAdirus,
             electrical: A320TestElectrical,
             ext_pwr: ExternalPowerSource,
+            cargo_doors_opening_inhibited: [bool; 2],
 
             powered_source_ac: TestElectricitySource,
             ac_ground_service_bus: ElectricalBus,
@@ -6196,6 +6585,7 @@ mod tests {
                     adirus: A320TestAdirus::default(),
                     electrical: A320TestElectrical::new(),
                     ext_pwr: ExternalPowerSource::new(context, 1),
+                    cargo_doors_opening_inhibited: [false; 2],
                     powered_source_ac: TestElectricitySource::powered(
                         context,
                         PotentialOrigin::EngineGenerator(1),
@@ -6448,6 +6838,7 @@ mod tests {
                     &self.electrical,
                     &self.pneumatics,
                     &self.adirus,
+                    self.cargo_doors_opening_inhibited,
                 );
 
                 self.overhead.update(&self.hydraulics);
@@ -6642,6 +7033,14 @@ mod tests {
                 self.read_by_name("HYD_BRAKE_NORM_RIGHT_PRESS")
             }
 
+            fn get_left_braking_force(&mut self) -> f64 {
+                self.read_by_name("BRAKE LEFT FORCE FACTOR")
+            }
+
+            fn get_right_braking_force(&mut self) -> f64 {
+                self.read_by_name("BRAKE RIGHT FORCE FACTOR")
+            }
+
             fn get_brake_yellow_accumulator_pressure(&mut self) -> Pressure {
                 self.read_by_name("HYD_BRAKE_ALTN_ACC_PRESS")
             }
@@ -6867,6 +7266,11 @@ mod tests {
                 self
             }
 
+            fn inhibit_cargo_doors_opening(mut self, inhibited: bool) -> Self {
+                self.command(|a| a.cargo_doors_opening_inhibited = [inhibited; 2]);
+                self
+            }
+
             fn open_fwd_cargo_door(mut self) -> Self {
                 self.write_by_name("FWD_DOOR_CARGO_OPEN_REQ", 1.);
                 self
@@ -9057,6 +9461,31 @@ mod tests {
             );
         }
 
+        #[test]
+        fn burst_tyre_reduces_braking_force_on_its_side() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed.fail(FailureType::TyreBurst(1));
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            let left_force = test_bed.get_left_braking_force();
+            let right_force = test_bed.get_right_braking_force();
+
+            assert!(right_force > 0.5);
+            assert!((left_force - right_force / 2.).abs() < 0.01);
+        }
+
         #[test]
         fn brakes_inactive_in_flight() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10127,7 +10556,7 @@ mod tests {
 
             test_bed = test_bed
                 .set_flaps_handle_position(4)
-                .run_waiting_for(Duration::from_secs(32));
+                .run_waiting_for(Duration::from_secs(33));
 
             assert!(test_bed.get_flaps_left_position_percent() > 99.);
             assert!(test_bed.get_flaps_right_position_percent() > 99.);
@@ -10290,6 +10719,30 @@ mod tests {
             assert!(test_bed.cargo_fwd_door_position() >= 0.);
         }
 
+        #[test]
+        fn cargo_door_stays_locked_while_opening_is_inhibited() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .inhibit_cargo_doors_opening(true)
+                .run_one_tick();
+
+            test_bed = test_bed.open_fwd_cargo_door().run_waiting_for(
+                HydraulicDoorController::DELAY_UNLOCK_TO_HYDRAULIC_CONTROL + Duration::from_secs(5),
+            );
+
+            assert!(test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.cargo_fwd_door_position() == 0.);
+
+            test_bed = test_bed
+                .inhibit_cargo_doors_opening(false)
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            assert!(!test_bed.is_cargo_fwd_door_locked_down());
+        }
+
         #[test]
         fn cargo_door_controller_opens_the_door() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10450,6 +10903,27 @@ mod tests {
             assert!(test_bed.nose_steering_position().get::<degree>() >= -74.1);
         }
 
+        #[test]
+        fn nose_steering_authority_reduced_with_burst_nose_tyre() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            test_bed.fail(FailureType::TyreBurst(5));
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() >= 36.9);
+            assert!(test_bed.nose_steering_position().get::<degree>() <= 37.1);
+        }
+
         #[test]
         fn nose_steering_does_not_move_if_yellow_pressure_but_no_engine() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11757,5 +12231,69 @@ mod tests {
             assert!(test_bed.get_reverser_1_position().get::<ratio>() < 0.01);
             assert!(test_bed.get_reverser_2_position().get::<ratio>() < 0.01);
         }
+
+        #[test]
+        fn hydraulics_behave_the_same_at_any_frame_rate() {
+            // Pressures are averaged as the phase of PTU cycling differs between frame rates.
+            FrameRateHarness::new(Duration::from_secs(60), || {
+                test_bed_on_ground_with()
+                    .engines_off()
+                    .on_the_ground()
+                    .set_cold_dark_inputs()
+            })
+            .at(Duration::from_secs(1), |test_bed| {
+                test_bed
+                    .start_eng1(Ratio::new::<percent>(80.))
+                    .start_eng2(Ratio::new::<percent>(80.))
+            })
+            .at(Duration::from_secs(10), |test_bed| {
+                test_bed.set_flaps_handle_position(4)
+            })
+            .at(Duration::from_secs(30), |test_bed| {
+                test_bed.set_yellow_e_pump(false)
+            })
+            .at(Duration::from_secs(40), |test_bed| {
+                test_bed.stop_eng1().stop_eng2()
+            })
+            .probe_averaged(
+                "HYD",
+                "green pressure",
+                50.,
+                Duration::from_secs(5),
+                |test_bed| test_bed.green_pressure().get::<psi>(),
+            )
+            .probe_averaged(
+                "HYD",
+                "blue pressure",
+                50.,
+                Duration::from_secs(5),
+                |test_bed| test_bed.blue_pressure().get::<psi>(),
+            )
+            .probe_averaged(
+                "HYD",
+                "yellow pressure",
+                50.,
+                Duration::from_secs(5),
+                |test_bed| test_bed.yellow_pressure().get::<psi>(),
+            )
+            .probe("HYD", "green reservoir", 0.15, |test_bed| {
+                test_bed.get_green_reservoir_volume().get::<liter>()
+            })
+            .probe("BRAKES", "accumulator pressure", 50., |test_bed| {
+                test_bed
+                    .get_brake_yellow_accumulator_pressure()
+                    .get::<psi>()
+            })
+            .probe("FLAPS", "flaps position", 1., |test_bed| {
+                test_bed.get_flaps_left_position_percent()
+            })
+            .probe("FLAPS", "slats position", 1., |test_bed| {
+                test_bed.get_slats_left_position_percent()
+            })
+            .assert_frame_rate_independent(
+                &FrameSchedule::fixed_rate(60),
+                &FrameSchedule::typical(),
+            );
+        }
     }
 }
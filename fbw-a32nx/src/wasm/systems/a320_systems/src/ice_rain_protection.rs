@@ -0,0 +1,136 @@
+use systems::{
+    accept_iterable,
+    engine::Engine,
+    ice_rain_protection::{ProbeHeatComputer, WindowHeatComputer},
+    shared::{ElectricalBusType, HeatedProbe, LgciuWeightOnWheels},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        VariableIdentifier, VariableMetadata,
+    },
+};
+
+/// The probe heat computers of the captain, first officer and standby probes, and the
+/// window heat computers of the captain and first officer sides.
+pub struct A320ProbeWindowHeat {
+    probe_window_heat_selected_on_id: VariableIdentifier,
+    probe_window_heat_selected_on: bool,
+
+    probe_heat_computers: [ProbeHeatComputer; 3],
+    window_heat_computers: [WindowHeatComputer; 2],
+}
+impl A320ProbeWindowHeat {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            probe_window_heat_selected_on_id: context.get_identifier_with_metadata(
+                "MAN_PITOT_HEAT".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The probe and window heat pushbutton is on")
+                    .written_externally(),
+            ),
+            probe_window_heat_selected_on: false,
+
+            probe_heat_computers: [
+                ProbeHeatComputer::new(
+                    context,
+                    1,
+                    ElectricalBusType::DirectCurrentEssential,
+                    &[
+                        (
+                            HeatedProbe::Pitot,
+                            ElectricalBusType::AlternatingCurrentEssentialShed,
+                        ),
+                        (
+                            HeatedProbe::StaticPorts,
+                            ElectricalBusType::AlternatingCurrentEssentialShed,
+                        ),
+                        (
+                            HeatedProbe::AngleOfAttack,
+                            ElectricalBusType::AlternatingCurrentEssentialShed,
+                        ),
+                        (
+                            HeatedProbe::TotalAirTemperature,
+                            ElectricalBusType::AlternatingCurrent(1),
+                        ),
+                    ],
+                ),
+                ProbeHeatComputer::new(
+                    context,
+                    2,
+                    ElectricalBusType::DirectCurrent(2),
+                    &[
+                        (HeatedProbe::Pitot, ElectricalBusType::AlternatingCurrent(2)),
+                        (
+                            HeatedProbe::StaticPorts,
+                            ElectricalBusType::AlternatingCurrent(2),
+                        ),
+                        (
+                            HeatedProbe::AngleOfAttack,
+                            ElectricalBusType::AlternatingCurrent(2),
+                        ),
+                        (
+                            HeatedProbe::TotalAirTemperature,
+                            ElectricalBusType::AlternatingCurrent(2),
+                        ),
+                    ],
+                ),
+                // The standby probes don't include a TAT probe.
+                ProbeHeatComputer::new(
+                    context,
+                    3,
+                    ElectricalBusType::DirectCurrent(1),
+                    &[
+                        (HeatedProbe::Pitot, ElectricalBusType::AlternatingCurrent(1)),
+                        (
+                            HeatedProbe::StaticPorts,
+                            ElectricalBusType::AlternatingCurrent(1),
+                        ),
+                        (
+                            HeatedProbe::AngleOfAttack,
+                            ElectricalBusType::AlternatingCurrent(1),
+                        ),
+                    ],
+                ),
+            ],
+            window_heat_computers: [1, 2].map(|number| {
+                WindowHeatComputer::new(
+                    context,
+                    number,
+                    ElectricalBusType::DirectCurrent(number as u8),
+                    ElectricalBusType::AlternatingCurrent(number as u8),
+                    ElectricalBusType::AlternatingCurrent(number as u8),
+                )
+            }),
+        }
+    }
+
+    pub fn update(&mut self, engines: [&impl Engine; 2], lgciu: &impl LgciuWeightOnWheels) {
+        for computer in self.probe_heat_computers.iter_mut() {
+            computer.update(engines, lgciu, self.probe_window_heat_selected_on);
+        }
+
+        for computer in self.window_heat_computers.iter_mut() {
+            computer.update(engines, lgciu, self.probe_window_heat_selected_on);
+        }
+    }
+
+    pub fn probe_heat_computer(&self, number: usize) -> &ProbeHeatComputer {
+        &self.probe_heat_computers[number - 1]
+    }
+
+    pub fn window_heat_computer(&self, number: usize) -> &WindowHeatComputer {
+        &self.window_heat_computers[number - 1]
+    }
+}
+impl SimulationElement for A320ProbeWindowHeat {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.probe_heat_computers, visitor);
+        accept_iterable!(self.window_heat_computers, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.probe_window_heat_selected_on = reader.read(&self.probe_window_heat_selected_on_id);
+    }
+}
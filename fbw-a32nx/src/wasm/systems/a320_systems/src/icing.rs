@@ -0,0 +1,392 @@
+use systems::{
+    accept_iterable,
+    icing_state::{ActiveDeicingController, IcingState},
+    shared::{AirDataProbeIcing, HeatedProbe, HeatedProbes, HeatedWindow, HeatedWindows},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+use std::time::Duration;
+use uom::si::{f64::*, ratio::ratio};
+
+/// Heat applied to a probe or window, seen as a deicing rate.
+struct Heating(Ratio);
+impl ActiveDeicingController for Heating {
+    fn active_deicing_normalized_rate(&self) -> Ratio {
+        self.0
+    }
+}
+
+/// Ice accretion on the parts of the airframe which matter to the flight model and
+/// performance computations. Each zone writes its own `ICING_STATE_*` variable.
+pub struct A320Icing {
+    wing_leading_edges: [IcingState; 2],
+    engine_nacelles: [IcingState; 2],
+    pitot_probes: [IcingState; 3],
+    angle_of_attack_sensors: [IcingState; 3],
+    total_air_temperature_probes: [IcingState; 2],
+    windshields: [IcingState; 2],
+}
+impl A320Icing {
+    // Above this amount of ice, a probe no longer senses the airflow.
+    const PROBE_OBSTRUCTING_ICING_STATE: f64 = 0.5;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            wing_leading_edges: [1, 2].map(|number| {
+                IcingState::new(
+                    context,
+                    &format!("WING_LEADING_EDGE_{}", number),
+                    Duration::from_secs(300),
+                    Duration::from_secs(600),
+                    Some(Duration::from_secs(60)),
+                )
+            }),
+            engine_nacelles: [1, 2].map(|number| {
+                IcingState::new(
+                    context,
+                    &format!("ENG_{}_NACELLE", number),
+                    Duration::from_secs(240),
+                    Duration::from_secs(600),
+                    Some(Duration::from_secs(30)),
+                )
+            }),
+            // Probes are small and accrete ice quickly.
+            pitot_probes: [1, 2, 3].map(|number| {
+                IcingState::new(
+                    context,
+                    &format!("PITOT_{}", number),
+                    Duration::from_secs(120),
+                    Duration::from_secs(300),
+                    Some(Duration::from_secs(20)),
+                )
+            }),
+            angle_of_attack_sensors: [1, 2, 3].map(|number| {
+                IcingState::new(
+                    context,
+                    &format!("AOA_{}", number),
+                    Duration::from_secs(180),
+                    Duration::from_secs(300),
+                    Some(Duration::from_secs(20)),
+                )
+            }),
+            total_air_temperature_probes: [1, 2].map(|number| {
+                IcingState::new(
+                    context,
+                    &format!("TAT_{}", number),
+                    Duration::from_secs(120),
+                    Duration::from_secs(300),
+                    Some(Duration::from_secs(20)),
+                )
+            }),
+            windshields: [1, 2].map(|number| {
+                IcingState::new(
+                    context,
+                    &format!("WINDSHIELD_{}", number),
+                    Duration::from_secs(300),
+                    Duration::from_secs(600),
+                    Some(Duration::from_secs(60)),
+                )
+            }),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        wing_anti_ice: [&impl ActiveDeicingController; 2],
+        engine_anti_ice: [&impl ActiveDeicingController; 2],
+        probe_heat: [&impl HeatedProbes; 3],
+        window_heat: [&impl HeatedWindows; 2],
+    ) {
+        for (wing_leading_edge, deicing) in self.wing_leading_edges.iter_mut().zip(wing_anti_ice) {
+            wing_leading_edge.update(context, Some(deicing));
+        }
+
+        for (nacelle, deicing) in self.engine_nacelles.iter_mut().zip(engine_anti_ice) {
+            nacelle.update(context, Some(deicing));
+        }
+
+        for (pitot, heat) in self.pitot_probes.iter_mut().zip(probe_heat) {
+            pitot.update(
+                context,
+                Some(&Heating(heat.probe_heating(HeatedProbe::Pitot))),
+            );
+        }
+
+        for (sensor, heat) in self.angle_of_attack_sensors.iter_mut().zip(probe_heat) {
+            sensor.update(
+                context,
+                Some(&Heating(heat.probe_heating(HeatedProbe::AngleOfAttack))),
+            );
+        }
+
+        for (probe, heat) in self.total_air_temperature_probes.iter_mut().zip(probe_heat) {
+            probe.update(
+                context,
+                Some(&Heating(
+                    heat.probe_heating(HeatedProbe::TotalAirTemperature),
+                )),
+            );
+        }
+
+        for (windshield, heat) in self.windshields.iter_mut().zip(window_heat) {
+            windshield.update(
+                context,
+                Some(&Heating(heat.window_heating(HeatedWindow::Windshield))),
+            );
+        }
+    }
+
+    fn obstructs(icing_state: &IcingState) -> bool {
+        icing_state.icing_state_normalized().get::<ratio>() > Self::PROBE_OBSTRUCTING_ICING_STATE
+    }
+}
+impl AirDataProbeIcing for A320Icing {
+    fn pitot_is_iced(&self, adr_number: usize) -> bool {
+        Self::obstructs(&self.pitot_probes[adr_number - 1])
+    }
+
+    fn angle_of_attack_sensor_is_iced(&self, adr_number: usize) -> bool {
+        Self::obstructs(&self.angle_of_attack_sensors[adr_number - 1])
+    }
+
+    fn total_air_temperature_probe_is_iced(&self, adr_number: usize) -> bool {
+        // ADR 3 shares the captain's TAT probe.
+        let probe_number = if adr_number == 3 { 1 } else { adr_number };
+        Self::obstructs(&self.total_air_temperature_probes[probe_number - 1])
+    }
+}
+impl SimulationElement for A320Icing {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.wing_leading_edges, visitor);
+        accept_iterable!(self.engine_nacelles, visitor);
+        accept_iterable!(self.pitot_probes, visitor);
+        accept_iterable!(self.angle_of_attack_sensors, visitor);
+        accept_iterable!(self.total_air_temperature_probes, visitor);
+        accept_iterable!(self.windshields, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+
+    struct TestAntiIce {
+        rate: Ratio,
+    }
+    impl ActiveDeicingController for TestAntiIce {
+        fn active_deicing_normalized_rate(&self) -> Ratio {
+            self.rate
+        }
+    }
+
+    struct TestHeat {
+        heating: Ratio,
+    }
+    impl HeatedProbes for TestHeat {
+        fn probe_heating(&self, _: HeatedProbe) -> Ratio {
+            self.heating
+        }
+    }
+    impl HeatedWindows for TestHeat {
+        fn window_heating(&self, _: HeatedWindow) -> Ratio {
+            self.heating
+        }
+    }
+
+    struct TestAircraft {
+        icing: A320Icing,
+        wing_anti_ice: [TestAntiIce; 2],
+        engine_anti_ice: [TestAntiIce; 2],
+        probe_heat: [TestHeat; 3],
+        window_heat: [TestHeat; 2],
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                icing: A320Icing::new(context),
+                wing_anti_ice: [0, 1].map(|_| TestAntiIce {
+                    rate: Ratio::new::<ratio>(0.),
+                }),
+                engine_anti_ice: [0, 1].map(|_| TestAntiIce {
+                    rate: Ratio::new::<ratio>(0.),
+                }),
+                probe_heat: [0, 1, 2].map(|_| TestHeat {
+                    heating: Ratio::new::<ratio>(0.),
+                }),
+                window_heat: [0, 1].map(|_| TestHeat {
+                    heating: Ratio::new::<ratio>(0.),
+                }),
+            }
+        }
+
+        fn probe_heat_on(&mut self, number: usize) {
+            self.probe_heat[number - 1].heating = Ratio::new::<ratio>(1.);
+        }
+
+        fn wing_anti_ice_on(&mut self) {
+            self.wing_anti_ice
+                .iter_mut()
+                .for_each(|a| a.rate = Ratio::new::<ratio>(1.));
+        }
+
+        fn engine_anti_ice_on(&mut self, number: usize) {
+            self.engine_anti_ice[number - 1].rate = Ratio::new::<ratio>(1.);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.icing.update(
+                context,
+                [&self.wing_anti_ice[0], &self.wing_anti_ice[1]],
+                [&self.engine_anti_ice[0], &self.engine_anti_ice[1]],
+                [
+                    &self.probe_heat[0],
+                    &self.probe_heat[1],
+                    &self.probe_heat[2],
+                ],
+                [&self.window_heat[0], &self.window_heat[1]],
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.icing.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_in_icing_conditions() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("AMBIENT TEMPERATURE", -12.);
+        test_bed.write_by_name("AMBIENT IN CLOUD", 1.);
+
+        test_bed
+    }
+
+    fn run_for_minutes(test_bed: &mut SimulationTestBed<TestAircraft>, minutes: u64) {
+        for _ in 0..minutes * 60 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn writes_icing_state_of_all_zones() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run();
+
+        for name in [
+            "ICING_STATE_WING_LEADING_EDGE_1",
+            "ICING_STATE_WING_LEADING_EDGE_2",
+            "ICING_STATE_ENG_1_NACELLE",
+            "ICING_STATE_ENG_2_NACELLE",
+            "ICING_STATE_PITOT_1",
+            "ICING_STATE_PITOT_2",
+            "ICING_STATE_PITOT_3",
+            "ICING_STATE_AOA_1",
+            "ICING_STATE_AOA_2",
+            "ICING_STATE_AOA_3",
+            "ICING_STATE_TAT_1",
+            "ICING_STATE_TAT_2",
+            "ICING_STATE_WINDSHIELD_1",
+            "ICING_STATE_WINDSHIELD_2",
+        ] {
+            assert!(test_bed.contains_variable_with_name(name));
+        }
+    }
+
+    #[test]
+    fn all_zones_accrete_ice_without_anti_ice() {
+        let mut test_bed = test_bed_in_icing_conditions();
+        run_for_minutes(&mut test_bed, 6);
+
+        for zone in [
+            "WING_LEADING_EDGE_1",
+            "WING_LEADING_EDGE_2",
+            "ENG_1_NACELLE",
+            "ENG_2_NACELLE",
+            "PITOT_1",
+            "AOA_2",
+            "TAT_1",
+            "WINDSHIELD_2",
+        ] {
+            let icing_state: f64 = test_bed.read_by_name(&format!("ICING_STATE_{}", zone));
+            assert!(icing_state > 0.95);
+        }
+    }
+
+    #[test]
+    fn wing_anti_ice_keeps_the_wing_clean() {
+        let mut test_bed = test_bed_in_icing_conditions();
+        test_bed.command(|a| a.wing_anti_ice_on());
+        run_for_minutes(&mut test_bed, 6);
+
+        let icing_state: f64 = test_bed.read_by_name("ICING_STATE_WING_LEADING_EDGE_1");
+        assert!(icing_state < 0.05);
+        let icing_state: f64 = test_bed.read_by_name("ICING_STATE_ENG_1_NACELLE");
+        assert!(icing_state > 0.95);
+    }
+
+    #[test]
+    fn engine_anti_ice_only_keeps_its_own_nacelle_clean() {
+        let mut test_bed = test_bed_in_icing_conditions();
+        test_bed.command(|a| a.engine_anti_ice_on(1));
+        run_for_minutes(&mut test_bed, 6);
+
+        let icing_state: f64 = test_bed.read_by_name("ICING_STATE_ENG_1_NACELLE");
+        assert!(icing_state < 0.05);
+        let icing_state: f64 = test_bed.read_by_name("ICING_STATE_ENG_2_NACELLE");
+        assert!(icing_state > 0.95);
+    }
+
+    #[test]
+    fn switching_on_wing_anti_ice_removes_accreted_ice() {
+        let mut test_bed = test_bed_in_icing_conditions();
+        run_for_minutes(&mut test_bed, 6);
+
+        test_bed.command(|a| a.wing_anti_ice_on());
+        run_for_minutes(&mut test_bed, 2);
+
+        let icing_state: f64 = test_bed.read_by_name("ICING_STATE_WING_LEADING_EDGE_2");
+        assert!(icing_state < 0.05);
+    }
+
+    #[test]
+    fn unheated_probes_become_obstructed_by_ice() {
+        let mut test_bed = test_bed_in_icing_conditions();
+        run_for_minutes(&mut test_bed, 3);
+
+        assert!(test_bed.query(|a| a.icing.pitot_is_iced(1)));
+        assert!(test_bed.query(|a| a.icing.angle_of_attack_sensor_is_iced(2)));
+        assert!(test_bed.query(|a| a.icing.total_air_temperature_probe_is_iced(3)));
+    }
+
+    #[test]
+    fn heated_probes_stay_clear_of_ice() {
+        let mut test_bed = test_bed_in_icing_conditions();
+        test_bed.command(|a| a.probe_heat_on(1));
+        run_for_minutes(&mut test_bed, 6);
+
+        assert!(!test_bed.query(|a| a.icing.pitot_is_iced(1)));
+        assert!(!test_bed.query(|a| a.icing.angle_of_attack_sensor_is_iced(1)));
+        assert!(test_bed.query(|a| a.icing.pitot_is_iced(2)));
+    }
+
+    #[test]
+    fn adr_3_uses_the_captain_tat_probe() {
+        let mut test_bed = test_bed_in_icing_conditions();
+        test_bed.command(|a| a.probe_heat_on(1));
+        test_bed.command(|a| a.probe_heat_on(3));
+        run_for_minutes(&mut test_bed, 6);
+
+        assert!(!test_bed.query(|a| a.icing.total_air_temperature_probe_is_iced(3)));
+        assert!(test_bed.query(|a| a.icing.total_air_temperature_probe_is_iced(2)));
+    }
+}
@@ -1,17 +1,25 @@
 extern crate systems;
 
 mod air_conditioning;
+mod doors;
 mod electrical;
+pub mod failures;
 mod fuel;
 pub mod hydraulic;
+mod ice_rain_protection;
+mod icing;
 mod navigation;
 mod payload;
 mod pneumatic;
 mod power_consumption;
+mod weight_and_balance;
 
 use self::{
     air_conditioning::{A320AirConditioning, A320PressurizationOverheadPanel},
+    doors::A320Doors,
     fuel::A320Fuel,
+    ice_rain_protection::A320ProbeWindowHeat,
+    icing::A320Icing,
     payload::A320Payload,
     pneumatic::{A320Pneumatic, A320PneumaticOverheadPanel},
 };
@@ -20,11 +28,12 @@ use electrical::{
     APU_START_MOTOR_BUS_TYPE,
 };
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
-use navigation::A320RadioAltimeters;
+use navigation::{A320GpsReceivers, A320RadioAltimeters};
 use power_consumption::A320PowerConsumption;
 use systems::enhanced_gpwc::EnhancedGroundProximityWarningComputer;
 use systems::simulation::InitContext;
 use uom::si::{f64::Length, length::nautical_mile};
+use weight_and_balance::A320WeightAndBalance;
 
 use systems::{
     apu::{
@@ -39,13 +48,17 @@ use systems::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
     },
     shared::ElectricalBusType,
-    simulation::{Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext},
+    simulation::{
+        profiling::measure, Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext,
+    },
+    weight_and_balance::LoadSheet,
 };
 
 pub struct A320 {
     adirs: AirDataInertialReferenceSystem,
     adirs_overhead: AirDataInertialReferenceSystemOverheadPanel,
     air_conditioning: A320AirConditioning,
+    doors: A320Doors,
     apu: AuxiliaryPowerUnit<Aps3200ApuGenerator, Aps3200StartMotor, 1>,
     apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
     apu_overhead: AuxiliaryPowerUnitOverheadPanel,
@@ -55,6 +68,7 @@ pub struct A320 {
     emergency_electrical_overhead: A320EmergencyElectricalOverheadPanel,
     payload: A320Payload,
     fuel: A320Fuel,
+    weight_and_balance: A320WeightAndBalance,
     engine_1: LeapEngine,
     engine_2: LeapEngine,
     engine_fire_overhead: EngineFireOverheadPanel<2>,
@@ -64,10 +78,13 @@ pub struct A320 {
     lgcius: LandingGearControlInterfaceUnitSet,
     hydraulic: A320Hydraulic,
     hydraulic_overhead: A320HydraulicOverheadPanel,
+    icing: A320Icing,
+    probe_window_heat: A320ProbeWindowHeat,
     autobrake_panel: AutobrakePanel,
     landing_gear: LandingGear,
     pneumatic: A320Pneumatic,
     radio_altimeters: A320RadioAltimeters,
+    gps_receivers: A320GpsReceivers,
     egpwc: EnhancedGroundProximityWarningComputer,
     reverse_thrust: ReverserForce,
 }
@@ -77,6 +94,7 @@ impl A320 {
             adirs: AirDataInertialReferenceSystem::new(context),
             adirs_overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
             air_conditioning: A320AirConditioning::new(context),
+            doors: A320Doors::new(context),
             apu: AuxiliaryPowerUnitFactory::new_aps3200(
                 context,
                 1,
@@ -92,6 +110,7 @@ impl A320 {
             emergency_electrical_overhead: A320EmergencyElectricalOverheadPanel::new(context),
             payload: A320Payload::new(context),
             fuel: A320Fuel::new(context),
+            weight_and_balance: A320WeightAndBalance::new(context),
             engine_1: LeapEngine::new(context, 1),
             engine_2: LeapEngine::new(context, 2),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
@@ -105,10 +124,13 @@ impl A320 {
             ),
             hydraulic: A320Hydraulic::new(context),
             hydraulic_overhead: A320HydraulicOverheadPanel::new(context),
+            icing: A320Icing::new(context),
+            probe_window_heat: A320ProbeWindowHeat::new(context),
             autobrake_panel: AutobrakePanel::new(context),
             landing_gear: LandingGear::new(context),
             pneumatic: A320Pneumatic::new(context),
             radio_altimeters: A320RadioAltimeters::new(context),
+            gps_receivers: A320GpsReceivers::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
                 ElectricalBusType::DirectCurrent(1),
@@ -125,6 +147,12 @@ impl A320 {
             reverse_thrust: ReverserForce::new(context),
         }
     }
+
+    /// The load sheet of the current weight and balance. The EFB reads it through the
+    /// `WB_*` variables, as the simulation can only write numeric variables.
+    pub fn load_sheet(&self) -> &LoadSheet {
+        self.weight_and_balance.load_sheet()
+    }
 }
 impl Aircraft for A320 {
     fn update_before_power_distribution(
@@ -132,40 +160,46 @@ impl Aircraft for A320 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
-        self.apu.update_before_electrical(
-            context,
-            &self.apu_overhead,
-            &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
-            // This will be replaced when integrating the whole electrical system.
-            // For now we use the same logic as found in the JavaScript code; ignoring whether or not
-            // the engine generators are supplying electricity.
-            self.electrical_overhead.apu_generator_is_on()
-                && !(self.electrical_overhead.external_power_is_on()
-                    && self.electrical_overhead.external_power_is_available()),
-            self.pneumatic.apu_bleed_air_valve(),
-            self.fuel.left_inner_tank_has_fuel_remaining(),
-        );
+        measure("apu", || {
+            self.apu.update_before_electrical(
+                context,
+                &self.apu_overhead,
+                &self.apu_fire_overhead,
+                self.pneumatic_overhead.apu_bleed_is_on(),
+                // This will be replaced when integrating the whole electrical system.
+                // For now we use the same logic as found in the JavaScript code; ignoring whether or not
+                // the engine generators are supplying electricity.
+                self.electrical_overhead.apu_generator_is_on()
+                    && !(self.electrical_overhead.external_power_is_on()
+                        && self.electrical_overhead.external_power_is_available()),
+                self.pneumatic.apu_bleed_air_valve(),
+                self.fuel.left_inner_tank_has_fuel_remaining(),
+            )
+        });
+        measure("fuel", || self.fuel.update(context, self.apu.fuel_flow()));
 
-        self.electrical.update(
-            context,
-            electricity,
-            &self.ext_pwr,
-            &self.electrical_overhead,
-            &self.emergency_electrical_overhead,
-            &mut self.apu,
-            &self.apu_overhead,
-            &self.engine_fire_overhead,
-            [&self.engine_1, &self.engine_2],
-            &self.hydraulic,
-            self.lgcius.lgciu1(),
-        );
+        measure("electrical", || {
+            self.electrical.update(
+                context,
+                electricity,
+                &self.ext_pwr,
+                &self.electrical_overhead,
+                &self.emergency_electrical_overhead,
+                &mut self.apu,
+                &self.apu_overhead,
+                &self.engine_fire_overhead,
+                [&self.engine_1, &self.engine_2],
+                &self.hydraulic,
+                self.lgcius.lgciu1(),
+            )
+        });
 
         self.electrical_overhead
             .update_after_electrical(&self.electrical, electricity);
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
         self.payload.update(context);
+        self.weight_and_balance.update(&self.payload, &self.fuel);
     }
 
     fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -180,20 +214,27 @@ impl Aircraft for A320 {
         );
 
         self.radio_altimeters.update(context);
+        self.gps_receivers.update(context);
 
-        self.hydraulic.update(
-            context,
-            &self.engine_1,
-            &self.engine_2,
-            &self.hydraulic_overhead,
-            &self.autobrake_panel,
-            &self.engine_fire_overhead,
-            &self.lgcius,
-            &self.emergency_electrical_overhead,
-            &self.electrical,
-            &self.pneumatic,
-            &self.adirs,
-        );
+        self.probe_window_heat
+            .update([&self.engine_1, &self.engine_2], self.lgcius.lgciu1());
+
+        measure("hydraulic", || {
+            self.hydraulic.update(
+                context,
+                &self.engine_1,
+                &self.engine_2,
+                &self.hydraulic_overhead,
+                &self.autobrake_panel,
+                &self.engine_fire_overhead,
+                &self.lgcius,
+                &self.emergency_electrical_overhead,
+                &self.electrical,
+                &self.pneumatic,
+                &self.adirs,
+                self.doors.cargo_doors_opening_inhibited(),
+            )
+        });
 
         self.reverse_thrust.update(
             context,
@@ -209,35 +250,88 @@ impl Aircraft for A320 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
-        self.adirs_overhead.update(context, &self.adirs);
+        measure("adirs", || {
+            self.adirs.update_probe_icing(&self.icing);
+            self.adirs.update_gps_data([
+                self.gps_receivers.receiver(1),
+                self.gps_receivers.receiver(2),
+            ]);
+            self.adirs.update(context, &self.adirs_overhead);
+            self.adirs_overhead.update(context, &self.adirs);
+        });
 
-        self.power_consumption.update(context);
+        measure("power_consumption", || {
+            self.power_consumption.update(context)
+        });
 
-        self.pneumatic.update(
-            context,
-            [&self.engine_1, &self.engine_2],
-            &self.pneumatic_overhead,
-            &self.engine_fire_overhead,
-            &self.apu,
+        measure("pneumatic", || {
+            self.pneumatic.update(
+                context,
+                [&self.engine_1, &self.engine_2],
+                &self.pneumatic_overhead,
+                &self.engine_fire_overhead,
+                &self.apu,
+                &self.air_conditioning,
+                [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            )
+        });
+        measure("icing", || {
+            self.icing.update(
+                context,
+                [
+                    self.pneumatic.wing_anti_ice(1),
+                    self.pneumatic.wing_anti_ice(2),
+                ],
+                [
+                    self.pneumatic.engine_anti_ice(1),
+                    self.pneumatic.engine_anti_ice(2),
+                ],
+                [
+                    self.probe_window_heat.probe_heat_computer(1),
+                    self.probe_window_heat.probe_heat_computer(2),
+                    self.probe_window_heat.probe_heat_computer(3),
+                ],
+                [
+                    self.probe_window_heat.window_heat_computer(1),
+                    self.probe_window_heat.window_heat_computer(2),
+                ],
+            )
+        });
+        let cargo_doors = self.hydraulic.cargo_doors();
+        self.doors.update(
             &self.air_conditioning,
-            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
-        );
-        self.air_conditioning
-            .mix_packs_air_update(self.pneumatic.packs());
-        self.air_conditioning.update(
-            context,
-            &self.adirs,
             [&self.engine_1, &self.engine_2],
-            &self.engine_fire_overhead,
-            &self.payload,
-            &self.pneumatic,
-            &self.pneumatic_overhead,
-            &self.pressurization_overhead,
-            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            [cargo_doors[0].position(), cargo_doors[1].position()],
         );
+        measure("air_conditioning", || {
+            self.air_conditioning
+                .mix_packs_air_update(self.pneumatic.packs());
+            self.air_conditioning.update(
+                context,
+                &self.adirs,
+                [&self.engine_1, &self.engine_2],
+                &self.engine_fire_overhead,
+                &self.payload,
+                &self.doors.open_ratios(),
+                &self.pneumatic,
+                &self.pneumatic_overhead,
+                &self.pressurization_overhead,
+                [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            );
+        });
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        measure("egpwc", || {
+            self.egpwc.update(
+                context,
+                &self.adirs,
+                [
+                    self.radio_altimeters.radio_altimeter(1),
+                    self.radio_altimeters.radio_altimeter(2),
+                ],
+                self.gps_receivers.receiver(1),
+                self.lgcius.lgciu1(),
+            )
+        });
     }
 }
 impl SimulationElement for A320 {
@@ -245,6 +339,7 @@ impl SimulationElement for A320 {
         self.adirs.accept(visitor);
         self.adirs_overhead.accept(visitor);
         self.air_conditioning.accept(visitor);
+        self.doors.accept(visitor);
         self.apu.accept(visitor);
         self.apu_fire_overhead.accept(visitor);
         self.apu_overhead.accept(visitor);
@@ -252,6 +347,7 @@ impl SimulationElement for A320 {
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fuel.accept(visitor);
+        self.weight_and_balance.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
         self.pressurization_overhead.accept(visitor);
         self.engine_1.accept(visitor);
@@ -262,9 +358,12 @@ impl SimulationElement for A320 {
         self.ext_pwr.accept(visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
+        self.gps_receivers.accept(visitor);
         self.autobrake_panel.accept(visitor);
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
+        self.icing.accept(visitor);
+        self.probe_window_heat.accept(visitor);
         self.landing_gear.accept(visitor);
         self.pneumatic.accept(visitor);
         self.egpwc.accept(visitor);
@@ -273,3 +372,22 @@ impl SimulationElement for A320 {
         visitor.visit(self);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::simulation::{StartState, VariableCatalogue};
+
+    #[test]
+    fn variables_read_are_written_by_the_simulation_or_externally() {
+        let catalogue = VariableCatalogue::of(StartState::Cruise, A320::new);
+
+        assert_eq!(
+            catalogue
+                .read_but_never_written()
+                .map(|entry| entry.name())
+                .collect::<Vec<_>>(),
+            Vec::<&str>::new()
+        );
+    }
+}
@@ -3,7 +3,12 @@ pub const MINUTES_TO_SECONDS: u64 = 60;
 
 use rand::seq::IteratorRandom;
 use rand::SeedableRng;
-use systems::electrical::Electricity;
+use std::time::Duration;
+use systems::{
+    electrical::Electricity,
+    payload::{BoardingRate, GsxState},
+    simulation::UpdateContext,
+};
 use uom::si::mass::pound;
 
 use super::*;
@@ -67,7 +72,7 @@ impl BoardingTestBed {
 
     fn init_vars(mut self) -> Self {
         self.write_by_name("BOARDING_RATE", BoardingRate::Instant);
-        self.write_by_name("WB_PER_PAX_WEIGHT", A320Payload::DEFAULT_PER_PAX_WEIGHT_KG);
+        self.write_by_name("WB_PER_PAX_WEIGHT", Payload::DEFAULT_PER_PAX_WEIGHT_KG);
 
         self
     }
@@ -130,7 +135,7 @@ impl BoardingTestBed {
     fn board_gsx_pax_half(mut self) -> Self {
         let mut max_pax = 0;
         for ps in A320Pax::iterator() {
-            max_pax += A320_PAX[ps].max_pax as i32;
+            max_pax += A320_PAX[ps].max_pax;
         }
         self.write_by_name("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL", max_pax / 2);
         self
@@ -139,7 +144,7 @@ impl BoardingTestBed {
     fn board_gsx_pax_full(mut self) -> Self {
         let mut max_pax = 0;
         for ps in A320Pax::iterator() {
-            max_pax += A320_PAX[ps].max_pax as i32;
+            max_pax += A320_PAX[ps].max_pax;
         }
         self.write_by_name("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL", max_pax);
         self
@@ -148,7 +153,7 @@ impl BoardingTestBed {
     fn deboard_gsx_pax_half(mut self) -> Self {
         let mut max_pax = 0;
         for ps in A320Pax::iterator() {
-            max_pax += A320_PAX[ps].max_pax as i32;
+            max_pax += A320_PAX[ps].max_pax;
         }
         self.write_by_name("FSDT_GSX_NUMPASSENGERS_DEBOARDING_TOTAL", max_pax / 2);
         self
@@ -163,7 +168,7 @@ impl BoardingTestBed {
     fn deboard_gsx_pax_full(mut self) -> Self {
         let mut max_pax = 0;
         for ps in A320Pax::iterator() {
-            max_pax += A320_PAX[ps].max_pax as i32;
+            max_pax += A320_PAX[ps].max_pax;
         }
         self.write_by_name("FSDT_GSX_NUMPASSENGERS_DEBOARDING_TOTAL", max_pax);
         self
@@ -189,7 +194,7 @@ impl BoardingTestBed {
         self
     }
 
-    fn load_pax(&mut self, ps: A320Pax, pax_qty: i8) {
+    fn load_pax(&mut self, ps: A320Pax, pax_qty: i32) {
         assert!(pax_qty <= A320_PAX[ps].max_pax);
 
         let per_pax_weight: Mass = Mass::new::<kilogram>(self.read_by_name("WB_PER_PAX_WEIGHT"));
@@ -197,7 +202,7 @@ impl BoardingTestBed {
         let seed = 380320;
         let mut rng = rand_pcg::Pcg32::seed_from_u64(seed);
 
-        let binding: Vec<i8> = (0..A320_PAX[ps].max_pax).collect();
+        let binding: Vec<i32> = (0..A320_PAX[ps].max_pax).collect();
         let choices = binding
             .iter()
             .choose_multiple(&mut rng, pax_qty.try_into().unwrap());
@@ -213,13 +218,13 @@ impl BoardingTestBed {
         self.write_by_name(&A320_PAX[ps].payload_id, payload);
     }
 
-    fn target_pax(&mut self, ps: A320Pax, pax_qty: i8) {
+    fn target_pax(&mut self, ps: A320Pax, pax_qty: i32) {
         assert!(pax_qty <= A320_PAX[ps].max_pax);
 
         let seed = 747777;
         let mut rng = rand_pcg::Pcg32::seed_from_u64(seed);
 
-        let binding: Vec<i8> = (0..A320_PAX[ps].max_pax).collect();
+        let binding: Vec<i32> = (0..A320_PAX[ps].max_pax).collect();
         let choices = binding
             .iter()
             .choose_multiple(&mut rng, pax_qty.try_into().unwrap());
@@ -302,7 +307,7 @@ impl BoardingTestBed {
         assert!(!pax_ambience);
     }
 
-    fn with_pax(mut self, ps: A320Pax, pax_qty: i8) -> Self {
+    fn with_pax(mut self, ps: A320Pax, pax_qty: i32) -> Self {
         self.load_pax(ps, pax_qty);
         self
     }
@@ -342,7 +347,7 @@ impl BoardingTestBed {
         self
     }
 
-    fn with_pax_target(mut self, ps: A320Pax, pax_qty: i8) -> Self {
+    fn with_pax_target(mut self, ps: A320Pax, pax_qty: i32) -> Self {
         self.target_pax(ps, pax_qty);
         self
     }
@@ -521,43 +526,43 @@ impl BoardingTestBed {
     }
 
     fn is_boarding(&self) -> bool {
-        self.query(|a| a.boarding.is_boarding())
+        self.query(|a| a.boarding.payload.is_boarding())
     }
 
     fn board_rate(&self) -> BoardingRate {
-        self.query(|a| a.boarding.board_rate())
+        self.query(|a| a.boarding.payload.board_rate())
     }
 
     fn sound_pax_ambience(&self) -> bool {
-        self.query(|a| a.boarding.boarding_sounds.pax_ambience)
+        self.query(|a| a.boarding.payload.boarding_sounds().pax_ambience())
     }
 
     fn sound_pax_boarding(&self) -> bool {
-        self.query(|a| a.boarding.boarding_sounds.pax_boarding)
+        self.query(|a| a.boarding.payload.boarding_sounds().pax_boarding())
     }
 
     fn sound_pax_deboarding(&self) -> bool {
-        self.query(|a| a.boarding.boarding_sounds.pax_deboarding)
+        self.query(|a| a.boarding.payload.boarding_sounds().pax_deboarding())
     }
 
     fn sound_pax_complete(&self) -> bool {
-        self.query(|a| a.boarding.boarding_sounds.pax_complete)
+        self.query(|a| a.boarding.payload.boarding_sounds().pax_complete())
     }
 
-    fn pax_num(&self, ps: A320Pax) -> i8 {
-        self.query(|a| a.boarding.pax[ps as usize].pax_num())
+    fn pax_num(&self, ps: A320Pax) -> i32 {
+        self.query(|a| a.boarding.payload.pax_num(ps as usize))
     }
 
     fn pax_payload(&self, ps: A320Pax) -> Mass {
-        self.query(|a| a.boarding.pax[ps as usize].payload())
+        self.query(|a| a.boarding.payload.pax_payload(ps as usize))
     }
 
     fn cargo(&self, cs: A320Cargo) -> Mass {
-        self.query(|a| a.boarding.cargo[cs as usize].cargo())
+        self.query(|a| a.boarding.payload.cargo(cs as usize))
     }
 
     fn cargo_payload(&self, cs: A320Cargo) -> Mass {
-        self.query(|a| a.boarding.cargo[cs as usize].payload())
+        self.query(|a| a.boarding.payload.cargo_payload(cs as usize))
     }
 }
 
@@ -12,6 +12,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    icing_state::ActiveDeicingController,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
         valve::*, BleedMonitoringComputerChannelOperationMode,
@@ -30,11 +31,13 @@ use systems::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
     },
 };
 
+mod engine_anti_ice;
 mod wing_anti_ice;
+use engine_anti_ice::EngineAntiIceValve;
 use wing_anti_ice::*;
 
 macro_rules! valve_signal_implementation {
@@ -125,6 +128,7 @@ pub struct A320Pneumatic {
     apu_bleed_air_valve: DefaultValve,
 
     wing_anti_ice: WingAntiIceComplex,
+    engine_anti_ice_valves: [EngineAntiIceValve; 2],
 
     hydraulic_reservoir_bleed_air_valves: [PurelyPneumaticValve; 2],
     hydraulic_reservoir_bleed_air_pipe: PneumaticPipe,
@@ -168,6 +172,10 @@ impl A320Pneumatic {
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
             wing_anti_ice: WingAntiIceComplex::new(context),
+            engine_anti_ice_valves: [
+                EngineAntiIceValve::new(context, 1),
+                EngineAntiIceValve::new(context, 2),
+            ],
             hydraulic_reservoir_bleed_air_valves: [
                 PurelyPneumaticValve::new(),
                 PurelyPneumaticValve::new(),
@@ -224,6 +232,10 @@ impl A320Pneumatic {
         pack_flow_valve_signals: &impl PackFlowControllers,
         lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
+        for (valve, engine) in self.engine_anti_ice_valves.iter_mut().zip(engines) {
+            valve.update(context, engine);
+        }
+
         self.physics_updater.update(context);
 
         for cur_time_step in self.physics_updater {
@@ -360,6 +372,14 @@ impl A320Pneumatic {
             .change_spatial_volume(yellow_hydraulic_reservoir.available_volume());
     }
 
+    pub fn wing_anti_ice(&self, number: usize) -> &impl ActiveDeicingController {
+        self.wing_anti_ice.wing_anti_ice_system(number - 1)
+    }
+
+    pub fn engine_anti_ice(&self, number: usize) -> &impl ActiveDeicingController {
+        &self.engine_anti_ice_valves[number - 1]
+    }
+
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
@@ -394,6 +414,7 @@ impl SimulationElement for A320Pneumatic {
         self.cross_bleed_valve.accept(visitor);
         self.fadec.accept(visitor);
         self.wing_anti_ice.accept(visitor);
+        accept_iterable!(self.engine_anti_ice_valves, visitor);
 
         accept_iterable!(self.bleed_monitoring_computers, visitor);
         accept_iterable!(self.engine_systems, visitor);
@@ -1205,12 +1226,33 @@ pub struct FullAuthorityDigitalEngineControl {
 impl FullAuthorityDigitalEngineControl {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            engine_1_state_id: context.get_identifier("ENGINE_STATE:1".to_owned()),
-            engine_2_state_id: context.get_identifier("ENGINE_STATE:2".to_owned()),
+            engine_1_state_id: context.get_identifier_with_metadata(
+                "ENGINE_STATE:1".to_owned(),
+                VariableMetadata::new()
+                    .unit("enum")
+                    .description(
+                        "State of engine 1: off, on, starting, restarting or shutting down",
+                    )
+                    .written_externally(),
+            ),
+            engine_2_state_id: context.get_identifier_with_metadata(
+                "ENGINE_STATE:2".to_owned(),
+                VariableMetadata::new()
+                    .unit("enum")
+                    .description(
+                        "State of engine 2: off, on, starting, restarting or shutting down",
+                    )
+                    .written_externally(),
+            ),
             engine_1_state: EngineState::Off,
             engine_2_state: EngineState::Off,
-            engine_mode_selector1_id: context
-                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
+            engine_mode_selector1_id: context.get_identifier_with_metadata(
+                "TURB ENG IGNITION SWITCH EX1:1".to_owned(),
+                VariableMetadata::new()
+                    .unit("enum")
+                    .description("Engine mode selector position: crank, norm or ignition")
+                    .written_externally(),
+            ),
             engine_mode_selector1_position: EngineModeSelector::Norm,
         }
     }
@@ -1412,6 +1454,7 @@ mod tests {
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         engine::leap_engine::LeapEngine,
         failures::FailureType,
+        icing_state::ActiveDeicingController,
         pneumatic::{
             BleedMonitoringComputerChannelOperationMode, ControllablePneumaticValve,
             CrossBleedValveSelectorMode, EngineState, PneumaticContainer, PneumaticValveSignal,
@@ -2268,6 +2311,14 @@ mod tests {
             self
         }
 
+        fn wing_deicing_rate(&self, number: usize) -> Ratio {
+            self.query(|a| {
+                a.pneumatic
+                    .wing_anti_ice(number)
+                    .active_deicing_normalized_rate()
+            })
+        }
+
         fn wing_anti_ice_system_on(&mut self) -> bool {
             self.read_by_name("PNEU_WING_ANTI_ICE_SYSTEM_ON")
         }
@@ -3529,6 +3580,31 @@ mod tests {
             assert!(!test_bed.wing_anti_ice_has_fault());
         }
 
+        #[test]
+        fn wing_anti_ice_deices_only_when_on() {
+            let altitude = Length::new::<foot>(15000.);
+
+            let mut test_bed = test_bed_with()
+                .holding_eng1()
+                .holding_eng2()
+                .mach_number(MachNumber(0.5))
+                .in_isa_atmosphere(altitude)
+                .and_stabilize();
+            test_bed.set_lgciu_on_ground(false);
+
+            test_bed = test_bed
+                .wing_anti_ice_push_button(WingAntiIcePushButtonMode::Off)
+                .and_stabilize();
+            assert_eq!(test_bed.wing_deicing_rate(1), Ratio::new::<ratio>(0.));
+            assert_eq!(test_bed.wing_deicing_rate(2), Ratio::new::<ratio>(0.));
+
+            test_bed = test_bed
+                .wing_anti_ice_push_button(WingAntiIcePushButtonMode::On)
+                .and_stabilize();
+            assert!(test_bed.wing_deicing_rate(1) > Ratio::new::<ratio>(0.5));
+            assert!(test_bed.wing_deicing_rate(2) > Ratio::new::<ratio>(0.5));
+        }
+
         #[test]
         fn wing_anti_ice_when_precooler_not_pressurized() {
             let altitude = Length::new::<foot>(500.);
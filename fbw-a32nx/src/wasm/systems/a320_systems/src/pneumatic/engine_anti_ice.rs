@@ -0,0 +1,198 @@
+use crate::UpdateContext;
+
+use uom::si::{f64::*, ratio::percent};
+
+use systems::{
+    icing_state::ActiveDeicingController,
+    shared::EngineCorrectedN2,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
+        VariableMetadata, Write,
+    },
+};
+
+// Each nacelle is de-iced by HP compressor air, supplied through a single
+// engine anti ice valve. The valve is electrically controlled and
+// pneumatically operated: it can only open once the engine supplies
+// enough pressure.
+pub struct EngineAntiIceValve {
+    selected_id: VariableIdentifier,
+    valve_open_id: VariableIdentifier,
+
+    is_selected: bool,
+    open_amount: Ratio,
+}
+impl EngineAntiIceValve {
+    // Below this N2 the HP compressor doesn't supply enough pressure to open the valve
+    const MIN_N2_FOR_VALVE_OPENING: f64 = 50.;
+    // Percentage of full travel per second
+    const VALVE_SPEED: f64 = 50.;
+
+    pub fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            selected_id: context.get_identifier_with_metadata(
+                format!("ENG ANTI ICE:{}", number),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The engine anti ice pushbutton is on")
+                    .written_externally(),
+            ),
+            valve_open_id: context
+                .get_identifier(format!("PNEU_ENG_{}_ANTI_ICE_VALVE_OPEN", number)),
+
+            is_selected: false,
+            open_amount: Ratio::new::<percent>(0.),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, engine: &impl EngineCorrectedN2) {
+        let target_open_amount = if self.is_selected
+            && engine.corrected_n2() >= Ratio::new::<percent>(Self::MIN_N2_FOR_VALVE_OPENING)
+        {
+            Ratio::new::<percent>(100.)
+        } else {
+            Ratio::new::<percent>(0.)
+        };
+
+        let max_travel = Ratio::new::<percent>(Self::VALVE_SPEED * context.delta_as_secs_f64());
+        let travel = target_open_amount - self.open_amount;
+
+        self.open_amount += if travel > max_travel {
+            max_travel
+        } else if travel < -max_travel {
+            -max_travel
+        } else {
+            travel
+        };
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open_amount > Ratio::new::<percent>(0.)
+    }
+}
+impl ActiveDeicingController for EngineAntiIceValve {
+    fn active_deicing_normalized_rate(&self) -> Ratio {
+        self.open_amount
+    }
+}
+impl SimulationElement for EngineAntiIceValve {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_selected = reader.read(&self.selected_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.valve_open_id, self.is_open());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use systems::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElementVisitor,
+    };
+    use uom::si::ratio::ratio;
+
+    struct TestEngine {
+        corrected_n2: Ratio,
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.corrected_n2
+        }
+    }
+
+    struct TestAircraft {
+        valve: EngineAntiIceValve,
+        engine: TestEngine,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                valve: EngineAntiIceValve::new(context, 1),
+                engine: TestEngine {
+                    corrected_n2: Ratio::new::<percent>(0.),
+                },
+            }
+        }
+
+        fn set_corrected_n2(&mut self, corrected_n2: Ratio) {
+            self.engine.corrected_n2 = corrected_n2;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.valve.update(context, &self.engine);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.valve.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(percent_n2: f64, selected: bool) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| a.set_corrected_n2(Ratio::new::<percent>(percent_n2)));
+        test_bed.write_by_name("ENG ANTI ICE:1", selected);
+
+        test_bed
+    }
+
+    fn deicing_rate(test_bed: &SimulationTestBed<TestAircraft>) -> f64 {
+        test_bed.query(|a| a.valve.active_deicing_normalized_rate().get::<ratio>())
+    }
+
+    #[test]
+    fn valve_is_closed_when_not_selected() {
+        let mut test_bed = test_bed(80., false);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        let is_open: bool = test_bed.read_by_name("PNEU_ENG_1_ANTI_ICE_VALVE_OPEN");
+        assert!(!is_open);
+        assert_eq!(deicing_rate(&test_bed), 0.);
+    }
+
+    #[test]
+    fn valve_opens_when_selected_with_engine_running() {
+        let mut test_bed = test_bed(80., true);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        let is_open: bool = test_bed.read_by_name("PNEU_ENG_1_ANTI_ICE_VALVE_OPEN");
+        assert!(is_open);
+        assert_eq!(deicing_rate(&test_bed), 1.);
+    }
+
+    #[test]
+    fn valve_takes_time_to_open() {
+        let mut test_bed = test_bed(80., true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(deicing_rate(&test_bed) > 0.);
+        assert!(deicing_rate(&test_bed) < 1.);
+    }
+
+    #[test]
+    fn valve_stays_closed_without_engine_pressure() {
+        let mut test_bed = test_bed(20., true);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        let is_open: bool = test_bed.read_by_name("PNEU_ENG_1_ANTI_ICE_VALVE_OPEN");
+        assert!(!is_open);
+    }
+
+    #[test]
+    fn valve_closes_when_deselected() {
+        let mut test_bed = test_bed(80., true);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        test_bed.write_by_name("ENG ANTI ICE:1", false);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert_eq!(deicing_rate(&test_bed), 0.);
+    }
+}
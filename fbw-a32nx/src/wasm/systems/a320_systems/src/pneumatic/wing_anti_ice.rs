@@ -13,6 +13,7 @@ use uom::si::{
 
 use systems::{
     accept_iterable,
+    icing_state::ActiveDeicingController,
     pneumatic::{
         valve::DefaultValve, valve::PneumaticExhaust, ControllablePneumaticValve,
         PneumaticContainer, PneumaticPipe, PneumaticValveSignal, WingAntiIcePushButtonMode,
@@ -314,6 +315,7 @@ pub struct WingAntiIceSystem {
     wai_high_pressure: bool,
     wai_low_pressure: bool,
     wai_bleed_pressurised: bool,
+    wai_ambient_temperature: ThermodynamicTemperature,
 
     wai_pressure_id: VariableIdentifier,
     wai_temperature_id: VariableIdentifier,
@@ -327,6 +329,7 @@ impl WingAntiIceSystem {
     const WAI_MAX_PRESSURE: f64 = 2.1; //BAR
     const WAI_EXHAUST_SPEED: f64 = 0.1285; // Regulate wing_anti_ice_tweak_exhaust
     const WAI_VALVE_TRANSFER_SPEED: f64 = 1.3; // Regulate wing_anti_ice_tweak_time_to_open
+    const WAI_FULL_DEICING_TEMPERATURE_DIFFERENCE: f64 = 100.; // Degree celsius
 
     // Each WAI duct is made of
     // Flow Trimming Restrictor 47mm diameter
@@ -369,6 +372,7 @@ impl WingAntiIceSystem {
             wai_high_pressure: false,
             wai_low_pressure: false,
             wai_bleed_pressurised: false,
+            wai_ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
 
             wai_valve_closed_id: context
                 .get_identifier(format!("PNEU_WING_ANTI_ICE_{}_VALVE_CLOSED", number)),
@@ -461,6 +465,7 @@ impl WingAntiIceSystem {
         engine_system: &mut impl PneumaticContainer,
         wai_relay: &WingAntiIceRelay,
     ) {
+        self.wai_ambient_temperature = context.ambient_temperature();
         self.update_pressure_above_minimum(context, engine_system.pressure());
 
         // First, we see if the valve's open amount changes this update,
@@ -504,6 +509,20 @@ impl WingAntiIceSystem {
         }
     }
 }
+// The slats are de-iced by the heat of the bleed air in the piccolo ducts.
+// The de-icing rate is proportional to how much warmer the ducts are
+// than both the ambient air and the freezing point.
+impl ActiveDeicingController for WingAntiIceSystem {
+    fn active_deicing_normalized_rate(&self) -> Ratio {
+        let ambient_or_freezing = self.wai_ambient_temperature.get::<degree_celsius>().max(0.);
+
+        Ratio::new::<ratio>(
+            ((self.wai_consumer_temperature().get::<degree_celsius>() - ambient_or_freezing)
+                / Self::WAI_FULL_DEICING_TEMPERATURE_DIFFERENCE)
+                .clamp(0., 1.),
+        )
+    }
+}
 impl SimulationElement for WingAntiIceSystem {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.wai_pressure_id, self.wai_consumer_pressure());
@@ -580,6 +599,10 @@ impl WingAntiIceComplex {
         self.wai_systems[number].wai_mass_flow()
     }
 
+    pub fn wing_anti_ice_system(&self, number: usize) -> &WingAntiIceSystem {
+        &self.wai_systems[number]
+    }
+
     pub fn update(
         &mut self,
         context: &UpdateContext,
@@ -0,0 +1,334 @@
+use systems::{
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        VariableIdentifier, VariableMetadata,
+    },
+    weight_and_balance::{
+        BalanceItem, CenterOfGravityEnvelope, LoadSheet, MeanAerodynamicChord, TakeoffTrimSchedule,
+        WeightAndBalanceComputer,
+    },
+};
+use uom::si::{
+    f64::{Length, Mass},
+    length::foot,
+};
+
+use crate::{
+    fuel::A320Fuel,
+    payload::{A320Cargo, A320Pax, A320Payload},
+};
+
+/// Provides the A320 station arms to the weight and balance computer. The positions are those of
+/// flight_model.cfg, in feet forward of its reference datum, such that the computed CG matches
+/// the one of the simulator and the EFB. The dry operating weight is the empty weight of the
+/// simulator, which includes the crew, pantry and equipment.
+pub struct A320WeightAndBalance {
+    empty_weight_id: VariableIdentifier,
+
+    computer: WeightAndBalanceComputer,
+    empty_weight: Mass,
+}
+impl A320WeightAndBalance {
+    const LEMAC_POSITION_FEET: f64 = -5.383;
+    const MAC_LENGTH_FEET: f64 = 13.464;
+
+    const EMPTY_WEIGHT_CG_POSITION_FEET: f64 = -9.42;
+
+    const CENTER_TANK_POSITION_FEET: f64 = -4.5;
+    const INNER_TANKS_POSITION_FEET: f64 = -8.;
+    const OUTER_TANKS_POSITION_FEET: f64 = -16.9;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            empty_weight_id: context.get_identifier_with_metadata(
+                "EMPTY WEIGHT".to_owned(),
+                VariableMetadata::new()
+                    .unit("pounds")
+                    .description("Empty weight, including the crew and equipment")
+                    .written_externally(),
+            ),
+
+            computer: WeightAndBalanceComputer::new(
+                context,
+                Self::mac(),
+                CenterOfGravityEnvelope::new(
+                    vec![40000., 53000., 64300.],
+                    vec![17., 17., 19.5],
+                    vec![37.5, 40.5, 40.],
+                ),
+                CenterOfGravityEnvelope::new(
+                    vec![40000., 53000., 64000., 79000.],
+                    vec![17., 17., 18.5, 25.],
+                    vec![37.5, 40.5, 41., 38.],
+                ),
+                TakeoffTrimSchedule::new(vec![17., 25., 30., 40.], vec![3.8, 1.7, 0.3, -2.5]),
+            ),
+            empty_weight: Mass::default(),
+        }
+    }
+
+    fn mac() -> MeanAerodynamicChord {
+        MeanAerodynamicChord::new(
+            Self::arm(Self::LEMAC_POSITION_FEET),
+            Length::new::<foot>(Self::MAC_LENGTH_FEET),
+        )
+    }
+
+    /// The computer measures its arms aft, while flight_model.cfg measures its positions forward.
+    fn arm(position_feet: f64) -> Length {
+        Length::new::<foot>(-position_feet)
+    }
+
+    pub(crate) fn update(&mut self, payload: &A320Payload, fuel: &A320Fuel) {
+        let dry_operating = BalanceItem::new(
+            self.empty_weight,
+            Self::arm(Self::EMPTY_WEIGHT_CG_POSITION_FEET),
+        );
+
+        let payload_items: Vec<BalanceItem> = A320Pax::iterator()
+            .map(|ps| BalanceItem::new(payload.pax_payload(ps), Self::pax_arm(ps)))
+            .chain(
+                A320Cargo::iterator()
+                    .map(|cs| BalanceItem::new(payload.cargo_payload(cs), Self::cargo_arm(cs))),
+            )
+            .collect();
+
+        let fuel_items = [
+            BalanceItem::new(
+                fuel.center_tank_fuel_quantity(),
+                Self::arm(Self::CENTER_TANK_POSITION_FEET),
+            ),
+            BalanceItem::new(
+                fuel.inner_tanks_fuel_quantity(),
+                Self::arm(Self::INNER_TANKS_POSITION_FEET),
+            ),
+            BalanceItem::new(
+                fuel.outer_tanks_fuel_quantity(),
+                Self::arm(Self::OUTER_TANKS_POSITION_FEET),
+            ),
+        ];
+
+        self.computer
+            .update(dry_operating, &payload_items, &fuel_items);
+    }
+
+    fn pax_arm(ps: A320Pax) -> Length {
+        Self::arm(match ps {
+            A320Pax::A => 20.5,
+            A320Pax::B => 1.5,
+            A320Pax::C => -16.6,
+            A320Pax::D => -35.6,
+        })
+    }
+
+    fn cargo_arm(cs: A320Cargo) -> Length {
+        Self::arm(match cs {
+            A320Cargo::FwdBaggage => 17.3,
+            A320Cargo::AftContainer => -24.1,
+            A320Cargo::AftBaggage => -34.1,
+            A320Cargo::AftBulkLoose => -42.4,
+        })
+    }
+
+    pub fn load_sheet(&self) -> &LoadSheet {
+        self.computer.load_sheet()
+    }
+}
+impl SimulationElement for A320WeightAndBalance {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.computer.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.empty_weight = reader.read(&self.empty_weight_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ntest::assert_about_eq;
+    use systems::{
+        electrical::Electricity,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, UpdateContext,
+        },
+    };
+    use uom::si::mass::kilogram;
+
+    struct TestAircraft {
+        payload: A320Payload,
+        fuel: A320Fuel,
+        weight_and_balance: A320WeightAndBalance,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                payload: A320Payload::new(context),
+                fuel: A320Fuel::new(context),
+                weight_and_balance: A320WeightAndBalance::new(context),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _electricity: &mut Electricity,
+        ) {
+            self.payload.update(context);
+            self.weight_and_balance.update(&self.payload, &self.fuel);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.payload.accept(visitor);
+            self.fuel.accept(visitor);
+            self.weight_and_balance.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct WeightAndBalanceTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl WeightAndBalanceTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.write_by_name("WB_PER_PAX_WEIGHT", 84.);
+            test_bed.write_by_name("EMPTY WEIGHT", Mass::new::<kilogram>(42500.));
+
+            test_bed
+        }
+
+        fn with_full_pax(mut self, pax_id: &str, max_pax: u32) -> Self {
+            self.write_by_name(pax_id, (1u64 << max_pax) - 1);
+            self
+        }
+
+        fn with_fuel(mut self, tank: &str, quantity_kg: f64) -> Self {
+            self.write_by_name(tank, Mass::new::<kilogram>(quantity_kg));
+            self
+        }
+
+        fn and_run(mut self) -> Self {
+            self.run();
+            self
+        }
+
+        fn zero_fuel_weight(&mut self) -> f64 {
+            self.read_by_name("WB_ZFW")
+        }
+
+        fn zero_fuel_weight_cg(&mut self) -> f64 {
+            self.read_by_name("WB_ZFW_CG")
+        }
+
+        fn gross_weight(&mut self) -> f64 {
+            self.read_by_name("WB_GW")
+        }
+
+        fn gross_weight_cg(&mut self) -> f64 {
+            self.read_by_name("WB_GW_CG")
+        }
+
+        fn is_within_limits(&mut self) -> bool {
+            let zero_fuel_weight_within_limits: bool = self.read_by_name("WB_ZFW_WITHIN_LIMITS");
+            let gross_weight_within_limits: bool = self.read_by_name("WB_GW_WITHIN_LIMITS");
+
+            zero_fuel_weight_within_limits && gross_weight_within_limits
+        }
+    }
+    impl TestBed for WeightAndBalanceTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> WeightAndBalanceTestBed {
+        WeightAndBalanceTestBed::new()
+    }
+
+    #[test]
+    fn empty_aircraft_is_at_dry_operating_weight() {
+        let mut test_bed = test_bed().and_run();
+
+        assert_about_eq!(test_bed.zero_fuel_weight(), 42500.);
+        assert_about_eq!(test_bed.zero_fuel_weight_cg(), 29.98, 0.01);
+        assert!(test_bed.is_within_limits());
+    }
+
+    #[test]
+    fn boarding_the_forward_cabin_moves_the_cg_forward() {
+        let mut test_bed = test_bed().with_full_pax("PAX_A", 36).and_run();
+
+        assert_about_eq!(test_bed.zero_fuel_weight(), 42500. + 36. * 84., 0.1);
+        assert!(test_bed.zero_fuel_weight_cg() < 29.98);
+    }
+
+    #[test]
+    fn boarding_the_aft_cabin_moves_the_cg_aft() {
+        let mut test_bed = test_bed().with_full_pax("PAX_D", 48).and_run();
+
+        assert!(test_bed.zero_fuel_weight_cg() > 29.98);
+    }
+
+    #[test]
+    fn full_cabin_is_within_limits() {
+        let mut test_bed = test_bed()
+            .with_full_pax("PAX_A", 36)
+            .with_full_pax("PAX_B", 42)
+            .with_full_pax("PAX_C", 48)
+            .with_full_pax("PAX_D", 48)
+            .and_run();
+
+        assert!(test_bed.is_within_limits());
+    }
+
+    #[test]
+    fn full_cabin_cg_matches_the_efb() {
+        let mut test_bed = test_bed()
+            .with_full_pax("PAX_A", 36)
+            .with_full_pax("PAX_B", 42)
+            .with_full_pax("PAX_C", 48)
+            .with_full_pax("PAX_D", 48)
+            .and_run();
+
+        // Computed by the EFB payload page for 174 passengers of 84 kg.
+        assert_about_eq!(test_bed.zero_fuel_weight(), 57116., 0.1);
+        assert_about_eq!(test_bed.zero_fuel_weight_cg(), 30.70, 0.01);
+    }
+
+    #[test]
+    fn fuel_adds_to_gross_weight_only() {
+        let mut test_bed = test_bed()
+            .with_fuel("FUEL TANK LEFT MAIN QUANTITY", 5000.)
+            .with_fuel("FUEL TANK RIGHT MAIN QUANTITY", 5000.)
+            .and_run();
+
+        assert_about_eq!(test_bed.zero_fuel_weight(), 42500., 0.1);
+        assert_about_eq!(test_bed.gross_weight(), 52500., 0.1);
+        assert_about_eq!(test_bed.gross_weight_cg(), 27.97, 0.01);
+    }
+
+    #[test]
+    fn load_sheet_reflects_the_boarded_payload() {
+        let test_bed = test_bed().with_full_pax("PAX_B", 42).and_run();
+
+        let load_sheet = test_bed.query(|a| a.weight_and_balance.load_sheet().clone());
+        assert_about_eq!(load_sheet.payload.get::<kilogram>(), 42. * 84., 0.1);
+        assert!(load_sheet.to_json().contains("\"zfw\":46028"));
+    }
+}
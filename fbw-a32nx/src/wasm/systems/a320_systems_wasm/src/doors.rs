@@ -0,0 +1,23 @@
+use std::error::Error;
+use systems::shared::to_bool;
+use systems_wasm::aspects::{ExecuteOn, MsfsAspectBuilder};
+use systems_wasm::msfs::legacy::execute_calculator_code;
+use systems_wasm::Variable;
+
+/// Closes a door in the simulator again when the differential pressure holds it closed.
+pub(super) fn doors(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    // The doors are the simulator's exits 1 to 4, being its interactive points 0 to 3.
+    for (id, exit) in [("L1", 1), ("R1", 2), ("L2", 3), ("R2", 4)] {
+        builder.on_change(
+            ExecuteOn::PostTick,
+            vec![Variable::named(&format!("DOOR_{}_HELD_CLOSED", id))],
+            Box::new(move |_, values| {
+                if to_bool(values[0]) {
+                    execute_calculator_code::<()>(&format!("{} (>K:TOGGLE_AIRCRAFT_EXIT)", exit));
+                }
+            }),
+        );
+    }
+
+    Ok(())
+}
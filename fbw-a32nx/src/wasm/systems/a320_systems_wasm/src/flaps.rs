@@ -1,10 +1,9 @@
-use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
 use std::error::Error;
 use systems_wasm::aspects::{
     EventToVariableMapping, ExecuteOn, MsfsAspectBuilder, ObjectWrite, VariablesToObject,
 };
-use systems_wasm::{set_data_on_sim_object, Variable};
+use systems_wasm::msfs::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
+use systems_wasm::{data_definition, set_data_on_sim_object, Variable};
 
 pub(super) fn flaps(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
     builder.event_to_variable(
@@ -94,15 +93,16 @@ fn get_handle_pos_from_0_1(input: f64, current_value: f64) -> f64 {
     }
 }
 
-#[sim_connect::data_definition]
-struct FlapsSurface {
-    #[name = "TRAILING EDGE FLAPS LEFT PERCENT"]
-    #[unit = "Percent"]
-    left_flap: f64,
+data_definition! {
+    struct FlapsSurface {
+        #[name = "TRAILING EDGE FLAPS LEFT PERCENT"]
+        #[unit = "Percent"]
+        left_flap: f64,
 
-    #[name = "TRAILING EDGE FLAPS RIGHT PERCENT"]
-    #[unit = "Percent"]
-    right_flap: f64,
+        #[name = "TRAILING EDGE FLAPS RIGHT PERCENT"]
+        #[unit = "Percent"]
+        right_flap: f64,
+    }
 }
 
 impl VariablesToObject for FlapsSurface {
@@ -123,15 +123,16 @@ impl VariablesToObject for FlapsSurface {
     set_data_on_sim_object!();
 }
 
-#[sim_connect::data_definition]
-struct SlatsSurface {
-    #[name = "LEADING EDGE FLAPS LEFT PERCENT"]
-    #[unit = "Percent"]
-    left_slat: f64,
+data_definition! {
+    struct SlatsSurface {
+        #[name = "LEADING EDGE FLAPS LEFT PERCENT"]
+        #[unit = "Percent"]
+        left_slat: f64,
 
-    #[name = "LEADING EDGE FLAPS RIGHT PERCENT"]
-    #[unit = "Percent"]
-    right_slat: f64,
+        #[name = "LEADING EDGE FLAPS RIGHT PERCENT"]
+        #[unit = "Percent"]
+        right_slat: f64,
+    }
 }
 
 impl VariablesToObject for SlatsSurface {
@@ -152,11 +153,12 @@ impl VariablesToObject for SlatsSurface {
     set_data_on_sim_object!();
 }
 
-#[sim_connect::data_definition]
-struct FlapsHandleIndex {
-    #[name = "FLAPS HANDLE INDEX"]
-    #[unit = "Number"]
-    index: f64,
+data_definition! {
+    struct FlapsHandleIndex {
+        #[name = "FLAPS HANDLE INDEX"]
+        #[unit = "Number"]
+        index: f64,
+    }
 }
 
 impl VariablesToObject for FlapsHandleIndex {
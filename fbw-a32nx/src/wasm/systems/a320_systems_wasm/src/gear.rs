@@ -1,12 +1,11 @@
 use std::error::Error;
 
-use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+use systems_wasm::msfs::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
 
 use systems_wasm::aspects::{
     EventToVariableMapping, MsfsAspectBuilder, ObjectWrite, VariablesToObject,
 };
-use systems_wasm::{set_data_on_sim_object, Variable};
+use systems_wasm::{data_definition, set_data_on_sim_object, Variable};
 
 pub(super) fn gear(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
     // Read gear demand from all sim sim events and mask them
@@ -57,23 +56,24 @@ pub(super) fn gear(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>
     Ok(())
 }
 
-#[sim_connect::data_definition]
-struct GearPosition {
-    #[name = "GEAR CENTER POSITION"]
-    #[unit = "Percent over 100"]
-    nose_position: f64,
+data_definition! {
+    struct GearPosition {
+        #[name = "GEAR CENTER POSITION"]
+        #[unit = "Percent over 100"]
+        nose_position: f64,
 
-    #[name = "GEAR LEFT POSITION"]
-    #[unit = "Percent over 100"]
-    left_position: f64,
+        #[name = "GEAR LEFT POSITION"]
+        #[unit = "Percent over 100"]
+        left_position: f64,
 
-    #[name = "GEAR RIGHT POSITION"]
-    #[unit = "Percent over 100"]
-    right_position: f64,
+        #[name = "GEAR RIGHT POSITION"]
+        #[unit = "Percent over 100"]
+        right_position: f64,
 
-    #[name = "GEAR HANDLE POSITION"]
-    #[unit = "Percent over 100"]
-    gear_handle_position: f64,
+        #[name = "GEAR HANDLE POSITION"]
+        #[unit = "Percent over 100"]
+        gear_handle_position: f64,
+    }
 }
 
 impl VariablesToObject for GearPosition {
@@ -1,10 +1,9 @@
 use std::error::Error;
 
-use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+use systems_wasm::msfs::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
 
 use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
-use systems_wasm::{set_data_on_sim_object, Variable};
+use systems_wasm::{data_definition, set_data_on_sim_object, Variable};
 
 pub(super) fn payload(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
     builder.copy(
@@ -54,39 +53,40 @@ pub(super) fn payload(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Err
     Ok(())
 }
 
-#[sim_connect::data_definition]
-struct Payload {
-    #[name = "PAYLOAD STATION WEIGHT:1"]
-    #[unit = "Pounds"]
-    payload_station_1: f64,
+data_definition! {
+    struct Payload {
+        #[name = "PAYLOAD STATION WEIGHT:1"]
+        #[unit = "Pounds"]
+        payload_station_1: f64,
 
-    #[name = "PAYLOAD STATION WEIGHT:2"]
-    #[unit = "Pounds"]
-    payload_station_2: f64,
+        #[name = "PAYLOAD STATION WEIGHT:2"]
+        #[unit = "Pounds"]
+        payload_station_2: f64,
 
-    #[name = "PAYLOAD STATION WEIGHT:3"]
-    #[unit = "Pounds"]
-    payload_station_3: f64,
+        #[name = "PAYLOAD STATION WEIGHT:3"]
+        #[unit = "Pounds"]
+        payload_station_3: f64,
 
-    #[name = "PAYLOAD STATION WEIGHT:4"]
-    #[unit = "Pounds"]
-    payload_station_4: f64,
+        #[name = "PAYLOAD STATION WEIGHT:4"]
+        #[unit = "Pounds"]
+        payload_station_4: f64,
 
-    #[name = "PAYLOAD STATION WEIGHT:5"]
-    #[unit = "Pounds"]
-    payload_station_5: f64,
+        #[name = "PAYLOAD STATION WEIGHT:5"]
+        #[unit = "Pounds"]
+        payload_station_5: f64,
 
-    #[name = "PAYLOAD STATION WEIGHT:6"]
-    #[unit = "Pounds"]
-    payload_station_6: f64,
+        #[name = "PAYLOAD STATION WEIGHT:6"]
+        #[unit = "Pounds"]
+        payload_station_6: f64,
 
-    #[name = "PAYLOAD STATION WEIGHT:7"]
-    #[unit = "Pounds"]
-    payload_station_7: f64,
+        #[name = "PAYLOAD STATION WEIGHT:7"]
+        #[unit = "Pounds"]
+        payload_station_7: f64,
 
-    #[name = "PAYLOAD STATION WEIGHT:8"]
-    #[unit = "Pounds"]
-    payload_station_8: f64,
+        #[name = "PAYLOAD STATION WEIGHT:8"]
+        #[unit = "Pounds"]
+        payload_station_8: f64,
+    }
 }
 
 impl VariablesToObject for Payload {
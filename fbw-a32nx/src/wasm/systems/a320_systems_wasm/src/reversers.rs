@@ -1,10 +1,9 @@
 use std::error::Error;
 
-use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+use systems_wasm::msfs::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
 
 use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
-use systems_wasm::{set_data_on_sim_object, Variable};
+use systems_wasm::{data_definition, set_data_on_sim_object, Variable};
 
 pub(super) fn reversers(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
     builder.variables_to_object(Box::new(ReverserThrust {
@@ -23,15 +22,16 @@ const LOW_SPEED_MODE_SPEED_FORCE_MULTIPLIER: f64 = 3.;
 // Multiplier to tune the angular torque caused by thrust reverser asymetry
 const ASYMETRY_EFFECT_MAGIC_MULTIPLIER: f64 = 10.;
 
-#[sim_connect::data_definition]
-struct ReverserThrust {
-    #[name = "VELOCITY BODY Z"]
-    #[unit = "Feet per second"]
-    velocity_z: f64,
+data_definition! {
+    struct ReverserThrust {
+        #[name = "VELOCITY BODY Z"]
+        #[unit = "Feet per second"]
+        velocity_z: f64,
 
-    #[name = "ROTATION ACCELERATION BODY Y"]
-    #[unit = "Radian per second squared"]
-    angular_acc_y: f64,
+        #[name = "ROTATION ACCELERATION BODY Y"]
+        #[unit = "Radian per second squared"]
+        angular_acc_y: f64,
+    }
 }
 
 impl VariablesToObject for ReverserThrust {
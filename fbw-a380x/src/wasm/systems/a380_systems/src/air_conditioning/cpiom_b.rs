@@ -1,14 +1,17 @@
 use std::time::Duration;
 
 use systems::{
+    accept_iterable,
     air_conditioning::{
         acs_controller::{AirConditioningStateManager, Pack},
-        AdirsToAirCondInterface, AirConditioningOverheadShared, OverheadFlowSelector, PackFlow,
+        cabin_pressure_controller::CabinPressureController,
+        pressure_valve::{OutflowValve, PressureValveSignal, SafetyValve},
+        AdirsToAirCondInterface, AirConditioningOverheadShared, OutflowValveSignal,
+        OverheadFlowSelector, PackFlow, PressurizationOverheadShared,
     },
-    integrated_modular_avionics::core_processing_input_output_module::CoreProcessingInputOutputModule,
     shared::{
-        CabinAltitude, EngineCorrectedN1, EngineStartState, LgciuWeightOnWheels,
-        PackFlowValveState, PneumaticBleed,
+        random_number, CabinAltitude, CabinSimulation, ControllerSignal, EngineCorrectedN1,
+        EngineStartState, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed,
     },
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
@@ -23,12 +26,14 @@ use uom::si::{
     ratio::{percent, ratio},
 };
 
+use super::A380PressurizationConstants;
+
 pub(super) struct CoreProcessingInputOutputModuleB {
     cpiom_are_active: [bool; 4],
     ags_app: AirGenerationSystemApplication,
+    cabin_pressure_control_system_app: CabinPressureControlSystemApplication,
     // temperature_control_system_app: TemperatureControlSystemApplication,
     // ventilation_control_system_app: VentilationControlSystemApplication,
-    // cabin_pressure_control_system_app: CabinPressureControllSystemApplication,
     // avionics_ventilation_system_app: AvionicsVentilationSystemApplication,
 }
 
@@ -37,9 +42,9 @@ impl CoreProcessingInputOutputModuleB {
         Self {
             cpiom_are_active: [false; 4],
             ags_app: AirGenerationSystemApplication::new(context),
+            cabin_pressure_control_system_app: CabinPressureControlSystemApplication::new(context),
             // temperature_control_system_app: TemperatureControlSystemApplication::new(),
             // ventilation_control_system_app: VentilationControlSystemApplication::new(),
-            // cabin_pressure_control_system_app: CabinPressureControllSystemApplication::new(),
             // avionics_ventilation_system_app: AvionicsVentilationSystemApplication::new(),
         }
     }
@@ -49,14 +54,15 @@ impl CoreProcessingInputOutputModuleB {
         context: &UpdateContext,
         adirs: &impl AdirsToAirCondInterface,
         acs_overhead: &impl AirConditioningOverheadShared,
-        cpiom_b: [&CoreProcessingInputOutputModule; 4],
+        cpiom_b_are_connected: [bool; 4],
         engines: &[&impl EngineCorrectedN1],
         lgciu: [&impl LgciuWeightOnWheels; 2],
         number_of_passengers: usize,
         pneumatic: &(impl EngineStartState + PackFlowValveState + PneumaticBleed),
-        pressurization: &impl CabinAltitude,
     ) {
-        self.cpiom_are_active = cpiom_b.map(|cpiom| cpiom.is_available());
+        self.cpiom_are_active = cpiom_b_are_connected;
+        self.cabin_pressure_control_system_app
+            .update_availability(self.cpiom_are_active);
 
         // We check if any CPIOM B is available to run the applications
         if self.cpiom_are_active.iter().any(|&cpiom| cpiom) {
@@ -68,10 +74,64 @@ impl CoreProcessingInputOutputModuleB {
                 lgciu,
                 number_of_passengers,
                 pneumatic,
-                pressurization,
+                &self.cabin_pressure_control_system_app,
             );
         }
     }
+
+    pub(super) fn update_cabin_pressure_control_system(
+        &mut self,
+        context: &UpdateContext,
+        adirs: &impl AdirsToAirCondInterface,
+        engines: [&impl EngineCorrectedN1; 4],
+        lgciu_gears_compressed: bool,
+        press_overhead: &impl PressurizationOverheadShared,
+        cabin_simulation: &impl CabinSimulation,
+        outflow_valves: &[OutflowValve],
+        safety_valve: &SafetyValve,
+    ) {
+        self.cabin_pressure_control_system_app.update(
+            context,
+            adirs,
+            engines,
+            lgciu_gears_compressed,
+            press_overhead,
+            cabin_simulation,
+            outflow_valves,
+            safety_valve,
+        );
+    }
+
+    pub(super) fn update_pressurization_ambient_conditions(
+        &mut self,
+        context: &UpdateContext,
+        adirs: &impl AdirsToAirCondInterface,
+    ) {
+        self.cabin_pressure_control_system_app
+            .update_ambient_conditions(context, adirs);
+    }
+
+    pub(super) fn cabin_delta_p(&self) -> Pressure {
+        self.cabin_pressure_control_system_app.cabin_delta_p()
+    }
+
+    #[cfg(test)]
+    pub(super) fn active_cabin_pressure_control_system(&self) -> usize {
+        self.cabin_pressure_control_system_app.active_system
+    }
+
+    #[cfg(test)]
+    pub(super) fn set_active_cabin_pressure_control_system(&mut self, system: usize) {
+        self.cabin_pressure_control_system_app.active_system = system;
+    }
+
+    #[cfg(test)]
+    pub(super) fn cabin_pressure_controller(
+        &self,
+        system: usize,
+    ) -> &CabinPressureController<A380PressurizationConstants> {
+        &self.cabin_pressure_control_system_app.cpc[system - 1]
+    }
 }
 
 impl PackFlow for CoreProcessingInputOutputModuleB {
@@ -90,9 +150,28 @@ impl PackFlow for CoreProcessingInputOutputModuleB {
     }
 }
 
+impl CabinAltitude for CoreProcessingInputOutputModuleB {
+    fn altitude(&self) -> Length {
+        self.cabin_pressure_control_system_app.altitude()
+    }
+}
+
+impl ControllerSignal<OutflowValveSignal> for CoreProcessingInputOutputModuleB {
+    fn signal(&self) -> Option<OutflowValveSignal> {
+        self.cabin_pressure_control_system_app.signal()
+    }
+}
+
+impl ControllerSignal<PressureValveSignal> for CoreProcessingInputOutputModuleB {
+    fn signal(&self) -> Option<PressureValveSignal> {
+        self.cabin_pressure_control_system_app.signal()
+    }
+}
+
 impl SimulationElement for CoreProcessingInputOutputModuleB {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.ags_app.accept(visitor);
+        self.cabin_pressure_control_system_app.accept(visitor);
 
         visitor.visit(self);
     }
@@ -317,3 +396,150 @@ impl SimulationElement for AirGenerationSystemApplication {
             .for_each(|(id, flow)| writer.write(id, flow));
     }
 }
+
+/// Runs the two cabin pressure controllers. The first system is hosted on CPIOM B1 and B3, the
+/// second on CPIOM B2 and B4. A system is only able to run, and to command the outflow valves,
+/// while at least one of its CPIOMs is connected to the AFDX network.
+struct CabinPressureControlSystemApplication {
+    active_cpc_sys_id: VariableIdentifier,
+
+    cpc: [CabinPressureController<A380PressurizationConstants>; 2],
+    cpc_is_available: [bool; 2],
+    active_system: usize,
+}
+
+impl CabinPressureControlSystemApplication {
+    fn new(context: &mut InitContext) -> Self {
+        let random = random_number();
+        let active = 2 - (random % 2);
+
+        Self {
+            active_cpc_sys_id: context.get_identifier("PRESS_ACTIVE_CPC_SYS".to_owned()),
+
+            cpc: [
+                CabinPressureController::new(context),
+                CabinPressureController::new(context),
+            ],
+            cpc_is_available: [false; 2],
+            active_system: active as usize,
+        }
+    }
+
+    fn update_availability(&mut self, cpiom_are_active: [bool; 4]) {
+        self.cpc_is_available = [
+            cpiom_are_active[0] || cpiom_are_active[2],
+            cpiom_are_active[1] || cpiom_are_active[3],
+        ];
+
+        // When the hosting CPIOMs of the active system are lost the other system takes over
+        if !self.active_system_is_available() && self.cpc_is_available[2 - self.active_system] {
+            self.active_system = 3 - self.active_system;
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        adirs: &impl AdirsToAirCondInterface,
+        engines: [&impl EngineCorrectedN1; 4],
+        lgciu_gears_compressed: bool,
+        press_overhead: &impl PressurizationOverheadShared,
+        cabin_simulation: &impl CabinSimulation,
+        outflow_valves: &[OutflowValve],
+        safety_valve: &SafetyValve,
+    ) {
+        for (controller, _) in self
+            .cpc
+            .iter_mut()
+            .zip(self.cpc_is_available)
+            .filter(|(_, is_available)| *is_available)
+        {
+            controller.update(
+                context,
+                adirs,
+                engines,
+                lgciu_gears_compressed,
+                press_overhead,
+                cabin_simulation,
+                outflow_valves.iter().collect(),
+                safety_valve,
+            );
+        }
+
+        self.switch_active_system();
+    }
+
+    fn switch_active_system(&mut self) {
+        if self
+            .cpc
+            .iter_mut()
+            .any(|controller| controller.should_switch_cpc())
+            && self.cpc_is_available[2 - self.active_system]
+        {
+            self.active_system = 3 - self.active_system;
+        }
+        for controller in &mut self.cpc {
+            if controller.should_switch_cpc() {
+                controller.reset_cpc_switch()
+            }
+        }
+    }
+
+    fn update_ambient_conditions(
+        &mut self,
+        context: &UpdateContext,
+        adirs: &impl AdirsToAirCondInterface,
+    ) {
+        self.cpc
+            .iter_mut()
+            .zip(self.cpc_is_available)
+            .filter(|(_, is_available)| *is_available)
+            .for_each(|(controller, _)| controller.update_ambient_conditions(context, adirs));
+    }
+
+    fn active_system_is_available(&self) -> bool {
+        self.cpc_is_available[self.active_system - 1]
+    }
+
+    fn cabin_delta_p(&self) -> Pressure {
+        self.cpc[self.active_system - 1].cabin_delta_p()
+    }
+}
+
+impl CabinAltitude for CabinPressureControlSystemApplication {
+    fn altitude(&self) -> Length {
+        self.cpc[self.active_system - 1].cabin_altitude()
+    }
+}
+
+impl ControllerSignal<OutflowValveSignal> for CabinPressureControlSystemApplication {
+    fn signal(&self) -> Option<OutflowValveSignal> {
+        if self.active_system_is_available() {
+            self.cpc[self.active_system - 1].signal()
+        } else {
+            None
+        }
+    }
+}
+
+impl ControllerSignal<PressureValveSignal> for CabinPressureControlSystemApplication {
+    fn signal(&self) -> Option<PressureValveSignal> {
+        if self.active_system_is_available() {
+            self.cpc[self.active_system - 1].signal()
+        } else {
+            None
+        }
+    }
+}
+
+impl SimulationElement for CabinPressureControlSystemApplication {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.active_cpc_sys_id, self.active_system);
+    }
+
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.cpc, visitor);
+
+        visitor.visit(self);
+    }
+}
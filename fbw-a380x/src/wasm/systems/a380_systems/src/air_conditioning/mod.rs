@@ -3,9 +3,8 @@ use systems::{
     air_conditioning::{
         acs_controller::{AirConditioningSystemController, Pack},
         cabin_air::CabinAirSimulation,
-        cabin_pressure_controller::CabinPressureController,
         full_digital_agu_controller::FullDigitalAGUController,
-        pressure_valve::{OutflowValve, SafetyValve},
+        pressure_valve::{OutflowValve, PressureValveSignal, SafetyValve},
         AdirsToAirCondInterface, Air, AirConditioningOverheadShared, AirConditioningPack, CabinFan,
         DuctTemperature, MixerUnit, OutflowValveSignal, OutletAir, OverheadFlowSelector, PackFlow,
         PackFlowControllers, PressurizationConstants, PressurizationOverheadShared, TrimAirSystem,
@@ -17,20 +16,22 @@ use systems::{
     },
     pneumatic::PneumaticContainer,
     shared::{
-        random_number, update_iterator::MaxStepLoop, AverageExt, CabinAltitude, CabinSimulation,
-        ControllerSignal, ElectricalBusType, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineFirePushButtons, EngineStartState, LgciuWeightOnWheels, PackFlowValveState,
-        PneumaticBleed,
+        update_iterator::MaxStepLoop, AverageExt, CabinAltitude, CabinSimulation, ControllerSignal,
+        ElectricalBusType, EngineBleedPushbutton, EngineCorrectedN1, EngineFirePushButtons,
+        EngineStartState, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        UpdateContext, VariableIdentifier, VariableMetadata,
     },
 };
 
 use std::time::Duration;
 use uom::si::{
-    f64::*, pressure::hectopascal, ratio::percent, thermodynamic_temperature::degree_celsius,
+    f64::*,
+    pressure::hectopascal,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::degree_celsius,
     velocity::knot,
 };
 
@@ -43,7 +44,7 @@ mod cpiom_b;
 pub(super) struct A380AirConditioning {
     a380_cabin: A380Cabin,
     a380_air_conditioning_system: A380AirConditioningSystem,
-    a320_pressurization_system: A320PressurizationSystem,
+    a380_pressurization_system: A380PressurizationSystem,
 
     cpiom_b: CoreProcessingInputOutputModuleB,
 
@@ -78,7 +79,7 @@ impl A380AirConditioning {
         Self {
             a380_cabin: A380Cabin::new(context, &cabin_zones),
             a380_air_conditioning_system: A380AirConditioningSystem::new(context, &cabin_zones),
-            a320_pressurization_system: A320PressurizationSystem::new(context),
+            a380_pressurization_system: A380PressurizationSystem::new(),
 
             cpiom_b: CoreProcessingInputOutputModuleB::new(context),
 
@@ -100,20 +101,19 @@ impl A380AirConditioning {
     ) {
         self.pressurization_updater.update(context);
 
-        let cpiom =
-            ["B1", "B2", "B3", "B4"].map(|name| cpiom_b.core_processing_input_output_module(name));
+        let cpiom_b_are_connected = ["B1", "B2", "B3", "B4"]
+            .map(|name| cpiom_b.core_processing_input_output_module_is_connected(name));
 
         self.cpiom_b.update(
             context,
             adirs,
             self.a380_air_conditioning_system
                 .air_conditioning_overhead(),
-            cpiom,
+            cpiom_b_are_connected,
             &engines,
             lgciu,
             self.a380_cabin.number_of_passengers(),
             pneumatic,
-            &self.a320_pressurization_system,
         );
 
         self.a380_air_conditioning_system.update(
@@ -126,7 +126,7 @@ impl A380AirConditioning {
             self.a380_cabin.number_of_open_doors(),
             pneumatic,
             pneumatic_overhead,
-            &self.a320_pressurization_system,
+            &self.cpiom_b,
             pressurization_overhead,
             lgciu,
         );
@@ -134,24 +134,36 @@ impl A380AirConditioning {
         // This is here due to the ADIRS updating at a different rate than the pressurization system
         self.update_pressurization_ambient_conditions(context, adirs);
 
+        let lgciu_gears_compressed = lgciu
+            .iter()
+            .all(|&a| a.left_and_right_gear_compressed(true));
+
         for cur_time_step in self.pressurization_updater {
             self.a380_cabin.update(
                 &context.with_delta(cur_time_step),
                 &self.a380_air_conditioning_system,
                 lgciu,
-                &self.a320_pressurization_system,
+                &self.a380_pressurization_system,
             );
 
-            // Temporary array until pressurization is done for A380
-            let temp_engines = [engines[0], engines[1]];
-
-            self.a320_pressurization_system.update(
+            self.cpiom_b.update_cabin_pressure_control_system(
                 &context.with_delta(cur_time_step),
                 adirs,
+                engines,
+                lgciu_gears_compressed,
                 pressurization_overhead,
-                temp_engines,
-                lgciu,
                 &self.a380_cabin,
+                self.a380_pressurization_system.outflow_valves(),
+                self.a380_pressurization_system.safety_valve(),
+            );
+
+            self.a380_pressurization_system.update(
+                &context.with_delta(cur_time_step),
+                &self.cpiom_b,
+                self.cpiom_b.cabin_delta_p(),
+                pressurization_overhead,
+                engines,
+                lgciu_gears_compressed,
             );
         }
     }
@@ -169,8 +181,8 @@ impl A380AirConditioning {
         context: &UpdateContext,
         adirs: &impl AdirsToAirCondInterface,
     ) {
-        self.a320_pressurization_system
-            .update_ambient_conditions(context, adirs);
+        self.cpiom_b
+            .update_pressurization_ambient_conditions(context, adirs);
     }
 
     pub(crate) fn fcv_to_pack_id(fcv_id: usize) -> usize {
@@ -196,7 +208,7 @@ impl SimulationElement for A380AirConditioning {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.a380_cabin.accept(visitor);
         self.a380_air_conditioning_system.accept(visitor);
-        self.a320_pressurization_system.accept(visitor);
+        self.a380_pressurization_system.accept(visitor);
         self.cpiom_b.accept(visitor);
 
         visitor.visit(self);
@@ -210,7 +222,7 @@ struct A380Cabin {
     fwd_door_is_open: bool,
     rear_door_is_open: bool,
     number_of_passengers: [u8; 18],
-    cabin_air_simulation: CabinAirSimulation<A320PressurizationConstants, 18>,
+    cabin_air_simulation: CabinAirSimulation<A380PressurizationConstants, 18>,
 }
 
 impl A380Cabin {
@@ -222,8 +234,20 @@ impl A380Cabin {
         number_of_passengers[0] = 2;
 
         Self {
-            fwd_door_id: context.get_identifier(Self::FWD_DOOR.to_owned()),
-            rear_door_id: context.get_identifier(Self::REAR_DOOR.to_owned()),
+            fwd_door_id: context.get_identifier_with_metadata(
+                Self::FWD_DOOR.to_owned(),
+                VariableMetadata::new()
+                    .unit("percent")
+                    .description("Opening of the forward passenger door")
+                    .written_externally(),
+            ),
+            rear_door_id: context.get_identifier_with_metadata(
+                Self::REAR_DOOR.to_owned(),
+                VariableMetadata::new()
+                    .unit("percent")
+                    .description("Opening of the rear passenger door")
+                    .written_externally(),
+            ),
 
             fwd_door_is_open: false,
             rear_door_is_open: false,
@@ -237,7 +261,7 @@ impl A380Cabin {
         context: &UpdateContext,
         air_conditioning_system: &(impl OutletAir + DuctTemperature),
         lgciu: [&impl LgciuWeightOnWheels; 2],
-        pressurization: &A320PressurizationSystem,
+        pressurization: &A380PressurizationSystem,
     ) {
         let lgciu_gears_compressed = lgciu
             .iter()
@@ -246,11 +270,11 @@ impl A380Cabin {
         self.cabin_air_simulation.update(
             context,
             air_conditioning_system,
-            pressurization.outflow_valve_open_amount(0),
+            pressurization.outflow_valve_open_amount(),
             pressurization.safety_valve_open_amount(),
             lgciu_gears_compressed,
             self.number_of_passengers,
-            self.number_of_open_doors(),
+            &self.door_open_ratios(),
         );
     }
 
@@ -258,6 +282,11 @@ impl A380Cabin {
         self.fwd_door_is_open as u8 + self.rear_door_is_open as u8
     }
 
+    fn door_open_ratios(&self) -> [Ratio; 2] {
+        [self.fwd_door_is_open, self.rear_door_is_open]
+            .map(|is_open| Ratio::new::<ratio>(if is_open { 1. } else { 0. }))
+    }
+
     fn number_of_passengers(&self) -> usize {
         self.number_of_passengers
             .iter()
@@ -503,8 +532,13 @@ struct A380AirConditioningSystemOverhead {
 impl A380AirConditioningSystemOverhead {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            flow_selector_id: context
-                .get_identifier("KNOB_OVHD_AIRCOND_PACKFLOW_Position".to_owned()),
+            flow_selector_id: context.get_identifier_with_metadata(
+                "KNOB_OVHD_AIRCOND_PACKFLOW_Position".to_owned(),
+                VariableMetadata::new()
+                    .unit("number")
+                    .description("Pack flow selector position")
+                    .written_externally(),
+            ),
 
             // Air panel
             flow_selector: OverheadFlowSelector::Norm,
@@ -604,79 +638,57 @@ impl SimulationElement for A380AirConditioningSystemOverhead {
     }
 }
 
-struct A320PressurizationSystem {
-    active_cpc_sys_id: VariableIdentifier,
-
-    cpc: [CabinPressureController<A320PressurizationConstants>; 2],
-    outflow_valve: [OutflowValve; 1], // Array to prepare for more than 1 outflow valve in A380
+struct A380PressurizationSystem {
+    outflow_valves: [OutflowValve; 4],
     safety_valve: SafetyValve,
     residual_pressure_controller: ResidualPressureController,
-    active_system: usize,
 }
 
-impl A320PressurizationSystem {
-    fn new(context: &mut InitContext) -> Self {
-        let random = random_number();
-        let active = 2 - (random % 2);
+impl A380PressurizationSystem {
+    fn new() -> Self {
+        // Each outflow valve has its auto motor powered by one of the main DC buses or by the
+        // DC ESS bus, and a manual motor powered by the DC ESS bus
+        let outflow_valve = |main_dc_bus| {
+            OutflowValve::new(
+                vec![main_dc_bus, ElectricalBusType::DirectCurrentEssential],
+                vec![ElectricalBusType::DirectCurrentEssential],
+            )
+        };
 
         Self {
-            active_cpc_sys_id: context.get_identifier("PRESS_ACTIVE_CPC_SYS".to_owned()),
-
-            cpc: [
-                CabinPressureController::new(context),
-                CabinPressureController::new(context),
+            outflow_valves: [
+                outflow_valve(ElectricalBusType::DirectCurrent(1)),
+                outflow_valve(ElectricalBusType::DirectCurrent(1)),
+                outflow_valve(ElectricalBusType::DirectCurrent(2)),
+                outflow_valve(ElectricalBusType::DirectCurrent(2)),
             ],
-            outflow_valve: [OutflowValve::new(
-                vec![
-                    ElectricalBusType::DirectCurrentEssential,
-                    ElectricalBusType::DirectCurrent(2),
-                ],
-                vec![ElectricalBusType::DirectCurrentBattery],
-            )],
             safety_valve: SafetyValve::new(),
             residual_pressure_controller: ResidualPressureController::new(),
-            active_system: active as usize,
         }
     }
 
     fn update(
         &mut self,
         context: &UpdateContext,
-        adirs: &impl AdirsToAirCondInterface,
+        cabin_pressure_control_system: &(impl ControllerSignal<OutflowValveSignal>
+              + ControllerSignal<PressureValveSignal>),
+        cabin_delta_p: Pressure,
         press_overhead: &A380PressurizationOverheadPanel,
-        engines: [&impl EngineCorrectedN1; 2],
-        lgciu: [&impl LgciuWeightOnWheels; 2],
-        cabin_simulation: &impl CabinSimulation,
+        engines: [&impl EngineCorrectedN1; 4],
+        lgciu_gears_compressed: bool,
     ) {
-        let lgciu_gears_compressed = lgciu
-            .iter()
-            .all(|&a| a.left_and_right_gear_compressed(true));
-
-        for controller in self.cpc.iter_mut() {
-            controller.update(
-                context,
-                adirs,
-                engines,
-                lgciu_gears_compressed,
-                press_overhead,
-                cabin_simulation,
-                self.outflow_valve.iter().collect(),
-                &self.safety_valve,
-            );
-        }
-
         self.residual_pressure_controller.update(
             context,
             engines,
-            self.outflow_valve[0].open_amount(),
+            self.outflow_valve_open_amount(),
             press_overhead.is_in_man_mode(),
             lgciu_gears_compressed,
-            self.cpc[self.active_system - 1].cabin_delta_p(),
+            cabin_delta_p,
         );
 
-        // The outflow valve(s) is(are) controlled by either the CPC, the RCPU (both in auto) or the overhead (manual)
+        // The outflow valves are controlled by either the CPCs, the RCPU (both in auto) or the overhead (manual)
         if self.residual_pressure_controller.signal().is_some() {
-            self.outflow_valve.iter_mut().for_each(|valve| {
+            self.outflow_valves.iter_mut().for_each(|valve| {
                 valve.update(
                     context,
                     &self.residual_pressure_controller,
@@ -684,52 +696,36 @@ impl A320PressurizationSystem {
                 )
             })
         } else if press_overhead.is_in_man_mode() {
-            self.outflow_valve.iter_mut().for_each(|valve| {
+            self.outflow_valves.iter_mut().for_each(|valve| {
                 valve.update(context, press_overhead, press_overhead.is_in_man_mode())
             })
         } else {
-            self.outflow_valve.iter_mut().for_each(|valve| {
+            self.outflow_valves.iter_mut().for_each(|valve| {
                 valve.update(
                     context,
-                    &self.cpc[self.active_system - 1],
+                    cabin_pressure_control_system,
                     press_overhead.is_in_man_mode(),
                 )
             });
         }
 
         self.safety_valve
-            .update(context, &self.cpc[self.active_system - 1]);
-
-        self.switch_active_system();
+            .update(context, cabin_pressure_control_system);
     }
 
-    fn switch_active_system(&mut self) {
-        if self
-            .cpc
-            .iter_mut()
-            .any(|controller| controller.should_switch_cpc())
-        {
-            self.active_system = if self.active_system == 1 { 2 } else { 1 };
-        }
-        for controller in &mut self.cpc {
-            if controller.should_switch_cpc() {
-                controller.reset_cpc_switch()
-            }
-        }
+    fn outflow_valves(&self) -> &[OutflowValve] {
+        &self.outflow_valves
     }
 
-    fn update_ambient_conditions(
-        &mut self,
-        context: &UpdateContext,
-        adirs: &impl AdirsToAirCondInterface,
-    ) {
-        self.cpc
-            .iter_mut()
-            .for_each(|c| c.update_ambient_conditions(context, adirs));
+    fn outflow_valve_open_amount(&self) -> Ratio {
+        self.outflow_valves
+            .iter()
+            .map(|valve| valve.open_amount())
+            .average()
     }
 
-    fn outflow_valve_open_amount(&self, ofv_id: usize) -> Ratio {
-        self.outflow_valve[ofv_id].open_amount()
+    fn safety_valve(&self) -> &SafetyValve {
+        &self.safety_valve
     }
 
     fn safety_valve_open_amount(&self) -> Ratio {
@@ -737,45 +733,35 @@ impl A320PressurizationSystem {
     }
 }
 
-impl CabinAltitude for A320PressurizationSystem {
-    fn altitude(&self) -> Length {
-        self.cpc[self.active_system - 1].cabin_altitude()
-    }
-}
-
-impl SimulationElement for A320PressurizationSystem {
-    fn write(&self, writer: &mut SimulatorWriter) {
-        writer.write(&self.active_cpc_sys_id, self.active_system);
-    }
-
+impl SimulationElement for A380PressurizationSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.cpc, visitor);
-        accept_iterable!(self.outflow_valve, visitor);
+        accept_iterable!(self.outflow_valves, visitor);
 
         visitor.visit(self);
     }
 }
 
-struct A320PressurizationConstants;
+struct A380PressurizationConstants;
 
-impl PressurizationConstants for A320PressurizationConstants {
-    // Volume data from A320 AIRCRAFT CHARACTERISTICS - AIRPORT AND MAINTENANCE PLANNING
-    const CABIN_VOLUME_CUBIC_METER: f64 = 139.; // m3
-    const COCKPIT_VOLUME_CUBIC_METER: f64 = 9.; // m3
-    const PRESSURIZED_FUSELAGE_VOLUME_CUBIC_METER: f64 = 330.; // m3
-    const CABIN_LEAKAGE_AREA: f64 = 0.0003; // m2
-    const OUTFLOW_VALVE_SIZE: f64 = 0.05; // m2
-    const SAFETY_VALVE_SIZE: f64 = 0.02; // m2
-    const DOOR_OPENING_AREA: f64 = 1.5; // m2
+impl PressurizationConstants for A380PressurizationConstants {
+    // Approximate volumes for the main and upper decks. The cabin volume is the volume of
+    // each of the sixteen cabin zones
+    const CABIN_VOLUME_CUBIC_METER: f64 = 110.; // m3
+    const COCKPIT_VOLUME_CUBIC_METER: f64 = 12.; // m3
+    const PRESSURIZED_FUSELAGE_VOLUME_CUBIC_METER: f64 = 1650.; // m3
+    const CABIN_LEAKAGE_AREA: f64 = 0.0008; // m2
+    const OUTFLOW_VALVE_SIZE: f64 = 0.25; // m2 - Combined area of the four outflow valves
+    const SAFETY_VALVE_SIZE: f64 = 0.04; // m2
+    const DOOR_OPENING_AREA: f64 = 1.7; // m2
 
     const MAX_CLIMB_RATE: f64 = 750.; // fpm
     const MAX_CLIMB_RATE_IN_DESCENT: f64 = 500.; // fpm
     const MAX_DESCENT_RATE: f64 = -750.; // fpm
     const MAX_ABORT_DESCENT_RATE: f64 = -500.; //fpm
     const MAX_TAKEOFF_DELTA_P: f64 = 0.1; // PSI
-    const MAX_CLIMB_DELTA_P: f64 = 8.06; // PSI
-    const MAX_CLIMB_CABIN_ALTITUDE: f64 = 8050.; // feet
-    const MAX_SAFETY_DELTA_P: f64 = 8.1; // PSI
+    const MAX_CLIMB_DELTA_P: f64 = 8.56; // PSI
+    const MAX_CLIMB_CABIN_ALTITUDE: f64 = 7350.; // feet
+    const MAX_SAFETY_DELTA_P: f64 = 8.9; // PSI
     const MIN_SAFETY_DELTA_P: f64 = -0.5; // PSI
     const TAKEOFF_RATE: f64 = -400.;
     const DEPRESS_RATE: f64 = 500.;
@@ -865,7 +851,7 @@ impl ResidualPressureController {
     fn update(
         &mut self,
         context: &UpdateContext,
-        engines: [&impl EngineCorrectedN1; 2],
+        engines: [&impl EngineCorrectedN1; 4],
         outflow_valve_open_amount: Ratio,
         is_in_man_mode: bool,
         lgciu_gears_compressed: bool,
@@ -979,6 +965,7 @@ mod tests {
 
     struct TestAdcn {
         cpiom_b: [CoreProcessingInputOutputModule; 4],
+        afdx_switches_are_available: [bool; 4],
     }
     impl TestAdcn {
         fn new(context: &mut InitContext) -> Self {
@@ -990,8 +977,21 @@ mod tests {
                     ("B4", ElectricalBusType::DirectCurrent(2)),
                 ]
                 .map(|(name, bus)| CoreProcessingInputOutputModule::new(context, name, bus)),
+                afdx_switches_are_available: [true; 4],
             }
         }
+
+        fn fail_afdx_switches_of(&mut self, cpiom: &str) {
+            let index = self.index_of(cpiom);
+            self.afdx_switches_are_available[index] = false;
+        }
+
+        fn index_of(&self, cpiom: &str) -> usize {
+            self.cpiom_b
+                .iter()
+                .position(|module| module.name() == cpiom)
+                .unwrap()
+        }
     }
     impl CoreProcessingInputOutputModuleShared for TestAdcn {
         fn core_processing_input_output_module(
@@ -1004,6 +1004,12 @@ mod tests {
                 .find(|&module| module.name() == cpiom)
                 .unwrap()
         }
+
+        fn core_processing_input_output_module_is_connected(&self, cpiom: &str) -> bool {
+            self.core_processing_input_output_module(cpiom)
+                .is_available()
+                && self.afdx_switches_are_available[self.index_of(cpiom)]
+        }
     }
     impl SimulationElement for TestAdcn {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1549,8 +1555,8 @@ mod tests {
             };
             test_aircraft
                 .a380_cabin_air
-                .a320_pressurization_system
-                .active_system = 1;
+                .cpiom_b
+                .set_active_cabin_pressure_control_system(1);
             test_aircraft
         }
 
@@ -2019,6 +2025,16 @@ mod tests {
             self
         }
 
+        fn command_cpiom_failure(mut self, cpiom: &str) -> Self {
+            self.write_by_name(&format!("CPIOM_{}_FAILURE", cpiom), true);
+            self
+        }
+
+        fn command_cpiom_afdx_switches_failure(mut self, cpiom: &str) -> Self {
+            self.command(|a| a.adcn.fail_afdx_switches_of(cpiom));
+            self
+        }
+
         fn command_on_ground(&mut self, on_ground: bool) {
             self.command(|a| a.set_on_ground(on_ground));
         }
@@ -2064,7 +2080,12 @@ mod tests {
         }
 
         fn cabin_altitude(&self) -> Length {
-            self.query(|a| a.a380_cabin_air.a320_pressurization_system.cpc[0].cabin_altitude())
+            self.query(|a| {
+                a.a380_cabin_air
+                    .cpiom_b
+                    .cabin_pressure_controller(1)
+                    .cabin_altitude()
+            })
         }
 
         fn cabin_pressure(&self) -> Pressure {
@@ -2087,28 +2108,53 @@ mod tests {
 
         fn cabin_vs(&self) -> Velocity {
             self.query(|a| {
-                a.a380_cabin_air.a320_pressurization_system.cpc[0].cabin_vertical_speed()
+                a.a380_cabin_air
+                    .cpiom_b
+                    .cabin_pressure_controller(1)
+                    .cabin_vertical_speed()
             })
         }
 
         fn cabin_delta_p(&self) -> Pressure {
-            self.query(|a| a.a380_cabin_air.a320_pressurization_system.cpc[0].cabin_delta_p())
+            self.query(|a| {
+                a.a380_cabin_air
+                    .cpiom_b
+                    .cabin_pressure_controller(1)
+                    .cabin_delta_p()
+            })
         }
 
         fn active_system(&self) -> usize {
-            self.query(|a| a.a380_cabin_air.a320_pressurization_system.active_system)
+            self.query(|a| {
+                a.a380_cabin_air
+                    .cpiom_b
+                    .active_cabin_pressure_control_system()
+            })
         }
 
         fn outflow_valve_open_amount(&self) -> Ratio {
             self.query(|a| {
-                a.a380_cabin_air.a320_pressurization_system.outflow_valve[0].open_amount()
+                a.a380_cabin_air
+                    .a380_pressurization_system
+                    .outflow_valve_open_amount()
+            })
+        }
+
+        fn individual_outflow_valve_open_amount(&self) -> Vec<Ratio> {
+            self.query(|a| {
+                a.a380_cabin_air
+                    .a380_pressurization_system
+                    .outflow_valves()
+                    .iter()
+                    .map(|valve| valve.open_amount())
+                    .collect()
             })
         }
 
         fn safety_valve_open_amount(&self) -> Ratio {
             self.query(|a| {
                 a.a380_cabin_air
-                    .a320_pressurization_system
+                    .a380_pressurization_system
                     .safety_valve
                     .open_amount()
             })
@@ -2126,7 +2172,12 @@ mod tests {
         }
 
         fn landing_elevation(&self) -> Length {
-            self.query(|a| a.a380_cabin_air.a320_pressurization_system.cpc[0].landing_elevation())
+            self.query(|a| {
+                a.a380_cabin_air
+                    .cpiom_b
+                    .cabin_pressure_controller(1)
+                    .landing_elevation()
+            })
         }
 
         fn duct_temperature(&self) -> Vec<ThermodynamicTemperature> {
@@ -2142,7 +2193,12 @@ mod tests {
         }
 
         fn reference_pressure(&self) -> Pressure {
-            self.query(|a| a.a380_cabin_air.a320_pressurization_system.cpc[0].reference_pressure())
+            self.query(|a| {
+                a.a380_cabin_air
+                    .cpiom_b
+                    .cabin_pressure_controller(1)
+                    .reference_pressure()
+            })
         }
 
         fn pack_1_has_fault(&mut self) -> bool {
@@ -2200,11 +2256,9 @@ mod tests {
     }
 
     mod a380_pressurization_tests {
-        // All presurization tests ignored until the A380 pressurization system is modelled
         use super::*;
 
         #[test]
-        #[ignore]
         fn conversion_from_pressure_to_altitude_works() {
             let test_bed = test_bed()
                 .on_ground()
@@ -2222,7 +2276,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn pressure_initialization_works() {
             let test_bed = test_bed()
                 .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
@@ -2247,7 +2300,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn positive_cabin_vs_reduces_cabin_pressure() {
             let test_bed = test_bed()
                 .run_and()
@@ -2259,7 +2311,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn seventy_seconds_after_landing_cpc_switches() {
             let mut test_bed = test_bed_in_descent()
                 .indicated_airspeed_of(Velocity::new::<knot>(99.))
@@ -2279,7 +2330,96 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
+        fn loss_of_both_cpioms_of_active_system_switches_cpc() {
+            let mut test_bed = test_bed_in_cruise();
+
+            assert_eq!(test_bed.active_system(), 1);
+
+            test_bed = test_bed
+                .command_cpiom_failure("B1")
+                .command_cpiom_failure("B3")
+                .iterate(2);
+
+            assert_eq!(test_bed.active_system(), 2);
+        }
+
+        #[test]
+        fn loss_of_one_cpiom_of_active_system_does_not_switch_cpc() {
+            let test_bed = test_bed_in_cruise().command_cpiom_failure("B1").iterate(2);
+
+            assert_eq!(test_bed.active_system(), 1);
+        }
+
+        #[test]
+        fn loss_of_afdx_switches_of_active_system_switches_cpc() {
+            let test_bed = test_bed_in_cruise()
+                .command_cpiom_afdx_switches_failure("B1")
+                .command_cpiom_afdx_switches_failure("B3")
+                .iterate(2);
+
+            assert_eq!(test_bed.active_system(), 2);
+        }
+
+        #[test]
+        fn cpc_does_not_switch_to_system_without_available_cpioms() {
+            let test_bed = test_bed_in_descent()
+                .command_cpiom_failure("B2")
+                .command_cpiom_failure("B4")
+                .indicated_airspeed_of(Velocity::new::<knot>(99.))
+                .then()
+                .set_on_ground()
+                .iterate(71);
+
+            assert_eq!(test_bed.active_system(), 1);
+        }
+
+        #[test]
+        fn outflow_valves_are_frozen_when_all_cpioms_are_lost() {
+            let test_bed = test_bed_in_cruise()
+                .command_cpiom_failure("B1")
+                .command_cpiom_failure("B2")
+                .command_cpiom_failure("B3")
+                .command_cpiom_failure("B4")
+                .iterate(2)
+                .memorize_outflow_valve_open_amount()
+                .then()
+                .command_packs_on_off(false)
+                .iterate(100);
+
+            assert_eq!(
+                test_bed.outflow_valve_open_amount(),
+                test_bed.initial_outflow_valve_open_amount()
+            );
+        }
+
+        #[test]
+        fn outflow_valves_can_be_moved_in_manual_mode_when_all_cpioms_are_lost() {
+            let test_bed = test_bed()
+                .command_cpiom_failure("B1")
+                .command_cpiom_failure("B2")
+                .command_cpiom_failure("B3")
+                .command_cpiom_failure("B4")
+                .iterate(10)
+                .command_mode_sel_pb_man()
+                .command_man_vs_switch_position(2)
+                .iterate(10);
+
+            assert!(test_bed.outflow_valve_open_amount() < Ratio::new::<percent>(100.));
+        }
+
+        #[test]
+        fn all_four_outflow_valves_are_controlled_together() {
+            let test_bed = test_bed_in_cruise().iterate(10);
+
+            let open_amounts = test_bed.individual_outflow_valve_open_amount();
+
+            assert_eq!(open_amounts.len(), 4);
+            assert!(open_amounts.iter().all(|&open_amount| open_amount
+                == test_bed.outflow_valve_open_amount()
+                && open_amount < Ratio::new::<percent>(100.)));
+        }
+
+        #[test]
         fn fifty_five_seconds_after_landing_outflow_valve_opens() {
             let mut test_bed = test_bed_in_descent()
                 .indicated_airspeed_of(Velocity::new::<knot>(99.))
@@ -2299,7 +2439,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn going_to_ground_and_ground_again_resets_valve_opening() {
             let mut test_bed = test_bed_in_descent()
                 .indicated_airspeed_of(Velocity::new::<knot>(99.))
@@ -2334,7 +2473,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn outflow_valve_closes_when_ditching_pb_is_on() {
             let mut test_bed = test_bed().iterate(50);
 
@@ -2346,7 +2484,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn fifty_five_seconds_after_landing_outflow_valve_doesnt_open_if_ditching_pb_is_on() {
             let mut test_bed = test_bed_in_descent()
                 .indicated_airspeed_of(Velocity::new::<knot>(99.))
@@ -2363,7 +2500,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn fifty_five_seconds_after_landing_outflow_valve_doesnt_open_if_mode_sel_man() {
             let test_bed = test_bed_in_descent()
                 .memorize_outflow_valve_open_amount()
@@ -2380,7 +2516,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn rpcu_opens_ofv_if_mode_sel_man() {
             let test_bed = test_bed_in_descent()
                 .command_mode_sel_pb_man()
@@ -2396,7 +2531,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cpc_man_mode_starts_in_auto() {
             let mut test_bed = test_bed();
 
@@ -2404,7 +2538,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cpc_switches_if_man_mode_is_engaged_for_at_least_10_seconds() {
             let mut test_bed = test_bed();
 
@@ -2421,7 +2554,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cpc_does_not_switch_if_man_mode_is_engaged_for_less_than_10_seconds() {
             let mut test_bed = test_bed();
 
@@ -2438,7 +2570,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cpc_switching_timer_resets() {
             let mut test_bed = test_bed();
 
@@ -2462,7 +2593,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cpc_targets_manual_landing_elev_if_knob_not_in_initial_position() {
             let mut test_bed = test_bed();
 
@@ -2474,7 +2604,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cpc_targets_auto_landing_elev_if_knob_returns_to_initial_position() {
             let mut test_bed = test_bed();
 
@@ -2490,7 +2619,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn aircraft_vs_starts_at_0() {
             let test_bed = test_bed().set_on_ground().iterate(300);
 
@@ -2498,7 +2626,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn outflow_valve_stays_open_on_ground() {
             let mut test_bed = test_bed().set_on_ground().iterate(10);
 
@@ -2516,13 +2643,13 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cabin_vs_changes_to_takeoff() {
+            // The larger A380 cabin takes longer than the A320 one to settle on the takeoff rate
             let test_bed = test_bed()
                 .set_on_ground()
                 .iterate(50)
                 .set_takeoff_power()
-                .iterate_with_delta(400, Duration::from_millis(10));
+                .iterate_with_delta(2000, Duration::from_millis(10));
             assert!(
                 (test_bed.cabin_vs() - Velocity::new::<foot_per_minute>(-400.)).abs()
                     < Velocity::new::<foot_per_minute>(20.)
@@ -2530,7 +2657,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cabin_delta_p_does_not_exceed_0_1_during_takeoff() {
             let test_bed = test_bed()
                 .on_ground()
@@ -2546,7 +2672,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cabin_vs_changes_to_climb() {
             let test_bed = test_bed()
                 .iterate(10)
@@ -2558,7 +2683,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cabin_vs_increases_with_altitude() {
             let test_bed = test_bed()
                 .iterate(10)
@@ -2578,7 +2702,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cabin_vs_changes_to_cruise() {
             let test_bed = test_bed_in_cruise().iterate_with_delta(200, Duration::from_millis(100));
 
@@ -2586,7 +2709,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cabin_vs_maintains_stability_in_cruise() {
             let mut test_bed = test_bed_in_cruise().iterate(400);
 
@@ -2606,7 +2728,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cabin_vs_changes_to_descent() {
             let test_bed = test_bed_in_cruise()
                 .vertical_speed_of(Velocity::new::<foot_per_minute>(-260.))
@@ -2617,7 +2738,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn cabin_vs_changes_to_ground() {
             let test_bed = test_bed_in_descent()
                 .indicated_airspeed_of(Velocity::new::<knot>(99.))
@@ -2632,8 +2752,7 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
-        fn cabin_delta_p_does_not_exceed_8_06_psi_in_climb() {
+        fn cabin_delta_p_does_not_exceed_8_56_psi_in_climb() {
             let test_bed = test_bed()
                 .and_run()
                 .with()
@@ -2645,28 +2764,27 @@ mod tests {
                 .vertical_speed_of(Velocity::default())
                 .iterate(10);
 
-            assert!(test_bed.cabin_delta_p() < Pressure::new::<psi>(8.06));
+            assert!(test_bed.cabin_delta_p() < Pressure::new::<psi>(8.56));
         }
 
         #[test]
-        #[ignore]
         fn outflow_valve_closes_to_compensate_packs_off() {
             let test_bed = test_bed_in_cruise()
                 .iterate(200)
                 .memorize_outflow_valve_open_amount()
+                .memorize_cabin_pressure()
                 .then()
                 .command_packs_on_off(false)
                 .iterate(100);
 
             assert!(
-                (test_bed.initial_outflow_valve_open_amount()
-                    - test_bed.outflow_valve_open_amount())
-                    > Ratio::new::<percent>(5.)
+                test_bed.outflow_valve_open_amount() < test_bed.initial_outflow_valve_open_amount()
             );
+            assert_eq!(test_bed.outflow_valve_open_amount(), Ratio::default());
+            assert!(test_bed.cabin_pressure() < test_bed.initial_pressure());
         }
 
         #[test]
-        #[ignore]
         fn outflow_valve_does_not_move_when_man_mode_engaged() {
             let test_bed = test_bed()
                 .iterate(10)
@@ -2686,7 +2804,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn outflow_valve_responds_to_man_inputs_when_in_man_mode() {
             let test_bed = test_bed_in_cruise()
                 .command_mode_sel_pb_man()
@@ -2701,7 +2818,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn outflow_valve_position_affects_cabin_vs_when_in_man_mode() {
             let test_bed = test_bed()
                 .with()
@@ -2718,7 +2834,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn pressure_builds_up_when_ofv_closed_and_packs_on() {
             let test_bed = test_bed()
                 .iterate(10)
@@ -2733,7 +2848,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn pressure_decreases_when_ofv_closed_and_packs_off() {
             let test_bed = test_bed()
                 .with()
@@ -2752,7 +2866,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn pressure_is_constant_when_ofv_closed_and_packs_off_with_no_delta_p() {
             let test_bed = test_bed()
                 .with()
@@ -2771,7 +2884,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn pressure_never_goes_below_ambient_when_ofv_opens() {
             let test_bed = test_bed()
                 .with()
@@ -2793,12 +2905,11 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
-        fn safety_valve_stays_closed_when_delta_p_is_less_than_8_6_psi() {
+        fn safety_valve_stays_closed_when_delta_p_is_less_than_9_4_psi() {
             let test_bed = test_bed()
                 .ambient_pressure_of(
                     InternationalStandardAtmosphere::pressure_at_altitude(Length::default())
-                        - Pressure::new::<psi>(8.6),
+                        - Pressure::new::<psi>(9.4),
                 )
                 .and_run();
 
@@ -2806,7 +2917,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn safety_valve_stays_closed_when_delta_p_is_less_than_minus_1_psi() {
             let test_bed = test_bed()
                 .ambient_pressure_of(
@@ -2819,8 +2929,7 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
-        fn safety_valve_opens_when_delta_p_above_8_6_psi() {
+        fn safety_valve_opens_when_delta_p_above_9_4_psi() {
             let test_bed = test_bed()
                 .command_mode_sel_pb_man()
                 .and_run()
@@ -2837,7 +2946,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn safety_valve_opens_when_delta_p_below_minus_1_psi() {
             let test_bed = test_bed()
                 .command_mode_sel_pb_man()
@@ -2855,7 +2963,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn safety_valve_closes_when_condition_is_not_met() {
             let mut test_bed = test_bed()
                 .command_mode_sel_pb_man()
@@ -2881,7 +2988,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn opening_doors_affects_cabin_pressure() {
             let test_bed = test_bed_in_cruise()
                 .command_aircraft_climb(Length::new::<foot>(0.), Length::new::<foot>(10000.))
@@ -2901,7 +3007,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn opening_doors_affects_cabin_temperature() {
             let mut test_bed = test_bed()
                 .on_ground()
@@ -2922,7 +3027,6 @@ mod tests {
         }
 
         #[test]
-        #[ignore]
         fn when_on_ground_pressure_diff_is_less_than_excessive() {
             let test_bed = test_bed()
                 .on_ground()
@@ -2932,7 +3036,7 @@ mod tests {
             assert!(
                 test_bed.cabin_delta_p()
                     < Pressure::new::<psi>(
-                        A320PressurizationConstants::EXCESSIVE_RESIDUAL_PRESSURE_WARNING
+                        A380PressurizationConstants::EXCESSIVE_RESIDUAL_PRESSURE_WARNING
                     )
             );
         }
@@ -4,6 +4,7 @@ use crate::systems::{
         avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
         core_processing_input_output_module::CoreProcessingInputOutputModule,
         input_output_module::InputOutputModule,
+        virtual_link::{AfdxEndSystem, AfdxNetwork, AfdxNetworkTopology},
     },
     shared::ElectricalBusType,
     simulation::{
@@ -17,6 +18,9 @@ use std::vec::Vec;
 
 pub(crate) trait CoreProcessingInputOutputModuleShared {
     fn core_processing_input_output_module(&self, cpiom: &str) -> &CoreProcessingInputOutputModule;
+    /// Returns true when the CPIOM is available and at least one of the AFDX switches it is
+    /// connected to is available, i.e. its applications can exchange data with the network.
+    fn core_processing_input_output_module_is_connected(&self, cpiom: &str) -> bool;
 }
 
 struct RoutingTableEntry {
@@ -63,6 +67,9 @@ impl RoutingTableEntry {
 //
 // The routing tables define the upper triangular matrix for the two networks.
 // A breadth-first-search is used to update the routing table per AFDX switch.
+//
+// Virtual links use the ADCN as their network topology to transport data between CPIOMs and IOMs.
+// The length of the shortest path between two switches defines the latency of the frames.
 pub struct A380AvionicsDataCommunicationNetwork {
     afdx_switches: [AvionicsFullDuplexSwitch; 16],
     afdx_networks: [FxHashMap<u8, Vec<u8>>; 2],
@@ -327,53 +334,95 @@ impl A380AvionicsDataCommunicationNetwork {
         }
     }
 
-    fn switches_reachable(
+    // The CPIOMs are connected to one switch of each network
+    fn afdx_switch_ids_of_cpiom(cpiom: &str) -> Option<(u8, u8)> {
+        match cpiom {
+            "A1" | "B1" | "D1" => Some((1, 11)),
+            "A2" | "B2" => Some((2, 12)),
+            "A3" | "B3" | "D3" => Some((3, 13)),
+            "A4" | "B4" => Some((4, 14)),
+            "E1" | "F1" | "F3" => Some((5, 15)),
+            "E2" | "F2" | "F4" => Some((6, 16)),
+            "G1" | "G2" | "G3" | "G4" | "C2" => Some((7, 17)),
+            "C1" => Some((9, 19)),
+            _ => None,
+        }
+    }
+
+    fn afdx_switch_index(id: u8) -> usize {
+        match id {
+            1..=7 => id as usize - 1,
+            9 => 7,
+            11..=17 => id as usize - 3,
+            19 => 15,
+            _ => panic!("Invalid AFDX switch id {}", id),
+        }
+    }
+
+    // The IOMs are connected to one switch of each network
+    fn afdx_switch_ids_of_iom(iom: &str) -> Option<(u8, u8)> {
+        match iom {
+            "A1" => Some((1, 11)),
+            "A2" => Some((2, 12)),
+            "A3" => Some((3, 13)),
+            "A4" => Some((4, 14)),
+            "A5" => Some((5, 15)),
+            "A6" => Some((6, 16)),
+            "A7" => Some((7, 17)),
+            "A8" => Some((9, 19)),
+            _ => None,
+        }
+    }
+
+    // Returns the number of hops on the shortest path between the two switches
+    fn shortest_path_hops(
         afdx_switches: &[AvionicsFullDuplexSwitch; 16],
         network: &FxHashMap<u8, Vec<u8>>,
         from: u8,
         to: u8,
-    ) -> bool {
+    ) -> Option<usize> {
         let mut frontier: VecDeque<u8> = VecDeque::new();
-        let mut visited: Vec<u8> = Vec::new();
+        let mut hops: Vec<Option<usize>> = Vec::new();
 
         if !afdx_switches[from as usize].is_available() {
-            return false;
+            return None;
         }
 
-        visited.resize(network.len() * 2, 0xff);
+        hops.resize(network.len() * 2, None);
         frontier.push_front(from);
-        visited[from as usize] = from;
-
-        while !frontier.is_empty() {
-            let node = frontier.pop_front().unwrap();
+        hops[from as usize] = Some(0);
 
+        while let Some(node) = frontier.pop_front() {
             if node == to {
-                return true;
+                return hops[node as usize];
             }
 
             let neighbors = &network[&node];
             for &neighbor in neighbors {
                 if afdx_switches[neighbor as usize].is_available()
-                    && visited[neighbor as usize] == 0xff
+                    && hops[neighbor as usize].is_none()
                 {
-                    visited[neighbor as usize] = node;
+                    hops[neighbor as usize] = hops[node as usize].map(|hops| hops + 1);
                     frontier.push_back(neighbor);
                 }
             }
         }
 
-        false
+        None
     }
 
     fn update_routing_table(&mut self, network: usize, offset: usize) {
         for (y, row) in self.routing_tables[network].iter_mut().enumerate() {
             for (x, entry) in row.iter_mut().enumerate() {
-                entry.set_reachable(Self::switches_reachable(
-                    &self.afdx_switches,
-                    &self.afdx_networks[network],
-                    (y + offset) as u8,
-                    (x + offset) as u8,
-                ));
+                entry.set_reachable(
+                    Self::shortest_path_hops(
+                        &self.afdx_switches,
+                        &self.afdx_networks[network],
+                        (y + offset) as u8,
+                        (x + offset) as u8,
+                    )
+                    .is_some(),
+                );
             }
         }
     }
@@ -413,6 +462,68 @@ impl CoreProcessingInputOutputModuleShared for A380AvionicsDataCommunicationNetw
             .find(|&module| module.name() == cpiom)
             .unwrap()
     }
+
+    fn core_processing_input_output_module_is_connected(&self, cpiom: &str) -> bool {
+        self.core_processing_input_output_module(cpiom)
+            .is_available()
+            && match Self::afdx_switch_ids_of_cpiom(cpiom) {
+                Some((network_a, network_b)) => [network_a, network_b]
+                    .iter()
+                    .any(|&id| self.afdx_switches[Self::afdx_switch_index(id)].is_available()),
+                None => false,
+            }
+    }
+}
+
+impl AfdxNetworkTopology for A380AvionicsDataCommunicationNetwork {
+    // CPIOMs and IOMs are named by their type, e.g. CPIOM_B1 or IOM_A1
+    fn end_system(&self, name: &'static str) -> Option<AfdxEndSystem> {
+        let switch_ids = if let Some(cpiom) = name.strip_prefix("CPIOM_") {
+            Self::afdx_switch_ids_of_cpiom(cpiom)
+        } else if let Some(iom) = name.strip_prefix("IOM_") {
+            Self::afdx_switch_ids_of_iom(iom)
+        } else {
+            None
+        };
+
+        switch_ids.map(|(network_a, network_b)| AfdxEndSystem::new(name, network_a, network_b))
+    }
+
+    fn end_system_is_available(&self, end_system: &AfdxEndSystem) -> bool {
+        if let Some(cpiom) = end_system.name().strip_prefix("CPIOM_") {
+            self.core_processing_input_output_module(cpiom)
+                .is_available()
+        } else if let Some(iom) = end_system.name().strip_prefix("IOM_") {
+            self.io_modules
+                .iter()
+                .any(|module| module.name() == iom && module.is_available())
+        } else {
+            false
+        }
+    }
+
+    fn switch_hops(
+        &self,
+        network: AfdxNetwork,
+        from_switch_id: u8,
+        to_switch_id: u8,
+    ) -> Option<usize> {
+        let network_index = network as usize;
+        let from = Self::afdx_switch_index(from_switch_id);
+        let to = Self::afdx_switch_index(to_switch_id);
+
+        // Switches of the other network are not reachable
+        if from / 8 != network_index || to / 8 != network_index {
+            return None;
+        }
+
+        Self::shortest_path_hops(
+            &self.afdx_switches,
+            &self.afdx_networks[network_index],
+            from as u8,
+            to as u8,
+        )
+    }
 }
 
 impl SimulationElement for A380AvionicsDataCommunicationNetwork {
@@ -438,6 +549,7 @@ impl SimulationElement for A380AvionicsDataCommunicationNetwork {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::systems::integrated_modular_avionics::virtual_link::VirtualLink;
     use crate::systems::{
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         shared::PotentialOrigin,
@@ -446,10 +558,13 @@ mod tests {
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
+    use std::time::Duration;
     use uom::si::{electric_potential::volt, f64::*};
 
     struct AdcnTestAircraft {
         adcn: A380AvionicsDataCommunicationNetwork,
+        virtual_link: VirtualLink<f64>,
+        fuel_virtual_link: VirtualLink<f64>,
         powered_source_dc: TestElectricitySource,
         dc_1_bus: ElectricalBus,
         dc_2_bus: ElectricalBus,
@@ -458,8 +573,25 @@ mod tests {
     }
     impl AdcnTestAircraft {
         fn new(context: &mut InitContext) -> Self {
+            let adcn = A380AvionicsDataCommunicationNetwork::new(context);
+            let mut virtual_link = VirtualLink::new(
+                1,
+                Duration::from_millis(32),
+                adcn.end_system("CPIOM_B1").unwrap(),
+            );
+            virtual_link.subscribe(adcn.end_system("IOM_A4").unwrap());
+
+            let mut fuel_virtual_link = VirtualLink::new(
+                2,
+                Duration::from_millis(32),
+                adcn.end_system("CPIOM_F1").unwrap(),
+            );
+            fuel_virtual_link.subscribe(adcn.end_system("CPIOM_G1").unwrap());
+
             Self {
-                adcn: A380AvionicsDataCommunicationNetwork::new(context),
+                adcn,
+                virtual_link,
+                fuel_virtual_link,
                 powered_source_dc: TestElectricitySource::powered(
                     context,
                     PotentialOrigin::Battery(2),
@@ -471,8 +603,10 @@ mod tests {
             }
         }
 
-        fn update(&mut self) {
+        fn update(&mut self, context: &UpdateContext) {
             self.adcn.update();
+            self.virtual_link.update(context, &self.adcn);
+            self.fuel_virtual_link.update(context, &self.adcn);
         }
 
         fn set_elec_powered(&mut self, is_powered: bool) {
@@ -496,8 +630,8 @@ mod tests {
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for AdcnTestAircraft {
@@ -664,4 +798,215 @@ mod tests {
         reachable = test_bed.read_by_name("AFDX_1_9_REACHABLE");
         assert!(!reachable);
     }
+
+    #[test]
+    fn cpiom_b_is_connected_when_powered() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+
+        ["B1", "B2", "B3", "B4"].iter().for_each(|cpiom| {
+            assert!(test_bed.query(|a| a
+                .adcn
+                .core_processing_input_output_module_is_connected(cpiom)));
+        });
+    }
+
+    #[test]
+    fn cpiom_b_is_not_connected_without_power() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.run();
+
+        ["B1", "B2", "B3", "B4"].iter().for_each(|cpiom| {
+            assert!(!test_bed.query(|a| a
+                .adcn
+                .core_processing_input_output_module_is_connected(cpiom)));
+        });
+    }
+
+    #[test]
+    fn cpiom_b_remains_connected_with_one_failed_switch() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("AFDX_SWITCH_1_FAILURE", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a
+            .adcn
+            .core_processing_input_output_module_is_connected("B1")));
+    }
+
+    #[test]
+    fn cpiom_b_is_not_connected_when_both_switches_failed() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("AFDX_SWITCH_1_FAILURE", true);
+        test_bed.write_by_name("AFDX_SWITCH_11_FAILURE", true);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a
+            .adcn
+            .core_processing_input_output_module_is_connected("B1")));
+        assert!(test_bed.query(|a| a
+            .adcn
+            .core_processing_input_output_module_is_connected("B2")));
+    }
+
+    #[test]
+    fn switch_hops_follow_the_shortest_available_path() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.adcn.switch_hops(AfdxNetwork::A, 1, 5)),
+            Some(2)
+        );
+
+        test_bed.write_by_name("AFDX_SWITCH_3_FAILURE", true);
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.adcn.switch_hops(AfdxNetwork::A, 1, 5)),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn switches_of_different_networks_do_not_reach_each_other() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.adcn.switch_hops(AfdxNetwork::A, 1, 11)),
+            None
+        );
+    }
+
+    #[test]
+    fn virtual_link_transports_data_from_cpiom_to_iom() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.virtual_link.publish(42.));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link.received_value("IOM_A4")),
+            Some(42.)
+        );
+    }
+
+    #[test]
+    fn virtual_link_uses_network_b_when_network_a_switch_failed() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("AFDX_SWITCH_1_FAILURE", true);
+        test_bed.command(|a| a.virtual_link.publish(42.));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link.received_value("IOM_A4")),
+            Some(42.)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link.port("IOM_A4").last_network()),
+            Some(AfdxNetwork::B)
+        );
+    }
+
+    #[test]
+    fn virtual_link_loses_data_when_both_networks_are_unreachable() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.virtual_link.publish(42.));
+        test_bed.run();
+
+        test_bed.write_by_name("AFDX_SWITCH_1_FAILURE", true);
+        test_bed.write_by_name("AFDX_SWITCH_11_FAILURE", true);
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link.received_value("IOM_A4")),
+            None
+        );
+    }
+
+    #[test]
+    fn virtual_link_does_not_transport_data_without_power() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.virtual_link.publish(42.));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.virtual_link.received_value("IOM_A4")),
+            None
+        );
+    }
+
+    #[test]
+    fn every_cpiom_and_iom_is_an_end_system() {
+        let test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        [
+            "CPIOM_A1", "CPIOM_A2", "CPIOM_A3", "CPIOM_A4", "CPIOM_B1", "CPIOM_B2", "CPIOM_B3",
+            "CPIOM_B4", "CPIOM_C1", "CPIOM_C2", "CPIOM_D1", "CPIOM_D3", "CPIOM_E1", "CPIOM_E2",
+            "CPIOM_F1", "CPIOM_F2", "CPIOM_F3", "CPIOM_F4", "CPIOM_G1", "CPIOM_G2", "CPIOM_G3",
+            "CPIOM_G4", "IOM_A1", "IOM_A2", "IOM_A3", "IOM_A4", "IOM_A5", "IOM_A6", "IOM_A7",
+            "IOM_A8",
+        ]
+        .iter()
+        .for_each(|&name| assert!(test_bed.query(|a| a.adcn.end_system(name)).is_some()));
+    }
+
+    #[test]
+    fn unknown_end_system_is_not_connected() {
+        let test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        assert_eq!(test_bed.query(|a| a.adcn.end_system("CPIOM_Z1")), None);
+        assert_eq!(test_bed.query(|a| a.adcn.end_system("FCDC_1")), None);
+    }
+
+    #[test]
+    fn every_cpiom_is_connected_when_powered() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+
+        [
+            "A1", "A2", "A3", "A4", "B1", "B2", "B3", "B4", "C1", "C2", "D1", "D3", "E1", "E2",
+            "F1", "F3", "G1", "G2", "G3", "G4",
+        ]
+        .iter()
+        .for_each(|cpiom| {
+            assert!(test_bed.query(|a| a
+                .adcn
+                .core_processing_input_output_module_is_connected(cpiom)));
+        });
+    }
+
+    #[test]
+    fn virtual_link_transports_data_between_cpioms_of_other_types() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.fuel_virtual_link.publish(42.));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.fuel_virtual_link.received_value("CPIOM_G1")),
+            Some(42.)
+        );
+    }
 }
@@ -1,12 +1,15 @@
 use crate::systems::{
     accept_iterable,
-    indicating_recording::controls::keyboard_cursor_control_unit::KeyboardCursorControlUnit,
+    indicating_recording::{
+        controls::keyboard_cursor_control_unit::KeyboardCursorControlUnit,
+        display_unit::{DisplayFormat, DisplayUnit, KeyboardCursorControlledDisplayUnits},
+    },
     shared::{can_bus::CanBus, ElectricalBusType},
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
 // they are not the same values as the real IDs
-// PFD and ND are missing
+// PFD and ND do not take part in the communication and only listen to the buses
 enum CanBusFunctionIds {
     KccuKeyboard = 42,
     KccuCursorControl = 43,
@@ -23,15 +26,23 @@ pub struct A380ControlDisplaySystem {
     can_bus_2: [CanBus<5>; 2],
     kccu_capt: KeyboardCursorControlUnit,
     kccu_fo: KeyboardCursorControlUnit,
+    display_units_capt: KeyboardCursorControlledDisplayUnits<3>,
+    display_units_fo: KeyboardCursorControlledDisplayUnits<3>,
+    engine_warning_display: DisplayUnit,
+    system_display: DisplayUnit,
 }
 
 impl A380ControlDisplaySystem {
+    const OUTER_DISPLAY_UNIT: usize = 0;
+    const INNER_DISPLAY_UNIT: usize = 1;
+
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             can_bus_1: [
                 CanBus::new(
                     context,
                     "CDS_CAN_BUS_1_1",
+                    1,
                     [
                         CanBusFunctionIds::KccuKeyboard as u8,
                         CanBusFunctionIds::KccuCursorControl as u8,
@@ -43,6 +54,7 @@ impl A380ControlDisplaySystem {
                 CanBus::new(
                     context,
                     "CDS_CAN_BUS_1_2",
+                    2,
                     [
                         CanBusFunctionIds::KccuKeyboard as u8,
                         CanBusFunctionIds::KccuCursorControl as u8,
@@ -56,6 +68,7 @@ impl A380ControlDisplaySystem {
                 CanBus::new(
                     context,
                     "CDS_CAN_BUS_2_1",
+                    3,
                     [
                         CanBusFunctionIds::KccuKeyboard as u8,
                         CanBusFunctionIds::KccuCursorControl as u8,
@@ -67,6 +80,7 @@ impl A380ControlDisplaySystem {
                 CanBus::new(
                     context,
                     "CDS_CAN_BUS_2_2",
+                    4,
                     [
                         CanBusFunctionIds::KccuKeyboard as u8,
                         CanBusFunctionIds::KccuCursorControl as u8,
@@ -94,19 +108,121 @@ impl A380ControlDisplaySystem {
                 ElectricalBusType::DirectCurrent(1),
                 ElectricalBusType::DirectCurrent(2),
             ),
+            display_units_capt: Self::side_display_units(
+                context,
+                "CAPT",
+                1,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::DirectCurrentEssential,
+                ],
+            ),
+            display_units_fo: Self::side_display_units(
+                context,
+                "FO",
+                6,
+                [
+                    ElectricalBusType::DirectCurrent(2),
+                    ElectricalBusType::DirectCurrent(2),
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            engine_warning_display: DisplayUnit::new(
+                context,
+                "EWD",
+                4,
+                DisplayFormat::EngineWarningDisplay,
+                ElectricalBusType::DirectCurrentEssential,
+            ),
+            system_display: DisplayUnit::new(
+                context,
+                "SD",
+                5,
+                DisplayFormat::SystemDisplay,
+                ElectricalBusType::DirectCurrent(2),
+            ),
         }
     }
 
-    pub fn update(&mut self) {
+    fn side_display_units(
+        context: &mut InitContext,
+        side: &str,
+        first_number: usize,
+        power_supplies: [ElectricalBusType; 3],
+    ) -> KeyboardCursorControlledDisplayUnits<3> {
+        KeyboardCursorControlledDisplayUnits::new(
+            [
+                DisplayUnit::new(
+                    context,
+                    &format!("{}_OUTER", side),
+                    first_number,
+                    DisplayFormat::PrimaryFlightDisplay,
+                    power_supplies[0],
+                ),
+                DisplayUnit::new(
+                    context,
+                    &format!("{}_INNER", side),
+                    first_number + 1,
+                    DisplayFormat::NavigationDisplay,
+                    power_supplies[1],
+                ),
+                DisplayUnit::new(
+                    context,
+                    &format!("{}_MFD", side),
+                    first_number + 2,
+                    DisplayFormat::MultiFunctionDisplay,
+                    power_supplies[2],
+                ),
+            ],
+            CanBusFunctionIds::KccuKeyboard as u8,
+            CanBusFunctionIds::KccuCursorControl as u8,
+        )
+    }
+
+    // The PFD is transferred to the inner display unit when the outer one fails
+    fn reconfigure_side(display_units: &mut KeyboardCursorControlledDisplayUnits<3>) {
+        let inner_format = if display_units
+            .display_unit(Self::OUTER_DISPLAY_UNIT)
+            .is_available()
+        {
+            DisplayFormat::NavigationDisplay
+        } else {
+            DisplayFormat::PrimaryFlightDisplay
+        };
+
+        display_units
+            .display_unit_mut(Self::INNER_DISPLAY_UNIT)
+            .show(inner_format);
+    }
+
+    // The EWD is transferred to the SD display unit when its own display unit fails
+    fn reconfigure_center(&mut self) {
+        if self.engine_warning_display.is_available() {
+            self.system_display.show_nominal_format();
+        } else {
+            self.system_display
+                .show(DisplayFormat::EngineWarningDisplay);
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
         self.kccu_capt.update(&mut self.can_bus_1);
         self.kccu_fo.update(&mut self.can_bus_2);
 
         self.can_bus_1.iter_mut().for_each(|bus| {
-            bus.update();
+            bus.update(context);
         });
         self.can_bus_2.iter_mut().for_each(|bus| {
-            bus.update();
+            bus.update(context);
         });
+
+        Self::reconfigure_side(&mut self.display_units_capt);
+        Self::reconfigure_side(&mut self.display_units_fo);
+        self.reconfigure_center();
+
+        self.display_units_capt.update(&self.can_bus_1);
+        self.display_units_fo.update(&self.can_bus_2);
     }
 }
 
@@ -116,6 +232,140 @@ impl SimulationElement for A380ControlDisplaySystem {
         accept_iterable!(self.can_bus_2, visitor);
         self.kccu_capt.accept(visitor);
         self.kccu_fo.accept(visitor);
+        self.display_units_capt.accept(visitor);
+        self.display_units_fo.accept(visitor);
+        self.engine_warning_display.accept(visitor);
+        self.system_display.accept(visitor);
         visitor.visit(self);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        failures::FailureType,
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft,
+        },
+    };
+    use uom::si::{electric_potential::volt, f64::ElectricPotential};
+
+    struct CdsTestAircraft {
+        cds: A380ControlDisplaySystem,
+        powered_source_dc: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+        dc_ess_bus: ElectricalBus,
+    }
+    impl CdsTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                cds: A380ControlDisplaySystem::new(context),
+                powered_source_dc: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(2),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+            }
+        }
+    }
+    impl Aircraft for CdsTestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.powered_source_dc
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.powered_source_dc);
+            electricity.flow(&self.powered_source_dc, &self.dc_1_bus);
+            electricity.flow(&self.powered_source_dc, &self.dc_2_bus);
+            electricity.flow(&self.powered_source_dc, &self.dc_ess_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.cds.update(context);
+        }
+    }
+    impl SimulationElement for CdsTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.cds.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    fn displayed_format(test_bed: &mut SimulationTestBed<CdsTestAircraft>, unit: &str) -> u8 {
+        test_bed.read_by_name(&format!("CDS_DU_{}_FORMAT", unit))
+    }
+
+    #[test]
+    fn display_units_show_their_nominal_formats() {
+        let mut test_bed = SimulationTestBed::new(CdsTestAircraft::new);
+        test_bed.run();
+
+        assert_eq!(
+            displayed_format(&mut test_bed, "CAPT_OUTER"),
+            DisplayFormat::PrimaryFlightDisplay as u8
+        );
+        assert_eq!(
+            displayed_format(&mut test_bed, "CAPT_INNER"),
+            DisplayFormat::NavigationDisplay as u8
+        );
+        assert_eq!(
+            displayed_format(&mut test_bed, "SD"),
+            DisplayFormat::SystemDisplay as u8
+        );
+    }
+
+    #[test]
+    fn pfd_is_transferred_to_the_inner_display_unit() {
+        let mut test_bed = SimulationTestBed::new(CdsTestAircraft::new);
+        test_bed.fail(FailureType::DisplayUnit(6));
+        test_bed.run();
+
+        assert_eq!(displayed_format(&mut test_bed, "FO_OUTER"), 0);
+        assert_eq!(
+            displayed_format(&mut test_bed, "FO_INNER"),
+            DisplayFormat::PrimaryFlightDisplay as u8
+        );
+        assert_eq!(
+            displayed_format(&mut test_bed, "CAPT_INNER"),
+            DisplayFormat::NavigationDisplay as u8
+        );
+    }
+
+    #[test]
+    fn ewd_is_transferred_to_the_system_display_unit() {
+        let mut test_bed = SimulationTestBed::new(CdsTestAircraft::new);
+        test_bed.fail(FailureType::DisplayUnit(4));
+        test_bed.run();
+
+        assert_eq!(
+            displayed_format(&mut test_bed, "SD"),
+            DisplayFormat::EngineWarningDisplay as u8
+        );
+
+        test_bed.unfail(FailureType::DisplayUnit(4));
+        test_bed.run();
+
+        assert_eq!(
+            displayed_format(&mut test_bed, "SD"),
+            DisplayFormat::SystemDisplay as u8
+        );
+    }
+
+    #[test]
+    fn cursor_is_on_the_navigation_display_after_power_up() {
+        let mut test_bed = SimulationTestBed::new(CdsTestAircraft::new);
+        test_bed.run();
+
+        let has_cursor: bool = test_bed.read_by_name("CDS_DU_CAPT_INNER_CURSOR");
+        assert!(has_cursor);
+    }
+}
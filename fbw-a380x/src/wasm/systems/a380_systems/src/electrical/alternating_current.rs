@@ -9,7 +9,7 @@ use systems::simulation::InitContext;
 use systems::{
     electrical::{
         AlternatingCurrentElectricalSystem, Contactor, ElectricalBus, Electricity,
-        EmergencyGenerator, EngineGenerator, ExternalPowerSource, TransformerRectifier,
+        EmergencyGenerator, ExternalPowerSource, TransformerRectifier, VariableFrequencyGenerator,
     },
     shared::{
         AuxiliaryPowerUnitElectrical, ElectricalBusType, EngineCorrectedN2, EngineFirePushButtons,
@@ -275,7 +275,7 @@ impl SimulationElement for A380AlternatingCurrentElectrical {
 }
 
 struct A380MainPowerSources {
-    engine_gens: [EngineGenerator; 4],
+    engine_gens: [VariableFrequencyGenerator; 4],
     engine_generator_contactors: [Contactor; 4],
     bus_tie_contactors: [Contactor; 6],
     // FCOM: BTC7
@@ -376,7 +376,7 @@ impl A380MainPowerSources {
 
     fn new(context: &mut InitContext) -> Self {
         A380MainPowerSources {
-            engine_gens: [1, 2, 3, 4].map(|i| EngineGenerator::new(context, i)),
+            engine_gens: [1, 2, 3, 4].map(|i| VariableFrequencyGenerator::new(context, i)),
             engine_generator_contactors: [1, 2, 3, 4]
                 .map(|id| Contactor::new(context, &format!("990XU{id}"))),
             bus_tie_contactors: [1, 2, 3, 4, 5, 6]
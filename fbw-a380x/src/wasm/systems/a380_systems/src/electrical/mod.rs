@@ -545,7 +545,8 @@ mod a380_electrical_circuit_tests {
         electrical::{
             ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
             Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+            ProvideLoad, ProvidePotential,
+            VARIABLE_FREQUENCY_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
         },
         failures::FailureType,
         shared::{
@@ -681,6 +682,32 @@ mod a380_electrical_circuit_tests {
             .is_single(PotentialOrigin::TransformerRectifier(2)));
     }
 
+    #[test]
+    fn engine_generator_over_frequency_trip_is_reset_by_cycling_the_gen_push_button() {
+        let mut test_bed = test_bed_with()
+            .all_bats_auto()
+            .and()
+            .running_engines()
+            .and()
+            .overspeeding_engine(1)
+            .run();
+
+        assert!(test_bed.gen_has_fault(1));
+
+        test_bed = test_bed.running_engine(1);
+        assert!(test_bed.gen_has_fault(1));
+
+        test_bed = test_bed.gen_off(1).run().then_continue_with().gen_on(1);
+        test_bed = test_bed.run_waiting_for(Duration::from_millis(
+            VARIABLE_FREQUENCY_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+        ));
+
+        assert!(!test_bed.gen_has_fault(1));
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::EngineGenerator(1)));
+    }
+
     /// # Source
     /// A380 FCOM
     #[test]
@@ -1310,6 +1337,26 @@ mod a380_electrical_circuit_tests {
             .is_single(PotentialOrigin::TransformerRectifier(2)));
     }
 
+    #[test]
+    fn engine_generator_frequency_follows_engine_speed() {
+        let mut test_bed = test_bed_with()
+            .all_bats_auto()
+            .and()
+            .running_engines()
+            .run();
+
+        for i in 1..=4 {
+            assert_eq!(
+                test_bed.engine_generator_frequency(i),
+                Frequency::new::<hertz>(640.)
+            );
+            assert_eq!(
+                test_bed.ac_bus_output(i as u8).frequency(),
+                Frequency::new::<hertz>(640.)
+            );
+        }
+    }
+
     /// # Source
     /// A380 FCOM
     #[test]
@@ -2816,6 +2863,15 @@ mod a380_electrical_circuit_tests {
             self.is_available
         }
     }
+    impl ProvideLoad for TestApuGenerator {
+        fn load(&self) -> Ratio {
+            Ratio::new::<percent>(0.)
+        }
+
+        fn load_normal(&self) -> bool {
+            true
+        }
+    }
     impl ProvideFrequency for TestApuGenerator {
         fn frequency(&self) -> Frequency {
             if self.is_available {
@@ -2878,20 +2934,26 @@ mod a380_electrical_circuit_tests {
 
     #[derive(Clone, Copy)]
     struct TestEngine {
-        is_running: bool,
+        corrected_n2: Ratio,
     }
     impl TestEngine {
         fn new() -> Self {
-            Self { is_running: false }
+            Self {
+                corrected_n2: Ratio::new::<percent>(0.),
+            }
         }
 
         fn run(&mut self) {
-            self.is_running = true;
+            self.corrected_n2 = Ratio::new::<percent>(80.);
+        }
+
+        fn overspeed(&mut self) {
+            self.corrected_n2 = Ratio::new::<percent>(105.);
         }
     }
     impl EngineCorrectedN2 for TestEngine {
         fn corrected_n2(&self) -> Ratio {
-            Ratio::new::<percent>(if self.is_running { 80. } else { 0. })
+            self.corrected_n2
         }
     }
 
@@ -3065,6 +3127,10 @@ mod a380_electrical_circuit_tests {
             self.engines[number - 1].run();
         }
 
+        fn overspeeding_engine(&mut self, number: usize) {
+            self.engines[number - 1].overspeed();
+        }
+
         fn running_apu(&mut self) {
             self.apu.set_available(true);
         }
@@ -3208,13 +3274,18 @@ mod a380_electrical_circuit_tests {
 
             self = self.without_triggering_emergency_elec(|x| {
                 x.run_waiting_for(Duration::from_millis(
-                    INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+                    VARIABLE_FREQUENCY_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
                 ))
             });
 
             self
         }
 
+        fn overspeeding_engine(mut self, number: usize) -> Self {
+            self.command(|a| a.overspeeding_engine(number));
+            self
+        }
+
         fn running_engines(self) -> Self {
             self.running_engine(1)
                 .and()
@@ -3329,6 +3400,11 @@ mod a380_electrical_circuit_tests {
             self
         }
 
+        fn gen_on(mut self, number: usize) -> Self {
+            self.write_by_name(&format!("OVHD_ELEC_ENG_GEN_{}_PB_IS_ON", number), true);
+            self
+        }
+
         fn released_engine_fire_push_button(mut self, engine_number: usize) -> Self {
             self.command(|a| a.release_engine_fire_push_button(engine_number));
             self
@@ -3544,6 +3620,10 @@ mod a380_electrical_circuit_tests {
             self.read_by_name(&format!("OVHD_ELEC_ENG_GEN_{}_PB_HAS_FAULT", number))
         }
 
+        fn engine_generator_frequency(&mut self, number: usize) -> Frequency {
+            self.read_by_name(&format!("ELEC_ENG_GEN_{}_FREQUENCY", number))
+        }
+
         fn rat_and_emer_gen_has_fault(&mut self) -> bool {
             self.read_by_name("OVHD_EMER_ELEC_RAT_AND_EMER_GEN_HAS_FAULT")
         }
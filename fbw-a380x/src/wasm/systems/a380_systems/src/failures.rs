@@ -0,0 +1,281 @@
+use systems::{
+    failures::FailureType,
+    shared::{ElectricalBusType, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId},
+};
+
+/// The failures which can be activated on the A380, by the identifiers used within MSFS.
+pub const FAILURES: &[(u64, FailureType)] = &[
+    (24_000, FailureType::TransformerRectifier(1)),
+    (24_001, FailureType::TransformerRectifier(2)),
+    (24_002, FailureType::TransformerRectifier(3)),
+    (24_004, FailureType::StaticInverter),
+    (24_020, FailureType::Generator(1)),
+    (24_021, FailureType::Generator(2)),
+    (24_022, FailureType::Generator(3)),
+    (24_023, FailureType::Generator(4)),
+    (24_030, FailureType::ApuGenerator(1)),
+    (24_031, FailureType::ApuGenerator(2)),
+    (
+        24_100,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
+    ),
+    (
+        24_101,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
+    ),
+    (
+        24_102,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(3)),
+    ),
+    (
+        24_103,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(4)),
+    ),
+    (
+        24_104,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
+    ),
+    (
+        24_105,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
+    ),
+    (
+        24_106,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentNamed("247XP")),
+    ),
+    (
+        24_107,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
+    ),
+    (
+        24_108,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
+    ),
+    (
+        24_109,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
+    ),
+    (
+        24_110,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
+    ),
+    (
+        24_111,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("247PP")),
+    ),
+    (
+        24_112,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("309PP")),
+    ),
+    (
+        24_113,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
+    ),
+    (
+        24_114,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
+    ),
+    (
+        24_115,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(3)),
+    ),
+    (
+        24_116,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(4)),
+    ),
+    (
+        24_117,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
+    ),
+    (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+    (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
+    (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+    (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+    (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
+    (
+        29_005,
+        FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_006,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+    ),
+    (
+        29_007,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
+    ),
+    (
+        29_008,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+    ),
+    (31_000, FailureType::CanBusCorruption(1)),
+    (31_001, FailureType::CanBusCorruption(2)),
+    (31_002, FailureType::CanBusCorruption(3)),
+    (31_003, FailureType::CanBusCorruption(4)),
+    (31_010, FailureType::CanBusTransmitter(1, 42)),
+    (31_011, FailureType::CanBusTransmitter(1, 43)),
+    (31_012, FailureType::CanBusTransmitter(1, 46)),
+    (31_013, FailureType::CanBusTransmitter(1, 47)),
+    (31_014, FailureType::CanBusTransmitter(1, 48)),
+    (31_020, FailureType::CanBusTransmitter(2, 42)),
+    (31_021, FailureType::CanBusTransmitter(2, 43)),
+    (31_022, FailureType::CanBusTransmitter(2, 46)),
+    (31_023, FailureType::CanBusTransmitter(2, 47)),
+    (31_024, FailureType::CanBusTransmitter(2, 48)),
+    (31_030, FailureType::CanBusTransmitter(3, 42)),
+    (31_031, FailureType::CanBusTransmitter(3, 43)),
+    (31_032, FailureType::CanBusTransmitter(3, 46)),
+    (31_033, FailureType::CanBusTransmitter(3, 47)),
+    (31_034, FailureType::CanBusTransmitter(3, 48)),
+    (31_040, FailureType::CanBusTransmitter(4, 42)),
+    (31_041, FailureType::CanBusTransmitter(4, 43)),
+    (31_042, FailureType::CanBusTransmitter(4, 46)),
+    (31_043, FailureType::CanBusTransmitter(4, 47)),
+    (31_044, FailureType::CanBusTransmitter(4, 48)),
+    (31_101, FailureType::DisplayUnit(1)),
+    (31_102, FailureType::DisplayUnit(2)),
+    (31_103, FailureType::DisplayUnit(3)),
+    (31_104, FailureType::DisplayUnit(4)),
+    (31_105, FailureType::DisplayUnit(5)),
+    (31_106, FailureType::DisplayUnit(6)),
+    (31_107, FailureType::DisplayUnit(7)),
+    (31_108, FailureType::DisplayUnit(8)),
+    (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+    (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+    (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+    (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+    (
+        32_004,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+    ),
+    (
+        32_005,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+    ),
+    (
+        32_006,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+    ),
+    (
+        32_007,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+    ),
+    (
+        32_008,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+    ),
+    (
+        32_009,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+    ),
+    (
+        32_010,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+    ),
+    (
+        32_011,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+    ),
+    (
+        32_012,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+    ),
+    (
+        32_013,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+    ),
+    (
+        32_014,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+    ),
+    (
+        32_015,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+    ),
+    (
+        32_020,
+        FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+    ),
+    (
+        32_021,
+        FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+    ),
+    (
+        32_022,
+        FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+    ),
+    (
+        32_023,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+    ),
+    (
+        32_024,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+    ),
+    (
+        32_025,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+    ),
+    (32_030, FailureType::BodyWheelSteering),
+    (32_200, FailureType::TyreSlowLeak(1)),
+    (32_201, FailureType::TyreSlowLeak(2)),
+    (32_202, FailureType::TyreSlowLeak(3)),
+    (32_203, FailureType::TyreSlowLeak(4)),
+    (32_204, FailureType::TyreSlowLeak(5)),
+    (32_205, FailureType::TyreSlowLeak(6)),
+    (32_206, FailureType::TyreSlowLeak(7)),
+    (32_207, FailureType::TyreSlowLeak(8)),
+    (32_208, FailureType::TyreSlowLeak(9)),
+    (32_209, FailureType::TyreSlowLeak(10)),
+    (32_210, FailureType::TyreSlowLeak(11)),
+    (32_211, FailureType::TyreSlowLeak(12)),
+    (32_212, FailureType::TyreSlowLeak(13)),
+    (32_213, FailureType::TyreSlowLeak(14)),
+    (32_214, FailureType::TyreSlowLeak(15)),
+    (32_215, FailureType::TyreSlowLeak(16)),
+    (32_216, FailureType::TyreSlowLeak(17)),
+    (32_217, FailureType::TyreSlowLeak(18)),
+    (32_218, FailureType::TyreSlowLeak(19)),
+    (32_219, FailureType::TyreSlowLeak(20)),
+    (32_220, FailureType::TyreSlowLeak(21)),
+    (32_221, FailureType::TyreSlowLeak(22)),
+    (32_250, FailureType::TyreBurst(1)),
+    (32_251, FailureType::TyreBurst(2)),
+    (32_252, FailureType::TyreBurst(3)),
+    (32_253, FailureType::TyreBurst(4)),
+    (32_254, FailureType::TyreBurst(5)),
+    (32_255, FailureType::TyreBurst(6)),
+    (32_256, FailureType::TyreBurst(7)),
+    (32_257, FailureType::TyreBurst(8)),
+    (32_258, FailureType::TyreBurst(9)),
+    (32_259, FailureType::TyreBurst(10)),
+    (32_260, FailureType::TyreBurst(11)),
+    (32_261, FailureType::TyreBurst(12)),
+    (32_262, FailureType::TyreBurst(13)),
+    (32_263, FailureType::TyreBurst(14)),
+    (32_264, FailureType::TyreBurst(15)),
+    (32_265, FailureType::TyreBurst(16)),
+    (32_266, FailureType::TyreBurst(17)),
+    (32_267, FailureType::TyreBurst(18)),
+    (32_268, FailureType::TyreBurst(19)),
+    (32_269, FailureType::TyreBurst(20)),
+    (32_270, FailureType::TyreBurst(21)),
+    (32_271, FailureType::TyreBurst(22)),
+    (34_000, FailureType::RadioAltimeter(1)),
+    (34_001, FailureType::RadioAltimeter(2)),
+    (34_002, FailureType::RadioAltimeter(3)),
+    (34_100, FailureType::PitotBlockage(1)),
+    (34_101, FailureType::PitotBlockage(2)),
+    (34_102, FailureType::PitotBlockage(3)),
+    (34_110, FailureType::PitotDrainBlockage(1)),
+    (34_111, FailureType::PitotDrainBlockage(2)),
+    (34_112, FailureType::PitotDrainBlockage(3)),
+    (34_120, FailureType::StaticPortBlockage(1)),
+    (34_121, FailureType::StaticPortBlockage(2)),
+    (34_122, FailureType::StaticPortBlockage(3)),
+    (34_130, FailureType::AngleOfAttackVaneJam(1)),
+    (34_131, FailureType::AngleOfAttackVaneJam(2)),
+    (34_132, FailureType::AngleOfAttackVaneJam(3)),
+    (34_200, FailureType::GpsReceiver(1)),
+    (34_201, FailureType::GpsReceiver(2)),
+];
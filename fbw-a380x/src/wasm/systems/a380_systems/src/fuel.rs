@@ -1,34 +1,36 @@
-use systems::simulation::{
-    InitContext, Read, SimulationElement, SimulatorReader, VariableIdentifier,
+use systems::{
+    fuel::FuelTank,
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
-use uom::si::{f64::*, mass::kilogram};
+use uom::si::f64::*;
 
 pub struct A380Fuel {
-    unlimited_fuel_id: VariableIdentifier,
-    fuel_tank_left_main_quantity_id: VariableIdentifier,
-
-    unlimited_fuel: bool,
-    left_inner_tank_fuel_quantity: Mass,
+    left_inner_tank: FuelTank,
 }
 impl A380Fuel {
     pub fn new(context: &mut InitContext) -> Self {
         A380Fuel {
-            unlimited_fuel_id: context.get_identifier("UNLIMITED FUEL".to_owned()),
-            fuel_tank_left_main_quantity_id: context
-                .get_identifier("FUEL TANK LEFT MAIN QUANTITY".to_owned()),
-
-            unlimited_fuel: false,
-            left_inner_tank_fuel_quantity: Mass::new::<kilogram>(0.),
+            left_inner_tank: FuelTank::new(
+                context,
+                "FUEL TANK LEFT MAIN QUANTITY",
+                "FUEL_TANK_LEFT_MAIN_QUANTITY_REQ",
+            ),
         }
     }
 
+    /// The APU is fed from the left inner tank.
+    pub fn update(&mut self, context: &UpdateContext, apu_fuel_flow: MassRate) {
+        self.left_inner_tank.update(context, apu_fuel_flow);
+    }
+
     pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
-        self.unlimited_fuel || self.left_inner_tank_fuel_quantity > Mass::new::<kilogram>(0.)
+        self.left_inner_tank.has_fuel_remaining()
     }
 }
 impl SimulationElement for A380Fuel {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.unlimited_fuel = reader.read(&self.unlimited_fuel_id);
-        self.left_inner_tank_fuel_quantity = reader.read(&self.fuel_tank_left_main_quantity_id);
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.left_inner_tank.accept(visitor);
+
+        visitor.visit(self);
     }
 }
@@ -0,0 +1,474 @@
+use crate::systems::{
+    failures::{Failure, FailureType},
+    hydraulic::{
+        body_wheel_steering::{BodyWheelSteeringActuator, BodyWheelSteeringController},
+        linear_actuator::Actuator,
+    },
+    landing_gear::tyre_pressure::TyreCondition,
+    shared::{interpolation, ElectricalBusType, ElectricalBuses, SectionPressure},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use uom::si::{
+    angle::degree, angular_velocity::radian_per_second, f64::*, length::meter, ratio::ratio,
+    velocity::knot,
+};
+
+/// Body wheel steering part of the BSCU.
+///
+/// Body wheels steer in the opposite direction of the nose wheel once nose wheel angle is above
+/// a threshold, and are centered and locked above a ground speed limit, on failure, when
+/// the computer is unpowered or when a tyre of a steered axle is deflated.
+pub struct A380BodyWheelSteeringController {
+    fault_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    failure: Failure,
+
+    requested_position: Angle,
+    is_unlock_requested: bool,
+}
+impl A380BodyWheelSteeringController {
+    const NOSE_WHEEL_ANGLE_BREAKPOINTS_DEGREES: [f64; 4] = [0., 20., 70., 90.];
+    const BODY_WHEEL_ANGLE_DEGREES: [f64; 4] = [0., 0., 15., 15.];
+
+    const NOSE_WHEEL_ANGLE_ACTIVATION_THRESHOLD_DEGREES: f64 = 20.;
+    const MAX_GROUND_SPEED_FOR_OPERATION_KNOTS: f64 = 20.;
+
+    // Tyres of the aft axle of left and right body gears
+    const STEERED_AXLE_TYRES: [usize; 4] = [13, 14, 19, 20];
+
+    pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        Self {
+            fault_id: context.get_identifier("HYD_BODY_WHEEL_STEERING_FAULT".to_owned()),
+
+            powered_by,
+            is_powered: false,
+
+            failure: Failure::new(FailureType::BodyWheelSteering),
+
+            requested_position: Angle::default(),
+            is_unlock_requested: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        nose_wheel_angle: Angle,
+        ground_speed: Velocity,
+        tyres: &impl TyreCondition,
+    ) {
+        let is_operative = self.is_powered && !self.failure.is_active();
+
+        let steered_tyres_are_inflated = tyres
+            .inflated_ratio(&Self::STEERED_AXLE_TYRES)
+            .get::<ratio>()
+            >= 1.;
+
+        let is_in_steering_envelope = ground_speed.abs()
+            < Velocity::new::<knot>(Self::MAX_GROUND_SPEED_FOR_OPERATION_KNOTS)
+            && nose_wheel_angle.abs()
+                > Angle::new::<degree>(Self::NOSE_WHEEL_ANGLE_ACTIVATION_THRESHOLD_DEGREES);
+
+        self.is_unlock_requested =
+            is_operative && is_in_steering_envelope && steered_tyres_are_inflated;
+
+        self.requested_position = if self.is_unlock_requested {
+            let body_wheel_angle_abs = Angle::new::<degree>(interpolation(
+                &Self::NOSE_WHEEL_ANGLE_BREAKPOINTS_DEGREES,
+                &Self::BODY_WHEEL_ANGLE_DEGREES,
+                nose_wheel_angle.get::<degree>().abs(),
+            ));
+
+            // Body wheels steer opposite to the nose wheel
+            if nose_wheel_angle.get::<degree>() > 0. {
+                -body_wheel_angle_abs
+            } else {
+                body_wheel_angle_abs
+            }
+        } else {
+            Angle::default()
+        };
+    }
+
+    fn has_fault(&self) -> bool {
+        !self.is_powered || self.failure.is_active()
+    }
+}
+impl BodyWheelSteeringController for A380BodyWheelSteeringController {
+    fn requested_position(&self) -> Angle {
+        self.requested_position
+    }
+
+    fn is_unlock_requested(&self) -> bool {
+        self.is_unlock_requested
+    }
+}
+impl SimulationElement for A380BodyWheelSteeringController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.fault_id, self.has_fault());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Left and right body gear steering actuators with their controller
+pub struct A380BodyWheelSteering {
+    controller: A380BodyWheelSteeringController,
+
+    left_actuator: BodyWheelSteeringActuator,
+    right_actuator: BodyWheelSteeringActuator,
+}
+impl A380BodyWheelSteering {
+    const MAX_BODY_WHEEL_ANGLE_DEGREES: f64 = 15.;
+    const NOMINAL_SPEED_RAD_S: f64 = 0.1;
+    const ACTUATOR_DIAMETER_METER: f64 = 0.06;
+    const ANGULAR_TO_LINEAR_RATIO: f64 = 0.25;
+
+    pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        Self {
+            controller: A380BodyWheelSteeringController::new(context, powered_by),
+
+            left_actuator: Self::new_actuator(context, "LEFT"),
+            right_actuator: Self::new_actuator(context, "RIGHT"),
+        }
+    }
+
+    fn new_actuator(context: &mut InitContext, id: &str) -> BodyWheelSteeringActuator {
+        BodyWheelSteeringActuator::new(
+            context,
+            id,
+            Angle::new::<degree>(Self::MAX_BODY_WHEEL_ANGLE_DEGREES),
+            AngularVelocity::new::<radian_per_second>(Self::NOMINAL_SPEED_RAD_S),
+            Length::new::<meter>(Self::ACTUATOR_DIAMETER_METER),
+            Ratio::new::<ratio>(Self::ANGULAR_TO_LINEAR_RATIO),
+        )
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        section_pressure: &impl SectionPressure,
+        nose_wheel_angle: Angle,
+        ground_speed: Velocity,
+        tyres: &impl TyreCondition,
+    ) {
+        self.controller
+            .update(nose_wheel_angle, ground_speed, tyres);
+
+        self.left_actuator
+            .update(context, section_pressure, &self.controller);
+        self.right_actuator
+            .update(context, section_pressure, &self.controller);
+    }
+
+    pub fn left_position(&self) -> Angle {
+        self.left_actuator.position_feedback()
+    }
+
+    pub fn right_position(&self) -> Angle {
+        self.right_actuator.position_feedback()
+    }
+
+    #[cfg(test)]
+    fn is_locked(&self) -> bool {
+        self.left_actuator.is_locked() && self.right_actuator.is_locked()
+    }
+
+    pub fn left_actuator(&mut self) -> &mut impl Actuator {
+        &mut self.left_actuator
+    }
+
+    pub fn right_actuator(&mut self) -> &mut impl Actuator {
+        &mut self.right_actuator
+    }
+}
+impl SimulationElement for A380BodyWheelSteering {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.controller.accept(visitor);
+        self.left_actuator.accept(visitor);
+        self.right_actuator.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft,
+        },
+    };
+    use std::time::Duration;
+    use uom::si::{electric_potential::volt, pressure::psi};
+
+    #[derive(Default)]
+    struct TestHydraulicSection {
+        pressure: Pressure,
+    }
+    impl SectionPressure for TestHydraulicSection {
+        fn pressure(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_leak_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_priority_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn is_pressure_switch_pressurised(&self) -> bool {
+            self.pressure.get::<psi>() > 1700.
+        }
+    }
+
+    #[derive(Default)]
+    struct TestTyres {
+        deflated_tyres: Vec<usize>,
+    }
+    impl TyreCondition for TestTyres {
+        fn is_deflated(&self, tyre_number: usize) -> bool {
+            self.deflated_tyres.contains(&tyre_number)
+        }
+    }
+
+    struct TestAircraft {
+        body_wheel_steering: A380BodyWheelSteering,
+
+        tyres: TestTyres,
+
+        yellow_section: TestHydraulicSection,
+        nose_wheel_angle: Angle,
+        ground_speed: Velocity,
+
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        is_dc_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                body_wheel_steering: A380BodyWheelSteering::new(
+                    context,
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+
+                tyres: TestTyres::default(),
+
+                yellow_section: TestHydraulicSection {
+                    pressure: Pressure::new::<psi>(5000.),
+                },
+                nose_wheel_angle: Angle::default(),
+                ground_speed: Velocity::default(),
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                is_dc_1_powered: true,
+            }
+        }
+
+        fn set_nose_wheel_angle(&mut self, angle: Angle) {
+            self.nose_wheel_angle = angle;
+        }
+
+        fn set_ground_speed(&mut self, speed: Velocity) {
+            self.ground_speed = speed;
+        }
+
+        fn set_dc_1_power(&mut self, is_powered: bool) {
+            self.is_dc_1_powered = is_powered;
+        }
+
+        fn deflate_tyre(&mut self, tyre_number: usize) {
+            self.tyres.deflated_tyres.push(tyre_number);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.powered_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.powered_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.powered_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.body_wheel_steering.update(
+                context,
+                &self.yellow_section,
+                self.nose_wheel_angle,
+                self.ground_speed,
+                &self.tyres,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.body_wheel_steering.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_with_nose_angle_and_speed(
+        nose_wheel_angle: Angle,
+        ground_speed: Velocity,
+    ) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_nose_wheel_angle(nose_wheel_angle));
+        test_bed.command(|a| a.set_ground_speed(ground_speed));
+
+        test_bed
+    }
+
+    #[test]
+    fn body_wheels_locked_with_small_nose_wheel_angle() {
+        let mut test_bed = test_bed_with_nose_angle_and_speed(
+            Angle::new::<degree>(15.),
+            Velocity::new::<knot>(5.),
+        );
+
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.body_wheel_steering.is_locked()));
+    }
+
+    #[test]
+    fn body_wheels_steer_opposite_to_nose_wheel_at_taxi_speed() {
+        let mut test_bed = test_bed_with_nose_angle_and_speed(
+            Angle::new::<degree>(70.),
+            Velocity::new::<knot>(5.),
+        );
+
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.body_wheel_steering.is_locked()));
+        assert!(
+            (test_bed.query(|a| a.body_wheel_steering.left_position()) + Angle::new::<degree>(15.))
+                .abs()
+                < Angle::new::<degree>(0.1)
+        );
+        assert!(
+            (test_bed.query(|a| a.body_wheel_steering.right_position())
+                + Angle::new::<degree>(15.))
+            .abs()
+                < Angle::new::<degree>(0.1)
+        );
+
+        test_bed.command(|a| a.set_nose_wheel_angle(Angle::new::<degree>(-45.)));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(
+            (test_bed.query(|a| a.body_wheel_steering.left_position()) - Angle::new::<degree>(7.5))
+                .abs()
+                < Angle::new::<degree>(0.1)
+        );
+    }
+
+    #[test]
+    fn body_wheels_center_and_lock_above_speed_limit() {
+        let mut test_bed = test_bed_with_nose_angle_and_speed(
+            Angle::new::<degree>(70.),
+            Velocity::new::<knot>(5.),
+        );
+
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+        assert!(!test_bed.query(|a| a.body_wheel_steering.is_locked()));
+
+        test_bed.command(|a| a.set_ground_speed(Velocity::new::<knot>(25.)));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.body_wheel_steering.is_locked()));
+        assert!(
+            test_bed
+                .query(|a| a.body_wheel_steering.left_position())
+                .abs()
+                < Angle::new::<degree>(0.1)
+        );
+    }
+
+    #[test]
+    fn body_wheels_center_and_lock_on_failure() {
+        let mut test_bed = test_bed_with_nose_angle_and_speed(
+            Angle::new::<degree>(70.),
+            Velocity::new::<knot>(5.),
+        );
+
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+        assert!(!test_bed.query(|a| a.body_wheel_steering.is_locked()));
+
+        test_bed.fail(FailureType::BodyWheelSteering);
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.body_wheel_steering.is_locked()));
+
+        let has_fault: bool = test_bed.read_by_name("HYD_BODY_WHEEL_STEERING_FAULT");
+        assert!(has_fault);
+    }
+
+    #[test]
+    fn body_wheels_center_and_lock_when_unpowered() {
+        let mut test_bed = test_bed_with_nose_angle_and_speed(
+            Angle::new::<degree>(70.),
+            Velocity::new::<knot>(5.),
+        );
+
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+        assert!(!test_bed.query(|a| a.body_wheel_steering.is_locked()));
+
+        test_bed.command(|a| a.set_dc_1_power(false));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.body_wheel_steering.is_locked()));
+    }
+
+    #[test]
+    fn body_wheels_center_and_lock_with_deflated_steered_axle_tyre() {
+        let mut test_bed = test_bed_with_nose_angle_and_speed(
+            Angle::new::<degree>(70.),
+            Velocity::new::<knot>(5.),
+        );
+
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+        assert!(!test_bed.query(|a| a.body_wheel_steering.is_locked()));
+
+        test_bed.command(|a| a.deflate_tyre(19));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.body_wheel_steering.is_locked()));
+
+        let has_fault: bool = test_bed.read_by_name("HYD_BODY_WHEEL_STEERING_FAULT");
+        assert!(!has_fault);
+    }
+}
@@ -47,7 +47,10 @@ use systems::{
         HydraulicCircuitController, HydraulicPressureSensors, ManualPump, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear},
+    landing_gear::{
+        tyre_pressure::{Tyre, TyreCondition, TyrePressureIndicatingSystem},
+        GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear,
+    },
     overhead::{AutoOffFaultPushButton, AutoOnFaultPushButton},
     shared::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
@@ -58,12 +61,14 @@ use systems::{
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, StartState, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, StartState, UpdateContext, VariableIdentifier, VariableMetadata, Write,
     },
 };
 
 use std::fmt::Debug;
 
+mod body_wheel_steering;
+use body_wheel_steering::A380BodyWheelSteering;
 mod flaps_computer;
 use flaps_computer::SlatFlapComplex;
 mod engine_pump_disc;
@@ -1501,6 +1506,7 @@ impl A380GearSystemFactory {
 
 pub(super) struct A380Hydraulic {
     nose_steering: SteeringActuator,
+    body_wheel_steering: A380BodyWheelSteering,
 
     core_hydraulic_updater: MaxStepLoop,
 
@@ -1556,6 +1562,8 @@ pub(super) struct A380Hydraulic {
     braking_circuit_altn: BrakeCircuit,
     braking_force: A380BrakingForce,
 
+    tyre_pressure_indicating_system: TyrePressureIndicatingSystem<22>,
+
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
     slats_flaps_complex: SlatFlapComplex,
@@ -1624,6 +1632,44 @@ impl A380Hydraulic {
 
     const EDP_CONTROL_POWER_BUS1: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
 
+    const BODY_WHEEL_STEERING_CONTROL_POWER_BUS: ElectricalBusType =
+        ElectricalBusType::DirectCurrent(1);
+
+    const TYRE_PRESSURE_INDICATING_SYSTEM_POWER_BUS: ElectricalBusType =
+        ElectricalBusType::DirectCurrent(1);
+    const MAIN_GEAR_TYRE_COLD_PRESSURE_PSI: f64 = 220.;
+    const NOSE_GEAR_TYRE_COLD_PRESSURE_PSI: f64 = 185.;
+
+    // Brake of each tyre, for the tyres braked by A380BrakingForce. Wing gear tyres 1 to 4 (left)
+    // and 5 to 8 (right) are all braked. Body gear tyres 9 to 14 (left) and 15 to 20 (right) are
+    // numbered in pairs from the forward to the aft axle, the middle axle being unbraked. This gives
+    // brakes 1 to 16. Nose tyres 21 and 22 are unbraked.
+    const TYRE_BRAKE_NUMBERS: [Option<usize>; 22] = [
+        Some(1),
+        Some(2),
+        Some(3),
+        Some(4),
+        Some(5),
+        Some(6),
+        Some(7),
+        Some(8),
+        Some(9),
+        Some(10),
+        None,
+        None,
+        Some(11),
+        Some(12),
+        Some(13),
+        Some(14),
+        None,
+        None,
+        Some(15),
+        Some(16),
+        None,
+        None,
+    ];
+    const FIRST_NOSE_GEAR_TYRE_NUMBER: usize = 21;
+
     const ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE: f64 = 1000.0; // Nitrogen PSI
                                                                     // Refresh rate of core hydraulic simulation
     const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(10);
@@ -1636,6 +1682,22 @@ impl A380Hydraulic {
             Ratio::new::<ratio>(0.01),
         );
 
+        let tyres = std::array::from_fn(|index| {
+            let number = index + 1;
+            let cold_inflation_pressure = if number >= Self::FIRST_NOSE_GEAR_TYRE_NUMBER {
+                Self::NOSE_GEAR_TYRE_COLD_PRESSURE_PSI
+            } else {
+                Self::MAIN_GEAR_TYRE_COLD_PRESSURE_PSI
+            };
+
+            Tyre::new(
+                context,
+                number,
+                Pressure::new::<psi>(cold_inflation_pressure),
+                Self::TYRE_BRAKE_NUMBERS[index],
+            )
+        });
+
         A380Hydraulic {
             nose_steering: SteeringActuator::new(
                 context,
@@ -1644,6 +1706,10 @@ impl A380Hydraulic {
                 Length::new::<meter>(0.075),
                 Ratio::new::<ratio>(0.18),
             ),
+            body_wheel_steering: A380BodyWheelSteering::new(
+                context,
+                Self::BODY_WHEEL_STEERING_CONTROL_POWER_BUS,
+            ),
 
             core_hydraulic_updater: MaxStepLoop::new(Self::HYDRAULIC_SIM_TIME_STEP),
 
@@ -1822,6 +1888,12 @@ impl A380Hydraulic {
 
             braking_force: A380BrakingForce::new(context),
 
+            tyre_pressure_indicating_system: TyrePressureIndicatingSystem::new(
+                context,
+                Self::TYRE_PRESSURE_INDICATING_SYSTEM_POWER_BUS,
+                tyres,
+            ),
+
             flap_system: FlapSlatAssembly::new(
                 context,
                 "FLAPS",
@@ -2039,6 +2111,22 @@ impl A380Hydraulic {
         lgcius: &LandingGearControlInterfaceUnitSet,
         adirs: &impl AdirsDiscreteOutputs,
     ) {
+        self.flap_system.update(
+            context,
+            self.slats_flaps_complex.flap_demand(),
+            self.slats_flaps_complex.flap_demand(),
+            self.green_circuit.system_section(),
+            self.yellow_circuit.system_section(),
+        );
+
+        self.slat_system.update(
+            context,
+            self.slats_flaps_complex.slat_demand(),
+            self.slats_flaps_complex.slat_demand(),
+            self.green_circuit.system_section(),
+            self.green_circuit.system_section(),
+        );
+
         self.forward_cargo_door.update(
             context,
             &self.forward_cargo_door_controller,
@@ -2207,7 +2295,7 @@ impl A380Hydraulic {
 
         self.rudder_system_controller.update();
 
-        self.tilting_gears.update(context);
+        self.tyre_pressure_indicating_system.update(context);
 
         self.nose_steering.update(
             context,
@@ -2216,6 +2304,17 @@ impl A380Hydraulic {
             &self.pushback_tug,
         );
 
+        self.body_wheel_steering.update(
+            context,
+            self.yellow_circuit.system_section(),
+            self.nose_steering.position_feedback(),
+            self.brake_steer_computer.ground_speed(),
+            &self.tyre_pressure_indicating_system,
+        );
+
+        self.tilting_gears
+            .update(context, &self.body_wheel_steering);
+
         // Process brake logic (which circuit brakes) and send brake demands (how much)
         self.brake_steer_computer.update(
             context,
@@ -2226,6 +2325,7 @@ impl A380Hydraulic {
             autobrake_panel,
             engine1,
             engine2,
+            &self.tyre_pressure_indicating_system,
         );
 
         self.pushback_tug.update(context);
@@ -2237,37 +2337,25 @@ impl A380Hydraulic {
             engine1,
             engine2,
             &self.pushback_tug,
+            &self.tyre_pressure_indicating_system,
         );
 
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
 
-        self.flap_system.update(
-            context,
-            self.slats_flaps_complex.flap_demand(),
-            self.slats_flaps_complex.flap_demand(),
-            self.green_circuit.system_section(),
-            self.yellow_circuit.system_section(),
-        );
-
-        self.slat_system.update(
-            context,
-            self.slats_flaps_complex.slat_demand(),
-            self.slats_flaps_complex.slat_demand(),
-            self.green_circuit.system_section(),
-            self.green_circuit.system_section(),
-        );
-
+        // The A380 doesn't simulate the residual pressure inhibition of its doors yet.
         self.forward_cargo_door_controller.update(
             context,
             &self.forward_cargo_door,
             self.green_circuit.auxiliary_section(),
+            false,
         );
 
         self.aft_cargo_door_controller.update(
             context,
             &self.aft_cargo_door,
             self.green_circuit.auxiliary_section(),
+            false,
         );
 
         self.slats_flaps_complex
@@ -2450,6 +2538,11 @@ impl A380Hydraulic {
         self.yellow_circuit
             .update_system_actuator_volumes(&mut self.nose_steering);
 
+        self.yellow_circuit
+            .update_system_actuator_volumes(self.body_wheel_steering.left_actuator());
+        self.yellow_circuit
+            .update_system_actuator_volumes(self.body_wheel_steering.right_actuator());
+
         self.yellow_circuit
             .update_system_actuator_volumes(self.right_elevator.actuator(
                 ElevatorActuatorPosition::Outward,
@@ -2859,7 +2952,10 @@ impl SimulationElement for A380Hydraulic {
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
 
+        self.tyre_pressure_indicating_system.accept(visitor);
+
         self.nose_steering.accept(visitor);
+        self.body_wheel_steering.accept(visitor);
         self.slats_flaps_complex.accept(visitor);
         self.flap_system.accept(visitor);
         self.slat_system.accept(visitor);
@@ -3790,6 +3886,8 @@ impl A380HydraulicBrakeSteerComputerUnit {
 
     const MAX_STEERING_ANGLE_DEMAND_DEGREES: f64 = 74.;
 
+    const NOSE_TYRES: [usize; 2] = [21, 22];
+
     // Minimum pressure hysteresis on green until main switched on ALTN brakes
     // Feedback by Cpt. Chaos — 25/04/2021 #pilot-feedback
     const MIN_PRESSURE_BRAKE_ALTN_HYST_LO: f64 = 1305.;
@@ -3805,19 +3903,70 @@ impl A380HydraulicBrakeSteerComputerUnit {
 
     fn new(context: &mut InitContext) -> Self {
         Self {
-            park_brake_lever_pos_id: context.get_identifier("PARK_BRAKE_LEVER_POS".to_owned()),
-            antiskid_brakes_active_id: context.get_identifier("ANTISKID BRAKES ACTIVE".to_owned()),
-            left_brake_pedal_input_id: context.get_identifier("LEFT_BRAKE_PEDAL_INPUT".to_owned()),
-            right_brake_pedal_input_id: context
-                .get_identifier("RIGHT_BRAKE_PEDAL_INPUT".to_owned()),
-
-            ground_speed_id: context.get_identifier("GPS GROUND SPEED".to_owned()),
-            rudder_pedal_input_id: context.get_identifier("RUDDER_PEDAL_POSITION_RATIO".to_owned()),
-            tiller_handle_input_id: context.get_identifier("TILLER_HANDLE_POSITION".to_owned()),
-            tiller_pedal_disconnect_id: context
-                .get_identifier("TILLER_PEDAL_DISCONNECT".to_owned()),
-            autopilot_nosewheel_demand_id: context
-                .get_identifier("AUTOPILOT_NOSEWHEEL_DEMAND".to_owned()),
+            park_brake_lever_pos_id: context.get_identifier_with_metadata(
+                "PARK_BRAKE_LEVER_POS".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The parking brake lever is set")
+                    .written_externally(),
+            ),
+            antiskid_brakes_active_id: context.get_identifier_with_metadata(
+                "ANTISKID BRAKES ACTIVE".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The anti skid switch is on")
+                    .written_externally(),
+            ),
+            left_brake_pedal_input_id: context.get_identifier_with_metadata(
+                "LEFT_BRAKE_PEDAL_INPUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("percent")
+                    .description("Deflection of the left brake pedals")
+                    .written_externally(),
+            ),
+            right_brake_pedal_input_id: context.get_identifier_with_metadata(
+                "RIGHT_BRAKE_PEDAL_INPUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("percent")
+                    .description("Deflection of the right brake pedals")
+                    .written_externally(),
+            ),
+
+            ground_speed_id: context.get_identifier_with_metadata(
+                "GPS GROUND SPEED".to_owned(),
+                VariableMetadata::new()
+                    .unit("knots")
+                    .description("Ground speed")
+                    .written_externally(),
+            ),
+            rudder_pedal_input_id: context.get_identifier_with_metadata(
+                "RUDDER_PEDAL_POSITION_RATIO".to_owned(),
+                VariableMetadata::new()
+                    .unit("ratio")
+                    .description("Deflection of the rudder pedals, positive to the right")
+                    .written_externally(),
+            ),
+            tiller_handle_input_id: context.get_identifier_with_metadata(
+                "TILLER_HANDLE_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("ratio")
+                    .description("Deflection of the steering tiller, positive to the right")
+                    .written_externally(),
+            ),
+            tiller_pedal_disconnect_id: context.get_identifier_with_metadata(
+                "TILLER_PEDAL_DISCONNECT".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The rudder pedals are disconnected from the nose wheel steering")
+                    .written_externally(),
+            ),
+            autopilot_nosewheel_demand_id: context.get_identifier_with_metadata(
+                "AUTOPILOT_NOSEWHEEL_DEMAND".to_owned(),
+                VariableMetadata::new()
+                    .unit("ratio")
+                    .description("Nose wheel steering demand of the autopilot")
+                    .written_externally(),
+            ),
 
             autobrake_controller: A380AutobrakeController::new(context),
 
@@ -3919,8 +4068,9 @@ impl A380HydraulicBrakeSteerComputerUnit {
         autobrake_panel: &AutobrakePanel,
         engine1: &impl Engine,
         engine2: &impl Engine,
+        tyres: &impl TyreCondition,
     ) {
-        self.update_steering_demands(lgciu1, engine1, engine2);
+        self.update_steering_demands(lgciu1, engine1, engine2, tyres);
 
         self.update_normal_braking_availability(current_pressure.pressure());
         self.update_brake_pressure_limitation();
@@ -4001,6 +4151,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
         lgciu1: &impl LgciuInterface,
         engine1: &impl Engine,
         engine2: &impl Engine,
+        tyres: &impl TyreCondition,
     ) {
         let steer_angle_from_autopilot = Angle::new::<degree>(
             self.autopilot_nosewheel_demand.get::<ratio>() * Self::AUTOPILOT_STEERING_INPUT_GAIN,
@@ -4034,22 +4185,28 @@ impl A380HydraulicBrakeSteerComputerUnit {
         let is_both_engine_low_oil_pressure =
             engine1.oil_pressure_is_low() && engine2.oil_pressure_is_low();
 
+        // A deflated nose tyre loses its cornering force so steering authority is reduced
+        let max_steering_angle_demand = Angle::new::<degree>(
+            Self::MAX_STEERING_ANGLE_DEMAND_DEGREES
+                * tyres.inflated_ratio(&Self::NOSE_TYRES).get::<ratio>(),
+        );
+
         self.final_steering_position_request = if !is_both_engine_low_oil_pressure
             && self.anti_skid_activated
             && lgciu1.nose_gear_compressed(false)
         {
             (final_steer_rudder_plus_autopilot + steer_angle_from_tiller)
-                .min(Angle::new::<degree>(
-                    Self::MAX_STEERING_ANGLE_DEMAND_DEGREES,
-                ))
-                .max(Angle::new::<degree>(
-                    -Self::MAX_STEERING_ANGLE_DEMAND_DEGREES,
-                ))
+                .min(max_steering_angle_demand)
+                .max(-max_steering_angle_demand)
         } else {
             Angle::new::<degree>(0.)
         };
     }
 
+    fn ground_speed(&self) -> Velocity {
+        self.ground_speed
+    }
+
     fn norm_controller(&self) -> &impl BrakeCircuitController {
         &self.norm_brake_outputs
     }
@@ -4112,6 +4269,9 @@ impl A380BrakingForce {
     const FLAPS_BREAKPOINTS: [f64; 3] = [0., 50., 100.];
     const FLAPS_PENALTY_PERCENT: [f64; 3] = [5., 5., 0.];
 
+    const LEFT_BRAKED_TYRES: [usize; 8] = [1, 2, 3, 4, 9, 10, 13, 14];
+    const RIGHT_BRAKED_TYRES: [usize; 8] = [5, 6, 7, 8, 15, 16, 19, 20];
+
     pub fn new(context: &mut InitContext) -> Self {
         A380BrakingForce {
             brake_left_force_factor_id: context
@@ -4123,8 +4283,20 @@ impl A380BrakingForce {
             trailing_edge_flaps_right_percent_id: context
                 .get_identifier("RIGHT_FLAPS_POSITION_PERCENT".to_owned()),
 
-            enabled_chocks_id: context.get_identifier("MODEL_WHEELCHOCKS_ENABLED".to_owned()),
-            light_beacon_on_id: context.get_identifier("LIGHT BEACON".to_owned()),
+            enabled_chocks_id: context.get_identifier_with_metadata(
+                "MODEL_WHEELCHOCKS_ENABLED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The wheel chocks are in place")
+                    .written_externally(),
+            ),
+            light_beacon_on_id: context.get_identifier_with_metadata(
+                "LIGHT BEACON".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The beacon light is on")
+                    .written_externally(),
+            ),
 
             left_braking_force: 0.,
             right_braking_force: 0.,
@@ -4144,6 +4316,7 @@ impl A380BrakingForce {
         engine1: &impl Engine,
         engine2: &impl Engine,
         pushback_tug: &PushbackTug,
+        tyres: &impl TyreCondition,
     ) {
         // Base formula for output force is output_force[0:1] = 50 * sqrt(current_pressure) / Max_brake_pressure
         // This formula gives a bit more punch for lower brake pressures (like 1000 psi alternate braking), as linear formula
@@ -4164,10 +4337,21 @@ impl A380BrakingForce {
         self.right_braking_force = self.right_braking_force.max(0.).min(1.);
 
         self.correct_with_flaps_state(context);
+        self.correct_with_tyres_state(tyres);
 
         self.update_chocks_braking(context, engine1, engine2, pushback_tug);
     }
 
+    // A wheel running on a deflated tyre cannot transmit its share of braking force
+    fn correct_with_tyres_state(&mut self, tyres: &impl TyreCondition) {
+        self.left_braking_force *= tyres
+            .inflated_ratio(&Self::LEFT_BRAKED_TYRES)
+            .get::<ratio>();
+        self.right_braking_force *= tyres
+            .inflated_ratio(&Self::RIGHT_BRAKED_TYRES)
+            .get::<ratio>();
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -4278,13 +4462,34 @@ impl A380AutobrakeController {
             armed_mode_id_set: context.get_identifier("AUTOBRAKES_ARMED_MODE_SET".to_owned()),
             decel_light_id: context.get_identifier("AUTOBRAKES_DECEL_LIGHT".to_owned()),
             active_id: context.get_identifier("AUTOBRAKES_ACTIVE".to_owned()),
-            ground_spoilers_out_sec1_id: context
-                .get_identifier("SEC_1_GROUND_SPOILER_OUT".to_owned()),
-            ground_spoilers_out_sec2_id: context
-                .get_identifier("SEC_2_GROUND_SPOILER_OUT".to_owned()),
-            ground_spoilers_out_sec3_id: context
-                .get_identifier("SEC_3_GROUND_SPOILER_OUT".to_owned()),
-            external_disarm_event_id: context.get_identifier("AUTOBRAKE_DISARM".to_owned()),
+            ground_spoilers_out_sec1_id: context.get_identifier_with_metadata(
+                "SEC_1_GROUND_SPOILER_OUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("SEC 1 has extended the ground spoilers")
+                    .written_externally(),
+            ),
+            ground_spoilers_out_sec2_id: context.get_identifier_with_metadata(
+                "SEC_2_GROUND_SPOILER_OUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("SEC 2 has extended the ground spoilers")
+                    .written_externally(),
+            ),
+            ground_spoilers_out_sec3_id: context.get_identifier_with_metadata(
+                "SEC_3_GROUND_SPOILER_OUT".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("SEC 3 has extended the ground spoilers")
+                    .written_externally(),
+            ),
+            external_disarm_event_id: context.get_identifier_with_metadata(
+                "AUTOBRAKE_DISARM".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The flight controls request the autobrake to disarm")
+                    .written_externally(),
+            ),
 
             deceleration_governor: AutobrakeDecelerationGovernor::new(),
             target: Acceleration::new::<meter_per_second_squared>(0.),
@@ -4826,55 +5031,192 @@ struct AileronSystemHydraulicController {
 impl AileronSystemHydraulicController {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            left_inboard_aileron_green_actuator_solenoid_id: context
-                .get_identifier("LEFT_INBOARD_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            left_inboard_aileron_eha_actuator_solenoid_id: context
-                .get_identifier("LEFT_INBOARD_AIL_EHA_SERVO_SOLENOID_ENERGIZED".to_owned()),
+            left_inboard_aileron_green_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_INBOARD_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the left inboard aileron green actuator is energized")
+                    .written_externally(),
+            ),
+            left_inboard_aileron_eha_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_INBOARD_AIL_EHA_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the left inboard aileron EHA is energized")
+                    .written_externally(),
+            ),
             left_midboard_aileron_yellow_actuator_solenoid_id: context
-                .get_identifier("LEFT_MIDBOARD_AIL_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            left_midboard_aileron_eha_actuator_solenoid_id: context
-                .get_identifier("LEFT_MIDBOARD_AIL_EHA_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            left_outboard_aileron_green_actuator_solenoid_id: context
-                .get_identifier("LEFT_OUTBOARD_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_MIDBOARD_AIL_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                    VariableMetadata::new()
+                        .unit("bool")
+                        .description("The servo solenoid of the left midboard aileron yellow actuator is energized")
+                        .written_externally(),
+                ),
+            left_midboard_aileron_eha_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_MIDBOARD_AIL_EHA_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the left midboard aileron EHA is energized")
+                    .written_externally(),
+            ),
+            left_outboard_aileron_green_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_OUTBOARD_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the left outboard aileron green actuator is energized")
+                    .written_externally(),
+            ),
             left_outboard_aileron_yellow_actuator_solenoid_id: context
-                .get_identifier("LEFT_OUTBOARD_AIL_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            right_inboard_aileron_green_actuator_solenoid_id: context
-                .get_identifier("RIGHT_INBOARD_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            right_inboard_aileron_eha_actuator_solenoid_id: context
-                .get_identifier("RIGHT_INBOARD_AIL_EHA_SERVO_SOLENOID_ENERGIZED".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_OUTBOARD_AIL_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                    VariableMetadata::new()
+                        .unit("bool")
+                        .description("The servo solenoid of the left outboard aileron yellow actuator is energized")
+                        .written_externally(),
+                ),
+            right_inboard_aileron_green_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "RIGHT_INBOARD_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the right inboard aileron green actuator is energized")
+                    .written_externally(),
+            ),
+            right_inboard_aileron_eha_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "RIGHT_INBOARD_AIL_EHA_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the right inboard aileron EHA is energized")
+                    .written_externally(),
+            ),
             right_midboard_aileron_yellow_actuator_solenoid_id: context
-                .get_identifier("RIGHT_MIDBOARD_AIL_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            right_midboard_aileron_eha_actuator_solenoid_id: context
-                .get_identifier("RIGHT_MIDBOARD_AIL_EHA_SERVO_SOLENOID_ENERGIZED".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_MIDBOARD_AIL_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                    VariableMetadata::new()
+                        .unit("bool")
+                        .description("The servo solenoid of the right midboard aileron yellow actuator is energized")
+                        .written_externally(),
+                ),
+            right_midboard_aileron_eha_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "RIGHT_MIDBOARD_AIL_EHA_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the right midboard aileron EHA is energized")
+                    .written_externally(),
+            ),
             right_outboard_aileron_green_actuator_solenoid_id: context
-                .get_identifier("RIGHT_OUTBOARD_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_OUTBOARD_AIL_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                    VariableMetadata::new()
+                        .unit("bool")
+                        .description("The servo solenoid of the right outboard aileron green actuator is energized")
+                        .written_externally(),
+                ),
             right_outboard_aileron_yellow_actuator_solenoid_id: context
-                .get_identifier("RIGHT_OUTBOARD_AIL_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_OUTBOARD_AIL_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                    VariableMetadata::new()
+                        .unit("bool")
+                        .description("The servo solenoid of the right outboard aileron yellow actuator is energized")
+                        .written_externally(),
+                ),
 
             left_inboard_aileron_green_actuator_position_demand_id: context
-                .get_identifier("LEFT_INBOARD_AIL_GREEN_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_INBOARD_AIL_GREEN_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left inboard aileron position commanded to the green actuator")
+                        .written_externally(),
+                ),
             left_inboard_aileron_eha_actuator_position_demand_id: context
-                .get_identifier("LEFT_INBOARD_AIL_EHA_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_INBOARD_AIL_EHA_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left inboard aileron position commanded to the EHA")
+                        .written_externally(),
+                ),
             left_midboard_aileron_yellow_actuator_position_demand_id: context
-                .get_identifier("LEFT_MIDBOARD_AIL_YELLOW_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_MIDBOARD_AIL_YELLOW_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left midboard aileron position commanded to the yellow actuator")
+                        .written_externally(),
+                ),
             left_midboard_aileron_eha_actuator_position_demand_id: context
-                .get_identifier("LEFT_MIDBOARD_AIL_EHA_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_MIDBOARD_AIL_EHA_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left midboard aileron position commanded to the EHA")
+                        .written_externally(),
+                ),
             left_outboard_aileron_green_actuator_position_demand_id: context
-                .get_identifier("LEFT_OUTBOARD_AIL_GREEN_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_OUTBOARD_AIL_GREEN_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left outboard aileron position commanded to the green actuator")
+                        .written_externally(),
+                ),
             left_outboard_aileron_yellow_actuator_position_demand_id: context
-                .get_identifier("LEFT_OUTBOARD_AIL_YELLOW_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_OUTBOARD_AIL_YELLOW_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left outboard aileron position commanded to the yellow actuator")
+                        .written_externally(),
+                ),
             right_inboard_aileron_green_actuator_position_demand_id: context
-                .get_identifier("RIGHT_INBOARD_AIL_GREEN_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_INBOARD_AIL_GREEN_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right inboard aileron position commanded to the green actuator")
+                        .written_externally(),
+                ),
             right_inboard_aileron_eha_actuator_position_demand_id: context
-                .get_identifier("RIGHT_INBOARD_AIL_EHA_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_INBOARD_AIL_EHA_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right inboard aileron position commanded to the EHA")
+                        .written_externally(),
+                ),
             right_midboard_aileron_yellow_actuator_position_demand_id: context
-                .get_identifier("RIGHT_MIDBOARD_AIL_YELLOW_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_MIDBOARD_AIL_YELLOW_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right midboard aileron position commanded to the yellow actuator")
+                        .written_externally(),
+                ),
             right_midboard_aileron_eha_actuator_position_demand_id: context
-                .get_identifier("RIGHT_MIDBOARD_AIL_EHA_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_MIDBOARD_AIL_EHA_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right midboard aileron position commanded to the EHA")
+                        .written_externally(),
+                ),
             right_outboard_aileron_green_actuator_position_demand_id: context
-                .get_identifier("RIGHT_OUTBOARD_AIL_GREEN_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_OUTBOARD_AIL_GREEN_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right outboard aileron position commanded to the green actuator")
+                        .written_externally(),
+                ),
             right_outboard_aileron_yellow_actuator_position_demand_id: context
-                .get_identifier("RIGHT_OUTBOARD_AIL_YELLOW_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_OUTBOARD_AIL_YELLOW_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right outboard aileron position commanded to the yellow actuator")
+                        .written_externally(),
+                ),
 
             left_inboard_position_requests_from_fbw: [Ratio::default(); 2],
             left_inboard_solenoid_energized_from_fbw: [false; 2],
@@ -5280,39 +5622,130 @@ struct ElevatorSystemHydraulicController {
 impl ElevatorSystemHydraulicController {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            left_inboard_elevator_green_actuator_solenoid_id: context
-                .get_identifier("LEFT_INBOARD_ELEV_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            left_inboard_elevator_eha_actuator_solenoid_id: context
-                .get_identifier("LEFT_INBOARD_ELEV_EHA_SERVO_SOLENOID_ENERGIZED".to_owned()),
+            left_inboard_elevator_green_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_INBOARD_ELEV_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the left inboard elevator green actuator is energized")
+                    .written_externally(),
+            ),
+            left_inboard_elevator_eha_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_INBOARD_ELEV_EHA_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the left inboard elevator EHA is energized")
+                    .written_externally(),
+            ),
             left_outboard_elevator_green_actuator_solenoid_id: context
-                .get_identifier("LEFT_OUTBOARD_ELEV_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            left_outboard_elevator_eha_actuator_solenoid_id: context
-                .get_identifier("LEFT_OUTBOARD_ELEV_EHA_SERVO_SOLENOID_ENERGIZED".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_OUTBOARD_ELEV_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                    VariableMetadata::new()
+                        .unit("bool")
+                        .description("The servo solenoid of the left outboard elevator green actuator is energized")
+                        .written_externally(),
+                ),
+            left_outboard_elevator_eha_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "LEFT_OUTBOARD_ELEV_EHA_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the left outboard elevator EHA is energized")
+                    .written_externally(),
+            ),
             right_inboard_elevator_yellow_actuator_solenoid_id: context
-                .get_identifier("RIGHT_INBOARD_ELEV_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            right_inboard_elevator_eha_actuator_solenoid_id: context
-                .get_identifier("RIGHT_INBOARD_ELEV_EHA_SERVO_SOLENOID_ENERGIZED".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_INBOARD_ELEV_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                    VariableMetadata::new()
+                        .unit("bool")
+                        .description("The servo solenoid of the right inboard elevator yellow actuator is energized")
+                        .written_externally(),
+                ),
+            right_inboard_elevator_eha_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "RIGHT_INBOARD_ELEV_EHA_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the right inboard elevator EHA is energized")
+                    .written_externally(),
+            ),
             right_outboard_elevator_yellow_actuator_solenoid_id: context
-                .get_identifier("RIGHT_OUTBOARD_ELEV_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            right_outboard_elevator_eha_actuator_solenoid_id: context
-                .get_identifier("RIGHT_OUTBOARD_ELEV_EHA_SERVO_SOLENOID_ENERGIZED".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_OUTBOARD_ELEV_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                    VariableMetadata::new()
+                        .unit("bool")
+                        .description("The servo solenoid of the right outboard elevator yellow actuator is energized")
+                        .written_externally(),
+                ),
+            right_outboard_elevator_eha_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "RIGHT_OUTBOARD_ELEV_EHA_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the right outboard elevator EHA is energized")
+                    .written_externally(),
+            ),
 
             left_inboard_elevator_green_actuator_position_demand_id: context
-                .get_identifier("LEFT_INBOARD_ELEV_GREEN_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_INBOARD_ELEV_GREEN_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left inboard elevator position commanded to the green actuator")
+                        .written_externally(),
+                ),
             left_inboard_elevator_eha_actuator_position_demand_id: context
-                .get_identifier("LEFT_INBOARD_ELEV_EHA_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_INBOARD_ELEV_EHA_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left inboard elevator position commanded to the EHA")
+                        .written_externally(),
+                ),
             left_outboard_elevator_green_actuator_position_demand_id: context
-                .get_identifier("LEFT_OUTBOARD_ELEV_GREEN_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_OUTBOARD_ELEV_GREEN_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left outboard elevator position commanded to the green actuator")
+                        .written_externally(),
+                ),
             left_outboard_elevator_eha_actuator_position_demand_id: context
-                .get_identifier("LEFT_OUTBOARD_ELEV_EHA_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "LEFT_OUTBOARD_ELEV_EHA_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Left outboard elevator position commanded to the EHA")
+                        .written_externally(),
+                ),
             right_inboard_elevator_yellow_actuator_position_demand_id: context
-                .get_identifier("RIGHT_INBOARD_ELEV_YELLOW_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_INBOARD_ELEV_YELLOW_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right inboard elevator position commanded to the yellow actuator")
+                        .written_externally(),
+                ),
             right_inboard_elevator_eha_actuator_position_demand_id: context
-                .get_identifier("RIGHT_INBOARD_ELEV_EHA_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_INBOARD_ELEV_EHA_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right inboard elevator position commanded to the EHA")
+                        .written_externally(),
+                ),
             right_outboard_elevator_yellow_actuator_position_demand_id: context
-                .get_identifier("RIGHT_OUTBOARD_ELEV_YELLOW_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_OUTBOARD_ELEV_YELLOW_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right outboard elevator position commanded to the yellow actuator")
+                        .written_externally(),
+                ),
             right_outboard_elevator_eha_actuator_position_demand_id: context
-                .get_identifier("RIGHT_OUTBOARD_ELEV_EHA_COMMANDED_POSITION".to_owned()),
+                .get_identifier_with_metadata(
+                    "RIGHT_OUTBOARD_ELEV_EHA_COMMANDED_POSITION".to_owned(),
+                    VariableMetadata::new()
+                        .unit("degrees")
+                        .description("Right outboard elevator position commanded to the EHA")
+                        .written_externally(),
+                ),
 
             left_inboard_position_requests_from_fbw: [Ratio::default(); 2],
             left_inboard_solenoid_energized_from_fbw: [false; 2],
@@ -5614,15 +6047,35 @@ struct TrimmableHorizontalStabilizerSystemHydraulicController {
 impl TrimmableHorizontalStabilizerSystemHydraulicController {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            ths_green_actuator_solenoid_id: context
-                .get_identifier("THS_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned()),
-            ths_yellow_actuator_solenoid_id: context
-                .get_identifier("THS_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned()),
+            ths_green_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "THS_GREEN_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the green THS actuator is energized")
+                    .written_externally(),
+            ),
+            ths_yellow_actuator_solenoid_id: context.get_identifier_with_metadata(
+                "THS_YELLOW_SERVO_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description("The servo solenoid of the yellow THS actuator is energized")
+                    .written_externally(),
+            ),
 
-            ths_green_actuator_position_demand_id: context
-                .get_identifier("THS_GREEN_COMMANDED_POSITION".to_owned()),
-            ths_yellow_actuator_position_demand_id: context
-                .get_identifier("THS_YELLOW_COMMANDED_POSITION".to_owned()),
+            ths_green_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "THS_GREEN_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("THS position commanded to the green actuator")
+                    .written_externally(),
+            ),
+            ths_yellow_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "THS_YELLOW_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("THS position commanded to the yellow actuator")
+                    .written_externally(),
+            ),
 
             position_requests_from_fbw: [Angle::default(); 2],
             solenoid_energized_from_fbw: [false; 2],
@@ -5761,39 +6214,113 @@ struct RudderSystemHydraulicController {
 impl RudderSystemHydraulicController {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            upper_rudder_yellow_actuator_hydraulic_solenoid_id: context.get_identifier(
+            upper_rudder_yellow_actuator_hydraulic_solenoid_id: context
+                .get_identifier_with_metadata(
                 "UPPER_RUDDER_YELLOW_EBHA_HYDRAULIC_MODE_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The hydraulic mode solenoid of the yellow upper rudder EBHA is energized",
+                    )
+                    .written_externally(),
             ),
-            upper_rudder_yellow_actuator_electric_solenoid_id: context.get_identifier(
+            upper_rudder_yellow_actuator_electric_solenoid_id: context
+                .get_identifier_with_metadata(
                 "UPPER_RUDDER_YELLOW_EBHA_ELECTRIC_MODE_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The electric mode solenoid of the yellow upper rudder EBHA is energized",
+                    )
+                    .written_externally(),
             ),
-            upper_rudder_green_actuator_hydraulic_solenoid_id: context.get_identifier(
+            upper_rudder_green_actuator_hydraulic_solenoid_id: context
+                .get_identifier_with_metadata(
                 "UPPER_RUDDER_GREEN_EBHA_HYDRAULIC_MODE_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The hydraulic mode solenoid of the green upper rudder EBHA is energized",
+                    )
+                    .written_externally(),
             ),
-            upper_rudder_green_actuator_electric_solenoid_id: context.get_identifier(
+            upper_rudder_green_actuator_electric_solenoid_id: context.get_identifier_with_metadata(
                 "UPPER_RUDDER_GREEN_EBHA_ELECTRIC_MODE_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The electric mode solenoid of the green upper rudder EBHA is energized",
+                    )
+                    .written_externally(),
             ),
-            lower_rudder_yellow_actuator_hydraulic_solenoid_id: context.get_identifier(
+            lower_rudder_yellow_actuator_hydraulic_solenoid_id: context
+                .get_identifier_with_metadata(
                 "LOWER_RUDDER_YELLOW_EBHA_HYDRAULIC_MODE_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The hydraulic mode solenoid of the yellow lower rudder EBHA is energized",
+                    )
+                    .written_externally(),
             ),
-            lower_rudder_yellow_actuator_electric_solenoid_id: context.get_identifier(
+            lower_rudder_yellow_actuator_electric_solenoid_id: context
+                .get_identifier_with_metadata(
                 "LOWER_RUDDER_YELLOW_EBHA_ELECTRIC_MODE_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The electric mode solenoid of the yellow lower rudder EBHA is energized",
+                    )
+                    .written_externally(),
             ),
-            lower_rudder_green_actuator_hydraulic_solenoid_id: context.get_identifier(
+            lower_rudder_green_actuator_hydraulic_solenoid_id: context
+                .get_identifier_with_metadata(
                 "LOWER_RUDDER_GREEN_EBHA_HYDRAULIC_MODE_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The hydraulic mode solenoid of the green lower rudder EBHA is energized",
+                    )
+                    .written_externally(),
             ),
-            lower_rudder_green_actuator_electric_solenoid_id: context.get_identifier(
+            lower_rudder_green_actuator_electric_solenoid_id: context.get_identifier_with_metadata(
                 "LOWER_RUDDER_GREEN_EBHA_ELECTRIC_MODE_SOLENOID_ENERGIZED".to_owned(),
+                VariableMetadata::new()
+                    .unit("bool")
+                    .description(
+                        "The electric mode solenoid of the green lower rudder EBHA is energized",
+                    )
+                    .written_externally(),
             ),
 
-            upper_rudder_yellow_actuator_position_demand_id: context
-                .get_identifier("UPPER_RUDDER_YELLOW_EBHA_COMMANDED_POSITION".to_owned()),
-            upper_rudder_green_actuator_position_demand_id: context
-                .get_identifier("UPPER_RUDDER_GREEN_EBHA_COMMANDED_POSITION".to_owned()),
-            lower_rudder_yellow_actuator_position_demand_id: context
-                .get_identifier("LOWER_RUDDER_YELLOW_EBHA_COMMANDED_POSITION".to_owned()),
-            lower_rudder_green_actuator_position_demand_id: context
-                .get_identifier("LOWER_RUDDER_GREEN_EBHA_COMMANDED_POSITION".to_owned()),
+            upper_rudder_yellow_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "UPPER_RUDDER_YELLOW_EBHA_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Upper rudder position commanded to the yellow EBHA")
+                    .written_externally(),
+            ),
+            upper_rudder_green_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "UPPER_RUDDER_GREEN_EBHA_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Upper rudder position commanded to the green EBHA")
+                    .written_externally(),
+            ),
+            lower_rudder_yellow_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "LOWER_RUDDER_YELLOW_EBHA_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Lower rudder position commanded to the yellow EBHA")
+                    .written_externally(),
+            ),
+            lower_rudder_green_actuator_position_demand_id: context.get_identifier_with_metadata(
+                "LOWER_RUDDER_GREEN_EBHA_COMMANDED_POSITION".to_owned(),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Lower rudder position commanded to the green EBHA")
+                    .written_externally(),
+            ),
 
             upper_position_requests_from_fbw: [Ratio::default(); 2],
             upper_hydraulic_mode_solenoid_energized_from_fbw: [false; 2],
@@ -6487,16 +7014,25 @@ struct SpoilerController {
 impl SpoilerController {
     fn new(context: &mut InitContext, spoiler_side: &str, spoiler_id_number: usize) -> Self {
         Self {
-            position_demand_id: context.get_identifier(format!(
-                "{}_SPOILER_{}_COMMANDED_POSITION",
-                spoiler_side, spoiler_id_number
-            )),
+            position_demand_id: context.get_identifier_with_metadata(
+                format!(
+                    "{}_SPOILER_{}_COMMANDED_POSITION",
+                    spoiler_side, spoiler_id_number
+                ),
+                VariableMetadata::new()
+                    .unit("degrees")
+                    .description("Spoiler position commanded to its actuator")
+                    .written_externally(),
+            ),
             electrical_mode_ena_id: if spoiler_id_number == 6 {
                 Some(
-                    context.get_identifier(format!(
-                        "{}_SPOILER_6_EBHA_ELECTRONIC_ENABLE",
-                        spoiler_side
-                    )),
+                    context.get_identifier_with_metadata(
+                        format!("{}_SPOILER_6_EBHA_ELECTRONIC_ENABLE", spoiler_side),
+                        VariableMetadata::new()
+                            .unit("bool")
+                            .description("The electrical backup of the spoiler 6 EBHA is enabled")
+                            .written_externally(),
+                    ),
                 )
             } else {
                 None
@@ -6555,8 +7091,13 @@ struct A380GravityExtension {
 impl A380GravityExtension {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            gear_gravity_extension_handle_position_id: context
-                .get_identifier("GRAVITYGEAR_ROTATE_PCT".to_owned()),
+            gear_gravity_extension_handle_position_id: context.get_identifier_with_metadata(
+                "GRAVITYGEAR_ROTATE_PCT".to_owned(),
+                VariableMetadata::new()
+                    .unit("percent")
+                    .description("Rotation of the landing gear gravity extension handle")
+                    .written_externally(),
+            ),
 
             handle_angle: Angle::default(),
         }
@@ -6598,11 +7139,16 @@ impl A380TiltingGears {
         }
     }
 
-    fn update(&mut self, context: &UpdateContext) {
+    fn update(&mut self, context: &UpdateContext, body_wheel_steering: &A380BodyWheelSteering) {
         self.left_body_gear.update(context);
         self.right_body_gear.update(context);
         self.left_wing_gear.update(context);
         self.right_wing_gear.update(context);
+
+        self.left_body_gear
+            .update_steering_angle(body_wheel_steering.left_position());
+        self.right_body_gear
+            .update_steering_angle(body_wheel_steering.right_position());
     }
 }
 impl SimulationElement for A380TiltingGears {
@@ -6634,6 +7180,7 @@ mod tests {
             landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
             shared::{EmergencyElectricalState, LgciuId, PotentialOrigin},
             simulation::{
+                frame_rate::{FrameRateHarness, FrameSchedule},
                 test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
                 Aircraft, InitContext,
             },
@@ -6921,6 +7468,13 @@ mod tests {
                 self.hydraulics.nose_steering.position_feedback()
             }
 
+            fn body_wheel_steering_positions(&self) -> (Angle, Angle) {
+                (
+                    self.hydraulics.body_wheel_steering.left_position(),
+                    self.hydraulics.body_wheel_steering.right_position(),
+                )
+            }
+
             fn is_cargo_fwd_door_locked_up(&self) -> bool {
                 self.hydraulics
                     .forward_cargo_door_controller
@@ -7142,6 +7696,17 @@ mod tests {
                 self.query(|a| a.nose_steering_position())
             }
 
+            fn body_wheel_steering_positions(&self) -> (Angle, Angle) {
+                self.query(|a| a.body_wheel_steering_positions())
+            }
+
+            fn body_gear_steering_angles(&mut self) -> (Angle, Angle) {
+                (
+                    self.read_by_name("GEAR_1_STEERING_ANGLE"),
+                    self.read_by_name("GEAR_2_STEERING_ANGLE"),
+                )
+            }
+
             fn is_cargo_fwd_door_locked_down(&mut self) -> bool {
                 self.read_by_name("FWD_DOOR_CARGO_LOCKED")
             }
@@ -7218,6 +7783,14 @@ mod tests {
                 self.read_by_name("HYD_BRAKE_NORM_RIGHT_PRESS")
             }
 
+            fn get_left_braking_force(&mut self) -> f64 {
+                self.read_by_name("BRAKE LEFT FORCE FACTOR")
+            }
+
+            fn get_right_braking_force(&mut self) -> f64 {
+                self.read_by_name("BRAKE RIGHT FORCE FACTOR")
+            }
+
             fn _get_brake_yellow_accumulator_pressure(&mut self) -> Pressure {
                 self.read_by_name("HYD_BRAKE_ALTN_ACC_PRESS")
             }
@@ -10214,6 +10787,140 @@ mod tests {
             assert!(test_bed.nose_steering_position().get::<degree>() <= 0.1);
         }
 
+        #[test]
+        fn body_wheel_steering_follows_nose_steering_at_full_tiller() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() > 70.);
+
+            let (left_body_wheel_angle, right_body_wheel_angle) =
+                test_bed.body_wheel_steering_positions();
+            assert!(left_body_wheel_angle.get::<degree>() < -14.);
+            assert!(right_body_wheel_angle.get::<degree>() < -14.);
+
+            let (left_gear_steering, right_gear_steering) = test_bed.body_gear_steering_angles();
+            assert!(
+                (left_gear_steering - left_body_wheel_angle)
+                    .abs()
+                    .get::<degree>()
+                    < 0.01
+            );
+            assert!(
+                (right_gear_steering - right_body_wheel_angle)
+                    .abs()
+                    .get::<degree>()
+                    < 0.01
+            );
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(0.))
+                .run_waiting_for(Duration::from_secs_f64(15.));
+
+            let (left_body_wheel_angle, right_body_wheel_angle) =
+                test_bed.body_wheel_steering_positions();
+            assert!(left_body_wheel_angle.get::<degree>().abs() < 0.1);
+            assert!(right_body_wheel_angle.get::<degree>().abs() < 0.1);
+        }
+
+        #[test]
+        fn nose_steering_authority_reduced_with_burst_nose_tyre() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .run_one_tick();
+
+            test_bed.fail(FailureType::TyreBurst(22));
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() >= 36.9);
+            assert!(test_bed.nose_steering_position().get::<degree>() <= 37.1);
+        }
+
+        #[test]
+        fn burst_tyre_reduces_braking_force_on_its_side() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed.fail(FailureType::TyreBurst(15));
+            test_bed.fail(FailureType::TyreBurst(19));
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            let left_force = test_bed.get_left_braking_force();
+            let right_force = test_bed.get_right_braking_force();
+
+            assert!(left_force > 0.5);
+            assert!((right_force - left_force * 0.75).abs() < 0.01);
+        }
+
+        #[test]
+        fn tyres_with_a_brake_are_the_braked_tyres() {
+            let tyres_with_brake: Vec<usize> = A380Hydraulic::TYRE_BRAKE_NUMBERS
+                .iter()
+                .enumerate()
+                .filter(|(_, brake)| brake.is_some())
+                .map(|(index, _)| index + 1)
+                .collect();
+
+            let mut braked_tyres: Vec<usize> = A380BrakingForce::LEFT_BRAKED_TYRES
+                .iter()
+                .chain(A380BrakingForce::RIGHT_BRAKED_TYRES.iter())
+                .copied()
+                .collect();
+            braked_tyres.sort_unstable();
+
+            assert_eq!(tyres_with_brake, braked_tyres);
+        }
+
+        #[test]
+        fn body_wheel_steering_does_not_move_without_yellow_pressure() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            let (left_body_wheel_angle, right_body_wheel_angle) =
+                test_bed.body_wheel_steering_positions();
+            assert!(left_body_wheel_angle.get::<degree>().abs() < 0.1);
+            assert!(right_body_wheel_angle.get::<degree>().abs() < 0.1);
+        }
+
         #[test]
         fn yellow_epump_has_cavitation_at_low_air_press() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10605,5 +11312,59 @@ mod tests {
             assert!(test_bed.is_cargo_fwd_door_locked_up());
             assert!(test_bed.is_cargo_aft_door_locked_up());
         }
+
+        #[test]
+        fn hydraulics_behave_the_same_at_any_frame_rate() {
+            // Pressures are averaged as pump regulation ripple differs between frame rates.
+            FrameRateHarness::new(Duration::from_secs(60), || {
+                test_bed_on_ground_with()
+                    .engines_off()
+                    .on_the_ground()
+                    .set_cold_dark_inputs()
+            })
+            .at(Duration::from_secs(1), |test_bed| {
+                test_bed
+                    .start_eng1(Ratio::new::<percent>(80.))
+                    .start_eng2(Ratio::new::<percent>(80.))
+                    .start_eng3(Ratio::new::<percent>(80.))
+                    .start_eng4(Ratio::new::<percent>(80.))
+            })
+            .at(Duration::from_secs(10), |test_bed| {
+                test_bed.set_flaps_handle_position(4)
+            })
+            .at(Duration::from_secs(40), |test_bed| {
+                test_bed.stop_eng1().stop_eng2().stop_eng3().stop_eng4()
+            })
+            .at(Duration::from_secs(45), |test_bed| {
+                test_bed.set_yellow_e_pump_a(true)
+            })
+            .probe_averaged(
+                "HYD",
+                "green pressure",
+                50.,
+                Duration::from_secs(5),
+                |test_bed| test_bed.green_pressure().get::<psi>(),
+            )
+            .probe_averaged(
+                "HYD",
+                "yellow pressure",
+                50.,
+                Duration::from_secs(5),
+                |test_bed| test_bed.yellow_pressure().get::<psi>(),
+            )
+            .probe("HYD", "green reservoir", 0.05, |test_bed| {
+                test_bed.get_green_reservoir_volume().get::<liter>()
+            })
+            .probe("FLAPS", "flaps position", 0.5, |test_bed| {
+                test_bed.get_flaps_left_position_percent()
+            })
+            .probe("FLAPS", "slats position", 0.5, |test_bed| {
+                test_bed.get_slats_left_position_percent()
+            })
+            .assert_frame_rate_independent(
+                &FrameSchedule::fixed_rate(60),
+                &FrameSchedule::typical(),
+            );
+        }
     }
 }
@@ -4,10 +4,12 @@ mod air_conditioning;
 mod avionics_data_communication_network;
 mod control_display_system;
 mod electrical;
+pub mod failures;
 mod fuel;
 pub mod hydraulic;
 mod icing;
 mod navigation;
+mod payload;
 mod pneumatic;
 mod power_consumption;
 
@@ -24,15 +26,16 @@ use electrical::{
 };
 use hydraulic::{A380Hydraulic, A380HydraulicOverheadPanel};
 use icing::Icing;
-use navigation::A380RadioAltimeters;
+use navigation::{A380GpsReceivers, A380RadioAltimeters};
+use payload::A380Payload;
 use power_consumption::A380PowerConsumption;
 use uom::si::{f64::Length, length::nautical_mile};
 
 use systems::{
     accept_iterable,
     apu::{
-        Aps3200ApuGenerator, Aps3200StartMotor, AuxiliaryPowerUnit, AuxiliaryPowerUnitFactory,
-        AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel,
+        Aps3200StartMotor, AuxiliaryPowerUnit, AuxiliaryPowerUnitFactory,
+        AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel, Pw980ApuGenerator,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
     engine::engine_wing_flex::EnginesFlexiblePhysics,
@@ -45,7 +48,8 @@ use systems::{
     },
     shared::ElectricalBusType,
     simulation::{
-        Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+        profiling::measure, Aircraft, InitContext, SimulationElement, SimulationElementVisitor,
+        UpdateContext,
     },
     structural_flex::elevator_flex::FlexibleElevators,
 };
@@ -55,7 +59,7 @@ pub struct A380 {
     adirs: AirDataInertialReferenceSystem,
     adirs_overhead: AirDataInertialReferenceSystemOverheadPanel,
     air_conditioning: A380AirConditioning,
-    apu: AuxiliaryPowerUnit<Aps3200ApuGenerator, Aps3200StartMotor, 2>,
+    apu: AuxiliaryPowerUnit<Pw980ApuGenerator, Aps3200StartMotor, 2>,
     apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
     apu_overhead: AuxiliaryPowerUnitOverheadPanel,
     pneumatic_overhead: A380PneumaticOverheadPanel,
@@ -76,8 +80,12 @@ pub struct A380 {
     hydraulic_overhead: A380HydraulicOverheadPanel,
     autobrake_panel: AutobrakePanel,
     landing_gear: LandingGear,
+    // Unlike the A320, the payload isn't fed into a weight and balance computer, as the A380's
+    // station arms, mean aerodynamic chord and CG envelopes aren't modelled yet.
+    payload: A380Payload,
     pneumatic: A380Pneumatic,
     radio_altimeters: A380RadioAltimeters,
+    gps_receivers: A380GpsReceivers,
     engines_flex_physics: EnginesFlexiblePhysics<4>,
     elevators_flex_physics: FlexibleElevators,
     cds: A380ControlDisplaySystem,
@@ -121,8 +129,10 @@ impl A380 {
             hydraulic_overhead: A380HydraulicOverheadPanel::new(context),
             autobrake_panel: AutobrakePanel::new(context),
             landing_gear: LandingGear::new(context),
+            payload: A380Payload::new(context),
             pneumatic: A380Pneumatic::new(context),
             radio_altimeters: A380RadioAltimeters::new(context),
+            gps_receivers: A380GpsReceivers::new(context),
             engines_flex_physics: EnginesFlexiblePhysics::new(context),
             elevators_flex_physics: FlexibleElevators::new(context),
             cds: A380ControlDisplaySystem::new(context),
@@ -152,51 +162,57 @@ impl Aircraft for A380 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
-        self.apu.update_before_electrical(
-            context,
-            &self.apu_overhead,
-            &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
-            // This will be replaced when integrating the whole electrical system.
-            // For now we use the same logic as found in the JavaScript code; ignoring whether or not
-            // the engine generators are supplying electricity.
-            (self.electrical_overhead.apu_generator_is_on(1)
-                || self.electrical_overhead.apu_generator_is_on(2))
-                && !(self.electrical_overhead.external_power_is_on(1)
-                    && self.electrical_overhead.external_power_is_available(1)),
-            self.pneumatic.apu_bleed_air_valve(),
-            self.fuel.left_inner_tank_has_fuel_remaining(),
-        );
+        measure("apu", || {
+            self.apu.update_before_electrical(
+                context,
+                &self.apu_overhead,
+                &self.apu_fire_overhead,
+                self.pneumatic_overhead.apu_bleed_is_on(),
+                // This will be replaced when integrating the whole electrical system.
+                // For now we use the same logic as found in the JavaScript code; ignoring whether or not
+                // the engine generators are supplying electricity.
+                (self.electrical_overhead.apu_generator_is_on(1)
+                    || self.electrical_overhead.apu_generator_is_on(2))
+                    && !(self.electrical_overhead.external_power_is_on(1)
+                        && self.electrical_overhead.external_power_is_available(1)),
+                self.pneumatic.apu_bleed_air_valve(),
+                self.fuel.left_inner_tank_has_fuel_remaining(),
+            )
+        });
+        measure("fuel", || self.fuel.update(context, self.apu.fuel_flow()));
 
-        self.electrical.update(
-            context,
-            electricity,
-            &self.ext_pwrs,
-            &self.electrical_overhead,
-            &self.emergency_electrical_overhead,
-            &mut self.apu,
-            &self.engine_fire_overhead,
-            [
-                &self.engine_1,
-                &self.engine_2,
-                &self.engine_3,
-                &self.engine_4,
-            ],
-            self.lgcius.lgciu1(),
-            &self.adirs,
-        );
+        measure("electrical", || {
+            self.electrical.update(
+                context,
+                electricity,
+                &self.ext_pwrs,
+                &self.electrical_overhead,
+                &self.emergency_electrical_overhead,
+                &mut self.apu,
+                &self.engine_fire_overhead,
+                [
+                    &self.engine_1,
+                    &self.engine_2,
+                    &self.engine_3,
+                    &self.engine_4,
+                ],
+                self.lgcius.lgciu1(),
+                &self.adirs,
+            )
+        });
 
         self.electrical_overhead
             .update_after_electrical(&self.electrical, electricity);
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
+        self.payload.update(context);
     }
 
     fn update_after_power_distribution(&mut self, context: &UpdateContext) {
         self.apu.update_after_power_distribution();
         self.apu_overhead.update_after_apu(&self.apu);
 
-        self.adcn.update();
+        measure("adcn", || self.adcn.update());
         self.lgcius.update(
             context,
             &self.landing_gear,
@@ -205,22 +221,25 @@ impl Aircraft for A380 {
         );
 
         self.radio_altimeters.update(context);
+        self.gps_receivers.update(context);
 
-        self.hydraulic.update(
-            context,
-            [
-                &self.engine_1,
-                &self.engine_2,
-                &self.engine_3,
-                &self.engine_4,
-            ],
-            &self.hydraulic_overhead,
-            &self.autobrake_panel,
-            &self.engine_fire_overhead,
-            &self.lgcius,
-            &self.pneumatic,
-            &self.adirs,
-        );
+        measure("hydraulic", || {
+            self.hydraulic.update(
+                context,
+                [
+                    &self.engine_1,
+                    &self.engine_2,
+                    &self.engine_3,
+                    &self.engine_4,
+                ],
+                &self.hydraulic_overhead,
+                &self.autobrake_panel,
+                &self.engine_fire_overhead,
+                &self.lgcius,
+                &self.pneumatic,
+                &self.adirs,
+            )
+        });
 
         self.pneumatic.update_hydraulic_reservoir_spatial_volumes(
             self.hydraulic.green_reservoir(),
@@ -229,57 +248,82 @@ impl Aircraft for A380 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
-        self.adirs_overhead.update(context, &self.adirs);
+        measure("adirs", || {
+            self.adirs.update_gps_data([
+                self.gps_receivers.receiver(1),
+                self.gps_receivers.receiver(2),
+            ]);
+            self.adirs.update(context, &self.adirs_overhead);
+            self.adirs_overhead.update(context, &self.adirs);
+        });
 
-        self.power_consumption.update(context);
+        measure("power_consumption", || {
+            self.power_consumption.update(context)
+        });
 
-        self.pneumatic.update(
-            context,
-            [
-                &self.engine_1,
-                &self.engine_2,
-                &self.engine_3,
-                &self.engine_4,
-            ],
-            &self.pneumatic_overhead,
-            &self.engine_fire_overhead,
-            &self.apu,
-            &self.air_conditioning,
-        );
-        self.air_conditioning
-            .mix_packs_air_update(self.pneumatic.packs());
-        self.air_conditioning.update(
-            context,
-            &self.adirs,
-            &self.adcn,
-            [
-                &self.engine_1,
-                &self.engine_2,
-                &self.engine_3,
-                &self.engine_4,
-            ],
-            &self.engine_fire_overhead,
-            &self.pneumatic,
-            &self.pneumatic_overhead,
-            &self.pressurization_overhead,
-            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
-        );
+        measure("pneumatic", || {
+            self.pneumatic.update(
+                context,
+                [
+                    &self.engine_1,
+                    &self.engine_2,
+                    &self.engine_3,
+                    &self.engine_4,
+                ],
+                &self.pneumatic_overhead,
+                &self.engine_fire_overhead,
+                &self.apu,
+                &self.air_conditioning,
+            )
+        });
+        measure("air_conditioning", || {
+            self.air_conditioning
+                .mix_packs_air_update(self.pneumatic.packs());
+            self.air_conditioning.update(
+                context,
+                &self.adirs,
+                &self.adcn,
+                [
+                    &self.engine_1,
+                    &self.engine_2,
+                    &self.engine_3,
+                    &self.engine_4,
+                ],
+                &self.engine_fire_overhead,
+                &self.pneumatic,
+                &self.pneumatic_overhead,
+                &self.pressurization_overhead,
+                [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            );
+        });
 
-        self.engines_flex_physics.update(context);
-        self.elevators_flex_physics.update(
-            context,
-            [
-                self.hydraulic.left_elevator_aero_torques(),
-                self.hydraulic.right_elevator_aero_torques(),
-            ],
-            self.hydraulic.up_down_rudder_aero_torques(),
-        );
-        self.cds.update();
+        measure("flex_physics", || {
+            self.engines_flex_physics.update(context);
+            self.elevators_flex_physics.update(
+                context,
+                [
+                    self.hydraulic.left_elevator_aero_torques(),
+                    self.hydraulic.right_elevator_aero_torques(),
+                ],
+                self.hydraulic.up_down_rudder_aero_torques(),
+            );
+        });
+        measure("cds", || self.cds.update(context));
 
-        self.icing_simulation.update(context);
+        measure("icing", || self.icing_simulation.update(context));
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        measure("egpwc", || {
+            self.egpwc.update(
+                context,
+                &self.adirs,
+                [
+                    self.radio_altimeters.radio_altimeter(1),
+                    self.radio_altimeters.radio_altimeter(2),
+                ],
+                self.gps_receivers.receiver(1),
+                self.lgcius.lgciu1(),
+            )
+        });
     }
 }
 impl SimulationElement for A380 {
@@ -306,10 +350,12 @@ impl SimulationElement for A380 {
         accept_iterable!(self.ext_pwrs, visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
+        self.gps_receivers.accept(visitor);
         self.autobrake_panel.accept(visitor);
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
         self.landing_gear.accept(visitor);
+        self.payload.accept(visitor);
         self.pneumatic.accept(visitor);
         self.elevators_flex_physics.accept(visitor);
         self.engines_flex_physics.accept(visitor);
@@ -320,3 +366,22 @@ impl SimulationElement for A380 {
         visitor.visit(self);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::simulation::{StartState, VariableCatalogue};
+
+    #[test]
+    fn variables_read_are_written_by_the_simulation_or_externally() {
+        let catalogue = VariableCatalogue::of(StartState::Cruise, A380::new);
+
+        assert_eq!(
+            catalogue
+                .read_but_never_written()
+                .map(|entry| entry.name())
+                .collect::<Vec<_>>(),
+            Vec::<&str>::new()
+        );
+    }
+}
@@ -1,8 +1,10 @@
+use systems::accept_iterable;
 use systems::navigation::ala52b::{
     Ala52BAircraftInstallationDelay, Ala52BRadioAltimeter, Ala52BTransceiverPair,
 };
-use systems::navigation::radio_altimeter::AntennaInstallation;
-use systems::shared::ElectricalBusType;
+use systems::navigation::gps::GpsReceiver;
+use systems::navigation::radio_altimeter::{AntennaInstallation, RadioAltimeter};
+use systems::shared::{arinc429::Arinc429Word, ElectricalBusType};
 use systems::simulation::{
     InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
 };
@@ -74,6 +76,15 @@ impl A380RadioAltimeters {
         self.radio_altimeter_2.update(context);
         self.radio_altimeter_3.update(context);
     }
+
+    pub fn radio_altimeter(&self, number: usize) -> &A380RadioAltimeter {
+        match number {
+            1 => &self.radio_altimeter_1,
+            2 => &self.radio_altimeter_2,
+            3 => &self.radio_altimeter_3,
+            _ => panic!("Invalid radio altimeter number"),
+        }
+    }
 }
 
 impl SimulationElement for A380RadioAltimeters {
@@ -115,6 +126,12 @@ impl A380RadioAltimeter {
     }
 }
 
+impl RadioAltimeter for A380RadioAltimeter {
+    fn radio_altitude(&self) -> Arinc429Word<Length> {
+        self.radio_altimeter.radio_altitude()
+    }
+}
+
 impl SimulationElement for A380RadioAltimeter {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.transceivers.accept(visitor);
@@ -123,3 +140,37 @@ impl SimulationElement for A380RadioAltimeter {
         visitor.visit(self);
     }
 }
+
+/// The GPS receivers housed in the two multi mode receivers (MMR).
+pub struct A380GpsReceivers {
+    gps_receivers: [GpsReceiver; 2],
+}
+
+impl A380GpsReceivers {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            gps_receivers: [
+                GpsReceiver::new(context, 1, ElectricalBusType::AlternatingCurrentEssential),
+                GpsReceiver::new(context, 2, ElectricalBusType::AlternatingCurrent(2)),
+            ],
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.gps_receivers
+            .iter_mut()
+            .for_each(|gps_receiver| gps_receiver.update(context));
+    }
+
+    pub fn receiver(&self, number: usize) -> &GpsReceiver {
+        &self.gps_receivers[number - 1]
+    }
+}
+
+impl SimulationElement for A380GpsReceivers {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.gps_receivers, visitor);
+
+        visitor.visit(self);
+    }
+}
@@ -0,0 +1,110 @@
+use uom::si::{f64::Mass, mass::kilogram};
+
+use systems::{
+    payload::{CargoInfo, PaxInfo, Payload},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+#[cfg(test)]
+pub mod test;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum A380Pax {
+    MainFwd,
+    MainMidFwd,
+    MainMidAft,
+    MainAft,
+    UpperFwd,
+    UpperMid,
+    UpperAft,
+}
+impl A380Pax {
+    pub fn iterator() -> impl Iterator<Item = A380Pax> {
+        [
+            A380Pax::MainFwd,
+            A380Pax::MainMidFwd,
+            A380Pax::MainMidAft,
+            A380Pax::MainAft,
+            A380Pax::UpperFwd,
+            A380Pax::UpperMid,
+            A380Pax::UpperAft,
+        ]
+        .iter()
+        .copied()
+    }
+
+    pub fn info(&self) -> PaxInfo {
+        match self {
+            A380Pax::MainFwd => PaxInfo::new(70, "PAX_MAIN_FWD", "PAYLOAD_STATION_1_REQ"),
+            A380Pax::MainMidFwd => PaxInfo::new(106, "PAX_MAIN_MID_FWD", "PAYLOAD_STATION_2_REQ"),
+            A380Pax::MainMidAft => PaxInfo::new(98, "PAX_MAIN_MID_AFT", "PAYLOAD_STATION_3_REQ"),
+            A380Pax::MainAft => PaxInfo::new(100, "PAX_MAIN_AFT", "PAYLOAD_STATION_4_REQ"),
+            A380Pax::UpperFwd => PaxInfo::new(14, "PAX_UPPER_FWD", "PAYLOAD_STATION_5_REQ"),
+            A380Pax::UpperMid => PaxInfo::new(76, "PAX_UPPER_MID", "PAYLOAD_STATION_6_REQ"),
+            A380Pax::UpperAft => PaxInfo::new(54, "PAX_UPPER_AFT", "PAYLOAD_STATION_7_REQ"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum A380Cargo {
+    FwdBaggage,
+    AftContainer,
+    AftBulkLoose,
+}
+impl A380Cargo {
+    pub fn iterator() -> impl Iterator<Item = A380Cargo> {
+        [
+            A380Cargo::FwdBaggage,
+            A380Cargo::AftContainer,
+            A380Cargo::AftBulkLoose,
+        ]
+        .iter()
+        .copied()
+    }
+
+    pub fn info(&self) -> CargoInfo {
+        match self {
+            A380Cargo::FwdBaggage => CargoInfo::new(
+                Mass::new::<kilogram>(28577.),
+                "CARGO_FWD_BAGGAGE_CONTAINER",
+                "PAYLOAD_STATION_8_REQ",
+            ),
+            A380Cargo::AftContainer => CargoInfo::new(
+                Mass::new::<kilogram>(20310.),
+                "CARGO_AFT_CONTAINER",
+                "PAYLOAD_STATION_9_REQ",
+            ),
+            A380Cargo::AftBulkLoose => CargoInfo::new(
+                Mass::new::<kilogram>(2513.),
+                "CARGO_AFT_BULK_LOOSE",
+                "PAYLOAD_STATION_10_REQ",
+            ),
+        }
+    }
+}
+
+pub struct A380Payload {
+    payload: Payload,
+}
+impl A380Payload {
+    pub fn new(context: &mut InitContext) -> Self {
+        let pax: Vec<_> = A380Pax::iterator().map(|ps| ps.info()).collect();
+        let cargo: Vec<_> = A380Cargo::iterator().map(|cs| cs.info()).collect();
+
+        A380Payload {
+            payload: Payload::new(context, &pax, &cargo),
+        }
+    }
+
+    pub(crate) fn update(&mut self, context: &UpdateContext) {
+        self.payload.update(context);
+    }
+}
+impl SimulationElement for A380Payload {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.payload.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
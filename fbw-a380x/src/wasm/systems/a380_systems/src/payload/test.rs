@@ -0,0 +1,271 @@
+pub const MINUTES_TO_SECONDS: u64 = 60;
+
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+use rand::SeedableRng;
+use std::time::Duration;
+use systems::{electrical::Electricity, payload::BoardingRate};
+use uom::si::mass::pound;
+
+use super::*;
+use crate::payload::A380Payload;
+use crate::systems::simulation::{
+    test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+    Aircraft, SimulationElement, SimulationElementVisitor,
+};
+
+struct BoardingTestAircraft {
+    boarding: A380Payload,
+}
+
+impl BoardingTestAircraft {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            boarding: A380Payload::new(context),
+        }
+    }
+}
+impl Aircraft for BoardingTestAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        context: &UpdateContext,
+        _electricity: &mut Electricity,
+    ) {
+        self.boarding.update(context);
+    }
+}
+impl SimulationElement for BoardingTestAircraft {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.boarding.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+struct BoardingTestBed {
+    test_bed: SimulationTestBed<BoardingTestAircraft>,
+}
+impl BoardingTestBed {
+    fn new() -> Self {
+        BoardingTestBed {
+            test_bed: SimulationTestBed::new(BoardingTestAircraft::new),
+        }
+    }
+
+    fn and_run(mut self) -> Self {
+        self.run();
+
+        self
+    }
+
+    fn and_stabilize(mut self) -> Self {
+        let five_minutes = 5 * MINUTES_TO_SECONDS;
+        self.test_bed
+            .run_multiple_frames(Duration::from_secs(five_minutes));
+
+        self
+    }
+
+    fn init_vars(mut self) -> Self {
+        self.write_by_name("BOARDING_RATE", BoardingRate::Instant);
+        self.write_by_name("WB_PER_PAX_WEIGHT", Payload::DEFAULT_PER_PAX_WEIGHT_KG);
+
+        self
+    }
+
+    fn instant_board_rate(mut self) -> Self {
+        self.write_by_name("BOARDING_RATE", BoardingRate::Instant);
+
+        self
+    }
+
+    fn fast_board_rate(mut self) -> Self {
+        self.write_by_name("BOARDING_RATE", BoardingRate::Fast);
+
+        self
+    }
+
+    fn seat_words(ps: A380Pax, pax_qty: i32, seed: u64) -> Vec<u64> {
+        let info = ps.info();
+        assert!(pax_qty <= info.max_pax);
+
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let binding: Vec<i32> = (0..info.max_pax).collect();
+        let choices = binding
+            .iter()
+            .choose_multiple(&mut rng, pax_qty.try_into().unwrap());
+
+        let mut seat_words: Vec<u64> = vec![0; info.seat_words()];
+        for c in choices {
+            seat_words[*c as usize / 53] ^= 1 << (c % 53);
+        }
+
+        seat_words
+    }
+
+    fn load_pax(&mut self, ps: A380Pax, pax_qty: i32) {
+        let per_pax_weight: Mass = Mass::new::<kilogram>(self.read_by_name("WB_PER_PAX_WEIGHT"));
+
+        let seat_words = Self::seat_words(ps, pax_qty, 380320);
+        for (pax_id, seat_word) in ps.info().pax_ids().iter().zip(seat_words) {
+            self.write_by_name(pax_id, seat_word);
+        }
+
+        let payload = Mass::new::<pound>(pax_qty as f64 * per_pax_weight.get::<pound>());
+        self.write_by_name(&ps.info().payload_id, payload);
+    }
+
+    fn target_pax(&mut self, ps: A380Pax, pax_qty: i32) {
+        let seat_words = Self::seat_words(ps, pax_qty, 747777);
+        for (pax_id, seat_word) in ps.info().pax_ids().iter().zip(seat_words) {
+            self.write_by_name(&format!("{}_DESIRED", pax_id), seat_word);
+        }
+    }
+
+    fn target_cargo(&mut self, cs: A380Cargo, cargo_qty: Mass) {
+        assert!(cargo_qty <= cs.info().max_cargo);
+
+        self.write_by_name(
+            &format!("{}_DESIRED", cs.info().cargo_id),
+            cargo_qty.get::<kilogram>(),
+        );
+    }
+
+    fn start_boarding(mut self) -> Self {
+        self.write_by_name("BOARDING_STARTED_BY_USR", true);
+        self
+    }
+
+    fn boarding_stopped(&mut self) {
+        let boarded_var: bool = self.read_by_name("BOARDING_STARTED_BY_USR");
+        assert!(!self.query(|a| a.boarding.payload.is_boarding()));
+        assert!(!boarded_var);
+    }
+
+    fn with_pax(mut self, ps: A380Pax, pax_qty: i32) -> Self {
+        self.load_pax(ps, pax_qty);
+        self
+    }
+
+    fn with_pax_target(mut self, ps: A380Pax, pax_qty: i32) -> Self {
+        self.target_pax(ps, pax_qty);
+        self
+    }
+
+    fn target_full_pax(mut self) -> Self {
+        for ps in A380Pax::iterator() {
+            self.target_pax(ps, ps.info().max_pax);
+        }
+        self
+    }
+
+    fn target_full_cargo(mut self) -> Self {
+        for cs in A380Cargo::iterator() {
+            self.target_cargo(cs, cs.info().max_cargo);
+        }
+        self
+    }
+
+    fn has_full_pax(&mut self) {
+        let per_pax_weight: Mass = Mass::new::<kilogram>(self.read_by_name("WB_PER_PAX_WEIGHT"));
+
+        for ps in A380Pax::iterator() {
+            let pax_num = ps.info().max_pax;
+            let pax_payload = Mass::new::<pound>(pax_num as f64 * per_pax_weight.get::<pound>());
+            assert_eq!(self.pax_num(ps), pax_num);
+            assert_eq!(
+                self.query(|a| a.boarding.payload.pax_payload(ps as usize))
+                    .get::<pound>()
+                    .floor(),
+                pax_payload.get::<pound>().floor()
+            );
+        }
+    }
+
+    fn pax_num(&self, ps: A380Pax) -> i32 {
+        self.query(|a| a.boarding.payload.pax_num(ps as usize))
+    }
+}
+
+impl TestBed for BoardingTestBed {
+    type Aircraft = BoardingTestAircraft;
+
+    fn test_bed(&self) -> &SimulationTestBed<BoardingTestAircraft> {
+        &self.test_bed
+    }
+
+    fn test_bed_mut(&mut self) -> &mut SimulationTestBed<BoardingTestAircraft> {
+        &mut self.test_bed
+    }
+}
+
+fn test_bed_with() -> BoardingTestBed {
+    BoardingTestBed::new()
+}
+
+#[test]
+fn stations_with_more_seats_than_a_seat_word_use_multiple_variables() {
+    let test_bed = test_bed_with().init_vars();
+
+    assert!(test_bed.contains_variable_with_name("PAX_MAIN_MID_FWD"));
+    assert!(test_bed.contains_variable_with_name("PAX_MAIN_MID_FWD_2"));
+    assert!(test_bed.contains_variable_with_name("PAX_MAIN_MID_FWD_2_DESIRED"));
+    assert!(!test_bed.contains_variable_with_name("PAX_UPPER_FWD_2"));
+}
+
+#[test]
+fn full_two_deck_cabin_boards_all_passengers() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .target_full_pax()
+        .instant_board_rate()
+        .start_boarding()
+        .and_run();
+
+    test_bed.has_full_pax();
+    test_bed.boarding_stopped();
+
+    let total_pax: i32 = A380Pax::iterator().map(|ps| test_bed.pax_num(ps)).sum();
+    assert!(total_pax > 500);
+
+    let upper_deck_pax: i32 = [A380Pax::UpperFwd, A380Pax::UpperMid, A380Pax::UpperAft]
+        .iter()
+        .map(|&ps| test_bed.pax_num(ps))
+        .sum();
+    assert_eq!(upper_deck_pax, 144);
+}
+
+#[test]
+fn boards_seats_beyond_the_first_seat_word_one_by_one() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .with_pax(A380Pax::MainMidFwd, 100)
+        .with_pax_target(A380Pax::MainMidFwd, 106)
+        .fast_board_rate()
+        .start_boarding()
+        .and_run()
+        .and_stabilize();
+
+    assert_eq!(test_bed.pax_num(A380Pax::MainMidFwd), 106);
+    test_bed.boarding_stopped();
+}
+
+#[test]
+fn cargo_is_loaded_into_the_payload_stations_after_the_passengers() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .target_full_cargo()
+        .instant_board_rate()
+        .start_boarding()
+        .and_run()
+        .and_run();
+
+    for (cs, station) in A380Cargo::iterator().zip(8..) {
+        let payload: Mass = test_bed.read_by_name(&format!("PAYLOAD_STATION_{}_REQ", station));
+        assert_eq!(
+            payload.get::<pound>().floor(),
+            cs.info().max_cargo.get::<pound>().floor()
+        );
+    }
+}
@@ -29,7 +29,7 @@ use systems::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
     },
 };
 
@@ -1136,16 +1136,53 @@ pub struct FullAuthorityDigitalEngineControl {
 impl FullAuthorityDigitalEngineControl {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            engine_1_state_id: context.get_identifier("ENGINE_STATE:1".to_owned()),
-            engine_2_state_id: context.get_identifier("ENGINE_STATE:2".to_owned()),
-            engine_3_state_id: context.get_identifier("ENGINE_STATE:3".to_owned()),
-            engine_4_state_id: context.get_identifier("ENGINE_STATE:4".to_owned()),
+            engine_1_state_id: context.get_identifier_with_metadata(
+                "ENGINE_STATE:1".to_owned(),
+                VariableMetadata::new()
+                    .unit("enum")
+                    .description(
+                        "State of engine 1: off, on, starting, restarting or shutting down",
+                    )
+                    .written_externally(),
+            ),
+            engine_2_state_id: context.get_identifier_with_metadata(
+                "ENGINE_STATE:2".to_owned(),
+                VariableMetadata::new()
+                    .unit("enum")
+                    .description(
+                        "State of engine 2: off, on, starting, restarting or shutting down",
+                    )
+                    .written_externally(),
+            ),
+            engine_3_state_id: context.get_identifier_with_metadata(
+                "ENGINE_STATE:3".to_owned(),
+                VariableMetadata::new()
+                    .unit("enum")
+                    .description(
+                        "State of engine 3: off, on, starting, restarting or shutting down",
+                    )
+                    .written_externally(),
+            ),
+            engine_4_state_id: context.get_identifier_with_metadata(
+                "ENGINE_STATE:4".to_owned(),
+                VariableMetadata::new()
+                    .unit("enum")
+                    .description(
+                        "State of engine 4: off, on, starting, restarting or shutting down",
+                    )
+                    .written_externally(),
+            ),
             engine_1_state: EngineState::Off,
             engine_2_state: EngineState::Off,
             engine_3_state: EngineState::Off,
             engine_4_state: EngineState::Off,
-            engine_mode_selector1_id: context
-                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
+            engine_mode_selector1_id: context.get_identifier_with_metadata(
+                "TURB ENG IGNITION SWITCH EX1:1".to_owned(),
+                VariableMetadata::new()
+                    .unit("enum")
+                    .description("Engine mode selector position: crank, norm or ignition")
+                    .written_externally(),
+            ),
             engine_mode_selector1_position: EngineModeSelector::Norm,
         }
     }
@@ -1,10 +1,13 @@
+use systems::accept_iterable;
 use systems::simulation::InitContext;
 use systems::{
-    electrical::consumption::{FlightPhasePowerConsumer, PowerConsumerFlightPhase},
+    electrical::consumption::{
+        FlightPhasePowerConsumer, FrequencyDependentPowerConsumer, PowerConsumerFlightPhase,
+    },
     shared::ElectricalBusType,
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
-use uom::si::{f64::*, power::watt};
+use uom::si::{f64::*, frequency::hertz, power::watt};
 
 /// This type provides an aggregated form of power consumption.
 /// We haven't yet implemented all power consumers and thus need something to
@@ -24,71 +27,39 @@ pub(super) struct A380PowerConsumption {
     dc_hot_bus_1_consumer: FlightPhasePowerConsumer,
     dc_hot_bus_2_consumer: FlightPhasePowerConsumer,
     dc_gnd_flt_service_consumer: FlightPhasePowerConsumer,
+    /// The fans and pumps driven by induction motors on AC BUS 1 and 2.
+    ac_bus_motor_consumers: [FrequencyDependentPowerConsumer; 2],
 }
 impl A380PowerConsumption {
+    const MOTOR_NOMINAL_FREQUENCY_HZ: f64 = 400.;
+    const MOTOR_MINIMUM_FREQUENCY_HZ: f64 = 360.;
+    const MOTOR_MAXIMUM_FREQUENCY_HZ: f64 = 800.;
+
+    const FLIGHT_PHASES: [PowerConsumerFlightPhase; 6] = [
+        PowerConsumerFlightPhase::BeforeStart,
+        PowerConsumerFlightPhase::AfterStart,
+        PowerConsumerFlightPhase::Takeoff,
+        PowerConsumerFlightPhase::Flight,
+        PowerConsumerFlightPhase::Landing,
+        PowerConsumerFlightPhase::TaxiIn,
+    ];
+
     pub fn new(context: &mut InitContext) -> Self {
         // The watts in this function are all provided by komp.
+        let ac_bus_1_consumers = Self::variable_frequency_ac_bus_consumers(
+            context,
+            1,
+            [26816.3, 30350.1, 33797.3, 39032.5, 30733.3, 30243.1],
+        );
+        let ac_bus_2_consumers = Self::variable_frequency_ac_bus_consumers(
+            context,
+            2,
+            [26960.2, 21735.8, 25183., 29777.4, 22119., 24475.8],
+        );
+
         Self {
-            ac_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26816.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(30350.1),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(33797.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(39032.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(30733.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(30243.1),
-                ),
-            ]),
-            ac_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26960.2),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(21735.8),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(25183.),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(29777.4),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(22119.),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(24475.8),
-                ),
-            ]),
+            ac_bus_1_consumer: ac_bus_1_consumers.0,
+            ac_bus_2_consumer: ac_bus_2_consumers.0,
             ac_ess_bus_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::AlternatingCurrentEssential,
@@ -305,6 +276,7 @@ impl A380PowerConsumption {
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(112.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(84.)),
             ]),
+            ac_bus_motor_consumers: [ac_bus_1_consumers.1, ac_bus_2_consumers.1],
         }
     }
 
@@ -324,6 +296,47 @@ impl A380PowerConsumption {
         self.dc_hot_bus_2_consumer.update(context);
         self.dc_gnd_flt_service_consumer.update(context);
     }
+
+    /// Splits the load of an AC bus, given for every flight phase in the order of
+    /// [`Self::FLIGHT_PHASES`], into a flight phase dependent part and the part drawn by its
+    /// induction motors, whose demand depends on the frequency of the bus.
+    ///
+    /// The fans and pumps run from the moment the aircraft is powered until it is shut down,
+    /// while the galleys, lights and heaters come and go with the flight phases. The motor
+    /// demand is therefore calibrated to the part of komp's load which is present in every
+    /// flight phase. The remainder stays with the flight phase consumer, such that the total
+    /// load at the nominal frequency is unchanged. The motor demand then scales with the cube
+    /// of the frequency, see [`FrequencyDependentPowerConsumer`].
+    fn variable_frequency_ac_bus_consumers(
+        context: &mut InitContext,
+        number: u8,
+        demand_watt: [f64; 6],
+    ) -> (FlightPhasePowerConsumer, FrequencyDependentPowerConsumer) {
+        let motor_demand_watt = demand_watt.iter().copied().fold(f64::INFINITY, f64::min);
+
+        let mut flight_phase_demand =
+            [(PowerConsumerFlightPhase::BeforeStart, Power::default()); 6];
+        for (index, phase) in Self::FLIGHT_PHASES.iter().enumerate() {
+            flight_phase_demand[index] = (
+                *phase,
+                Power::new::<watt>(demand_watt[index] - motor_demand_watt),
+            );
+        }
+
+        let flight_phase_consumer =
+            FlightPhasePowerConsumer::new(context, ElectricalBusType::AlternatingCurrent(number))
+                .demand(flight_phase_demand);
+
+        let mut motor_consumer = FrequencyDependentPowerConsumer::from(
+            ElectricalBusType::AlternatingCurrent(number),
+            Frequency::new::<hertz>(Self::MOTOR_NOMINAL_FREQUENCY_HZ),
+            Frequency::new::<hertz>(Self::MOTOR_MINIMUM_FREQUENCY_HZ)
+                ..=Frequency::new::<hertz>(Self::MOTOR_MAXIMUM_FREQUENCY_HZ),
+        );
+        motor_consumer.demand(Power::new::<watt>(motor_demand_watt));
+
+        (flight_phase_consumer, motor_consumer)
+    }
 }
 impl SimulationElement for A380PowerConsumption {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -341,6 +354,7 @@ impl SimulationElement for A380PowerConsumption {
         self.dc_hot_bus_1_consumer.accept(visitor);
         self.dc_hot_bus_2_consumer.accept(visitor);
         self.dc_gnd_flt_service_consumer.accept(visitor);
+        accept_iterable!(self.ac_bus_motor_consumers, visitor);
 
         visitor.visit(self);
     }
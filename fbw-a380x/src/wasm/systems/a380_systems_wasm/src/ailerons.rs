@@ -2,10 +2,9 @@ use std::error::Error;
 
 use systems::shared::to_bool;
 use systems_wasm::aspects::{ExecuteOn, MsfsAspectBuilder, ObjectWrite, VariablesToObject};
-use systems_wasm::{set_data_on_sim_object, Variable};
+use systems_wasm::{data_definition, set_data_on_sim_object, Variable};
 
-use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+use systems_wasm::msfs::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
 
 pub(super) fn ailerons(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
     const MIN_ACTUAL_DEFLECTION_ANGLE: f64 = 20.;
@@ -124,11 +123,12 @@ pub(super) fn ailerons(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Er
     Ok(())
 }
 
-#[sim_connect::data_definition]
-struct RollSimOutput {
-    #[name = "AILERON POSITION"]
-    #[unit = "Position"]
-    ailerons: f64,
+data_definition! {
+    struct RollSimOutput {
+        #[name = "AILERON POSITION"]
+        #[unit = "Position"]
+        ailerons: f64,
+    }
 }
 impl VariablesToObject for RollSimOutput {
     fn variables(&self) -> Vec<Variable> {
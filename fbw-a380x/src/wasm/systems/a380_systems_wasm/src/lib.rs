@@ -6,11 +6,12 @@ mod elevators;
 mod flaps;
 mod gear;
 mod nose_wheel_steering;
+mod payload;
 mod rudder;
 mod spoilers;
 mod trimmable_horizontal_stabilizer;
 
-use a380_systems::A380;
+use a380_systems::{failures::FAILURES, A380};
 use ailerons::ailerons;
 use autobrakes::autobrakes;
 use brakes::brakes;
@@ -19,26 +20,41 @@ use elevators::elevators;
 use flaps::flaps;
 use gear::gear;
 use nose_wheel_steering::nose_wheel_steering;
+use payload::payload;
 use rudder::rudder;
 use spoilers::spoilers;
 use std::error::Error;
-use systems::failures::FailureType;
 use systems::shared::{
     ElectricalBusType, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
 };
 
-use systems_wasm::{MsfsSimulationBuilder, Variable};
+use systems::simulation::Simulation;
+use systems_wasm::msfs::sim_connect::SimConnect;
+use systems_wasm::{MsfsHandler, MsfsSimulationBuilder, Variable};
 use trimmable_horizontal_stabilizer::trimmable_horizontal_stabilizer;
 
+#[cfg(target_arch = "wasm32")]
 #[msfs::gauge(name=systems)]
 async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     let mut sim_connect = gauge.open_simconnect("systems")?;
+    let (mut simulation, mut handler) = simulation(sim_connect.as_mut().get_mut())?;
 
+    while let Some(event) = gauge.next_event().await {
+        handler.handle(event, &mut simulation, sim_connect.as_mut().get_mut())?;
+    }
+
+    Ok(())
+}
+
+/// Builds the simulation and the handler which bridges it to the simulator.
+pub fn simulation(
+    sim_connect: &mut SimConnect,
+) -> Result<(Simulation<A380>, MsfsHandler), Box<dyn Error>> {
     let key_prefix = "A32NX_";
-    let (mut simulation, mut handler) = MsfsSimulationBuilder::new(
+    MsfsSimulationBuilder::new(
         key_prefix,
         Variable::named(&format!("{}START_STATE", key_prefix)),
-        sim_connect.as_mut().get_mut(),
+        sim_connect,
     )
     .with_electrical_buses([
         (ElectricalBusType::AlternatingCurrent(1), 2),
@@ -59,190 +75,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (ElectricalBusType::DirectCurrentGndFltService, 17),
     ])?
     .with_auxiliary_power_unit(Variable::named("OVHD_APU_START_PB_IS_AVAILABLE"), 8, 7)?
-    .with_failures(vec![
-        (24_000, FailureType::TransformerRectifier(1)),
-        (24_001, FailureType::TransformerRectifier(2)),
-        (24_002, FailureType::TransformerRectifier(3)),
-        (24_004, FailureType::StaticInverter),
-        (24_020, FailureType::Generator(1)),
-        (24_021, FailureType::Generator(2)),
-        (24_022, FailureType::Generator(3)),
-        (24_023, FailureType::Generator(4)),
-        (24_030, FailureType::ApuGenerator(1)),
-        (24_031, FailureType::ApuGenerator(2)),
-        (
-            24_100,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
-        ),
-        (
-            24_101,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
-        ),
-        (
-            24_102,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(3)),
-        ),
-        (
-            24_103,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(4)),
-        ),
-        (
-            24_104,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
-        ),
-        (
-            24_105,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
-        ),
-        (
-            24_106,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentNamed("247XP")),
-        ),
-        (
-            24_107,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
-        ),
-        (
-            24_108,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
-        ),
-        (
-            24_109,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
-        ),
-        (
-            24_110,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
-        ),
-        (
-            24_111,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("247PP")),
-        ),
-        (
-            24_112,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("309PP")),
-        ),
-        (
-            24_113,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
-        ),
-        (
-            24_114,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
-        ),
-        (
-            24_115,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(3)),
-        ),
-        (
-            24_116,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(4)),
-        ),
-        (
-            24_117,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
-        ),
-        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
-        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
-        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
-        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
-        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
-        (
-            29_005,
-            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
-        ),
-        (
-            29_006,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
-        ),
-        (
-            29_007,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
-        ),
-        (
-            29_008,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
-        ),
-        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
-        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
-        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
-        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
-        (
-            32_004,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
-        ),
-        (
-            32_005,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
-        ),
-        (
-            32_006,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
-        ),
-        (
-            32_007,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
-        ),
-        (
-            32_008,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
-        ),
-        (
-            32_009,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
-        ),
-        (
-            32_010,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
-        ),
-        (
-            32_011,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
-        ),
-        (
-            32_012,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
-        ),
-        (
-            32_013,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
-        ),
-        (
-            32_014,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
-        ),
-        (
-            32_015,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
-        ),
-        (
-            32_020,
-            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
-        ),
-        (
-            32_021,
-            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
-        ),
-        (
-            32_022,
-            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
-        ),
-        (
-            32_023,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
-        ),
-        (
-            32_024,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
-        ),
-        (
-            32_025,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
-        ),
-        (34_000, FailureType::RadioAltimeter(1)),
-        (34_001, FailureType::RadioAltimeter(2)),
-        (34_002, FailureType::RadioAltimeter(3)),
-    ])
+    .with_left_main_fuel_tank()?
+    .with_failures(FAILURES.to_vec())
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Z", "feet per second squared", 0)?
@@ -312,6 +146,22 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("ROTATION VELOCITY BODY X", "degree per second", 0)?
     .provides_aircraft_variable("ROTATION VELOCITY BODY Y", "degree per second", 0)?
     .provides_aircraft_variable("ROTATION VELOCITY BODY Z", "degree per second", 0)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 1)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 2)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 3)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 4)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 5)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 6)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 7)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 8)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 9)?
+    .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 10)?
+    .provides_named_variable("FSDT_GSX_BOARDING_STATE")?
+    .provides_named_variable("FSDT_GSX_DEBOARDING_STATE")?
+    .provides_named_variable("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL")?
+    .provides_named_variable("FSDT_GSX_NUMPASSENGERS_DEBOARDING_TOTAL")?
+    .provides_named_variable("FSDT_GSX_BOARDING_CARGO_PERCENT")?
+    .provides_named_variable("FSDT_GSX_DEBOARDING_CARGO_PERCENT")?
     .with_aspect(|builder| {
         for i in 1..=2 {
             builder.copy(
@@ -354,11 +204,130 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_aspect(rudder)?
     .with_aspect(gear)?
     .with_aspect(trimmable_horizontal_stabilizer)?
-    .build(A380::new)?;
+    .with_aspect(payload)?
+    .build(A380::new)
+}
 
-    while let Some(event) = gauge.next_event().await {
-        handler.handle(event, &mut simulation, sim_connect.as_mut().get_mut())?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use systems_wasm::msfs::{legacy::NamedVariable, sim_connect::SimConnectRecv, MSFSEvent};
+
+    fn named_variable_value(name: &str) -> f64 {
+        NamedVariable::from(&format!("A32NX_{}", name)).get_value()
     }
 
-    Ok(())
+    fn receive_event(
+        handler: &mut MsfsHandler,
+        simulation: &mut Simulation<A380>,
+        sim_connect: &mut SimConnect,
+        event_name: &str,
+        data: u32,
+    ) -> Result<(), Box<dyn Error>> {
+        let event = sim_connect.event(event_name, data);
+        handler.handle(
+            MSFSEvent::SimConnect(SimConnectRecv::Event(&event)),
+            simulation,
+            sim_connect,
+        )
+    }
+
+    #[test]
+    fn runs_frames() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = simulation(&mut sim_connect)?;
+
+        for _ in 0..10 {
+            handler.run_frame(Duration::from_millis(50), &mut simulation, &mut sim_connect)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parking_brake_event_toggles_the_parking_brake_lever() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = simulation(&mut sim_connect)?;
+        handler.run_frame(Duration::from_millis(50), &mut simulation, &mut sim_connect)?;
+
+        assert!(sim_connect.is_event_masked("PARKING_BRAKES"));
+        let lever_position = named_variable_value("PARK_BRAKE_LEVER_POS");
+        receive_event(
+            &mut handler,
+            &mut simulation,
+            &mut sim_connect,
+            "PARKING_BRAKES",
+            0,
+        )?;
+
+        assert_eq!(
+            named_variable_value("PARK_BRAKE_LEVER_POS"),
+            1. - lever_position
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn gear_events_request_the_gear_lever_position() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = simulation(&mut sim_connect)?;
+
+        assert!(sim_connect.is_event_masked("GEAR_UP"));
+        receive_event(
+            &mut handler,
+            &mut simulation,
+            &mut sim_connect,
+            "GEAR_UP",
+            0,
+        )?;
+        assert_eq!(named_variable_value("GEAR_LEVER_POSITION_REQUEST"), 0.);
+
+        receive_event(
+            &mut handler,
+            &mut simulation,
+            &mut sim_connect,
+            "GEAR_DOWN",
+            0,
+        )?;
+        assert_eq!(named_variable_value("GEAR_LEVER_POSITION_REQUEST"), 1.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn brake_axes_are_written_back_every_frame() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = simulation(&mut sim_connect)?;
+        let left_brake_event_id = sim_connect.event_id("AXIS_LEFT_BRAKE_SET").unwrap();
+
+        for _ in 0..2 {
+            handler.run_frame(Duration::from_millis(50), &mut simulation, &mut sim_connect)?;
+
+            assert_eq!(
+                sim_connect
+                    .take_transmitted_events()
+                    .iter()
+                    .filter(|event| event.event_id == left_brake_event_id)
+                    .count(),
+                1
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn failures_requested_by_the_simulator_are_consumed() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = simulation(&mut sim_connect)?;
+
+        NamedVariable::from("A32NX_FAILURE_ACTIVATE").set_value(32_250.);
+        handler.run_frame(Duration::from_millis(50), &mut simulation, &mut sim_connect)?;
+
+        assert_eq!(named_variable_value("FAILURE_ACTIVATE"), 0.);
+
+        Ok(())
+    }
 }
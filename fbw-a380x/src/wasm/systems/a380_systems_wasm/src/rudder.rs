@@ -3,9 +3,8 @@ use std::error::Error;
 use systems::shared::to_bool;
 use systems_wasm::aspects::{ExecuteOn, MsfsAspectBuilder, ObjectWrite, VariablesToObject};
 
-use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
-use systems_wasm::{set_data_on_sim_object, Variable};
+use systems_wasm::msfs::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
+use systems_wasm::{data_definition, set_data_on_sim_object, Variable};
 
 pub(super) fn rudder(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
     builder.map(
@@ -45,11 +44,12 @@ pub(super) fn rudder(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Erro
     Ok(())
 }
 
-#[sim_connect::data_definition]
-struct YawSimOutput {
-    #[name = "RUDDER POSITION"]
-    #[unit = "Position"]
-    rudder: f64,
+data_definition! {
+    struct YawSimOutput {
+        #[name = "RUDDER POSITION"]
+        #[unit = "Position"]
+        rudder: f64,
+    }
 }
 impl VariablesToObject for YawSimOutput {
     fn variables(&self) -> Vec<Variable> {
@@ -1,12 +1,11 @@
 use std::error::Error;
 
 use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
-use systems_wasm::{set_data_on_sim_object, Variable};
+use systems_wasm::{data_definition, set_data_on_sim_object, Variable};
 
 use systems::shared::to_bool;
 
-use msfs::sim_connect;
-use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+use systems_wasm::msfs::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
 
 pub(super) fn trimmable_horizontal_stabilizer(
     builder: &mut MsfsAspectBuilder,
@@ -16,11 +15,12 @@ pub(super) fn trimmable_horizontal_stabilizer(
     Ok(())
 }
 
-#[sim_connect::data_definition]
-struct PitchTrimSimOutput {
-    #[name = "ELEVATOR TRIM POSITION"]
-    #[unit = "DEGREE"]
-    elevator_trim: f64,
+data_definition! {
+    struct PitchTrimSimOutput {
+        #[name = "ELEVATOR TRIM POSITION"]
+        #[unit = "DEGREE"]
+        elevator_trim: f64,
+    }
 }
 impl VariablesToObject for PitchTrimSimOutput {
     fn variables(&self) -> Vec<Variable> {
@@ -1774,7 +1774,7 @@ mod acs_controller_tests {
                 safety_valve_open_amount,
                 lgciu_gear_compressed,
                 passengers,
-                0,
+                &[],
             );
         }
 
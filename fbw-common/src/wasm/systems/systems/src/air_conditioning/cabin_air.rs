@@ -77,8 +77,14 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
         safety_valve_open_amount: Ratio,
         lgciu_gear_compressed: bool,
         passengers: [u8; ZONES],
-        number_of_open_doors: u8,
+        door_open_ratios: &[Ratio],
     ) {
+        // Each door contributes to the air exchanged with the exterior by how far it is opened
+        let open_doors: f64 = door_open_ratios
+            .iter()
+            .map(|open_ratio| open_ratio.get::<ratio>())
+            .sum();
+
         if !self.is_initialised {
             let initial_cabin_pressure =
                 self.initialize_cabin_pressure(context, lgciu_gear_compressed);
@@ -110,7 +116,7 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
                 flow_rate_per_cubic_meter,
                 self.internal_air.pressure(),
                 passengers[zone.zone_id()],
-                number_of_open_doors,
+                open_doors,
             );
         }
 
@@ -125,7 +131,7 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
         self.air_out.set_flow_rate(self.calculate_cabin_flow_out(
             outflow_valve_open_amount,
             safety_valve_open_amount,
-            number_of_open_doors,
+            open_doors,
         ));
 
         // Calculate internal air properties
@@ -267,14 +273,14 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
         &self,
         outflow_valve_open_amount: Ratio,
         safety_valve_open_amount: Ratio,
-        number_of_open_doors: u8,
+        open_doors: f64,
     ) -> MassRate {
         const TRANSONIC_PR_VALUE: f64 = 0.53;
 
         let outflow_valve_area = C::OUTFLOW_VALVE_SIZE * outflow_valve_open_amount.get::<ratio>(); // sq m
         let leakage_area = C::CABIN_LEAKAGE_AREA
             + C::SAFETY_VALVE_SIZE * safety_valve_open_amount.get::<ratio>()
-            + number_of_open_doors as f64 * C::DOOR_OPENING_AREA; // sq m
+            + open_doors * C::DOOR_OPENING_AREA; // sq m
 
         let pressure_ratio =
             (self.filtered_exterior_pressure / self.internal_air.pressure()).get::<ratio>();
@@ -374,7 +380,7 @@ impl<C: PressurizationConstants> CabinZone<C> {
         pack_flow_per_cubic_meter: MassRate,
         cabin_pressure: Pressure,
         passengers: u8,
-        number_of_open_doors: u8,
+        open_doors: f64,
     ) {
         let mut air_in = Air::new();
         air_in.set_temperature(duct_temperature.duct_temperature()[self.zone_id]);
@@ -386,7 +392,7 @@ impl<C: PressurizationConstants> CabinZone<C> {
             &air_in,
             self.zone_volume,
             self.passengers,
-            number_of_open_doors,
+            open_doors,
             cabin_pressure,
         );
     }
@@ -448,14 +454,14 @@ impl ZoneAir {
         air_in: &Air,
         zone_volume: Volume,
         zone_passengers: u8,
-        number_of_open_doors: u8,
+        open_doors: f64,
         cabin_pressure: Pressure,
     ) {
         self.internal_air.set_pressure(cabin_pressure);
 
         let new_equilibrium_temperature = self.equilibrium_temperature_calculation(
             context,
-            number_of_open_doors,
+            open_doors,
             air_in,
             zone_volume,
             zone_passengers,
@@ -470,7 +476,7 @@ impl ZoneAir {
     fn equilibrium_temperature_calculation(
         &self,
         context: &UpdateContext,
-        number_of_open_doors: u8,
+        open_doors: f64,
         air_in: &Air,
         zone_volume: Volume,
         zone_passengers: u8,
@@ -478,14 +484,14 @@ impl ZoneAir {
         let inlet_air_energy = air_in.flow_rate().get::<kilogram_per_second>()
             * Air::SPECIFIC_HEAT_CAPACITY_PRESSURE
             * air_in.temperature().get::<kelvin>();
-        let mut inlet_door_air_energy = number_of_open_doors as f64
+        let mut inlet_door_air_energy = open_doors
             * (Self::FLOW_RATE_THROUGH_OPEN_DOOR_KG_PER_SECOND)
             * Air::SPECIFIC_HEAT_CAPACITY_PRESSURE
             * context.ambient_temperature().get::<kelvin>();
         let outlet_air_energy = self.flow_out.flow_rate().get::<kilogram_per_second>()
             * Air::SPECIFIC_HEAT_CAPACITY_PRESSURE
             * self.flow_out.temperature().get::<kelvin>();
-        let mut outlet_door_air_energy = number_of_open_doors as f64
+        let mut outlet_door_air_energy = open_doors
             * (Self::FLOW_RATE_THROUGH_OPEN_DOOR_KG_PER_SECOND)
             * Air::SPECIFIC_HEAT_CAPACITY_PRESSURE
             * self.internal_air.temperature().get::<kelvin>();
@@ -789,7 +795,7 @@ mod cabin_air_tests {
                 Ratio::default(),
                 self.lgciu_gears_compressed,
                 [2, self.number_of_passengers],
-                0u8,
+                &[],
             );
         }
     }
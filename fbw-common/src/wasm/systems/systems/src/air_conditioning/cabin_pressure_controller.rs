@@ -5,7 +5,7 @@ use crate::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        VariableIdentifier, VariableMetadata, Write,
     },
 };
 
@@ -75,6 +75,8 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
     const AMBIENT_CONDITIONS_FILTER_TIME_CONSTANT: Duration = Duration::from_millis(2000);
     // Altitude in ft equivalent to 0.1 PSI delta P at sea level
     const TARGET_LANDING_ALT_DIFF: f64 = 187.818;
+    // Delta P beyond the safety limits at which the safety valve is fully opened
+    const SAFETY_VALVE_OPENING_MARGIN: f64 = 0.5; // PSI
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
@@ -91,8 +93,14 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
             safety_valve_open_percentage_id: context
                 .get_identifier("PRESS_SAFETY_VALVE_OPEN_PERCENTAGE".to_owned()),
 
-            auto_landing_elevation_id: context.get_identifier("FM1_LANDING_ELEVATION".to_owned()),
-            destination_qnh_id: context.get_identifier("DESTINATION_QNH".to_owned()),
+            auto_landing_elevation_id: context.get_identifier_with_metadata(
+                "FM1_LANDING_ELEVATION".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            destination_qnh_id: context.get_identifier_with_metadata(
+                "DESTINATION_QNH".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
 
             pressure_schedule_manager: Some(PressureScheduleManager::new()),
             outflow_valve_controller: OutflowValveController::new(),
@@ -129,11 +137,11 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
         }
     }
 
-    pub fn update(
+    pub fn update<const N: usize>(
         &mut self,
         context: &UpdateContext,
         adirs: &impl AdirsToAirCondInterface,
-        engines: [&impl EngineCorrectedN1; 2],
+        engines: [&impl EngineCorrectedN1; N],
         lgciu_gears_compressed: bool,
         press_overhead: &impl PressurizationOverheadShared,
         cabin_simulation: &impl CabinSimulation,
@@ -548,14 +556,18 @@ impl<C: PressurizationConstants> ControllerSignal<PressureValveSignal>
             Ratio::new::<percent>(0.),
             Duration::from_secs(1),
         ));
-        if self.cabin_delta_p() > Pressure::new::<psi>(8.1) {
-            if self.cabin_delta_p() > Pressure::new::<psi>(8.6) {
+        if self.cabin_delta_p() > Pressure::new::<psi>(C::MAX_SAFETY_DELTA_P) {
+            if self.cabin_delta_p()
+                > Pressure::new::<psi>(C::MAX_SAFETY_DELTA_P + Self::SAFETY_VALVE_OPENING_MARGIN)
+            {
                 open
             } else {
                 Some(PressureValveSignal::Neutral)
             }
-        } else if self.cabin_delta_p() < Pressure::new::<psi>(-0.5) {
-            if self.cabin_delta_p() < Pressure::new::<psi>(-1.) {
+        } else if self.cabin_delta_p() < Pressure::new::<psi>(C::MIN_SAFETY_DELTA_P) {
+            if self.cabin_delta_p()
+                < Pressure::new::<psi>(C::MIN_SAFETY_DELTA_P - Self::SAFETY_VALVE_OPENING_MARGIN)
+            {
                 open
             } else {
                 Some(PressureValveSignal::Neutral)
@@ -678,12 +690,12 @@ impl PressureScheduleManager {
         PressureScheduleManager::Ground(PressureSchedule::with_open_outflow_valve())
     }
 
-    fn update(
+    fn update<const N: usize>(
         mut self,
         context: &UpdateContext,
         adirs_airspeed: Velocity,
         adirs_ambient_pressure: Pressure,
-        engines: [&impl EngineCorrectedN1; 2],
+        engines: [&impl EngineCorrectedN1; N],
         lgciu_gears_compressed: bool,
         exterior_flight_altitude: Length,
         exterior_vertical_speed: Velocity,
@@ -816,12 +828,12 @@ impl PressureSchedule<Ground> {
         }
     }
 
-    fn step(
+    fn step<const N: usize>(
         self: PressureSchedule<Ground>,
         context: &UpdateContext,
         adirs_airspeed: Velocity,
         adirs_ambient_pressure: Pressure,
-        engines: [&impl EngineCorrectedN1; 2],
+        engines: [&impl EngineCorrectedN1; N],
         lgciu_gears_compressed: bool,
     ) -> PressureScheduleManager {
         if engines
@@ -862,11 +874,11 @@ transition_with_ctor!(Abort, Ground, Ground::reset);
 struct TakeOff;
 
 impl PressureSchedule<TakeOff> {
-    fn step(
+    fn step<const N: usize>(
         self: PressureSchedule<TakeOff>,
         adirs_airspeed: Velocity,
         adirs_ambient_pressure: Pressure,
-        engines: [&impl EngineCorrectedN1; 2],
+        engines: [&impl EngineCorrectedN1; N],
         lgciu_gears_compressed: bool,
     ) -> PressureScheduleManager {
         if engines
@@ -1431,7 +1443,7 @@ mod tests {
                 self.safety_valve.open_amount(),
                 lgciu_gears_compressed,
                 [2, 50, 50],
-                0,
+                &[],
             );
             self.cpc.update_ambient_conditions(context, &self.adirs);
             self.cpc.update(
@@ -1,8 +1,8 @@
 use std::time::Duration;
 
 use uom::si::{
-    electric_potential::volt, f64::*, frequency::hertz, power::watt, pressure::psi, ratio::percent,
-    temperature_interval, thermodynamic_temperature::degree_celsius,
+    electric_potential::volt, f64::*, frequency::hertz, power::watt, ratio::percent,
+    temperature_interval, thermodynamic_temperature::degree_celsius, volume::quart_liquid,
 };
 
 use crate::{
@@ -13,78 +13,84 @@ use crate::{
     },
     failures::{Failure, FailureType},
     shared::{
-        calculate_towards_target_temperature, random_number, ConsumePower, ControllerSignal,
-        ElectricalBusType, ElectricalBuses, PotentialOrigin, PowerConsumptionReport,
+        random_number, ConsumePower, ElectricalBusType, ElectricalBuses, PotentialOrigin,
+        PowerConsumptionReport,
     },
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    turbine::{TurbineType, UsageEgtDelta},
+    ApuGenerator, ApuStartMotor,
+};
 
-pub struct ShutdownAps3200Turbine {
-    egt: ThermodynamicTemperature,
-}
-impl ShutdownAps3200Turbine {
-    pub fn new() -> Self {
-        ShutdownAps3200Turbine {
-            egt: ThermodynamicTemperature::new::<degree_celsius>(0.),
-        }
-    }
+const OIL_CAPACITY_QUARTS: f64 = 6.;
 
-    fn new_with_egt(egt: ThermodynamicTemperature) -> Self {
-        ShutdownAps3200Turbine { egt }
-    }
+pub(super) fn oil_capacity() -> Volume {
+    Volume::new::<quart_liquid>(OIL_CAPACITY_QUARTS)
 }
-impl Turbine for ShutdownAps3200Turbine {
-    fn update(
-        mut self: Box<Self>,
-        context: &UpdateContext,
-        _: bool,
-        _: bool,
-        controller: &dyn ControllerSignal<TurbineSignal>,
-    ) -> Box<dyn Turbine> {
-        self.egt = calculate_towards_ambient_egt(self.egt, context);
-
-        match controller.signal() {
-            Some(TurbineSignal::StartOrContinue) => Box::new(Starting::new(self.egt)),
-            Some(TurbineSignal::Stop) | None => self,
-        }
-    }
 
-    fn n(&self) -> Ratio {
-        Ratio::new::<percent>(0.)
-    }
+/// The APS3200 APU of the A320.
+pub struct Aps3200;
+impl TurbineType for Aps3200 {
+    type UsageEgtDelta = Aps3200UsageEgtDelta;
 
-    fn egt(&self) -> ThermodynamicTemperature {
-        self.egt
-    }
+    const START_IGNITION_AFTER_SECONDS: f64 = 1.5;
+    const START_ENVELOPE_CEILING_FEET: f64 = 39_000.;
 
-    fn state(&self) -> TurbineState {
-        TurbineState::Shutdown
-    }
+    const IDLE_FUEL_FLOW_KG_PER_HOUR: f64 = 75.;
+    const LIGHT_OFF_FUEL_FLOW_KG_PER_HOUR: f64 = 15.;
+    const MAXIMUM_BLEED_AIR_FUEL_FLOW_KG_PER_HOUR: f64 = 55.;
+    const FULL_GENERATOR_LOAD_FUEL_FLOW_KG_PER_HOUR: f64 = 30.;
 
-    fn bleed_air_pressure(&self) -> Pressure {
-        Pressure::new::<psi>(14.7)
-    }
-}
+    const STARTING_WARNING_EGT_BELOW_25000_FEET: f64 = 900.;
+    const STARTING_WARNING_EGT_AT_OR_ABOVE_25000_FEET: f64 = 982.;
+    const RUNNING_WARNING_EGT: f64 = 682.;
 
-struct Starting {
-    since: Duration,
-    n: Ratio,
-    egt: ThermodynamicTemperature,
-    ignore_calculated_egt: bool,
-}
-impl Starting {
-    fn new(egt: ThermodynamicTemperature) -> Starting {
-        Starting {
-            since: Duration::from_secs(0),
-            n: Ratio::new::<percent>(0.),
-            egt,
-            ignore_calculated_egt: true,
-        }
+    const RUNNING_BASE_EGT: f64 = 340.;
+    const RUNNING_BASE_EGT_DEVIATION_DECREASE_PER_SECOND: f64 = 1.;
+    const RUNNING_BLEED_AIR_PRESSURE_PSI: f64 = 42.;
+
+    fn starting_n(seconds_since_ignition: f64) -> Ratio {
+        const APU_N_CONST: f64 = -0.08013606018640967;
+        const APU_N_X: f64 = 2.129832736394534;
+        const APU_N_X2: f64 = 3.928273438786404;
+        const APU_N_X3: f64 = -1.88613299921213;
+        const APU_N_X4: f64 = 0.42749452749180916;
+        const APU_N_X5: f64 = -0.05757707967690426;
+        const APU_N_X6: f64 = 0.005022142795451004;
+        const APU_N_X7: f64 = -0.00029612873626050866;
+        const APU_N_X8: f64 = 0.00001204152497871946;
+        const APU_N_X9: f64 = -0.00000033829604438116;
+        const APU_N_X10: f64 = 0.00000000645140818528;
+        const APU_N_X11: f64 = -0.00000000007974743535;
+        const APU_N_X12: f64 = 0.00000000000057654695;
+        const APU_N_X13: f64 = -0.00000000000000185126;
+
+        // Protect against the formula returning decreasing results after this value.
+        const TIME_LIMIT: f64 = 45.12;
+        let ignition_turned_on_secs = seconds_since_ignition.min(TIME_LIMIT);
+
+        let n = (APU_N_CONST
+            + (APU_N_X * ignition_turned_on_secs)
+            + (APU_N_X2 * ignition_turned_on_secs.powi(2))
+            + (APU_N_X3 * ignition_turned_on_secs.powi(3))
+            + (APU_N_X4 * ignition_turned_on_secs.powi(4))
+            + (APU_N_X5 * ignition_turned_on_secs.powi(5))
+            + (APU_N_X6 * ignition_turned_on_secs.powi(6))
+            + (APU_N_X7 * ignition_turned_on_secs.powi(7))
+            + (APU_N_X8 * ignition_turned_on_secs.powi(8))
+            + (APU_N_X9 * ignition_turned_on_secs.powi(9))
+            + (APU_N_X10 * ignition_turned_on_secs.powi(10))
+            + (APU_N_X11 * ignition_turned_on_secs.powi(11))
+            + (APU_N_X12 * ignition_turned_on_secs.powi(12))
+            + (APU_N_X13 * ignition_turned_on_secs.powi(13)))
+        .clamp(0., 100.);
+
+        Ratio::new::<percent>(n)
     }
 
-    fn calculate_egt(&mut self, context: &UpdateContext) -> ThermodynamicTemperature {
+    fn starting_egt(n: Ratio) -> ThermodynamicTemperature {
         // Refer to APS3200.md for details on the values below and source data.
         const APU_N_TEMP_CONST: f64 = -92.3417137705543;
         const APU_N_TEMP_X: f64 = -14.36417426895237;
@@ -101,9 +107,9 @@ impl Starting {
         const APU_N_TEMP_X12: f64 = 0.00000000000000151429;
         const APU_N_TEMP_X13: f64 = -0.00000000000000000227;
 
-        let n = self.n.get::<percent>();
+        let n = n.get::<percent>();
 
-        let temperature = ThermodynamicTemperature::new::<degree_celsius>(
+        ThermodynamicTemperature::new::<degree_celsius>(
             APU_N_TEMP_CONST
                 + (APU_N_TEMP_X * n)
                 + (APU_N_TEMP_X2 * n.powi(2))
@@ -118,107 +124,117 @@ impl Starting {
                 + (APU_N_TEMP_X11 * n.powi(11))
                 + (APU_N_TEMP_X12 * n.powi(12))
                 + (APU_N_TEMP_X13 * n.powi(13)),
-        );
+        )
+    }
 
-        // The above calculated EGT can be lower than the ambient temperature,
-        // or the current APU EGT (when cooling down). To prevent sudden changes
-        // in temperature, we ignore the calculated EGT until it exceeds the current
-        // EGT.
-        let towards_ambient_egt = calculate_towards_ambient_egt(self.egt, context);
-        if temperature > towards_ambient_egt {
-            self.ignore_calculated_egt = false;
-        }
+    fn stopping_n(since: Duration) -> Ratio {
+        // Refer to APS3200.md for details on the values below and source data.
+        const APU_N_CONST: f64 = 100.22975364965701;
+        const APU_N_X: f64 = -24.692008355859773;
+        const APU_N_X2: f64 = 2.6116524551318787;
+        const APU_N_X3: f64 = 0.006812541903222142;
+        const APU_N_X4: f64 = -0.03134644787752123;
+        const APU_N_X5: f64 = 0.0036345606954833213;
+        const APU_N_X6: f64 = -0.00021794252200618456;
+        const APU_N_X7: f64 = 0.00000798097055109138;
+        const APU_N_X8: f64 = -0.00000018481154462604;
+        const APU_N_X9: f64 = 0.00000000264691628669;
+        const APU_N_X10: f64 = -0.00000000002143677577;
+        const APU_N_X11: f64 = 0.00000000000007515448;
 
-        if self.ignore_calculated_egt {
-            towards_ambient_egt
-        } else {
-            temperature
-        }
-    }
+        // Protect against the formula returning increasing results after this value.
+        const TIME_LIMIT: f64 = 49.411;
+        let since = since.as_secs_f64().min(TIME_LIMIT);
 
-    fn calculate_n(&self) -> Ratio {
-        const APU_N_CONST: f64 = -0.08013606018640967;
-        const APU_N_X: f64 = 2.129832736394534;
-        const APU_N_X2: f64 = 3.928273438786404;
-        const APU_N_X3: f64 = -1.88613299921213;
-        const APU_N_X4: f64 = 0.42749452749180916;
-        const APU_N_X5: f64 = -0.05757707967690426;
-        const APU_N_X6: f64 = 0.005022142795451004;
-        const APU_N_X7: f64 = -0.00029612873626050866;
-        const APU_N_X8: f64 = 0.00001204152497871946;
-        const APU_N_X9: f64 = -0.00000033829604438116;
-        const APU_N_X10: f64 = 0.00000000645140818528;
-        const APU_N_X11: f64 = -0.00000000007974743535;
-        const APU_N_X12: f64 = 0.00000000000057654695;
-        const APU_N_X13: f64 = -0.00000000000000185126;
+        let n = (APU_N_CONST
+            + (APU_N_X * since)
+            + (APU_N_X2 * since.powi(2))
+            + (APU_N_X3 * since.powi(3))
+            + (APU_N_X4 * since.powi(4))
+            + (APU_N_X5 * since.powi(5))
+            + (APU_N_X6 * since.powi(6))
+            + (APU_N_X7 * since.powi(7))
+            + (APU_N_X8 * since.powi(8))
+            + (APU_N_X9 * since.powi(9))
+            + (APU_N_X10 * since.powi(10))
+            + (APU_N_X11 * since.powi(11)))
+        .clamp(0., 100.);
 
-        // Protect against the formula returning decreasing results after this value.
-        const TIME_LIMIT: f64 = 45.12;
-        const START_IGNITION_AFTER_SECONDS: f64 = 1.5;
-        let ignition_turned_on_secs =
-            (self.since.as_secs_f64() - START_IGNITION_AFTER_SECONDS).min(TIME_LIMIT);
-
-        if ignition_turned_on_secs > 0. {
-            let n = (APU_N_CONST
-                + (APU_N_X * ignition_turned_on_secs)
-                + (APU_N_X2 * ignition_turned_on_secs.powi(2))
-                + (APU_N_X3 * ignition_turned_on_secs.powi(3))
-                + (APU_N_X4 * ignition_turned_on_secs.powi(4))
-                + (APU_N_X5 * ignition_turned_on_secs.powi(5))
-                + (APU_N_X6 * ignition_turned_on_secs.powi(6))
-                + (APU_N_X7 * ignition_turned_on_secs.powi(7))
-                + (APU_N_X8 * ignition_turned_on_secs.powi(8))
-                + (APU_N_X9 * ignition_turned_on_secs.powi(9))
-                + (APU_N_X10 * ignition_turned_on_secs.powi(10))
-                + (APU_N_X11 * ignition_turned_on_secs.powi(11))
-                + (APU_N_X12 * ignition_turned_on_secs.powi(12))
-                + (APU_N_X13 * ignition_turned_on_secs.powi(13)))
-            .min(100.)
-            .max(0.);
+        Ratio::new::<percent>(n)
+    }
 
-            Ratio::new::<percent>(n)
-        } else {
-            Ratio::new::<percent>(0.)
-        }
+    fn stopping_egt(
+        _: &UpdateContext,
+        _: ThermodynamicTemperature,
+        egt_at_entry: ThermodynamicTemperature,
+        n_at_entry: Ratio,
+        n: Ratio,
+    ) -> ThermodynamicTemperature {
+        // EGT in this state is a function of N. When the APU start is unsuccessful the
+        // stopping state is entered with N < 100%. The EGT delta at entry ensures EGT
+        // doesn't just suddenly drop by e.g. 80 degrees due to the low N.
+        egt_at_entry + Aps3200::stopping_egt_delta(n) - Aps3200::stopping_egt_delta(n_at_entry)
     }
 }
-impl Turbine for Starting {
-    fn update(
-        mut self: Box<Self>,
-        context: &UpdateContext,
-        _: bool,
-        _: bool,
-        controller: &dyn ControllerSignal<TurbineSignal>,
-    ) -> Box<dyn Turbine> {
-        self.since += context.delta();
-        self.n = self.calculate_n();
-        self.egt = self.calculate_egt(context);
-
-        match controller.signal() {
-            Some(TurbineSignal::Stop) | None => Box::new(Stopping::new(self.egt, self.n)),
-            Some(TurbineSignal::StartOrContinue)
-                if { (self.n.get::<percent>() - 100.).abs() < f64::EPSILON } =>
-            {
-                Box::new(Running::new(self.egt))
-            }
-            Some(TurbineSignal::StartOrContinue) => self,
-        }
+impl Aps3200 {
+    fn stopping_egt_delta(n: Ratio) -> TemperatureInterval {
+        // Refer to APS3200.md for details on the values below and source data.
+        const APU_N_TEMP_DELTA_CONST: f64 = -125.73137672208446;
+        const APU_N_TEMP_DELTA_X: f64 = 2.7141683591219037;
+        const APU_N_TEMP_DELTA_X2: f64 = -0.8102923071483102;
+        const APU_N_TEMP_DELTA_X3: f64 = 0.08890509495240731;
+        const APU_N_TEMP_DELTA_X4: f64 = -0.003509532681984154;
+        const APU_N_TEMP_DELTA_X5: f64 = -0.00002709133732344767;
+        const APU_N_TEMP_DELTA_X6: f64 = 0.00000749250123766767;
+        const APU_N_TEMP_DELTA_X7: f64 = -0.00000030306978045244;
+        const APU_N_TEMP_DELTA_X8: f64 = 0.00000000641099706269;
+        const APU_N_TEMP_DELTA_X9: f64 = -0.00000000008068326110;
+        const APU_N_TEMP_DELTA_X10: f64 = 0.00000000000060754088;
+        const APU_N_TEMP_DELTA_X11: f64 = -0.00000000000000253354;
+        const APU_N_TEMP_DELTA_X12: f64 = 0.00000000000000000451;
+
+        let n = n.get::<percent>();
+        TemperatureInterval::new::<temperature_interval::degree_celsius>(
+            APU_N_TEMP_DELTA_CONST
+                + (APU_N_TEMP_DELTA_X * n)
+                + (APU_N_TEMP_DELTA_X2 * n.powi(2))
+                + (APU_N_TEMP_DELTA_X3 * n.powi(3))
+                + (APU_N_TEMP_DELTA_X4 * n.powi(4))
+                + (APU_N_TEMP_DELTA_X5 * n.powi(5))
+                + (APU_N_TEMP_DELTA_X6 * n.powi(6))
+                + (APU_N_TEMP_DELTA_X7 * n.powi(7))
+                + (APU_N_TEMP_DELTA_X8 * n.powi(8))
+                + (APU_N_TEMP_DELTA_X9 * n.powi(9))
+                + (APU_N_TEMP_DELTA_X10 * n.powi(10))
+                + (APU_N_TEMP_DELTA_X11 * n.powi(11))
+                + (APU_N_TEMP_DELTA_X12 * n.powi(12)),
+        )
     }
+}
 
-    fn n(&self) -> Ratio {
-        self.n
+pub struct Aps3200UsageEgtDelta {
+    bleed_air: BleedAirUsageEgtDelta,
+    apu_gen: ApuGenUsageEgtDelta,
+}
+impl UsageEgtDelta for Aps3200UsageEgtDelta {
+    fn new() -> Self {
+        Self {
+            bleed_air: BleedAirUsageEgtDelta::new(),
+            apu_gen: ApuGenUsageEgtDelta::new(),
+        }
     }
 
-    fn egt(&self) -> ThermodynamicTemperature {
-        self.egt
+    fn update(&mut self, context: &UpdateContext, apu_gen_is_used: bool, apu_bleed_is_used: bool) {
+        self.apu_gen.update(context, apu_gen_is_used);
+        self.bleed_air.update(context, apu_bleed_is_used);
     }
 
-    fn state(&self) -> TurbineState {
-        TurbineState::Starting
+    fn egt_delta(&self) -> TemperatureInterval {
+        self.apu_gen.egt_delta() + self.bleed_air.egt_delta()
     }
 
-    fn bleed_air_pressure(&self) -> Pressure {
-        Pressure::new::<psi>(14.7)
+    fn bleed_air_usage(&self) -> f64 {
+        self.bleed_air.usage()
     }
 }
 
@@ -258,6 +274,11 @@ impl BleedAirUsageEgtDelta {
         self.current = self.current.max(self.min).min(self.max);
     }
 
+    /// The extent to which the bleed air usage has built up, from 0 to 1.
+    fn usage(&self) -> f64 {
+        self.current / self.max
+    }
+
     fn egt_delta(&self) -> TemperatureInterval {
         TemperatureInterval::new::<temperature_interval::degree_celsius>(self.current)
     }
@@ -326,238 +347,6 @@ impl ApuGenUsageEgtDelta {
     }
 }
 
-struct Running {
-    egt: ThermodynamicTemperature,
-    base_egt: ThermodynamicTemperature,
-    base_egt_deviation: TemperatureInterval,
-    bleed_air_usage: BleedAirUsageEgtDelta,
-    apu_gen_usage: ApuGenUsageEgtDelta,
-}
-impl Running {
-    fn new(egt: ThermodynamicTemperature) -> Running {
-        let base_egt = 340. + ((random_number() % 11) as f64);
-        Running {
-            egt,
-            base_egt: ThermodynamicTemperature::new::<degree_celsius>(base_egt),
-            // This contains the deviation from the base EGT at the moment of entering the running state.
-            // This code assumes the base EGT is lower than the EGT at this point in time, which is always the case.
-            // Should this change in the future, then changes have to be made here.
-            base_egt_deviation: TemperatureInterval::new::<temperature_interval::degree_celsius>(
-                egt.get::<degree_celsius>() - base_egt,
-            ),
-            bleed_air_usage: BleedAirUsageEgtDelta::new(),
-            apu_gen_usage: ApuGenUsageEgtDelta::new(),
-        }
-    }
-
-    fn calculate_egt(
-        &mut self,
-        context: &UpdateContext,
-        apu_gen_is_used: bool,
-        apu_bleed_is_used: bool,
-    ) -> ThermodynamicTemperature {
-        // Reduce the deviation by 1 per second to slowly creep back to normal temperatures
-        self.base_egt_deviation -= TemperatureInterval::new::<temperature_interval::degree_celsius>(
-            (context.delta_as_secs_f64() * 1.).min(
-                self.base_egt_deviation
-                    .get::<temperature_interval::degree_celsius>(),
-            ),
-        );
-
-        let mut target = self.base_egt + self.base_egt_deviation;
-        self.apu_gen_usage.update(context, apu_gen_is_used);
-        target += self.apu_gen_usage.egt_delta();
-
-        self.bleed_air_usage.update(context, apu_bleed_is_used);
-        target += self.bleed_air_usage.egt_delta();
-
-        target
-    }
-}
-impl Turbine for Running {
-    fn update(
-        mut self: Box<Self>,
-        context: &UpdateContext,
-        apu_bleed_is_used: bool,
-        apu_gen_is_used: bool,
-        controller: &dyn ControllerSignal<TurbineSignal>,
-    ) -> Box<dyn Turbine> {
-        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
-
-        match controller.signal() {
-            Some(TurbineSignal::StartOrContinue) => self,
-            Some(TurbineSignal::Stop) | None => {
-                Box::new(Stopping::new(self.egt, Ratio::new::<percent>(100.)))
-            }
-        }
-    }
-
-    fn n(&self) -> Ratio {
-        Ratio::new::<percent>(100.)
-    }
-
-    fn egt(&self) -> ThermodynamicTemperature {
-        self.egt
-    }
-
-    fn state(&self) -> TurbineState {
-        TurbineState::Running
-    }
-
-    fn bleed_air_pressure(&self) -> Pressure {
-        Pressure::new::<psi>(42.)
-    }
-}
-
-struct Stopping {
-    since: Duration,
-    base_temperature: ThermodynamicTemperature,
-    // When the APU start is unsuccessful the stopping state
-    // is entered with N < 100%. This factor ensures that we damped
-    // the resulting N calculated by this state, to ensure N doesn't
-    // suddenly go from 30 to 100.
-    n_factor: f64,
-    // When the APU start is unsuccessful the stopping state
-    // is entered with N < 100%. As EGT in this state is a function of N,
-    // we need to adapt the EGT calculation to this. This ensures EGT
-    // doesn't just suddenly drop by e.g. 80 degrees due to the low N.
-    egt_delta_at_entry: TemperatureInterval,
-    n: Ratio,
-    egt: ThermodynamicTemperature,
-}
-impl Stopping {
-    fn new(egt: ThermodynamicTemperature, n: Ratio) -> Stopping {
-        Stopping {
-            since: Duration::from_secs(0),
-            base_temperature: egt,
-            n_factor: n.get::<percent>() / 100.,
-            egt_delta_at_entry: Stopping::calculate_egt_delta(n),
-            n,
-            egt,
-        }
-    }
-
-    fn calculate_egt_delta(n: Ratio) -> TemperatureInterval {
-        // Refer to APS3200.md for details on the values below and source data.
-        const APU_N_TEMP_DELTA_CONST: f64 = -125.73137672208446;
-        const APU_N_TEMP_DELTA_X: f64 = 2.7141683591219037;
-        const APU_N_TEMP_DELTA_X2: f64 = -0.8102923071483102;
-        const APU_N_TEMP_DELTA_X3: f64 = 0.08890509495240731;
-        const APU_N_TEMP_DELTA_X4: f64 = -0.003509532681984154;
-        const APU_N_TEMP_DELTA_X5: f64 = -0.00002709133732344767;
-        const APU_N_TEMP_DELTA_X6: f64 = 0.00000749250123766767;
-        const APU_N_TEMP_DELTA_X7: f64 = -0.00000030306978045244;
-        const APU_N_TEMP_DELTA_X8: f64 = 0.00000000641099706269;
-        const APU_N_TEMP_DELTA_X9: f64 = -0.00000000008068326110;
-        const APU_N_TEMP_DELTA_X10: f64 = 0.00000000000060754088;
-        const APU_N_TEMP_DELTA_X11: f64 = -0.00000000000000253354;
-        const APU_N_TEMP_DELTA_X12: f64 = 0.00000000000000000451;
-
-        let n = n.get::<percent>();
-        TemperatureInterval::new::<temperature_interval::degree_celsius>(
-            APU_N_TEMP_DELTA_CONST
-                + (APU_N_TEMP_DELTA_X * n)
-                + (APU_N_TEMP_DELTA_X2 * n.powi(2))
-                + (APU_N_TEMP_DELTA_X3 * n.powi(3))
-                + (APU_N_TEMP_DELTA_X4 * n.powi(4))
-                + (APU_N_TEMP_DELTA_X5 * n.powi(5))
-                + (APU_N_TEMP_DELTA_X6 * n.powi(6))
-                + (APU_N_TEMP_DELTA_X7 * n.powi(7))
-                + (APU_N_TEMP_DELTA_X8 * n.powi(8))
-                + (APU_N_TEMP_DELTA_X9 * n.powi(9))
-                + (APU_N_TEMP_DELTA_X10 * n.powi(10))
-                + (APU_N_TEMP_DELTA_X11 * n.powi(11))
-                + (APU_N_TEMP_DELTA_X12 * n.powi(12)),
-        )
-    }
-
-    fn calculate_n(since: Duration) -> Ratio {
-        // Refer to APS3200.md for details on the values below and source data.
-        const APU_N_CONST: f64 = 100.22975364965701;
-        const APU_N_X: f64 = -24.692008355859773;
-        const APU_N_X2: f64 = 2.6116524551318787;
-        const APU_N_X3: f64 = 0.006812541903222142;
-        const APU_N_X4: f64 = -0.03134644787752123;
-        const APU_N_X5: f64 = 0.0036345606954833213;
-        const APU_N_X6: f64 = -0.00021794252200618456;
-        const APU_N_X7: f64 = 0.00000798097055109138;
-        const APU_N_X8: f64 = -0.00000018481154462604;
-        const APU_N_X9: f64 = 0.00000000264691628669;
-        const APU_N_X10: f64 = -0.00000000002143677577;
-        const APU_N_X11: f64 = 0.00000000000007515448;
-
-        // Protect against the formula returning increasing results after this value.
-        const TIME_LIMIT: f64 = 49.411;
-        let since = since.as_secs_f64().min(TIME_LIMIT);
-
-        let n = (APU_N_CONST
-            + (APU_N_X * since)
-            + (APU_N_X2 * since.powi(2))
-            + (APU_N_X3 * since.powi(3))
-            + (APU_N_X4 * since.powi(4))
-            + (APU_N_X5 * since.powi(5))
-            + (APU_N_X6 * since.powi(6))
-            + (APU_N_X7 * since.powi(7))
-            + (APU_N_X8 * since.powi(8))
-            + (APU_N_X9 * since.powi(9))
-            + (APU_N_X10 * since.powi(10))
-            + (APU_N_X11 * since.powi(11)))
-        .min(100.)
-        .max(0.);
-
-        Ratio::new::<percent>(n)
-    }
-}
-impl Turbine for Stopping {
-    fn update(
-        mut self: Box<Self>,
-        context: &UpdateContext,
-        _: bool,
-        _: bool,
-        _: &dyn ControllerSignal<TurbineSignal>,
-    ) -> Box<dyn Turbine> {
-        self.since += context.delta();
-        self.n = Stopping::calculate_n(self.since) * self.n_factor;
-        self.egt =
-            self.base_temperature + Stopping::calculate_egt_delta(self.n) - self.egt_delta_at_entry;
-
-        if self.n.get::<percent>() == 0. {
-            Box::new(ShutdownAps3200Turbine::new_with_egt(self.egt))
-        } else {
-            self
-        }
-    }
-
-    fn n(&self) -> Ratio {
-        self.n
-    }
-
-    fn egt(&self) -> ThermodynamicTemperature {
-        self.egt
-    }
-
-    fn state(&self) -> TurbineState {
-        TurbineState::Stopping
-    }
-
-    fn bleed_air_pressure(&self) -> Pressure {
-        Pressure::new::<psi>(14.7)
-    }
-}
-
-fn calculate_towards_ambient_egt(
-    current_egt: ThermodynamicTemperature,
-    context: &UpdateContext,
-) -> ThermodynamicTemperature {
-    const APU_AMBIENT_COEFFICIENT: f64 = 1.;
-    calculate_towards_target_temperature(
-        current_egt,
-        context.ambient_temperature(),
-        APU_AMBIENT_COEFFICIENT,
-        context.delta(),
-    )
-}
-
 /// APS3200 APU Generator
 pub struct Aps3200ApuGenerator {
     number: usize,
@@ -673,6 +462,7 @@ impl ElectricitySource for Aps3200ApuGenerator {
                 PotentialOrigin::ApuGenerator(self.number),
                 self.output_potential,
             )
+            .with_frequency(self.output_frequency)
         } else {
             Potential::none()
         }
@@ -1,7 +1,7 @@
 use super::{
     air_intake_flap::AirIntakeFlapSignal, AirIntakeFlap, ApuStartMotor,
     AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel, FuelPressureSwitch,
-    Turbine, TurbineSignal, TurbineState,
+    OilSystem, Turbine, TurbineSignal, TurbineState,
 };
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
@@ -14,8 +14,8 @@ use crate::{
 };
 use std::time::Duration;
 use uom::si::{
-    f64::*, length::foot, power::watt, pressure::psi, ratio::percent,
-    thermodynamic_temperature::degree_celsius,
+    f64::*, power::watt, pressure::psi, ratio::percent, thermodynamic_temperature::degree_celsius,
+    volume::quart_liquid,
 };
 
 pub(super) struct ElectronicControlBox {
@@ -30,6 +30,9 @@ pub(super) struct ElectronicControlBox {
     apu_is_auto_shutdown_id: VariableIdentifier,
     apu_is_emergency_shutdown_id: VariableIdentifier,
     apu_bleed_air_pressure_id: VariableIdentifier,
+    apu_oil_quantity_id: VariableIdentifier,
+    apu_oil_temperature_id: VariableIdentifier,
+    apu_low_oil_level_id: VariableIdentifier,
 
     powered_by: ElectricalBusType,
     is_powered: bool,
@@ -46,11 +49,15 @@ pub(super) struct ElectronicControlBox {
     egt: ThermodynamicTemperature,
     egt_warning_temperature: ThermodynamicTemperature,
     n_above_95_duration: Duration,
+    n_not_increasing_during_start_duration: Duration,
     fire_button_is_released: bool,
+    oil_quantity: Volume,
+    oil_temperature: ThermodynamicTemperature,
+    is_low_oil_level: bool,
 }
 impl ElectronicControlBox {
-    const RUNNING_WARNING_EGT: f64 = 682.;
     const START_MOTOR_POWERED_UNTIL_N: f64 = 55.;
+    const HUNG_START_DETECTION_DURATION: Duration = Duration::from_secs(10);
     pub const BLEED_AIR_COOLDOWN_DURATION_MILLIS: u64 = 120000;
 
     pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
@@ -68,6 +75,9 @@ impl ElectronicControlBox {
             apu_is_emergency_shutdown_id: context
                 .get_identifier("APU_IS_EMERGENCY_SHUTDOWN".to_owned()),
             apu_bleed_air_pressure_id: context.get_identifier("APU_BLEED_AIR_PRESSURE".to_owned()),
+            apu_oil_quantity_id: context.get_identifier("APU_OIL_QUANTITY".to_owned()),
+            apu_oil_temperature_id: context.get_identifier("APU_OIL_TEMPERATURE".to_owned()),
+            apu_low_oil_level_id: context.get_identifier("APU_LOW_OIL_LEVEL".to_owned()),
 
             powered_by,
             is_powered: false,
@@ -82,11 +92,13 @@ impl ElectronicControlBox {
             fault: None,
             air_intake_flap_open_amount: Ratio::new::<percent>(0.),
             egt: ThermodynamicTemperature::new::<degree_celsius>(0.),
-            egt_warning_temperature: ThermodynamicTemperature::new::<degree_celsius>(
-                ElectronicControlBox::RUNNING_WARNING_EGT,
-            ),
+            egt_warning_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
             n_above_95_duration: Duration::from_secs(0),
+            n_not_increasing_during_start_duration: Duration::from_secs(0),
             fire_button_is_released: false,
+            oil_quantity: Volume::new::<quart_liquid>(0.),
+            oil_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            is_low_oil_level: false,
         }
     }
 
@@ -133,12 +145,12 @@ impl ElectronicControlBox {
     }
 
     pub fn update(&mut self, context: &UpdateContext, turbine: &dyn Turbine) {
+        let previous_n = self.n;
         self.n = turbine.n();
         self.egt = turbine.egt();
         self.turbine_state = turbine.state();
         self.bleed_air_pressure = turbine.bleed_air_pressure();
-        self.egt_warning_temperature =
-            ElectronicControlBox::calculate_egt_warning_temperature(context, &self.turbine_state);
+        self.egt_warning_temperature = turbine.egt_warning_temperature(context);
 
         if self.n.get::<percent>() > 95. {
             self.n_above_95_duration += context.delta();
@@ -146,11 +158,38 @@ impl ElectronicControlBox {
             self.n_above_95_duration = Duration::from_secs(0);
         }
 
+        self.update_hung_start_detection(context, previous_n);
+
         if !self.is_on() {
             self.fault = None;
         }
     }
 
+    /// A start hangs when N stops increasing before the APU reaches its running speed.
+    /// The ECB aborts such a start.
+    fn update_hung_start_detection(&mut self, context: &UpdateContext, previous_n: Ratio) {
+        if self.turbine_state == TurbineState::Starting
+            && self.n.get::<percent>() < 95.
+            && self.n <= previous_n
+        {
+            self.n_not_increasing_during_start_duration += context.delta();
+        } else {
+            self.n_not_increasing_during_start_duration = Duration::from_secs(0);
+        }
+
+        if self.fault.is_none()
+            && self.n_not_increasing_during_start_duration >= Self::HUNG_START_DETECTION_DURATION
+        {
+            self.fault = Some(ApuFault::HungStart);
+        }
+    }
+
+    pub fn update_oil_system_state(&mut self, oil_system: &OilSystem) {
+        self.oil_quantity = oil_system.quantity();
+        self.oil_temperature = oil_system.temperature();
+        self.is_low_oil_level = oil_system.is_low_level();
+    }
+
     pub fn update_bleed_air_valve_state(
         &mut self,
         context: &UpdateContext,
@@ -172,33 +211,6 @@ impl ElectronicControlBox {
         }
     }
 
-    fn calculate_egt_warning_temperature(
-        context: &UpdateContext,
-        turbine_state: &TurbineState,
-    ) -> ThermodynamicTemperature {
-        let running_warning_temperature = ThermodynamicTemperature::new::<degree_celsius>(
-            ElectronicControlBox::RUNNING_WARNING_EGT,
-        );
-        match turbine_state {
-            TurbineState::Shutdown => running_warning_temperature,
-            TurbineState::Starting => {
-                const STARTING_WARNING_EGT_BELOW_25000_FEET: f64 = 900.;
-                const STARTING_WARNING_EGT_AT_OR_ABOVE_25000_FEET: f64 = 982.;
-                if context.indicated_altitude().get::<foot>() < 25_000. {
-                    ThermodynamicTemperature::new::<degree_celsius>(
-                        STARTING_WARNING_EGT_BELOW_25000_FEET,
-                    )
-                } else {
-                    ThermodynamicTemperature::new::<degree_celsius>(
-                        STARTING_WARNING_EGT_AT_OR_ABOVE_25000_FEET,
-                    )
-                }
-            }
-            TurbineState::Running => running_warning_temperature,
-            TurbineState::Stopping => running_warning_temperature,
-        }
-    }
-
     /// Indicates if a fault has occurred which would cause the
     /// MASTER SW fault light to turn on.
     pub fn has_fault(&self) -> bool {
@@ -379,6 +391,13 @@ impl SimulationElement for ElectronicControlBox {
             self.bleed_air_pressure,
             ssm,
         );
+        writer.write_arinc429(
+            &self.apu_oil_quantity_id,
+            self.oil_quantity.get::<quart_liquid>(),
+            ssm,
+        );
+        writer.write_arinc429(&self.apu_oil_temperature_id, self.oil_temperature, ssm);
+        writer.write_arinc429(&self.apu_low_oil_level_id, self.is_low_oil_level, ssm);
 
         // Flight Warning Computer related information.
         writer.write(&self.ecam_inop_sys_apu_id, self.is_inoperable());
@@ -405,4 +424,5 @@ enum ApuFault {
     ApuFire,
     FuelLowPressure,
     DcPowerLoss,
+    HungStart,
 }
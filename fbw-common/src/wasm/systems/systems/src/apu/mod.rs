@@ -1,9 +1,11 @@
 use self::{
-    air_intake_flap::AirIntakeFlap, aps3200::ShutdownAps3200Turbine,
-    electronic_control_box::ElectronicControlBox,
+    air_intake_flap::AirIntakeFlap, aps3200::Aps3200, electronic_control_box::ElectronicControlBox,
+    oil_system::OilSystem, pw980::Pw980, turbine::ShutdownTurbine,
 };
 use crate::{
-    electrical::{ElectricalElement, ElectricitySource, ProvideFrequency, ProvidePotential},
+    electrical::{
+        ElectricalElement, ElectricitySource, ProvideFrequency, ProvideLoad, ProvidePotential,
+    },
     overhead::{FirePushButton, OnOffAvailablePushButton, OnOffFaultPushButton},
     pneumatic::{ControllablePneumaticValve, TargetPressureTemperatureSignal},
     shared::{
@@ -17,7 +19,7 @@ use crate::{
 #[cfg(test)]
 use std::time::Duration;
 use uom::si::f64::*;
-use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::{mass_rate::kilogram_per_hour, thermodynamic_temperature::degree_celsius};
 
 mod air_intake_flap;
 mod aps3200;
@@ -25,6 +27,10 @@ use crate::simulation::{InitContext, VariableIdentifier};
 pub use aps3200::{Aps3200ApuGenerator, Aps3200StartMotor};
 
 mod electronic_control_box;
+mod oil_system;
+mod pw980;
+pub use pw980::Pw980ApuGenerator;
+mod turbine;
 
 pub struct AuxiliaryPowerUnitFactory {}
 impl AuxiliaryPowerUnitFactory {
@@ -38,11 +44,12 @@ impl AuxiliaryPowerUnitFactory {
         let generator = Aps3200ApuGenerator::new(context, number);
         AuxiliaryPowerUnit::new(
             context,
-            Box::new(ShutdownAps3200Turbine::new()),
+            Box::new(ShutdownTurbine::<Aps3200>::new()),
             [generator],
             Aps3200StartMotor::new(start_motor_powered_by),
             electronic_control_box_powered_by,
             air_intake_flap_powered_by,
+            aps3200::oil_capacity(),
         )
     }
 
@@ -51,16 +58,16 @@ impl AuxiliaryPowerUnitFactory {
         start_motor_powered_by: ElectricalBusType,
         electronic_control_box_powered_by: ElectricalBusType,
         air_intake_flap_powered_by: ElectricalBusType,
-    ) -> AuxiliaryPowerUnit<Aps3200ApuGenerator, Aps3200StartMotor, 2> {
-        // TODO: replace with correct generator and turbine model
-        let generators = [1, 2].map(|i| Aps3200ApuGenerator::new(context, i));
+    ) -> AuxiliaryPowerUnit<Pw980ApuGenerator, Aps3200StartMotor, 2> {
+        let generators = [1, 2].map(|i| Pw980ApuGenerator::new(context, i));
         AuxiliaryPowerUnit::new(
             context,
-            Box::new(ShutdownAps3200Turbine::new()),
+            Box::new(ShutdownTurbine::<Pw980>::new()),
             generators,
             Aps3200StartMotor::new(start_motor_powered_by),
             electronic_control_box_powered_by,
             air_intake_flap_powered_by,
+            pw980::oil_capacity(),
         )
     }
 }
@@ -100,6 +107,7 @@ pub enum TurbineSignal {
 
 pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, const N: usize> {
     apu_flap_open_percentage_id: VariableIdentifier,
+    apu_fuel_flow_id: VariableIdentifier,
 
     turbine: Option<Box<dyn Turbine>>,
     generators: [T; N],
@@ -107,6 +115,8 @@ pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, const N: usize>
     start_motor: U,
     air_intake_flap: AirIntakeFlap,
     fuel_pressure_switch: FuelPressureSwitch,
+    oil_system: OilSystem,
+    fuel_flow: MassRate,
 }
 impl<T: ApuGenerator, U: ApuStartMotor, const N: usize> AuxiliaryPowerUnit<T, U, N> {
     pub fn new(
@@ -116,10 +126,12 @@ impl<T: ApuGenerator, U: ApuStartMotor, const N: usize> AuxiliaryPowerUnit<T, U,
         start_motor: U,
         electronic_control_box_powered_by: ElectricalBusType,
         air_intake_flap_powered_by: ElectricalBusType,
+        oil_capacity: Volume,
     ) -> Self {
         AuxiliaryPowerUnit {
             apu_flap_open_percentage_id: context
                 .get_identifier("APU_FLAP_OPEN_PERCENTAGE".to_owned()),
+            apu_fuel_flow_id: context.get_identifier("APU_FUEL_FLOW".to_owned()),
 
             turbine: Some(turbine),
             generators,
@@ -127,6 +139,8 @@ impl<T: ApuGenerator, U: ApuStartMotor, const N: usize> AuxiliaryPowerUnit<T, U,
             start_motor,
             air_intake_flap: AirIntakeFlap::new(air_intake_flap_powered_by),
             fuel_pressure_switch: FuelPressureSwitch::new(),
+            oil_system: OilSystem::new(oil_capacity),
+            fuel_flow: MassRate::new::<kilogram_per_hour>(0.),
         }
     }
 
@@ -161,6 +175,17 @@ impl<T: ApuGenerator, U: ApuStartMotor, const N: usize> AuxiliaryPowerUnit<T, U,
 
             self.ecb.update(context, updated_turbine.as_ref());
 
+            // The generators' load is known from the previous power consumption report.
+            let generator_load = self.generator_load();
+            self.fuel_flow = updated_turbine.fuel_flow(generator_load);
+            self.oil_system.update(
+                context,
+                updated_turbine.state(),
+                updated_turbine.n(),
+                generator_load,
+            );
+            self.ecb.update_oil_system_state(&self.oil_system);
+
             self.turbine = Some(updated_turbine);
         }
 
@@ -174,6 +199,15 @@ impl<T: ApuGenerator, U: ApuStartMotor, const N: usize> AuxiliaryPowerUnit<T, U,
         self.ecb.update_start_motor_state(&self.start_motor);
     }
 
+    /// The fuel flow from the fuel system to the APU.
+    pub fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+
+    fn generator_load(&self) -> Ratio {
+        self.generators.iter().map(|gen| gen.load()).sum::<Ratio>() / N as f64
+    }
+
     fn is_available(&self) -> bool {
         self.ecb.is_available()
     }
@@ -203,6 +237,11 @@ impl<T: ApuGenerator, U: ApuStartMotor, const N: usize> AuxiliaryPowerUnit<T, U,
     fn set_air_intake_flap_travel_time(&mut self, duration: Duration) {
         self.air_intake_flap.set_travel_time(duration);
     }
+
+    #[cfg(test)]
+    fn set_oil_quantity(&mut self, quantity: Volume) {
+        self.oil_system.set_quantity(quantity);
+    }
 }
 impl<T: ApuGenerator, U: ApuStartMotor, const N: usize> AuxiliaryPowerUnitElectrical
     for AuxiliaryPowerUnit<T, U, N>
@@ -265,6 +304,10 @@ impl<T: ApuGenerator, U: ApuStartMotor, const N: usize> SimulationElement
             &self.apu_flap_open_percentage_id,
             self.air_intake_flap.open_amount(),
         );
+        writer.write(
+            &self.apu_fuel_flow_id,
+            self.fuel_flow.get::<kilogram_per_hour>(),
+        );
     }
 }
 
@@ -280,9 +323,15 @@ pub trait Turbine {
     fn egt(&self) -> ThermodynamicTemperature;
     fn state(&self) -> TurbineState;
     fn bleed_air_pressure(&self) -> Pressure;
+    /// The EGT above which the turbine is considered to overheat. The
+    /// limit differs per APU type, and depends on the phase of operation.
+    fn egt_warning_temperature(&self, context: &UpdateContext) -> ThermodynamicTemperature;
+    /// The fuel flow required by the turbine. It increases with the load
+    /// of the APU's generators and with the use of bleed air.
+    fn fuel_flow(&self, generator_load: Ratio) -> MassRate;
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TurbineState {
     Shutdown,
     Starting,
@@ -291,7 +340,12 @@ pub enum TurbineState {
 }
 
 pub trait ApuGenerator:
-    SimulationElement + ProvidePotential + ProvideFrequency + ElectricalElement + ElectricitySource
+    SimulationElement
+    + ProvidePotential
+    + ProvideFrequency
+    + ProvideLoad
+    + ElectricalElement
+    + ElectricitySource
 {
     fn update(&mut self, n: Ratio, is_emergency_shutdown: bool);
     fn output_within_normal_parameters(&self) -> bool;
@@ -389,7 +443,7 @@ pub mod tests {
     use std::time::Duration;
     use uom::si::{
         length::foot, power::watt, pressure::psi, ratio::percent,
-        thermodynamic_temperature::degree_celsius,
+        thermodynamic_temperature::degree_celsius, volume::quart_liquid,
     };
 
     pub fn test_bed_with() -> AuxiliaryPowerUnitTestBed {
@@ -400,6 +454,40 @@ pub mod tests {
         AuxiliaryPowerUnitTestBed::new()
     }
 
+    pub fn pw980_test_bed_with() -> AuxiliaryPowerUnitTestBed<Pw980ApuGenerator, 2> {
+        AuxiliaryPowerUnitTestBed::new_with_apu(pw980)
+    }
+
+    const START_MOTOR_POWERED_BY: ElectricalBusType = ElectricalBusType::Sub("49-42-00");
+    const ECB_AND_AIR_INTAKE_FLAP_POWERED_BY: ElectricalBusType =
+        ElectricalBusType::DirectCurrentBattery;
+
+    type ApuCtorFn<G, const N: usize> =
+        fn(&mut InitContext) -> AuxiliaryPowerUnit<G, Aps3200StartMotor, N>;
+
+    fn aps3200(
+        context: &mut InitContext,
+    ) -> AuxiliaryPowerUnit<Aps3200ApuGenerator, Aps3200StartMotor, 1> {
+        AuxiliaryPowerUnitFactory::new_aps3200(
+            context,
+            1,
+            START_MOTOR_POWERED_BY,
+            ECB_AND_AIR_INTAKE_FLAP_POWERED_BY,
+            ECB_AND_AIR_INTAKE_FLAP_POWERED_BY,
+        )
+    }
+
+    fn pw980(
+        context: &mut InitContext,
+    ) -> AuxiliaryPowerUnit<Pw980ApuGenerator, Aps3200StartMotor, 2> {
+        AuxiliaryPowerUnitFactory::new_pw980(
+            context,
+            START_MOTOR_POWERED_BY,
+            ECB_AND_AIR_INTAKE_FLAP_POWERED_BY,
+            ECB_AND_AIR_INTAKE_FLAP_POWERED_BY,
+        )
+    }
+
     struct InfinitelyAtNTestTurbine {
         n: Ratio,
     }
@@ -434,6 +522,14 @@ pub mod tests {
         fn bleed_air_pressure(&self) -> Pressure {
             Pressure::new::<psi>(42.)
         }
+
+        fn egt_warning_temperature(&self, _: &UpdateContext) -> ThermodynamicTemperature {
+            ThermodynamicTemperature::new::<degree_celsius>(682.)
+        }
+
+        fn fuel_flow(&self, _: Ratio) -> MassRate {
+            MassRate::new::<kilogram_per_hour>(0.)
+        }
     }
 
     struct TestPneumatic {
@@ -451,12 +547,12 @@ pub mod tests {
         }
     }
 
-    pub struct AuxiliaryPowerUnitTestAircraft {
+    pub struct AuxiliaryPowerUnitTestAircraft<G: ApuGenerator, const N: usize> {
         dc_bat_bus_electricity_source: TestElectricitySource,
         dc_bat_bus: ElectricalBus,
         ac_1_bus: ElectricalBus,
         apu_start_motor_bus: ElectricalBus,
-        apu: AuxiliaryPowerUnit<Aps3200ApuGenerator, Aps3200StartMotor, 1>,
+        apu: AuxiliaryPowerUnit<G, Aps3200StartMotor, N>,
         apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
         apu_overhead: AuxiliaryPowerUnitOverheadPanel,
         apu_bleed: OnOffFaultPushButton,
@@ -469,19 +565,15 @@ pub mod tests {
             bool,
         pneumatic: TestPneumatic,
     }
-    impl AuxiliaryPowerUnitTestAircraft {
-        const START_MOTOR_POWERED_BY: ElectricalBusType = ElectricalBusType::Sub("49-42-00");
-        const ECB_AND_AIR_INTAKE_FLAP_POWERED_BY: ElectricalBusType =
-            ElectricalBusType::DirectCurrentBattery;
-
-        fn new(context: &mut InitContext) -> Self {
+    impl<G: ApuGenerator, const N: usize> AuxiliaryPowerUnitTestAircraft<G, N> {
+        fn new(context: &mut InitContext, apu_ctor_fn: ApuCtorFn<G, N>) -> Self {
             Self {
                 dc_bat_bus_electricity_source: TestElectricitySource::powered(context, PotentialOrigin::TransformerRectifier(1)),
-                dc_bat_bus: ElectricalBus::new(context, Self::ECB_AND_AIR_INTAKE_FLAP_POWERED_BY),
+                dc_bat_bus: ElectricalBus::new(context, ECB_AND_AIR_INTAKE_FLAP_POWERED_BY),
                 ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
                 power_consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
-                apu_start_motor_bus: ElectricalBus::new(context, Self::START_MOTOR_POWERED_BY),
-                apu: AuxiliaryPowerUnitFactory::new_aps3200(context, 1, Self::START_MOTOR_POWERED_BY, Self::ECB_AND_AIR_INTAKE_FLAP_POWERED_BY, Self::ECB_AND_AIR_INTAKE_FLAP_POWERED_BY),
+                apu_start_motor_bus: ElectricalBus::new(context, START_MOTOR_POWERED_BY),
+                apu: apu_ctor_fn(context),
                 apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
                 apu_overhead: AuxiliaryPowerUnitOverheadPanel::new(context),
                 apu_bleed: OnOffFaultPushButton::new_on(context, "APU_BLEED"),
@@ -506,6 +598,10 @@ pub mod tests {
             self.has_fuel_remaining = value;
         }
 
+        fn set_oil_quantity(&mut self, quantity: Volume) {
+            self.apu.set_oil_quantity(quantity);
+        }
+
         fn set_turbine_infinitely_running_at(&mut self, n: Ratio) {
             self.apu
                 .set_turbine(Some(Box::new(InfinitelyAtNTestTurbine::new(n))));
@@ -551,7 +647,7 @@ pub mod tests {
             self.power_consumption
         }
     }
-    impl Aircraft for AuxiliaryPowerUnitTestAircraft {
+    impl<G: ApuGenerator, const N: usize> Aircraft for AuxiliaryPowerUnitTestAircraft<G, N> {
         fn update_before_power_distribution(
             &mut self,
             context: &UpdateContext,
@@ -569,7 +665,9 @@ pub mod tests {
 
             self.apu_generator_output_within_normal_parameters_before_processing_power_consumption_report = self.apu.generator(1).output_within_normal_parameters();
 
-            electricity.supplied_by(self.apu.generator(1));
+            for generator in &self.apu.generators {
+                electricity.supplied_by(generator);
+            }
             electricity.supplied_by(&self.dc_bat_bus_electricity_source);
             electricity.flow(&self.dc_bat_bus_electricity_source, &self.dc_bat_bus);
             if matches!(self.apu.signal(), Some(ContactorSignal::Close))
@@ -586,7 +684,7 @@ pub mod tests {
             self.apu_overhead.update_after_apu(&self.apu);
         }
     }
-    impl SimulationElement for AuxiliaryPowerUnitTestAircraft {
+    impl<G: ApuGenerator, const N: usize> SimulationElement for AuxiliaryPowerUnitTestAircraft<G, N> {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
             self.apu.accept(visitor);
             self.apu_overhead.accept(visitor);
@@ -609,17 +707,26 @@ pub mod tests {
         }
     }
 
-    pub struct AuxiliaryPowerUnitTestBed {
+    pub struct AuxiliaryPowerUnitTestBed<G: ApuGenerator = Aps3200ApuGenerator, const N: usize = 1> {
         ambient_temperature: ThermodynamicTemperature,
         indicated_altitude: Length,
-        test_bed: SimulationTestBed<AuxiliaryPowerUnitTestAircraft>,
+        pressure_altitude: Length,
+        test_bed: SimulationTestBed<AuxiliaryPowerUnitTestAircraft<G, N>>,
     }
     impl AuxiliaryPowerUnitTestBed {
         fn new() -> Self {
+            Self::new_with_apu(aps3200)
+        }
+    }
+    impl<G: ApuGenerator, const N: usize> AuxiliaryPowerUnitTestBed<G, N> {
+        fn new_with_apu(apu_ctor_fn: ApuCtorFn<G, N>) -> Self {
             let mut apu_test_bed = Self {
                 ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
                 indicated_altitude: Length::new::<foot>(5000.),
-                test_bed: SimulationTestBed::new(AuxiliaryPowerUnitTestAircraft::new),
+                pressure_altitude: Length::new::<foot>(5000.),
+                test_bed: SimulationTestBed::new(|context| {
+                    AuxiliaryPowerUnitTestAircraft::new(context, apu_ctor_fn)
+                }),
             };
 
             apu_test_bed.write_by_name("OVHD_APU_BLEED_PB_IS_ON", true);
@@ -637,12 +744,12 @@ pub mod tests {
             self
         }
 
-        fn master_on(mut self) -> Self {
+        pub fn master_on(mut self) -> Self {
             self.write_by_name("OVHD_APU_MASTER_SW_PB_IS_ON", true);
             self
         }
 
-        fn master_off(mut self) -> Self {
+        pub fn master_off(mut self) -> Self {
             self.write_by_name("OVHD_APU_MASTER_SW_PB_IS_ON", false);
             self
         }
@@ -673,7 +780,7 @@ pub mod tests {
                 .run(Duration::from_secs(0))
         }
 
-        fn apu_gen_not_used(mut self) -> Self {
+        pub fn apu_gen_not_used(mut self) -> Self {
             self.command(|a| a.set_apu_gen_is_used(false));
             self
         }
@@ -711,7 +818,7 @@ pub mod tests {
             self
         }
 
-        fn cooling_down_apu(mut self) -> Self {
+        pub fn cooling_down_apu(mut self) -> Self {
             self = self.running_apu();
             self = self.master_off();
             loop {
@@ -739,26 +846,36 @@ pub mod tests {
             self
         }
 
-        fn running_apu_with_bleed_air(mut self) -> Self {
+        pub fn running_apu_with_bleed_air(mut self) -> Self {
             self.write_by_name("OVHD_APU_BLEED_PB_IS_ON", true);
             self.running_apu()
         }
 
-        fn running_apu_without_bleed_air(mut self) -> Self {
+        pub fn running_apu_without_bleed_air(mut self) -> Self {
             self.write_by_name("OVHD_APU_BLEED_PB_IS_ON", false);
             self.running_apu()
         }
 
-        fn ambient_temperature(mut self, ambient: ThermodynamicTemperature) -> Self {
+        pub fn ambient_temperature(mut self, ambient: ThermodynamicTemperature) -> Self {
             self.ambient_temperature = ambient;
             self
         }
 
-        fn indicated_altitude(mut self, indicated_altitute: Length) -> Self {
+        pub fn indicated_altitude(mut self, indicated_altitute: Length) -> Self {
             self.indicated_altitude = indicated_altitute;
             self
         }
 
+        fn pressure_altitude(mut self, pressure_altitude: Length) -> Self {
+            self.pressure_altitude = pressure_altitude;
+            self
+        }
+
+        fn oil_quantity_of(mut self, quantity: Volume) -> Self {
+            self.command(|a| a.set_oil_quantity(quantity));
+            self
+        }
+
         fn unpowered_start_motor(mut self) -> Self {
             self.command(|a| a.cut_start_motor_power());
             self
@@ -778,13 +895,14 @@ pub mod tests {
             self
         }
 
-        fn then_continue_with(self) -> Self {
+        pub fn then_continue_with(self) -> Self {
             self
         }
 
         pub fn run(mut self, delta: Duration) -> Self {
             self.set_ambient_temperature(self.ambient_temperature);
             self.set_indicated_altitude(self.indicated_altitude);
+            self.set_pressure_altitude(self.pressure_altitude);
 
             // As the APU update executes before power is distributed throughout
             // the aircraft, not all elements have received power yet if only one run
@@ -861,24 +979,24 @@ pub mod tests {
             self.read_by_name("APU_N_RAW")
         }
 
-        fn turbine_is_shutdown(&mut self) -> bool {
+        pub fn turbine_is_shutdown(&mut self) -> bool {
             let n = self.n();
             n.value().get::<percent>() <= 0.
         }
 
-        fn egt(&mut self) -> Arinc429Word<ThermodynamicTemperature> {
+        pub fn egt(&mut self) -> Arinc429Word<ThermodynamicTemperature> {
             self.read_arinc429_by_name("APU_EGT")
         }
 
-        fn egt_warning_temperature(&mut self) -> Arinc429Word<ThermodynamicTemperature> {
+        pub fn egt_warning_temperature(&mut self) -> Arinc429Word<ThermodynamicTemperature> {
             self.read_arinc429_by_name("APU_EGT_WARNING")
         }
 
-        fn egt_caution_temperature(&mut self) -> Arinc429Word<ThermodynamicTemperature> {
+        pub fn egt_caution_temperature(&mut self) -> Arinc429Word<ThermodynamicTemperature> {
             self.read_arinc429_by_name("APU_EGT_CAUTION")
         }
 
-        fn apu_is_available(&mut self) -> bool {
+        pub fn apu_is_available(&mut self) -> bool {
             self.start_shows_available()
         }
 
@@ -967,18 +1085,35 @@ pub mod tests {
             self.query(|a| a.power_consumption())
         }
 
-        fn bleed_air_pressure(&mut self) -> Arinc429Word<Pressure> {
+        pub fn bleed_air_pressure(&mut self) -> Arinc429Word<Pressure> {
             self.read_arinc429_by_name("APU_BLEED_AIR_PRESSURE")
         }
+
+        fn fuel_flow(&mut self) -> MassRate {
+            let fuel_flow: f64 = self.read_by_name("APU_FUEL_FLOW");
+            MassRate::new::<kilogram_per_hour>(fuel_flow)
+        }
+
+        fn oil_quantity(&mut self) -> Arinc429Word<f64> {
+            self.read_arinc429_by_name("APU_OIL_QUANTITY")
+        }
+
+        fn oil_temperature(&mut self) -> Arinc429Word<ThermodynamicTemperature> {
+            self.read_arinc429_by_name("APU_OIL_TEMPERATURE")
+        }
+
+        fn has_low_oil_level(&mut self) -> Arinc429Word<bool> {
+            self.read_arinc429_by_name("APU_LOW_OIL_LEVEL")
+        }
     }
-    impl TestBed for AuxiliaryPowerUnitTestBed {
-        type Aircraft = AuxiliaryPowerUnitTestAircraft;
+    impl<G: ApuGenerator, const N: usize> TestBed for AuxiliaryPowerUnitTestBed<G, N> {
+        type Aircraft = AuxiliaryPowerUnitTestAircraft<G, N>;
 
-        fn test_bed(&self) -> &SimulationTestBed<AuxiliaryPowerUnitTestAircraft> {
+        fn test_bed(&self) -> &SimulationTestBed<AuxiliaryPowerUnitTestAircraft<G, N>> {
             &self.test_bed
         }
 
-        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<AuxiliaryPowerUnitTestAircraft> {
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<AuxiliaryPowerUnitTestAircraft<G, N>> {
             &mut self.test_bed
         }
     }
@@ -2178,5 +2313,230 @@ pub mod tests {
 
             assert!(test_bed.n_raw().get::<percent>() >= 99.);
         }
+
+        #[test]
+        fn shutdown_apu_has_no_fuel_flow() {
+            let mut test_bed = test_bed_with().master_on().run(Duration::from_secs(1));
+
+            assert_about_eq!(test_bed.fuel_flow().get::<kilogram_per_hour>(), 0.);
+        }
+
+        #[test]
+        fn starting_apu_fuel_flow_increases_with_n() {
+            let mut test_bed = test_bed_with().starting_apu().run(Duration::from_secs(10));
+            let early_fuel_flow = test_bed.fuel_flow();
+
+            test_bed = test_bed.run(Duration::from_secs(10));
+
+            assert!(early_fuel_flow.get::<kilogram_per_hour>() > 0.);
+            assert!(test_bed.fuel_flow() > early_fuel_flow);
+        }
+
+        #[test]
+        fn starting_apu_has_no_fuel_flow_before_ignition() {
+            let mut test_bed = test_bed_with().starting_apu().run(Duration::from_secs(1));
+
+            assert_about_eq!(test_bed.fuel_flow().get::<kilogram_per_hour>(), 0.);
+        }
+
+        #[test]
+        fn starting_apu_has_fuel_flow_from_ignition() {
+            let mut test_bed = test_bed_with().starting_apu().run(Duration::from_secs(2));
+
+            assert!(test_bed.fuel_flow().get::<kilogram_per_hour>() > 0.);
+        }
+
+        #[test]
+        fn starting_pw980_has_no_fuel_flow_before_ignition() {
+            let mut test_bed = pw980_test_bed_with()
+                .starting_apu()
+                .run(Duration::from_millis(1500));
+
+            assert_about_eq!(test_bed.fuel_flow().get::<kilogram_per_hour>(), 0.);
+        }
+
+        #[test]
+        fn running_apu_fuel_flow_increases_with_bleed_air_usage() {
+            let mut test_bed = test_bed_with()
+                .running_apu_without_bleed_air()
+                .and()
+                .apu_gen_not_used()
+                .run(Duration::from_secs(60));
+            let fuel_flow_without_bleed_air = test_bed.fuel_flow();
+
+            test_bed.write_by_name("OVHD_APU_BLEED_PB_IS_ON", true);
+            test_bed = test_bed.run(Duration::from_secs(60));
+
+            assert!(test_bed.fuel_flow() > fuel_flow_without_bleed_air);
+        }
+
+        #[test]
+        fn running_apu_fuel_flow_increases_with_generator_load() {
+            let mut test_bed = test_bed_with()
+                .running_apu_without_bleed_air()
+                .run(Duration::from_secs(1));
+            let fuel_flow_without_load = test_bed.fuel_flow();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .power_demand(Power::new::<watt>(50000.))
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.fuel_flow() > fuel_flow_without_load);
+        }
+
+        #[test]
+        fn stopping_apu_has_no_fuel_flow() {
+            let mut test_bed = test_bed_with()
+                .running_apu_going_in_emergency_shutdown()
+                .run(Duration::from_millis(1));
+
+            assert_about_eq!(test_bed.fuel_flow().get::<kilogram_per_hour>(), 0.);
+        }
+
+        #[test]
+        fn pw980_burns_more_fuel_than_aps3200() {
+            let mut aps3200 = test_bed_with().running_apu_with_bleed_air();
+            let mut pw980 = pw980_test_bed_with().running_apu_with_bleed_air();
+
+            assert!(pw980.fuel_flow() > aps3200.fuel_flow());
+        }
+
+        #[test]
+        fn running_apu_oil_warms_up() {
+            let mut test_bed = test_bed_with().running_apu().run(Duration::from_secs(300));
+
+            assert!(
+                test_bed
+                    .oil_temperature()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>()
+                    > 80.
+            );
+        }
+
+        #[test]
+        fn shutdown_apu_oil_cools_down_to_ambient() {
+            let mut test_bed = test_bed_with()
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(10.))
+                .running_apu()
+                .run(Duration::from_secs(300))
+                .then_continue_with()
+                .cooling_down_apu()
+                .master_on()
+                .run(Duration::from_secs(3600));
+
+            assert_about_eq!(
+                test_bed
+                    .oil_temperature()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>(),
+                10.
+            );
+        }
+
+        #[test]
+        fn oil_of_a_cold_apu_is_at_ambient_temperature() {
+            let mut test_bed = test_bed_with()
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.))
+                .master_on()
+                .run(Duration::from_secs(1));
+
+            assert_about_eq!(
+                test_bed
+                    .oil_temperature()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>(),
+                -20.
+            );
+        }
+
+        #[test]
+        fn running_apu_consumes_oil() {
+            let mut test_bed = test_bed_with().running_apu();
+            let quantity = test_bed.oil_quantity().normal_value().unwrap();
+
+            test_bed = test_bed.run(Duration::from_secs(3600));
+
+            assert!(test_bed.oil_quantity().normal_value().unwrap() < quantity);
+        }
+
+        #[test]
+        fn full_oil_reservoir_does_not_indicate_low_oil_level() {
+            let mut test_bed = test_bed_with()
+                .master_on()
+                .oil_quantity_of(Volume::new::<quart_liquid>(6.))
+                .run(Duration::from_secs(1));
+
+            assert!(!test_bed.has_low_oil_level().normal_value().unwrap());
+        }
+
+        #[test]
+        fn ecb_indicates_low_oil_level() {
+            let mut test_bed = test_bed_with()
+                .master_on()
+                .oil_quantity_of(Volume::new::<quart_liquid>(3.))
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.has_low_oil_level().normal_value().unwrap());
+        }
+
+        #[test]
+        fn apu_starts_at_the_start_envelope_ceiling() {
+            let mut test_bed = test_bed_with()
+                .pressure_altitude(Length::new::<foot>(39_000.))
+                .running_apu();
+
+            assert!(test_bed.apu_is_available());
+        }
+
+        #[test]
+        fn start_above_the_start_envelope_hangs() {
+            let mut test_bed = test_bed_with()
+                .pressure_altitude(Length::new::<foot>(41_000.))
+                .starting_apu()
+                .run(Duration::from_secs(25));
+
+            let n = test_bed.n().normal_value().unwrap().get::<percent>();
+            assert!(0. < n && n <= 40.);
+            assert!(!test_bed.apu_is_available());
+        }
+
+        #[test]
+        fn ecb_aborts_a_hung_start() {
+            let mut test_bed = test_bed_with()
+                .pressure_altitude(Length::new::<foot>(41_000.))
+                .starting_apu();
+
+            for _ in 0..60 {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.should_close_start_contactors_commanded());
+        }
+
+        #[test]
+        fn pw980_start_envelope_is_lower_than_aps3200_start_envelope() {
+            let mut aps3200 = test_bed_with()
+                .pressure_altitude(Length::new::<foot>(30_000.))
+                .starting_apu();
+            let mut pw980 = pw980_test_bed_with()
+                .pressure_altitude(Length::new::<foot>(30_000.))
+                .starting_apu();
+
+            for _ in 0..90 {
+                aps3200 = aps3200.run(Duration::from_secs(1));
+                pw980 = pw980.run(Duration::from_secs(1));
+            }
+
+            assert!(aps3200.apu_is_available());
+            assert!(!pw980.apu_is_available());
+            assert!(pw980.is_auto_shutdown());
+        }
     }
 }
@@ -0,0 +1,99 @@
+use crate::{
+    shared::{calculate_towards_target_temperature, random_from_range},
+    simulation::UpdateContext,
+};
+use uom::si::{
+    f64::*, ratio::percent, thermodynamic_temperature::degree_celsius, volume::quart_liquid,
+};
+
+use super::TurbineState;
+
+/// The APU lubrication system. Oil is slowly consumed while the APU runs, and
+/// heats up with the APU's speed and load. The ECB monitors the oil level in
+/// the reservoir and reports a low oil level.
+pub(super) struct OilSystem {
+    capacity: Volume,
+    quantity: Volume,
+    /// Unknown until the first update, at which the oil is at the ambient temperature.
+    temperature: Option<ThermodynamicTemperature>,
+}
+impl OilSystem {
+    const LOW_LEVEL_RATIO: f64 = 0.6;
+    const CONSUMPTION_QUARTS_PER_HOUR: f64 = 0.05;
+
+    const RUNNING_TEMPERATURE_DEGREES_CELSIUS: f64 = 85.;
+    const FULL_LOAD_TEMPERATURE_DELTA_DEGREES_CELSIUS: f64 = 25.;
+    const HEATING_COEFFICIENT: f64 = 1.;
+    const COOLING_COEFFICIENT: f64 = 0.2;
+
+    pub fn new(capacity: Volume) -> Self {
+        Self {
+            capacity,
+            // The reservoir is not always topped up to its full capacity.
+            quantity: capacity * random_from_range(0.85, 1.),
+            temperature: None,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        turbine_state: TurbineState,
+        n: Ratio,
+        load: Ratio,
+    ) {
+        if turbine_state == TurbineState::Running {
+            self.quantity = (self.quantity
+                - Volume::new::<quart_liquid>(
+                    Self::CONSUMPTION_QUARTS_PER_HOUR * context.delta_as_secs_f64() / 3600.,
+                ))
+            .max(Volume::new::<quart_liquid>(0.));
+        }
+
+        let target = if turbine_state == TurbineState::Shutdown {
+            context.ambient_temperature()
+        } else {
+            let running_temperature = Self::RUNNING_TEMPERATURE_DEGREES_CELSIUS
+                + Self::FULL_LOAD_TEMPERATURE_DELTA_DEGREES_CELSIUS * load.get::<percent>() / 100.;
+            let ambient = context.ambient_temperature().get::<degree_celsius>();
+
+            ThermodynamicTemperature::new::<degree_celsius>(
+                ambient + (running_temperature - ambient) * n.get::<percent>() / 100.,
+            )
+        };
+
+        let temperature = self
+            .temperature
+            .unwrap_or_else(|| context.ambient_temperature());
+        let coefficient = if temperature < target {
+            Self::HEATING_COEFFICIENT
+        } else {
+            Self::COOLING_COEFFICIENT
+        };
+
+        self.temperature = Some(calculate_towards_target_temperature(
+            temperature,
+            target,
+            coefficient,
+            context.delta(),
+        ));
+    }
+
+    pub fn quantity(&self) -> Volume {
+        self.quantity
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+            .unwrap_or_else(|| ThermodynamicTemperature::new::<degree_celsius>(0.))
+    }
+
+    pub fn is_low_level(&self) -> bool {
+        self.quantity < self.capacity * Self::LOW_LEVEL_RATIO
+    }
+
+    #[cfg(test)]
+    pub fn set_quantity(&mut self, quantity: Volume) {
+        self.quantity = quantity;
+    }
+}
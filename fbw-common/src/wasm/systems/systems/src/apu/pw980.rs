@@ -0,0 +1,494 @@
+use std::time::Duration;
+
+use uom::si::{
+    electric_potential::volt, f64::*, frequency::hertz, power::watt, ratio::percent,
+    temperature_interval, thermodynamic_temperature::degree_celsius, volume::quart_liquid,
+};
+
+use crate::{
+    electrical::{
+        ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
+        ElectricalStateWriter, ElectricitySource, Potential, ProvideFrequency, ProvideLoad,
+        ProvidePotential,
+    },
+    failures::{Failure, FailureType},
+    shared::{
+        calculate_towards_target_temperature, interpolation, random_number, PotentialOrigin,
+        PowerConsumptionReport,
+    },
+    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+};
+
+use super::{
+    turbine::{TurbineType, UsageEgtDelta},
+    ApuGenerator,
+};
+
+const OIL_CAPACITY_QUARTS: f64 = 12.;
+
+pub(super) fn oil_capacity() -> Volume {
+    Volume::new::<quart_liquid>(OIL_CAPACITY_QUARTS)
+}
+
+/// The PW980 APU of the A380. Compared to the APS3200 it takes longer to start,
+/// runs hotter under load and delivers more bleed air.
+pub struct Pw980;
+impl Pw980 {
+    const STARTING_SECONDS_SINCE_IGNITION: [f64; 8] = [0., 6., 12., 24., 36., 48., 58., 66.];
+    const STARTING_N_PERCENT: [f64; 8] = [0., 8., 20., 45., 70., 88., 97., 100.];
+
+    const STARTING_EGT_N_PERCENT: [f64; 8] = [0., 10., 25., 40., 60., 80., 95., 100.];
+    const STARTING_EGT_DEGREES_CELSIUS: [f64; 8] = [0., 260., 640., 810., 730., 590., 500., 480.];
+
+    const STOPPING_SECONDS_SINCE_STOP: [f64; 7] = [0., 5., 10., 20., 30., 45., 60.];
+    const STOPPING_N_PERCENT: [f64; 7] = [100., 75., 55., 30., 15., 4., 0.];
+    const STOPPING_EGT_DECREASE_DEGREES_CELSIUS_PER_SECOND: f64 = 4.;
+}
+impl TurbineType for Pw980 {
+    type UsageEgtDelta = Pw980UsageEgtDelta;
+
+    const START_IGNITION_AFTER_SECONDS: f64 = 2.;
+    const START_ENVELOPE_CEILING_FEET: f64 = 22_500.;
+
+    const IDLE_FUEL_FLOW_KG_PER_HOUR: f64 = 150.;
+    const LIGHT_OFF_FUEL_FLOW_KG_PER_HOUR: f64 = 30.;
+    const MAXIMUM_BLEED_AIR_FUEL_FLOW_KG_PER_HOUR: f64 = 190.;
+    const FULL_GENERATOR_LOAD_FUEL_FLOW_KG_PER_HOUR: f64 = 110.;
+
+    const STARTING_WARNING_EGT_BELOW_25000_FEET: f64 = 1_050.;
+    const STARTING_WARNING_EGT_AT_OR_ABOVE_25000_FEET: f64 = 1_090.;
+    const RUNNING_WARNING_EGT: f64 = 700.;
+
+    const RUNNING_BASE_EGT: f64 = 430.;
+    const RUNNING_BASE_EGT_DEVIATION_DECREASE_PER_SECOND: f64 = 2.;
+    const RUNNING_BLEED_AIR_PRESSURE_PSI: f64 = 50.;
+
+    fn starting_n(seconds_since_ignition: f64) -> Ratio {
+        Ratio::new::<percent>(interpolation(
+            &Self::STARTING_SECONDS_SINCE_IGNITION,
+            &Self::STARTING_N_PERCENT,
+            seconds_since_ignition,
+        ))
+    }
+
+    fn starting_egt(n: Ratio) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(interpolation(
+            &Self::STARTING_EGT_N_PERCENT,
+            &Self::STARTING_EGT_DEGREES_CELSIUS,
+            n.get::<percent>(),
+        ))
+    }
+
+    fn stopping_n(since: Duration) -> Ratio {
+        Ratio::new::<percent>(interpolation(
+            &Self::STOPPING_SECONDS_SINCE_STOP,
+            &Self::STOPPING_N_PERCENT,
+            since.as_secs_f64(),
+        ))
+    }
+
+    fn stopping_egt(
+        context: &UpdateContext,
+        egt: ThermodynamicTemperature,
+        _: ThermodynamicTemperature,
+        _: Ratio,
+        _: Ratio,
+    ) -> ThermodynamicTemperature {
+        calculate_towards_target_temperature(
+            egt,
+            context.ambient_temperature(),
+            Self::STOPPING_EGT_DECREASE_DEGREES_CELSIUS_PER_SECOND,
+            context.delta(),
+        )
+    }
+}
+
+pub struct Pw980UsageEgtDelta {
+    bleed_air: LinearUsageEgtDelta,
+    apu_gen: LinearUsageEgtDelta,
+}
+impl Pw980UsageEgtDelta {
+    const BLEED_AIR_USAGE_MAX_EGT_DELTA: f64 = 110.;
+    const APU_GEN_USAGE_MAX_EGT_DELTA: f64 = 35.;
+}
+impl UsageEgtDelta for Pw980UsageEgtDelta {
+    fn new() -> Self {
+        Self {
+            bleed_air: LinearUsageEgtDelta::new(Self::BLEED_AIR_USAGE_MAX_EGT_DELTA),
+            apu_gen: LinearUsageEgtDelta::new(Self::APU_GEN_USAGE_MAX_EGT_DELTA),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, apu_gen_is_used: bool, apu_bleed_is_used: bool) {
+        self.apu_gen.update(context, apu_gen_is_used);
+        self.bleed_air.update(context, apu_bleed_is_used);
+    }
+
+    fn egt_delta(&self) -> TemperatureInterval {
+        self.apu_gen.egt_delta() + self.bleed_air.egt_delta()
+    }
+
+    fn bleed_air_usage(&self) -> f64 {
+        self.bleed_air.usage()
+    }
+}
+
+/// The EGT increase caused by a consumer of the APU's power or bleed air.
+/// The increase builds up and decays at a constant rate.
+struct LinearUsageEgtDelta {
+    current: f64,
+    max: f64,
+}
+impl LinearUsageEgtDelta {
+    const DEGREES_CELSIUS_PER_SECOND: f64 = 6.;
+
+    fn new(max: f64) -> Self {
+        let randomisation = 0.95 + ((random_number() % 101) as f64 / 1000.);
+
+        Self {
+            current: 0.,
+            max: max * randomisation,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, is_used: bool) {
+        let target = if is_used { self.max } else { 0. };
+        let step = Self::DEGREES_CELSIUS_PER_SECOND * context.delta_as_secs_f64();
+
+        self.current = if self.current < target {
+            (self.current + step).min(target)
+        } else {
+            (self.current - step).max(target)
+        };
+    }
+
+    /// The extent to which the usage has built up, from 0 to 1.
+    fn usage(&self) -> f64 {
+        self.current / self.max
+    }
+
+    fn egt_delta(&self) -> TemperatureInterval {
+        TemperatureInterval::new::<temperature_interval::degree_celsius>(self.current)
+    }
+}
+
+/// PW980 APU Generator, rated at 120 kVA.
+pub struct Pw980ApuGenerator {
+    number: usize,
+    identifier: ElectricalElementIdentifier,
+    n: Ratio,
+    writer: ElectricalStateWriter,
+    output_frequency: Frequency,
+    output_potential: ElectricPotential,
+    load: Ratio,
+    is_emergency_shutdown: bool,
+    failure: Failure,
+}
+impl Pw980ApuGenerator {
+    pub(super) const APU_GEN_POWERED_N: f64 = 90.;
+    const RATED_APPARENT_POWER_VOLT_AMPERE: f64 = 120_000.;
+    const POWER_FACTOR: f64 = 0.8;
+
+    pub fn new(context: &mut InitContext, number: usize) -> Pw980ApuGenerator {
+        Pw980ApuGenerator {
+            number,
+            identifier: context.next_electrical_identifier(),
+            n: Ratio::new::<percent>(0.),
+            writer: ElectricalStateWriter::new(context, &format!("APU_GEN_{}", number)),
+            output_potential: ElectricPotential::new::<volt>(0.),
+            output_frequency: Frequency::new::<hertz>(0.),
+            load: Ratio::new::<percent>(0.),
+            is_emergency_shutdown: false,
+            failure: Failure::new(FailureType::ApuGenerator(number)),
+        }
+    }
+
+    fn calculate_potential(&self) -> ElectricPotential {
+        if self.n.get::<percent>() < 95. {
+            ElectricPotential::new::<volt>(108.)
+        } else {
+            ElectricPotential::new::<volt>(115.)
+        }
+    }
+
+    fn calculate_frequency(&self) -> Frequency {
+        // The generator is directly driven by the APU's gearbox.
+        Frequency::new::<hertz>(400. * self.n.get::<percent>().min(100.) / 100.)
+    }
+
+    fn should_provide_output(&self) -> bool {
+        !self.failure.is_active()
+            && !self.is_emergency_shutdown
+            && self.n.get::<percent>() >= Pw980ApuGenerator::APU_GEN_POWERED_N
+    }
+}
+impl ApuGenerator for Pw980ApuGenerator {
+    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool) {
+        self.n = n;
+        self.is_emergency_shutdown = is_emergency_shutdown;
+    }
+
+    fn output_within_normal_parameters(&self) -> bool {
+        self.should_provide_output() && self.potential_normal() && self.frequency_normal()
+    }
+}
+provide_potential!(Pw980ApuGenerator, (110.0..=120.0));
+provide_frequency!(Pw980ApuGenerator, (390.0..=410.0));
+provide_load!(Pw980ApuGenerator);
+impl ElectricalElement for Pw980ApuGenerator {
+    fn input_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn output_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn is_conductive(&self) -> bool {
+        true
+    }
+}
+impl ElectricitySource for Pw980ApuGenerator {
+    fn output_potential(&self) -> Potential {
+        if self.should_provide_output() {
+            Potential::new(
+                PotentialOrigin::ApuGenerator(self.number),
+                self.output_potential,
+            )
+            .with_frequency(self.output_frequency)
+        } else {
+            Potential::none()
+        }
+    }
+}
+impl SimulationElement for Pw980ApuGenerator {
+    fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        self.writer.write_alternating_with_load(self, writer);
+    }
+
+    fn process_power_consumption_report<T: PowerConsumptionReport>(
+        &mut self,
+        _: &UpdateContext,
+        report: &T,
+    ) {
+        self.output_potential = if self.should_provide_output() {
+            self.calculate_potential()
+        } else {
+            ElectricPotential::new::<volt>(0.)
+        };
+
+        self.output_frequency = if self.should_provide_output() {
+            self.calculate_frequency()
+        } else {
+            Frequency::new::<hertz>(0.)
+        };
+
+        let power_consumption = report
+            .total_consumption_of(PotentialOrigin::ApuGenerator(self.number))
+            .get::<watt>();
+        self.load = Ratio::new::<percent>(
+            (power_consumption * Self::POWER_FACTOR / Self::RATED_APPARENT_POWER_VOLT_AMPERE)
+                * 100.,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntest::assert_about_eq;
+    use uom::si::{frequency::hertz, length::foot, pressure::psi};
+
+    use crate::{apu::tests::pw980_test_bed_with, simulation::test::ReadByName};
+
+    use super::*;
+
+    #[test]
+    fn starts_within_expected_time() {
+        let mut test_bed = pw980_test_bed_with().starting_apu();
+        let mut start_time = Duration::from_secs(0);
+
+        while !test_bed.apu_is_available() {
+            test_bed = test_bed.run(Duration::from_millis(500));
+            start_time += Duration::from_millis(500);
+        }
+
+        assert!(Duration::from_secs(55) <= start_time && start_time <= Duration::from_secs(65));
+    }
+
+    #[test]
+    fn starting_egt_stays_below_warning_temperature() {
+        let mut test_bed = pw980_test_bed_with().starting_apu();
+        let mut max_egt = ThermodynamicTemperature::new::<degree_celsius>(0.);
+
+        while !test_bed.apu_is_available() {
+            test_bed = test_bed.run(Duration::from_millis(500));
+
+            let egt = test_bed.egt().normal_value().unwrap();
+            assert!(egt < test_bed.egt_caution_temperature().normal_value().unwrap());
+            if egt > max_egt {
+                max_egt = egt;
+            }
+        }
+
+        assert!(max_egt > ThermodynamicTemperature::new::<degree_celsius>(800.));
+    }
+
+    #[test]
+    fn starting_egt_warning_temperature_depends_on_altitude() {
+        let mut test_bed = pw980_test_bed_with()
+            .indicated_altitude(Length::new::<foot>(10_000.))
+            .starting_apu()
+            .run(Duration::from_secs(5));
+        assert_about_eq!(
+            test_bed
+                .egt_warning_temperature()
+                .normal_value()
+                .unwrap()
+                .get::<degree_celsius>(),
+            1_050.
+        );
+
+        let mut test_bed = pw980_test_bed_with()
+            .indicated_altitude(Length::new::<foot>(30_000.))
+            .starting_apu()
+            .run(Duration::from_secs(5));
+        assert_about_eq!(
+            test_bed
+                .egt_warning_temperature()
+                .normal_value()
+                .unwrap()
+                .get::<degree_celsius>(),
+            1_090.
+        );
+    }
+
+    #[test]
+    fn running_egt_warning_temperature_is_700() {
+        let mut test_bed = pw980_test_bed_with().running_apu();
+
+        assert_about_eq!(
+            test_bed
+                .egt_warning_temperature()
+                .normal_value()
+                .unwrap()
+                .get::<degree_celsius>(),
+            700.
+        );
+    }
+
+    #[test]
+    fn bleed_and_generator_usage_increase_egt() {
+        let mut unloaded = pw980_test_bed_with()
+            .apu_gen_not_used()
+            .running_apu_without_bleed_air()
+            .run(Duration::from_secs(120));
+        let mut loaded = pw980_test_bed_with()
+            .running_apu_with_bleed_air()
+            .run(Duration::from_secs(120));
+
+        let unloaded_egt = unloaded.egt().normal_value().unwrap();
+        let loaded_egt = loaded.egt().normal_value().unwrap();
+        assert!(loaded_egt.get::<degree_celsius>() - unloaded_egt.get::<degree_celsius>() > 120.);
+        assert!(loaded_egt < loaded.egt_caution_temperature().normal_value().unwrap());
+    }
+
+    #[test]
+    fn running_apu_supplies_bleed_air_at_50_psi() {
+        let mut test_bed = pw980_test_bed_with()
+            .running_apu_with_bleed_air()
+            .run(Duration::from_secs(1));
+
+        assert_about_eq!(
+            test_bed
+                .bleed_air_pressure()
+                .normal_value()
+                .unwrap()
+                .get::<psi>(),
+            50.
+        );
+    }
+
+    #[test]
+    fn stopping_apu_shuts_down_and_cools_down_to_ambient() {
+        let ambient = ThermodynamicTemperature::new::<degree_celsius>(10.);
+        let mut test_bed = pw980_test_bed_with()
+            .ambient_temperature(ambient)
+            .cooling_down_apu()
+            .master_on()
+            .run(Duration::from_secs(1_000));
+
+        assert!(test_bed.turbine_is_shutdown());
+        assert_eq!(test_bed.egt().value(), ambient);
+    }
+
+    #[test]
+    fn both_generators_provide_output_when_running() {
+        let mut test_bed = pw980_test_bed_with()
+            .running_apu()
+            .run(Duration::from_secs(1));
+
+        for number in 1..=2 {
+            let potential_normal: bool =
+                test_bed.read_by_name(&format!("ELEC_APU_GEN_{}_POTENTIAL_NORMAL", number));
+            let frequency_normal: bool =
+                test_bed.read_by_name(&format!("ELEC_APU_GEN_{}_FREQUENCY_NORMAL", number));
+
+            assert!(potential_normal);
+            assert!(frequency_normal);
+        }
+    }
+
+    #[test]
+    fn generator_has_no_output_below_90_percent_n() {
+        let mut test_bed = pw980_test_bed_with().starting_apu();
+
+        loop {
+            test_bed = test_bed.run(Duration::from_millis(500));
+
+            let n = test_bed.n().normal_value().unwrap().get::<percent>();
+            if n < 90. {
+                assert_about_eq!(test_bed.potential().get::<volt>(), 0.);
+            } else {
+                assert!(test_bed.potential().get::<volt>() > 0.);
+                assert!(test_bed.frequency().get::<hertz>() >= 360.);
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn when_load_below_120_kva_it_is_normal() {
+        let mut test_bed = pw980_test_bed_with()
+            .running_apu()
+            .power_demand(Power::new::<watt>(120_000. / 0.8))
+            .run(Duration::from_secs(1_000));
+
+        assert!(test_bed.load_within_normal_range());
+    }
+
+    #[test]
+    fn when_load_exceeds_120_kva_not_normal() {
+        let mut test_bed = pw980_test_bed_with()
+            .running_apu()
+            .power_demand(Power::new::<watt>((120_000. / 0.8) + 1.))
+            .run(Duration::from_secs(1_000));
+
+        assert!(!test_bed.load_within_normal_range());
+    }
+
+    #[test]
+    fn when_apu_emergency_shutdown_provides_no_output() {
+        let test_bed = pw980_test_bed_with()
+            .running_apu()
+            .and()
+            .released_apu_fire_pb()
+            .run(Duration::from_secs(1));
+
+        assert!(test_bed.generator_is_unpowered());
+    }
+}
@@ -0,0 +1,437 @@
+use std::{marker::PhantomData, time::Duration};
+
+use uom::si::{
+    f64::*, length::foot, mass_rate::kilogram_per_hour, pressure::psi, ratio::percent,
+    temperature_interval, thermodynamic_temperature::degree_celsius,
+};
+
+use crate::{
+    shared::{calculate_towards_target_temperature, random_number, ControllerSignal},
+    simulation::UpdateContext,
+};
+
+use super::{Turbine, TurbineSignal, TurbineState};
+
+/// The characteristics of a type of APU turbine. The turbine states and the transitions
+/// between them are shared by all APU types, while the behaviour of N, EGT and fuel flow
+/// within those states is provided by the type.
+pub trait TurbineType: 'static {
+    /// The EGT increase caused by the use of the APU's generators and bleed air.
+    type UsageEgtDelta: UsageEgtDelta;
+
+    const START_IGNITION_AFTER_SECONDS: f64;
+    const START_ENVELOPE_CEILING_FEET: f64;
+
+    const IDLE_FUEL_FLOW_KG_PER_HOUR: f64;
+    /// The minimum flow scheduled by the fuel control unit from ignition onwards.
+    const LIGHT_OFF_FUEL_FLOW_KG_PER_HOUR: f64;
+    const MAXIMUM_BLEED_AIR_FUEL_FLOW_KG_PER_HOUR: f64;
+    const FULL_GENERATOR_LOAD_FUEL_FLOW_KG_PER_HOUR: f64;
+
+    const STARTING_WARNING_EGT_BELOW_25000_FEET: f64;
+    const STARTING_WARNING_EGT_AT_OR_ABOVE_25000_FEET: f64;
+    const RUNNING_WARNING_EGT: f64;
+
+    /// The lowest base EGT of a running APU. Each start adds up to 10 degrees to it.
+    const RUNNING_BASE_EGT: f64;
+    const RUNNING_BASE_EGT_DEVIATION_DECREASE_PER_SECOND: f64;
+    const RUNNING_BLEED_AIR_PRESSURE_PSI: f64;
+
+    fn starting_n(seconds_since_ignition: f64) -> Ratio;
+    fn starting_egt(n: Ratio) -> ThermodynamicTemperature;
+    /// The N of a turbine which has been stopping for the given duration after running at 100%.
+    fn stopping_n(since: Duration) -> Ratio;
+    fn stopping_egt(
+        context: &UpdateContext,
+        egt: ThermodynamicTemperature,
+        egt_at_entry: ThermodynamicTemperature,
+        n_at_entry: Ratio,
+        n: Ratio,
+    ) -> ThermodynamicTemperature;
+}
+
+pub trait UsageEgtDelta {
+    fn new() -> Self;
+    fn update(&mut self, context: &UpdateContext, apu_gen_is_used: bool, apu_bleed_is_used: bool);
+    fn egt_delta(&self) -> TemperatureInterval;
+    /// The extent to which the bleed air usage has built up, from 0 to 1.
+    fn bleed_air_usage(&self) -> f64;
+}
+
+fn egt_warning_temperature<T: TurbineType>(
+    context: &UpdateContext,
+    turbine_state: TurbineState,
+) -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<degree_celsius>(match turbine_state {
+        TurbineState::Starting if context.indicated_altitude().get::<foot>() < 25_000. => {
+            T::STARTING_WARNING_EGT_BELOW_25000_FEET
+        }
+        TurbineState::Starting => T::STARTING_WARNING_EGT_AT_OR_ABOVE_25000_FEET,
+        TurbineState::Shutdown | TurbineState::Running | TurbineState::Stopping => {
+            T::RUNNING_WARNING_EGT
+        }
+    })
+}
+
+/// Above its start envelope, the APU fails to accelerate to self-sustaining speed and
+/// the start hangs. The further above the envelope, the lower the N at which it hangs.
+fn hung_start_n(context: &UpdateContext, start_envelope_ceiling: Length) -> Option<Ratio> {
+    const N_AT_START_ENVELOPE_CEILING: f64 = 50.;
+    const N_DECREASE_PER_THOUSAND_FEET_ABOVE_CEILING: f64 = 5.;
+    const MINIMUM_N: f64 = 20.;
+
+    let feet_above_ceiling = (context.pressure_altitude() - start_envelope_ceiling).get::<foot>();
+    (feet_above_ceiling > 0.).then(|| {
+        Ratio::new::<percent>(
+            (N_AT_START_ENVELOPE_CEILING
+                - N_DECREASE_PER_THOUSAND_FEET_ABOVE_CEILING * feet_above_ceiling / 1000.)
+                .max(MINIMUM_N),
+        )
+    })
+}
+
+fn calculate_towards_ambient_egt(
+    current_egt: ThermodynamicTemperature,
+    context: &UpdateContext,
+) -> ThermodynamicTemperature {
+    const APU_AMBIENT_COEFFICIENT: f64 = 1.;
+    calculate_towards_target_temperature(
+        current_egt,
+        context.ambient_temperature(),
+        APU_AMBIENT_COEFFICIENT,
+        context.delta(),
+    )
+}
+
+pub struct ShutdownTurbine<T: TurbineType> {
+    egt: ThermodynamicTemperature,
+    turbine_type: PhantomData<T>,
+}
+impl<T: TurbineType> ShutdownTurbine<T> {
+    pub fn new() -> Self {
+        Self::new_with_egt(ThermodynamicTemperature::new::<degree_celsius>(0.))
+    }
+
+    fn new_with_egt(egt: ThermodynamicTemperature) -> Self {
+        ShutdownTurbine {
+            egt,
+            turbine_type: PhantomData,
+        }
+    }
+}
+impl<T: TurbineType> Turbine for ShutdownTurbine<T> {
+    fn update(
+        mut self: Box<Self>,
+        context: &UpdateContext,
+        _: bool,
+        _: bool,
+        controller: &dyn ControllerSignal<TurbineSignal>,
+    ) -> Box<dyn Turbine> {
+        self.egt = calculate_towards_ambient_egt(self.egt, context);
+
+        match controller.signal() {
+            Some(TurbineSignal::StartOrContinue) => Box::new(Starting::<T>::new(self.egt)),
+            Some(TurbineSignal::Stop) | None => self,
+        }
+    }
+
+    fn n(&self) -> Ratio {
+        Ratio::new::<percent>(0.)
+    }
+
+    fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+
+    fn state(&self) -> TurbineState {
+        TurbineState::Shutdown
+    }
+
+    fn bleed_air_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(14.7)
+    }
+
+    fn egt_warning_temperature(&self, context: &UpdateContext) -> ThermodynamicTemperature {
+        egt_warning_temperature::<T>(context, self.state())
+    }
+
+    fn fuel_flow(&self, _: Ratio) -> MassRate {
+        MassRate::new::<kilogram_per_hour>(0.)
+    }
+}
+
+struct Starting<T: TurbineType> {
+    since: Duration,
+    n: Ratio,
+    egt: ThermodynamicTemperature,
+    ignore_calculated_egt: bool,
+    turbine_type: PhantomData<T>,
+}
+impl<T: TurbineType> Starting<T> {
+    fn new(egt: ThermodynamicTemperature) -> Self {
+        Starting {
+            since: Duration::from_secs(0),
+            n: Ratio::new::<percent>(0.),
+            egt,
+            ignore_calculated_egt: true,
+            turbine_type: PhantomData,
+        }
+    }
+
+    fn calculate_egt(&mut self, context: &UpdateContext) -> ThermodynamicTemperature {
+        let temperature = T::starting_egt(self.n);
+
+        // The above calculated EGT can be lower than the ambient temperature,
+        // or the current APU EGT (when cooling down). To prevent sudden changes
+        // in temperature, we ignore the calculated EGT until it exceeds the current
+        // EGT.
+        let towards_ambient_egt = calculate_towards_ambient_egt(self.egt, context);
+        if temperature > towards_ambient_egt {
+            self.ignore_calculated_egt = false;
+        }
+
+        if self.ignore_calculated_egt {
+            towards_ambient_egt
+        } else {
+            temperature
+        }
+    }
+
+    fn calculate_n(&self) -> Ratio {
+        let ignition_turned_on_secs = self.since.as_secs_f64() - T::START_IGNITION_AFTER_SECONDS;
+
+        if ignition_turned_on_secs > 0. {
+            T::starting_n(ignition_turned_on_secs)
+        } else {
+            Ratio::new::<percent>(0.)
+        }
+    }
+
+    fn has_ignited(&self) -> bool {
+        self.since.as_secs_f64() > T::START_IGNITION_AFTER_SECONDS
+    }
+}
+impl<T: TurbineType> Turbine for Starting<T> {
+    fn update(
+        mut self: Box<Self>,
+        context: &UpdateContext,
+        _: bool,
+        _: bool,
+        controller: &dyn ControllerSignal<TurbineSignal>,
+    ) -> Box<dyn Turbine> {
+        self.since += context.delta();
+        self.n = self.calculate_n();
+        if let Some(hung_start_n) =
+            hung_start_n(context, Length::new::<foot>(T::START_ENVELOPE_CEILING_FEET))
+        {
+            self.n = self.n.min(hung_start_n);
+        }
+        self.egt = self.calculate_egt(context);
+
+        match controller.signal() {
+            Some(TurbineSignal::Stop) | None => Box::new(Stopping::<T>::new(self.egt, self.n)),
+            Some(TurbineSignal::StartOrContinue)
+                if { (self.n.get::<percent>() - 100.).abs() < f64::EPSILON } =>
+            {
+                Box::new(Running::<T>::new(self.egt))
+            }
+            Some(TurbineSignal::StartOrContinue) => self,
+        }
+    }
+
+    fn n(&self) -> Ratio {
+        self.n
+    }
+
+    fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+
+    fn state(&self) -> TurbineState {
+        TurbineState::Starting
+    }
+
+    fn bleed_air_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(14.7)
+    }
+
+    fn egt_warning_temperature(&self, context: &UpdateContext) -> ThermodynamicTemperature {
+        egt_warning_temperature::<T>(context, self.state())
+    }
+
+    fn fuel_flow(&self, _: Ratio) -> MassRate {
+        // No fuel is injected while the starter motor cranks the turbine before ignition.
+        // From then on fuel is scheduled with N.
+        if self.has_ignited() {
+            MassRate::new::<kilogram_per_hour>(
+                (T::IDLE_FUEL_FLOW_KG_PER_HOUR * self.n.get::<percent>() / 100.)
+                    .max(T::LIGHT_OFF_FUEL_FLOW_KG_PER_HOUR),
+            )
+        } else {
+            MassRate::new::<kilogram_per_hour>(0.)
+        }
+    }
+}
+
+struct Running<T: TurbineType> {
+    egt: ThermodynamicTemperature,
+    base_egt: ThermodynamicTemperature,
+    base_egt_deviation: TemperatureInterval,
+    usage_egt_delta: T::UsageEgtDelta,
+}
+impl<T: TurbineType> Running<T> {
+    fn new(egt: ThermodynamicTemperature) -> Self {
+        let base_egt = T::RUNNING_BASE_EGT + ((random_number() % 11) as f64);
+        Running {
+            egt,
+            base_egt: ThermodynamicTemperature::new::<degree_celsius>(base_egt),
+            // This contains the deviation from the base EGT at the moment of entering the running state.
+            // The start ends above the base EGT, thus the deviation is always positive.
+            base_egt_deviation: TemperatureInterval::new::<temperature_interval::degree_celsius>(
+                (egt.get::<degree_celsius>() - base_egt).max(0.),
+            ),
+            usage_egt_delta: T::UsageEgtDelta::new(),
+        }
+    }
+
+    fn calculate_egt(
+        &mut self,
+        context: &UpdateContext,
+        apu_gen_is_used: bool,
+        apu_bleed_is_used: bool,
+    ) -> ThermodynamicTemperature {
+        // Reduce the deviation to slowly creep back to normal temperatures.
+        self.base_egt_deviation -= TemperatureInterval::new::<temperature_interval::degree_celsius>(
+            (context.delta_as_secs_f64() * T::RUNNING_BASE_EGT_DEVIATION_DECREASE_PER_SECOND).min(
+                self.base_egt_deviation
+                    .get::<temperature_interval::degree_celsius>(),
+            ),
+        );
+
+        self.usage_egt_delta
+            .update(context, apu_gen_is_used, apu_bleed_is_used);
+
+        self.base_egt + self.base_egt_deviation + self.usage_egt_delta.egt_delta()
+    }
+}
+impl<T: TurbineType> Turbine for Running<T> {
+    fn update(
+        mut self: Box<Self>,
+        context: &UpdateContext,
+        apu_bleed_is_used: bool,
+        apu_gen_is_used: bool,
+        controller: &dyn ControllerSignal<TurbineSignal>,
+    ) -> Box<dyn Turbine> {
+        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
+
+        match controller.signal() {
+            Some(TurbineSignal::StartOrContinue) => self,
+            Some(TurbineSignal::Stop) | None => {
+                Box::new(Stopping::<T>::new(self.egt, Ratio::new::<percent>(100.)))
+            }
+        }
+    }
+
+    fn n(&self) -> Ratio {
+        Ratio::new::<percent>(100.)
+    }
+
+    fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+
+    fn state(&self) -> TurbineState {
+        TurbineState::Running
+    }
+
+    fn bleed_air_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(T::RUNNING_BLEED_AIR_PRESSURE_PSI)
+    }
+
+    fn egt_warning_temperature(&self, context: &UpdateContext) -> ThermodynamicTemperature {
+        egt_warning_temperature::<T>(context, self.state())
+    }
+
+    fn fuel_flow(&self, generator_load: Ratio) -> MassRate {
+        MassRate::new::<kilogram_per_hour>(
+            T::IDLE_FUEL_FLOW_KG_PER_HOUR
+                + T::MAXIMUM_BLEED_AIR_FUEL_FLOW_KG_PER_HOUR
+                    * self.usage_egt_delta.bleed_air_usage()
+                + T::FULL_GENERATOR_LOAD_FUEL_FLOW_KG_PER_HOUR * generator_load.get::<percent>()
+                    / 100.,
+        )
+    }
+}
+
+struct Stopping<T: TurbineType> {
+    since: Duration,
+    egt_at_entry: ThermodynamicTemperature,
+    // When the APU start is unsuccessful the stopping state is entered with N < 100%.
+    // N runs down from the N at entry, rather than suddenly going from e.g. 30 to 100.
+    n_at_entry: Ratio,
+    n: Ratio,
+    egt: ThermodynamicTemperature,
+    turbine_type: PhantomData<T>,
+}
+impl<T: TurbineType> Stopping<T> {
+    fn new(egt: ThermodynamicTemperature, n: Ratio) -> Self {
+        Stopping {
+            since: Duration::from_secs(0),
+            egt_at_entry: egt,
+            n_at_entry: n,
+            n,
+            egt,
+            turbine_type: PhantomData,
+        }
+    }
+}
+impl<T: TurbineType> Turbine for Stopping<T> {
+    fn update(
+        mut self: Box<Self>,
+        context: &UpdateContext,
+        _: bool,
+        _: bool,
+        _: &dyn ControllerSignal<TurbineSignal>,
+    ) -> Box<dyn Turbine> {
+        self.since += context.delta();
+        self.n = T::stopping_n(self.since) * self.n_at_entry.get::<percent>() / 100.;
+        self.egt = T::stopping_egt(
+            context,
+            self.egt,
+            self.egt_at_entry,
+            self.n_at_entry,
+            self.n,
+        );
+
+        if self.n.get::<percent>() == 0. {
+            Box::new(ShutdownTurbine::<T>::new_with_egt(self.egt))
+        } else {
+            self
+        }
+    }
+
+    fn n(&self) -> Ratio {
+        self.n
+    }
+
+    fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+
+    fn state(&self) -> TurbineState {
+        TurbineState::Stopping
+    }
+
+    fn bleed_air_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(14.7)
+    }
+
+    fn egt_warning_temperature(&self, context: &UpdateContext) -> ThermodynamicTemperature {
+        egt_warning_temperature::<T>(context, self.state())
+    }
+
+    fn fuel_flow(&self, _: Ratio) -> MassRate {
+        // The fuel valve is closed as soon as the APU stops.
+        MassRate::new::<kilogram_per_hour>(0.)
+    }
+}
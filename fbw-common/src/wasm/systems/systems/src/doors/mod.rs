@@ -0,0 +1,487 @@
+use crate::{
+    shared::{CabinSimulation, EngineCorrectedN2},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
+        VariableMetadata, Write,
+    },
+};
+use uom::si::{f64::*, pressure::hectopascal, ratio::percent};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DoorType {
+    Passenger,
+    Service,
+    Emergency,
+    Cargo,
+}
+impl DoorType {
+    /// Passenger, service and emergency doors are fitted with an escape slide.
+    fn has_slide(&self) -> bool {
+        !matches!(self, DoorType::Cargo)
+    }
+}
+
+/// A fuselage door. A door moved by the simulator follows the position requested through its
+/// position variable, unless the differential pressure across it prevents it from being opened.
+/// The door is then held closed, such that the simulator can be asked to close it again. A door
+/// actuated by a system simulated here, such as a hydraulic cargo door, is given its position
+/// instead, and it is up to that system's controller to respect the opening inhibition.
+/// Doors fitted with an escape slide have a slide arming lever, set through [`Door::arm_slide`]
+/// and [`Door::disarm_slide`]; opening the door with the slide armed deploys the slide.
+pub struct Door {
+    position_id: Option<VariableIdentifier>,
+    slide_armed_id: VariableIdentifier,
+    slide_deployed_id: VariableIdentifier,
+    cabin_pressure_warning_id: VariableIdentifier,
+    opening_inhibited_id: VariableIdentifier,
+    held_closed_id: VariableIdentifier,
+
+    door_type: DoorType,
+
+    requested_position: Ratio,
+    position: Ratio,
+    slide_arming_lever_armed: bool,
+    slide_deployed: bool,
+    cabin_pressure_warning: bool,
+    opening_inhibited: bool,
+}
+impl Door {
+    /// Above this differential pressure the red CABIN PRESSURE light of a door with a disarmed
+    /// slide flashes while the engines are stopped.
+    const RESIDUAL_PRESSURE_WARNING_HPA: f64 = 2.5;
+
+    /// Above this differential pressure the door can't be lifted out of its stops.
+    const MAX_OPENING_DIFFERENTIAL_PRESSURE_HPA: f64 = 20.;
+
+    const ENGINE_STOPPED_MAX_N2_PERCENT: f64 = 5.;
+
+    /// The girt bar only pulls the slide out of its pack once the door has swung open this far.
+    const SLIDE_DEPLOYMENT_MIN_OPEN_PERCENT: f64 = 25.;
+
+    /// Creates a door which is moved by the simulator through the given position variable.
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        door_type: DoorType,
+        position_variable: &str,
+    ) -> Self {
+        let position_id = context.get_identifier_with_metadata(
+            position_variable.to_owned(),
+            VariableMetadata::new().written_externally(),
+        );
+
+        Self::new_with_position_id(context, id, door_type, Some(position_id))
+    }
+
+    /// Creates a door which is actuated by a system simulated here. Its position is given
+    /// through [`Door::update_actuated`].
+    pub fn new_actuated(context: &mut InitContext, id: &str, door_type: DoorType) -> Self {
+        Self::new_with_position_id(context, id, door_type, None)
+    }
+
+    fn new_with_position_id(
+        context: &mut InitContext,
+        id: &str,
+        door_type: DoorType,
+        position_id: Option<VariableIdentifier>,
+    ) -> Self {
+        Self {
+            position_id,
+            slide_armed_id: context.get_identifier(format!("DOOR_{}_SLIDE_ARMED", id)),
+            slide_deployed_id: context.get_identifier(format!("DOOR_{}_SLIDE_DEPLOYED", id)),
+            cabin_pressure_warning_id: context
+                .get_identifier(format!("DOOR_{}_CABIN_PRESSURE_WARNING", id)),
+            opening_inhibited_id: context.get_identifier(format!("DOOR_{}_OPENING_INHIBITED", id)),
+            held_closed_id: context.get_identifier(format!("DOOR_{}_HELD_CLOSED", id)),
+
+            door_type,
+
+            requested_position: Ratio::default(),
+            position: Ratio::default(),
+            slide_arming_lever_armed: false,
+            slide_deployed: false,
+            cabin_pressure_warning: false,
+            opening_inhibited: false,
+        }
+    }
+
+    /// Updates a door which is moved by the simulator.
+    pub fn update(&mut self, cabin: &impl CabinSimulation, engines: &[&impl EngineCorrectedN2]) {
+        self.update_opening_inhibition(cabin);
+
+        let position = if self.is_closed() && self.opening_inhibited {
+            Ratio::default()
+        } else {
+            self.requested_position
+        };
+
+        self.update_position(cabin, engines, position);
+    }
+
+    /// Updates a door which is actuated by a system simulated here, using the position of its
+    /// actuator. The position isn't held closed by the differential pressure.
+    pub fn update_actuated(
+        &mut self,
+        cabin: &impl CabinSimulation,
+        engines: &[&impl EngineCorrectedN2],
+        position: Ratio,
+    ) {
+        self.update_opening_inhibition(cabin);
+        self.update_position(cabin, engines, position);
+    }
+
+    fn update_opening_inhibition(&mut self, cabin: &impl CabinSimulation) {
+        self.opening_inhibited = cabin.cabin_pressure() - cabin.exterior_pressure()
+            > Pressure::new::<hectopascal>(Self::MAX_OPENING_DIFFERENTIAL_PRESSURE_HPA);
+    }
+
+    fn update_position(
+        &mut self,
+        cabin: &impl CabinSimulation,
+        engines: &[&impl EngineCorrectedN2],
+        position: Ratio,
+    ) {
+        let differential_pressure = cabin.cabin_pressure() - cabin.exterior_pressure();
+        self.position = position;
+
+        if self.door_type.has_slide() {
+            if self.slide_arming_lever_armed
+                && self.position >= Ratio::new::<percent>(Self::SLIDE_DEPLOYMENT_MIN_OPEN_PERCENT)
+            {
+                self.slide_deployed = true;
+            } else if !self.slide_arming_lever_armed && self.is_closed() {
+                self.slide_deployed = false;
+            }
+        }
+
+        let engines_stopped = engines.iter().all(|engine| {
+            engine.corrected_n2() < Ratio::new::<percent>(Self::ENGINE_STOPPED_MAX_N2_PERCENT)
+        });
+        self.cabin_pressure_warning = self.door_type.has_slide()
+            && !self.slide_arming_lever_armed
+            && engines_stopped
+            && differential_pressure
+                > Pressure::new::<hectopascal>(Self::RESIDUAL_PRESSURE_WARNING_HPA);
+    }
+
+    pub fn door_type(&self) -> DoorType {
+        self.door_type
+    }
+
+    /// The opening of the door, from 0 when closed to 1 when fully open.
+    pub fn open_ratio(&self) -> Ratio {
+        self.position
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.position <= Ratio::default()
+    }
+
+    pub fn is_slide_armed(&self) -> bool {
+        self.door_type.has_slide() && self.slide_arming_lever_armed && self.is_closed()
+    }
+
+    pub fn is_slide_deployed(&self) -> bool {
+        self.slide_deployed
+    }
+
+    pub fn has_cabin_pressure_warning(&self) -> bool {
+        self.cabin_pressure_warning
+    }
+
+    pub fn is_opening_inhibited(&self) -> bool {
+        self.opening_inhibited
+    }
+
+    /// Indicates the door is requested to open, but is held closed by the differential pressure.
+    pub fn is_held_closed(&self) -> bool {
+        self.is_closed() && self.requested_position > Ratio::default()
+    }
+
+    pub fn arm_slide(&mut self) {
+        self.slide_arming_lever_armed = true;
+    }
+
+    pub fn disarm_slide(&mut self) {
+        self.slide_arming_lever_armed = false;
+    }
+}
+impl SimulationElement for Door {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        if let Some(position_id) = &self.position_id {
+            self.requested_position = reader.read(position_id);
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        if self.door_type.has_slide() {
+            writer.write(&self.slide_armed_id, self.is_slide_armed());
+            writer.write(&self.slide_deployed_id, self.slide_deployed);
+            writer.write(&self.cabin_pressure_warning_id, self.cabin_pressure_warning);
+        }
+        writer.write(&self.opening_inhibited_id, self.opening_inhibited);
+        writer.write(&self.held_closed_id, self.is_held_closed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::Electricity;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, SimulationElementVisitor, UpdateContext};
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    struct TestCabin {
+        differential_pressure: Pressure,
+    }
+    impl CabinSimulation for TestCabin {
+        fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+            vec![ThermodynamicTemperature::new::<degree_celsius>(24.)]
+        }
+
+        fn cabin_pressure(&self) -> Pressure {
+            self.exterior_pressure() + self.differential_pressure
+        }
+    }
+
+    struct TestEngine {
+        corrected_n2: Ratio,
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.corrected_n2
+        }
+    }
+
+    struct TestAircraft {
+        door: Door,
+        actuated_door: Door,
+        actuated_door_position: Ratio,
+        cabin: TestCabin,
+        engine: TestEngine,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext, door_type: DoorType) -> Self {
+            Self {
+                door: Door::new(context, "L1", door_type, "TEST_DOOR_POSITION"),
+                actuated_door: Door::new_actuated(context, "CARGO", DoorType::Cargo),
+                actuated_door_position: Ratio::default(),
+                cabin: TestCabin {
+                    differential_pressure: Pressure::default(),
+                },
+                engine: TestEngine {
+                    corrected_n2: Ratio::default(),
+                },
+            }
+        }
+
+        fn set_differential_pressure(&mut self, differential_pressure_hpa: f64) {
+            self.cabin.differential_pressure =
+                Pressure::new::<hectopascal>(differential_pressure_hpa);
+        }
+
+        fn set_engine_running(&mut self) {
+            self.engine.corrected_n2 = Ratio::new::<percent>(60.);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(&mut self, _: &UpdateContext, _: &mut Electricity) {
+            self.door.update(&self.cabin, &[&self.engine]);
+            self.actuated_door.update_actuated(
+                &self.cabin,
+                &[&self.engine],
+                self.actuated_door_position,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.door.accept(visitor);
+            self.actuated_door.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(door_type: DoorType) -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(|context| TestAircraft::new(context, door_type))
+    }
+
+    fn request_position(test_bed: &mut SimulationTestBed<TestAircraft>, position_percent: f64) {
+        test_bed.write_by_name("TEST_DOOR_POSITION", position_percent);
+    }
+
+    fn arm_slide(test_bed: &mut SimulationTestBed<TestAircraft>, armed: bool) {
+        test_bed.command(|a| {
+            if armed {
+                a.door.arm_slide()
+            } else {
+                a.door.disarm_slide()
+            }
+        });
+    }
+
+    #[test]
+    fn door_follows_the_requested_position() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        request_position(&mut test_bed, 50.);
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.door.open_ratio()),
+            Ratio::new::<percent>(50.)
+        );
+    }
+
+    #[test]
+    fn slide_armed_indication_requires_armed_lever_and_closed_door() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        arm_slide(&mut test_bed, true);
+        test_bed.run();
+
+        let slide_armed: bool = test_bed.read_by_name("DOOR_L1_SLIDE_ARMED");
+        assert!(slide_armed);
+
+        arm_slide(&mut test_bed, false);
+        test_bed.run();
+
+        let slide_armed: bool = test_bed.read_by_name("DOOR_L1_SLIDE_ARMED");
+        assert!(!slide_armed);
+    }
+
+    #[test]
+    fn opening_an_armed_door_deploys_the_slide() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        arm_slide(&mut test_bed, true);
+        test_bed.run();
+
+        request_position(&mut test_bed, 100.);
+        test_bed.run();
+
+        let slide_deployed: bool = test_bed.read_by_name("DOOR_L1_SLIDE_DEPLOYED");
+        assert!(slide_deployed);
+    }
+
+    #[test]
+    fn slide_is_not_deployed_before_the_door_is_open_far_enough() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        arm_slide(&mut test_bed, true);
+        test_bed.run();
+
+        request_position(&mut test_bed, 10.);
+        test_bed.run();
+        arm_slide(&mut test_bed, false);
+        request_position(&mut test_bed, 100.);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.door.is_slide_deployed()));
+    }
+
+    #[test]
+    fn opening_a_disarmed_door_does_not_deploy_the_slide() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        request_position(&mut test_bed, 100.);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.door.is_slide_deployed()));
+    }
+
+    #[test]
+    fn cargo_doors_have_no_slide() {
+        let mut test_bed = test_bed(DoorType::Cargo);
+        arm_slide(&mut test_bed, true);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.door.is_slide_armed()));
+        assert!(!test_bed.contains_variable_with_name("DOOR_L1_SLIDE_ARMED"));
+    }
+
+    #[test]
+    fn residual_pressure_warning_with_disarmed_slide_and_engines_stopped() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        test_bed.command(|a| a.set_differential_pressure(5.));
+        test_bed.run();
+
+        let warning: bool = test_bed.read_by_name("DOOR_L1_CABIN_PRESSURE_WARNING");
+        assert!(warning);
+    }
+
+    #[test]
+    fn no_residual_pressure_warning_below_threshold() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        test_bed.command(|a| a.set_differential_pressure(2.));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.door.has_cabin_pressure_warning()));
+    }
+
+    #[test]
+    fn no_residual_pressure_warning_with_armed_slide() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        arm_slide(&mut test_bed, true);
+        test_bed.command(|a| a.set_differential_pressure(5.));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.door.has_cabin_pressure_warning()));
+    }
+
+    #[test]
+    fn no_residual_pressure_warning_with_engine_running() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        test_bed.command(|a| {
+            a.set_differential_pressure(5.);
+            a.set_engine_running();
+        });
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.door.has_cabin_pressure_warning()));
+    }
+
+    #[test]
+    fn opening_is_inhibited_by_differential_pressure() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        test_bed.command(|a| a.set_differential_pressure(100.));
+        request_position(&mut test_bed, 100.);
+        test_bed.run();
+
+        let inhibited: bool = test_bed.read_by_name("DOOR_L1_OPENING_INHIBITED");
+        assert!(inhibited);
+        assert!(test_bed.query(|a| a.door.is_closed()));
+        let held_closed: bool = test_bed.read_by_name("DOOR_L1_HELD_CLOSED");
+        assert!(held_closed);
+
+        test_bed.command(|a| a.set_differential_pressure(0.));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.door.is_closed()));
+        assert!(!test_bed.query(|a| a.door.is_held_closed()));
+    }
+
+    #[test]
+    fn closed_door_is_not_held_closed_without_an_opening_request() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        test_bed.command(|a| a.set_differential_pressure(100.));
+        test_bed.run();
+
+        let held_closed: bool = test_bed.read_by_name("DOOR_L1_HELD_CLOSED");
+        assert!(!held_closed);
+    }
+
+    #[test]
+    fn actuated_door_follows_its_actuator_despite_the_opening_inhibition() {
+        let mut test_bed = test_bed(DoorType::Passenger);
+        test_bed.command(|a| {
+            a.set_differential_pressure(100.);
+            a.actuated_door_position = Ratio::new::<percent>(30.);
+        });
+        test_bed.run();
+
+        let inhibited: bool = test_bed.read_by_name("DOOR_CARGO_OPENING_INHIBITED");
+        assert!(inhibited);
+        assert_eq!(
+            test_bed.query(|a| a.actuated_door.open_ratio()),
+            Ratio::new::<percent>(30.)
+        );
+    }
+}
@@ -17,7 +17,7 @@
 //!    load %, voltage, frequency and current.
 
 use super::ElectricalBusType;
-use crate::simulation::{InitContext, VariableIdentifier};
+use crate::simulation::{InitContext, VariableIdentifier, VariableMetadata};
 use crate::{
     shared::{random_number, ConsumePower, ElectricalBuses, FwcFlightPhase},
     simulation::{
@@ -25,8 +25,8 @@ use crate::{
     },
 };
 use num_traits::FromPrimitive;
-use std::time::Duration;
-use uom::si::{f64::*, power::watt};
+use std::{ops::RangeInclusive, time::Duration};
+use uom::si::{f64::*, frequency::hertz, power::watt, ratio::ratio};
 
 /// A generic consumer of power.
 pub struct PowerConsumer {
@@ -59,6 +59,79 @@ impl SimulationElement for PowerConsumer {
     }
 }
 
+/// A power consumer which responds to the frequency of the alternating current it
+/// is supplied with, such as an induction motor on a variable frequency network.
+/// The consumer only operates while the frequency is within its range. Its demand scales
+/// with the cube of the frequency relative to the nominal frequency, as is the case for
+/// fans and pumps whose speed follows the frequency.
+///
+/// # Source
+/// The speed of an induction motor follows its synchronous speed `120 * f / p`, which is
+/// proportional to the supply frequency `f` for a motor with `p` poles. The power absorbed
+/// by a fan or centrifugal pump grows with the cube of its speed according to the affinity
+/// laws, as given in the fans chapter of the ASHRAE Handbook, HVAC Systems and Equipment.
+pub struct FrequencyDependentPowerConsumer {
+    is_powered: bool,
+    frequency: Frequency,
+    nominal_demand: Power,
+    nominal_frequency: Frequency,
+    frequency_range: RangeInclusive<Frequency>,
+    powered_by_bus: ElectricalBusType,
+}
+impl FrequencyDependentPowerConsumer {
+    /// Create a power consumer which consumes power from the given bus type, as long
+    /// as the frequency of the bus is within the given range.
+    pub fn from(
+        bus_type: ElectricalBusType,
+        nominal_frequency: Frequency,
+        frequency_range: RangeInclusive<Frequency>,
+    ) -> Self {
+        Self {
+            is_powered: false,
+            frequency: Frequency::new::<hertz>(0.),
+            nominal_demand: Power::new::<watt>(0.),
+            nominal_frequency,
+            frequency_range,
+            powered_by_bus: bus_type,
+        }
+    }
+
+    /// Set the amount of power that is demanded by the consumer when powered
+    /// at the nominal frequency.
+    pub fn demand(&mut self, power: Power) {
+        self.nominal_demand = power;
+    }
+
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
+
+    /// The speed of the consumer relative to its speed at the nominal frequency.
+    pub fn speed_ratio(&self) -> f64 {
+        if self.is_powered {
+            (self.frequency / self.nominal_frequency).get::<ratio>()
+        } else {
+            0.
+        }
+    }
+}
+impl SimulationElement for FrequencyDependentPowerConsumer {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.frequency = buses.potential_of(self.powered_by_bus).frequency();
+        self.is_powered =
+            buses.is_powered(self.powered_by_bus) && self.frequency_range.contains(&self.frequency);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_powered {
+            consumption.consume_from_bus(
+                self.powered_by_bus,
+                self.nominal_demand * self.speed_ratio().powi(3),
+            );
+        }
+    }
+}
+
 /// A special type of power consumer which changes its consumption
 /// based on the phase of the flight.
 pub struct FlightPhasePowerConsumer {
@@ -72,7 +145,10 @@ pub struct FlightPhasePowerConsumer {
 impl FlightPhasePowerConsumer {
     pub fn new(context: &mut InitContext, bus_type: ElectricalBusType) -> Self {
         Self {
-            fwc_flight_phase_id: context.get_identifier("FWC_FLIGHT_PHASE".to_owned()),
+            fwc_flight_phase_id: context.get_identifier_with_metadata(
+                "FWC_FLIGHT_PHASE".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
 
             consumer: PowerConsumer::from(bus_type),
             base_demand: Default::default(),
@@ -351,4 +427,131 @@ mod tests {
             assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
         }
     }
+    #[cfg(test)]
+    mod frequency_dependent_power_consumer_tests {
+        use super::*;
+        use crate::{
+            electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+            simulation::{
+                test::{SimulationTestBed, TestBed},
+                Aircraft, InitContext,
+            },
+        };
+        use uom::si::electric_potential::volt;
+
+        struct FrequencyDependentPowerConsumerTestAircraft {
+            electricity_source: TestElectricitySource,
+            generator_consumption: Power,
+            consumer: FrequencyDependentPowerConsumer,
+            bus: ElectricalBus,
+        }
+        impl FrequencyDependentPowerConsumerTestAircraft {
+            fn new(context: &mut InitContext) -> Self {
+                let mut consumer = FrequencyDependentPowerConsumer::from(
+                    ElectricalBusType::AlternatingCurrent(1),
+                    Frequency::new::<hertz>(400.),
+                    Frequency::new::<hertz>(360.)..=Frequency::new::<hertz>(800.),
+                );
+                consumer.demand(Power::new::<watt>(1000.));
+
+                Self {
+                    electricity_source: TestElectricitySource::unpowered(
+                        context,
+                        PotentialOrigin::EngineGenerator(1),
+                    ),
+                    generator_consumption: Power::new::<watt>(0.),
+                    consumer,
+                    bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                }
+            }
+
+            fn power_with_frequency(&mut self, frequency: Frequency) {
+                self.electricity_source
+                    .power_with_potential(ElectricPotential::new::<volt>(115.));
+                self.electricity_source.set_frequency(frequency);
+            }
+        }
+        impl Aircraft for FrequencyDependentPowerConsumerTestAircraft {
+            fn update_before_power_distribution(
+                &mut self,
+                _: &UpdateContext,
+                electricity: &mut Electricity,
+            ) {
+                electricity.supplied_by(&self.electricity_source);
+                electricity.flow(&self.electricity_source, &self.bus);
+            }
+        }
+        impl SimulationElement for FrequencyDependentPowerConsumerTestAircraft {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.consumer.accept(visitor);
+
+                visitor.visit(self);
+            }
+
+            fn process_power_consumption_report<T: PowerConsumptionReport>(
+                &mut self,
+                _: &UpdateContext,
+                report: &T,
+            ) {
+                self.generator_consumption =
+                    report.total_consumption_of(PotentialOrigin::EngineGenerator(1));
+            }
+        }
+
+        fn test_bed_with_frequency(
+            hz: f64,
+        ) -> SimulationTestBed<FrequencyDependentPowerConsumerTestAircraft> {
+            let mut test_bed =
+                SimulationTestBed::new(FrequencyDependentPowerConsumerTestAircraft::new);
+            test_bed.command(|a| a.power_with_frequency(Frequency::new::<hertz>(hz)));
+            test_bed.run();
+
+            test_bed
+        }
+
+        #[test]
+        fn consumes_nominal_demand_at_nominal_frequency() {
+            let test_bed = test_bed_with_frequency(400.);
+
+            assert!(test_bed.query(|a| a.consumer.is_powered()));
+            assert!(
+                (test_bed.query(|a| a.generator_consumption.get::<watt>()) - 1000.).abs() < 0.001
+            );
+        }
+
+        #[test]
+        fn consumes_more_at_higher_frequency() {
+            let test_bed = test_bed_with_frequency(800.);
+
+            assert!(
+                (test_bed.query(|a| a.generator_consumption.get::<watt>()) - 8000.).abs() < 0.001
+            );
+            assert!((test_bed.query(|a| a.consumer.speed_ratio()) - 2.).abs() < f64::EPSILON);
+        }
+
+        #[test]
+        fn is_unpowered_below_its_frequency_range() {
+            let test_bed = test_bed_with_frequency(300.);
+
+            assert!(!test_bed.query(|a| a.consumer.is_powered()));
+            assert_eq!(
+                test_bed.query(|a| a.generator_consumption),
+                Power::new::<watt>(0.)
+            );
+        }
+
+        #[test]
+        fn is_unpowered_above_its_frequency_range() {
+            let test_bed = test_bed_with_frequency(850.);
+
+            assert!(!test_bed.query(|a| a.consumer.is_powered()));
+        }
+
+        #[test]
+        fn is_unpowered_by_direct_current() {
+            let test_bed = test_bed_with_frequency(0.);
+
+            assert!(!test_bed.query(|a| a.consumer.is_powered()));
+        }
+    }
 }
@@ -85,6 +85,7 @@ impl ElectricitySource for EmergencyGenerator {
     fn output_potential(&self) -> Potential {
         if self.should_provide_output() {
             Potential::new(PotentialOrigin::EmergencyGenerator, self.output_potential)
+                .with_frequency(self.output_frequency)
         } else {
             Potential::none()
         }
@@ -81,6 +81,7 @@ impl ElectricitySource for EngineGenerator {
                 PotentialOrigin::EngineGenerator(self.number),
                 self.output_potential,
             )
+            .with_frequency(self.output_frequency)
         } else {
             Potential::none()
         }
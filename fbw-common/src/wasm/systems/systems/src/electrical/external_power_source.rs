@@ -4,7 +4,7 @@ use crate::{
     shared::PowerConsumptionReport,
     simulation::{
         InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
-        VariableIdentifier,
+        VariableIdentifier, VariableMetadata,
     },
 };
 
@@ -26,8 +26,10 @@ pub struct ExternalPowerSource {
 impl ExternalPowerSource {
     pub fn new(context: &mut InitContext, id: u32) -> ExternalPowerSource {
         ExternalPowerSource {
-            external_power_available_id: context
-                .get_identifier(format!("EXTERNAL POWER AVAILABLE:{id}")),
+            external_power_available_id: context.get_identifier_with_metadata(
+                format!("EXTERNAL POWER AVAILABLE:{id}"),
+                VariableMetadata::new().written_externally(),
+            ),
             identifier: context.next_electrical_identifier(),
             writer: ElectricalStateWriter::new(context, "EXT_PWR"),
             is_connected: false,
@@ -66,6 +68,7 @@ impl ElectricitySource for ExternalPowerSource {
     fn output_potential(&self) -> Potential {
         if self.should_provide_output() {
             Potential::new(PotentialOrigin::External, self.output_potential)
+                .with_frequency(self.output_frequency)
         } else {
             Potential::none()
         }
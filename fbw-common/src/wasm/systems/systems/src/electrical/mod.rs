@@ -10,6 +10,7 @@ mod external_power_source;
 mod ram_air_turbine;
 mod static_inverter;
 mod transformer_rectifier;
+mod variable_frequency_generator;
 
 use std::{
     cell::{Ref, RefCell},
@@ -41,7 +42,10 @@ pub use external_power_source::ExternalPowerSource;
 use fxhash::{FxHashMap, FxHashSet};
 pub use static_inverter::StaticInverter;
 pub use transformer_rectifier::TransformerRectifier;
-use uom::si::{electric_potential::volt, f64::*, power::watt, velocity::knot};
+use uom::si::{electric_potential::volt, f64::*, frequency::hertz, power::watt, velocity::knot};
+pub use variable_frequency_generator::{
+    VariableFrequencyGenerator, VARIABLE_FREQUENCY_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+};
 
 pub use ram_air_turbine::{GeneratorControlUnit, RamAirTurbine};
 
@@ -691,6 +695,7 @@ pub struct Potential {
     origins: FxHashSet<PotentialOrigin>,
     elements: FxHashSet<ElectricalElementIdentifier>,
     raw: ElectricPotential,
+    frequency: Frequency,
 }
 impl Potential {
     pub fn new(origin: PotentialOrigin, raw: ElectricPotential) -> Self {
@@ -701,6 +706,7 @@ impl Potential {
             origins,
             elements: FxHashSet::default(),
             raw,
+            frequency: Frequency::new::<hertz>(0.),
         }
     }
 
@@ -709,13 +715,26 @@ impl Potential {
             origins: FxHashSet::default(),
             elements: FxHashSet::default(),
             raw: ElectricPotential::new::<volt>(0.),
+            frequency: Frequency::new::<hertz>(0.),
         }
     }
 
+    /// Sets the frequency of alternating current potential. Potential without
+    /// a frequency is considered direct current.
+    pub fn with_frequency(mut self, frequency: Frequency) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
     pub(super) fn raw(&self) -> ElectricPotential {
         self.raw
     }
 
+    /// The frequency of the potential, or 0 Hz for direct current.
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
     fn include(mut self, identifier: ElectricalElementIdentifier) -> Self {
         self.elements.insert(identifier);
         self
@@ -749,6 +768,7 @@ impl Potential {
             // two batteries providing potential. BAT1 at 27.05V and BAT2 at 27.1V.
             // If we would return the higher potential, BAT1 would start charging itself.
             self.raw = self.raw.min(other.raw);
+            self.frequency = self.frequency.max(other.frequency);
 
             self
         } else if self.raw > other.raw {
@@ -52,6 +52,7 @@ impl ElectricityTransformer for StaticInverter {
     fn transform(&self, input: Ref<Potential>) -> super::Potential {
         if input.is_powered() && input.raw().get::<volt>() >= 16. {
             Potential::new(PotentialOrigin::StaticInverter, self.output_potential)
+                .with_frequency(self.output_frequency)
         } else {
             Potential::none()
         }
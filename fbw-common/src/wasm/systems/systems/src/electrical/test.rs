@@ -3,12 +3,13 @@ use crate::shared::PotentialOrigin;
 use super::{ElectricalElement, ElectricalElementIdentifier, ElectricitySource, Potential};
 use crate::electrical::ElectricalElementIdentifierProvider;
 
-use uom::si::{electric_potential::volt, f64::*};
+use uom::si::{electric_potential::volt, f64::*, frequency::hertz};
 
 pub struct TestElectricitySource {
     identifier: ElectricalElementIdentifier,
     origin: PotentialOrigin,
     potential: ElectricPotential,
+    frequency: Frequency,
 }
 impl TestElectricitySource {
     pub fn unpowered(
@@ -19,6 +20,7 @@ impl TestElectricitySource {
             identifier: identifier_provider.next_electrical_identifier(),
             origin,
             potential: ElectricPotential::new::<volt>(0.),
+            frequency: Frequency::new::<hertz>(0.),
         }
     }
 
@@ -30,6 +32,7 @@ impl TestElectricitySource {
             identifier: identifier_provider.next_electrical_identifier(),
             origin,
             potential: ElectricPotential::new::<volt>(28.),
+            frequency: Frequency::new::<hertz>(0.),
         }
     }
 
@@ -48,6 +51,10 @@ impl TestElectricitySource {
     pub fn set_potential(&mut self, potential: ElectricPotential) {
         self.potential = potential;
     }
+
+    pub fn set_frequency(&mut self, frequency: Frequency) {
+        self.frequency = frequency;
+    }
 }
 impl ElectricalElement for TestElectricitySource {
     fn input_identifier(&self) -> ElectricalElementIdentifier {
@@ -65,7 +72,7 @@ impl ElectricalElement for TestElectricitySource {
 impl ElectricitySource for TestElectricitySource {
     fn output_potential(&self) -> Potential {
         if self.potential > ElectricPotential::new::<volt>(0.) {
-            Potential::new(self.origin, self.potential)
+            Potential::new(self.origin, self.potential).with_frequency(self.frequency)
         } else {
             Potential::none()
         }
@@ -139,6 +139,7 @@ mod transformer_rectifier_tests {
     use uom::si::power::watt;
 
     use super::*;
+    use crate::simulation::property::{PropertyTest, ScenarioGenerator};
     use crate::simulation::test::ReadByName;
     use crate::simulation::InitContext;
     use crate::{
@@ -151,6 +152,7 @@ mod transformer_rectifier_tests {
             Aircraft, SimulationElementVisitor, UpdateContext,
         },
     };
+    use std::time::Duration;
 
     struct TransformerRectifierTestBed {
         test_bed: SimulationTestBed<TestAircraft>,
@@ -412,4 +414,35 @@ mod transformer_rectifier_tests {
         assert!(test_bed.contains_variable_with_name("ELEC_TR_1_POTENTIAL"));
         assert!(test_bed.contains_variable_with_name("ELEC_TR_1_POTENTIAL_NORMAL"));
     }
+
+    #[test]
+    fn output_potential_is_normal_or_zero_with_random_failures() {
+        PropertyTest::new(
+            ScenarioGenerator::new(Duration::from_secs(30))
+                .with_failures(&[FailureType::TransformerRectifier(1)], 0.05),
+        )
+        .check(
+            || {
+                let mut test_bed =
+                    TransformerRectifierTestBed::with_powered_transformer_rectifier();
+                test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+                test_bed
+            },
+            |test_bed| {
+                let potential = test_bed.potential().get::<volt>();
+                let is_powered = test_bed.transformer_rectifier_is_powered();
+                if is_powered && (25. ..=31.).contains(&potential) && test_bed.potential_is_normal()
+                    || !is_powered && potential == 0.
+                {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "potential of {} V while {}",
+                        potential,
+                        if is_powered { "powered" } else { "unpowered" }
+                    ))
+                }
+            },
+        );
+    }
 }
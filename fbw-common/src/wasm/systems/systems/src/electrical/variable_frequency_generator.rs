@@ -0,0 +1,712 @@
+use std::time::Duration;
+
+use uom::si::{
+    electric_potential::volt, f64::*, frequency::hertz, power::watt, ratio::percent,
+    thermodynamic_temperature::degree_celsius,
+};
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        calculate_towards_target_temperature, EngineCorrectedN2, EngineFirePushButtons,
+        PowerConsumptionReport,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use super::{
+    ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
+    ElectricalStateWriter, ElectricitySource, EngineGeneratorPushButtons, Potential,
+    PotentialOrigin, ProvideFrequency, ProvideLoad, ProvidePotential,
+};
+
+pub const VARIABLE_FREQUENCY_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS: u64 = 500;
+
+/// A generator which is directly driven by the engine's accessory gearbox, without
+/// a constant speed drive in between. Its output frequency therefore follows the
+/// engine's N2, roughly from 360 Hz at idle to 800 Hz at full power.
+pub struct VariableFrequencyGenerator {
+    writer: ElectricalStateWriter,
+    number: usize,
+    identifier: ElectricalElementIdentifier,
+    drive: GeneratorDrive,
+    frequency_protection: FrequencyProtection,
+    output_frequency: Frequency,
+    output_potential: ElectricPotential,
+    load: Ratio,
+    failure: Failure,
+}
+impl VariableFrequencyGenerator {
+    const HERTZ_PER_PERCENT_N2: f64 = 8.;
+    const MINIMUM_NORMAL_FREQUENCY: f64 = 360.;
+    const MAXIMUM_NORMAL_FREQUENCY: f64 = 800.;
+
+    pub fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            writer: ElectricalStateWriter::new(context, &format!("ENG_GEN_{}", number)),
+            number,
+            identifier: context.next_electrical_identifier(),
+            drive: GeneratorDrive::new(context, number),
+            frequency_protection: FrequencyProtection::new(),
+            output_frequency: Frequency::new::<hertz>(0.),
+            output_potential: ElectricPotential::new::<volt>(0.),
+            load: Ratio::new::<percent>(0.),
+            failure: Failure::new(FailureType::Generator(number)),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine: &impl EngineCorrectedN2,
+        generator_buttons: &impl EngineGeneratorPushButtons,
+        fire_buttons: &impl EngineFirePushButtons,
+    ) {
+        self.drive.update(context, engine, generator_buttons);
+
+        let rotor_frequency = self.rotor_frequency(engine.corrected_n2());
+        self.frequency_protection.update(
+            context,
+            rotor_frequency,
+            generator_buttons.engine_gen_push_button_is_on(self.number)
+                && !fire_buttons.is_released(self.number),
+        );
+
+        // Unlike potential, frequency doesn't depend on the load. It is
+        // therefore known before power is distributed.
+        self.output_frequency = if self.should_provide_output() {
+            rotor_frequency
+        } else {
+            Frequency::new::<hertz>(0.)
+        };
+    }
+
+    /// Indicates if the provided electricity's potential and frequency
+    /// are within normal parameters. Use this to decide if the
+    /// generator line contactor should close.
+    pub fn output_within_normal_parameters(&self) -> bool {
+        self.should_provide_output() && self.frequency_normal() && self.potential_normal()
+    }
+
+    /// Indicates if the frequency protection tripped the generator due to
+    /// an over frequency. The generator remains tripped until its push button
+    /// is switched off and on again.
+    pub fn is_over_frequency_tripped(&self) -> bool {
+        self.frequency_protection.is_over_frequency_tripped()
+    }
+
+    fn rotor_frequency(&self, corrected_n2: Ratio) -> Frequency {
+        if self.drive.is_connected() {
+            Frequency::new::<hertz>(corrected_n2.get::<percent>() * Self::HERTZ_PER_PERCENT_N2)
+        } else {
+            Frequency::new::<hertz>(0.)
+        }
+    }
+
+    fn should_provide_output(&self) -> bool {
+        self.frequency_protection.provides_stable_power_output() && !self.failure.is_active()
+    }
+}
+impl ElectricitySource for VariableFrequencyGenerator {
+    fn output_potential(&self) -> Potential {
+        if self.should_provide_output() {
+            Potential::new(
+                PotentialOrigin::EngineGenerator(self.number),
+                self.output_potential,
+            )
+            .with_frequency(self.output_frequency)
+        } else {
+            Potential::none()
+        }
+    }
+}
+provide_potential!(VariableFrequencyGenerator, (110.0..=120.0));
+provide_frequency!(
+    VariableFrequencyGenerator,
+    (VariableFrequencyGenerator::MINIMUM_NORMAL_FREQUENCY
+        ..=VariableFrequencyGenerator::MAXIMUM_NORMAL_FREQUENCY)
+);
+provide_load!(VariableFrequencyGenerator);
+impl ElectricalElement for VariableFrequencyGenerator {
+    fn input_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn output_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn is_conductive(&self) -> bool {
+        true
+    }
+}
+impl SimulationElement for VariableFrequencyGenerator {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.drive.accept(visitor);
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn process_power_consumption_report<T: PowerConsumptionReport>(
+        &mut self,
+        _: &UpdateContext,
+        report: &T,
+    ) {
+        self.output_potential = if self.should_provide_output() {
+            ElectricPotential::new::<volt>(115.)
+        } else {
+            ElectricPotential::new::<volt>(0.)
+        };
+
+        let power_consumption = report
+            .total_consumption_of(PotentialOrigin::EngineGenerator(self.number))
+            .get::<watt>();
+        let power_factor_correction = 0.8;
+        let maximum_true_power = 150000.;
+        self.load = Ratio::new::<percent>(
+            (power_consumption * power_factor_correction / maximum_true_power) * 100.,
+        );
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        self.writer.write_alternating_with_load(self, writer);
+    }
+}
+
+/// The mechanical connection between the accessory gearbox and the generator.
+/// Once disconnected, the drive cannot be reconnected in flight.
+struct GeneratorDrive {
+    oil_outlet_temperature_id: VariableIdentifier,
+    oil_outlet_temperature: ThermodynamicTemperature,
+    is_connected_id: VariableIdentifier,
+    connected: bool,
+    number: usize,
+}
+impl GeneratorDrive {
+    fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            oil_outlet_temperature_id: context.get_identifier(format!(
+                "ELEC_ENG_GEN_{}_IDG_OIL_OUTLET_TEMPERATURE",
+                number
+            )),
+            oil_outlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            is_connected_id: context
+                .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_IS_CONNECTED", number)),
+            connected: true,
+            number,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine: &impl EngineCorrectedN2,
+        generator_buttons: &impl EngineGeneratorPushButtons,
+    ) {
+        if generator_buttons.idg_push_button_is_released(self.number) {
+            self.connected = false;
+        }
+
+        self.update_temperature(context, engine.corrected_n2());
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext, corrected_n2: Ratio) {
+        const HEATING_COEFFICIENT: f64 = 1.4;
+        const COOLING_COEFFICIENT: f64 = 0.4;
+
+        let target = if self.connected {
+            ThermodynamicTemperature::new::<degree_celsius>(
+                corrected_n2.get::<percent>() * 1.8
+                    + context.ambient_temperature().get::<degree_celsius>(),
+            )
+        } else {
+            context.ambient_temperature()
+        };
+
+        self.oil_outlet_temperature = calculate_towards_target_temperature(
+            self.oil_outlet_temperature,
+            target,
+            if self.oil_outlet_temperature < target {
+                HEATING_COEFFICIENT
+            } else {
+                COOLING_COEFFICIENT
+            },
+            context.delta(),
+        );
+    }
+}
+impl SimulationElement for GeneratorDrive {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.oil_outlet_temperature_id, self.oil_outlet_temperature);
+        writer.write(&self.is_connected_id, self.connected);
+    }
+}
+
+/// Protects the network against a generator running outside of its frequency limits.
+/// - Under frequency: the output is inhibited until the frequency recovers and has been
+///   stable for some time. This happens during every engine start and shutdown.
+/// - Over frequency: the generator is tripped. It remains tripped until its push button
+///   is switched off and on again.
+struct FrequencyProtection {
+    is_activated: bool,
+    over_frequency_tripped: bool,
+    is_under_frequency: bool,
+    stable_duration: Duration,
+}
+impl FrequencyProtection {
+    const UNDER_FREQUENCY_TRIP_HZ: f64 = 360.;
+    const UNDER_FREQUENCY_RESET_HZ: f64 = 370.;
+    const OVER_FREQUENCY_TRIP_HZ: f64 = 830.;
+
+    fn new() -> Self {
+        Self {
+            is_activated: true,
+            over_frequency_tripped: false,
+            is_under_frequency: false,
+            stable_duration: Duration::from_millis(
+                VARIABLE_FREQUENCY_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+            ),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, rotor_frequency: Frequency, is_activated: bool) {
+        if self.is_activated && !is_activated {
+            self.over_frequency_tripped = false;
+        }
+        self.is_activated = is_activated;
+
+        let hz = rotor_frequency.get::<hertz>();
+        if hz > Self::OVER_FREQUENCY_TRIP_HZ {
+            self.over_frequency_tripped = true;
+        }
+
+        if hz < Self::UNDER_FREQUENCY_TRIP_HZ {
+            self.is_under_frequency = true;
+        } else if hz >= Self::UNDER_FREQUENCY_RESET_HZ {
+            self.is_under_frequency = false;
+        }
+
+        self.stable_duration =
+            if self.is_activated && !self.over_frequency_tripped && !self.is_under_frequency {
+                (self.stable_duration + context.delta()).min(Duration::from_millis(
+                    VARIABLE_FREQUENCY_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+                ))
+            } else {
+                Duration::from_secs(0)
+            };
+    }
+
+    fn provides_stable_power_output(&self) -> bool {
+        self.stable_duration
+            >= Duration::from_millis(
+                VARIABLE_FREQUENCY_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+            )
+    }
+
+    fn is_over_frequency_tripped(&self) -> bool {
+        self.over_frequency_tripped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{
+            consumption::{FrequencyDependentPowerConsumer, PowerConsumer},
+            ElectricalBus, ElectricalBusType, Electricity,
+        },
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft,
+        },
+    };
+    use std::time::Duration;
+
+    struct TestEngine {
+        corrected_n2: Ratio,
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.corrected_n2
+        }
+    }
+
+    struct TestOverhead {
+        engine_gen_push_button_is_on: bool,
+        idg_push_button_is_released: bool,
+    }
+    impl EngineGeneratorPushButtons for TestOverhead {
+        fn engine_gen_push_button_is_on(&self, _: usize) -> bool {
+            self.engine_gen_push_button_is_on
+        }
+
+        fn idg_push_button_is_released(&self, _: usize) -> bool {
+            self.idg_push_button_is_released
+        }
+    }
+
+    struct TestFireOverhead {
+        engine_fire_push_button_is_released: bool,
+    }
+    impl EngineFirePushButtons for TestFireOverhead {
+        fn is_released(&self, _: usize) -> bool {
+            self.engine_fire_push_button_is_released
+        }
+    }
+
+    struct TestAircraft {
+        generator: VariableFrequencyGenerator,
+        bus: ElectricalBus,
+        corrected_n2: Ratio,
+        gen_push_button_on: bool,
+        idg_push_button_released: bool,
+        fire_push_button_released: bool,
+        consumer: PowerConsumer,
+        frequency_dependent_consumer: FrequencyDependentPowerConsumer,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let mut frequency_dependent_consumer = FrequencyDependentPowerConsumer::from(
+                ElectricalBusType::AlternatingCurrent(1),
+                Frequency::new::<hertz>(400.),
+                Frequency::new::<hertz>(360.)..=Frequency::new::<hertz>(800.),
+            );
+            frequency_dependent_consumer.demand(Power::new::<watt>(1000.));
+
+            Self {
+                generator: VariableFrequencyGenerator::new(context, 1),
+                bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                corrected_n2: Ratio::new::<percent>(0.),
+                gen_push_button_on: true,
+                idg_push_button_released: false,
+                fire_push_button_released: false,
+                consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
+                frequency_dependent_consumer,
+            }
+        }
+
+        fn set_corrected_n2(&mut self, corrected_n2: Ratio) {
+            self.corrected_n2 = corrected_n2;
+        }
+
+        fn set_gen_push_button_on(&mut self, on: bool) {
+            self.gen_push_button_on = on;
+        }
+
+        fn disconnect_idg(&mut self) {
+            self.idg_push_button_released = true;
+        }
+
+        fn release_fire_push_button(&mut self) {
+            self.fire_push_button_released = true;
+        }
+
+        fn power_demand(&mut self, power: Power) {
+            self.consumer.demand(power);
+        }
+
+        fn generator_is_powered(&self, electricity: &Electricity) -> bool {
+            electricity.is_powered(&self.generator)
+        }
+
+        fn bus_frequency(&self, electricity: &Electricity) -> Frequency {
+            electricity.output_of(&self.bus).frequency()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.generator.update(
+                context,
+                &TestEngine {
+                    corrected_n2: self.corrected_n2,
+                },
+                &TestOverhead {
+                    engine_gen_push_button_is_on: self.gen_push_button_on,
+                    idg_push_button_is_released: self.idg_push_button_released,
+                },
+                &TestFireOverhead {
+                    engine_fire_push_button_is_released: self.fire_push_button_released,
+                },
+            );
+            electricity.supplied_by(&self.generator);
+            electricity.flow(&self.generator, &self.bus);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.generator.accept(visitor);
+            self.consumer.accept(visitor);
+            self.frequency_dependent_consumer.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct VariableFrequencyGeneratorTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl VariableFrequencyGeneratorTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn with_n2(mut self, percent_n2: f64) -> Self {
+            self.command(|a| a.set_corrected_n2(Ratio::new::<percent>(percent_n2)));
+            self
+        }
+
+        fn running_for(mut self, duration: Duration) -> Self {
+            self.run_with_delta(duration);
+            self
+        }
+
+        fn running(self) -> Self {
+            self.running_for(Duration::from_secs(1))
+        }
+
+        fn gen_push_button_on(mut self, on: bool) -> Self {
+            self.command(|a| a.set_gen_push_button_on(on));
+            self
+        }
+
+        fn frequency(&mut self) -> Frequency {
+            self.read_by_name("ELEC_ENG_GEN_1_FREQUENCY")
+        }
+
+        fn frequency_is_normal(&mut self) -> bool {
+            self.read_by_name("ELEC_ENG_GEN_1_FREQUENCY_NORMAL")
+        }
+
+        fn potential(&mut self) -> ElectricPotential {
+            self.read_by_name("ELEC_ENG_GEN_1_POTENTIAL")
+        }
+
+        fn load(&mut self) -> Ratio {
+            self.read_by_name("ELEC_ENG_GEN_1_LOAD")
+        }
+
+        fn generator_is_powered(&self) -> bool {
+            self.query_elec(|a, elec| a.generator_is_powered(elec))
+        }
+
+        fn output_within_normal_parameters(&self) -> bool {
+            self.query(|a| a.generator.output_within_normal_parameters())
+        }
+
+        fn is_over_frequency_tripped(&self) -> bool {
+            self.query(|a| a.generator.is_over_frequency_tripped())
+        }
+
+        fn bus_frequency(&self) -> Frequency {
+            self.query_elec(|a, elec| a.bus_frequency(elec))
+        }
+
+        fn frequency_dependent_consumer_is_powered(&self) -> bool {
+            self.query(|a| a.frequency_dependent_consumer.is_powered())
+        }
+    }
+    impl TestBed for VariableFrequencyGeneratorTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> VariableFrequencyGeneratorTestBed {
+        VariableFrequencyGeneratorTestBed::new()
+    }
+
+    #[test]
+    fn when_engine_running_provides_output() {
+        let test_bed = test_bed().with_n2(70.).running();
+
+        assert!(test_bed.generator_is_powered());
+        assert!(test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn when_engine_shutdown_provides_no_output() {
+        let mut test_bed = test_bed().with_n2(0.).running();
+
+        assert!(!test_bed.generator_is_powered());
+        assert!(!test_bed.frequency_is_normal());
+    }
+
+    #[test]
+    fn frequency_follows_engine_speed() {
+        let mut test_bed = test_bed().with_n2(50.).running();
+        assert_eq!(test_bed.frequency(), Frequency::new::<hertz>(400.));
+
+        let mut test_bed = test_bed.with_n2(75.).running();
+        assert_eq!(test_bed.frequency(), Frequency::new::<hertz>(600.));
+
+        let mut test_bed = test_bed.with_n2(100.).running();
+        assert_eq!(test_bed.frequency(), Frequency::new::<hertz>(800.));
+        assert!(test_bed.frequency_is_normal());
+    }
+
+    #[test]
+    fn bus_potential_carries_the_generator_frequency() {
+        let test_bed = test_bed().with_n2(75.).running();
+
+        assert_eq!(test_bed.bus_frequency(), Frequency::new::<hertz>(600.));
+    }
+
+    #[test]
+    fn provides_115_volts_when_running() {
+        let mut test_bed = test_bed().with_n2(70.).running();
+
+        assert_eq!(test_bed.potential(), ElectricPotential::new::<volt>(115.));
+    }
+
+    #[test]
+    fn output_requires_stabilization_time() {
+        let test_bed =
+            test_bed()
+                .with_n2(0.)
+                .running()
+                .with_n2(70.)
+                .running_for(Duration::from_millis(
+                    VARIABLE_FREQUENCY_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS - 1,
+                ));
+        assert!(!test_bed.generator_is_powered());
+
+        let test_bed = test_bed.running_for(Duration::from_millis(1));
+        assert!(test_bed.generator_is_powered());
+    }
+
+    #[test]
+    fn under_frequency_inhibits_output() {
+        let test_bed = test_bed().with_n2(70.).running();
+        assert!(test_bed.generator_is_powered());
+
+        let test_bed = test_bed.with_n2(44.).running();
+        assert!(!test_bed.generator_is_powered());
+        assert!(!test_bed.is_over_frequency_tripped());
+    }
+
+    #[test]
+    fn under_frequency_protection_has_hysteresis() {
+        let test_bed = test_bed().with_n2(44.).running();
+        assert!(!test_bed.generator_is_powered());
+
+        let test_bed = test_bed.with_n2(45.5).running();
+        assert!(!test_bed.generator_is_powered());
+
+        let test_bed = test_bed.with_n2(46.5).running();
+        assert!(test_bed.generator_is_powered());
+    }
+
+    #[test]
+    fn over_frequency_trips_the_generator() {
+        let test_bed = test_bed().with_n2(70.).running();
+        assert!(test_bed.generator_is_powered());
+
+        let test_bed = test_bed.with_n2(105.).running();
+        assert!(!test_bed.generator_is_powered());
+        assert!(test_bed.is_over_frequency_tripped());
+    }
+
+    #[test]
+    fn over_frequency_trip_remains_when_frequency_returns_to_normal() {
+        let test_bed = test_bed().with_n2(105.).running().with_n2(70.).running();
+
+        assert!(!test_bed.generator_is_powered());
+        assert!(test_bed.is_over_frequency_tripped());
+    }
+
+    #[test]
+    fn over_frequency_trip_is_reset_by_cycling_the_gen_push_button() {
+        let test_bed = test_bed()
+            .with_n2(105.)
+            .running()
+            .with_n2(70.)
+            .running()
+            .gen_push_button_on(false)
+            .running()
+            .gen_push_button_on(true)
+            .running();
+
+        assert!(test_bed.generator_is_powered());
+        assert!(!test_bed.is_over_frequency_tripped());
+    }
+
+    #[test]
+    fn when_gen_push_button_off_provides_no_output() {
+        let test_bed = test_bed().with_n2(70.).gen_push_button_on(false).running();
+
+        assert!(!test_bed.generator_is_powered());
+    }
+
+    #[test]
+    fn when_drive_disconnected_provides_no_output() {
+        let mut test_bed = test_bed().with_n2(70.).running();
+        test_bed.command(|a| a.disconnect_idg());
+        let test_bed = test_bed.running();
+
+        assert!(!test_bed.generator_is_powered());
+    }
+
+    #[test]
+    fn when_fire_push_button_released_provides_no_output() {
+        let mut test_bed = test_bed().with_n2(70.).running();
+        test_bed.command(|a| a.release_fire_push_button());
+        let test_bed = test_bed.running();
+
+        assert!(!test_bed.generator_is_powered());
+    }
+
+    #[test]
+    fn when_failed_provides_no_output() {
+        let mut test_bed = test_bed().with_n2(70.).running();
+        test_bed.fail(FailureType::Generator(1));
+        let test_bed = test_bed.running();
+
+        assert!(!test_bed.generator_is_powered());
+    }
+
+    #[test]
+    fn load_is_relative_to_maximum_power() {
+        let mut test_bed = test_bed().with_n2(70.);
+        test_bed.command(|a| a.power_demand(Power::new::<watt>(75000.)));
+        let mut test_bed = test_bed.running();
+
+        // The frequency dependent consumer draws more than its nominal demand at 560 Hz.
+        assert!(test_bed.load() > Ratio::new::<percent>(40.));
+        assert!(test_bed.load() < Ratio::new::<percent>(42.));
+    }
+
+    #[test]
+    fn frequency_dependent_consumer_is_powered_within_its_frequency_range() {
+        let test_bed = test_bed().with_n2(70.).running();
+
+        assert!(test_bed.frequency_dependent_consumer_is_powered());
+    }
+
+    #[test]
+    fn frequency_dependent_consumer_is_unpowered_without_power() {
+        let test_bed = test_bed().with_n2(0.).running();
+
+        assert!(!test_bed.frequency_dependent_consumer_is_powered());
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     shared::update_iterator::MaxStepLoop,
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
     },
 };
 
@@ -42,7 +42,10 @@ impl EngineFlexPhysics {
             output_gain_id: context.get_identifier("ENGINE_WOBBLE_DEV_OUT_GAIN".to_owned()),
             lateral_damping_id: context.get_identifier("ENGINE_WOBBLE_DEV_XDAMP".to_owned()),
             vertical_damping_id: context.get_identifier("ENGINE_WOBBLE_DEV_YDAMP".to_owned()),
-            dev_mode_enable_id: context.get_identifier("ENGINE_WOBBLE_DEV_ENABLE".to_owned()),
+            dev_mode_enable_id: context.get_identifier_with_metadata(
+                "ENGINE_WOBBLE_DEV_ENABLE".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
 
             wobble_physics: WobblePhysics::new(
                 GravityEffect::NoGravity,
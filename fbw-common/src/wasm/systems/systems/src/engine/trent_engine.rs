@@ -2,7 +2,7 @@ use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ra
 
 use crate::{
     shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
-    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
+    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext, VariableMetadata},
 };
 
 use super::Engine;
@@ -43,15 +43,30 @@ impl TrentEngine {
 
     pub fn new(context: &mut InitContext, number: usize) -> TrentEngine {
         TrentEngine {
-            thrust_id: context.get_identifier(format!("TURB ENG JET THRUST:{}", number)),
-
-            corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
+            thrust_id: context.get_identifier_with_metadata(
+                format!("TURB ENG JET THRUST:{}", number),
+                VariableMetadata::new().written_externally(),
+            ),
+
+            corrected_n1_id: context.get_identifier_with_metadata(
+                format!("TURB ENG CORRECTED N1:{}", number),
+                VariableMetadata::new().written_externally(),
+            ),
             corrected_n1: Ratio::new::<percent>(0.),
-            corrected_n2_id: context.get_identifier(format!("TURB ENG CORRECTED N2:{}", number)),
+            corrected_n2_id: context.get_identifier_with_metadata(
+                format!("TURB ENG CORRECTED N2:{}", number),
+                VariableMetadata::new().written_externally(),
+            ),
             corrected_n2: Ratio::new::<percent>(0.),
-            uncorrected_n2_id: context.get_identifier(format!("ENGINE_N2:{}", number)),
+            uncorrected_n2_id: context.get_identifier_with_metadata(
+                format!("ENGINE_N2:{}", number),
+                VariableMetadata::new().written_externally(),
+            ),
             uncorrected_n2: Ratio::new::<percent>(0.),
-            uncorrected_n3_id: context.get_identifier(format!("ENGINE_N3:{}", number)),
+            uncorrected_n3_id: context.get_identifier_with_metadata(
+                format!("ENGINE_N3:{}", number),
+                VariableMetadata::new().written_externally(),
+            ),
             uncorrected_n3: Ratio::new::<percent>(0.),
 
             hydraulic_pump_output_speed: AngularVelocity::new::<revolution_per_minute>(0.),
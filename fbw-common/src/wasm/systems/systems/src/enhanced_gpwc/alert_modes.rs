@@ -0,0 +1,675 @@
+use crate::{
+    shared::{arinc429::Arinc429Word, low_pass_filter::LowPassFilter, DelayedTrueLogicGate},
+    simulation::{Read, Reader, UpdateContext, Write, Writer},
+};
+use std::time::Duration;
+use uom::si::{
+    angle::degree,
+    f64::*,
+    length::foot,
+    velocity::{foot_per_minute, knot},
+};
+
+/// The aural alert which is currently announced. When several alerts are active at the
+/// same time, only the one with the highest priority is announced.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum AuralAlert {
+    None = 0,
+    Windshear = 1,
+    PullUp = 2,
+    Terrain = 3,
+    TooLowTerrain = 4,
+    TooLowGear = 5,
+    TooLowFlaps = 6,
+    SinkRate = 7,
+    DontSink = 8,
+    HardGlideslope = 9,
+    Glideslope = 10,
+}
+
+read_write_enum!(AuralAlert);
+
+impl From<f64> for AuralAlert {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            1 => AuralAlert::Windshear,
+            2 => AuralAlert::PullUp,
+            3 => AuralAlert::Terrain,
+            4 => AuralAlert::TooLowTerrain,
+            5 => AuralAlert::TooLowGear,
+            6 => AuralAlert::TooLowFlaps,
+            7 => AuralAlert::SinkRate,
+            8 => AuralAlert::DontSink,
+            9 => AuralAlert::HardGlideslope,
+            10 => AuralAlert::Glideslope,
+            _ => AuralAlert::None,
+        }
+    }
+}
+
+/// The data received by the EGPWC from which the alert modes are computed.
+pub(super) struct AlertModeInputs {
+    pub(super) radio_altitude: Arinc429Word<Length>,
+    pub(super) altitude: Arinc429Word<Length>,
+    pub(super) vertical_speed: Arinc429Word<Velocity>,
+    pub(super) computed_airspeed: Arinc429Word<Velocity>,
+    pub(super) gear_is_down: bool,
+    pub(super) flaps_in_landing_configuration: bool,
+    pub(super) glideslope_deviation: Option<Angle>,
+    pub(super) system_is_off: bool,
+    pub(super) glideslope_mode_is_off: bool,
+    pub(super) flap_mode_is_off: bool,
+    pub(super) glideslope_cancel_pressed: bool,
+}
+
+/// The basic GPWS modes 1 to 5, the altitude callouts (mode 6) and the reactive
+/// windshear detection (mode 7).
+pub(super) struct AlertModes {
+    takeoff_mode: bool,
+    was_in_landing_configuration: bool,
+
+    mode_1: ExcessiveSinkRate,
+    mode_2: ExcessiveTerrainClosureRate,
+    mode_3: AltitudeLossAfterTakeoff,
+    mode_4: UnsafeTerrainClearance,
+    mode_5: GlideslopeDeviation,
+    mode_6: AltitudeCallouts,
+    mode_7: Windshear,
+}
+impl AlertModes {
+    const MINIMUM_RADIO_ALTITUDE_FOOT: f64 = 10.;
+    const MAXIMUM_RADIO_ALTITUDE_FOOT: f64 = 2450.;
+    const TAKEOFF_MODE_END_RADIO_ALTITUDE_FOOT: f64 = 1500.;
+
+    pub(super) fn new() -> Self {
+        Self {
+            takeoff_mode: false,
+            was_in_landing_configuration: false,
+
+            mode_1: ExcessiveSinkRate::default(),
+            mode_2: ExcessiveTerrainClosureRate::new(),
+            mode_3: AltitudeLossAfterTakeoff::default(),
+            mode_4: UnsafeTerrainClearance::default(),
+            mode_5: GlideslopeDeviation::default(),
+            mode_6: AltitudeCallouts::default(),
+            mode_7: Windshear::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, context: &UpdateContext, inputs: &AlertModeInputs) {
+        let radio_altitude = inputs.radio_altitude.normal_value();
+
+        self.update_takeoff_mode(radio_altitude, inputs);
+        self.mode_5
+            .update_cancellation(radio_altitude, inputs.glideslope_cancel_pressed);
+        self.mode_6.update(radio_altitude);
+        self.mode_7.update(
+            context,
+            radio_altitude,
+            inputs.computed_airspeed.normal_value(),
+        );
+
+        match radio_altitude {
+            Some(radio_altitude)
+                if !inputs.system_is_off
+                    && (Self::MINIMUM_RADIO_ALTITUDE_FOOT..=Self::MAXIMUM_RADIO_ALTITUDE_FOOT)
+                        .contains(&radio_altitude.get::<foot>()) =>
+            {
+                let computed_airspeed = inputs.computed_airspeed.normal_value().unwrap_or_default();
+
+                self.mode_1
+                    .update(radio_altitude, inputs.vertical_speed.normal_value());
+                self.mode_2.update(
+                    context,
+                    radio_altitude,
+                    computed_airspeed,
+                    inputs.altitude.normal_value(),
+                    inputs.gear_is_down,
+                    inputs.flaps_in_landing_configuration,
+                );
+                self.mode_3.update(
+                    radio_altitude,
+                    inputs.altitude.normal_value(),
+                    self.takeoff_mode,
+                );
+                self.mode_4.update(
+                    radio_altitude,
+                    computed_airspeed,
+                    inputs.gear_is_down,
+                    inputs.flaps_in_landing_configuration,
+                    inputs.flap_mode_is_off,
+                    self.takeoff_mode,
+                );
+                self.mode_5.update(
+                    radio_altitude,
+                    inputs.glideslope_deviation,
+                    inputs.gear_is_down,
+                    inputs.glideslope_mode_is_off,
+                );
+            }
+            _ => {
+                self.mode_1 = ExcessiveSinkRate::default();
+                self.mode_2 = ExcessiveTerrainClosureRate::new();
+                self.mode_3 = AltitudeLossAfterTakeoff::default();
+                self.mode_4.reset_alerts();
+                self.mode_5.reset_alerts();
+            }
+        }
+    }
+
+    /// The takeoff mode is set on ground and when a go-around is flown, i.e. when the
+    /// aircraft leaves the landing configuration at low height. It ends at 1500 ft RA.
+    fn update_takeoff_mode(&mut self, radio_altitude: Option<Length>, inputs: &AlertModeInputs) {
+        let is_in_landing_configuration =
+            inputs.gear_is_down && inputs.flaps_in_landing_configuration;
+
+        if let Some(radio_altitude) = radio_altitude {
+            let radio_altitude = radio_altitude.get::<foot>();
+            if radio_altitude < Self::MINIMUM_RADIO_ALTITUDE_FOOT {
+                self.takeoff_mode = true;
+            } else if radio_altitude > Self::TAKEOFF_MODE_END_RADIO_ALTITUDE_FOOT {
+                self.takeoff_mode = false;
+            } else if self.was_in_landing_configuration && !is_in_landing_configuration {
+                self.takeoff_mode = true;
+            }
+        }
+
+        self.was_in_landing_configuration = is_in_landing_configuration;
+    }
+
+    pub(super) fn sink_rate(&self) -> bool {
+        self.mode_1.sink_rate
+    }
+
+    pub(super) fn sink_rate_pull_up(&self) -> bool {
+        self.mode_1.pull_up
+    }
+
+    pub(super) fn terrain(&self) -> bool {
+        self.mode_2.terrain
+    }
+
+    pub(super) fn terrain_pull_up(&self) -> bool {
+        self.mode_2.pull_up
+    }
+
+    pub(super) fn dont_sink(&self) -> bool {
+        self.mode_3.dont_sink
+    }
+
+    pub(super) fn too_low_gear(&self) -> bool {
+        self.mode_4.too_low_gear
+    }
+
+    pub(super) fn too_low_flaps(&self) -> bool {
+        self.mode_4.too_low_flaps
+    }
+
+    pub(super) fn too_low_terrain(&self) -> bool {
+        self.mode_4.too_low_terrain
+    }
+
+    pub(super) fn glideslope(&self) -> bool {
+        self.mode_5.glideslope
+    }
+
+    pub(super) fn hard_glideslope(&self) -> bool {
+        self.mode_5.hard_glideslope
+    }
+
+    pub(super) fn glideslope_is_cancelled(&self) -> bool {
+        self.mode_5.is_cancelled
+    }
+
+    pub(super) fn windshear(&self) -> bool {
+        self.mode_7.windshear
+    }
+
+    pub(super) fn altitude_callout(&self) -> Option<u32> {
+        self.mode_6.callout
+    }
+
+    /// The GPWS light illuminates for the modes 1 to 4.
+    pub(super) fn gpws_alert(&self) -> bool {
+        self.sink_rate()
+            || self.sink_rate_pull_up()
+            || self.terrain()
+            || self.terrain_pull_up()
+            || self.dont_sink()
+            || self.too_low_gear()
+            || self.too_low_flaps()
+            || self.too_low_terrain()
+    }
+
+    /// The G/S light illuminates for mode 5.
+    pub(super) fn glideslope_alert(&self) -> bool {
+        self.glideslope() || self.hard_glideslope()
+    }
+
+    pub(super) fn aural_alert(&self) -> AuralAlert {
+        if self.windshear() {
+            AuralAlert::Windshear
+        } else if self.sink_rate_pull_up() || self.terrain_pull_up() {
+            AuralAlert::PullUp
+        } else if self.terrain() {
+            AuralAlert::Terrain
+        } else if self.too_low_terrain() {
+            AuralAlert::TooLowTerrain
+        } else if self.too_low_gear() {
+            AuralAlert::TooLowGear
+        } else if self.too_low_flaps() {
+            AuralAlert::TooLowFlaps
+        } else if self.sink_rate() {
+            AuralAlert::SinkRate
+        } else if self.dont_sink() {
+            AuralAlert::DontSink
+        } else if self.hard_glideslope() {
+            AuralAlert::HardGlideslope
+        } else if self.glideslope() {
+            AuralAlert::Glideslope
+        } else {
+            AuralAlert::None
+        }
+    }
+}
+
+/// Mode 1 alerts when the sink rate is too high for the height above terrain.
+#[derive(Default)]
+struct ExcessiveSinkRate {
+    sink_rate: bool,
+    pull_up: bool,
+}
+impl ExcessiveSinkRate {
+    const MINIMUM_SINK_RATE_FEET_PER_MINUTE: f64 = 1000.;
+
+    fn update(&mut self, radio_altitude: Length, vertical_speed: Option<Velocity>) {
+        let sink_rate = -vertical_speed.unwrap_or_default().get::<foot_per_minute>();
+        let radio_altitude = radio_altitude.get::<foot>();
+
+        if sink_rate <= Self::MINIMUM_SINK_RATE_FEET_PER_MINUTE {
+            self.sink_rate = false;
+            self.pull_up = false;
+            return;
+        }
+
+        let sink_rate_boundary = 0.61 * sink_rate - 600.;
+        let pull_up_boundary = if sink_rate < 1700. {
+            1.3 * sink_rate - 1940.
+        } else {
+            0.4 * sink_rate - 410.
+        };
+
+        self.pull_up = radio_altitude <= pull_up_boundary;
+        self.sink_rate = !self.pull_up && radio_altitude <= sink_rate_boundary;
+    }
+}
+
+/// Mode 2 alerts when the terrain rises too fast below the aircraft. The alert continues
+/// after leaving the envelope until the aircraft gained 300 ft of barometric altitude.
+struct ExcessiveTerrainClosureRate {
+    previous_radio_altitude: Option<Length>,
+    closure_rate: LowPassFilter<Velocity>,
+    is_in_envelope: DelayedTrueLogicGate,
+    pull_up_delay: DelayedTrueLogicGate,
+    altitude_at_envelope_exit: Option<Length>,
+
+    terrain: bool,
+    pull_up: bool,
+}
+impl ExcessiveTerrainClosureRate {
+    const CLOSURE_RATE_FILTER_TIME_CONSTANT: Duration = Duration::from_millis(500);
+    const ENVELOPE_CONFIRMATION_TIME: Duration = Duration::from_millis(500);
+    // After two "TERRAIN" aural alerts, the alert changes to "PULL UP".
+    const PULL_UP_DELAY: Duration = Duration::from_millis(1600);
+    const ALTITUDE_GAIN_TO_END_ALERT_FOOT: f64 = 300.;
+
+    fn new() -> Self {
+        Self {
+            previous_radio_altitude: None,
+            closure_rate: LowPassFilter::new(Self::CLOSURE_RATE_FILTER_TIME_CONSTANT),
+            is_in_envelope: DelayedTrueLogicGate::new(Self::ENVELOPE_CONFIRMATION_TIME),
+            pull_up_delay: DelayedTrueLogicGate::new(Self::PULL_UP_DELAY),
+            altitude_at_envelope_exit: None,
+
+            terrain: false,
+            pull_up: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Length,
+        computed_airspeed: Velocity,
+        altitude: Option<Length>,
+        gear_is_down: bool,
+        flaps_in_landing_configuration: bool,
+    ) {
+        // Without time passing there is no rate, the previous sample is kept until time passes.
+        if !context.delta().is_zero() {
+            if let Some(previous_radio_altitude) = self.previous_radio_altitude {
+                self.closure_rate.update(
+                    context.delta(),
+                    (previous_radio_altitude - radio_altitude) / context.delta_as_time(),
+                );
+            }
+            self.previous_radio_altitude = Some(radio_altitude);
+        }
+
+        let closure_rate = self.closure_rate.output().get::<foot_per_minute>();
+        let radio_altitude = radio_altitude.get::<foot>();
+
+        let rate_boundary = if closure_rate < 3500. {
+            0.7937 * closure_rate - 1557.5
+        } else {
+            0.19166 * closure_rate + 610.
+        };
+        let is_in_envelope = closure_rate > 2000.
+            && radio_altitude < rate_boundary
+            && if flaps_in_landing_configuration {
+                closure_rate < 10000. && radio_altitude < 775.
+            } else {
+                radio_altitude
+                    < (8.8888 * computed_airspeed.get::<knot>() - 305.555).clamp(1650., 2450.)
+            };
+
+        self.is_in_envelope.update(context, is_in_envelope);
+        self.pull_up_delay
+            .update(context, self.is_in_envelope.output() && !gear_is_down);
+
+        if self.is_in_envelope.output() {
+            self.altitude_at_envelope_exit = altitude;
+            self.pull_up = self.pull_up_delay.output();
+            self.terrain = !self.pull_up;
+        } else {
+            self.pull_up = false;
+            self.terrain = match (self.terrain, self.altitude_at_envelope_exit, altitude) {
+                (true, Some(exit_altitude), Some(altitude)) => {
+                    (altitude - exit_altitude).get::<foot>() < Self::ALTITUDE_GAIN_TO_END_ALERT_FOOT
+                }
+                _ => false,
+            };
+        }
+    }
+}
+
+/// Mode 3 alerts when altitude is lost after takeoff or go-around.
+#[derive(Default)]
+struct AltitudeLossAfterTakeoff {
+    maximum_altitude: Option<Length>,
+    dont_sink: bool,
+}
+impl AltitudeLossAfterTakeoff {
+    const MAXIMUM_RADIO_ALTITUDE_FOOT: f64 = 1500.;
+
+    fn update(&mut self, radio_altitude: Length, altitude: Option<Length>, takeoff_mode: bool) {
+        let radio_altitude = radio_altitude.get::<foot>();
+        if !takeoff_mode || radio_altitude > Self::MAXIMUM_RADIO_ALTITUDE_FOOT {
+            self.maximum_altitude = None;
+            self.dont_sink = false;
+            return;
+        }
+
+        self.dont_sink = match altitude {
+            Some(altitude) => {
+                let maximum_altitude = self
+                    .maximum_altitude
+                    .map_or(altitude, |maximum| maximum.max(altitude));
+                self.maximum_altitude = Some(maximum_altitude);
+
+                (maximum_altitude - altitude).get::<foot>() > 0.09 * radio_altitude + 7.1
+            }
+            None => false,
+        };
+    }
+}
+
+/// Mode 4 alerts when the terrain clearance is insufficient for the aircraft configuration:
+/// - 4A: the gear isn't down during approach,
+/// - 4B: the flaps aren't in landing configuration during approach,
+/// - 4C: the terrain rises below the aircraft after takeoff.
+#[derive(Default)]
+struct UnsafeTerrainClearance {
+    maximum_radio_altitude: Option<Length>,
+
+    too_low_gear: bool,
+    too_low_flaps: bool,
+    too_low_terrain: bool,
+}
+impl UnsafeTerrainClearance {
+    const MINIMUM_RADIO_ALTITUDE_FOOT: f64 = 30.;
+    const MAXIMUM_RADIO_ALTITUDE_FOOT: f64 = 1000.;
+
+    fn update(
+        &mut self,
+        radio_altitude: Length,
+        computed_airspeed: Velocity,
+        gear_is_down: bool,
+        flaps_in_landing_configuration: bool,
+        flap_mode_is_off: bool,
+        takeoff_mode: bool,
+    ) {
+        self.maximum_radio_altitude = if takeoff_mode {
+            Some(
+                self.maximum_radio_altitude
+                    .map_or(radio_altitude, |maximum| maximum.max(radio_altitude)),
+            )
+        } else {
+            None
+        };
+
+        self.reset_alerts();
+
+        let radio_altitude = radio_altitude.get::<foot>();
+        if !(Self::MINIMUM_RADIO_ALTITUDE_FOOT..=Self::MAXIMUM_RADIO_ALTITUDE_FOOT)
+            .contains(&radio_altitude)
+        {
+            return;
+        }
+
+        let computed_airspeed = computed_airspeed.get::<knot>();
+        if takeoff_mode {
+            if let Some(maximum_radio_altitude) = self.maximum_radio_altitude {
+                let maximum_radio_altitude = maximum_radio_altitude.get::<foot>();
+                self.too_low_terrain = !(gear_is_down && flaps_in_landing_configuration)
+                    && maximum_radio_altitude > 100.
+                    && radio_altitude < (8.3333 * computed_airspeed - 1083.33).clamp(500., 1000.)
+                    && radio_altitude < 0.750751 * maximum_radio_altitude - 0.750751;
+            }
+        } else if !gear_is_down {
+            if computed_airspeed < 190. {
+                self.too_low_gear = radio_altitude < 500.;
+            } else {
+                self.too_low_terrain = radio_altitude < 8.333 * computed_airspeed - 1083.333;
+            }
+        } else if !flaps_in_landing_configuration && !flap_mode_is_off {
+            if computed_airspeed < 159. {
+                self.too_low_flaps = radio_altitude < 245.;
+            } else {
+                self.too_low_terrain = radio_altitude < 8.2967 * computed_airspeed - 1074.18;
+            }
+        }
+    }
+
+    fn reset_alerts(&mut self) {
+        self.too_low_gear = false;
+        self.too_low_flaps = false;
+        self.too_low_terrain = false;
+    }
+}
+
+/// Mode 5 alerts when the aircraft descends below the glideslope. The alert can be
+/// cancelled by pressing the G/S push button below 2000 ft RA.
+#[derive(Default)]
+struct GlideslopeDeviation {
+    is_cancelled: bool,
+
+    glideslope: bool,
+    hard_glideslope: bool,
+}
+impl GlideslopeDeviation {
+    const MINIMUM_RADIO_ALTITUDE_FOOT: f64 = 30.;
+    const MAXIMUM_RADIO_ALTITUDE_FOOT: f64 = 1000.;
+    const CANCELLATION_RADIO_ALTITUDE_FOOT: f64 = 2000.;
+    // One dot of glideslope deviation equals 0.4 degrees.
+    const DOTS_PER_DEGREE: f64 = 2.5;
+
+    fn update_cancellation(&mut self, radio_altitude: Option<Length>, cancel_pressed: bool) {
+        if let Some(radio_altitude) = radio_altitude {
+            let radio_altitude = radio_altitude.get::<foot>();
+            if !(Self::MINIMUM_RADIO_ALTITUDE_FOOT..=Self::CANCELLATION_RADIO_ALTITUDE_FOOT)
+                .contains(&radio_altitude)
+            {
+                self.is_cancelled = false;
+            } else if cancel_pressed {
+                self.is_cancelled = true;
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        radio_altitude: Length,
+        glideslope_deviation: Option<Angle>,
+        gear_is_down: bool,
+        mode_is_off: bool,
+    ) {
+        self.reset_alerts();
+
+        let radio_altitude = radio_altitude.get::<foot>();
+        if mode_is_off
+            || self.is_cancelled
+            || !gear_is_down
+            || !(Self::MINIMUM_RADIO_ALTITUDE_FOOT..=Self::MAXIMUM_RADIO_ALTITUDE_FOOT)
+                .contains(&radio_altitude)
+        {
+            return;
+        }
+
+        if let Some(glideslope_deviation) = glideslope_deviation {
+            let dots_below = -glideslope_deviation.get::<degree>() * Self::DOTS_PER_DEGREE;
+
+            let glideslope_boundary = if dots_below < 2.9 {
+                -75. * dots_below + 247.5
+            } else {
+                30.
+            };
+            let hard_glideslope_boundary = if dots_below < 3.8 {
+                -66.66 * dots_below + 283.33
+            } else {
+                30.
+            };
+
+            self.hard_glideslope = dots_below > 2.
+                && radio_altitude > hard_glideslope_boundary
+                && radio_altitude < 350.;
+            self.glideslope =
+                !self.hard_glideslope && dots_below > 1.3 && radio_altitude > glideslope_boundary;
+        }
+    }
+
+    fn reset_alerts(&mut self) {
+        self.glideslope = false;
+        self.hard_glideslope = false;
+    }
+}
+
+/// Mode 6 announces the radio altitude when descending through the callout altitudes.
+/// A callout is re-armed once the aircraft climbs above its re-arm altitude.
+#[derive(Default)]
+struct AltitudeCallouts {
+    armed: [bool; Self::CALLOUTS.len()],
+    callout: Option<u32>,
+}
+impl AltitudeCallouts {
+    // The callout altitude and the re-arm altitude in feet.
+    const CALLOUTS: [(u32, f64); 13] = [
+        (2500, 2530.),
+        (1000, 1020.),
+        (500, 513.),
+        (400, 410.),
+        (300, 310.),
+        (200, 210.),
+        (100, 110.),
+        (50, 53.),
+        (40, 42.),
+        (30, 32.),
+        (20, 22.),
+        (10, 12.),
+        (5, 6.),
+    ];
+
+    fn update(&mut self, radio_altitude: Option<Length>) {
+        let radio_altitude = match radio_altitude {
+            Some(radio_altitude) => radio_altitude.get::<foot>(),
+            None => return,
+        };
+
+        for (armed, (callout, rearm_altitude)) in self.armed.iter_mut().zip(Self::CALLOUTS) {
+            if radio_altitude > rearm_altitude {
+                *armed = true;
+                if self.callout == Some(callout) {
+                    self.callout = None;
+                }
+            } else if *armed && radio_altitude <= callout as f64 {
+                *armed = false;
+                self.callout = Some(callout);
+            }
+        }
+    }
+}
+
+/// Mode 7 alerts when the aircraft encounters a decreasing performance windshear during
+/// takeoff or approach. This simplified reactive detection only considers the rate at
+/// which the airspeed decreases.
+struct Windshear {
+    previous_computed_airspeed: Option<Velocity>,
+    airspeed_decrease_rate: LowPassFilter<f64>,
+    windshear: bool,
+}
+impl Windshear {
+    const MINIMUM_RADIO_ALTITUDE_FOOT: f64 = 10.;
+    const MAXIMUM_RADIO_ALTITUDE_FOOT: f64 = 1500.;
+    const AIRSPEED_DECREASE_RATE_FILTER_TIME_CONSTANT: Duration = Duration::from_secs(1);
+    const WARNING_AIRSPEED_DECREASE_RATE_KNOTS_PER_SECOND: f64 = 2.5;
+
+    fn new() -> Self {
+        Self {
+            previous_computed_airspeed: None,
+            airspeed_decrease_rate: LowPassFilter::new(
+                Self::AIRSPEED_DECREASE_RATE_FILTER_TIME_CONSTANT,
+            ),
+            windshear: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Option<Length>,
+        computed_airspeed: Option<Velocity>,
+    ) {
+        match (self.previous_computed_airspeed, computed_airspeed) {
+            // Without time passing there is no rate, the previous sample is kept until time passes.
+            (Some(_), Some(_)) if context.delta().is_zero() => {}
+            (Some(previous), Some(current)) => {
+                self.airspeed_decrease_rate.update(
+                    context.delta(),
+                    (previous - current).get::<knot>() / context.delta_as_secs_f64(),
+                );
+                self.previous_computed_airspeed = computed_airspeed;
+            }
+            _ => {
+                self.airspeed_decrease_rate =
+                    LowPassFilter::new(Self::AIRSPEED_DECREASE_RATE_FILTER_TIME_CONSTANT);
+                self.previous_computed_airspeed = computed_airspeed;
+            }
+        }
+
+        self.windshear = matches!(radio_altitude, Some(radio_altitude)
+            if (Self::MINIMUM_RADIO_ALTITUDE_FOOT..=Self::MAXIMUM_RADIO_ALTITUDE_FOOT)
+                .contains(&radio_altitude.get::<foot>()))
+            && self.airspeed_decrease_rate.output()
+                > Self::WARNING_AIRSPEED_DECREASE_RATE_KNOTS_PER_SECOND;
+    }
+}
@@ -1,13 +1,18 @@
 use crate::{
     accept_iterable,
-    enhanced_gpwc::navigation_display::NavigationDisplay,
+    enhanced_gpwc::{
+        alert_modes::{AlertModeInputs, AlertModes, AuralAlert},
+        navigation_display::NavigationDisplay,
+    },
+    navigation::radio_altimeter::RadioAltimeter,
     shared::{
         arinc429::{Arinc429Word, SignStatus},
-        AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses, LgciuGearExtension,
+        AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses, GpsReceiverOutputs,
+        LgciuGearExtension,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
     },
 };
 use std::vec::Vec;
@@ -17,6 +22,7 @@ use uom::si::{
     velocity::foot_per_minute,
 };
 
+mod alert_modes;
 pub mod navigation_display;
 
 pub struct EnhancedGroundProximityWarningComputer {
@@ -37,6 +43,23 @@ pub struct EnhancedGroundProximityWarningComputer {
     navigation_displays: [NavigationDisplay; 2],
     gear_is_down: bool,
     terronnd_rendering_mode: u8,
+    // input variables of the GPWS alert modes
+    gpws_sys_off_id: VariableIdentifier,
+    gpws_gs_off_id: VariableIdentifier,
+    gpws_flap_off_id: VariableIdentifier,
+    gpws_flaps3_id: VariableIdentifier,
+    gpws_gs_cancel_id: VariableIdentifier,
+    flaps_handle_index_id: VariableIdentifier,
+    glideslope_is_valid_id: VariableIdentifier,
+    glideslope_deviation_id: VariableIdentifier,
+    gpws_sys_off: bool,
+    gpws_gs_off: bool,
+    gpws_flap_off: bool,
+    gpws_flaps3: bool,
+    gpws_gs_cancel_pressed: bool,
+    flaps_handle_index: u8,
+    glideslope_deviation: Option<Angle>,
+    alert_modes: AlertModes,
     // output variables of the EGPWC
     egpwc_destination_longitude_id: VariableIdentifier,
     egpwc_destination_latitude_id: VariableIdentifier,
@@ -47,9 +70,16 @@ pub struct EnhancedGroundProximityWarningComputer {
     egpwc_present_vertical_speed_id: VariableIdentifier,
     egpwc_gear_is_down_id: VariableIdentifier,
     egpwc_terronnd_rendering_mode: VariableIdentifier,
+    egpwc_discrete_word_1_id: VariableIdentifier,
+    egpwc_discrete_word_2_id: VariableIdentifier,
+    egpwc_aural_alert_id: VariableIdentifier,
+    egpwc_altitude_callout_id: VariableIdentifier,
 }
 
 impl EnhancedGroundProximityWarningComputer {
+    const FLAPS_HANDLE_INDEX_FLAPS_3: u8 = 3;
+    const FLAPS_HANDLE_INDEX_FLAPS_FULL: u8 = 4;
+
     pub fn new(
         context: &mut InitContext,
         powered_by: ElectricalBusType,
@@ -59,11 +89,22 @@ impl EnhancedGroundProximityWarningComputer {
         EnhancedGroundProximityWarningComputer {
             powered_by,
             is_powered: false,
-            fm1_destination_longitude_ssm_id: context
-                .get_identifier("FM1_DEST_LONG_SSM".to_owned()),
-            fm1_destination_longitude_id: context.get_identifier("FM1_DEST_LONG".to_owned()),
-            fm1_destination_latitude_ssm_id: context.get_identifier("FM1_DEST_LAT_SSM".to_owned()),
-            fm1_destination_latitude_id: context.get_identifier("FM1_DEST_LAT".to_owned()),
+            fm1_destination_longitude_ssm_id: context.get_identifier_with_metadata(
+                "FM1_DEST_LONG_SSM".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            fm1_destination_longitude_id: context.get_identifier_with_metadata(
+                "FM1_DEST_LONG".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            fm1_destination_latitude_ssm_id: context.get_identifier_with_metadata(
+                "FM1_DEST_LAT_SSM".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            fm1_destination_latitude_id: context.get_identifier_with_metadata(
+                "FM1_DEST_LAT".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             destination_longitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
             destination_latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
             latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
@@ -78,6 +119,46 @@ impl EnhancedGroundProximityWarningComputer {
             ],
             gear_is_down: true,
             terronnd_rendering_mode,
+            gpws_sys_off_id: context.get_identifier_with_metadata(
+                "GPWS_SYS_OFF".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gpws_gs_off_id: context.get_identifier_with_metadata(
+                "GPWS_GS_OFF".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gpws_flap_off_id: context.get_identifier_with_metadata(
+                "GPWS_FLAP_OFF".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gpws_flaps3_id: context.get_identifier_with_metadata(
+                "GPWS_FLAPS3".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gpws_gs_cancel_id: context.get_identifier_with_metadata(
+                "GPWS_TEST".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            flaps_handle_index_id: context.get_identifier_with_metadata(
+                "FLAPS_HANDLE_INDEX".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            glideslope_is_valid_id: context.get_identifier_with_metadata(
+                "RADIO_RECEIVER_GS_IS_VALID".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            glideslope_deviation_id: context.get_identifier_with_metadata(
+                "RADIO_RECEIVER_GS_DEVIATION".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gpws_sys_off: false,
+            gpws_gs_off: false,
+            gpws_flap_off: false,
+            gpws_flaps3: false,
+            gpws_gs_cancel_pressed: false,
+            flaps_handle_index: 0,
+            glideslope_deviation: None,
+            alert_modes: AlertModes::new(),
             egpwc_destination_longitude_id: context.get_identifier("EGPWC_DEST_LONG".to_owned()),
             egpwc_destination_latitude_id: context.get_identifier("EGPWC_DEST_LAT".to_owned()),
             egpwc_present_latitude_id: context.get_identifier("EGPWC_PRESENT_LAT".to_owned()),
@@ -89,25 +170,86 @@ impl EnhancedGroundProximityWarningComputer {
             egpwc_gear_is_down_id: context.get_identifier("EGPWC_GEAR_IS_DOWN".to_owned()),
             egpwc_terronnd_rendering_mode: context
                 .get_identifier("EGPWC_TERRONND_RENDERING_MODE".to_owned()),
+            egpwc_discrete_word_1_id: context.get_identifier("EGPWC_DISCRETE_WORD_1".to_owned()),
+            egpwc_discrete_word_2_id: context.get_identifier("EGPWC_DISCRETE_WORD_2".to_owned()),
+            egpwc_aural_alert_id: context.get_identifier("EGPWC_AURAL_ALERT".to_owned()),
+            egpwc_altitude_callout_id: context.get_identifier("EGPWC_ALTITUDE_CALLOUT".to_owned()),
         }
     }
 
-    fn update_position_data(&mut self, adirs_output: &impl AdirsMeasurementOutputs) {
-        // documentation hints:
-        //   - EGPWC has direct connection to GPS sensor && ADIRS_1
-        //   - uses direct GPS data if ADIRS_1 is unavailable
-        // TODO:
-        //   - implement logic as soon as GPS sensor is available
-        self.latitude = adirs_output.latitude(1);
-        self.longitude = adirs_output.longitude(1);
+    fn update_position_data(
+        &mut self,
+        adirs_output: &impl AdirsMeasurementOutputs,
+        gps: &impl GpsReceiverOutputs,
+    ) {
+        // The EGPWC has a direct connection to the GPS receiver and ADIRU 1.
+        // It uses the GPS position when ADIRU 1 doesn't provide a position.
+        let adiru_latitude = adirs_output.latitude(1);
+        let adiru_longitude = adirs_output.longitude(1);
+        let gps_latitude = gps.latitude();
+        let gps_longitude = gps.longitude();
+        if (!adiru_latitude.is_normal_operation() || !adiru_longitude.is_normal_operation())
+            && gps_latitude.is_normal_operation()
+            && gps_longitude.is_normal_operation()
+        {
+            self.latitude = gps_latitude;
+            self.longitude = gps_longitude;
+        } else {
+            self.latitude = adiru_latitude;
+            self.longitude = adiru_longitude;
+        }
+
         self.altitude = adirs_output.altitude(1);
         self.heading = adirs_output.true_heading(1);
         self.vertical_speed = adirs_output.vertical_speed(1);
     }
 
-    pub fn update(
+    fn flaps_in_landing_configuration(&self) -> bool {
+        if self.gpws_flaps3 {
+            self.flaps_handle_index >= Self::FLAPS_HANDLE_INDEX_FLAPS_3
+        } else {
+            self.flaps_handle_index == Self::FLAPS_HANDLE_INDEX_FLAPS_FULL
+        }
+    }
+
+    fn update_alert_modes<T: RadioAltimeter>(
+        &mut self,
+        context: &UpdateContext,
+        adirs_output: &impl AdirsMeasurementOutputs,
+        radio_altimeters: [&T; 2],
+    ) {
+        // The EGPWC uses radio altimeter 1 and reverts to radio altimeter 2 when it fails.
+        let radio_altitude = radio_altimeters[0].radio_altitude();
+        let radio_altitude = if radio_altitude.is_failure_warning() {
+            radio_altimeters[1].radio_altitude()
+        } else {
+            radio_altitude
+        };
+
+        self.alert_modes.update(
+            context,
+            &AlertModeInputs {
+                radio_altitude,
+                altitude: adirs_output.altitude(1),
+                vertical_speed: adirs_output.vertical_speed(1),
+                computed_airspeed: adirs_output.computed_airspeed(1),
+                gear_is_down: self.gear_is_down,
+                flaps_in_landing_configuration: self.flaps_in_landing_configuration(),
+                glideslope_deviation: self.glideslope_deviation,
+                system_is_off: self.gpws_sys_off,
+                glideslope_mode_is_off: self.gpws_gs_off,
+                flap_mode_is_off: self.gpws_flap_off,
+                glideslope_cancel_pressed: self.gpws_gs_cancel_pressed,
+            },
+        );
+    }
+
+    pub fn update<T: RadioAltimeter>(
         &mut self,
+        context: &UpdateContext,
         adirs_output: &impl AdirsMeasurementOutputs,
+        radio_altimeters: [&T; 2],
+        gps: &impl GpsReceiverOutputs,
         lgcius: &impl LgciuGearExtension,
     ) {
         if !self.is_powered {
@@ -123,9 +265,11 @@ impl EnhancedGroundProximityWarningComputer {
                 Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning);
 
             self.gear_is_down = false;
+            self.alert_modes = AlertModes::new();
         } else {
-            self.update_position_data(adirs_output);
+            self.update_position_data(adirs_output, gps);
             self.gear_is_down = lgcius.main_down_and_locked();
+            self.update_alert_modes(context, adirs_output, radio_altimeters);
         }
 
         self.navigation_displays.iter_mut().for_each(|display| {
@@ -136,6 +280,63 @@ impl EnhancedGroundProximityWarningComputer {
             )
         });
     }
+
+    /// The alerts of the GPWS modes, as they are sent to the FWC for the aural alerts.
+    pub fn discrete_word_1(&self) -> Arinc429Word<u32> {
+        if !self.is_powered {
+            Arinc429Word::new(0, SignStatus::FailureWarning)
+        } else {
+            let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+            word.set_bit(11, self.alert_modes.sink_rate());
+            word.set_bit(12, self.alert_modes.sink_rate_pull_up());
+            word.set_bit(13, self.alert_modes.terrain());
+            word.set_bit(14, self.alert_modes.terrain_pull_up());
+            word.set_bit(15, self.alert_modes.dont_sink());
+            word.set_bit(16, self.alert_modes.too_low_gear());
+            word.set_bit(17, self.alert_modes.too_low_flaps());
+            word.set_bit(18, self.alert_modes.too_low_terrain());
+            word.set_bit(19, self.alert_modes.glideslope());
+            word.set_bit(20, self.alert_modes.hard_glideslope());
+            word.set_bit(21, self.alert_modes.windshear());
+
+            word
+        }
+    }
+
+    /// The visual warnings and the state of the GPWS push buttons.
+    pub fn discrete_word_2(&self) -> Arinc429Word<u32> {
+        if !self.is_powered {
+            Arinc429Word::new(0, SignStatus::FailureWarning)
+        } else {
+            let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+            word.set_bit(11, self.alert_modes.gpws_alert());
+            word.set_bit(12, self.alert_modes.glideslope_alert());
+            word.set_bit(13, self.alert_modes.windshear());
+            word.set_bit(14, self.gpws_sys_off);
+            word.set_bit(15, self.gpws_gs_off);
+            word.set_bit(16, self.gpws_flap_off);
+            word.set_bit(17, self.gpws_flaps3);
+            word.set_bit(18, self.alert_modes.glideslope_is_cancelled());
+
+            word
+        }
+    }
+
+    fn aural_alert(&self) -> AuralAlert {
+        if self.is_powered {
+            self.alert_modes.aural_alert()
+        } else {
+            AuralAlert::None
+        }
+    }
+
+    fn altitude_callout(&self) -> u32 {
+        if self.is_powered {
+            self.alert_modes.altitude_callout().unwrap_or_default()
+        } else {
+            0
+        }
+    }
 }
 
 impl SimulationElement for EnhancedGroundProximityWarningComputer {
@@ -157,6 +358,21 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
             Angle::new::<degree>(destination_lat),
             SignStatus::from(destination_lat_ssm),
         );
+
+        self.gpws_sys_off = reader.read(&self.gpws_sys_off_id);
+        self.gpws_gs_off = reader.read(&self.gpws_gs_off_id);
+        self.gpws_flap_off = reader.read(&self.gpws_flap_off_id);
+        self.gpws_flaps3 = reader.read(&self.gpws_flaps3_id);
+        self.gpws_gs_cancel_pressed = reader.read(&self.gpws_gs_cancel_id);
+        self.flaps_handle_index = reader.read(&self.flaps_handle_index_id);
+
+        let glideslope_is_valid: bool = reader.read(&self.glideslope_is_valid_id);
+        let glideslope_deviation: f64 = reader.read(&self.glideslope_deviation_id);
+        self.glideslope_deviation = if glideslope_is_valid {
+            Some(Angle::new::<degree>(glideslope_deviation))
+        } else {
+            None
+        };
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -182,6 +398,10 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
             &self.egpwc_terronnd_rendering_mode,
             self.terronnd_rendering_mode,
         );
+        writer.write(&self.egpwc_discrete_word_1_id, self.discrete_word_1());
+        writer.write(&self.egpwc_discrete_word_2_id, self.discrete_word_2());
+        writer.write(&self.egpwc_aural_alert_id, self.aural_alert());
+        writer.write(&self.egpwc_altitude_callout_id, self.altitude_callout());
     }
 
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -202,12 +422,13 @@ mod tests {
         },
     };
     use ntest::assert_about_eq;
+    use std::time::Duration;
     use uom::si::{
         angle::degree,
         electric_potential::volt,
         f64::*,
         length::{foot, nautical_mile},
-        velocity::foot_per_minute,
+        velocity::{foot_per_minute, knot},
     };
 
     struct TestAdirs {
@@ -217,6 +438,7 @@ mod tests {
         heading: Arinc429Word<Angle>,
         vertical_speed: Arinc429Word<Velocity>,
         altitude: Arinc429Word<Length>,
+        computed_airspeed: Arinc429Word<Velocity>,
     }
     impl TestAdirs {
         fn new() -> Self {
@@ -227,6 +449,10 @@ mod tests {
                 heading: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 vertical_speed: Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning),
                 altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+                computed_airspeed: Arinc429Word::new(
+                    Velocity::default(),
+                    SignStatus::FailureWarning,
+                ),
             }
         }
 
@@ -244,6 +470,8 @@ mod tests {
             );
             self.altitude =
                 Arinc429Word::new(Length::new::<foot>(15000.0), SignStatus::NormalOperation);
+            self.computed_airspeed =
+                Arinc429Word::new(Velocity::new::<knot>(250.0), SignStatus::NormalOperation);
         }
     }
     impl AdirsMeasurementOutputs for TestAdirs {
@@ -274,6 +502,64 @@ mod tests {
         fn altitude(&self, _adiru_number: usize) -> Arinc429Word<Length> {
             self.altitude
         }
+
+        fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+            self.computed_airspeed
+        }
+    }
+
+    struct TestRadioAltimeter {
+        radio_altitude: Arinc429Word<Length>,
+    }
+    impl TestRadioAltimeter {
+        fn new() -> Self {
+            Self {
+                radio_altitude: Arinc429Word::new(Length::default(), SignStatus::NoComputedData),
+            }
+        }
+    }
+    impl RadioAltimeter for TestRadioAltimeter {
+        fn radio_altitude(&self) -> Arinc429Word<Length> {
+            self.radio_altitude
+        }
+    }
+
+    struct TestGpsReceiver {
+        is_tracking_satellites: bool,
+    }
+    impl TestGpsReceiver {
+        fn ssm(&self) -> SignStatus {
+            if self.is_tracking_satellites {
+                SignStatus::NormalOperation
+            } else {
+                SignStatus::NoComputedData
+            }
+        }
+    }
+    impl GpsReceiverOutputs for TestGpsReceiver {
+        fn latitude(&self) -> Arinc429Word<Angle> {
+            Arinc429Word::new(Angle::new::<degree>(20.4), self.ssm())
+        }
+
+        fn longitude(&self) -> Arinc429Word<Angle> {
+            Arinc429Word::new(Angle::new::<degree>(30.4), self.ssm())
+        }
+
+        fn ground_speed(&self) -> Arinc429Word<Velocity> {
+            Arinc429Word::new(Velocity::default(), self.ssm())
+        }
+
+        fn true_track(&self) -> Arinc429Word<Angle> {
+            Arinc429Word::new(Angle::default(), self.ssm())
+        }
+
+        fn horizontal_figure_of_merit(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::default(), self.ssm())
+        }
+
+        fn horizontal_integrity_limit(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::default(), self.ssm())
+        }
     }
 
     struct TestLgciu {
@@ -317,6 +603,8 @@ mod tests {
 
     struct EgpwcTestAircraft {
         adirs: TestAdirs,
+        radio_altimeters: [TestRadioAltimeter; 2],
+        gps: TestGpsReceiver,
         lgciu: TestLgciu,
         egpwc: EnhancedGroundProximityWarningComputer,
         powered_source_dc: TestElectricitySource,
@@ -327,6 +615,10 @@ mod tests {
         fn new(context: &mut InitContext) -> Self {
             Self {
                 adirs: TestAdirs::new(),
+                radio_altimeters: [TestRadioAltimeter::new(), TestRadioAltimeter::new()],
+                gps: TestGpsReceiver {
+                    is_tracking_satellites: false,
+                },
                 lgciu: TestLgciu::new(),
                 egpwc: EnhancedGroundProximityWarningComputer::new(
                     context,
@@ -350,14 +642,24 @@ mod tests {
             }
         }
 
-        fn update(&mut self) {
-            self.egpwc.update(&self.adirs, &self.lgciu);
+        fn update(&mut self, context: &UpdateContext) {
+            self.egpwc.update(
+                context,
+                &self.adirs,
+                [&self.radio_altimeters[0], &self.radio_altimeters[1]],
+                &self.gps,
+                &self.lgciu,
+            );
         }
 
         fn initialize_adiru(&mut self) {
             self.adirs.initialize();
         }
 
+        fn acquire_gps(&mut self) {
+            self.gps.is_tracking_satellites = true;
+        }
+
         fn gear_down(&mut self) {
             self.lgciu.set_gear_down(true);
         }
@@ -365,6 +667,41 @@ mod tests {
         fn set_elec_powered(&mut self, is_powered: bool) {
             self.is_elec_powered = is_powered;
         }
+
+        fn set_radio_altitude(&mut self, radio_altitude: f64) {
+            self.radio_altimeters
+                .iter_mut()
+                .for_each(|radio_altimeter| {
+                    radio_altimeter.radio_altitude = Arinc429Word::new(
+                        Length::new::<foot>(radio_altitude),
+                        SignStatus::NormalOperation,
+                    )
+                });
+        }
+
+        fn fail_radio_altimeter(&mut self, number: usize) {
+            self.radio_altimeters[number - 1].radio_altitude =
+                Arinc429Word::new(Length::default(), SignStatus::FailureWarning);
+        }
+
+        fn set_altitude(&mut self, altitude: f64) {
+            self.adirs.altitude =
+                Arinc429Word::new(Length::new::<foot>(altitude), SignStatus::NormalOperation);
+        }
+
+        fn set_vertical_speed(&mut self, vertical_speed: f64) {
+            self.adirs.vertical_speed = Arinc429Word::new(
+                Velocity::new::<foot_per_minute>(vertical_speed),
+                SignStatus::NormalOperation,
+            );
+        }
+
+        fn set_computed_airspeed(&mut self, computed_airspeed: f64) {
+            self.adirs.computed_airspeed = Arinc429Word::new(
+                Velocity::new::<knot>(computed_airspeed),
+                SignStatus::NormalOperation,
+            );
+        }
     }
     impl Aircraft for EgpwcTestAircraft {
         fn update_before_power_distribution(
@@ -381,8 +718,8 @@ mod tests {
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for EgpwcTestAircraft {
@@ -617,4 +954,373 @@ mod tests {
         let nd_terr_activate_fo: bool = test_bed.read_by_name("EGPWC_ND_R_TERRAIN_ACTIVE");
         assert!(!nd_terr_activate_fo);
     }
+
+    #[test]
+    fn powered_up_uses_gps_position_when_adiru_unavailable() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.acquire_gps());
+        test_bed.run();
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert!(present_lat.is_normal_operation());
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.4);
+        let present_long: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
+        assert!(present_long.is_normal_operation());
+        assert_about_eq!(present_long.value().get::<degree>(), 30.4);
+    }
+
+    #[test]
+    fn powered_up_prefers_adiru_position_over_gps_position() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.acquire_gps());
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.run();
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.3);
+        let present_long: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
+        assert_about_eq!(present_long.value().get::<degree>(), 30.3);
+    }
+
+    fn alert_mode_test_bed(radio_altitude: f64) -> SimulationTestBed<EgpwcTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+        test_bed.command(|a| {
+            a.set_elec_powered(true);
+            a.initialize_adiru();
+            a.set_vertical_speed(0.);
+            a.set_radio_altitude(radio_altitude);
+        });
+
+        test_bed
+    }
+
+    fn aural_alert(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) -> AuralAlert {
+        test_bed.read_by_name("EGPWC_AURAL_ALERT")
+    }
+
+    fn altitude_callout(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) -> u32 {
+        test_bed.read_by_name("EGPWC_ALTITUDE_CALLOUT")
+    }
+
+    fn run_with_radio_altitude(
+        test_bed: &mut SimulationTestBed<EgpwcTestAircraft>,
+        radio_altitude: f64,
+    ) {
+        test_bed.command(|a| a.set_radio_altitude(radio_altitude));
+        test_bed.run();
+    }
+
+    #[test]
+    fn unpowered_egpwc_reports_failure_warning_discrete_words() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+        test_bed.run();
+
+        assert!(test_bed
+            .query(|a| a.egpwc.discrete_word_1())
+            .is_failure_warning());
+        assert!(test_bed
+            .query(|a| a.egpwc.discrete_word_2())
+            .is_failure_warning());
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+    }
+
+    #[test]
+    fn mode_1_alerts_sink_rate() {
+        let mut test_bed = alert_mode_test_bed(1500.);
+        test_bed.command(|a| a.set_vertical_speed(-4000.));
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::SinkRate);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(11));
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_2()).get_bit(11));
+    }
+
+    #[test]
+    fn mode_1_alerts_pull_up_close_to_terrain() {
+        let mut test_bed = alert_mode_test_bed(500.);
+        test_bed.command(|a| a.set_vertical_speed(-3000.));
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::PullUp);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(12));
+    }
+
+    #[test]
+    fn gpws_sys_off_inhibits_the_basic_modes() {
+        let mut test_bed = alert_mode_test_bed(500.);
+        test_bed.write_by_name("GPWS_SYS_OFF", true);
+        test_bed.command(|a| a.set_vertical_speed(-3000.));
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+        let word = test_bed.query(|a| a.egpwc.discrete_word_2());
+        assert!(!word.get_bit(11));
+        assert!(word.get_bit(14));
+    }
+
+    #[test]
+    fn egpwc_reverts_to_radio_altimeter_2_when_radio_altimeter_1_fails() {
+        let mut test_bed = alert_mode_test_bed(500.);
+        test_bed.command(|a| {
+            a.fail_radio_altimeter(1);
+            a.set_vertical_speed(-3000.);
+        });
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::PullUp);
+    }
+
+    #[test]
+    fn mode_2_alerts_terrain_then_pull_up_with_gear_up() {
+        let mut test_bed = alert_mode_test_bed(1500.);
+        test_bed.run();
+
+        run_with_radio_altitude(&mut test_bed, 1400.);
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::Terrain);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(13));
+
+        run_with_radio_altitude(&mut test_bed, 1300.);
+        run_with_radio_altitude(&mut test_bed, 1200.);
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::PullUp);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(14));
+    }
+
+    #[test]
+    fn mode_2_terrain_alert_continues_until_300_ft_are_gained() {
+        let mut test_bed = alert_mode_test_bed(1500.);
+        test_bed.command(|a| a.gear_down());
+        test_bed.run();
+
+        for radio_altitude in [1400., 1300., 1200.] {
+            run_with_radio_altitude(&mut test_bed, radio_altitude);
+            assert_eq!(aural_alert(&mut test_bed), AuralAlert::Terrain);
+        }
+
+        test_bed.command(|a| a.set_altitude(15200.));
+        test_bed.run();
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::Terrain);
+
+        test_bed.command(|a| a.set_altitude(15400.));
+        test_bed.run();
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+    }
+
+    #[test]
+    fn mode_3_alerts_dont_sink_when_losing_altitude_after_takeoff() {
+        let mut test_bed = alert_mode_test_bed(5.);
+        test_bed.command(|a| {
+            a.set_computed_airspeed(150.);
+            a.set_altitude(1000.);
+        });
+        test_bed.run();
+
+        run_with_radio_altitude(&mut test_bed, 400.);
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+
+        test_bed.command(|a| a.set_altitude(950.));
+        test_bed.run();
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::DontSink);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(15));
+    }
+
+    #[test]
+    fn mode_4a_alerts_too_low_gear_in_approach() {
+        let mut test_bed = alert_mode_test_bed(400.);
+        test_bed.command(|a| a.set_computed_airspeed(180.));
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::TooLowGear);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(16));
+    }
+
+    #[test]
+    fn mode_4b_alerts_too_low_flaps_when_not_in_landing_configuration() {
+        let mut test_bed = alert_mode_test_bed(200.);
+        test_bed.write_by_name("FLAPS_HANDLE_INDEX", 3);
+        test_bed.command(|a| {
+            a.gear_down();
+            a.set_computed_airspeed(150.);
+        });
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::TooLowFlaps);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(17));
+    }
+
+    #[test]
+    fn mode_4b_considers_flaps_3_as_landing_configuration_with_ldg_flap_3_selected() {
+        let mut test_bed = alert_mode_test_bed(200.);
+        test_bed.write_by_name("FLAPS_HANDLE_INDEX", 3);
+        test_bed.write_by_name("GPWS_FLAPS3", true);
+        test_bed.command(|a| {
+            a.gear_down();
+            a.set_computed_airspeed(150.);
+        });
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+    }
+
+    #[test]
+    fn mode_4b_is_inhibited_by_flap_mode_off() {
+        let mut test_bed = alert_mode_test_bed(200.);
+        test_bed.write_by_name("GPWS_FLAP_OFF", true);
+        test_bed.command(|a| {
+            a.gear_down();
+            a.set_computed_airspeed(150.);
+        });
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_2()).get_bit(16));
+    }
+
+    #[test]
+    fn mode_4c_alerts_too_low_terrain_when_terrain_rises_after_takeoff() {
+        let mut test_bed = alert_mode_test_bed(5.);
+        test_bed.command(|a| a.set_computed_airspeed(200.));
+        test_bed.run();
+
+        run_with_radio_altitude(&mut test_bed, 600.);
+        test_bed.run();
+        test_bed.run();
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+
+        for radio_altitude in [560., 520., 480.] {
+            run_with_radio_altitude(&mut test_bed, radio_altitude);
+            assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+        }
+
+        run_with_radio_altitude(&mut test_bed, 440.);
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::TooLowTerrain);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(18));
+    }
+
+    fn glideslope_test_bed(
+        radio_altitude: f64,
+        glideslope_deviation: f64,
+    ) -> SimulationTestBed<EgpwcTestAircraft> {
+        let mut test_bed = alert_mode_test_bed(radio_altitude);
+        test_bed.write_by_name("FLAPS_HANDLE_INDEX", 4);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", glideslope_deviation);
+        test_bed.command(|a| {
+            a.gear_down();
+            a.set_computed_airspeed(140.);
+        });
+
+        test_bed
+    }
+
+    #[test]
+    fn mode_5_alerts_glideslope_below_the_beam() {
+        let mut test_bed = glideslope_test_bed(500., -0.6);
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::Glideslope);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(19));
+        let word = test_bed.query(|a| a.egpwc.discrete_word_2());
+        assert!(word.get_bit(12));
+        assert!(!word.get_bit(11));
+    }
+
+    #[test]
+    fn mode_5_alerts_hard_glideslope_far_below_the_beam_at_low_height() {
+        let mut test_bed = glideslope_test_bed(300., -1.);
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::HardGlideslope);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(20));
+    }
+
+    #[test]
+    fn mode_5_is_inhibited_by_glideslope_mode_off() {
+        let mut test_bed = glideslope_test_bed(500., -0.6);
+        test_bed.write_by_name("GPWS_GS_OFF", true);
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+    }
+
+    #[test]
+    fn mode_5_is_cancelled_by_the_glideslope_push_button_until_above_2000_ft() {
+        let mut test_bed = glideslope_test_bed(500., -0.6);
+        test_bed.write_by_name("GPWS_TEST", true);
+        test_bed.run();
+        test_bed.write_by_name("GPWS_TEST", false);
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_2()).get_bit(18));
+
+        run_with_radio_altitude(&mut test_bed, 2100.);
+        assert!(!test_bed.query(|a| a.egpwc.discrete_word_2()).get_bit(18));
+
+        run_with_radio_altitude(&mut test_bed, 500.);
+
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(19));
+    }
+
+    #[test]
+    fn mode_6_announces_callouts_when_descending_through_them() {
+        let mut test_bed = alert_mode_test_bed(600.);
+        test_bed.run();
+        assert_eq!(altitude_callout(&mut test_bed), 0);
+
+        run_with_radio_altitude(&mut test_bed, 480.);
+        assert_eq!(altitude_callout(&mut test_bed), 500);
+
+        run_with_radio_altitude(&mut test_bed, 390.);
+        assert_eq!(altitude_callout(&mut test_bed), 400);
+
+        run_with_radio_altitude(&mut test_bed, 520.);
+        assert_eq!(altitude_callout(&mut test_bed), 0);
+
+        run_with_radio_altitude(&mut test_bed, 495.);
+        assert_eq!(altitude_callout(&mut test_bed), 500);
+    }
+
+    #[test]
+    fn mode_7_alerts_windshear_when_airspeed_decreases_rapidly() {
+        let mut test_bed = alert_mode_test_bed(800.);
+        test_bed.command(|a| a.set_computed_airspeed(150.));
+        test_bed.run();
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::None);
+
+        test_bed.command(|a| a.set_computed_airspeed(145.));
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::Windshear);
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_1()).get_bit(21));
+        assert!(test_bed.query(|a| a.egpwc.discrete_word_2()).get_bit(13));
+    }
+
+    #[test]
+    fn mode_2_alerts_after_a_frame_without_time_passing() {
+        let mut test_bed = alert_mode_test_bed(1500.);
+        test_bed.run();
+        test_bed.run_with_delta(Duration::ZERO);
+
+        run_with_radio_altitude(&mut test_bed, 1400.);
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::Terrain);
+    }
+
+    #[test]
+    fn mode_7_alerts_after_a_frame_without_time_passing() {
+        let mut test_bed = alert_mode_test_bed(800.);
+        test_bed.command(|a| a.set_computed_airspeed(150.));
+        test_bed.run();
+        test_bed.run_with_delta(Duration::ZERO);
+
+        test_bed.command(|a| a.set_computed_airspeed(145.));
+        test_bed.run();
+
+        assert_eq!(aural_alert(&mut test_bed), AuralAlert::Windshear);
+    }
 }
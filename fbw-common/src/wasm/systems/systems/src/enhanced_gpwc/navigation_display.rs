@@ -1,6 +1,6 @@
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
-    Write,
+    VariableMetadata, Write,
 };
 use uom::si::{
     f64::{Length, Ratio},
@@ -27,16 +27,27 @@ pub struct NavigationDisplay {
 impl NavigationDisplay {
     pub fn new(context: &mut InitContext, side: &str) -> Self {
         NavigationDisplay {
-            range_knob_id: context.get_identifier(format!("EFIS_{}_ND_RANGE", side)),
+            range_knob_id: context.get_identifier_with_metadata(
+                format!("EFIS_{}_ND_RANGE", side),
+                VariableMetadata::new().written_externally(),
+            ),
             range_knob_position: 0,
             range: Length::new::<nautical_mile>(10.0),
-            mode_id: context.get_identifier(format!("EFIS_{}_ND_MODE", side)),
+            mode_id: context.get_identifier_with_metadata(
+                format!("EFIS_{}_ND_MODE", side),
+                VariableMetadata::new().written_externally(),
+            ),
             mode: 0,
-            terrain_on_nd_pb_id: context.get_identifier(format!("EFIS_TERR_{}_ACTIVE", side)),
+            terrain_on_nd_pb_id: context.get_identifier_with_metadata(
+                format!("EFIS_TERR_{}_ACTIVE", side),
+                VariableMetadata::new().written_externally(),
+            ),
             terrain_on_nd_pb_active: false,
             terrain_on_nd_active: false,
-            potentiometer_id: context
-                .get_identifier(format!("ND_{}_TERR_ON_ND_POTENTIOMETER", side)),
+            potentiometer_id: context.get_identifier_with_metadata(
+                format!("ND_{}_TERR_ON_ND_POTENTIOMETER", side),
+                VariableMetadata::new().written_externally(),
+            ),
             potentiometer: Ratio::new::<percent>(100.0),
             egpwc_nd_range_id: context.get_identifier(format!("EGPWC_ND_{}_RANGE", side)),
             egpwc_nd_terrain_active_id: context
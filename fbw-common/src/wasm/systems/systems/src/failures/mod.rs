@@ -1,10 +1,10 @@
 use crate::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId, HeatedProbe,
+    HeatedWindow, HydraulicColor, LgciuId, ProximityDetectorId,
 };
 use crate::simulation::SimulationElement;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FailureType {
     Generator(usize),
     ApuGenerator(usize),
@@ -22,7 +22,20 @@ pub enum FailureType {
     GearActuatorJammed(GearActuatorId),
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
+    BodyWheelSteering,
+    TyreSlowLeak(usize),
+    TyreBurst(usize),
     RadioAltimeter(usize),
+    ProbeHeater(usize, HeatedProbe),
+    WindowHeater(usize, HeatedWindow),
+    PitotBlockage(usize),
+    PitotDrainBlockage(usize),
+    StaticPortBlockage(usize),
+    AngleOfAttackVaneJam(usize),
+    GpsReceiver(usize),
+    CanBusCorruption(usize),
+    CanBusTransmitter(usize, u8),
+    DisplayUnit(usize),
 }
 
 pub struct Failure {
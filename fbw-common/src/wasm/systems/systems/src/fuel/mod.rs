@@ -0,0 +1,161 @@
+use crate::simulation::{
+    InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+    VariableIdentifier, VariableMetadata, Write,
+};
+use uom::si::{f64::*, mass::kilogram};
+
+/// A fuel tank of which the quantity is owned by the simulator. Fuel burnt by a consumer
+/// simulated here, such as the APU, is subtracted from the quantity read from the simulator.
+/// The resulting quantity is written to the quantity request variable, which is to be written
+/// back to the simulator's tank whenever it is lower than the simulator's quantity.
+pub struct FuelTank {
+    unlimited_fuel_id: VariableIdentifier,
+    quantity_id: VariableIdentifier,
+    quantity_request_id: VariableIdentifier,
+
+    unlimited_fuel: bool,
+    quantity: Mass,
+    requested_quantity: Mass,
+}
+impl FuelTank {
+    pub fn new(
+        context: &mut InitContext,
+        quantity_name: &str,
+        quantity_request_name: &str,
+    ) -> Self {
+        Self {
+            unlimited_fuel_id: context.get_identifier_with_metadata(
+                "UNLIMITED FUEL".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            quantity_id: context.get_identifier_with_metadata(
+                quantity_name.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            quantity_request_id: context.get_identifier(quantity_request_name.to_owned()),
+
+            unlimited_fuel: false,
+            quantity: Mass::new::<kilogram>(0.),
+            requested_quantity: Mass::new::<kilogram>(0.),
+        }
+    }
+
+    /// Burns fuel from the tank at the given flow. No fuel is burnt when the simulator is set to
+    /// unlimited fuel.
+    pub fn update(&mut self, context: &UpdateContext, fuel_flow: MassRate) {
+        self.requested_quantity = if self.unlimited_fuel {
+            self.quantity
+        } else {
+            (self.quantity - fuel_flow * context.delta_as_time()).max(Mass::new::<kilogram>(0.))
+        };
+    }
+
+    pub fn quantity(&self) -> Mass {
+        self.quantity
+    }
+
+    pub fn has_fuel_remaining(&self) -> bool {
+        self.unlimited_fuel || self.quantity > Mass::new::<kilogram>(0.)
+    }
+}
+impl SimulationElement for FuelTank {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.unlimited_fuel = reader.read(&self.unlimited_fuel_id);
+        self.quantity = reader.read(&self.quantity_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.quantity_request_id, self.requested_quantity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElementVisitor,
+    };
+    use ntest::assert_about_eq;
+    use std::time::Duration;
+    use uom::si::{mass::pound, mass_rate::kilogram_per_second};
+
+    struct TestAircraft {
+        tank: FuelTank,
+        fuel_flow: MassRate,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                tank: FuelTank::new(
+                    context,
+                    "FUEL TANK LEFT MAIN QUANTITY",
+                    "FUEL_TANK_LEFT_MAIN_QUANTITY_REQ",
+                ),
+                fuel_flow: MassRate::new::<kilogram_per_second>(0.),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.tank.update(context, self.fuel_flow);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tank.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(quantity_lb: f64, fuel_flow_kg_per_s: f64) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("FUEL TANK LEFT MAIN QUANTITY", quantity_lb);
+        test_bed
+            .command(|a| a.fuel_flow = MassRate::new::<kilogram_per_second>(fuel_flow_kg_per_s));
+
+        test_bed
+    }
+
+    #[test]
+    fn burnt_fuel_is_subtracted_from_the_requested_quantity() {
+        let mut test_bed = test_bed(1000., 0.1);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let requested: Mass = test_bed.read_by_name("FUEL_TANK_LEFT_MAIN_QUANTITY_REQ");
+        assert_about_eq!(
+            requested.get::<kilogram>(),
+            Mass::new::<pound>(1000.).get::<kilogram>() - 0.1
+        );
+    }
+
+    #[test]
+    fn no_fuel_is_burnt_with_unlimited_fuel() {
+        let mut test_bed = test_bed(1000., 0.1);
+        test_bed.write_by_name("UNLIMITED FUEL", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let requested: Mass = test_bed.read_by_name("FUEL_TANK_LEFT_MAIN_QUANTITY_REQ");
+        assert_about_eq!(requested.get::<pound>(), 1000.);
+    }
+
+    #[test]
+    fn requested_quantity_is_never_negative() {
+        let mut test_bed = test_bed(0.1, 1.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let requested: Mass = test_bed.read_by_name("FUEL_TANK_LEFT_MAIN_QUANTITY_REQ");
+        assert_about_eq!(requested.get::<pound>(), 0.);
+    }
+
+    #[test]
+    fn empty_tank_has_no_fuel_remaining_unless_fuel_is_unlimited() {
+        let mut test_bed = test_bed(0., 0.);
+        test_bed.run();
+        assert!(!test_bed.query(|a| a.tank.has_fuel_remaining()));
+
+        test_bed.write_by_name("UNLIMITED FUEL", true);
+        test_bed.run();
+        assert!(test_bed.query(|a| a.tank.has_fuel_remaining()));
+    }
+}
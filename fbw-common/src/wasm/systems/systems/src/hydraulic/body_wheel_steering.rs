@@ -0,0 +1,522 @@
+use crate::hydraulic::linear_actuator::Actuator;
+use crate::shared::{low_pass_filter::LowPassFilter, SectionPressure};
+use crate::simulation::{
+    InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+};
+
+use std::time::Duration;
+use uom::si::{
+    angle::{degree, radian},
+    angular_velocity::radian_per_second,
+    f64::*,
+    length::meter,
+    pressure::psi,
+    ratio::ratio,
+    volume::gallon,
+};
+
+pub trait BodyWheelSteeringController {
+    /// Angle requested to the body wheels. Only relevant while unlocking is requested.
+    fn requested_position(&self) -> Angle;
+
+    /// True when the controller wants the centering lock to be released
+    fn is_unlock_requested(&self) -> bool;
+}
+
+/// Hydraulic actuator steering a body gear.
+///
+/// When no unlocking is requested, the actuator drives the body wheels back to neutral position
+/// and engages its centering lock. Without hydraulic pressure, the internal centering springs
+/// slowly bring the wheels back to neutral where the lock engages.
+pub struct BodyWheelSteeringActuator {
+    position_id: VariableIdentifier,
+    locked_id: VariableIdentifier,
+
+    current_speed: LowPassFilter<AngularVelocity>,
+    current_position: Angle,
+
+    max_half_angle: Angle,
+
+    max_speed: LowPassFilter<AngularVelocity>,
+    nominal_speed: AngularVelocity,
+
+    angular_to_linear_ratio: Ratio,
+
+    is_locked: bool,
+
+    total_volume_to_actuator: Volume,
+    total_volume_to_reservoir: Volume,
+
+    actuator_area: Area,
+}
+impl BodyWheelSteeringActuator {
+    const MIN_PRESSURE_ALLOWING_STEERING_PSI: f64 = 1000.;
+
+    const REFERENCE_PRESS_FOR_NOMINAL_SPEED_PSI: f64 = 3000.;
+
+    const CENTERING_SPRING_SPEED_RAD_S: f64 = 0.02;
+
+    const LOCK_ENGAGEMENT_ANGLE_DEGREES: f64 = 0.2;
+
+    const MAX_SPEED_FILTER_TIMECONST: Duration = Duration::from_millis(100);
+    const CURRENT_SPEED_FILTER_TIMECONST: Duration = Duration::from_millis(150);
+
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        max_half_angle: Angle,
+        nominal_speed: AngularVelocity,
+        actuator_diameter: Length,
+        angular_to_linear_ratio: Ratio,
+    ) -> Self {
+        Self {
+            position_id: context.get_identifier(format!("{}_BODY_WHEEL_STEERING_POSITION", id)),
+            locked_id: context.get_identifier(format!("{}_BODY_WHEEL_STEERING_LOCKED", id)),
+
+            current_speed: LowPassFilter::<AngularVelocity>::new(
+                Self::CURRENT_SPEED_FILTER_TIMECONST,
+            ),
+            current_position: Angle::new::<radian>(0.),
+
+            max_half_angle,
+
+            max_speed: LowPassFilter::<AngularVelocity>::new(Self::MAX_SPEED_FILTER_TIMECONST),
+            nominal_speed,
+            angular_to_linear_ratio,
+
+            is_locked: true,
+
+            total_volume_to_actuator: Volume::new::<gallon>(0.),
+            total_volume_to_reservoir: Volume::new::<gallon>(0.),
+
+            actuator_area: std::f64::consts::PI
+                * (actuator_diameter / 2.)
+                * (actuator_diameter / 2.),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        section_pressure: &impl SectionPressure,
+        controller: &impl BodyWheelSteeringController,
+    ) {
+        let is_pressurised = section_pressure.pressure_downstream_priority_valve()
+            > Pressure::new::<psi>(Self::MIN_PRESSURE_ALLOWING_STEERING_PSI);
+
+        if self.is_locked && controller.is_unlock_requested() && is_pressurised {
+            self.is_locked = false;
+        }
+
+        if self.is_locked {
+            self.current_speed
+                .reset(AngularVelocity::new::<radian_per_second>(0.));
+            self.max_speed
+                .reset(AngularVelocity::new::<radian_per_second>(0.));
+            self.current_position = Angle::new::<radian>(0.);
+        } else {
+            self.update_max_speed(context, section_pressure);
+
+            let target_angle = if controller.is_unlock_requested() && is_pressurised {
+                controller
+                    .requested_position()
+                    .min(self.max_half_angle)
+                    .max(-self.max_half_angle)
+            } else {
+                Angle::new::<radian>(0.)
+            };
+
+            if is_pressurised {
+                self.update_current_speed(context, target_angle);
+            } else {
+                self.update_centering_spring_speed(context);
+            }
+
+            self.update_final_speed_position(context, target_angle);
+
+            self.update_lock(controller);
+        }
+
+        self.update_flow(context, is_pressurised);
+    }
+
+    fn update_lock(&mut self, controller: &impl BodyWheelSteeringController) {
+        if !controller.is_unlock_requested()
+            && self.current_position.abs()
+                < Angle::new::<degree>(Self::LOCK_ENGAGEMENT_ANGLE_DEGREES)
+        {
+            self.is_locked = true;
+            self.current_position = Angle::new::<radian>(0.);
+            self.current_speed
+                .reset(AngularVelocity::new::<radian_per_second>(0.));
+        }
+    }
+
+    fn update_final_speed_position(&mut self, context: &UpdateContext, target_angle: Angle) {
+        let previous_position = self.current_position;
+
+        self.current_position += Angle::new::<radian>(
+            self.current_speed.output().get::<radian_per_second>() * context.delta_as_secs_f64(),
+        );
+
+        // If we crossed target position between frames we assume we stopped at correct position
+        if (previous_position - target_angle).get::<radian>()
+            * (self.current_position - target_angle).get::<radian>()
+            <= 0.
+        {
+            self.current_speed
+                .reset(AngularVelocity::new::<radian_per_second>(0.));
+            self.current_position = target_angle;
+        }
+
+        self.current_position = self
+            .current_position
+            .min(self.max_half_angle)
+            .max(-self.max_half_angle);
+    }
+
+    fn update_current_speed(&mut self, context: &UpdateContext, target_angle: Angle) {
+        let signed_max_speed = if target_angle > self.current_position {
+            self.max_speed.output()
+        } else if target_angle < self.current_position {
+            -self.max_speed.output()
+        } else {
+            AngularVelocity::new::<radian_per_second>(0.)
+        };
+
+        self.current_speed.update(context.delta(), signed_max_speed);
+    }
+
+    fn update_centering_spring_speed(&mut self, context: &UpdateContext) {
+        let spring_speed =
+            AngularVelocity::new::<radian_per_second>(Self::CENTERING_SPRING_SPEED_RAD_S);
+
+        let signed_spring_speed = if self.current_position > Angle::new::<radian>(0.) {
+            -spring_speed
+        } else if self.current_position < Angle::new::<radian>(0.) {
+            spring_speed
+        } else {
+            AngularVelocity::new::<radian_per_second>(0.)
+        };
+
+        self.current_speed
+            .update(context.delta(), signed_spring_speed);
+    }
+
+    fn update_max_speed(
+        &mut self,
+        context: &UpdateContext,
+        section_pressure: &impl SectionPressure,
+    ) {
+        let current_pressure = section_pressure.pressure_downstream_priority_valve();
+
+        let new_max_speed =
+            if current_pressure.get::<psi>() > Self::MIN_PRESSURE_ALLOWING_STEERING_PSI {
+                (self.nominal_speed * current_pressure.get::<psi>().sqrt()
+                    / Self::REFERENCE_PRESS_FOR_NOMINAL_SPEED_PSI.sqrt())
+                .min(self.nominal_speed)
+            } else {
+                AngularVelocity::new::<radian_per_second>(0.)
+            };
+
+        self.max_speed.update(context.delta(), new_max_speed);
+    }
+
+    fn update_flow(&mut self, context: &UpdateContext, is_pressurised: bool) {
+        if is_pressurised {
+            let angular_position_delta_abs = Angle::new::<radian>(
+                self.current_speed.output().get::<radian_per_second>().abs()
+                    * context.delta_as_secs_f64(),
+            );
+
+            let linear_position_delta = Length::new::<meter>(
+                angular_position_delta_abs.get::<radian>()
+                    * self.angular_to_linear_ratio.get::<ratio>(),
+            );
+
+            self.total_volume_to_actuator = linear_position_delta * self.actuator_area;
+            self.total_volume_to_reservoir = linear_position_delta * self.actuator_area;
+        }
+    }
+
+    pub fn position_feedback(&self) -> Angle {
+        self.current_position
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.is_locked
+    }
+
+    fn position_normalized(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            self.current_position.get::<radian>() / self.max_half_angle.get::<radian>(),
+        )
+    }
+}
+impl Actuator for BodyWheelSteeringActuator {
+    fn used_volume(&self) -> Volume {
+        self.total_volume_to_actuator
+    }
+
+    fn reservoir_return(&self) -> Volume {
+        self.total_volume_to_reservoir
+    }
+
+    fn reset_volumes(&mut self) {
+        self.total_volume_to_reservoir = Volume::new::<gallon>(0.);
+        self.total_volume_to_actuator = Volume::new::<gallon>(0.);
+    }
+}
+impl SimulationElement for BodyWheelSteeringActuator {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.position_id, self.position_normalized().get::<ratio>());
+        writer.write(&self.locked_id, self.is_locked);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed};
+    use crate::simulation::{Aircraft, SimulationElement, SimulationElementVisitor};
+    use std::time::Duration;
+
+    struct TestBodyWheelSteeringController {
+        requested_position: Angle,
+        unlock_requested: bool,
+    }
+    impl TestBodyWheelSteeringController {
+        fn new() -> Self {
+            Self {
+                requested_position: Angle::new::<radian>(0.),
+                unlock_requested: false,
+            }
+        }
+
+        fn command(&mut self, requested_position: Angle, unlock_requested: bool) {
+            self.requested_position = requested_position;
+            self.unlock_requested = unlock_requested;
+        }
+    }
+    impl BodyWheelSteeringController for TestBodyWheelSteeringController {
+        fn requested_position(&self) -> Angle {
+            self.requested_position
+        }
+
+        fn is_unlock_requested(&self) -> bool {
+            self.unlock_requested
+        }
+    }
+
+    #[derive(Default)]
+    struct TestHydraulicSection {
+        pressure: Pressure,
+    }
+    impl SectionPressure for TestHydraulicSection {
+        fn pressure(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_leak_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_priority_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn is_pressure_switch_pressurised(&self) -> bool {
+            self.pressure.get::<psi>() > 1700.
+        }
+    }
+
+    struct TestAircraft {
+        actuator: BodyWheelSteeringActuator,
+        controller: TestBodyWheelSteeringController,
+        pressure: TestHydraulicSection,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                actuator: BodyWheelSteeringActuator::new(
+                    context,
+                    "LEFT",
+                    Angle::new::<degree>(15.),
+                    AngularVelocity::new::<radian_per_second>(0.1),
+                    Length::new::<meter>(0.05),
+                    Ratio::new::<ratio>(0.15),
+                ),
+                controller: TestBodyWheelSteeringController::new(),
+                pressure: TestHydraulicSection::default(),
+            }
+        }
+
+        fn set_pressure(&mut self, pressure: Pressure) {
+            self.pressure.pressure = pressure;
+        }
+
+        fn command(&mut self, angle: Angle, unlock: bool) {
+            self.controller.command(angle, unlock);
+        }
+
+        fn position(&self) -> Angle {
+            self.actuator.position_feedback()
+        }
+
+        fn is_locked(&self) -> bool {
+            self.actuator.is_locked()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.actuator
+                .update(context, &self.pressure, &self.controller);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.actuator.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    #[test]
+    fn writes_its_states() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("LEFT_BODY_WHEEL_STEERING_POSITION"));
+        assert!(test_bed.contains_variable_with_name("LEFT_BODY_WHEEL_STEERING_LOCKED"));
+    }
+
+    #[test]
+    fn init_locked_at_neutral() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.is_locked()));
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.position()),
+            Angle::new::<degree>(0.)
+        ));
+
+        let is_locked: bool = test_bed.read_by_name("LEFT_BODY_WHEEL_STEERING_LOCKED");
+        assert!(is_locked);
+    }
+
+    #[test]
+    fn stays_locked_without_pressure() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.command(Angle::new::<degree>(10.), true));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.is_locked()));
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.position()),
+            Angle::new::<degree>(0.)
+        ));
+    }
+
+    #[test]
+    fn stays_locked_with_pressure_without_unlock_request() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(5000.)));
+        test_bed.command(|a| a.command(Angle::new::<degree>(10.), false));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.is_locked()));
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.position()),
+            Angle::new::<degree>(0.)
+        ));
+    }
+
+    #[test]
+    fn moves_to_requested_position_when_unlocked_with_pressure() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(5000.)));
+        test_bed.command(|a| a.command(Angle::new::<degree>(-10.), true));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.is_locked()));
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.position()),
+            Angle::new::<degree>(-10.)
+        ));
+    }
+
+    #[test]
+    fn position_limited_to_max_half_angle() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(5000.)));
+        test_bed.command(|a| a.command(Angle::new::<degree>(40.), true));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.position()),
+            Angle::new::<degree>(15.)
+        ));
+
+        let normalized_position: f64 = test_bed.read_by_name("LEFT_BODY_WHEEL_STEERING_POSITION");
+        assert!((normalized_position - 1.).abs() < 0.001);
+    }
+
+    #[test]
+    fn centers_and_locks_when_unlock_request_removed() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(5000.)));
+        test_bed.command(|a| a.command(Angle::new::<degree>(10.), true));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.is_locked()));
+
+        test_bed.command(|a| a.command(Angle::new::<degree>(10.), false));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.is_locked()));
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.position()),
+            Angle::new::<degree>(0.)
+        ));
+    }
+
+    #[test]
+    fn centering_springs_bring_wheels_back_slowly_on_pressure_loss() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(5000.)));
+        test_bed.command(|a| a.command(Angle::new::<degree>(10.), true));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(0.)));
+        test_bed.command(|a| a.command(Angle::new::<degree>(10.), false));
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        let position_after_2s = test_bed.query(|a| a.position());
+        assert!(position_after_2s < Angle::new::<degree>(10.));
+        assert!(position_after_2s > Angle::new::<degree>(5.));
+        assert!(!test_bed.query(|a| a.is_locked()));
+
+        test_bed.run_multiple_frames(Duration::from_secs(20));
+
+        assert!(test_bed.query(|a| a.is_locked()));
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.position()),
+            Angle::new::<degree>(0.)
+        ));
+    }
+
+    fn is_equal_angle(a1: Angle, a2: Angle) -> bool {
+        const EPSILON_DEGREE: f64 = 0.1;
+
+        (a1 - a2).abs() <= Angle::new::<degree>(EPSILON_DEGREE)
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     shared::{random_from_normal_distribution, SectionPressure},
     simulation::{
         InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        VariableIdentifier, VariableMetadata, Write,
     },
 };
 
@@ -53,7 +53,10 @@ impl HydraulicDoorController {
 
     pub fn new(context: &mut InitContext, id: &str) -> Self {
         Self {
-            requested_position_id: context.get_identifier(format!("{}_DOOR_CARGO_OPEN_REQ", id)),
+            requested_position_id: context.get_identifier_with_metadata(
+                format!("{}_DOOR_CARGO_OPEN_REQ", id),
+                VariableMetadata::new().written_externally(),
+            ),
             control_state: DoorControlState::DownLocked,
             position_requested: Ratio::new::<ratio>(0.),
 
@@ -75,14 +78,20 @@ impl HydraulicDoorController {
         ))
     }
 
+    /// While the differential pressure across the fuselage inhibits the door from being opened,
+    /// the request to open a locked door is ignored.
     pub fn update(
         &mut self,
         context: &UpdateContext,
         door: &CargoDoor,
         current_pressure: &impl SectionPressure,
+        opening_inhibited: bool,
     ) {
-        self.control_state =
-            self.determine_control_state_and_lock_action(door, current_pressure.pressure());
+        self.control_state = self.determine_control_state_and_lock_action(
+            door,
+            current_pressure.pressure(),
+            opening_inhibited,
+        );
         self.update_timers(context);
         self.update_actions_from_state();
     }
@@ -127,9 +136,12 @@ impl HydraulicDoorController {
         &mut self,
         door: &CargoDoor,
         current_pressure: Pressure,
+        opening_inhibited: bool,
     ) -> DoorControlState {
         match self.control_state {
-            DoorControlState::DownLocked if self.position_requested > Ratio::new::<ratio>(0.) => {
+            DoorControlState::DownLocked
+                if self.position_requested > Ratio::new::<ratio>(0.) && !opening_inhibited =>
+            {
                 self.should_unlock = true;
                 DoorControlState::NoControl
             }
@@ -232,7 +244,7 @@ impl CargoDoor {
         }
     }
 
-    fn position(&self) -> Ratio {
+    pub fn position(&self) -> Ratio {
         self.position
     }
 
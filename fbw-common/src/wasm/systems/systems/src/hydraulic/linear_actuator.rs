@@ -26,7 +26,7 @@ use crate::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        UpdateContext, VariableIdentifier,
+        UpdateContext, VariableIdentifier, VariableMetadata,
     },
 };
 
@@ -464,7 +464,10 @@ impl CoreHydraulicForce {
         let min_force = -max_working_pressure * rod_side_area;
 
         Self {
-            dev_gains_tuning_enable_id: context.get_identifier("DEV_HYD_GAINS_TUNING".to_owned()),
+            dev_gains_tuning_enable_id: context.get_identifier_with_metadata(
+                "DEV_HYD_GAINS_TUNING".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             test_p_gain_id: context.get_identifier("DEV_P_GAIN".to_owned()),
             test_i_gain_id: context.get_identifier("DEV_I_GAIN".to_owned()),
             test_force_gain_id: context.get_identifier("DEV_FORCE_GAIN".to_owned()),
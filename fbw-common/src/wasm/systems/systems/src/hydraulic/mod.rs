@@ -15,7 +15,7 @@ use crate::shared::{
 };
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
 };
 use nalgebra::Vector3;
 
@@ -33,6 +33,7 @@ use uom::si::{
 };
 
 pub mod aerodynamic_model;
+pub mod body_wheel_steering;
 pub mod brake_circuit;
 pub mod cargo_doors;
 pub mod electrical_generator;
@@ -398,9 +399,15 @@ impl PowerTransferUnit {
         Self {
             valve_opened_id: context.get_identifier("HYD_PTU_VALVE_OPENED".to_owned()),
             shaft_rpm_id: context.get_identifier("HYD_PTU_SHAFT_RPM".to_owned()),
-            dev_delta_pressure: context.get_identifier("HYD_PTU_DEV_DEACTIVATION_DELTA".to_owned()),
+            dev_delta_pressure: context.get_identifier_with_metadata(
+                "HYD_PTU_DEV_DEACTIVATION_DELTA".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             bark_strength_id: context.get_identifier("HYD_PTU_BARK_STRENGTH".to_owned()),
-            dev_efficiency_id: context.get_identifier("HYD_PTU_DEV_EFFICIENCY".to_owned()),
+            dev_efficiency_id: context.get_identifier_with_metadata(
+                "HYD_PTU_DEV_EFFICIENCY".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
 
             is_enabled: false,
             is_active_right: false,
@@ -3231,10 +3238,11 @@ impl SimulationElement for HydraulicValve {
 
 #[cfg(test)]
 mod tests {
+    use crate::simulation::property::{PropertyTest, ScenarioGenerator};
     use crate::simulation::test::{
-        ElementCtorFn, ReadByName, SimulationTestBed, TestBed, WriteByName,
+        ElementCtorFn, ReadByName, SimulationTestBed, TestAircraft, TestBed, WriteByName,
     };
-    use crate::simulation::InitContext;
+    use crate::simulation::{Aircraft, InitContext};
     use ntest::assert_about_eq;
 
     use uom::si::{f64::*, pressure::psi, ratio::percent, volume::gallon};
@@ -3476,6 +3484,301 @@ mod tests {
         assert!(volume_after_leak_gallon == 0.);
     }
 
+    fn closed_loop_reservoir_test_bed() -> SimulationTestBed<TestAircraft<Reservoir>> {
+        SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(4.),
+                Volume::new::<gallon>(3.),
+            )
+        }))
+        .with_update_after_power_distribution(|reservoir, context| {
+            let flow = reservoir.try_take_flow(context, VolumeRate::new::<gallon_per_second>(1.));
+            reservoir.add_return_volume(flow * context.delta_as_time());
+            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+        })
+    }
+
+    #[test]
+    fn reservoir_in_closed_loop_conserves_fluid_at_any_frame_rate() {
+        PropertyTest::new(
+            ScenarioGenerator::new(Duration::from_secs(30)).with_flight_conditions(0.05),
+        )
+        .check(closed_loop_reservoir_test_bed, |test_bed| {
+            let level = test_bed.query_element(|r| r.fluid_level_real().get::<gallon>());
+            if (level - 3.).abs() < 1e-9 {
+                Ok(())
+            } else {
+                Err(format!("fluid level changed to {} gallon", level))
+            }
+        });
+    }
+
+    #[test]
+    fn reservoir_level_stays_within_capacity_with_random_leaks() {
+        PropertyTest::new(
+            ScenarioGenerator::new(Duration::from_secs(60)).with_failures(
+                &[
+                    FailureType::ReservoirLeak(HydraulicColor::Green),
+                    FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+                ],
+                0.02,
+            ),
+        )
+        .check(closed_loop_reservoir_test_bed, |test_bed| {
+            let level = test_bed.query_element(|r| r.fluid_level_real().get::<gallon>());
+            if (0. ..=5.).contains(&level) {
+                Ok(())
+            } else {
+                Err(format!("fluid level out of range at {} gallon", level))
+            }
+        });
+    }
+
+    struct TestPumpController {
+        should_pressurise: bool,
+    }
+    impl PumpController for TestPumpController {
+        fn should_pressurise(&self) -> bool {
+            self.should_pressurise
+        }
+    }
+
+    struct TestHydraulicCircuitController {}
+    impl HydraulicCircuitController for TestHydraulicCircuitController {
+        fn should_open_fire_shutoff_valve(&self, _: usize) -> bool {
+            true
+        }
+
+        fn should_open_leak_measurement_valve(&self) -> bool {
+            true
+        }
+    }
+
+    /// Fills its chamber from the section while it is pressurised, and empties it into the
+    /// reservoir once full, like an actuator moving back and forth between its end stops.
+    struct TestConsumer {
+        chamber_volume: Volume,
+        is_filling: bool,
+        used_volume: Volume,
+        returned_volume: Volume,
+    }
+    impl TestConsumer {
+        const FLOW_GAL_PER_S: f64 = 0.2;
+        const CHAMBER_VOLUME_GAL: f64 = 0.5;
+        const MIN_PRESSURE_PSI: f64 = 1000.;
+
+        fn new() -> Self {
+            Self {
+                chamber_volume: Volume::default(),
+                is_filling: true,
+                used_volume: Volume::default(),
+                returned_volume: Volume::default(),
+            }
+        }
+
+        fn update(&mut self, context: &UpdateContext, section_pressure: Pressure) {
+            let max_chamber_volume = Volume::new::<gallon>(Self::CHAMBER_VOLUME_GAL);
+            let volume = VolumeRate::new::<gallon_per_second>(Self::FLOW_GAL_PER_S)
+                * context.delta_as_time();
+
+            if self.is_filling {
+                if section_pressure.get::<psi>() > Self::MIN_PRESSURE_PSI {
+                    let used = volume.min(max_chamber_volume - self.chamber_volume);
+                    self.chamber_volume += used;
+                    self.used_volume += used;
+                }
+                self.is_filling = self.chamber_volume < max_chamber_volume;
+            } else {
+                let returned = volume.min(self.chamber_volume);
+                self.chamber_volume -= returned;
+                self.returned_volume += returned;
+                self.is_filling = self.chamber_volume <= Volume::default();
+            }
+        }
+    }
+    impl Actuator for TestConsumer {
+        fn used_volume(&self) -> Volume {
+            self.used_volume
+        }
+
+        fn reservoir_return(&self) -> Volume {
+            self.returned_volume
+        }
+
+        fn reset_volumes(&mut self) {
+            self.used_volume = Volume::default();
+            self.returned_volume = Volume::default();
+        }
+    }
+
+    struct TestHydraulicCircuitAircraft {
+        circuit: HydraulicCircuit,
+        pump: EngineDrivenPump,
+        pump_controller: TestPumpController,
+        consumer: TestConsumer,
+        initial_fluid_volume: Volume,
+    }
+    impl TestHydraulicCircuitAircraft {
+        const PUMP_SPEED_RPM: f64 = 4000.;
+
+        fn new(context: &mut InitContext) -> Self {
+            let mut aircraft = Self {
+                circuit: hydraulic_circuit(context, HydraulicColor::Green, 1),
+                pump: engine_driven_pump(context),
+                pump_controller: TestPumpController {
+                    should_pressurise: false,
+                },
+                consumer: TestConsumer::new(),
+                initial_fluid_volume: Volume::default(),
+            };
+            aircraft.initial_fluid_volume = aircraft.total_fluid_volume();
+
+            aircraft
+        }
+
+        /// The fluid in the reservoir, the sections, the accumulator and the consumer.
+        fn total_fluid_volume(&self) -> Volume {
+            let sections_volume = self
+                .circuit
+                .pump_sections
+                .iter()
+                .fold(self.circuit.system_section.current_volume, |volume, s| {
+                    volume + s.current_volume
+                });
+
+            self.circuit.reservoir.fluid_level_real()
+                + sections_volume
+                + self.circuit.system_accumulator_fluid_volume()
+                + self.consumer.chamber_volume
+        }
+
+        fn pressures(&self) -> Vec<Pressure> {
+            let mut pressures = vec![
+                self.circuit.pump_pressure(0),
+                self.circuit.system_section_pressure(),
+            ];
+            if let Some(accumulator) = &self.circuit.system_section.accumulator {
+                pressures.push(accumulator.gas_pressure);
+            }
+
+            pressures
+        }
+    }
+    impl Aircraft for TestHydraulicCircuitAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            // The pump only pressurises in flight, so that scenarios changing the flight
+            // conditions alternate between pressurising and depressurising the circuit.
+            self.pump_controller.should_pressurise = !context.is_on_ground();
+
+            self.pump.update(
+                context,
+                self.circuit.pump_section(0),
+                self.circuit.reservoir(),
+                AngularVelocity::new::<revolution_per_minute>(Self::PUMP_SPEED_RPM),
+                &self.pump_controller,
+            );
+
+            self.consumer
+                .update(context, self.circuit.system_section_pressure());
+            self.circuit
+                .update_system_actuator_volumes(&mut self.consumer);
+
+            self.circuit.update(
+                context,
+                &mut [&mut self.pump],
+                None::<&mut ElectricPump>,
+                None::<&mut ElectricPump>,
+                None,
+                &TestHydraulicCircuitController {},
+                Pressure::new::<psi>(50.),
+            );
+        }
+    }
+    impl SimulationElement for TestHydraulicCircuitAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.circuit.accept(visitor);
+            self.pump.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn hydraulic_circuit_test_bed() -> SimulationTestBed<TestHydraulicCircuitAircraft> {
+        SimulationTestBed::new(TestHydraulicCircuitAircraft::new)
+    }
+
+    #[test]
+    fn circuit_with_pump_and_consumer_pressurises_in_flight() {
+        let mut test_bed = hydraulic_circuit_test_bed();
+        test_bed.set_on_ground(false);
+
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(
+            test_bed.query(|a| a.circuit.system_section_pressure()) > Pressure::new::<psi>(2500.)
+        );
+    }
+
+    #[test]
+    fn circuit_with_pump_and_consumer_conserves_fluid() {
+        PropertyTest::new(
+            ScenarioGenerator::new(Duration::from_secs(60))
+                .with_failures(
+                    &[FailureType::EnginePumpOverheat(
+                        AirbusEngineDrivenPumpId::Green,
+                    )],
+                    0.02,
+                )
+                .with_flight_conditions(0.05),
+        )
+        .check(hydraulic_circuit_test_bed, |test_bed| {
+            let (initial, current) = test_bed.query(|a| {
+                (
+                    a.initial_fluid_volume.get::<gallon>(),
+                    a.total_fluid_volume().get::<gallon>(),
+                )
+            });
+            if (current - initial).abs() < 1e-6 {
+                Ok(())
+            } else {
+                Err(format!(
+                    "fluid volume changed from {} to {} gallon",
+                    initial, current
+                ))
+            }
+        });
+    }
+
+    #[test]
+    fn circuit_with_pump_and_consumer_has_no_negative_pressure() {
+        PropertyTest::new(
+            ScenarioGenerator::new(Duration::from_secs(60))
+                .with_failures(
+                    &[
+                        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
+                        FailureType::ReservoirLeak(HydraulicColor::Green),
+                        FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+                    ],
+                    0.02,
+                )
+                .with_flight_conditions(0.05),
+        )
+        .check(hydraulic_circuit_test_bed, |test_bed| {
+            let pressures = test_bed.query(|a| a.pressures());
+            if pressures.iter().all(|p| *p >= Pressure::default()) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "negative pressure in {:?} psi",
+                    pressures.iter().map(|p| p.get::<psi>()).collect::<Vec<_>>()
+                ))
+            }
+        });
+    }
+
     #[test]
     fn reservoir_empty_has_level_switch_reporting_empty() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
@@ -4,7 +4,7 @@ use crate::{
     shared::{low_pass_filter::LowPassFilter, DelayedFalseLogicGate},
     simulation::{
         InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        VariableIdentifier, VariableMetadata, Write,
     },
 };
 use std::time::Duration;
@@ -39,8 +39,14 @@ impl PushbackTug {
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             nw_strg_disc_memo_id: context.get_identifier("HYD_NW_STRG_DISC_ECAM_MEMO".to_owned()),
-            state_id: context.get_identifier("PUSHBACK STATE".to_owned()),
-            steer_angle_id: context.get_identifier("PUSHBACK ANGLE".to_owned()),
+            state_id: context.get_identifier_with_metadata(
+                "PUSHBACK STATE".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            steer_angle_id: context.get_identifier_with_metadata(
+                "PUSHBACK ANGLE".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
 
             steering_angle_raw: Angle::default(),
             steering_angle: LowPassFilter::new(Self::STEERING_ANGLE_FILTER_TIME_CONSTANT),
@@ -0,0 +1,93 @@
+use crate::{
+    engine::Engine,
+    failures::{Failure, FailureType},
+    shared::{ConsumePower, ElectricalBusType, ElectricalBuses, LgciuWeightOnWheels},
+    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+use uom::si::{f64::*, power::watt, ratio::ratio};
+
+mod probe_heat_computer;
+#[cfg(test)]
+mod test;
+mod window_heat_computer;
+
+pub use probe_heat_computer::ProbeHeatComputer;
+pub use window_heat_computer::WindowHeatComputer;
+
+/// Probe and window heating is switched on automatically as soon as one engine
+/// runs or the aircraft is in flight. The crew can force it on using the
+/// PROBE/WINDOW HEAT push button.
+fn heating_is_required<const N: usize>(
+    engines: [&impl Engine; N],
+    lgciu: &impl LgciuWeightOnWheels,
+    manually_selected_on: bool,
+) -> bool {
+    manually_selected_on
+        || !is_on_ground(lgciu)
+        || engines.iter().any(|engine| engine.is_above_minimum_idle())
+}
+
+fn is_on_ground(lgciu: &impl LgciuWeightOnWheels) -> bool {
+    lgciu.left_and_right_gear_compressed(false)
+}
+
+/// A resistive heating element. It heats at the level demanded by its computer
+/// for as long as its bus is powered and the element hasn't failed.
+struct Heater {
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    failure: Failure,
+    rated_power: Power,
+    demanded_level: Ratio,
+}
+impl Heater {
+    fn new(powered_by: ElectricalBusType, rated_power: Power, failure_type: FailureType) -> Self {
+        Self {
+            powered_by,
+            is_powered: false,
+            failure: Failure::new(failure_type),
+            rated_power,
+            demanded_level: Ratio::new::<ratio>(0.),
+        }
+    }
+
+    fn demand(&mut self, level: Ratio) {
+        self.demanded_level = level;
+    }
+
+    fn heating_level(&self) -> Ratio {
+        if self.is_powered && !self.failure.is_active() {
+            self.demanded_level
+        } else {
+            Ratio::new::<ratio>(0.)
+        }
+    }
+
+    /// The computer monitors the current drawn by the element, and thus detects
+    /// an element which doesn't heat while it should.
+    fn has_fault(&self) -> bool {
+        self.demanded_level > Ratio::new::<ratio>(0.)
+            && self.heating_level() <= Ratio::new::<ratio>(0.)
+    }
+}
+impl SimulationElement for Heater {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        let level = self.heating_level().get::<ratio>();
+        if level > 0. {
+            consumption.consume_from_bus(
+                self.powered_by,
+                Power::new::<watt>(self.rated_power.get::<watt>() * level),
+            );
+        }
+    }
+}
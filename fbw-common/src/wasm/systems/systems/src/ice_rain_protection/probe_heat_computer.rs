@@ -0,0 +1,409 @@
+use super::{heating_is_required, is_on_ground, Heater};
+use crate::{
+    engine::Engine,
+    failures::FailureType,
+    shared::{
+        ConsumePower, ElectricalBusType, ElectricalBuses, HeatedProbe, HeatedProbes,
+        LgciuWeightOnWheels,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use uom::si::{f64::*, power::watt, ratio::ratio};
+
+struct ProbeHeater {
+    probe: HeatedProbe,
+    heat_fault_id: VariableIdentifier,
+    heater: Heater,
+}
+
+/// The probe heat computer (PHC) controls and monitors the heating of one set of air data
+/// probes: the pitot probe, the static ports, the angle of attack sensor and, where fitted,
+/// the total air temperature probe.
+pub struct ProbeHeatComputer {
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    heaters: Vec<ProbeHeater>,
+}
+impl ProbeHeatComputer {
+    const COMPUTER_POWER_WATT: f64 = 10.;
+
+    const PITOT_POWER_WATT: f64 = 300.;
+    const STATIC_PORTS_POWER_WATT: f64 = 100.;
+    const ANGLE_OF_ATTACK_POWER_WATT: f64 = 350.;
+    const TOTAL_AIR_TEMPERATURE_POWER_WATT: f64 = 250.;
+
+    // On ground the pitot probe is only heated at a low level to avoid overheating it.
+    const PITOT_ON_GROUND_HEATING_LEVEL: f64 = 0.3;
+
+    /// Creates a computer for the given probe set. Each heated probe comes with the bus
+    /// which powers its heating element.
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        powered_by: ElectricalBusType,
+        heaters: &[(HeatedProbe, ElectricalBusType)],
+    ) -> Self {
+        Self {
+            powered_by,
+            is_powered: false,
+            heaters: heaters
+                .iter()
+                .map(|&(probe, heater_powered_by)| ProbeHeater {
+                    probe,
+                    heat_fault_id: context
+                        .get_identifier(format!("PHC_{}_{}_HEAT_FAULT", number, probe)),
+                    heater: Heater::new(
+                        heater_powered_by,
+                        Self::rated_power_of(probe),
+                        FailureType::ProbeHeater(number, probe),
+                    ),
+                })
+                .collect(),
+        }
+    }
+
+    fn rated_power_of(probe: HeatedProbe) -> Power {
+        Power::new::<watt>(match probe {
+            HeatedProbe::Pitot => Self::PITOT_POWER_WATT,
+            HeatedProbe::StaticPorts => Self::STATIC_PORTS_POWER_WATT,
+            HeatedProbe::AngleOfAttack => Self::ANGLE_OF_ATTACK_POWER_WATT,
+            HeatedProbe::TotalAirTemperature => Self::TOTAL_AIR_TEMPERATURE_POWER_WATT,
+        })
+    }
+
+    pub fn update<const N: usize>(
+        &mut self,
+        engines: [&impl Engine; N],
+        lgciu: &impl LgciuWeightOnWheels,
+        manually_selected_on: bool,
+    ) {
+        let is_heating =
+            self.is_powered && heating_is_required(engines, lgciu, manually_selected_on);
+        let on_ground = is_on_ground(lgciu);
+
+        for probe_heater in self.heaters.iter_mut() {
+            let level = match probe_heater.probe {
+                _ if !is_heating => 0.,
+                HeatedProbe::Pitot if on_ground => Self::PITOT_ON_GROUND_HEATING_LEVEL,
+                // The TAT probe is aspirated by engine bleed air on ground and thus not heated.
+                HeatedProbe::TotalAirTemperature if on_ground => 0.,
+                _ => 1.,
+            };
+
+            probe_heater.heater.demand(Ratio::new::<ratio>(level));
+        }
+    }
+
+    /// Indicates a heating fault of the given probe. Without power the computer cannot
+    /// monitor its probes and thus reports all of them as faulty.
+    pub fn has_fault(&self, probe: HeatedProbe) -> bool {
+        !self.is_powered
+            || self
+                .heaters
+                .iter()
+                .any(|probe_heater| probe_heater.probe == probe && probe_heater.heater.has_fault())
+    }
+}
+impl HeatedProbes for ProbeHeatComputer {
+    fn probe_heating(&self, probe: HeatedProbe) -> Ratio {
+        self.heaters
+            .iter()
+            .find(|probe_heater| probe_heater.probe == probe)
+            .map_or_else(
+                || Ratio::new::<ratio>(0.),
+                |probe_heater| probe_heater.heater.heating_level(),
+            )
+    }
+}
+impl SimulationElement for ProbeHeatComputer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.heaters
+            .iter_mut()
+            .for_each(|probe_heater| probe_heater.heater.accept(visitor));
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for probe_heater in &self.heaters {
+            writer.write(
+                &probe_heater.heat_fault_id,
+                self.has_fault(probe_heater.probe),
+            );
+        }
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_powered {
+            consumption.consume_from_bus(
+                self.powered_by,
+                Power::new::<watt>(Self::COMPUTER_POWER_WATT),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        engine::leap_engine::LeapEngine,
+        ice_rain_protection::test::TestLgciu,
+        shared::{PotentialOrigin, PowerConsumptionReport},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use ntest::assert_about_eq;
+
+    struct TestAircraft {
+        dc_source: TestElectricitySource,
+        ac_source: TestElectricitySource,
+        dc_bus: ElectricalBus,
+        ac_bus: ElectricalBus,
+        phc: ProbeHeatComputer,
+        engines: [LeapEngine; 2],
+        lgciu: TestLgciu,
+        manually_selected_on: bool,
+        ac_consumption: Power,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                dc_source: TestElectricitySource::powered(context, PotentialOrigin::Battery(1)),
+                ac_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                ac_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                phc: ProbeHeatComputer::new(
+                    context,
+                    1,
+                    ElectricalBusType::DirectCurrent(1),
+                    &[
+                        (HeatedProbe::Pitot, ElectricalBusType::AlternatingCurrent(1)),
+                        (
+                            HeatedProbe::StaticPorts,
+                            ElectricalBusType::AlternatingCurrent(1),
+                        ),
+                        (
+                            HeatedProbe::AngleOfAttack,
+                            ElectricalBusType::AlternatingCurrent(1),
+                        ),
+                        (
+                            HeatedProbe::TotalAirTemperature,
+                            ElectricalBusType::AlternatingCurrent(1),
+                        ),
+                    ],
+                ),
+                engines: [LeapEngine::new(context, 1), LeapEngine::new(context, 2)],
+                lgciu: TestLgciu::new(true),
+                manually_selected_on: false,
+                ac_consumption: Power::new::<watt>(0.),
+            }
+        }
+
+        fn set_in_flight(&mut self) {
+            self.lgciu.set_compressed(false);
+        }
+
+        fn select_on(&mut self) {
+            self.manually_selected_on = true;
+        }
+
+        fn unpower_computer(&mut self) {
+            self.dc_source.unpower();
+        }
+
+        fn unpower_heaters(&mut self) {
+            self.ac_source.unpower();
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.dc_source);
+            electricity.supplied_by(&self.ac_source);
+            electricity.flow(&self.dc_source, &self.dc_bus);
+            electricity.flow(&self.ac_source, &self.ac_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+            self.phc.update(
+                [&self.engines[0], &self.engines[1]],
+                &self.lgciu,
+                self.manually_selected_on,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.phc.accept(visitor);
+            self.engines[0].accept(visitor);
+            self.engines[1].accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn process_power_consumption_report<T: PowerConsumptionReport>(
+            &mut self,
+            _: &UpdateContext,
+            report: &T,
+        ) {
+            self.ac_consumption = report.total_consumption_of(PotentialOrigin::EngineGenerator(1));
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn heating(test_bed: &SimulationTestBed<TestAircraft>, probe: HeatedProbe) -> f64 {
+        test_bed.query(|a| a.phc.probe_heating(probe).get::<ratio>())
+    }
+
+    fn ac_consumption(test_bed: &SimulationTestBed<TestAircraft>) -> f64 {
+        test_bed.query(|a| a.ac_consumption.get::<watt>())
+    }
+
+    #[test]
+    fn probes_are_not_heated_on_ground_with_engines_off() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        test_bed.run();
+
+        assert_about_eq!(heating(&test_bed, HeatedProbe::Pitot), 0.);
+        assert_about_eq!(ac_consumption(&test_bed), 0.);
+    }
+
+    #[test]
+    fn probes_are_heated_when_an_engine_runs() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ENGINE_N2:2", 70.);
+        test_bed.run();
+
+        assert!(heating(&test_bed, HeatedProbe::Pitot) > 0.);
+        assert_about_eq!(heating(&test_bed, HeatedProbe::AngleOfAttack), 1.);
+    }
+
+    #[test]
+    fn pitot_is_heated_at_low_level_and_tat_not_heated_on_ground() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.select_on());
+        test_bed.run();
+
+        assert_about_eq!(
+            heating(&test_bed, HeatedProbe::Pitot),
+            ProbeHeatComputer::PITOT_ON_GROUND_HEATING_LEVEL
+        );
+        assert_about_eq!(heating(&test_bed, HeatedProbe::TotalAirTemperature), 0.);
+        assert_about_eq!(heating(&test_bed, HeatedProbe::StaticPorts), 1.);
+    }
+
+    #[test]
+    fn all_probes_are_fully_heated_in_flight() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_in_flight());
+        test_bed.run();
+
+        for probe in [
+            HeatedProbe::Pitot,
+            HeatedProbe::StaticPorts,
+            HeatedProbe::AngleOfAttack,
+            HeatedProbe::TotalAirTemperature,
+        ] {
+            assert_about_eq!(heating(&test_bed, probe), 1.);
+        }
+    }
+
+    #[test]
+    fn heaters_consume_their_rated_power_in_flight() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_in_flight());
+        test_bed.run();
+        test_bed.run();
+
+        assert_about_eq!(ac_consumption(&test_bed), 1000.);
+    }
+
+    #[test]
+    fn failed_heater_does_not_heat_and_is_reported() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_in_flight());
+        test_bed.fail(FailureType::ProbeHeater(1, HeatedProbe::Pitot));
+        test_bed.run();
+
+        assert_about_eq!(heating(&test_bed, HeatedProbe::Pitot), 0.);
+        let pitot_fault: bool = test_bed.read_by_name("PHC_1_PITOT_HEAT_FAULT");
+        assert!(pitot_fault);
+        let aoa_fault: bool = test_bed.read_by_name("PHC_1_AOA_HEAT_FAULT");
+        assert!(!aoa_fault);
+    }
+
+    #[test]
+    fn heater_failure_is_not_reported_when_heating_isnt_required() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::ProbeHeater(1, HeatedProbe::Pitot));
+        test_bed.run();
+
+        let pitot_fault: bool = test_bed.read_by_name("PHC_1_PITOT_HEAT_FAULT");
+        assert!(!pitot_fault);
+    }
+
+    #[test]
+    fn unpowered_heaters_are_reported() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_in_flight());
+        test_bed.command(|a| a.unpower_heaters());
+        test_bed.run();
+
+        assert_about_eq!(heating(&test_bed, HeatedProbe::AngleOfAttack), 0.);
+        let aoa_fault: bool = test_bed.read_by_name("PHC_1_AOA_HEAT_FAULT");
+        assert!(aoa_fault);
+    }
+
+    #[test]
+    fn unpowered_computer_does_not_heat_its_probes() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_in_flight());
+        test_bed.command(|a| a.unpower_computer());
+        test_bed.run();
+
+        assert_about_eq!(heating(&test_bed, HeatedProbe::Pitot), 0.);
+        let static_fault: bool = test_bed.read_by_name("PHC_1_STATIC_HEAT_FAULT");
+        assert!(static_fault);
+    }
+
+    #[test]
+    fn probe_without_heater_is_not_heated() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let mut aircraft = TestAircraft::new(context);
+            aircraft.phc = ProbeHeatComputer::new(
+                context,
+                3,
+                ElectricalBusType::DirectCurrent(1),
+                &[(HeatedProbe::Pitot, ElectricalBusType::AlternatingCurrent(1))],
+            );
+            aircraft
+        });
+        test_bed.command(|a| a.set_in_flight());
+        test_bed.run();
+
+        assert_about_eq!(heating(&test_bed, HeatedProbe::Pitot), 1.);
+        assert_about_eq!(heating(&test_bed, HeatedProbe::TotalAirTemperature), 0.);
+        assert!(!test_bed.contains_variable_with_name("PHC_3_TAT_HEAT_FAULT"));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::shared::LgciuWeightOnWheels;
+
+/// Reports all gears as either compressed or extended.
+pub(super) struct TestLgciu {
+    compressed: bool,
+}
+impl TestLgciu {
+    pub(super) fn new(compressed: bool) -> Self {
+        Self { compressed }
+    }
+
+    pub(super) fn set_compressed(&mut self, compressed: bool) {
+        self.compressed = compressed;
+    }
+}
+impl LgciuWeightOnWheels for TestLgciu {
+    fn left_and_right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+        self.compressed
+    }
+    fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+        self.compressed
+    }
+    fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+        !self.compressed
+    }
+    fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+        self.compressed
+    }
+    fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+        !self.compressed
+    }
+    fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+        !self.compressed
+    }
+    fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+        self.compressed
+    }
+    fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+        !self.compressed
+    }
+}
@@ -0,0 +1,296 @@
+use super::{heating_is_required, is_on_ground, Heater};
+use crate::{
+    engine::Engine,
+    failures::FailureType,
+    shared::{
+        ConsumePower, ElectricalBusType, ElectricalBuses, HeatedWindow, HeatedWindows,
+        LgciuWeightOnWheels,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use uom::si::{f64::*, power::watt, ratio::ratio};
+
+/// The window heat computer (WHC) controls and monitors the heating of the windshield
+/// and the side window on one side of the cockpit. The windshield is heated for
+/// anti-icing and anti-fogging, the side window only for anti-fogging.
+pub struct WindowHeatComputer {
+    windshield_heat_fault_id: VariableIdentifier,
+    side_window_heat_fault_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    windshield: Heater,
+    side_window: Heater,
+}
+impl WindowHeatComputer {
+    const COMPUTER_POWER_WATT: f64 = 10.;
+
+    const WINDSHIELD_POWER_WATT: f64 = 1500.;
+    const SIDE_WINDOW_POWER_WATT: f64 = 400.;
+
+    // On ground the windshield is only heated at a low level.
+    const WINDSHIELD_ON_GROUND_HEATING_LEVEL: f64 = 0.3;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        powered_by: ElectricalBusType,
+        windshield_powered_by: ElectricalBusType,
+        side_window_powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            windshield_heat_fault_id: context.get_identifier(format!(
+                "WHC_{}_{}_HEAT_FAULT",
+                number,
+                HeatedWindow::Windshield
+            )),
+            side_window_heat_fault_id: context.get_identifier(format!(
+                "WHC_{}_{}_HEAT_FAULT",
+                number,
+                HeatedWindow::SideWindow
+            )),
+
+            powered_by,
+            is_powered: false,
+            windshield: Heater::new(
+                windshield_powered_by,
+                Power::new::<watt>(Self::WINDSHIELD_POWER_WATT),
+                FailureType::WindowHeater(number, HeatedWindow::Windshield),
+            ),
+            side_window: Heater::new(
+                side_window_powered_by,
+                Power::new::<watt>(Self::SIDE_WINDOW_POWER_WATT),
+                FailureType::WindowHeater(number, HeatedWindow::SideWindow),
+            ),
+        }
+    }
+
+    pub fn update<const N: usize>(
+        &mut self,
+        engines: [&impl Engine; N],
+        lgciu: &impl LgciuWeightOnWheels,
+        manually_selected_on: bool,
+    ) {
+        let is_heating =
+            self.is_powered && heating_is_required(engines, lgciu, manually_selected_on);
+
+        self.windshield.demand(Ratio::new::<ratio>(if !is_heating {
+            0.
+        } else if is_on_ground(lgciu) {
+            Self::WINDSHIELD_ON_GROUND_HEATING_LEVEL
+        } else {
+            1.
+        }));
+        self.side_window
+            .demand(Ratio::new::<ratio>(if is_heating { 1. } else { 0. }));
+    }
+
+    /// Indicates a heating fault of the given window. Without power the computer cannot
+    /// monitor its windows and thus reports both of them as faulty.
+    pub fn has_fault(&self, window: HeatedWindow) -> bool {
+        !self.is_powered || self.heater(window).has_fault()
+    }
+
+    fn heater(&self, window: HeatedWindow) -> &Heater {
+        match window {
+            HeatedWindow::Windshield => &self.windshield,
+            HeatedWindow::SideWindow => &self.side_window,
+        }
+    }
+}
+impl HeatedWindows for WindowHeatComputer {
+    fn window_heating(&self, window: HeatedWindow) -> Ratio {
+        self.heater(window).heating_level()
+    }
+}
+impl SimulationElement for WindowHeatComputer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.windshield.accept(visitor);
+        self.side_window.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.windshield_heat_fault_id,
+            self.has_fault(HeatedWindow::Windshield),
+        );
+        writer.write(
+            &self.side_window_heat_fault_id,
+            self.has_fault(HeatedWindow::SideWindow),
+        );
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_powered {
+            consumption.consume_from_bus(
+                self.powered_by,
+                Power::new::<watt>(Self::COMPUTER_POWER_WATT),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        engine::leap_engine::LeapEngine,
+        ice_rain_protection::test::TestLgciu,
+        shared::{PotentialOrigin, PowerConsumptionReport},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use ntest::assert_about_eq;
+
+    struct TestAircraft {
+        dc_source: TestElectricitySource,
+        ac_source: TestElectricitySource,
+        dc_bus: ElectricalBus,
+        ac_bus: ElectricalBus,
+        whc: WindowHeatComputer,
+        engine: LeapEngine,
+        lgciu: TestLgciu,
+        manually_selected_on: bool,
+        ac_consumption: Power,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                dc_source: TestElectricitySource::powered(context, PotentialOrigin::Battery(1)),
+                ac_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                ac_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                whc: WindowHeatComputer::new(
+                    context,
+                    1,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                engine: LeapEngine::new(context, 1),
+                lgciu: TestLgciu::new(true),
+                manually_selected_on: false,
+                ac_consumption: Power::new::<watt>(0.),
+            }
+        }
+
+        fn set_in_flight(&mut self) {
+            self.lgciu.set_compressed(false);
+        }
+
+        fn select_on(&mut self) {
+            self.manually_selected_on = true;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.dc_source);
+            electricity.supplied_by(&self.ac_source);
+            electricity.flow(&self.dc_source, &self.dc_bus);
+            electricity.flow(&self.ac_source, &self.ac_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+            self.whc
+                .update([&self.engine], &self.lgciu, self.manually_selected_on);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.whc.accept(visitor);
+            self.engine.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn process_power_consumption_report<T: PowerConsumptionReport>(
+            &mut self,
+            _: &UpdateContext,
+            report: &T,
+        ) {
+            self.ac_consumption = report.total_consumption_of(PotentialOrigin::EngineGenerator(1));
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn heating(test_bed: &SimulationTestBed<TestAircraft>, window: HeatedWindow) -> f64 {
+        test_bed.query(|a| a.whc.window_heating(window).get::<ratio>())
+    }
+
+    #[test]
+    fn windows_are_not_heated_on_ground_with_engines_off() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert_about_eq!(heating(&test_bed, HeatedWindow::Windshield), 0.);
+        assert_about_eq!(heating(&test_bed, HeatedWindow::SideWindow), 0.);
+    }
+
+    #[test]
+    fn windshield_is_heated_at_low_level_on_ground() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ENGINE_N2:1", 70.);
+        test_bed.run();
+
+        assert_about_eq!(
+            heating(&test_bed, HeatedWindow::Windshield),
+            WindowHeatComputer::WINDSHIELD_ON_GROUND_HEATING_LEVEL
+        );
+        assert_about_eq!(heating(&test_bed, HeatedWindow::SideWindow), 1.);
+    }
+
+    #[test]
+    fn windows_are_heated_when_selected_on() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.select_on());
+        test_bed.run();
+
+        assert!(heating(&test_bed, HeatedWindow::Windshield) > 0.);
+    }
+
+    #[test]
+    fn heaters_consume_their_rated_power_in_flight() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_in_flight());
+        test_bed.run();
+        test_bed.run();
+
+        assert_about_eq!(test_bed.query(|a| a.ac_consumption.get::<watt>()), 1900.);
+    }
+
+    #[test]
+    fn failed_windshield_heater_is_reported() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_in_flight());
+        test_bed.fail(FailureType::WindowHeater(1, HeatedWindow::Windshield));
+        test_bed.run();
+
+        assert_about_eq!(heating(&test_bed, HeatedWindow::Windshield), 0.);
+        let windshield_fault: bool = test_bed.read_by_name("WHC_1_WINDSHIELD_HEAT_FAULT");
+        assert!(windshield_fault);
+        let window_fault: bool = test_bed.read_by_name("WHC_1_WINDOW_HEAT_FAULT");
+        assert!(!window_fault);
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        VariableIdentifier,
+        VariableIdentifier, VariableMetadata,
     },
 };
 
@@ -36,7 +36,10 @@ impl CursorControlDevice {
                 Button::new(context, side, "REWIND", 0x007d),
                 Button::new(context, side, "FORWARD", 0x007e),
             ],
-            switch_ccd_id: context.get_identifier(format!("KCCU_{}_CCD_ON_OFF", side)),
+            switch_ccd_id: context.get_identifier_with_metadata(
+                format!("KCCU_{}_CCD_ON_OFF", side),
+                VariableMetadata::new().written_externally(),
+            ),
             switch_ccd_value: 0.0,
             function_id,
         }
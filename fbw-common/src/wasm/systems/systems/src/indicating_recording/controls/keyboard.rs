@@ -8,7 +8,7 @@ use crate::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, VariableIdentifier, VariableMetadata, Write,
     },
 };
 
@@ -92,7 +92,10 @@ impl Keyboard {
                 Button::new(context, side, "9", 0x0039),
                 Button::new(context, side, "DOT", 0x006e),
             ],
-            switch_kbd_id: context.get_identifier(format!("KCCU_{}_KBD_ON_OFF", side)),
+            switch_kbd_id: context.get_identifier_with_metadata(
+                format!("KCCU_{}_KBD_ON_OFF", side),
+                VariableMetadata::new().written_externally(),
+            ),
             switch_kbd_value: 0.0,
             function_id,
         }
@@ -5,7 +5,7 @@ use crate::{
     shared::{can_bus::CanBus, ElectricalBusType},
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, VariableIdentifier, VariableMetadata, Write,
     },
 };
 
@@ -18,7 +18,10 @@ pub struct Button {
 impl Button {
     pub fn new(context: &mut InitContext, side: &str, key: &str, keycode: u16) -> Self {
         Button {
-            button_id: context.get_identifier(format!("KCCU_{}_{}", side, key)),
+            button_id: context.get_identifier_with_metadata(
+                format!("KCCU_{}_{}", side, key),
+                VariableMetadata::new().written_externally(),
+            ),
             button_value: 0.0,
             keycode,
         }
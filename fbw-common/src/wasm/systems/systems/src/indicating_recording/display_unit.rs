@@ -0,0 +1,585 @@
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{arinc825::Arinc825Word, can_bus::CanBus, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter,
+        VariableIdentifier, Write,
+    },
+};
+use uom::si::{f64::Ratio, ratio::percent};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisplayFormat {
+    PrimaryFlightDisplay = 1,
+    NavigationDisplay = 2,
+    MultiFunctionDisplay = 3,
+    EngineWarningDisplay = 4,
+    SystemDisplay = 5,
+}
+impl DisplayFormat {
+    // The formats with interactive elements which can be controlled with the cursor
+    fn is_interactive(&self) -> bool {
+        matches!(
+            self,
+            DisplayFormat::NavigationDisplay
+                | DisplayFormat::MultiFunctionDisplay
+                | DisplayFormat::SystemDisplay
+        )
+    }
+}
+
+pub struct DisplayUnit {
+    power_supply: ElectricalBusType,
+    is_powered: bool,
+    failure: Failure,
+    nominal_format: DisplayFormat,
+    format: DisplayFormat,
+    has_cursor: bool,
+    has_keyboard: bool,
+    cursor_x: Ratio,
+    cursor_y: Ratio,
+    last_key: u16,
+
+    available_id: VariableIdentifier,
+    format_id: VariableIdentifier,
+    cursor_id: VariableIdentifier,
+    keyboard_id: VariableIdentifier,
+    cursor_x_id: VariableIdentifier,
+    cursor_y_id: VariableIdentifier,
+    last_key_id: VariableIdentifier,
+}
+impl DisplayUnit {
+    const CURSOR_STEP_PERCENT: f64 = 5.;
+
+    pub fn new(
+        context: &mut InitContext,
+        name: &str,
+        number: usize,
+        nominal_format: DisplayFormat,
+        power_supply: ElectricalBusType,
+    ) -> Self {
+        Self {
+            power_supply,
+            is_powered: false,
+            failure: Failure::new(FailureType::DisplayUnit(number)),
+            nominal_format,
+            format: nominal_format,
+            has_cursor: false,
+            has_keyboard: false,
+            cursor_x: Ratio::new::<percent>(50.),
+            cursor_y: Ratio::new::<percent>(50.),
+            last_key: 0,
+
+            available_id: context.get_identifier(format!("CDS_DU_{}_AVAIL", name)),
+            format_id: context.get_identifier(format!("CDS_DU_{}_FORMAT", name)),
+            cursor_id: context.get_identifier(format!("CDS_DU_{}_CURSOR", name)),
+            keyboard_id: context.get_identifier(format!("CDS_DU_{}_KEYBOARD", name)),
+            cursor_x_id: context.get_identifier(format!("CDS_DU_{}_CURSOR_X", name)),
+            cursor_y_id: context.get_identifier(format!("CDS_DU_{}_CURSOR_Y", name)),
+            last_key_id: context.get_identifier(format!("CDS_DU_{}_LAST_KEY", name)),
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.is_powered && !self.failure.is_active()
+    }
+
+    pub fn nominal_format(&self) -> DisplayFormat {
+        self.nominal_format
+    }
+
+    /// The displayed format, or `None` when the display unit is blank.
+    pub fn format(&self) -> Option<DisplayFormat> {
+        if self.is_available() {
+            Some(self.format)
+        } else {
+            None
+        }
+    }
+
+    /// Reconfigures the display unit to show the given format.
+    pub fn show(&mut self, format: DisplayFormat) {
+        self.format = format;
+    }
+
+    pub fn show_nominal_format(&mut self) {
+        self.format = self.nominal_format;
+    }
+
+    pub fn has_cursor(&self) -> bool {
+        self.has_cursor
+    }
+
+    pub fn has_keyboard(&self) -> bool {
+        self.has_keyboard
+    }
+
+    pub fn cursor_position(&self) -> (Ratio, Ratio) {
+        (self.cursor_x, self.cursor_y)
+    }
+
+    /// The keycode of the last key pressed on the keyboard while this unit had the keyboard.
+    pub fn last_key(&self) -> u16 {
+        self.last_key
+    }
+
+    fn accepts_cursor(&self) -> bool {
+        self.is_available() && self.format.is_interactive()
+    }
+
+    fn center_cursor(&mut self) {
+        self.cursor_x = Ratio::new::<percent>(50.);
+        self.cursor_y = Ratio::new::<percent>(50.);
+    }
+
+    fn move_cursor(&mut self, x_steps: f64, y_steps: f64) {
+        let step = Ratio::new::<percent>(Self::CURSOR_STEP_PERCENT);
+        let limit = |position: Ratio| {
+            position
+                .max(Ratio::new::<percent>(0.))
+                .min(Ratio::new::<percent>(100.))
+        };
+
+        self.cursor_x = limit(self.cursor_x + step * x_steps);
+        self.cursor_y = limit(self.cursor_y + step * y_steps);
+    }
+}
+impl SimulationElement for DisplayUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.available_id, self.is_available());
+        writer.write(
+            &self.format_id,
+            self.format().map_or(0, |format| format as u8),
+        );
+        writer.write(&self.cursor_id, self.has_cursor);
+        writer.write(&self.keyboard_id, self.has_keyboard);
+        writer.write(&self.cursor_x_id, self.cursor_x);
+        writer.write(&self.cursor_y_id, self.cursor_y);
+        writer.write(&self.last_key_id, self.last_key);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.power_supply);
+    }
+}
+
+/// The display units which are controlled by one KCCU. The display units listen to the
+/// messages of the KCCU on its CAN buses. The cursor control device moves the cursor between
+/// the interactive display units and assigns the keyboard to the display unit with the cursor.
+pub struct KeyboardCursorControlledDisplayUnits<const N: usize> {
+    display_units: [DisplayUnit; N],
+    kbd_function_id: u8,
+    ccd_function_id: u8,
+    cursor_unit: Option<usize>,
+    keyboard_unit: Option<usize>,
+}
+impl<const N: usize> KeyboardCursorControlledDisplayUnits<N> {
+    const KEY_PRESSED: u16 = 0x8000;
+    const KEY_ESCAPE: u16 = 0x001b;
+    const KEY_LEFT: u16 = 0x0025;
+    const KEY_UP: u16 = 0x0026;
+    const KEY_RIGHT: u16 = 0x0027;
+    const KEY_DOWN: u16 = 0x0028;
+    const KEY_KEYBOARD: u16 = 0x007c;
+    const KEY_REWIND: u16 = 0x007d;
+    const KEY_FORWARD: u16 = 0x007e;
+
+    pub fn new(display_units: [DisplayUnit; N], kbd_function_id: u8, ccd_function_id: u8) -> Self {
+        Self {
+            display_units,
+            kbd_function_id,
+            ccd_function_id,
+            cursor_unit: None,
+            keyboard_unit: None,
+        }
+    }
+
+    pub fn display_unit(&self, index: usize) -> &DisplayUnit {
+        &self.display_units[index]
+    }
+
+    pub fn display_unit_mut(&mut self, index: usize) -> &mut DisplayUnit {
+        &mut self.display_units[index]
+    }
+
+    pub fn update<const M: usize>(&mut self, can_buses: &[CanBus<M>; 2]) {
+        if !self.unit_accepts_cursor(self.cursor_unit) {
+            self.cursor_unit = self.next_cursor_unit(self.cursor_unit, true);
+        }
+        if !self.unit_accepts_cursor(self.keyboard_unit) {
+            self.keyboard_unit = self.cursor_unit;
+        }
+
+        if let Some(message) = Self::listen(can_buses) {
+            self.process(message);
+        }
+
+        for (index, unit) in self.display_units.iter_mut().enumerate() {
+            unit.has_cursor = self.cursor_unit == Some(index);
+            unit.has_keyboard = self.keyboard_unit == Some(index);
+        }
+    }
+
+    // Both buses transmit the same messages, the second bus is used when the first one failed
+    fn listen<const M: usize>(can_buses: &[CanBus<M>; 2]) -> Option<Arinc825Word<f64>> {
+        can_buses
+            .iter()
+            .find(|bus| bus.is_available())
+            .and_then(|bus| bus.transmitted_message())
+    }
+
+    fn process(&mut self, message: Arinc825Word<f64>) {
+        let value = message.value() as u16;
+        if value & Self::KEY_PRESSED == 0 {
+            return;
+        }
+
+        let keycode = value & 0x00ff;
+        if message.source_function_id() == self.ccd_function_id {
+            self.process_cursor_control_key(keycode);
+        } else if message.source_function_id() == self.kbd_function_id {
+            self.process_keyboard_key(keycode);
+        }
+    }
+
+    fn process_cursor_control_key(&mut self, keycode: u16) {
+        match keycode {
+            Self::KEY_FORWARD => self.cursor_unit = self.next_cursor_unit(self.cursor_unit, true),
+            Self::KEY_REWIND => self.cursor_unit = self.next_cursor_unit(self.cursor_unit, false),
+            Self::KEY_KEYBOARD => self.keyboard_unit = self.cursor_unit,
+            Self::KEY_ESCAPE => {
+                if let Some(index) = self.cursor_unit {
+                    self.display_units[index].center_cursor();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn process_keyboard_key(&mut self, keycode: u16) {
+        if let Some(index) = self.keyboard_unit {
+            let unit = &mut self.display_units[index];
+            unit.last_key = keycode;
+
+            match keycode {
+                Self::KEY_LEFT => unit.move_cursor(-1., 0.),
+                Self::KEY_RIGHT => unit.move_cursor(1., 0.),
+                Self::KEY_UP => unit.move_cursor(0., -1.),
+                Self::KEY_DOWN => unit.move_cursor(0., 1.),
+                _ => {}
+            }
+        }
+    }
+
+    fn unit_accepts_cursor(&self, index: Option<usize>) -> bool {
+        match index {
+            Some(index) => self.display_units[index].accepts_cursor(),
+            None => false,
+        }
+    }
+
+    fn next_cursor_unit(&self, current: Option<usize>, forward: bool) -> Option<usize> {
+        let start = current.unwrap_or(if forward { N - 1 } else { 0 });
+
+        (1..=N)
+            .map(|offset| {
+                if forward {
+                    (start + offset) % N
+                } else {
+                    (start + N - offset) % N
+                }
+            })
+            .find(|&index| self.display_units[index].accepts_cursor())
+    }
+}
+impl<const N: usize> SimulationElement for KeyboardCursorControlledDisplayUnits<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.display_units
+            .iter_mut()
+            .for_each(|unit| unit.accept(visitor));
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::{arinc825::LogicalCommunicationChannel, PotentialOrigin},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, UpdateContext,
+        },
+    };
+    use uom::si::{electric_potential::volt, f64::ElectricPotential};
+
+    const KBD: u8 = 1;
+    const CCD: u8 = 2;
+    const OUTER: usize = 0;
+    const INNER: usize = 1;
+    const MFD: usize = 2;
+
+    struct TestAircraft {
+        can_buses: [CanBus<3>; 2],
+        display_units: KeyboardCursorControlledDisplayUnits<3>,
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        pending_messages: Vec<Arinc825Word<f64>>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                can_buses: [
+                    CanBus::new(context, "TEST_CAN_BUS_1", 1, [KBD, CCD, 3]),
+                    CanBus::new(context, "TEST_CAN_BUS_2", 2, [KBD, CCD, 3]),
+                ],
+                display_units: KeyboardCursorControlledDisplayUnits::new(
+                    [
+                        DisplayUnit::new(
+                            context,
+                            "OUTER",
+                            1,
+                            DisplayFormat::PrimaryFlightDisplay,
+                            ElectricalBusType::DirectCurrent(1),
+                        ),
+                        DisplayUnit::new(
+                            context,
+                            "INNER",
+                            2,
+                            DisplayFormat::NavigationDisplay,
+                            ElectricalBusType::DirectCurrent(1),
+                        ),
+                        DisplayUnit::new(
+                            context,
+                            "MFD",
+                            3,
+                            DisplayFormat::MultiFunctionDisplay,
+                            ElectricalBusType::DirectCurrent(1),
+                        ),
+                    ],
+                    KBD,
+                    CCD,
+                ),
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                pending_messages: Vec::new(),
+            }
+        }
+
+        fn press(&mut self, function_id: u8, keycode: u16) {
+            let mut message = Arinc825Word::new(
+                (keycode | 0x8000) as f64,
+                LogicalCommunicationChannel::NormalOperationChannel,
+            );
+            message.set_source_function_id(function_id);
+            self.pending_messages.push(message);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.powered_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_1_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            for bus in self.can_buses.iter_mut() {
+                // all stations acknowledge the previous message
+                for id in [KBD, CCD, 3] {
+                    bus.received_message(id);
+                }
+                if let Some(message) = self.pending_messages.first() {
+                    bus.send_message(*message);
+                }
+                bus.update(context);
+            }
+            if !self.pending_messages.is_empty() {
+                self.pending_messages.remove(0);
+            }
+
+            self.display_units.update(&self.can_buses);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.can_buses
+                .iter_mut()
+                .for_each(|bus| bus.accept(visitor));
+            self.display_units.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct DisplayUnitsTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl DisplayUnitsTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.run();
+
+            test_bed
+        }
+
+        fn press(&mut self, function_id: u8, keycode: u16) {
+            self.command(|a| a.press(function_id, keycode));
+            self.run();
+        }
+
+        fn cursor_unit(&self) -> Option<usize> {
+            self.query(|a| (0..3).find(|&index| a.display_units.display_unit(index).has_cursor()))
+        }
+
+        fn keyboard_unit(&self) -> Option<usize> {
+            self.query(|a| (0..3).find(|&index| a.display_units.display_unit(index).has_keyboard()))
+        }
+    }
+    impl TestBed for DisplayUnitsTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> DisplayUnitsTestBed {
+        DisplayUnitsTestBed::new()
+    }
+
+    #[test]
+    fn powered_display_units_show_their_nominal_format() {
+        let mut test_bed = test_bed();
+
+        let available: bool = test_bed.read_by_name("CDS_DU_OUTER_AVAIL");
+        let format: u8 = test_bed.read_by_name("CDS_DU_OUTER_FORMAT");
+        assert!(available);
+        assert_eq!(format, DisplayFormat::PrimaryFlightDisplay as u8);
+        assert_eq!(
+            test_bed.query(|a| a.display_units.display_unit(OUTER).format()),
+            Some(DisplayFormat::PrimaryFlightDisplay)
+        );
+    }
+
+    #[test]
+    fn failed_display_unit_is_blank() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::DisplayUnit(3));
+        test_bed.run();
+
+        let format: u8 = test_bed.read_by_name("CDS_DU_MFD_FORMAT");
+        assert_eq!(format, 0);
+    }
+
+    #[test]
+    fn cursor_starts_on_the_first_interactive_display_unit() {
+        let test_bed = test_bed();
+
+        assert_eq!(test_bed.cursor_unit(), Some(INNER));
+        assert_eq!(test_bed.keyboard_unit(), Some(INNER));
+    }
+
+    #[test]
+    fn forward_and_rewind_move_the_cursor_between_interactive_units() {
+        let mut test_bed = test_bed();
+
+        test_bed.press(CCD, 0x007e);
+        assert_eq!(test_bed.cursor_unit(), Some(MFD));
+
+        test_bed.press(CCD, 0x007e);
+        assert_eq!(test_bed.cursor_unit(), Some(INNER));
+
+        test_bed.press(CCD, 0x007d);
+        assert_eq!(test_bed.cursor_unit(), Some(MFD));
+    }
+
+    #[test]
+    fn keyboard_is_assigned_to_the_unit_with_the_cursor() {
+        let mut test_bed = test_bed();
+
+        test_bed.press(CCD, 0x007e);
+        assert_eq!(test_bed.keyboard_unit(), Some(INNER));
+
+        test_bed.press(CCD, 0x007c);
+        assert_eq!(test_bed.keyboard_unit(), Some(MFD));
+
+        test_bed.press(KBD, 0x0041);
+        assert_eq!(
+            test_bed.query(|a| a.display_units.display_unit(MFD).last_key()),
+            0x0041
+        );
+    }
+
+    #[test]
+    fn arrow_keys_move_the_cursor() {
+        let mut test_bed = test_bed();
+
+        test_bed.press(KBD, 0x0027);
+        test_bed.press(KBD, 0x0028);
+
+        let (x, y) = test_bed.query(|a| a.display_units.display_unit(INNER).cursor_position());
+        assert!(x.get::<percent>() > 50.);
+        assert!(y.get::<percent>() > 50.);
+
+        test_bed.press(CCD, 0x001b);
+
+        let x: Ratio = test_bed.read_by_name("CDS_DU_INNER_CURSOR_X");
+        assert!((x.get::<percent>() - 50.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn cursor_leaves_a_failed_display_unit() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::DisplayUnit(2));
+        test_bed.run();
+
+        assert_eq!(test_bed.cursor_unit(), Some(MFD));
+        assert_eq!(test_bed.keyboard_unit(), Some(MFD));
+    }
+
+    #[test]
+    fn messages_are_received_over_the_second_bus_when_the_first_failed() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("TEST_CAN_BUS_1_FAILURE", true);
+        test_bed.run();
+
+        test_bed.press(CCD, 0x007e);
+
+        assert_eq!(test_bed.cursor_unit(), Some(MFD));
+    }
+
+    #[test]
+    fn reconfigured_display_unit_shows_the_new_format() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.display_units
+                .display_unit_mut(INNER)
+                .show(DisplayFormat::PrimaryFlightDisplay)
+        });
+        test_bed.run();
+
+        let format: u8 = test_bed.read_by_name("CDS_DU_INNER_FORMAT");
+        assert_eq!(format, DisplayFormat::PrimaryFlightDisplay as u8);
+        assert_eq!(test_bed.cursor_unit(), Some(MFD));
+    }
+}
@@ -1 +1,2 @@
 pub mod controls;
+pub mod display_unit;
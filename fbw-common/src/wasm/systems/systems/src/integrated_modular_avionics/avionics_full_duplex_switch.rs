@@ -2,7 +2,7 @@ use crate::{
     shared::{power_supply_relay::PowerSupplyRelay, ElectricalBusType, ElectricalBuses},
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, VariableIdentifier, VariableMetadata, Write,
     },
 };
 
@@ -32,7 +32,10 @@ impl AvionicsFullDuplexSwitch {
             power_supply: PowerSupply::Single(power_supply),
             last_is_powered: false,
             is_powered: false,
-            failure_indication_id: context.get_identifier(format!("AFDX_SWITCH_{}_FAILURE", id)),
+            failure_indication_id: context.get_identifier_with_metadata(
+                format!("AFDX_SWITCH_{}_FAILURE", id),
+                VariableMetadata::new().written_externally(),
+            ),
             last_failure_indication: false,
             failure_indication: false,
             available_id: context.get_identifier(format!("AFDX_SWITCH_{}_AVAIL", id)),
@@ -53,7 +56,10 @@ impl AvionicsFullDuplexSwitch {
             )),
             last_is_powered: false,
             is_powered: false,
-            failure_indication_id: context.get_identifier(format!("AFDX_SWITCH_{}_FAILURE", id)),
+            failure_indication_id: context.get_identifier_with_metadata(
+                format!("AFDX_SWITCH_{}_FAILURE", id),
+                VariableMetadata::new().written_externally(),
+            ),
             last_failure_indication: false,
             failure_indication: false,
             available_id: context.get_identifier(format!("AFDX_SWITCH_{}_AVAIL", id)),
@@ -2,7 +2,7 @@ use crate::{
     shared::{ElectricalBusType, ElectricalBuses},
     simulation::{
         InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
-        Write,
+        VariableMetadata, Write,
     },
 };
 
@@ -22,7 +22,10 @@ impl CoreProcessingInputOutputModule {
             power_supply,
             is_powered: false,
             available_id: context.get_identifier(format!("CPIOM_{}_AVAIL", name)),
-            failure_indication_id: context.get_identifier(format!("CPIOM_{}_FAILURE", name)),
+            failure_indication_id: context.get_identifier_with_metadata(
+                format!("CPIOM_{}_FAILURE", name),
+                VariableMetadata::new().written_externally(),
+            ),
             failure_indication: false,
         }
     }
@@ -2,11 +2,12 @@ use crate::{
     shared::{ElectricalBusType, ElectricalBuses},
     simulation::{
         InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
-        Write,
+        VariableMetadata, Write,
     },
 };
 
 pub struct InputOutputModule {
+    name: String,
     power_supply: ElectricalBusType,
     is_powered: bool,
     available_id: VariableIdentifier,
@@ -17,14 +18,22 @@ pub struct InputOutputModule {
 impl InputOutputModule {
     pub fn new(context: &mut InitContext, name: &str, power_supply: ElectricalBusType) -> Self {
         Self {
+            name: name.to_owned(),
             power_supply,
             is_powered: false,
             available_id: context.get_identifier(format!("IOM_{}_AVAIL", name)),
-            failure_indication_id: context.get_identifier(format!("IOM_{}_FAILURE", name)),
+            failure_indication_id: context.get_identifier_with_metadata(
+                format!("IOM_{}_FAILURE", name),
+                VariableMetadata::new().written_externally(),
+            ),
             failure_indication: false,
         }
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     pub fn is_available(&self) -> bool {
         self.is_powered & !self.failure_indication
     }
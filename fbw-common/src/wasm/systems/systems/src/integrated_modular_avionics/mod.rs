@@ -1,3 +1,4 @@
 pub mod avionics_full_duplex_switch;
 pub mod core_processing_input_output_module;
 pub mod input_output_module;
+pub mod virtual_link;
@@ -0,0 +1,546 @@
+use crate::simulation::UpdateContext;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AfdxNetwork {
+    A,
+    B,
+}
+impl AfdxNetwork {
+    const ALL: [AfdxNetwork; 2] = [AfdxNetwork::A, AfdxNetwork::B];
+}
+
+/// An end system is a CPIOM, IOM or any other system which is attached to one AFDX switch
+/// of each of the two redundant networks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AfdxEndSystem {
+    name: &'static str,
+    switch_ids: [u8; 2],
+}
+impl AfdxEndSystem {
+    pub fn new(name: &'static str, network_a_switch_id: u8, network_b_switch_id: u8) -> Self {
+        Self {
+            name,
+            switch_ids: [network_a_switch_id, network_b_switch_id],
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn switch_id(&self, network: AfdxNetwork) -> u8 {
+        self.switch_ids[network as usize]
+    }
+}
+
+/// The topology of the AFDX networks over which the virtual links transport their frames.
+pub trait AfdxNetworkTopology {
+    /// Returns the end system with the given name, as it is wired to the switches of the networks,
+    /// or `None` when no end system of that name is connected to the networks.
+    fn end_system(&self, name: &'static str) -> Option<AfdxEndSystem>;
+    fn end_system_is_available(&self, end_system: &AfdxEndSystem) -> bool;
+
+    /// Returns the number of switches a frame passes on its shortest path between the two
+    /// switches of the given network, or `None` when the switches cannot reach each other.
+    fn switch_hops(
+        &self,
+        network: AfdxNetwork,
+        from_switch_id: u8,
+        to_switch_id: u8,
+    ) -> Option<usize>;
+}
+
+/// The receiving side of a virtual link. It implements the redundancy management of the
+/// end system: the first valid frame of a sequence number is accepted, regardless of the
+/// network it arrived on, and the copy of the other network is discarded.
+pub struct VirtualLinkPort<T: Copy> {
+    end_system: AfdxEndSystem,
+    value: Option<T>,
+    age: Duration,
+    latency: Duration,
+    last_sequence_number: Option<u8>,
+    last_network: Option<AfdxNetwork>,
+    validity_period: Duration,
+}
+impl<T: Copy> VirtualLinkPort<T> {
+    fn new(end_system: AfdxEndSystem, validity_period: Duration) -> Self {
+        Self {
+            end_system,
+            value: None,
+            age: Duration::ZERO,
+            latency: Duration::ZERO,
+            last_sequence_number: None,
+            last_network: None,
+            validity_period,
+        }
+    }
+
+    pub fn end_system(&self) -> &AfdxEndSystem {
+        &self.end_system
+    }
+
+    /// The last received value, as long as it is refreshed within the validity period.
+    pub fn value(&self) -> Option<T> {
+        if self.is_fresh() {
+            self.value
+        } else {
+            None
+        }
+    }
+
+    pub fn is_fresh(&self) -> bool {
+        self.value.is_some() && self.age <= self.validity_period
+    }
+
+    /// The time passed since the last received value was published by the source.
+    pub fn age(&self) -> Duration {
+        self.age
+    }
+
+    /// The network latency of the last received frame.
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    pub fn last_network(&self) -> Option<AfdxNetwork> {
+        self.last_network
+    }
+
+    fn age_by(&mut self, delta: Duration) {
+        self.age += delta;
+    }
+
+    /// Frames are only compared with the last received sequence number while the port is fresh.
+    /// After a timeout the source might have restarted its sequence, thus any frame is accepted.
+    fn is_new_sequence_number(&self, sequence_number: u8) -> bool {
+        match self.last_sequence_number {
+            Some(last) if self.is_fresh() => (sequence_number.wrapping_sub(last) as i8) > 0,
+            _ => true,
+        }
+    }
+
+    fn receive(
+        &mut self,
+        value: T,
+        sequence_number: u8,
+        network: AfdxNetwork,
+        latency: Duration,
+    ) -> bool {
+        if !self.is_new_sequence_number(sequence_number) {
+            return false;
+        }
+
+        self.value = Some(value);
+        self.age = latency;
+        self.latency = latency;
+        self.last_sequence_number = Some(sequence_number);
+        self.last_network = Some(network);
+
+        true
+    }
+}
+
+/// An ARINC 664 virtual link transports the sampled messages of one source end system to
+/// all subscribed end systems over both AFDX networks. The source transmits at most one
+/// frame per bandwidth allocation gap (BAG), containing the latest published value.
+///
+/// The latency of the networks is far below the duration of a simulation step. Frames thus
+/// arrive within the update they are transmitted in and the latency is only accounted for
+/// in the age of the received data. A frame is lost on a network when the switches of the
+/// source and the subscriber cannot reach each other.
+pub struct VirtualLink<T: Copy> {
+    id: u16,
+    bandwidth_allocation_gap: Duration,
+    source: AfdxEndSystem,
+    ports: Vec<VirtualLinkPort<T>>,
+    sampled_value: Option<T>,
+    time_since_last_frame: Duration,
+    next_sequence_number: u8,
+}
+impl<T: Copy> VirtualLink<T> {
+    const END_SYSTEM_LATENCY: Duration = Duration::from_micros(150);
+    const SWITCH_LATENCY: Duration = Duration::from_micros(100);
+    const VALIDITY_PERIOD_BAG_MULTIPLE: u32 = 4;
+
+    pub fn new(id: u16, bandwidth_allocation_gap: Duration, source: AfdxEndSystem) -> Self {
+        Self {
+            id,
+            bandwidth_allocation_gap,
+            source,
+            ports: Vec::new(),
+            sampled_value: None,
+            // Allows the first frame to be transmitted immediately
+            time_since_last_frame: bandwidth_allocation_gap,
+            next_sequence_number: 0,
+        }
+    }
+
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    pub fn bandwidth_allocation_gap(&self) -> Duration {
+        self.bandwidth_allocation_gap
+    }
+
+    pub fn source(&self) -> &AfdxEndSystem {
+        &self.source
+    }
+
+    /// Adds a port for the given end system to the virtual link.
+    pub fn subscribe(&mut self, end_system: AfdxEndSystem) {
+        if self.ports.iter().all(|port| port.end_system != end_system) {
+            self.ports.push(VirtualLinkPort::new(
+                end_system,
+                self.bandwidth_allocation_gap * Self::VALIDITY_PERIOD_BAG_MULTIPLE,
+            ));
+        }
+    }
+
+    /// Samples the value which is transmitted with the next frames.
+    pub fn publish(&mut self, value: T) {
+        self.sampled_value = Some(value);
+    }
+
+    /// Returns the port of the given subscribed end system.
+    /// Panics when the end system did not subscribe to the virtual link.
+    pub fn port(&self, end_system_name: &str) -> &VirtualLinkPort<T> {
+        self.ports
+            .iter()
+            .find(|port| port.end_system.name() == end_system_name)
+            .unwrap_or_else(|| {
+                panic!(
+                    "{} is not subscribed to virtual link {}",
+                    end_system_name, self.id
+                )
+            })
+    }
+
+    pub fn received_value(&self, end_system_name: &str) -> Option<T> {
+        self.port(end_system_name).value()
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, network: &impl AfdxNetworkTopology) {
+        self.time_since_last_frame += context.delta();
+        self.ports
+            .iter_mut()
+            .for_each(|port| port.age_by(context.delta()));
+
+        if self.time_since_last_frame < self.bandwidth_allocation_gap
+            || !network.end_system_is_available(&self.source)
+        {
+            return;
+        }
+
+        if let Some(value) = self.sampled_value {
+            let sequence_number = self.next_sequence_number;
+            self.next_sequence_number = self.next_sequence_number.wrapping_add(1);
+            self.time_since_last_frame = Duration::ZERO;
+
+            let source = self.source;
+            for port in self.ports.iter_mut() {
+                if !network.end_system_is_available(&port.end_system) {
+                    continue;
+                }
+
+                let mut arrivals: Vec<(AfdxNetwork, Duration)> = AfdxNetwork::ALL
+                    .iter()
+                    .filter_map(|&afdx_network| {
+                        network
+                            .switch_hops(
+                                afdx_network,
+                                source.switch_id(afdx_network),
+                                port.end_system.switch_id(afdx_network),
+                            )
+                            .map(|hops| (afdx_network, Self::frame_latency(hops)))
+                    })
+                    .collect();
+                arrivals.sort_by_key(|&(_, latency)| latency);
+
+                for (afdx_network, latency) in arrivals {
+                    port.receive(value, sequence_number, afdx_network, latency);
+                }
+            }
+        }
+    }
+
+    fn frame_latency(hops: usize) -> Duration {
+        Self::END_SYSTEM_LATENCY * 2 + Self::SWITCH_LATENCY * hops as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::Electricity,
+        simulation::{
+            test::{SimulationTestBed, TestBed},
+            Aircraft, InitContext, SimulationElement,
+        },
+    };
+
+    struct TestNetwork {
+        switch_hops: [Option<usize>; 2],
+        unavailable_end_systems: Vec<&'static str>,
+    }
+    impl TestNetwork {
+        fn new() -> Self {
+            Self {
+                switch_hops: [Some(1), Some(1)],
+                unavailable_end_systems: Vec::new(),
+            }
+        }
+    }
+    impl AfdxNetworkTopology for TestNetwork {
+        fn end_system(&self, name: &'static str) -> Option<AfdxEndSystem> {
+            Some(AfdxEndSystem::new(name, 1, 11))
+        }
+
+        fn end_system_is_available(&self, end_system: &AfdxEndSystem) -> bool {
+            !self.unavailable_end_systems.contains(&end_system.name())
+        }
+
+        fn switch_hops(&self, network: AfdxNetwork, _: u8, _: u8) -> Option<usize> {
+            self.switch_hops[network as usize]
+        }
+    }
+
+    struct TestAircraft {
+        virtual_link: VirtualLink<f64>,
+        network: TestNetwork,
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            let mut virtual_link = VirtualLink::new(
+                1,
+                Duration::from_millis(32),
+                AfdxEndSystem::new("SOURCE", 1, 11),
+            );
+            virtual_link.subscribe(AfdxEndSystem::new("SUBSCRIBER", 2, 12));
+
+            Self {
+                virtual_link,
+                network: TestNetwork::new(),
+            }
+        }
+
+        fn fail_network(&mut self, network: AfdxNetwork) {
+            self.network.switch_hops[network as usize] = None;
+        }
+
+        fn set_switch_hops(&mut self, network: AfdxNetwork, hops: usize) {
+            self.network.switch_hops[network as usize] = Some(hops);
+        }
+
+        fn fail_end_system(&mut self, name: &'static str) {
+            self.network.unavailable_end_systems.push(name);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _: &mut Electricity,
+        ) {
+            self.virtual_link.update(context, &self.network);
+        }
+    }
+    impl SimulationElement for TestAircraft {}
+
+    struct VirtualLinkTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl VirtualLinkTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn publish(mut self, value: f64) -> Self {
+            self.command(|a| a.virtual_link.publish(value));
+            self
+        }
+
+        fn and_run(mut self) -> Self {
+            self.run();
+            self
+        }
+
+        fn received_value(&self) -> Option<f64> {
+            self.query(|a| a.virtual_link.received_value("SUBSCRIBER"))
+        }
+
+        fn latency(&self) -> Duration {
+            self.query(|a| a.virtual_link.port("SUBSCRIBER").latency())
+        }
+
+        fn last_network(&self) -> Option<AfdxNetwork> {
+            self.query(|a| a.virtual_link.port("SUBSCRIBER").last_network())
+        }
+    }
+    impl TestBed for VirtualLinkTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> VirtualLinkTestBed {
+        VirtualLinkTestBed::new()
+    }
+
+    #[test]
+    fn nothing_is_received_before_publishing() {
+        let test_bed = test_bed().and_run();
+
+        assert_eq!(test_bed.received_value(), None);
+    }
+
+    #[test]
+    fn published_value_is_received() {
+        let test_bed = test_bed().publish(42.).and_run();
+
+        assert_eq!(test_bed.received_value(), Some(42.));
+    }
+
+    #[test]
+    fn frames_are_not_transmitted_faster_than_the_bandwidth_allocation_gap() {
+        let mut test_bed = test_bed().publish(1.).and_run().publish(2.);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert_eq!(test_bed.received_value(), Some(1.));
+
+        test_bed.run_with_delta(Duration::from_millis(30));
+
+        assert_eq!(test_bed.received_value(), Some(2.));
+    }
+
+    #[test]
+    fn first_frame_is_accepted_from_the_faster_network() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_switch_hops(AfdxNetwork::A, 3));
+        let test_bed = test_bed.publish(1.).and_run();
+
+        assert_eq!(test_bed.last_network(), Some(AfdxNetwork::B));
+    }
+
+    #[test]
+    fn value_is_received_when_one_network_is_lost() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.fail_network(AfdxNetwork::A));
+        let test_bed = test_bed.publish(1.).and_run();
+
+        assert_eq!(test_bed.received_value(), Some(1.));
+        assert_eq!(test_bed.last_network(), Some(AfdxNetwork::B));
+    }
+
+    #[test]
+    fn value_becomes_stale_when_both_networks_are_lost() {
+        let mut test_bed = test_bed().publish(1.).and_run();
+        test_bed.command(|a| a.fail_network(AfdxNetwork::A));
+        test_bed.command(|a| a.fail_network(AfdxNetwork::B));
+        test_bed.run();
+
+        assert_eq!(test_bed.received_value(), None);
+    }
+
+    #[test]
+    fn value_becomes_stale_when_the_source_is_unavailable() {
+        let mut test_bed = test_bed().publish(1.).and_run();
+        test_bed.command(|a| a.fail_end_system("SOURCE"));
+        test_bed.run();
+
+        assert_eq!(test_bed.received_value(), None);
+    }
+
+    #[test]
+    fn unavailable_subscriber_receives_nothing() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.fail_end_system("SUBSCRIBER"));
+        let test_bed = test_bed.publish(1.).and_run();
+
+        assert_eq!(test_bed.received_value(), None);
+    }
+
+    #[test]
+    fn latency_increases_with_the_number_of_switches() {
+        let test_bed = test_bed().publish(1.).and_run();
+        let direct_latency = test_bed.latency();
+
+        let mut test_bed = test_bed;
+        test_bed.command(|a| a.set_switch_hops(AfdxNetwork::A, 4));
+        test_bed.command(|a| a.set_switch_hops(AfdxNetwork::B, 4));
+        test_bed.run();
+
+        assert!(test_bed.latency() > direct_latency);
+    }
+
+    #[test]
+    fn redundant_frames_are_discarded() {
+        let mut port = VirtualLinkPort::new(
+            AfdxEndSystem::new("SUBSCRIBER", 2, 12),
+            Duration::from_millis(128),
+        );
+
+        assert!(port.receive(1., 7, AfdxNetwork::A, Duration::ZERO));
+        assert!(!port.receive(1., 7, AfdxNetwork::B, Duration::ZERO));
+        assert!(port.receive(2., 8, AfdxNetwork::B, Duration::ZERO));
+    }
+
+    #[test]
+    fn sequence_number_wraps_around() {
+        let mut port = VirtualLinkPort::new(
+            AfdxEndSystem::new("SUBSCRIBER", 2, 12),
+            Duration::from_millis(128),
+        );
+
+        assert!(port.receive(1., 255, AfdxNetwork::A, Duration::ZERO));
+        assert!(port.receive(2., 0, AfdxNetwork::A, Duration::ZERO));
+        assert_eq!(port.value(), Some(2.));
+    }
+
+    #[test]
+    fn any_sequence_number_is_accepted_after_a_timeout() {
+        let mut port = VirtualLinkPort::new(
+            AfdxEndSystem::new("SUBSCRIBER", 2, 12),
+            Duration::from_millis(128),
+        );
+
+        assert!(port.receive(1., 100, AfdxNetwork::A, Duration::ZERO));
+        assert!(!port.receive(2., 0, AfdxNetwork::A, Duration::ZERO));
+
+        port.age_by(Duration::from_millis(200));
+
+        assert!(port.receive(2., 0, AfdxNetwork::A, Duration::ZERO));
+        assert_eq!(port.value(), Some(2.));
+    }
+
+    #[test]
+    fn value_is_received_from_a_restarted_source() {
+        let mut test_bed = test_bed().publish(1.);
+        for _ in 0..50 {
+            test_bed.run_with_delta(Duration::from_millis(32));
+        }
+
+        // The source is unavailable long enough for the port to time out, and restarts its
+        // sequence when it becomes available again.
+        test_bed.command(|a| a.fail_end_system("SOURCE"));
+        test_bed.run_with_delta(Duration::from_millis(200));
+        test_bed.command(|a| {
+            a.network.unavailable_end_systems.clear();
+            a.virtual_link.next_sequence_number = 0;
+        });
+        let mut test_bed = test_bed.publish(2.);
+        test_bed.run_with_delta(Duration::from_millis(32));
+
+        assert_eq!(test_bed.received_value(), Some(2.));
+    }
+}
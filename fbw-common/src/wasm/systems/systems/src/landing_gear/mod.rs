@@ -10,7 +10,7 @@ use crate::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
     },
 };
 use uom::si::{
@@ -21,6 +21,9 @@ use uom::si::{
 };
 
 use nalgebra::Vector3;
+
+pub mod tyre_pressure;
+
 pub trait GearSystemSensors {
     fn is_wheel_id_up_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool;
     fn is_wheel_id_down_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool;
@@ -31,6 +34,7 @@ pub trait GearSystemSensors {
 pub struct TiltingGear {
     tilt_animation_id: VariableIdentifier,
     compression_id: VariableIdentifier,
+    steering_angle_id: VariableIdentifier,
 
     tilt_height_from_low_to_up: Length,
     contact_point_offset_from_datum_ref_meters: Vector3<f64>,
@@ -38,6 +42,7 @@ pub struct TiltingGear {
 
     current_compression: Ratio,
     tilt_position: Ratio,
+    steering_angle: Angle,
 }
 impl TiltingGear {
     // Indicates the tilt angle already used with plane on ground standing still
@@ -58,17 +63,27 @@ impl TiltingGear {
         Self {
             tilt_animation_id: context
                 .get_identifier(format!("GEAR_{}_TILT_POSITION", contact_point_id)),
-            compression_id: context
-                .get_identifier(format!("GEAR ANIMATION POSITION:{}", contact_point_id)),
+            compression_id: context.get_identifier_with_metadata(
+                format!("GEAR ANIMATION POSITION:{}", contact_point_id),
+                VariableMetadata::new().written_externally(),
+            ),
+            steering_angle_id: context
+                .get_identifier(format!("GEAR_{}_STEERING_ANGLE", contact_point_id)),
             tilt_height_from_low_to_up,
             contact_point_offset_from_datum_ref_meters,
             tilting_max_angle,
 
             current_compression: Ratio::default(),
             tilt_position: Ratio::default(),
+            steering_angle: Angle::default(),
         }
     }
 
+    /// Steering angle of the gear, for gears fitted with a steering actuator
+    pub fn update_steering_angle(&mut self, steering_angle: Angle) {
+        self.steering_angle = steering_angle;
+    }
+
     pub fn update(&mut self, context: &UpdateContext) {
         let current_tire_height =
             height_over_ground(context, self.contact_point_offset_from_datum_ref_meters);
@@ -119,6 +134,7 @@ impl TiltingGear {
 impl SimulationElement for TiltingGear {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.tilt_animation_id, self.tilt_position.get::<ratio>());
+        writer.write(&self.steering_angle_id, self.steering_angle);
     }
 
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -150,9 +166,18 @@ impl LandingGear {
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
-            center_compression_id: context.get_identifier(Self::GEAR_CENTER_COMPRESSION.to_owned()),
-            left_compression_id: context.get_identifier(Self::GEAR_LEFT_COMPRESSION.to_owned()),
-            right_compression_id: context.get_identifier(Self::GEAR_RIGHT_COMPRESSION.to_owned()),
+            center_compression_id: context.get_identifier_with_metadata(
+                Self::GEAR_CENTER_COMPRESSION.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            left_compression_id: context.get_identifier_with_metadata(
+                Self::GEAR_LEFT_COMPRESSION.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            right_compression_id: context.get_identifier_with_metadata(
+                Self::GEAR_RIGHT_COMPRESSION.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
 
             center_compression: Ratio::new::<percent>(0.),
             left_compression: Ratio::new::<percent>(0.),
@@ -619,8 +644,10 @@ impl LandingGearHandleUnit {
 
         Self {
             gear_handle_real_position_id: context.get_identifier("GEAR_HANDLE_POSITION".to_owned()),
-            gear_handle_position_requested_id: context
-                .get_identifier("GEAR_LEVER_POSITION_REQUEST".to_owned()),
+            gear_handle_position_requested_id: context.get_identifier_with_metadata(
+                "GEAR_LEVER_POSITION_REQUEST".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             gear_handle_hits_lock_sound_id: context
                 .get_identifier("GEAR_HANDLE_HITS_LOCK_SOUND".to_owned()),
 
@@ -1939,6 +1966,20 @@ mod tests {
         assert!(tilt_position.get::<ratio>() < 1. && tilt_position.get::<ratio>() > 0.);
     }
 
+    #[test]
+    fn tilting_gear_writes_its_steering_angle() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
+            .with_update_before_power_distribution(|el, context, _| {
+                el.update(context);
+                el.update_steering_angle(Angle::new::<degree>(-12.));
+            });
+
+        test_bed.run();
+
+        let steering_angle: Angle = test_bed.read_by_name("GEAR_1_STEERING_ANGLE");
+        assert!((steering_angle.get::<degree>() + 12.).abs() < 0.001);
+    }
+
     fn test_tilting_gear_left(context: &mut InitContext) -> TiltingGear {
         TiltingGear::new(
             context,
@@ -0,0 +1,456 @@
+use std::time::Duration;
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        arinc429::SignStatus, low_pass_filter::LowPassFilter, ElectricalBusType, ElectricalBuses,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
+    },
+};
+use uom::si::{
+    f64::*,
+    pressure::psi,
+    ratio::ratio,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+pub trait TyreCondition {
+    fn is_deflated(&self, tyre_number: usize) -> bool;
+
+    /// Ratio of the given tyres which are still inflated enough to carry load
+    fn inflated_ratio(&self, tyre_numbers: &[usize]) -> Ratio {
+        if tyre_numbers.is_empty() {
+            return Ratio::new::<ratio>(1.);
+        }
+
+        let inflated_count = tyre_numbers
+            .iter()
+            .filter(|&&number| !self.is_deflated(number))
+            .count();
+
+        Ratio::new::<ratio>(inflated_count as f64 / tyre_numbers.len() as f64)
+    }
+}
+
+/// A single inflated tyre. Gas pressure follows the tyre temperature, which is driven by
+/// ambient temperature and by the heat soaking from the brake of the wheel if it has one.
+pub struct Tyre {
+    number: usize,
+    brake_temperature_id: Option<VariableIdentifier>,
+
+    cold_inflation_pressure: Pressure,
+    brake_temperature: ThermodynamicTemperature,
+    temperature_celsius: LowPassFilter<f64>,
+    temperature_is_initialised: bool,
+
+    gas_remaining: Ratio,
+    pressure: Pressure,
+
+    slow_leak: Failure,
+    burst: Failure,
+}
+impl Tyre {
+    // Cold inflation pressures are given for this gas temperature
+    const REFERENCE_TEMPERATURE_CELSIUS: f64 = 15.;
+    const STANDARD_ATMOSPHERE_PSI: f64 = 14.696;
+
+    // Part of the brake temperature above ambient that ends up in the tyre gas
+    const BRAKE_HEAT_COUPLING: f64 = 0.3;
+    const TEMPERATURE_TIME_CONSTANT: Duration = Duration::from_secs(600);
+
+    const SLOW_LEAK_TIME_CONSTANT_SECONDS: f64 = 3600.;
+
+    // Under this gas quantity the tyre runs flat and stops transmitting braking or cornering force
+    const DEFLATED_GAS_REMAINING_RATIO: f64 = 0.3;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        cold_inflation_pressure: Pressure,
+        brake_number: Option<usize>,
+    ) -> Self {
+        Self {
+            number,
+            brake_temperature_id: brake_number.map(|id| {
+                context.get_identifier_with_metadata(
+                    format!("BRAKE_TEMPERATURE_{}", id),
+                    VariableMetadata::new().written_externally(),
+                )
+            }),
+
+            cold_inflation_pressure,
+            brake_temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Self::REFERENCE_TEMPERATURE_CELSIUS,
+            ),
+            temperature_celsius: LowPassFilter::new_with_init_value(
+                Self::TEMPERATURE_TIME_CONSTANT,
+                Self::REFERENCE_TEMPERATURE_CELSIUS,
+            ),
+            temperature_is_initialised: false,
+
+            gas_remaining: Ratio::new::<ratio>(1.),
+            pressure: cold_inflation_pressure,
+
+            slow_leak: Failure::new(FailureType::TyreSlowLeak(number)),
+            burst: Failure::new(FailureType::TyreBurst(number)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.update_temperature(context);
+        self.update_gas_remaining(context);
+        self.update_pressure(context);
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext) {
+        let ambient_celsius = context.ambient_temperature().get::<degree_celsius>();
+
+        if !self.temperature_is_initialised {
+            self.temperature_celsius.reset(ambient_celsius);
+            self.temperature_is_initialised = true;
+        }
+
+        let brake_heat_celsius = if self.brake_temperature_id.is_some() {
+            (self.brake_temperature.get::<degree_celsius>() - ambient_celsius).max(0.)
+                * Self::BRAKE_HEAT_COUPLING
+        } else {
+            0.
+        };
+
+        self.temperature_celsius
+            .update(context.delta(), ambient_celsius + brake_heat_celsius);
+    }
+
+    fn update_gas_remaining(&mut self, context: &UpdateContext) {
+        if self.burst.is_active() {
+            self.gas_remaining = Ratio::new::<ratio>(0.);
+        } else if self.slow_leak.is_active() {
+            self.gas_remaining *=
+                (-context.delta_as_secs_f64() / Self::SLOW_LEAK_TIME_CONSTANT_SECONDS).exp();
+        }
+    }
+
+    fn update_pressure(&mut self, context: &UpdateContext) {
+        let reference_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::REFERENCE_TEMPERATURE_CELSIUS);
+
+        // Constant volume ideal gas: absolute pressure is proportional to absolute temperature
+        let reference_absolute_pressure =
+            self.cold_inflation_pressure + Pressure::new::<psi>(Self::STANDARD_ATMOSPHERE_PSI);
+        let absolute_pressure = reference_absolute_pressure
+            * self.gas_remaining.get::<ratio>()
+            * self.temperature().get::<kelvin>()
+            / reference_temperature.get::<kelvin>();
+
+        self.pressure =
+            (absolute_pressure - context.ambient_pressure()).max(Pressure::new::<psi>(0.));
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// Gauge pressure of the tyre
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.temperature_celsius.output())
+    }
+
+    pub fn is_burst(&self) -> bool {
+        self.burst.is_active()
+    }
+
+    pub fn is_deflated(&self) -> bool {
+        self.is_burst() || self.gas_remaining.get::<ratio>() < Self::DEFLATED_GAS_REMAINING_RATIO
+    }
+}
+impl SimulationElement for Tyre {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.slow_leak.accept(visitor);
+        self.burst.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        if let Some(id) = &self.brake_temperature_id {
+            self.brake_temperature = reader.read(id);
+        }
+    }
+}
+
+/// Tyre pressure indicating system. Owns the tyres of an aircraft and publishes
+/// each measured tyre pressure as an ARINC 429 word for the wheel page.
+pub struct TyrePressureIndicatingSystem<const N: usize> {
+    tyre_pressure_ids: [VariableIdentifier; N],
+
+    tyres: [Tyre; N],
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl<const N: usize> TyrePressureIndicatingSystem<N> {
+    pub fn new(context: &mut InitContext, powered_by: ElectricalBusType, tyres: [Tyre; N]) -> Self {
+        Self {
+            tyre_pressure_ids: std::array::from_fn(|index| {
+                context.get_identifier(format!("TPIS_TYRE_{}_PRESSURE", tyres[index].number()))
+            }),
+            tyres,
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        for tyre in &mut self.tyres {
+            tyre.update(context);
+        }
+    }
+
+    pub fn tyre(&self, tyre_number: usize) -> Option<&Tyre> {
+        self.tyres.iter().find(|tyre| tyre.number() == tyre_number)
+    }
+}
+impl<const N: usize> TyreCondition for TyrePressureIndicatingSystem<N> {
+    fn is_deflated(&self, tyre_number: usize) -> bool {
+        self.tyres
+            .iter()
+            .any(|tyre| tyre.number() == tyre_number && tyre.is_deflated())
+    }
+}
+impl<const N: usize> SimulationElement for TyrePressureIndicatingSystem<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.tyres, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let ssm = if self.is_powered {
+            SignStatus::NormalOperation
+        } else {
+            SignStatus::FailureWarning
+        };
+
+        for (id, tyre) in self.tyre_pressure_ids.iter().zip(self.tyres.iter()) {
+            writer.write_arinc429(id, tyre.pressure().get::<psi>(), ssm);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::electrical::{test::TestElectricitySource, ElectricalBus, Electricity};
+    use crate::shared::{arinc429::Arinc429Word, PotentialOrigin};
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use uom::si::electric_potential::volt;
+
+    struct TestAircraft {
+        tpis: TyrePressureIndicatingSystem<2>,
+
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        is_dc_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let tyres = [
+                Tyre::new(context, 1, Pressure::new::<psi>(200.), Some(1)),
+                Tyre::new(context, 2, Pressure::new::<psi>(180.), None),
+            ];
+
+            Self {
+                tpis: TyrePressureIndicatingSystem::new(
+                    context,
+                    ElectricalBusType::DirectCurrent(1),
+                    tyres,
+                ),
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                is_dc_1_powered: true,
+            }
+        }
+
+        fn set_dc_1_power(&mut self, is_powered: bool) {
+            self.is_dc_1_powered = is_powered;
+        }
+
+        fn tyre_pressure(&self, number: usize) -> Pressure {
+            self.tpis.tyre(number).unwrap().pressure()
+        }
+
+        fn tyre_temperature(&self, number: usize) -> ThermodynamicTemperature {
+            self.tpis.tyre(number).unwrap().temperature()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.powered_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.powered_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.powered_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.tpis.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tpis.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_at_ambient_temperature(celsius: f64) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(celsius));
+        test_bed.set_ambient_pressure(Pressure::new::<psi>(Tyre::STANDARD_ATMOSPHERE_PSI));
+
+        test_bed
+    }
+
+    fn run_for_minutes(test_bed: &mut SimulationTestBed<TestAircraft>, minutes: u64) {
+        for _ in 0..minutes {
+            test_bed.run_with_delta(Duration::from_secs(60));
+        }
+    }
+
+    #[test]
+    fn tyre_is_at_cold_inflation_pressure_at_reference_temperature() {
+        let mut test_bed = test_bed_at_ambient_temperature(15.);
+        test_bed.run();
+
+        assert!((test_bed.query(|a| a.tyre_pressure(1)).get::<psi>() - 200.).abs() < 0.1);
+        assert!((test_bed.query(|a| a.tyre_pressure(2)).get::<psi>() - 180.).abs() < 0.1);
+    }
+
+    #[test]
+    fn tyre_pressure_is_lower_in_cold_weather() {
+        let mut test_bed = test_bed_at_ambient_temperature(-30.);
+        test_bed.run();
+
+        let pressure = test_bed.query(|a| a.tyre_pressure(1)).get::<psi>();
+        assert!(pressure < 175.);
+        assert!(pressure > 165.);
+    }
+
+    #[test]
+    fn hot_brake_heats_up_its_tyre_and_raises_pressure() {
+        let mut test_bed = test_bed_at_ambient_temperature(15.);
+        test_bed.write_by_name(
+            "BRAKE_TEMPERATURE_1",
+            ThermodynamicTemperature::new::<degree_celsius>(300.),
+        );
+        run_for_minutes(&mut test_bed, 30);
+
+        assert!(
+            test_bed
+                .query(|a| a.tyre_temperature(1))
+                .get::<degree_celsius>()
+                > 80.
+        );
+        assert!(test_bed.query(|a| a.tyre_pressure(1)).get::<psi>() > 240.);
+
+        // Tyre without brake stays at ambient temperature
+        assert!((test_bed.query(|a| a.tyre_pressure(2)).get::<psi>() - 180.).abs() < 0.1);
+    }
+
+    #[test]
+    fn slow_leak_deflates_tyre_over_time() {
+        let mut test_bed = test_bed_at_ambient_temperature(15.);
+        test_bed.fail(FailureType::TyreSlowLeak(1));
+
+        run_for_minutes(&mut test_bed, 10);
+        let pressure_after_10_minutes = test_bed.query(|a| a.tyre_pressure(1)).get::<psi>();
+        assert!(pressure_after_10_minutes < 180.);
+        assert!(pressure_after_10_minutes > 150.);
+        assert!(!test_bed.query(|a| a.tpis.is_deflated(1)));
+
+        run_for_minutes(&mut test_bed, 120);
+        assert!(test_bed.query(|a| a.tyre_pressure(1)).get::<psi>() < 30.);
+        assert!(test_bed.query(|a| a.tpis.is_deflated(1)));
+        assert!(!test_bed.query(|a| a.tpis.is_deflated(2)));
+    }
+
+    #[test]
+    fn burst_tyre_has_no_pressure_and_is_deflated() {
+        let mut test_bed = test_bed_at_ambient_temperature(15.);
+        test_bed.fail(FailureType::TyreBurst(2));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.tyre_pressure(2)).get::<psi>() < 0.01);
+        assert!(test_bed.query(|a| a.tpis.tyre(2).unwrap().is_burst()));
+        assert!(test_bed.query(|a| a.tpis.is_deflated(2)));
+        assert!(!test_bed.query(|a| a.tpis.is_deflated(1)));
+    }
+
+    #[test]
+    fn inflated_ratio_counts_deflated_tyres() {
+        let mut test_bed = test_bed_at_ambient_temperature(15.);
+        test_bed.fail(FailureType::TyreBurst(1));
+        test_bed.run();
+
+        assert!(
+            (test_bed
+                .query(|a| a.tpis.inflated_ratio(&[1, 2]))
+                .get::<ratio>()
+                - 0.5)
+                .abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (test_bed
+                .query(|a| a.tpis.inflated_ratio(&[2]))
+                .get::<ratio>()
+                - 1.)
+                .abs()
+                < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn publishes_tyre_pressures_as_arinc429() {
+        let mut test_bed = test_bed_at_ambient_temperature(15.);
+        test_bed.run();
+
+        let word: Arinc429Word<f64> = test_bed.read_arinc429_by_name("TPIS_TYRE_1_PRESSURE");
+        assert!(word.is_normal_operation());
+        assert!((word.value() - 200.).abs() < 0.1);
+    }
+
+    #[test]
+    fn arinc429_words_are_failure_warning_when_unpowered() {
+        let mut test_bed = test_bed_at_ambient_temperature(15.);
+        test_bed.command(|a| a.set_dc_1_power(false));
+        test_bed.run();
+
+        let word: Arinc429Word<f64> = test_bed.read_arinc429_by_name("TPIS_TYRE_2_PRESSURE");
+        assert!(word.is_failure_warning());
+    }
+}
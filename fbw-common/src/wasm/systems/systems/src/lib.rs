@@ -9,11 +9,14 @@ pub mod macros;
 
 pub mod air_conditioning;
 pub mod apu;
+pub mod doors;
 pub mod electrical;
 pub mod engine;
 pub mod enhanced_gpwc;
 pub mod failures;
+pub mod fuel;
 pub mod hydraulic;
+pub mod ice_rain_protection;
 pub mod icing_state;
 pub mod indicating_recording;
 pub mod integrated_modular_avionics;
@@ -26,4 +29,5 @@ pub mod pneumatic;
 pub mod shared;
 pub mod simulation;
 pub mod structural_flex;
+pub mod weight_and_balance;
 pub mod wind_turbine;
@@ -1,11 +1,13 @@
 use crate::air_conditioning::AdirsToAirCondInterface;
-use crate::simulation::{InitContext, VariableIdentifier};
+use crate::simulation::{InitContext, VariableIdentifier, VariableMetadata};
 use crate::{
+    failures::{Failure, FailureType},
     overhead::{IndicationLight, OnOffFaultPushButton},
     shared::{
         arinc429::{Arinc429Word, SignStatus},
         low_pass_filter::LowPassFilter,
-        AdirsDiscreteOutputs, AdirsMeasurementOutputs, MachNumber,
+        random_from_range, AdirsDiscreteOutputs, AdirsMeasurementOutputs, AirDataProbeIcing,
+        GpsReceiverOutputs, MachNumber,
     },
     simulation::{
         Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -23,9 +25,10 @@ use uom::si::{
     angular_velocity::degree_per_second,
     f64::*,
     length::foot,
-    pressure::hectopascal,
+    pressure::{hectopascal, pascal},
     ratio::ratio,
-    velocity::{foot_per_minute, knot},
+    thermodynamic_temperature::kelvin,
+    velocity::{foot_per_minute, knot, meter_per_second},
 };
 
 pub struct AirDataInertialReferenceSystemOverheadPanel {
@@ -134,7 +137,10 @@ struct InertialReferenceModeSelector {
 impl InertialReferenceModeSelector {
     fn new(context: &mut InitContext, number: usize) -> Self {
         Self {
-            mode_id: context.get_identifier(Self::mode_id(number)),
+            mode_id: context.get_identifier_with_metadata(
+                Self::mode_id(number),
+                VariableMetadata::new().written_externally(),
+            ),
             // We start in an aligned state to support starting on the
             // runway or in the air.
             mode: InertialReferenceMode::Navigation,
@@ -277,7 +283,10 @@ impl AdirsSimulatorData {
             latitude_id: context.get_identifier(Self::LATITUDE.to_owned()),
             latitude: Default::default(),
 
-            longitude_id: context.get_identifier(Self::LONGITUDE.to_owned()),
+            longitude_id: context.get_identifier_with_metadata(
+                Self::LONGITUDE.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             longitude: Default::default(),
 
             pitch_id: context.get_identifier(Self::PITCH.to_owned()),
@@ -286,38 +295,67 @@ impl AdirsSimulatorData {
             roll_id: context.get_identifier(Self::ROLL.to_owned()),
             roll: Default::default(),
 
-            body_rotation_rate_x_id: context.get_identifier(Self::BODY_ROTATION_RATE_X.to_owned()),
+            body_rotation_rate_x_id: context.get_identifier_with_metadata(
+                Self::BODY_ROTATION_RATE_X.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             body_rotation_rate_x: Default::default(),
 
-            body_rotation_rate_y_id: context.get_identifier(Self::BODY_ROTATION_RATE_Y.to_owned()),
+            body_rotation_rate_y_id: context.get_identifier_with_metadata(
+                Self::BODY_ROTATION_RATE_Y.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             body_rotation_rate_y: Default::default(),
 
-            body_rotation_rate_z_id: context.get_identifier(Self::BODY_ROTATION_RATE_Z.to_owned()),
+            body_rotation_rate_z_id: context.get_identifier_with_metadata(
+                Self::BODY_ROTATION_RATE_Z.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             body_rotation_rate_z: Default::default(),
 
-            heading_id: context.get_identifier(Self::HEADING.to_owned()),
+            heading_id: context.get_identifier_with_metadata(
+                Self::HEADING.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             heading: Default::default(),
 
             true_heading_id: context.get_identifier(Self::TRUE_HEADING.to_owned()),
             true_heading: Default::default(),
 
-            track_id: context.get_identifier(Self::TRACK.to_owned()),
+            track_id: context.get_identifier_with_metadata(
+                Self::TRACK.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             track: Default::default(),
 
-            true_track_id: context.get_identifier(Self::TRUE_TRACK.to_owned()),
+            true_track_id: context.get_identifier_with_metadata(
+                Self::TRUE_TRACK.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             true_track: Default::default(),
 
-            ground_speed_id: context.get_identifier(Self::GROUND_SPEED.to_owned()),
+            ground_speed_id: context.get_identifier_with_metadata(
+                Self::GROUND_SPEED.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             ground_speed: Default::default(),
 
-            total_air_temperature_id: context
-                .get_identifier(Self::TOTAL_AIR_TEMPERATURE.to_owned()),
+            total_air_temperature_id: context.get_identifier_with_metadata(
+                Self::TOTAL_AIR_TEMPERATURE.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             total_air_temperature: Default::default(),
 
-            angle_of_attack_id: context.get_identifier(Self::ANGLE_OF_ATTACK.to_owned()),
+            angle_of_attack_id: context.get_identifier_with_metadata(
+                Self::ANGLE_OF_ATTACK.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             angle_of_attack: Default::default(),
 
-            baro_correction_1_id: context.get_identifier(Self::BARO_CORRECTION_1_HPA.to_owned()),
+            baro_correction_1_id: context.get_identifier_with_metadata(
+                Self::BARO_CORRECTION_1_HPA.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             baro_correction_1: Default::default(),
         }
     }
@@ -359,6 +397,7 @@ pub struct AirDataInertialReferenceSystem {
     adirus: [AirDataInertialReferenceUnit; 3],
     configured_align_time: AlignTime,
     simulator_data: AdirsSimulatorData,
+    gps_is_available: [bool; 3],
 }
 impl AirDataInertialReferenceSystem {
     const REMAINING_ALIGNMENT_TIME_KEY: &'static str = "ADIRS_REMAINING_IR_ALIGNMENT_TIME";
@@ -370,8 +409,10 @@ impl AirDataInertialReferenceSystem {
         Self {
             remaining_alignment_time_id: context
                 .get_identifier(Self::REMAINING_ALIGNMENT_TIME_KEY.to_owned()),
-            configured_align_time_id: context
-                .get_identifier(Self::CONFIGURED_ALIGN_TIME_KEY.to_owned()),
+            configured_align_time_id: context.get_identifier_with_metadata(
+                Self::CONFIGURED_ALIGN_TIME_KEY.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
             uses_gps_as_primary_id: context
                 .get_identifier(Self::USES_GPS_AS_PRIMARY_KEY.to_owned()),
 
@@ -382,6 +423,7 @@ impl AirDataInertialReferenceSystem {
             ],
             configured_align_time: AlignTime::Realistic,
             simulator_data: AdirsSimulatorData::new(context),
+            gps_is_available: [false; 3],
         }
     }
 
@@ -394,7 +436,41 @@ impl AirDataInertialReferenceSystem {
         let simulator_data = self.simulator_data;
         self.adirus
             .iter_mut()
-            .for_each(|adiru| adiru.update(context, overhead, align_time, simulator_data));
+            .zip(self.gps_is_available)
+            .for_each(|(adiru, gps_is_available)| {
+                adiru.update(
+                    context,
+                    overhead,
+                    align_time,
+                    simulator_data,
+                    gps_is_available,
+                )
+            });
+    }
+
+    /// Provides the IRs with the data of the two GPS receivers, which they mix with their
+    /// inertial position. ADIRU 1 and 3 receive the data of GPS receiver 1, ADIRU 2 that of
+    /// GPS receiver 2. Without valid GPS data, the position output by each IR drifts away
+    /// from the actual position. Call this before [`Self::update`].
+    pub fn update_gps_data(&mut self, gps_receivers: [&impl GpsReceiverOutputs; 2]) {
+        let gps_is_available = gps_receivers.map(|gps| {
+            gps.latitude().is_normal_operation()
+                && gps.longitude().is_normal_operation()
+                && gps.horizontal_integrity_limit().is_normal_operation()
+        });
+
+        self.gps_is_available = [
+            gps_is_available[0],
+            gps_is_available[1],
+            gps_is_available[0],
+        ];
+    }
+
+    /// Informs the ADRs about ice obstructing their probes. Call this before [`Self::update`].
+    pub fn update_probe_icing(&mut self, probes: &impl AirDataProbeIcing) {
+        self.adirus
+            .iter_mut()
+            .for_each(|adiru| adiru.update_probe_icing(probes));
     }
 
     fn remaining_align_duration(&self) -> Duration {
@@ -409,10 +485,13 @@ impl AirDataInertialReferenceSystem {
             .unwrap_or_else(|| Duration::from_secs(0))
     }
 
-    fn any_adiru_fully_aligned_with_ir_on(&self) -> bool {
+    fn any_adiru_mixes_gps_data(&self) -> bool {
         self.adirus
             .iter()
-            .any(|adiru| adiru.is_fully_aligned() && adiru.ir_is_on())
+            .zip(self.gps_is_available)
+            .any(|(adiru, gps_is_available)| {
+                gps_is_available && adiru.is_fully_aligned() && adiru.ir_is_on()
+            })
     }
 
     fn ir_has_fault(&self, number: usize) -> bool {
@@ -438,7 +517,7 @@ impl SimulationElement for AirDataInertialReferenceSystem {
         );
         writer.write(
             &self.uses_gps_as_primary_id,
-            self.any_adiru_fully_aligned_with_ir_on(),
+            self.any_adiru_mixes_gps_data(),
         )
     }
 }
@@ -501,6 +580,10 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length> {
         self.adirus[adiru_number - 1].altitude()
     }
+
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+        self.adirus[adiru_number - 1].computed_airspeed()
+    }
 }
 
 struct AirDataInertialReferenceUnit {
@@ -535,14 +618,25 @@ impl AirDataInertialReferenceUnit {
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
         align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
+        gps_is_available: bool,
     ) {
         self.adr.update(context, overhead, simulator_data);
-        self.ir
-            .update(context, &self.adr, overhead, align_time, simulator_data);
+        self.ir.update(
+            context,
+            &self.adr,
+            overhead,
+            align_time,
+            simulator_data,
+            gps_is_available,
+        );
 
         self.update_discrete_outputs();
     }
 
+    fn update_probe_icing(&mut self, probes: &impl AirDataProbeIcing) {
+        self.adr.update_probe_icing(probes);
+    }
+
     fn is_fully_aligned(&self) -> bool {
         self.ir.is_fully_aligned()
     }
@@ -653,6 +747,10 @@ impl AirDataInertialReferenceUnit {
         self.adr.altitude()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        self.adr.computed_airspeed()
+    }
+
     fn ground_speed(&self) -> Arinc429Word<Velocity> {
         self.ir.ground_speed()
     }
@@ -789,6 +887,208 @@ trait TrueAirspeedSource {
     fn true_airspeed(&self) -> Arinc429Word<Velocity>;
 }
 
+/// The pitot probe, static ports, angle of attack vane and TAT probe feeding one ADR.
+/// Blocked, jammed or iced sensors keep on delivering the pressures and values they
+/// trapped. The ADR cannot detect this and computes its air data from them as usual.
+struct AirDataSensors {
+    pitot_blockage: Failure,
+    pitot_drain_blockage: Failure,
+    static_port_blockage: Failure,
+    angle_of_attack_vane_jam: Failure,
+
+    pitot_is_iced: bool,
+    angle_of_attack_sensor_is_iced: bool,
+    total_air_temperature_probe_is_iced: bool,
+
+    total_pressure: Pressure,
+    static_pressure: Pressure,
+    pressure_altitude: Length,
+    baro_corrected_altitude: Length,
+    vertical_speed: Velocity,
+    computed_airspeed: Velocity,
+    mach: MachNumber,
+    true_airspeed: Velocity,
+    angle_of_attack: Angle,
+    total_air_temperature: ThermodynamicTemperature,
+}
+impl AirDataSensors {
+    /// The time constant with which the pressure trapped in a blocked pitot probe
+    /// escapes through its drain hole.
+    const PITOT_DRAIN_TIME_CONSTANT: Duration = Duration::from_secs(5);
+    const SEA_LEVEL_PRESSURE_PASCAL: f64 = 101325.;
+    const SEA_LEVEL_SPEED_OF_SOUND_KNOTS: f64 = 661.47;
+    const SPECIFIC_GAS_CONSTANT_AIR: f64 = 287.05;
+    const HEAT_CAPACITY_RATIO_AIR: f64 = 1.4;
+
+    fn new(number: usize) -> Self {
+        Self {
+            pitot_blockage: Failure::new(FailureType::PitotBlockage(number)),
+            pitot_drain_blockage: Failure::new(FailureType::PitotDrainBlockage(number)),
+            static_port_blockage: Failure::new(FailureType::StaticPortBlockage(number)),
+            angle_of_attack_vane_jam: Failure::new(FailureType::AngleOfAttackVaneJam(number)),
+
+            pitot_is_iced: false,
+            angle_of_attack_sensor_is_iced: false,
+            total_air_temperature_probe_is_iced: false,
+
+            total_pressure: Pressure::default(),
+            static_pressure: Pressure::default(),
+            pressure_altitude: Length::default(),
+            baro_corrected_altitude: Length::default(),
+            vertical_speed: Velocity::default(),
+            computed_airspeed: Velocity::default(),
+            mach: MachNumber::default(),
+            true_airspeed: Velocity::default(),
+            angle_of_attack: Angle::default(),
+            total_air_temperature: ThermodynamicTemperature::default(),
+        }
+    }
+
+    fn update_icing(&mut self, number: usize, probes: &impl AirDataProbeIcing) {
+        self.pitot_is_iced = probes.pitot_is_iced(number);
+        self.angle_of_attack_sensor_is_iced = probes.angle_of_attack_sensor_is_iced(number);
+        self.total_air_temperature_probe_is_iced =
+            probes.total_air_temperature_probe_is_iced(number);
+    }
+
+    fn update(&mut self, context: &UpdateContext, simulator_data: AdirsSimulatorData) {
+        let static_port_is_blocked = self.static_port_blockage.is_active();
+        if static_port_is_blocked {
+            self.vertical_speed = Velocity::default();
+        } else {
+            self.static_pressure = context.ambient_pressure();
+            self.pressure_altitude = context.pressure_altitude();
+            self.baro_corrected_altitude = context.indicated_altitude();
+            self.vertical_speed = simulator_data.vertical_speed;
+        }
+
+        let pitot_is_blocked = self.pitot_is_blocked();
+        if !pitot_is_blocked {
+            self.total_pressure = context.ambient_pressure()
+                + Self::impact_pressure_from_computed_airspeed(context.indicated_airspeed());
+        } else if !self.pitot_drain_is_blocked() {
+            // With its drain hole open, the pressure trapped in the pitot probe escapes
+            // until it equals the static pressure. The airspeed thus drops to zero.
+            let remaining_ratio = (-context.delta_as_secs_f64()
+                / Self::PITOT_DRAIN_TIME_CONSTANT.as_secs_f64())
+            .exp();
+            self.total_pressure = self.static_pressure
+                + (self.total_pressure - self.static_pressure) * remaining_ratio;
+        }
+
+        if pitot_is_blocked || static_port_is_blocked {
+            let impact_pressure =
+                (self.total_pressure - self.static_pressure).max(Pressure::new::<pascal>(0.));
+            self.computed_airspeed = Self::computed_airspeed_from_impact_pressure(impact_pressure);
+            self.mach = Self::mach_from_pressures(impact_pressure, self.static_pressure);
+            self.true_airspeed = Velocity::new::<meter_per_second>(
+                self.mach.0
+                    * (Self::HEAT_CAPACITY_RATIO_AIR
+                        * Self::SPECIFIC_GAS_CONSTANT_AIR
+                        * context.ambient_temperature().get::<kelvin>())
+                    .sqrt(),
+            );
+        } else {
+            self.computed_airspeed = context.indicated_airspeed();
+            self.mach = simulator_data.mach;
+            self.true_airspeed = simulator_data.true_airspeed;
+        }
+
+        if !self.angle_of_attack_vane_jam.is_active() && !self.angle_of_attack_sensor_is_iced {
+            self.angle_of_attack = simulator_data.angle_of_attack;
+        }
+
+        if !self.total_air_temperature_probe_is_iced {
+            self.total_air_temperature = simulator_data.total_air_temperature;
+        }
+    }
+
+    /// An iced pitot probe is obstructed at both its inlet and its drain hole.
+    fn pitot_is_blocked(&self) -> bool {
+        self.pitot_blockage.is_active() || self.pitot_is_iced
+    }
+
+    fn pitot_drain_is_blocked(&self) -> bool {
+        self.pitot_drain_blockage.is_active() || self.pitot_is_iced
+    }
+
+    fn impact_pressure_from_computed_airspeed(computed_airspeed: Velocity) -> Pressure {
+        let speed_ratio = computed_airspeed.get::<knot>() / Self::SEA_LEVEL_SPEED_OF_SOUND_KNOTS;
+
+        Pressure::new::<pascal>(
+            Self::SEA_LEVEL_PRESSURE_PASCAL * ((1. + 0.2 * speed_ratio.powi(2)).powf(3.5) - 1.),
+        )
+    }
+
+    fn computed_airspeed_from_impact_pressure(impact_pressure: Pressure) -> Velocity {
+        Velocity::new::<knot>(
+            Self::SEA_LEVEL_SPEED_OF_SOUND_KNOTS
+                * (5.
+                    * ((impact_pressure.get::<pascal>() / Self::SEA_LEVEL_PRESSURE_PASCAL + 1.)
+                        .powf(2. / 7.)
+                        - 1.))
+                    .sqrt(),
+        )
+    }
+
+    fn mach_from_pressures(impact_pressure: Pressure, static_pressure: Pressure) -> MachNumber {
+        if static_pressure <= Pressure::new::<pascal>(0.) {
+            return MachNumber::default();
+        }
+
+        MachNumber::from(
+            (5. * ((impact_pressure / static_pressure).get::<ratio>() + 1.).powf(2. / 7.) - 5.)
+                .sqrt(),
+        )
+    }
+
+    fn static_pressure(&self) -> Pressure {
+        self.static_pressure
+    }
+
+    fn pressure_altitude(&self) -> Length {
+        self.pressure_altitude
+    }
+
+    fn baro_corrected_altitude(&self) -> Length {
+        self.baro_corrected_altitude
+    }
+
+    fn vertical_speed(&self) -> Velocity {
+        self.vertical_speed
+    }
+
+    fn computed_airspeed(&self) -> Velocity {
+        self.computed_airspeed
+    }
+
+    fn mach(&self) -> MachNumber {
+        self.mach
+    }
+
+    fn true_airspeed(&self) -> Velocity {
+        self.true_airspeed
+    }
+
+    fn angle_of_attack(&self) -> Angle {
+        self.angle_of_attack
+    }
+
+    fn total_air_temperature(&self) -> ThermodynamicTemperature {
+        self.total_air_temperature
+    }
+}
+impl SimulationElement for AirDataSensors {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.pitot_blockage.accept(visitor);
+        self.pitot_drain_blockage.accept(visitor);
+        self.static_port_blockage.accept(visitor);
+        self.angle_of_attack_vane_jam.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 struct AirDataReference {
     number: usize,
     is_on: bool,
@@ -816,6 +1116,8 @@ struct AirDataReference {
     total_air_temperature: AdirsData<ThermodynamicTemperature>,
     angle_of_attack: AdirsData<Angle>,
 
+    sensors: AirDataSensors,
+
     remaining_initialisation_duration: Option<Duration>,
 }
 impl AirDataReference {
@@ -889,6 +1191,8 @@ impl AirDataReference {
             total_air_temperature: AdirsData::new_adr(context, number, Self::TOTAL_AIR_TEMPERATURE),
             angle_of_attack: AdirsData::new_adr(context, number, Self::ANGLE_OF_ATTACK),
 
+            sensors: AirDataSensors::new(number),
+
             // Start fully initialised.
             remaining_initialisation_duration: Some(Duration::from_secs(0)),
         }
@@ -902,9 +1206,14 @@ impl AirDataReference {
     ) {
         self.is_on = overhead.adr_is_on(self.number);
         self.update_remaining_initialisation_duration(context, overhead);
+        self.sensors.update(context, simulator_data);
         self.update_values(context, simulator_data);
     }
 
+    fn update_probe_icing(&mut self, probes: &impl AirDataProbeIcing) {
+        self.sensors.update_icing(self.number, probes);
+    }
+
     fn update_remaining_initialisation_duration(
         &mut self,
         context: &UpdateContext,
@@ -952,29 +1261,29 @@ impl AirDataReference {
                 .set_normal_operation_value(simulator_data.baro_correction_1);
 
             let pressure_alt = Length::new::<foot>(
-                ((context.pressure_altitude().get::<foot>() * 2.).round() / 2.)
+                ((self.sensors.pressure_altitude().get::<foot>() * 2.).round() / 2.)
                     .clamp(-131072., 131072.),
             );
 
             // FIXME split sides and do the correction ourselves
             // FIXME this currently returns pressure alt when STD mode is selected on the FCU
             let baro_alt = Length::new::<foot>(
-                ((context.indicated_altitude().get::<foot>() * 2.).round() / 2.)
+                ((self.sensors.baro_corrected_altitude().get::<foot>() * 2.).round() / 2.)
                     .clamp(-131072., 131072.),
             );
 
             self.corrected_average_static_pressure
-                .set_normal_operation_value(context.ambient_pressure());
+                .set_normal_operation_value(self.sensors.static_pressure());
             self.altitude.set_normal_operation_value(pressure_alt);
             self.baro_corrected_altitude_1
                 .set_normal_operation_value(baro_alt);
             self.baro_corrected_altitude_2
                 .set_normal_operation_value(baro_alt);
             self.barometric_vertical_speed
-                .set_normal_operation_value(simulator_data.vertical_speed.get::<foot_per_minute>());
+                .set_normal_operation_value(self.sensors.vertical_speed().get::<foot_per_minute>());
 
             // If CAS is below 30kn, output as 0 with SSM = NCD
-            let computed_airspeed = context.indicated_airspeed();
+            let computed_airspeed = self.sensors.computed_airspeed();
             self.computed_airspeed.normal_above_threshold_ncd_otherwise(
                 Velocity::new::<knot>(Self::MINIMUM_CAS),
                 computed_airspeed,
@@ -983,17 +1292,17 @@ impl AirDataReference {
             // If mach is below 0.1, output as 0 with SSM = NCD
             self.mach.normal_above_threshold_ncd_otherwise(
                 MachNumber::from(Self::MINIMUM_MACH),
-                simulator_data.mach,
+                self.sensors.mach(),
             );
 
             // If TAS is below 60 kts, output as 0 kt with SSM = NCD.
             self.true_airspeed.normal_above_threshold_ncd_otherwise(
                 Velocity::new::<knot>(Self::MINIMUM_TAS),
-                simulator_data.true_airspeed,
+                self.sensors.true_airspeed(),
             );
 
             self.angle_of_attack.set_value(
-                simulator_data.angle_of_attack,
+                self.sensors.angle_of_attack(),
                 if computed_airspeed < Velocity::new::<knot>(Self::MINIMUM_CAS_FOR_AOA) {
                     SignStatus::NoComputedData
                 } else {
@@ -1002,7 +1311,7 @@ impl AirDataReference {
             );
 
             self.total_air_temperature
-                .set_normal_operation_value(simulator_data.total_air_temperature);
+                .set_normal_operation_value(self.sensors.total_air_temperature());
             self.static_air_temperature
                 .set_normal_operation_value(context.ambient_temperature());
         }
@@ -1024,6 +1333,10 @@ impl AirDataReference {
         Arinc429Word::new(self.altitude.value(), self.altitude.ssm())
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        Arinc429Word::new(self.computed_airspeed.value(), self.computed_airspeed.ssm())
+    }
+
     fn baro_correction_1(&self) -> Arinc429Word<Pressure> {
         Arinc429Word::new(
             self.baro_correction_1_hpa.value(),
@@ -1044,6 +1357,12 @@ impl TrueAirspeedSource for AirDataReference {
     }
 }
 impl SimulationElement for AirDataReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.sensors.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.baro_correction_1_hpa
             .write_to_converted(writer, |value| value.get::<hectopascal>());
@@ -1116,6 +1435,77 @@ bitflags! {
     }
 }
 
+/// The position computed by an IR from its accelerometers drifts away from the actual
+/// position with the time since alignment. While GPS data is available, the IR estimates
+/// this drift by comparing its inertial position with the GPS position, and corrects its
+/// output position (GPIRS position) for it. When GPS data is lost, the last estimate is
+/// retained and the output position starts drifting from there.
+struct InertialPositionDrift {
+    /// The drift rate in NM per hour.
+    rate: Velocity,
+    direction: Angle,
+    time_since_alignment: Duration,
+    /// North and east components of the estimated drift in NM.
+    estimated_error: Vector2<f64>,
+}
+impl InertialPositionDrift {
+    const MINIMUM_DRIFT_RATE_KNOTS: f64 = 0.5;
+    const MAXIMUM_DRIFT_RATE_KNOTS: f64 = 1.5;
+
+    fn new() -> Self {
+        Self {
+            rate: Velocity::new::<knot>(random_from_range(
+                Self::MINIMUM_DRIFT_RATE_KNOTS,
+                Self::MAXIMUM_DRIFT_RATE_KNOTS,
+            )),
+            direction: Angle::new::<degree>(random_from_range(0., 360.)),
+            time_since_alignment: Duration::from_secs(0),
+            estimated_error: Vector2::zeros(),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, is_aligned: bool, gps_is_available: bool) {
+        if is_aligned {
+            self.time_since_alignment += context.delta();
+        }
+
+        if gps_is_available {
+            self.estimated_error = self.inertial_error();
+        }
+    }
+
+    fn inertial_error(&self) -> Vector2<f64> {
+        let distance_nm = self.rate.get::<knot>() * self.time_since_alignment.as_secs_f64() / 3600.;
+        let direction = self.direction.get::<radian>();
+
+        Vector2::new(distance_nm * direction.cos(), distance_nm * direction.sin())
+    }
+
+    /// The position error in NM which remains after mixing with GPS data.
+    fn position_error(&self) -> Vector2<f64> {
+        self.inertial_error() - self.estimated_error
+    }
+
+    fn drifted_position(&self, latitude: Angle, longitude: Angle) -> (Angle, Angle) {
+        let error = self.position_error();
+        let latitude_degrees = latitude.get::<degree>();
+        // One minute of latitude equals one NM. Minutes of longitude shrink towards the poles.
+        let longitude_nm_per_degree = 60. * latitude_degrees.to_radians().cos().max(0.01);
+
+        let drifted_longitude = longitude.get::<degree>() + error[1] / longitude_nm_per_degree;
+        (
+            Angle::new::<degree>((latitude_degrees + error[0] / 60.).clamp(-90., 90.)),
+            Angle::new::<degree>(if drifted_longitude > 180. {
+                drifted_longitude - 360.
+            } else if drifted_longitude <= -180. {
+                drifted_longitude + 360.
+            } else {
+                drifted_longitude
+            }),
+        )
+    }
+}
+
 struct InertialReference {
     number: usize,
     is_on: bool,
@@ -1126,6 +1516,7 @@ struct InertialReference {
     remaining_attitude_initialisation_duration: Option<Duration>,
     wind_velocity: LowPassFilter<Vector2<f64>>,
     extreme_latitude: bool,
+    position_drift: InertialPositionDrift,
 
     pitch: AdirsData<Angle>,
     roll: AdirsData<Angle>,
@@ -1205,6 +1596,7 @@ impl InertialReference {
             remaining_attitude_initialisation_duration: Some(Duration::from_secs(0)),
             wind_velocity: LowPassFilter::new(Self::WIND_VELOCITY_TIME_CONSTANT),
             extreme_latitude: false,
+            position_drift: InertialPositionDrift::new(),
 
             pitch: AdirsData::new_ir(context, number, Self::PITCH),
             roll: AdirsData::new_ir(context, number, Self::ROLL),
@@ -1243,9 +1635,14 @@ impl InertialReference {
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
         configured_align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
+        gps_is_available: bool,
     ) {
         self.is_on = overhead.ir_is_on(self.number);
 
+        if self.alignment_starting(overhead.mode_of(self.number)) {
+            self.position_drift = InertialPositionDrift::new();
+        }
+
         self.update_fault_flash_duration(context, overhead);
         self.update_remaining_attitude_align_duration(context, overhead);
         self.update_remaining_align_duration(
@@ -1254,6 +1651,8 @@ impl InertialReference {
             configured_align_time,
             simulator_data,
         );
+        self.position_drift
+            .update(context, self.is_fully_aligned(), gps_is_available);
 
         self.update_latitude(simulator_data);
         self.update_attitude_values(context, simulator_data);
@@ -1597,8 +1996,11 @@ impl InertialReference {
         self.ground_speed
             .set_value(simulator_data.ground_speed, ssm);
 
-        self.latitude.set_value(simulator_data.latitude, ssm);
-        self.longitude.set_value(simulator_data.longitude, ssm);
+        let (latitude, longitude) = self
+            .position_drift
+            .drifted_position(simulator_data.latitude, simulator_data.longitude);
+        self.latitude.set_value(latitude, ssm);
+        self.longitude.set_value(longitude, ssm);
 
         self.update_wind_velocity(context, true_airspeed_source, overhead, simulator_data);
     }
@@ -1868,20 +2270,93 @@ mod tests {
         velocity::{foot_per_minute, knot},
     };
 
+    struct TestProbeIcing {
+        iced: [bool; 3],
+    }
+    impl AirDataProbeIcing for TestProbeIcing {
+        fn pitot_is_iced(&self, adr_number: usize) -> bool {
+            self.iced[adr_number - 1]
+        }
+
+        fn angle_of_attack_sensor_is_iced(&self, adr_number: usize) -> bool {
+            self.iced[adr_number - 1]
+        }
+
+        fn total_air_temperature_probe_is_iced(&self, adr_number: usize) -> bool {
+            self.iced[adr_number - 1]
+        }
+    }
+
+    struct TestGpsReceiver {
+        is_available: bool,
+    }
+    impl TestGpsReceiver {
+        fn ssm(&self) -> SignStatus {
+            if self.is_available {
+                SignStatus::NormalOperation
+            } else {
+                SignStatus::NoComputedData
+            }
+        }
+    }
+    impl GpsReceiverOutputs for TestGpsReceiver {
+        fn latitude(&self) -> Arinc429Word<Angle> {
+            Arinc429Word::new(Angle::default(), self.ssm())
+        }
+
+        fn longitude(&self) -> Arinc429Word<Angle> {
+            Arinc429Word::new(Angle::default(), self.ssm())
+        }
+
+        fn ground_speed(&self) -> Arinc429Word<Velocity> {
+            Arinc429Word::new(Velocity::default(), self.ssm())
+        }
+
+        fn true_track(&self) -> Arinc429Word<Angle> {
+            Arinc429Word::new(Angle::default(), self.ssm())
+        }
+
+        fn horizontal_figure_of_merit(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::new::<foot>(50.), self.ssm())
+        }
+
+        fn horizontal_integrity_limit(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::new::<foot>(150.), self.ssm())
+        }
+    }
+
     struct TestAircraft {
         adirs: AirDataInertialReferenceSystem,
         overhead: AirDataInertialReferenceSystemOverheadPanel,
+        probe_icing: TestProbeIcing,
+        gps_receivers: [TestGpsReceiver; 2],
     }
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
                 adirs: AirDataInertialReferenceSystem::new(context),
                 overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
+                probe_icing: TestProbeIcing { iced: [false; 3] },
+                gps_receivers: [
+                    TestGpsReceiver { is_available: true },
+                    TestGpsReceiver { is_available: true },
+                ],
             }
         }
+
+        fn set_probes_iced(&mut self, adiru_number: usize, iced: bool) {
+            self.probe_icing.iced[adiru_number - 1] = iced;
+        }
+
+        fn set_gps_available(&mut self, number: usize, available: bool) {
+            self.gps_receivers[number - 1].is_available = available;
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.adirs.update_probe_icing(&self.probe_icing);
+            self.adirs
+                .update_gps_data([&self.gps_receivers[0], &self.gps_receivers[1]]);
             self.adirs.update(context, &self.overhead);
             self.overhead.update(context, &self.adirs);
         }
@@ -1966,6 +2441,22 @@ mod tests {
             self
         }
 
+        fn probes_iced(mut self, adiru_number: usize, iced: bool) -> Self {
+            self.command(|a| a.set_probes_iced(adiru_number, iced));
+            self
+        }
+
+        fn gps_available(self, available: bool) -> Self {
+            self.gps_receiver_available(1, available)
+                .and()
+                .gps_receiver_available(2, available)
+        }
+
+        fn gps_receiver_available(mut self, number: usize, available: bool) -> Self {
+            self.command(|a| a.set_gps_available(number, available));
+            self
+        }
+
         fn pitch_of(mut self, angle: Angle) -> Self {
             self.write_by_name(AdirsSimulatorData::PITCH, angle);
             self
@@ -3365,6 +3856,197 @@ mod tests {
                 SignStatus::NoComputedData
             );
         }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn iced_probes_freeze_air_data_at_last_measured_values(#[case] adiru_number: usize) {
+            let velocity = Velocity::new::<knot>(250.);
+            let angle = Angle::new::<degree>(3.);
+            let tat = ThermodynamicTemperature::new::<degree_celsius>(-10.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .angle_of_attack_of(angle)
+                .and()
+                .total_air_temperature_of(tat);
+            test_bed.set_indicated_airspeed(velocity);
+            test_bed.run();
+
+            test_bed = test_bed
+                .probes_iced(adiru_number, true)
+                .angle_of_attack_of(Angle::new::<degree>(8.))
+                .and()
+                .total_air_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(150.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed
+                    .computed_airspeed(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                velocity
+            );
+            assert_eq!(
+                test_bed
+                    .angle_of_attack(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                angle
+            );
+            assert_about_eq!(
+                test_bed
+                    .total_air_temperature(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>(),
+                tat.get::<degree_celsius>()
+            );
+        }
+
+        #[test]
+        fn iced_probes_only_affect_their_own_adr() {
+            let mut test_bed = all_adirus_aligned_test_bed().probes_iced(1, true);
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(150.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.computed_airspeed(2).normal_value().unwrap(),
+                Velocity::new::<knot>(150.)
+            );
+            assert_ne!(
+                test_bed.computed_airspeed(1).value(),
+                Velocity::new::<knot>(150.)
+            );
+        }
+
+        #[test]
+        fn air_data_recovers_once_probes_are_clear_of_ice() {
+            let mut test_bed = all_adirus_aligned_test_bed().probes_iced(1, true);
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(150.));
+            test_bed.run();
+
+            test_bed = test_bed.probes_iced(1, false);
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.computed_airspeed(1).normal_value().unwrap(),
+                Velocity::new::<knot>(150.)
+            );
+        }
+
+        #[test]
+        fn blocked_pitot_with_open_drain_hole_makes_airspeed_decay_to_zero() {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            test_bed.fail(FailureType::PitotBlockage(1));
+            test_bed.run_with_delta(Duration::from_secs(2));
+            let decaying_airspeed = test_bed.computed_airspeed(1).value();
+            assert!(decaying_airspeed < Velocity::new::<knot>(250.));
+            assert!(decaying_airspeed > Velocity::new::<knot>(AirDataReference::MINIMUM_CAS));
+
+            test_bed.run_with_delta(Duration::from_secs(60));
+            assert!(test_bed.computed_airspeed(1).is_no_computed_data());
+            assert_eq!(
+                test_bed.computed_airspeed(2).normal_value().unwrap(),
+                Velocity::new::<knot>(250.)
+            );
+        }
+
+        #[test]
+        fn blocked_pitot_and_drain_hole_make_airspeed_increase_in_a_climb() {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(800.));
+            test_bed.run();
+
+            test_bed.fail(FailureType::PitotBlockage(1));
+            test_bed.fail(FailureType::PitotDrainBlockage(1));
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(600.));
+            test_bed.run();
+
+            assert!(
+                test_bed.computed_airspeed(1).normal_value().unwrap() > Velocity::new::<knot>(300.)
+            );
+            assert!(test_bed.mach(1).normal_value().unwrap() > test_bed.mach(2).value());
+        }
+
+        #[test]
+        fn blocked_static_ports_freeze_altitude_and_vertical_speed() {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.set_pressure_altitude(Length::new::<foot>(10000.));
+            test_bed.set_indicated_altitude(Length::new::<foot>(10000.));
+            test_bed.run();
+
+            test_bed.fail(FailureType::StaticPortBlockage(1));
+            test_bed = test_bed.vertical_speed_of(Velocity::new::<foot_per_minute>(2000.));
+            test_bed.set_pressure_altitude(Length::new::<foot>(12000.));
+            test_bed.set_indicated_altitude(Length::new::<foot>(12000.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.altitude(1).normal_value().unwrap(),
+                Length::new::<foot>(10000.)
+            );
+            assert_eq!(
+                test_bed
+                    .baro_corrected_altitude_1(1)
+                    .normal_value()
+                    .unwrap(),
+                Length::new::<foot>(10000.)
+            );
+            assert_about_eq!(
+                test_bed
+                    .barometric_vertical_speed(1)
+                    .normal_value()
+                    .unwrap()
+                    .get::<foot_per_minute>(),
+                0.
+            );
+            assert_eq!(
+                test_bed.altitude(2).normal_value().unwrap(),
+                Length::new::<foot>(12000.)
+            );
+        }
+
+        #[test]
+        fn blocked_static_ports_make_airspeed_decrease_in_a_climb() {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(800.));
+            test_bed.run();
+
+            test_bed.fail(FailureType::StaticPortBlockage(1));
+            test_bed.set_ambient_pressure(Pressure::new::<hectopascal>(750.));
+            test_bed.run();
+
+            assert!(
+                test_bed.computed_airspeed(1).normal_value().unwrap() < Velocity::new::<knot>(250.)
+            );
+        }
+
+        #[test]
+        fn jammed_angle_of_attack_vane_freezes_angle_of_attack() {
+            let mut test_bed =
+                all_adirus_aligned_test_bed_with().angle_of_attack_of(Angle::new::<degree>(3.));
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            test_bed.fail(FailureType::AngleOfAttackVaneJam(2));
+            test_bed = test_bed.angle_of_attack_of(Angle::new::<degree>(10.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.angle_of_attack(2).normal_value().unwrap(),
+                Angle::new::<degree>(3.)
+            );
+            assert_eq!(
+                test_bed.angle_of_attack(1).normal_value().unwrap(),
+                Angle::new::<degree>(10.)
+            );
+        }
     }
 
     mod ir {
@@ -4197,7 +4879,7 @@ mod tests {
         #[case(2)]
         #[case(3)]
         fn uses_gps_as_primary_when_any_adiru_is_aligned(#[case] adiru_number: usize) {
-            // The GPSSU is for now assumed to always work. Thus, when any ADIRU is aligned
+            // When any ADIRU is aligned and mixes valid GPS data into its position,
             // GPS is used as the primary means of navigation.
             let mut test_bed = test_bed_with()
                 .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation)
@@ -4227,6 +4909,110 @@ mod tests {
             assert!(!test_bed.uses_gps_as_primary());
         }
 
+        #[test]
+        fn does_not_use_gps_as_primary_when_gps_is_unavailable() {
+            let mut test_bed = all_adirus_aligned_test_bed().gps_available(false);
+            test_bed.run();
+
+            assert!(!test_bed.uses_gps_as_primary());
+        }
+
+        fn position_error_nm(test_bed: &mut AdirsTestBed, adiru_number: usize) -> f64 {
+            let latitude_error = test_bed.latitude(adiru_number).value().get::<degree>() - 45.;
+            let longitude_error = test_bed.longitude(adiru_number).value().get::<degree>() - 10.;
+
+            (latitude_error * 60.).hypot(longitude_error * 60. * 45_f64.to_radians().cos())
+        }
+
+        fn test_bed_at_position_with_gps(available: bool) -> AdirsTestBed {
+            all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::new::<degree>(45.))
+                .longitude_of(Angle::new::<degree>(10.))
+                .and()
+                .gps_available(available)
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn ir_position_does_not_drift_while_mixed_with_gps(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_at_position_with_gps(true);
+            test_bed.run_with_delta(Duration::from_secs(2 * 3600));
+
+            assert_eq!(
+                test_bed.latitude(adiru_number).normal_value().unwrap(),
+                Angle::new::<degree>(45.)
+            );
+            assert_eq!(
+                test_bed.longitude(adiru_number).normal_value().unwrap(),
+                Angle::new::<degree>(10.)
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn ir_position_drifts_with_time_since_alignment_without_gps(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_at_position_with_gps(false);
+            test_bed.run_with_delta(Duration::from_secs(2 * 3600));
+
+            let error = position_error_nm(&mut test_bed, adiru_number);
+            assert!((2. * InertialPositionDrift::MINIMUM_DRIFT_RATE_KNOTS
+                ..=2. * InertialPositionDrift::MAXIMUM_DRIFT_RATE_KNOTS)
+                .contains(&error));
+        }
+
+        #[test]
+        fn ir_position_starts_drifting_once_gps_is_lost() {
+            let mut test_bed = test_bed_at_position_with_gps(true);
+            test_bed.run_with_delta(Duration::from_secs(2 * 3600));
+
+            test_bed = test_bed.gps_available(false);
+            test_bed.run_with_delta(Duration::from_secs(3600));
+
+            let error = position_error_nm(&mut test_bed, 1);
+            assert!((InertialPositionDrift::MINIMUM_DRIFT_RATE_KNOTS
+                ..=InertialPositionDrift::MAXIMUM_DRIFT_RATE_KNOTS)
+                .contains(&error));
+        }
+
+        #[test]
+        fn adiru_1_and_3_mix_gps_receiver_1_and_adiru_2_mixes_gps_receiver_2() {
+            let mut test_bed = test_bed_at_position_with_gps(true).gps_receiver_available(1, false);
+            test_bed.run_with_delta(Duration::from_secs(2 * 3600));
+
+            assert!(position_error_nm(&mut test_bed, 1) > 0.);
+            assert_about_eq!(position_error_nm(&mut test_bed, 2), 0.);
+            assert!(position_error_nm(&mut test_bed, 3) > 0.);
+            assert!(test_bed.uses_gps_as_primary());
+        }
+
+        #[test]
+        fn ir_positions_disagree_without_gps() {
+            let mut test_bed = test_bed_at_position_with_gps(false);
+            test_bed.run_with_delta(Duration::from_secs(2 * 3600));
+
+            assert_ne!(test_bed.latitude(1).value(), test_bed.latitude(2).value());
+            assert_ne!(test_bed.longitude(1).value(), test_bed.longitude(3).value());
+        }
+
+        #[test]
+        fn ir_position_drift_restarts_on_realignment() {
+            let mut test_bed = test_bed_at_position_with_gps(false);
+            test_bed.run_with_delta(Duration::from_secs(2 * 3600));
+
+            test_bed = test_bed.ir_mode_selector_set_to(1, InertialReferenceMode::Off);
+            test_bed.run();
+
+            test_bed = test_bed
+                .ir_mode_selector_set_to(1, InertialReferenceMode::Navigation)
+                .wait_for_alignment_of(1);
+
+            assert!(position_error_nm(&mut test_bed, 1) < 0.1);
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
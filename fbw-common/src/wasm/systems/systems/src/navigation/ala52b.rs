@@ -1,5 +1,6 @@
 use super::radio_altimeter::{
-    AntennaInstallation, RadioAltimeter, TransceiverPair, TransceiverPairResponse,
+    AntennaInstallation, FlatTerrain, RadioAltimeter, SurfaceReflection, TerrainProfile,
+    TerrainSurface, TransceiverPair, TransceiverPairResponse,
 };
 use crate::failures::{Failure, FailureType};
 use crate::shared::arinc429::{Arinc429Word, SignStatus};
@@ -23,7 +24,9 @@ const SPEED_OF_LIGHT_METER_PER_SECOND: f64 = 299_792_458.;
 /// distance and attitude cutoffs. The pair refers to 1 transmitter system and 1 receiver system.
 /// Both of these systems include their radio wave generator/receiver respectively, their
 /// respective antenna installation, and the cables between the generator/receiver and the antenna.
-pub struct Ala52BTransceiverPair {
+/// The surface below the aircraft is provided by a [TerrainProfile], which defaults to the flat
+/// ground known to the simulator.
+pub struct Ala52BTransceiverPair<T: TerrainProfile = FlatTerrain> {
     alt_above_ground_id: VariableIdentifier,
     pitch_id: VariableIdentifier,
     bank_id: VariableIdentifier,
@@ -34,17 +37,45 @@ pub struct Ala52BTransceiverPair {
     alt_above_ground: Length,
     pitch: Angle,
     bank: Angle,
+
+    terrain: T,
 }
 
 impl Ala52BTransceiverPair {
+    pub fn new(
+        context: &mut InitContext,
+        transmitter: AntennaInstallation,
+        receiver: AntennaInstallation,
+    ) -> Self {
+        Self::new_with_terrain_profile(context, transmitter, receiver, FlatTerrain)
+    }
+}
+
+impl<T: TerrainProfile> Ala52BTransceiverPair<T> {
     const ALT_ABOVE_GROUND: &'static str = "PLANE ALT ABOVE GROUND";
     const PITCH: &'static str = "PLANE PITCH DEGREES";
     const BANK: &'static str = "PLANE BANK DEGREES";
 
-    pub fn new(
+    /// The maximum length of the signal path over a fully reflecting surface. As the returned
+    /// signal strength decreases with the square of the path length, the maximum length over other
+    /// surfaces scales with the square root of their reflectivity.
+    const MAX_PATH_LENGTH_FEET: f64 = 10200.;
+
+    /// The half width of the antenna beams. Diffuse returns from outside of it are too weak.
+    const BEAM_HALF_WIDTH_DEGREES: f64 = 45.;
+
+    /// The maximum angle between the antenna boresight and a specular reflection for the
+    /// reflected signal to still hit the receiver antenna.
+    const SPECULAR_ACCEPTANCE_DEGREES: f64 = 10.;
+
+    /// The number of sections the beam footprint is divided into when looking for returns.
+    const FOOTPRINT_SECTIONS: usize = 100;
+
+    pub fn new_with_terrain_profile(
         context: &mut InitContext,
         transmitter: AntennaInstallation,
         receiver: AntennaInstallation,
+        terrain: T,
     ) -> Self {
         Self {
             alt_above_ground_id: context.get_identifier(Self::ALT_ABOVE_GROUND.to_owned()),
@@ -55,9 +86,14 @@ impl Ala52BTransceiverPair {
             alt_above_ground: Length::new::<foot>(0.),
             pitch: Angle::new::<degree>(0.),
             bank: Angle::new::<degree>(0.),
+            terrain,
         }
     }
 
+    pub fn set_terrain_profile(&mut self, terrain: T) {
+        self.terrain = terrain;
+    }
+
     /// Returns the height over ground of the physical transmitter antenna.
     fn transmitter_height_over_ground(&self) -> Length {
         let vertical_offset = self.pitch.sin() * self.transmitter.z();
@@ -71,18 +107,131 @@ impl Ala52BTransceiverPair {
         (self.alt_above_ground + vertical_offset - self.receiver.y()).max(Length::new::<foot>(0.))
     }
 
-    /// Returns the direct distance between the antennas (usually along the aircraft's fuselage)
-    fn distance_between_antennas(&self) -> Length {
-        (self.transmitter.z() - self.receiver.z()).abs()
+    /// Returns the position of the physical transmitter antenna along the ground track, relative
+    /// to the center of gravity and positive ahead of it.
+    fn transmitter_along_ground_position(&self) -> Length {
+        -self.pitch.cos() * self.transmitter.z()
+    }
+
+    /// Returns the position of the physical receiver antenna along the ground track, relative to
+    /// the center of gravity and positive ahead of it.
+    fn receiver_along_ground_position(&self) -> Length {
+        -self.pitch.cos() * self.receiver.z()
     }
 
-    /// Returns the distance between the projected ground tracks of the transmitter and receiver.
-    fn antenna_along_ground_distance(&self) -> Length {
-        self.pitch.cos() * self.distance_between_antennas()
+    /// Returns the range along the ground track which is illuminated by the antenna beams on
+    /// ground level, including the section between the antennas themselves.
+    fn beam_footprint(&self) -> (Length, Length) {
+        let transmitter_position = self.transmitter_along_ground_position();
+        let receiver_position = self.receiver_along_ground_position();
+        let center = (transmitter_position + receiver_position) / 2.;
+        let height = self
+            .transmitter_height_over_ground()
+            .max(self.receiver_height_over_ground());
+
+        // The boresight of the antennas is perpendicular to the fuselage, so it tilts forward
+        // when the nose is raised (negative pitch in the simulator).
+        let boresight = -self.pitch;
+        let beam_half_width = Angle::new::<degree>(Self::BEAM_HALF_WIDTH_DEGREES);
+        let max_angle = Angle::new::<degree>(75.);
+        let rear_edge = center + height * (boresight - beam_half_width).max(-max_angle).tan();
+        let front_edge = center + height * (boresight + beam_half_width).min(max_angle).tan();
+
+        (
+            rear_edge.min(transmitter_position).min(receiver_position),
+            front_edge.max(transmitter_position).max(receiver_position),
+        )
+    }
+
+    /// Returns the length of the shortest path between the transceivers via a reflection on a
+    /// section of the terrain, or [None] if the section doesn't return enough of the signal.
+    fn path_via(&self, start: Length, end: Length, surface: TerrainSurface) -> Option<Length> {
+        // As preparation, calculate the perpendicular distance from both transceivers to the
+        // surface.
+        let a = (self.transmitter_height_over_ground() - surface.elevation())
+            .max(Length::new::<foot>(0.));
+        let b =
+            (self.receiver_height_over_ground() - surface.elevation()).max(Length::new::<foot>(0.));
+        let transmitter_position = self.transmitter_along_ground_position();
+        let receiver_position = self.receiver_along_ground_position();
+
+        // Perform some 2D geometry to determine the shortest path between the two transceivers and
+        // the surface. The basic idea is that, given two transceivers A, B and a line g representing
+        // the surface. We now would like to determine the length of the shortest path from A to B
+        // via a point on the line. To do this, you can can reflect one of the transceivers B
+        // along the surface g to construct B'. The intersection of the line A-B' with g will result
+        // in the reflection point C in g, and this line A-C-B' now is equal to the shortest path
+        // A-C-B. When C lies outside of the section, the shortest path instead goes via the
+        // closest edge of it.
+        let specular_point = if a + b > Length::new::<foot>(0.) {
+            transmitter_position + (receiver_position - transmitter_position) * (a / (a + b))
+        } else {
+            (transmitter_position + receiver_position) / 2.
+        };
+        let reflection_point = specular_point.max(start).min(end);
+
+        // Determine how far off the boresight the reflection point is seen from the antennas.
+        // Points between the antennas are considered to be right below them.
+        let horizontal_offset = reflection_point
+            - reflection_point
+                .max(transmitter_position.min(receiver_position))
+                .min(transmitter_position.max(receiver_position));
+        let ray_angle = horizontal_offset.atan2((a + b) / 2.);
+        let longitudinal_off_boresight = (ray_angle + self.pitch).get::<degree>();
+        let off_boresight = longitudinal_off_boresight.hypot(self.bank.get::<degree>());
+
+        let is_returning = match surface.reflection() {
+            SurfaceReflection::Diffuse => off_boresight <= Self::BEAM_HALF_WIDTH_DEGREES,
+            SurfaceReflection::Specular => {
+                reflection_point == specular_point
+                    && off_boresight <= Self::SPECULAR_ACCEPTANCE_DEGREES
+            }
+        };
+        if !is_returning {
+            return None;
+        }
+
+        let transmitter_offset = reflection_point - transmitter_position;
+        let receiver_offset = receiver_position - reflection_point;
+        let path_length = (transmitter_offset * transmitter_offset + a * a).sqrt()
+            + (receiver_offset * receiver_offset + b * b).sqrt()
+            + self.transmitter.electric_length()
+            + self.receiver.electric_length();
+
+        let max_path_length = Length::new::<foot>(Self::MAX_PATH_LENGTH_FEET)
+            * surface.reflectivity().get::<ratio>().max(0.).sqrt();
+
+        if path_length < max_path_length {
+            Some(path_length)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the length of the shortest signal path which returns to the receiver. Like the
+    /// leading edge tracking of the real radar, this follows the closest return within the beam
+    /// footprint, which may be an obstacle ahead of the aircraft rather than the ground below it.
+    /// When the terrain below doesn't return enough of the signal, a longer path via a strongly
+    /// reflecting surface elsewhere within the beam is tracked instead, resulting in a false
+    /// reading. Paths with more than one reflection, e.g. between the ground and the fuselage,
+    /// aren't modelled.
+    fn shortest_returning_path(&self) -> Option<Length> {
+        let (rear_edge, front_edge) = self.beam_footprint();
+        let section_length = (front_edge - rear_edge) / Self::FOOTPRINT_SECTIONS as f64;
+
+        (0..Self::FOOTPRINT_SECTIONS)
+            .filter_map(|section| {
+                let start = rear_edge + section_length * section as f64;
+                let end = start + section_length;
+                let surface = self.terrain.surface_at((start + end) / 2.);
+
+                self.path_via(start, end, surface)
+            })
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
     }
 }
 
-impl TransceiverPair for Ala52BTransceiverPair {
+impl<T: TerrainProfile> TransceiverPair for Ala52BTransceiverPair<T> {
     /// Returns the time the signal took to travel from one of the transceivers to the other,
     /// to the receiver, or [None] if there is no path (or the signal is too weak).
     /// While it is simplified compared to a proper frequency simulation, you can imagine that the
@@ -96,31 +245,12 @@ impl TransceiverPair for Ala52BTransceiverPair {
         }
 
         // First, we need to determine the shortest path of the radio waves between the two
-        // transceivers. This will usually be based on a reflection on the ground, but one future
-        // failure case might be direct coupling where e.g. contamination leads to the shortest path
-        // being radio waves traveling directly along the fuselage, leading to extremely low
-        // readings.
-
-        // As preparation, calculate the perpendicular distance from both transceivers to the
-        // ground.
-        let a: Length = self.transmitter_height_over_ground();
-        let b: Length = self.receiver_height_over_ground();
-
-        // Perform some 2D geometry to determine the shortest path between the two transceivers and
-        // the ground. The basic idea is that, given two transceivers A, B and a line g representing
-        // the ground. We now would like to determine the length of the shortest path from A to B
-        // via a point on the line. To do this, you can can reflect one of the transceivers B
-        // along the ground g to construct B'. The intersection of the line A-B' with g will result
-        // in the reflection point C in g, and this line A-C-B' now is equal to the shortest path
-        // A-C-B. By then calculating some right angles between A, B', and g, we eventually have a
-        // simple pythagorean triangle where the hypotenuse corresponds to A-C-B', which we can
-        // calculate trivially.
-        let transceiver_along_ground_distance = self.antenna_along_ground_distance();
-        let path_in_air: Length = ((a + b) * (a + b)
-            + transceiver_along_ground_distance * transceiver_along_ground_distance)
-            .sqrt();
-        let shortest_path_length: Length =
-            path_in_air + self.transmitter.electric_length() + self.receiver.electric_length();
+        // transceivers which still returns enough of the signal. This will usually be based on a
+        // reflection on the ground, but obstacles within the beam or surfaces which don't reflect
+        // the signal back to the receiver can change it. One future failure case might be direct
+        // coupling where e.g. contamination leads to the shortest path being radio waves traveling
+        // directly along the fuselage, leading to extremely low readings.
+        let shortest_path_length = self.shortest_returning_path()?;
 
         // At this point we've determined the length of the shortest path between the two
         // transceivers. We now convert it into a travel time.
@@ -141,15 +271,11 @@ impl TransceiverPair for Ala52BTransceiverPair {
 
         let travel_time = shortest_path_length / speed_of_radio_waves;
 
-        if shortest_path_length < Length::new::<foot>(10200.) {
-            Some(TransceiverPairResponse::new(travel_time))
-        } else {
-            None
-        }
+        Some(TransceiverPairResponse::new(travel_time))
     }
 }
 
-impl SimulationElement for Ala52BTransceiverPair {
+impl<T: TerrainProfile> SimulationElement for Ala52BTransceiverPair<T> {
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.alt_above_ground = reader.read(&self.alt_above_ground_id);
         self.pitch = reader.read(&self.pitch_id);
@@ -360,7 +486,7 @@ impl Ala52BRadioAltimeter {
         format!("RA_{}_RADIO_ALTITUDE", number)
     }
 
-    pub fn update(&mut self, context: &UpdateContext, transceivers: &Ala52BTransceiverPair) {
+    pub fn update(&mut self, context: &UpdateContext, transceivers: &impl TransceiverPair) {
         if self.is_powered {
             self.unpowered_for = Duration::ZERO;
         } else {
@@ -454,11 +580,37 @@ mod tests {
     use uom::si::f64::ElectricPotential;
     use uom::si::length::meter;
 
+    type TestTransceiverPair = Ala52BTransceiverPair<TestTerrain>;
+
+    /// A terrain profile made of consecutive surfaces, each starting at the given distance
+    /// from the aircraft's center of gravity. It is flat ground wherever no surface is defined.
+    #[derive(Default)]
+    struct TestTerrain {
+        surfaces: Vec<(Length, TerrainSurface)>,
+    }
+    impl TestTerrain {
+        fn new(surfaces: Vec<(Length, TerrainSurface)>) -> Self {
+            Self { surfaces }
+        }
+    }
+    impl TerrainProfile for TestTerrain {
+        fn surface_at(&self, distance: Length) -> TerrainSurface {
+            self.surfaces
+                .iter()
+                .rev()
+                .find(|(start, _)| *start <= distance)
+                .map_or(
+                    TerrainSurface::ground(Length::new::<foot>(0.)),
+                    |(_, surface)| *surface,
+                )
+        }
+    }
+
     struct TestAircraft {
         electricity_source: TestElectricitySource,
         ac_1_bus: ElectricalBus,
         radio_altimeter_1: Ala52BRadioAltimeter,
-        system_1_transceivers: Ala52BTransceiverPair,
+        system_1_transceivers: TestTransceiverPair,
         is_ac_1_powered: bool,
         power_consumption: Power,
     }
@@ -477,7 +629,7 @@ mod tests {
                     ElectricalBusType::AlternatingCurrent(1),
                 ),
                 // roughly model the A320 RA 1 transceivers
-                system_1_transceivers: Ala52BTransceiverPair::new(
+                system_1_transceivers: TestTransceiverPair::new_with_terrain_profile(
                     context,
                     AntennaInstallation::new(
                         Length::new::<foot>(8.617) - Length::new::<meter>(1.8),
@@ -489,6 +641,7 @@ mod tests {
                         Length::new::<foot>(9.19),
                         Length::new::<foot>(22.6),
                     ),
+                    TestTerrain::default(),
                 ),
                 is_ac_1_powered: false,
                 power_consumption: Power::new::<watt>(0.),
@@ -509,6 +662,10 @@ mod tests {
         fn power_consumption(&self) -> Power {
             self.power_consumption
         }
+
+        fn set_terrain(&mut self, terrain: TestTerrain) {
+            self.system_1_transceivers.set_terrain_profile(terrain);
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_before_power_distribution(
@@ -584,31 +741,36 @@ mod tests {
         fn pitch(mut self, pitch: Angle) -> Self {
             // Confusingly the sim encodes the pitch inversely: 10 degrees pitch up will be encoded
             // as -10 degrees pitch in the SimVar.
-            self.write_by_name(Ala52BTransceiverPair::PITCH, pitch);
+            self.write_by_name(TestTransceiverPair::PITCH, pitch);
             self
         }
 
         fn bank(mut self, bank: Angle) -> Self {
-            self.write_by_name(Ala52BTransceiverPair::BANK, bank);
+            self.write_by_name(TestTransceiverPair::BANK, bank);
             self
         }
 
         fn level_flight(mut self) -> Self {
-            self.write_by_name(Ala52BTransceiverPair::PITCH, Angle::new::<degree>(0.));
-            self.write_by_name(Ala52BTransceiverPair::BANK, Angle::new::<degree>(0.));
+            self.write_by_name(TestTransceiverPair::PITCH, Angle::new::<degree>(0.));
+            self.write_by_name(TestTransceiverPair::BANK, Angle::new::<degree>(0.));
             self
         }
 
         fn above_ground(mut self) -> Self {
             self.write_by_name(
-                Ala52BTransceiverPair::ALT_ABOVE_GROUND,
+                TestTransceiverPair::ALT_ABOVE_GROUND,
                 Length::new::<foot>(508.6),
             );
             self
         }
 
+        fn terrain(mut self, surfaces: Vec<(Length, TerrainSurface)>) -> Self {
+            self.command(|a| a.set_terrain(TestTerrain::new(surfaces)));
+            self
+        }
+
         fn height_over_ground(mut self, height: Length) -> Self {
-            self.write_by_name(Ala52BTransceiverPair::ALT_ABOVE_GROUND, height);
+            self.write_by_name(TestTransceiverPair::ALT_ABOVE_GROUND, height);
             self
         }
 
@@ -838,6 +1000,157 @@ mod tests {
         test_bed.assert_radio_altitude(1, Length::new::<foot>(8192.));
     }
 
+    #[test]
+    fn measures_the_height_over_a_building_below_the_aircraft() {
+        let mut test_bed = test_bed_with()
+            .height_over_ground(Length::new::<foot>(508.6))
+            .terrain(vec![
+                (
+                    Length::new::<foot>(-100.),
+                    TerrainSurface::ground(Length::new::<foot>(100.)),
+                ),
+                (
+                    Length::new::<foot>(100.),
+                    TerrainSurface::ground(Length::new::<foot>(0.)),
+                ),
+            ]);
+        test_bed.run_with_delta(Duration::from_millis(
+            Ala52BRadioAltimeter::MAXIMUM_STARTUP_TIME_MILLIS,
+        ));
+
+        test_bed.assert_radio_altitude_normal_operation(1);
+        test_bed.assert_radio_altitude(1, Length::new::<foot>(400.0));
+    }
+
+    #[test]
+    fn tracks_a_cliff_edge_ahead_of_the_aircraft() {
+        let mut test_bed = test_bed_with()
+            .height_over_ground(Length::new::<foot>(508.6))
+            .terrain(vec![(
+                Length::new::<foot>(150.),
+                TerrainSurface::ground(Length::new::<foot>(300.)),
+            )]);
+        test_bed.run_with_delta(Duration::from_millis(
+            Ala52BRadioAltimeter::MAXIMUM_STARTUP_TIME_MILLIS,
+        ));
+
+        test_bed.assert_radio_altitude_normal_operation(1);
+        // The closest return is the cliff's edge, 200 ft below and 160 ft ahead of the antennas.
+        test_bed.assert_radio_altitude(1, Length::new::<foot>(256.));
+    }
+
+    #[test]
+    fn tracks_a_strong_reflection_within_the_beam_over_weakly_reflecting_terrain() {
+        let weakly_reflecting = TerrainSurface::new(
+            Length::new::<foot>(0.),
+            Ratio::new::<ratio>(0.1),
+            SurfaceReflection::Diffuse,
+        );
+        let mut test_bed = test_bed_with()
+            .height_over_ground(Length::new::<foot>(4000.))
+            .terrain(vec![
+                (Length::new::<foot>(-10000.), weakly_reflecting),
+                (
+                    Length::new::<foot>(1000.),
+                    TerrainSurface::ground(Length::new::<foot>(0.)),
+                ),
+                (Length::new::<foot>(1100.), weakly_reflecting),
+            ]);
+        test_bed.run_with_delta(Duration::from_millis(
+            Ala52BRadioAltimeter::MAXIMUM_STARTUP_TIME_MILLIS,
+        ));
+
+        test_bed.assert_radio_altitude_normal_operation(1);
+        // Instead of no computed data, the slant range to the strong reflection is tracked.
+        test_bed.assert_radio_altitude(1, Length::new::<foot>(4124.25));
+    }
+
+    #[test]
+    fn ignores_obstacles_outside_of_the_antenna_beam() {
+        let mut test_bed = test_bed_with()
+            .height_over_ground(Length::new::<foot>(508.6))
+            .terrain(vec![
+                (
+                    Length::new::<foot>(1000.),
+                    TerrainSurface::ground(Length::new::<foot>(100.)),
+                ),
+                (
+                    Length::new::<foot>(1100.),
+                    TerrainSurface::ground(Length::new::<foot>(0.)),
+                ),
+            ]);
+        test_bed.run_with_delta(Duration::from_millis(
+            Ala52BRadioAltimeter::MAXIMUM_STARTUP_TIME_MILLIS,
+        ));
+
+        test_bed.assert_radio_altitude_normal_operation(1);
+        test_bed.assert_radio_altitude(1, Length::new::<foot>(500.0));
+    }
+
+    #[test]
+    fn measures_the_height_over_calm_water_in_level_flight() {
+        let mut test_bed = test_bed_with()
+            .height_over_ground(Length::new::<foot>(508.6))
+            .terrain(vec![(
+                Length::new::<foot>(-10000.),
+                TerrainSurface::calm_water(Length::new::<foot>(0.)),
+            )]);
+        test_bed.run_with_delta(Duration::from_millis(
+            Ala52BRadioAltimeter::MAXIMUM_STARTUP_TIME_MILLIS,
+        ));
+
+        test_bed.assert_radio_altitude_normal_operation(1);
+        test_bed.assert_radio_altitude(1, Length::new::<foot>(500.0));
+    }
+
+    #[test]
+    fn loses_track_over_calm_water_when_banked() {
+        let mut test_bed = test_bed_with()
+            .height_over_ground(Length::new::<foot>(508.6))
+            .terrain(vec![(
+                Length::new::<foot>(-10000.),
+                TerrainSurface::calm_water(Length::new::<foot>(0.)),
+            )]);
+        test_bed.run_with_delta(Duration::from_millis(
+            Ala52BRadioAltimeter::MAXIMUM_STARTUP_TIME_MILLIS,
+        ));
+
+        test_bed = test_bed.bank(Angle::new::<degree>(20.));
+        for _ in 0..2 {
+            test_bed.run_with_delta(Duration::from_millis(100));
+        }
+
+        test_bed.assert_radio_altitude_no_computed_data(1);
+        test_bed.assert_radio_altitude(1, Length::new::<foot>(8192.));
+    }
+
+    #[test]
+    fn returns_ncd_over_weakly_reflecting_terrain_at_high_altitude() {
+        let mut test_bed = test_bed_with()
+            .height_over_ground(Length::new::<foot>(4000.))
+            .terrain(vec![(
+                Length::new::<foot>(-10000.),
+                TerrainSurface::new(
+                    Length::new::<foot>(0.),
+                    Ratio::new::<ratio>(0.1),
+                    SurfaceReflection::Diffuse,
+                ),
+            )]);
+        test_bed.run_with_delta(Duration::from_millis(
+            Ala52BRadioAltimeter::MAXIMUM_STARTUP_TIME_MILLIS,
+        ));
+
+        test_bed.assert_radio_altitude_no_computed_data(1);
+
+        test_bed = test_bed.height_over_ground(Length::new::<foot>(508.6));
+        for _ in 0..2 {
+            test_bed.run_with_delta(Duration::from_millis(100));
+        }
+
+        test_bed.assert_radio_altitude_normal_operation(1);
+        test_bed.assert_radio_altitude(1, Length::new::<foot>(500.0));
+    }
+
     #[test]
     fn returns_ncd_with_max_value_when_started_in_cruise() {
         let mut test_bed = test_bed_with().height_over_ground(Length::new::<foot>(10000.));
@@ -0,0 +1,505 @@
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        random_from_range, ConsumePower, ElectricalBusType, ElectricalBuses, GpsReceiverOutputs,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    angle::degree,
+    f64::*,
+    length::{meter, nautical_mile},
+    power::watt,
+    velocity::knot,
+};
+
+/// The GPS receiver housed in a multi mode receiver (MMR) or GPS sensor unit (GPSSU).
+/// After power up, the receiver needs some time to acquire the satellites in view before
+/// it can compute a position. The accuracy (HFOM) and integrity limit (HIL) of the position
+/// depend on the number of satellites the receiver tracks.
+pub struct GpsReceiver {
+    latitude_id: VariableIdentifier,
+    longitude_id: VariableIdentifier,
+    ground_speed_id: VariableIdentifier,
+    true_track_id: VariableIdentifier,
+    horizontal_figure_of_merit_id: VariableIdentifier,
+    horizontal_integrity_limit_id: VariableIdentifier,
+    satellites_tracked_id: VariableIdentifier,
+
+    simulator_latitude_id: VariableIdentifier,
+    simulator_longitude_id: VariableIdentifier,
+    simulator_ground_speed_id: VariableIdentifier,
+    simulator_true_track_id: VariableIdentifier,
+
+    failure: Failure,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    acquisition_duration: Duration,
+    /// The remaining time to acquire the satellites, where 0 indicates the receiver
+    /// tracks the satellites. None indicates the receiver isn't operating.
+    remaining_acquisition_duration: Option<Duration>,
+    satellites_tracked: u8,
+    time_until_constellation_change: Duration,
+
+    simulator_latitude: Angle,
+    simulator_longitude: Angle,
+    simulator_ground_speed: Velocity,
+    simulator_true_track: Angle,
+}
+impl GpsReceiver {
+    const SIMULATOR_LATITUDE: &'static str = "PLANE LATITUDE";
+    const SIMULATOR_LONGITUDE: &'static str = "PLANE LONGITUDE";
+    const SIMULATOR_GROUND_SPEED: &'static str = "GPS GROUND SPEED";
+    const SIMULATOR_TRUE_TRACK: &'static str = "GPS GROUND TRUE TRACK";
+
+    const POWER_CONSUMPTION_WATT: f64 = 40.;
+
+    const MINIMUM_ACQUISITION_TIME_SECS: f64 = 60.;
+    const MAXIMUM_ACQUISITION_TIME_SECS: f64 = 90.;
+
+    const MINIMUM_SATELLITES_TRACKED: u8 = 7;
+    const MAXIMUM_SATELLITES_TRACKED: u8 = 12;
+    const CONSTELLATION_CHANGE_INTERVAL: Duration = Duration::from_secs(300);
+
+    /// A position fix requires at least four satellites.
+    const MINIMUM_SATELLITES_FOR_POSITION: u8 = 4;
+    /// Fault detection (RAIM) requires at least one more satellite than the position fix.
+    const MINIMUM_SATELLITES_FOR_INTEGRITY: u8 = 5;
+    /// The user equivalent range error, i.e. the ranging error to a single satellite.
+    const USER_EQUIVALENT_RANGE_ERROR_METERS: f64 = 7.;
+    const INTEGRITY_LIMIT_TO_FIGURE_OF_MERIT_RATIO: f64 = 2.5;
+
+    pub fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        // Starting with running engines implies starting on the runway or in the air,
+        // in which case the receiver already tracks its satellites.
+        let is_acquired = context.has_engines_running();
+
+        Self {
+            latitude_id: context.get_identifier(Self::output_id(number, "LATITUDE")),
+            longitude_id: context.get_identifier(Self::output_id(number, "LONGITUDE")),
+            ground_speed_id: context.get_identifier(Self::output_id(number, "GROUND_SPEED")),
+            true_track_id: context.get_identifier(Self::output_id(number, "TRUE_TRACK")),
+            horizontal_figure_of_merit_id: context.get_identifier(Self::output_id(number, "HFOM")),
+            horizontal_integrity_limit_id: context.get_identifier(Self::output_id(number, "HIL")),
+            satellites_tracked_id: context
+                .get_identifier(Self::output_id(number, "SATELLITES_TRACKED")),
+
+            simulator_latitude_id: context.get_identifier(Self::SIMULATOR_LATITUDE.to_owned()),
+            simulator_longitude_id: context.get_identifier_with_metadata(
+                Self::SIMULATOR_LONGITUDE.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            simulator_ground_speed_id: context.get_identifier_with_metadata(
+                Self::SIMULATOR_GROUND_SPEED.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            simulator_true_track_id: context.get_identifier_with_metadata(
+                Self::SIMULATOR_TRUE_TRACK.to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+
+            failure: Failure::new(FailureType::GpsReceiver(number)),
+            powered_by,
+            is_powered: false,
+
+            acquisition_duration: Duration::from_secs_f64(random_from_range(
+                Self::MINIMUM_ACQUISITION_TIME_SECS,
+                Self::MAXIMUM_ACQUISITION_TIME_SECS,
+            )),
+            remaining_acquisition_duration: if is_acquired {
+                Some(Duration::ZERO)
+            } else {
+                None
+            },
+            satellites_tracked: Self::random_satellites_tracked(),
+            time_until_constellation_change: Self::CONSTELLATION_CHANGE_INTERVAL,
+
+            simulator_latitude: Angle::default(),
+            simulator_longitude: Angle::default(),
+            simulator_ground_speed: Velocity::default(),
+            simulator_true_track: Angle::default(),
+        }
+    }
+
+    fn output_id(number: usize, name: &str) -> String {
+        format!("GPS_{}_{}", number, name)
+    }
+
+    fn random_satellites_tracked() -> u8 {
+        random_from_range(
+            Self::MINIMUM_SATELLITES_TRACKED as f64,
+            Self::MAXIMUM_SATELLITES_TRACKED as f64 + 1.,
+        )
+        .floor() as u8
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.remaining_acquisition_duration = if !self.is_powered || self.failure.is_active() {
+            None
+        } else {
+            Some(match self.remaining_acquisition_duration {
+                Some(remaining) => remaining.saturating_sub(context.delta()),
+                None => self.acquisition_duration,
+            })
+        };
+
+        // As the satellites move along their orbits, satellites set below the horizon while
+        // others rise above it.
+        self.time_until_constellation_change = self
+            .time_until_constellation_change
+            .saturating_sub(context.delta());
+        if self.time_until_constellation_change == Duration::ZERO {
+            self.satellites_tracked = Self::random_satellites_tracked();
+            self.time_until_constellation_change = Self::CONSTELLATION_CHANGE_INTERVAL;
+        }
+    }
+
+    pub fn has_failed(&self) -> bool {
+        self.failure.is_active()
+    }
+
+    fn is_tracking_satellites(&self) -> bool {
+        self.remaining_acquisition_duration == Some(Duration::ZERO)
+    }
+
+    fn position_ssm(&self) -> SignStatus {
+        if !self.is_powered || self.failure.is_active() {
+            SignStatus::FailureWarning
+        } else if !self.is_tracking_satellites()
+            || self.satellites_tracked < Self::MINIMUM_SATELLITES_FOR_POSITION
+        {
+            SignStatus::NoComputedData
+        } else {
+            SignStatus::NormalOperation
+        }
+    }
+
+    /// The horizontal dilution of precision roughly improves with the square root of
+    /// the number of satellites used in excess of those required for a position fix.
+    fn horizontal_dilution_of_precision(satellites_tracked: u8) -> f64 {
+        3. / ((satellites_tracked - Self::MINIMUM_SATELLITES_FOR_POSITION + 1) as f64).sqrt()
+    }
+
+    /// The radius of the circle centred on the computed position in which the actual
+    /// position lies with a probability of 95%.
+    fn horizontal_figure_of_merit_for(satellites_tracked: u8) -> Length {
+        Length::new::<meter>(
+            2. * Self::USER_EQUIVALENT_RANGE_ERROR_METERS
+                * Self::horizontal_dilution_of_precision(satellites_tracked),
+        )
+    }
+
+    /// The radius of the circle centred on the computed position which is assured to
+    /// contain the actual position, unless a satellite fault has gone undetected.
+    fn horizontal_integrity_limit_for(satellites_tracked: u8) -> Length {
+        Self::horizontal_figure_of_merit_for(satellites_tracked)
+            * Self::INTEGRITY_LIMIT_TO_FIGURE_OF_MERIT_RATIO
+    }
+
+    fn satellites_tracked(&self) -> u8 {
+        if self.position_ssm() == SignStatus::FailureWarning || !self.is_tracking_satellites() {
+            0
+        } else {
+            self.satellites_tracked
+        }
+    }
+}
+impl GpsReceiverOutputs for GpsReceiver {
+    /// label 110
+    fn latitude(&self) -> Arinc429Word<Angle> {
+        Arinc429Word::new(self.simulator_latitude, self.position_ssm())
+    }
+
+    /// label 111
+    fn longitude(&self) -> Arinc429Word<Angle> {
+        Arinc429Word::new(self.simulator_longitude, self.position_ssm())
+    }
+
+    /// label 112
+    fn ground_speed(&self) -> Arinc429Word<Velocity> {
+        Arinc429Word::new(self.simulator_ground_speed, self.position_ssm())
+    }
+
+    /// label 103
+    fn true_track(&self) -> Arinc429Word<Angle> {
+        Arinc429Word::new(self.simulator_true_track, self.position_ssm())
+    }
+
+    /// label 247
+    fn horizontal_figure_of_merit(&self) -> Arinc429Word<Length> {
+        match self.position_ssm() {
+            SignStatus::NormalOperation => Arinc429Word::new(
+                Self::horizontal_figure_of_merit_for(self.satellites_tracked),
+                SignStatus::NormalOperation,
+            ),
+            ssm => Arinc429Word::new(Length::default(), ssm),
+        }
+    }
+
+    /// label 130
+    fn horizontal_integrity_limit(&self) -> Arinc429Word<Length> {
+        match self.position_ssm() {
+            SignStatus::NormalOperation
+                if self.satellites_tracked >= Self::MINIMUM_SATELLITES_FOR_INTEGRITY =>
+            {
+                Arinc429Word::new(
+                    Self::horizontal_integrity_limit_for(self.satellites_tracked),
+                    SignStatus::NormalOperation,
+                )
+            }
+            SignStatus::FailureWarning => {
+                Arinc429Word::new(Length::default(), SignStatus::FailureWarning)
+            }
+            _ => Arinc429Word::new(Length::default(), SignStatus::NoComputedData),
+        }
+    }
+}
+impl SimulationElement for GpsReceiver {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.simulator_latitude = reader.read(&self.simulator_latitude_id);
+        self.simulator_longitude = reader.read(&self.simulator_longitude_id);
+        self.simulator_ground_speed = reader.read(&self.simulator_ground_speed_id);
+        self.simulator_true_track = reader.read(&self.simulator_true_track_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let latitude = self.latitude();
+        writer.write_arinc429(
+            &self.latitude_id,
+            latitude.value().get::<degree>(),
+            latitude.ssm(),
+        );
+        let longitude = self.longitude();
+        writer.write_arinc429(
+            &self.longitude_id,
+            longitude.value().get::<degree>(),
+            longitude.ssm(),
+        );
+        let ground_speed = self.ground_speed();
+        writer.write_arinc429(
+            &self.ground_speed_id,
+            ground_speed.value().get::<knot>(),
+            ground_speed.ssm(),
+        );
+        let true_track = self.true_track();
+        writer.write_arinc429(
+            &self.true_track_id,
+            true_track.value().get::<degree>(),
+            true_track.ssm(),
+        );
+        let horizontal_figure_of_merit = self.horizontal_figure_of_merit();
+        writer.write_arinc429(
+            &self.horizontal_figure_of_merit_id,
+            horizontal_figure_of_merit.value().get::<nautical_mile>(),
+            horizontal_figure_of_merit.ssm(),
+        );
+        let horizontal_integrity_limit = self.horizontal_integrity_limit();
+        writer.write_arinc429(
+            &self.horizontal_integrity_limit_id,
+            horizontal_integrity_limit.value().get::<nautical_mile>(),
+            horizontal_integrity_limit.ssm(),
+        );
+        writer.write(&self.satellites_tracked_id, self.satellites_tracked());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_powered && !self.has_failed() {
+            consumption.consume_from_bus(
+                self.powered_by,
+                Power::new::<watt>(Self::POWER_CONSUMPTION_WATT),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, StartState,
+        },
+    };
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        gps_receiver: GpsReceiver,
+        is_ac_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                gps_receiver: GpsReceiver::new(
+                    context,
+                    1,
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                is_ac_1_powered: true,
+            }
+        }
+
+        fn set_ac_1_power(&mut self, is_powered: bool) {
+            self.is_ac_1_powered = is_powered;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+            if self.is_ac_1_powered {
+                electricity.flow(&self.electricity_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.gps_receiver.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.gps_receiver.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_in_flight() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed =
+            SimulationTestBed::new_with_start_state(StartState::Cruise, TestAircraft::new);
+        test_bed.write_by_name(GpsReceiver::SIMULATOR_LATITUDE, 47.);
+        test_bed.write_by_name(GpsReceiver::SIMULATOR_LONGITUDE, 8.5);
+        test_bed.write_by_name(GpsReceiver::SIMULATOR_GROUND_SPEED, 450.);
+
+        test_bed
+    }
+
+    fn test_bed_cold_and_dark() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new_with_start_state(StartState::Hangar, TestAircraft::new)
+    }
+
+    fn latitude(test_bed: &mut SimulationTestBed<TestAircraft>) -> Arinc429Word<f64> {
+        test_bed.read_arinc429_by_name("GPS_1_LATITUDE")
+    }
+
+    #[test]
+    fn outputs_position_and_ground_speed_when_started_in_flight() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.run();
+
+        let latitude = latitude(&mut test_bed);
+        assert!(latitude.is_normal_operation());
+        assert_eq!(latitude.value(), 47.);
+
+        let longitude: Arinc429Word<f64> = test_bed.read_arinc429_by_name("GPS_1_LONGITUDE");
+        assert!(longitude.is_normal_operation());
+        assert_eq!(longitude.value(), 8.5);
+
+        let ground_speed: Arinc429Word<f64> = test_bed.read_arinc429_by_name("GPS_1_GROUND_SPEED");
+        assert!(ground_speed.is_normal_operation());
+        assert_eq!(ground_speed.value(), 450.);
+    }
+
+    #[test]
+    fn position_is_failure_warning_when_unpowered() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.command(|a| a.set_ac_1_power(false));
+        test_bed.run();
+
+        assert!(latitude(&mut test_bed).is_failure_warning());
+    }
+
+    #[test]
+    fn position_is_failure_warning_when_failed() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.fail(FailureType::GpsReceiver(1));
+        test_bed.run();
+
+        assert!(latitude(&mut test_bed).is_failure_warning());
+        let satellites_tracked: u8 = test_bed.read_by_name("GPS_1_SATELLITES_TRACKED");
+        assert_eq!(satellites_tracked, 0);
+    }
+
+    #[test]
+    fn acquires_satellites_after_power_up() {
+        let mut test_bed = test_bed_cold_and_dark();
+        test_bed.run_with_delta(Duration::from_secs_f64(
+            GpsReceiver::MINIMUM_ACQUISITION_TIME_SECS - 1.,
+        ));
+
+        assert!(latitude(&mut test_bed).is_no_computed_data());
+
+        test_bed.run_with_delta(Duration::from_secs_f64(
+            GpsReceiver::MAXIMUM_ACQUISITION_TIME_SECS,
+        ));
+
+        assert!(latitude(&mut test_bed).is_normal_operation());
+    }
+
+    #[test]
+    fn reacquires_satellites_after_power_loss() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.command(|a| a.set_ac_1_power(false));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.run();
+
+        assert!(latitude(&mut test_bed).is_no_computed_data());
+    }
+
+    #[test]
+    fn tracks_enough_satellites_for_integrity_monitoring() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.run();
+
+        let satellites_tracked: u8 = test_bed.read_by_name("GPS_1_SATELLITES_TRACKED");
+        assert!((GpsReceiver::MINIMUM_SATELLITES_TRACKED
+            ..=GpsReceiver::MAXIMUM_SATELLITES_TRACKED)
+            .contains(&satellites_tracked));
+
+        let horizontal_integrity_limit: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("GPS_1_HIL");
+        assert!(horizontal_integrity_limit.is_normal_operation());
+        assert!(horizontal_integrity_limit.value() < 0.1);
+    }
+
+    #[test]
+    fn accuracy_improves_with_more_satellites() {
+        assert!(
+            GpsReceiver::horizontal_figure_of_merit_for(12)
+                < GpsReceiver::horizontal_figure_of_merit_for(6)
+        );
+        assert!(
+            GpsReceiver::horizontal_integrity_limit_for(6)
+                > GpsReceiver::horizontal_figure_of_merit_for(6)
+        );
+    }
+}
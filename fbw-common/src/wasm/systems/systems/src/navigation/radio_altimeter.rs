@@ -1,5 +1,9 @@
 use crate::shared::arinc429::Arinc429Word;
-use uom::si::f64::{Length, Time};
+use uom::si::{
+    f64::{Length, Ratio, Time},
+    length::foot,
+    ratio::ratio,
+};
 
 /// This struct describes the installation of a physical Antenna in relation to an aircraft's
 /// center of gravity.
@@ -59,6 +63,83 @@ impl TransceiverPairResponse {
     }
 }
 
+/// Describes how a surface reflects the signal of a radio altimeter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SurfaceReflection {
+    /// Rough surfaces like soil, grass or buildings scatter the signal in all directions, so a
+    /// part of it returns to the receiver from anywhere within the antenna beam.
+    Diffuse,
+    /// Smooth surfaces like calm water act as a mirror. The signal only returns from the
+    /// specular point, and only while it is close to the antenna boresight.
+    Specular,
+}
+
+/// A section of the surface below or around the aircraft, as seen by the radio altimeter.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TerrainSurface {
+    elevation: Length,
+    reflectivity: Ratio,
+    reflection: SurfaceReflection,
+}
+
+impl TerrainSurface {
+    /// Creates a surface with the given elevation relative to the ground below the aircraft's
+    /// center of gravity, and the ratio of the signal it reflects.
+    pub fn new(elevation: Length, reflectivity: Ratio, reflection: SurfaceReflection) -> Self {
+        Self {
+            elevation,
+            reflectivity,
+            reflection,
+        }
+    }
+
+    pub fn ground(elevation: Length) -> Self {
+        Self::new(
+            elevation,
+            Ratio::new::<ratio>(1.),
+            SurfaceReflection::Diffuse,
+        )
+    }
+
+    pub fn calm_water(elevation: Length) -> Self {
+        Self::new(
+            elevation,
+            Ratio::new::<ratio>(1.),
+            SurfaceReflection::Specular,
+        )
+    }
+
+    pub fn elevation(&self) -> Length {
+        self.elevation
+    }
+
+    pub fn reflectivity(&self) -> Ratio {
+        self.reflectivity
+    }
+
+    pub fn reflection(&self) -> SurfaceReflection {
+        self.reflection
+    }
+}
+
+/// Provides the surface along the ground track of the aircraft to the transceivers of a radio
+/// altimeter. This allows to feed synthetic profiles with obstacles, water or cliff edges.
+pub trait TerrainProfile {
+    /// Returns the surface at the given distance from the aircraft's center of gravity, measured
+    /// along the ground track. Positive distances are ahead of the aircraft, negative ones behind.
+    fn surface_at(&self, distance: Length) -> TerrainSurface;
+}
+
+/// The terrain as known to the simulator: flat ground at the height of the aircraft above ground.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct FlatTerrain;
+
+impl TerrainProfile for FlatTerrain {
+    fn surface_at(&self, _: Length) -> TerrainSurface {
+        TerrainSurface::ground(Length::new::<foot>(0.))
+    }
+}
+
 pub trait RadioAltimeter {
     fn radio_altitude(&self) -> Arinc429Word<Length>;
 }
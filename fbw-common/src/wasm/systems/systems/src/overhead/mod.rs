@@ -1,6 +1,6 @@
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
-    Write,
+    VariableMetadata, Write,
 };
 
 pub struct OnOffFaultPushButton {
@@ -597,7 +597,10 @@ pub struct MomentaryPushButton {
 impl MomentaryPushButton {
     pub fn new(context: &mut InitContext, name: &str) -> Self {
         Self {
-            is_pressed_id: context.get_identifier(format!("OVHD_{}_IS_PRESSED", name)),
+            is_pressed_id: context.get_identifier_with_metadata(
+                format!("OVHD_{}_IS_PRESSED", name),
+                VariableMetadata::new().written_externally(),
+            ),
             is_pressed: false,
         }
     }
@@ -621,7 +624,10 @@ pub struct PressSingleSignalButton {
 impl PressSingleSignalButton {
     pub fn new(context: &mut InitContext, name: &str) -> Self {
         Self {
-            is_pressed_id: context.get_identifier(format!("OVHD_{}_IS_PRESSED", name)),
+            is_pressed_id: context.get_identifier_with_metadata(
+                format!("OVHD_{}_IS_PRESSED", name),
+                VariableMetadata::new().written_externally(),
+            ),
             is_pressed: false,
             last_pressed_state: false,
         }
@@ -649,7 +655,10 @@ pub struct MomentaryOnPushButton {
 impl MomentaryOnPushButton {
     pub fn new(context: &mut InitContext, name: &str) -> Self {
         Self {
-            is_pressed_id: context.get_identifier(format!("OVHD_{}_IS_PRESSED", name)),
+            is_pressed_id: context.get_identifier_with_metadata(
+                format!("OVHD_{}_IS_PRESSED", name),
+                VariableMetadata::new().written_externally(),
+            ),
             is_on_id: context.get_identifier(format!("OVHD_{}_IS_ON", name)),
             is_pressed: false,
             last_pressed_state: false,
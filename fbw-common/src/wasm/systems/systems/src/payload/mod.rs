@@ -1,15 +1,17 @@
-use std::{cell::Cell, rc::Rc};
+use std::{cell::Cell, rc::Rc, time::Duration};
 
 use crate::{
     shared::random_from_range,
     simulation::{
-        Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write, Writer,
+        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write, Writer,
     },
 };
 use uom::si::{f64::Mass, mass::kilogram, mass::pound};
 
-const JS_MAX_SAFE_INTEGER: i8 = 53;
+/// The number of seats which can be encoded in one seat word. It is limited by the largest
+/// integer a JavaScript number can safely represent, which is 2^53.
+const JS_MAX_SAFE_INTEGER: u32 = 53;
 const MAX_CARGO_MOVE: f64 = 60.;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -40,18 +42,34 @@ impl From<f64> for GsxState {
 read_write_enum!(GsxState);
 
 pub struct PaxInfo {
-    pub max_pax: i8,
+    pub max_pax: i32,
     pub pax_id: String,
     pub payload_id: String,
 }
 impl PaxInfo {
-    pub fn new(max_pax: i8, pax_id: &str, payload_id: &str) -> Self {
+    pub fn new(max_pax: i32, pax_id: &str, payload_id: &str) -> Self {
         PaxInfo {
             max_pax,
             pax_id: pax_id.to_string(),
             payload_id: payload_id.to_string(),
         }
     }
+
+    /// Returns the number of seat words needed to encode all seats of the station.
+    pub fn seat_words(&self) -> usize {
+        ((self.max_pax.max(1) - 1) / JS_MAX_SAFE_INTEGER as i32 + 1) as usize
+    }
+
+    /// Returns the names of the variables holding the seat words of the station. The first word
+    /// uses the station's name, i.e. `PAX_A`, subsequent words are suffixed, i.e. `PAX_A_2`.
+    pub fn pax_ids(&self) -> Vec<String> {
+        (0..self.seat_words())
+            .map(|word| match word {
+                0 => self.pax_id.to_owned(),
+                _ => format!("{}_{}", self.pax_id, word + 1),
+            })
+            .collect()
+    }
 }
 
 pub struct CargoInfo {
@@ -68,14 +86,16 @@ impl CargoInfo {
         }
     }
 }
+/// A passenger station. The seats are encoded as bits in one or more seat words of
+/// [JS_MAX_SAFE_INTEGER] bits each, so stations aren't limited in size by the encoding.
 #[derive(Debug)]
 pub struct Pax {
-    pax_id: VariableIdentifier,
-    pax_target_id: VariableIdentifier,
+    pax_ids: Vec<VariableIdentifier>,
+    pax_target_ids: Vec<VariableIdentifier>,
     payload_id: VariableIdentifier,
     per_pax_weight: Rc<Cell<Mass>>,
-    pax_target: u64,
-    pax: u64,
+    pax_target: Vec<u64>,
+    pax: Vec<u64>,
     payload: Mass,
 }
 impl Pax {
@@ -85,13 +105,34 @@ impl Pax {
         payload_id: VariableIdentifier,
         per_pax_weight: Rc<Cell<Mass>>,
     ) -> Self {
+        Self::new_with_seat_words(
+            vec![pax_id],
+            vec![pax_target_id],
+            payload_id,
+            per_pax_weight,
+        )
+    }
+
+    pub fn new_with_seat_words(
+        pax_ids: Vec<VariableIdentifier>,
+        pax_target_ids: Vec<VariableIdentifier>,
+        payload_id: VariableIdentifier,
+        per_pax_weight: Rc<Cell<Mass>>,
+    ) -> Self {
+        assert_eq!(
+            pax_ids.len(),
+            pax_target_ids.len(),
+            "Every seat word requires a target seat word."
+        );
+
+        let seat_words = pax_ids.len();
         Pax {
-            pax_id,
-            pax_target_id,
+            pax_ids,
+            pax_target_ids,
             per_pax_weight,
             payload_id,
-            pax_target: 0,
-            pax: 0,
+            pax_target: vec![0; seat_words],
+            pax: vec![0; seat_words],
             payload: Mass::default(),
         }
     }
@@ -104,16 +145,19 @@ impl Pax {
         self.pax == self.pax_target
     }
 
-    pub fn pax(&self) -> u64 {
-        self.pax
+    pub fn pax(&self) -> &[u64] {
+        &self.pax
     }
 
-    pub fn pax_num(&self) -> i8 {
-        self.pax.count_ones() as i8
+    pub fn pax_num(&self) -> i32 {
+        self.pax.iter().map(|word| word.count_ones() as i32).sum()
     }
 
-    pub fn pax_target_num(&self) -> i8 {
-        self.pax_target.count_ones() as i8
+    pub fn pax_target_num(&self) -> i32 {
+        self.pax_target
+            .iter()
+            .map(|word| word.count_ones() as i32)
+            .sum()
     }
 
     pub fn payload(&self) -> Mass {
@@ -131,11 +175,11 @@ impl Pax {
     }
 
     pub fn move_all_pax(&mut self) {
-        self.pax = self.pax_target;
+        self.pax = self.pax_target.clone();
         self.load_payload();
     }
 
-    pub fn move_pax(&mut self, pax: i8) {
+    pub fn move_pax(&mut self, pax: i32) {
         for _ in 0..pax {
             self.move_one_pax();
         }
@@ -144,23 +188,37 @@ impl Pax {
     pub fn move_one_pax(&mut self) {
         let pax_diff = self.pax_target_num() - self.pax_num();
 
-        let n: u64 = if pax_diff > 0 {
-            !self.pax & self.pax_target
-        } else {
-            self.pax & !self.pax_target
-        };
-        let count = n.count_ones() as f64;
-        if count > 0. {
-            let mut skip: i8 = random_from_range(0., count) as i8;
-
-            for i in 0..JS_MAX_SAFE_INTEGER {
-                let bit = 1 << i;
-                if (n & bit) > 0 {
-                    if skip <= 0 {
-                        self.pax ^= bit;
-                        break;
+        let candidates: Vec<u64> = self
+            .pax
+            .iter()
+            .zip(&self.pax_target)
+            .map(|(&pax, &pax_target)| {
+                if pax_diff > 0 {
+                    !pax & pax_target
+                } else {
+                    pax & !pax_target
+                }
+            })
+            .collect();
+        let count: u32 = candidates.iter().map(|n| n.count_ones()).sum();
+        if count > 0 {
+            let mut skip: u32 = random_from_range(0., count as f64) as u32;
+
+            'words: for (word, n) in candidates.iter().enumerate() {
+                if skip >= n.count_ones() {
+                    skip -= n.count_ones();
+                    continue;
+                }
+
+                for i in 0..JS_MAX_SAFE_INTEGER {
+                    let bit = 1 << i;
+                    if (n & bit) > 0 {
+                        if skip == 0 {
+                            self.pax[word] ^= bit;
+                            break 'words;
+                        }
+                        skip -= 1;
                     }
-                    skip -= 1;
                 }
             }
         }
@@ -168,18 +226,26 @@ impl Pax {
     }
 
     pub fn reset_pax_target(&mut self) {
-        self.pax_target = 0;
+        self.pax_target.iter_mut().for_each(|word| *word = 0);
     }
 }
 impl SimulationElement for Pax {
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.pax = reader.read(&self.pax_id);
-        self.pax_target = reader.read(&self.pax_target_id);
+        for (word, id) in self.pax_ids.iter().enumerate() {
+            self.pax[word] = reader.read(id);
+        }
+        for (word, id) in self.pax_target_ids.iter().enumerate() {
+            self.pax_target[word] = reader.read(id);
+        }
         self.payload = reader.read(&self.payload_id);
     }
     fn write(&self, writer: &mut SimulatorWriter) {
-        writer.write(&self.pax_id, self.pax);
-        writer.write(&self.pax_target_id, self.pax_target);
+        for (id, &word) in self.pax_ids.iter().zip(&self.pax) {
+            writer.write(id, word);
+        }
+        for (id, &word) in self.pax_target_ids.iter().zip(&self.pax_target) {
+            writer.write(id, word);
+        }
         writer.write(&self.payload_id, self.payload.get::<pound>());
     }
 }
@@ -304,3 +370,580 @@ impl From<f64> for BoardingRate {
         }
     }
 }
+
+pub struct BoardingSounds {
+    pax_board_id: VariableIdentifier,
+    pax_deboard_id: VariableIdentifier,
+    pax_complete_id: VariableIdentifier,
+    pax_ambience_id: VariableIdentifier,
+    pax_boarding: bool,
+    pax_deboarding: bool,
+    pax_complete: bool,
+    pax_ambience: bool,
+}
+impl BoardingSounds {
+    pub fn new(
+        pax_board_id: VariableIdentifier,
+        pax_deboard_id: VariableIdentifier,
+        pax_complete_id: VariableIdentifier,
+        pax_ambience_id: VariableIdentifier,
+    ) -> Self {
+        BoardingSounds {
+            pax_board_id,
+            pax_deboard_id,
+            pax_complete_id,
+            pax_ambience_id,
+            pax_boarding: false,
+            pax_deboarding: false,
+            pax_complete: false,
+            pax_ambience: false,
+        }
+    }
+
+    pub fn pax_boarding(&self) -> bool {
+        self.pax_boarding
+    }
+
+    pub fn pax_deboarding(&self) -> bool {
+        self.pax_deboarding
+    }
+
+    pub fn pax_complete(&self) -> bool {
+        self.pax_complete
+    }
+
+    pub fn pax_ambience(&self) -> bool {
+        self.pax_ambience
+    }
+
+    fn start_pax_boarding(&mut self) {
+        self.pax_boarding = true;
+    }
+
+    fn stop_pax_boarding(&mut self) {
+        self.pax_boarding = false;
+    }
+
+    fn start_pax_deboarding(&mut self) {
+        self.pax_deboarding = true;
+    }
+
+    fn stop_pax_deboarding(&mut self) {
+        self.pax_deboarding = false;
+    }
+
+    fn start_pax_complete(&mut self) {
+        self.pax_complete = true;
+    }
+
+    fn stop_pax_complete(&mut self) {
+        self.pax_complete = false;
+    }
+
+    fn start_pax_ambience(&mut self) {
+        self.pax_ambience = true;
+    }
+
+    fn stop_pax_ambience(&mut self) {
+        self.pax_ambience = false;
+    }
+}
+impl SimulationElement for BoardingSounds {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.pax_board_id, self.pax_boarding);
+        writer.write(&self.pax_deboard_id, self.pax_deboarding);
+        writer.write(&self.pax_complete_id, self.pax_complete);
+        writer.write(&self.pax_ambience_id, self.pax_ambience);
+    }
+}
+
+/// The passengers and cargo of an aircraft, boarded by the EFB or by GSX. The aircraft provides
+/// its passenger stations and cargo compartments, which are then referred to by their index in
+/// the given tables.
+pub struct Payload {
+    developer_state_id: VariableIdentifier,
+    is_boarding_id: VariableIdentifier,
+    board_rate_id: VariableIdentifier,
+    per_pax_weight_id: VariableIdentifier,
+
+    is_gsx_enabled_id: VariableIdentifier,
+    gsx_boarding_state_id: VariableIdentifier,
+    gsx_deboarding_state_id: VariableIdentifier,
+    gsx_pax_boarding_id: VariableIdentifier,
+    gsx_pax_deboarding_id: VariableIdentifier,
+    gsx_cargo_boarding_pct_id: VariableIdentifier,
+    gsx_cargo_deboarding_pct_id: VariableIdentifier,
+
+    developer_state: i8,
+    is_boarding: bool,
+    board_rate: BoardingRate,
+    per_pax_weight: Rc<Cell<Mass>>,
+
+    is_gsx_enabled: bool,
+    gsx_boarding_state: GsxState,
+    gsx_deboarding_state: GsxState,
+    gsx_pax_boarding: i32,
+    gsx_pax_deboarding: i32,
+    gsx_cargo_boarding_pct: f64,
+    gsx_cargo_deboarding_pct: f64,
+
+    pax: Vec<Pax>,
+    cargo: Vec<Cargo>,
+    max_pax: i32,
+    boarding_sounds: BoardingSounds,
+    time: Duration,
+}
+impl Payload {
+    pub const DEFAULT_PER_PAX_WEIGHT_KG: f64 = 84.;
+
+    pub fn new(context: &mut InitContext, pax_info: &[PaxInfo], cargo_info: &[CargoInfo]) -> Self {
+        let per_pax_weight = Rc::new(Cell::new(Mass::new::<kilogram>(
+            Self::DEFAULT_PER_PAX_WEIGHT_KG,
+        )));
+
+        let pax = pax_info
+            .iter()
+            .map(|info| {
+                let pax_ids = info.pax_ids();
+                Pax::new_with_seat_words(
+                    pax_ids
+                        .iter()
+                        .map(|id| context.get_identifier(id.to_owned()))
+                        .collect(),
+                    pax_ids
+                        .iter()
+                        .map(|id| context.get_identifier(format!("{}_DESIRED", id)))
+                        .collect(),
+                    context.get_identifier(info.payload_id.to_owned()),
+                    Rc::clone(&per_pax_weight),
+                )
+            })
+            .collect();
+
+        let cargo = cargo_info
+            .iter()
+            .map(|info| {
+                Cargo::new(
+                    context.get_identifier(info.cargo_id.to_owned()),
+                    context.get_identifier_with_metadata(
+                        format!("{}_DESIRED", info.cargo_id),
+                        VariableMetadata::new().written_externally(),
+                    ),
+                    context.get_identifier(info.payload_id.to_owned()),
+                )
+            })
+            .collect();
+
+        Payload {
+            developer_state_id: context.get_identifier_with_metadata(
+                "DEVELOPER_STATE".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            is_boarding_id: context.get_identifier("BOARDING_STARTED_BY_USR".to_owned()),
+            board_rate_id: context.get_identifier_with_metadata(
+                "BOARDING_RATE".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            per_pax_weight_id: context.get_identifier("WB_PER_PAX_WEIGHT".to_owned()),
+
+            is_gsx_enabled_id: context.get_identifier_with_metadata(
+                "GSX_PAYLOAD_SYNC_ENABLED".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gsx_boarding_state_id: context.get_identifier_with_metadata(
+                "FSDT_GSX_BOARDING_STATE".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gsx_deboarding_state_id: context.get_identifier_with_metadata(
+                "FSDT_GSX_DEBOARDING_STATE".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gsx_pax_boarding_id: context.get_identifier_with_metadata(
+                "FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gsx_pax_deboarding_id: context.get_identifier_with_metadata(
+                "FSDT_GSX_NUMPASSENGERS_DEBOARDING_TOTAL".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gsx_cargo_boarding_pct_id: context.get_identifier_with_metadata(
+                "FSDT_GSX_BOARDING_CARGO_PERCENT".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+            gsx_cargo_deboarding_pct_id: context.get_identifier_with_metadata(
+                "FSDT_GSX_DEBOARDING_CARGO_PERCENT".to_owned(),
+                VariableMetadata::new().written_externally(),
+            ),
+
+            developer_state: 0,
+            is_boarding: false,
+            board_rate: BoardingRate::Instant,
+            per_pax_weight,
+            is_gsx_enabled: false,
+            gsx_boarding_state: GsxState::None,
+            gsx_deboarding_state: GsxState::None,
+            gsx_pax_boarding: 0,
+            gsx_pax_deboarding: 0,
+            gsx_cargo_boarding_pct: 0.,
+            gsx_cargo_deboarding_pct: 0.,
+            boarding_sounds: BoardingSounds::new(
+                context.get_identifier("SOUND_PAX_BOARDING".to_owned()),
+                context.get_identifier("SOUND_PAX_DEBOARDING".to_owned()),
+                context.get_identifier("SOUND_BOARDING_COMPLETE".to_owned()),
+                context.get_identifier("SOUND_PAX_AMBIENCE".to_owned()),
+            ),
+            pax,
+            cargo,
+            max_pax: pax_info.iter().map(|info| info.max_pax).sum(),
+            time: Duration::from_nanos(0),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        if !self.is_developer_state_active() {
+            self.ensure_payload_sync()
+        };
+
+        if self.is_gsx_enabled() {
+            self.stop_boarding();
+            self.stop_boarding_sounds();
+            self.update_extern_gsx(context);
+        } else {
+            self.update_intern(context);
+        }
+    }
+
+    fn ensure_payload_sync(&mut self) {
+        for pax in &mut self.pax {
+            if !pax.payload_is_sync() {
+                pax.load_payload();
+            }
+        }
+
+        for cargo in &mut self.cargo {
+            if !cargo.payload_is_sync() {
+                cargo.load_payload();
+            }
+        }
+    }
+
+    fn update_extern_gsx(&mut self, context: &UpdateContext) {
+        self.update_gsx_deboarding(context);
+        self.update_gsx_boarding(context);
+    }
+
+    fn update_gsx_deboarding(&mut self, _context: &UpdateContext) {
+        self.update_pax_ambience();
+        match self.gsx_deboarding_state {
+            GsxState::None | GsxState::Available | GsxState::NotAvailable | GsxState::Bypassed => {}
+            GsxState::Requested => {
+                self.update_cargo_loaded();
+                self.reset_all_pax_targets();
+                self.reset_all_cargo_targets();
+            }
+            GsxState::Completed => {
+                self.move_all_payload();
+                self.reset_cargo_loaded();
+                self.reset_all_cargo_targets();
+            }
+            GsxState::Performing => {
+                self.move_all_pax_num(
+                    self.total_pax_num() - (self.max_pax - self.gsx_pax_deboarding),
+                );
+                self.load_all_cargo_percent(100. - self.gsx_cargo_deboarding_pct);
+            }
+        }
+    }
+
+    fn update_cargo_loaded(&mut self) {
+        self.cargo
+            .iter_mut()
+            .for_each(|cargo| cargo.update_cargo_loaded());
+    }
+
+    fn reset_cargo_loaded(&mut self) {
+        self.cargo
+            .iter_mut()
+            .for_each(|cargo| cargo.reset_cargo_loaded());
+    }
+
+    fn update_gsx_boarding(&mut self, _context: &UpdateContext) {
+        self.update_pax_ambience();
+        match self.gsx_boarding_state {
+            GsxState::None
+            | GsxState::Available
+            | GsxState::NotAvailable
+            | GsxState::Bypassed
+            | GsxState::Requested => {}
+            GsxState::Completed => {
+                self.cargo
+                    .iter_mut()
+                    .for_each(|cargo| cargo.move_all_cargo());
+            }
+            GsxState::Performing => {
+                self.move_all_pax_num(self.gsx_pax_boarding - self.total_pax_num());
+                self.load_all_cargo_percent(self.gsx_cargo_boarding_pct);
+            }
+        }
+    }
+
+    fn update_intern(&mut self, context: &UpdateContext) {
+        self.update_pax_ambience();
+
+        if !self.is_boarding {
+            self.time = Duration::from_nanos(0);
+            self.stop_boarding_sounds();
+            return;
+        }
+
+        let ms_delay = if self.board_rate() == BoardingRate::Instant {
+            0
+        } else if self.board_rate() == BoardingRate::Fast {
+            1000
+        } else {
+            5000
+        };
+
+        let delta_time = context.delta();
+        self.time += delta_time;
+        if self.time.as_millis() > ms_delay {
+            self.time = Duration::from_nanos(0);
+            self.update_pax();
+            self.update_cargo();
+        }
+        // Check sound before updating boarding status
+        self.update_boarding_sounds();
+        self.update_boarding_status();
+    }
+
+    fn update_boarding_status(&mut self) {
+        if self.is_fully_loaded() {
+            self.is_boarding = false;
+        }
+    }
+
+    fn update_boarding_sounds(&mut self) {
+        let pax_board = self.is_pax_boarding();
+        self.play_sound_pax_boarding(pax_board);
+
+        let pax_deboard = self.is_pax_deboarding();
+        self.play_sound_pax_deboarding(pax_deboard);
+
+        let pax_complete = self.is_pax_loaded() && self.is_boarding();
+        self.play_sound_pax_complete(pax_complete);
+    }
+
+    fn update_pax_ambience(&mut self) {
+        let pax_ambience = !self.has_no_pax();
+        self.play_sound_pax_ambience(pax_ambience);
+    }
+
+    fn play_sound_pax_boarding(&mut self, playing: bool) {
+        if playing {
+            self.boarding_sounds.start_pax_boarding();
+        } else {
+            self.boarding_sounds.stop_pax_boarding();
+        }
+    }
+
+    fn play_sound_pax_deboarding(&mut self, playing: bool) {
+        if playing {
+            self.boarding_sounds.start_pax_deboarding();
+        } else {
+            self.boarding_sounds.stop_pax_deboarding();
+        }
+    }
+
+    fn play_sound_pax_complete(&mut self, playing: bool) {
+        if playing {
+            self.boarding_sounds.start_pax_complete();
+        } else {
+            self.boarding_sounds.stop_pax_complete();
+        }
+    }
+
+    fn play_sound_pax_ambience(&mut self, playing: bool) {
+        if playing {
+            self.boarding_sounds.start_pax_ambience();
+        } else {
+            self.boarding_sounds.stop_pax_ambience();
+        }
+    }
+
+    fn stop_boarding_sounds(&mut self) {
+        self.boarding_sounds.stop_pax_boarding();
+        self.boarding_sounds.stop_pax_deboarding();
+        self.boarding_sounds.stop_pax_complete();
+    }
+
+    fn reset_all_pax_targets(&mut self) {
+        self.pax.iter_mut().for_each(|pax| pax.reset_pax_target());
+    }
+
+    fn move_all_pax_num(&mut self, pax_diff: i32) {
+        for _ in 0..pax_diff.max(0) {
+            if let Some(pax) = self.pax.iter_mut().find(|pax| !pax.pax_is_target()) {
+                pax.move_one_pax();
+            }
+        }
+    }
+
+    fn update_pax(&mut self) {
+        for pax in self.pax.iter_mut().filter(|pax| !pax.pax_is_target()) {
+            if self.board_rate == BoardingRate::Instant {
+                pax.move_all_pax();
+            } else {
+                pax.move_one_pax();
+                break;
+            }
+        }
+    }
+
+    fn reset_all_cargo_targets(&mut self) {
+        self.cargo
+            .iter_mut()
+            .for_each(|cargo| cargo.reset_cargo_target());
+    }
+
+    fn update_cargo(&mut self) {
+        for cargo in self
+            .cargo
+            .iter_mut()
+            .filter(|cargo| !cargo.cargo_is_target())
+        {
+            if self.board_rate == BoardingRate::Instant {
+                cargo.move_all_cargo();
+            } else {
+                cargo.move_one_cargo();
+                break;
+            }
+        }
+    }
+
+    fn is_developer_state_active(&self) -> bool {
+        self.developer_state > 0
+    }
+
+    fn is_pax_boarding(&self) -> bool {
+        self.is_boarding()
+            && self
+                .pax
+                .iter()
+                .any(|pax| pax.pax_num() < pax.pax_target_num())
+    }
+
+    fn is_pax_deboarding(&self) -> bool {
+        self.is_boarding()
+            && self
+                .pax
+                .iter()
+                .any(|pax| pax.pax_num() > pax.pax_target_num())
+    }
+
+    fn is_pax_loaded(&self) -> bool {
+        self.pax.iter().all(|pax| pax.pax_is_target())
+    }
+
+    fn is_cargo_loaded(&self) -> bool {
+        self.cargo.iter().all(|cargo| cargo.cargo_is_target())
+    }
+
+    fn is_fully_loaded(&self) -> bool {
+        self.is_pax_loaded() && self.is_cargo_loaded()
+    }
+
+    fn has_no_pax(&self) -> bool {
+        self.total_pax_num() == 0
+    }
+
+    fn total_pax_num(&self) -> i32 {
+        self.pax.iter().map(|pax| pax.pax_num()).sum()
+    }
+
+    fn load_all_cargo_percent(&mut self, percent: f64) {
+        self.cargo
+            .iter_mut()
+            .for_each(|cargo| cargo.load_cargo_percent(percent));
+    }
+
+    fn move_all_payload(&mut self) {
+        self.pax.iter_mut().for_each(|pax| pax.move_all_pax());
+        self.cargo
+            .iter_mut()
+            .for_each(|cargo| cargo.move_all_cargo());
+    }
+
+    fn is_gsx_enabled(&self) -> bool {
+        self.is_gsx_enabled
+    }
+
+    fn stop_boarding(&mut self) {
+        self.is_boarding = false;
+    }
+
+    fn per_pax_weight(&self) -> Mass {
+        self.per_pax_weight.get()
+    }
+
+    pub fn is_boarding(&self) -> bool {
+        self.is_boarding
+    }
+
+    pub fn board_rate(&self) -> BoardingRate {
+        self.board_rate
+    }
+
+    pub fn boarding_sounds(&self) -> &BoardingSounds {
+        &self.boarding_sounds
+    }
+
+    /// The number of passengers seated in the station with the given index.
+    pub fn pax_num(&self, station: usize) -> i32 {
+        self.pax[station].pax_num()
+    }
+
+    pub fn pax_payload(&self, station: usize) -> Mass {
+        self.pax[station].payload()
+    }
+
+    /// The cargo loaded in the compartment with the given index.
+    pub fn cargo(&self, compartment: usize) -> Mass {
+        self.cargo[compartment].cargo()
+    }
+
+    pub fn cargo_payload(&self, compartment: usize) -> Mass {
+        self.cargo[compartment].payload()
+    }
+}
+impl SimulationElement for Payload {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.pax, visitor);
+        accept_iterable!(self.cargo, visitor);
+        self.boarding_sounds.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.developer_state = reader.read(&self.developer_state_id);
+        self.is_boarding = reader.read(&self.is_boarding_id);
+        self.board_rate = reader.read(&self.board_rate_id);
+        self.is_gsx_enabled = reader.read(&self.is_gsx_enabled_id);
+        self.gsx_boarding_state = reader.read(&self.gsx_boarding_state_id);
+        self.gsx_deboarding_state = reader.read(&self.gsx_deboarding_state_id);
+        self.gsx_pax_boarding = reader.read(&self.gsx_pax_boarding_id);
+        self.gsx_pax_deboarding = reader.read(&self.gsx_pax_deboarding_id);
+        self.gsx_cargo_boarding_pct = reader.read(&self.gsx_cargo_boarding_pct_id);
+        self.gsx_cargo_deboarding_pct = reader.read(&self.gsx_cargo_deboarding_pct_id);
+        self.per_pax_weight
+            .replace(Mass::new::<kilogram>(reader.read(&self.per_pax_weight_id)));
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_boarding_id, self.is_boarding);
+        writer.write(
+            &self.per_pax_weight_id,
+            self.per_pax_weight().get::<kilogram>(),
+        );
+    }
+}
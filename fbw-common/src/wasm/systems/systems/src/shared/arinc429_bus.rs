@@ -0,0 +1,717 @@
+use crate::{
+    shared::arinc429::{Arinc429Word, SignStatus},
+    simulation::{
+        InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{f64::Ratio, ratio::ratio};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Arinc429Speed {
+    Low,
+    High,
+}
+impl Arinc429Speed {
+    fn bits_per_second(&self) -> f64 {
+        match self {
+            Arinc429Speed::Low => 12_500.,
+            Arinc429Speed::High => 100_000.,
+        }
+    }
+}
+
+/// The encoding of the data field (bits 11 to 29) and the SSM (bits 30 and 31) of a word.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Arinc429Format {
+    /// Two's complement binary with the sign in bit 29 and the most significant bit in bit 28.
+    /// The value covers the range from `-range` to `range` with `significant_bits` (at most 18)
+    /// bits of resolution.
+    Bnr { range: f64, significant_bits: u8 },
+    /// Binary coded decimal with up to five `digits`, the most significant digit (three bits)
+    /// starting at bit 27. The sign is encoded in the SSM.
+    Bcd { resolution: f64, digits: u8 },
+    /// Individual bits, as set with [`Arinc429Word::set_bit`].
+    Discrete,
+}
+impl Arinc429Format {
+    const DATA_MASK: u32 = 0x1fff_fc00;
+
+    /// Returns the data field and SSM bits of the given word,
+    /// or `None` when the word cannot be expressed in this format.
+    fn encode(&self, value: f64, ssm: SignStatus) -> Option<(u32, u32)> {
+        match *self {
+            Arinc429Format::Bnr {
+                range,
+                significant_bits,
+            } => {
+                debug_assert!((1..=18).contains(&significant_bits));
+                let resolution = range / (1 << significant_bits) as f64;
+                let max_raw = (1_i64 << significant_bits) - 1;
+                let raw = ((value / resolution).round() as i64).clamp(-max_raw - 1, max_raw);
+                let mask = (1_u32 << (significant_bits + 1)) - 1;
+
+                Some((
+                    ((raw as u32) & mask) << (28 - significant_bits),
+                    u64::from(ssm) as u32,
+                ))
+            }
+            Arinc429Format::Bcd { resolution, digits } => {
+                debug_assert!((1..=5).contains(&digits));
+                let ssm = match ssm {
+                    SignStatus::NormalOperation if value < 0. => 0b11,
+                    SignStatus::NormalOperation => 0b00,
+                    SignStatus::NoComputedData => 0b01,
+                    SignStatus::FunctionalTest => 0b10,
+                    // A BCD word has no failure warning, the transmitter stops transmitting instead
+                    SignStatus::FailureWarning => return None,
+                };
+
+                let mut remaining = (value.abs() / resolution).round() as u32;
+                let mut data = 0;
+                for digit in (0..digits).rev() {
+                    let max_digit = if digit == 0 { 7 } else { 9 };
+                    data |= (remaining % 10).min(max_digit) << (26 - 4 * digit as u32);
+                    remaining /= 10;
+                }
+
+                Some((data, ssm))
+            }
+            Arinc429Format::Discrete => {
+                let ssm = match ssm {
+                    SignStatus::NormalOperation => 0b00,
+                    SignStatus::NoComputedData => 0b01,
+                    SignStatus::FunctionalTest => 0b10,
+                    SignStatus::FailureWarning => 0b11,
+                };
+
+                Some((value as u32 & Self::DATA_MASK, ssm))
+            }
+        }
+    }
+
+    fn decode(&self, data: u32, ssm: u32) -> Arinc429Word<f64> {
+        match *self {
+            Arinc429Format::Bnr {
+                range,
+                significant_bits,
+            } => {
+                let resolution = range / (1 << significant_bits) as f64;
+                let width = significant_bits + 1;
+                let raw = (data >> (28 - significant_bits)) & ((1 << width) - 1);
+                // Sign extend the two's complement value
+                let raw = ((raw << (32 - width)) as i32) >> (32 - width);
+
+                Arinc429Word::new(raw as f64 * resolution, ssm.into())
+            }
+            Arinc429Format::Bcd { resolution, digits } => {
+                let mut value = 0;
+                for digit in 0..digits {
+                    value = value * 10 + ((data >> (26 - 4 * digit as u32)) & 0xf);
+                }
+
+                let (sign, ssm) = match ssm {
+                    0b00 => (1., SignStatus::NormalOperation),
+                    0b11 => (-1., SignStatus::NormalOperation),
+                    0b01 => (1., SignStatus::NoComputedData),
+                    _ => (1., SignStatus::FunctionalTest),
+                };
+
+                Arinc429Word::new(sign * value as f64 * resolution, ssm)
+            }
+            Arinc429Format::Discrete => {
+                let ssm = match ssm {
+                    0b00 => SignStatus::NormalOperation,
+                    0b01 => SignStatus::NoComputedData,
+                    0b10 => SignStatus::FunctionalTest,
+                    _ => SignStatus::FailureWarning,
+                };
+
+                Arinc429Word::new((data & Self::DATA_MASK) as f64, ssm)
+            }
+        }
+    }
+}
+
+/// A word as it is transmitted on the bus: the label in bits 1 to 8 (most significant bit
+/// first), the SDI in bits 9 and 10, the data in bits 11 to 29, the SSM in bits 30 and 31
+/// and odd parity in bit 32.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Arinc429RawWord(u32);
+impl Arinc429RawWord {
+    fn new(label: u8, sdi: u8, data: u32, ssm: u32) -> Self {
+        let word = label.reverse_bits() as u32
+            | ((sdi as u32 & 0b11) << 8)
+            | (data & Arinc429Format::DATA_MASK)
+            | ((ssm & 0b11) << 29);
+
+        Self(word | (((word.count_ones() + 1) % 2) << 31))
+    }
+
+    pub fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// The label number, which is commonly written in octal notation.
+    pub fn label(&self) -> u8 {
+        (self.0 as u8).reverse_bits()
+    }
+
+    pub fn sdi(&self) -> u8 {
+        ((self.0 >> 8) & 0b11) as u8
+    }
+
+    fn data(&self) -> u32 {
+        self.0 & Arinc429Format::DATA_MASK
+    }
+
+    fn ssm(&self) -> u32 {
+        (self.0 >> 29) & 0b11
+    }
+
+    pub fn has_valid_parity(&self) -> bool {
+        self.0.count_ones() % 2 == 1
+    }
+}
+
+/// The definition of a label which is transmitted on a bus.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arinc429Label {
+    number: u8,
+    sdi: u8,
+    format: Arinc429Format,
+    transmit_interval: Duration,
+}
+impl Arinc429Label {
+    pub fn new(number: u8, sdi: u8, format: Arinc429Format, transmit_interval: Duration) -> Self {
+        debug_assert!(sdi <= 0b11);
+        Self {
+            number,
+            sdi,
+            format,
+            transmit_interval,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    pub fn sdi(&self) -> u8 {
+        self.sdi
+    }
+
+    fn matches(&self, number: u8, sdi: u8) -> bool {
+        self.number == number && self.sdi == sdi
+    }
+}
+
+struct TransmittedLabel {
+    label: Arinc429Label,
+    word_id: VariableIdentifier,
+    word: Option<Arinc429RawWord>,
+    time_since_transmission: Duration,
+}
+
+/// An ARINC 429 bus is driven by a single transmitting LRU and read by any number of
+/// [`Arinc429Receiver`]s. Each label is transmitted at its own interval, as long as a word
+/// which can be expressed in the label's format is set.
+///
+/// The last transmitted encoding of every label is written to the simulator as
+/// `{NAME}_{LABEL}_{SDI}`, with the label in octal notation.
+pub struct Arinc429Bus {
+    bus_load_id: VariableIdentifier,
+    speed: Arinc429Speed,
+    labels: Vec<TransmittedLabel>,
+    transmitted_words: Vec<Arinc429RawWord>,
+}
+impl Arinc429Bus {
+    // A word consists of 32 bits followed by a gap of at least four bit times
+    const BIT_TIMES_PER_WORD: f64 = 36.;
+
+    pub fn new(
+        context: &mut InitContext,
+        name: &str,
+        speed: Arinc429Speed,
+        labels: &[Arinc429Label],
+    ) -> Self {
+        Self {
+            bus_load_id: context.get_identifier(format!("{}_BUS_LOAD", name)),
+            speed,
+            labels: labels
+                .iter()
+                .map(|&label| TransmittedLabel {
+                    label,
+                    word_id: context
+                        .get_identifier(format!("{}_{:03o}_{}", name, label.number, label.sdi)),
+                    word: None,
+                    time_since_transmission: label.transmit_interval,
+                })
+                .collect(),
+            transmitted_words: Vec::new(),
+        }
+    }
+
+    /// Sets the word to transmit with the given label. Panics when the label isn't defined on the bus.
+    pub fn set_word(&mut self, number: u8, sdi: u8, word: Arinc429Word<f64>) {
+        let label = self.label_mut(number, sdi);
+        label.word = label
+            .label
+            .format
+            .encode(word.value(), word.ssm())
+            .map(|(data, ssm)| Arinc429RawWord::new(number, sdi, data, ssm));
+    }
+
+    pub fn set_discrete_word(&mut self, number: u8, sdi: u8, word: Arinc429Word<u32>) {
+        self.set_word(
+            number,
+            sdi,
+            Arinc429Word::new(word.value() as f64, word.ssm()),
+        );
+    }
+
+    /// Stops transmitting the given label, as done by an LRU which has no data to provide.
+    pub fn clear_word(&mut self, number: u8, sdi: u8) {
+        self.label_mut(number, sdi).word = None;
+    }
+
+    fn label_mut(&mut self, number: u8, sdi: u8) -> &mut TransmittedLabel {
+        self.labels
+            .iter_mut()
+            .find(|label| label.label.matches(number, sdi))
+            .unwrap_or_else(|| panic!("Label {:03o} with SDI {} is not defined", number, sdi))
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.transmitted_words.clear();
+
+        for label in self.labels.iter_mut() {
+            label.time_since_transmission += context.delta();
+
+            if label.time_since_transmission >= label.label.transmit_interval {
+                if let Some(word) = label.word {
+                    self.transmitted_words.push(word);
+                    label.time_since_transmission = Duration::ZERO;
+                }
+            }
+        }
+    }
+
+    /// The words transmitted during the last update.
+    pub fn transmitted_words(&self) -> &[Arinc429RawWord] {
+        &self.transmitted_words
+    }
+
+    /// The share of the bus capacity which is used by the transmitted labels.
+    pub fn bus_load(&self) -> Ratio {
+        let bit_times_per_second: f64 = self
+            .labels
+            .iter()
+            .filter(|label| label.word.is_some())
+            .map(|label| Self::BIT_TIMES_PER_WORD / label.label.transmit_interval.as_secs_f64())
+            .sum();
+
+        Ratio::new::<ratio>(bit_times_per_second / self.speed.bits_per_second())
+    }
+
+    pub fn is_overloaded(&self) -> bool {
+        self.bus_load().get::<ratio>() > 1.
+    }
+}
+impl SimulationElement for Arinc429Bus {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.bus_load_id, self.bus_load());
+
+        for label in self.labels.iter() {
+            writer.write(
+                &label.word_id,
+                label.word.map_or(0., |word| word.bits() as f64),
+            );
+        }
+    }
+}
+struct ReceivedLabel {
+    label: Arinc429Label,
+    word: Option<Arinc429RawWord>,
+    age: Duration,
+}
+
+/// Receives the labels it is interested in from an [`Arinc429Bus`]. Words with invalid
+/// parity are discarded and a label becomes stale when it isn't refreshed within three
+/// times its transmit interval.
+pub struct Arinc429Receiver {
+    labels: Vec<ReceivedLabel>,
+}
+impl Arinc429Receiver {
+    const REFRESH_TIMEOUT_INTERVAL_MULTIPLE: u32 = 3;
+
+    pub fn new(labels: &[Arinc429Label]) -> Self {
+        Self {
+            labels: labels
+                .iter()
+                .map(|&label| ReceivedLabel {
+                    label,
+                    word: None,
+                    age: Duration::ZERO,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, bus: &Arinc429Bus) {
+        self.labels
+            .iter_mut()
+            .for_each(|label| label.age += context.delta());
+
+        for word in bus.transmitted_words() {
+            self.receive(*word);
+        }
+    }
+
+    /// Receives a word which wasn't transmitted by an [`Arinc429Bus`] of this simulation,
+    /// e.g. from external hardware.
+    pub fn receive(&mut self, word: Arinc429RawWord) {
+        if !word.has_valid_parity() {
+            return;
+        }
+
+        if let Some(label) = self
+            .labels
+            .iter_mut()
+            .find(|label| label.label.matches(word.label(), word.sdi()))
+        {
+            label.word = Some(word);
+            label.age = Duration::ZERO;
+        }
+    }
+
+    /// Returns the decoded word, or `None` when the label wasn't received or is stale.
+    pub fn word(&self, number: u8, sdi: u8) -> Option<Arinc429Word<f64>> {
+        self.fresh_label(number, sdi).and_then(|label| {
+            label
+                .word
+                .map(|word| label.label.format.decode(word.data(), word.ssm()))
+        })
+    }
+
+    pub fn discrete_word(&self, number: u8, sdi: u8) -> Option<Arinc429Word<u32>> {
+        self.word(number, sdi)
+            .map(|word| Arinc429Word::new(word.value() as u32, word.ssm()))
+    }
+
+    pub fn is_stale(&self, number: u8, sdi: u8) -> bool {
+        self.fresh_label(number, sdi).is_none()
+    }
+
+    fn fresh_label(&self, number: u8, sdi: u8) -> Option<&ReceivedLabel> {
+        self.labels
+            .iter()
+            .find(|label| label.label.matches(number, sdi))
+            .filter(|label| {
+                label.word.is_some()
+                    && label.age
+                        <= label.label.transmit_interval * Self::REFRESH_TIMEOUT_INTERVAL_MULTIPLE
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::Electricity,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+    use ntest::assert_about_eq;
+    use rstest::rstest;
+    use uom::si::ratio::percent;
+
+    const ALTITUDE: u8 = 0o203;
+    const DATE: u8 = 0o260;
+    const DISCRETES: u8 = 0o270;
+
+    fn altitude_label() -> Arinc429Label {
+        Arinc429Label::new(
+            ALTITUDE,
+            0,
+            Arinc429Format::Bnr {
+                range: 131072.,
+                significant_bits: 17,
+            },
+            Duration::from_millis(50),
+        )
+    }
+
+    fn date_label() -> Arinc429Label {
+        Arinc429Label::new(
+            DATE,
+            0,
+            Arinc429Format::Bcd {
+                resolution: 1.,
+                digits: 5,
+            },
+            Duration::from_millis(500),
+        )
+    }
+
+    fn discretes_label() -> Arinc429Label {
+        Arinc429Label::new(
+            DISCRETES,
+            1,
+            Arinc429Format::Discrete,
+            Duration::from_millis(100),
+        )
+    }
+
+    fn labels() -> [Arinc429Label; 3] {
+        [altitude_label(), date_label(), discretes_label()]
+    }
+
+    struct TestAircraft {
+        bus: Arinc429Bus,
+        receiver: Arinc429Receiver,
+        bus_is_transmitting: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                bus: Arinc429Bus::new(context, "TEST_BUS", Arinc429Speed::Low, &labels()),
+                receiver: Arinc429Receiver::new(&labels()),
+                bus_is_transmitting: true,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _: &mut Electricity,
+        ) {
+            if self.bus_is_transmitting {
+                self.bus.update(context);
+            } else {
+                self.bus.transmitted_words.clear();
+            }
+            self.receiver.update(context, &self.bus);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.bus.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct Arinc429BusTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl Arinc429BusTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn with_word(mut self, number: u8, value: f64, ssm: SignStatus) -> Self {
+            self.command(|a| a.bus.set_word(number, 0, Arinc429Word::new(value, ssm)));
+            self
+        }
+
+        fn and_run(mut self) -> Self {
+            self.run_with_delta(Duration::from_millis(50));
+            self
+        }
+
+        fn received_word(&self, number: u8, sdi: u8) -> Option<Arinc429Word<f64>> {
+            self.query(|a| a.receiver.word(number, sdi))
+        }
+    }
+    impl TestBed for Arinc429BusTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> Arinc429BusTestBed {
+        Arinc429BusTestBed::new()
+    }
+
+    #[test]
+    fn raw_word_contains_label_sdi_and_odd_parity() {
+        let word = Arinc429RawWord::new(ALTITUDE, 2, 0, 0b11);
+
+        assert_eq!(word.label(), ALTITUDE);
+        assert_eq!(word.sdi(), 2);
+        assert_eq!(word.bits() & 0xff, 0b1100_0001);
+        assert!(word.has_valid_parity());
+        assert!(!Arinc429RawWord::from_bits(word.bits() ^ 1 << 12).has_valid_parity());
+    }
+
+    #[rstest]
+    #[case(0.)]
+    #[case(1.)]
+    #[case(-1.)]
+    #[case(35000.)]
+    #[case(-1200.)]
+    fn bnr_conversion_is_symmetric(#[case] value: f64) {
+        let format = altitude_label().format;
+        let (data, ssm) = format.encode(value, SignStatus::NormalOperation).unwrap();
+        let word = format.decode(data, ssm);
+
+        assert_about_eq!(word.value(), value);
+        assert_eq!(word.ssm(), SignStatus::NormalOperation);
+    }
+
+    #[test]
+    fn bnr_value_is_limited_to_the_range() {
+        let format = altitude_label().format;
+        let (data, ssm) = format.encode(200000., SignStatus::NormalOperation).unwrap();
+
+        assert_about_eq!(format.decode(data, ssm).value(), 131071.);
+    }
+
+    #[rstest]
+    #[case(12345.)]
+    #[case(-250.)]
+    fn bcd_conversion_is_symmetric(#[case] value: f64) {
+        let format = date_label().format;
+        let (data, ssm) = format.encode(value, SignStatus::NormalOperation).unwrap();
+        let word = format.decode(data, ssm);
+
+        assert_about_eq!(word.value(), value);
+        assert_eq!(word.ssm(), SignStatus::NormalOperation);
+    }
+
+    #[test]
+    fn bcd_digits_are_encoded_in_nibbles() {
+        let (data, _) = date_label()
+            .format
+            .encode(12345., SignStatus::NormalOperation)
+            .unwrap();
+
+        assert_eq!(data >> 10, 0x12345);
+    }
+
+    #[test]
+    fn bcd_failure_warning_is_not_transmitted() {
+        let test_bed = test_bed()
+            .with_word(DATE, 1., SignStatus::FailureWarning)
+            .and_run();
+
+        assert!(test_bed.received_word(DATE, 0).is_none());
+    }
+
+    #[test]
+    fn discrete_bits_are_transmitted() {
+        let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+        word.set_bit(11, true);
+        word.set_bit(29, true);
+
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.bus.set_discrete_word(DISCRETES, 1, word));
+        test_bed = test_bed.and_run();
+
+        let received = test_bed
+            .query(|a| a.receiver.discrete_word(DISCRETES, 1))
+            .unwrap();
+        assert!(received.get_bit(11));
+        assert!(!received.get_bit(12));
+        assert!(received.get_bit(29));
+    }
+
+    #[test]
+    fn receiver_receives_transmitted_words() {
+        let test_bed = test_bed()
+            .with_word(ALTITUDE, 10000., SignStatus::NormalOperation)
+            .and_run();
+
+        let word = test_bed.received_word(ALTITUDE, 0).unwrap();
+        assert_about_eq!(word.value(), 10000.);
+        assert!(word.is_normal_operation());
+    }
+
+    #[test]
+    fn receiver_ignores_other_sdi() {
+        let test_bed = test_bed()
+            .with_word(ALTITUDE, 10000., SignStatus::NormalOperation)
+            .and_run();
+
+        assert!(test_bed.query(|a| a.receiver.is_stale(ALTITUDE, 1)));
+    }
+
+    #[test]
+    fn receiver_discards_words_with_invalid_parity() {
+        let mut test_bed = test_bed();
+        let word = Arinc429RawWord::new(ALTITUDE, 0, 0, 0b11);
+        test_bed.command(|a| {
+            a.receiver
+                .receive(Arinc429RawWord::from_bits(word.bits() ^ 1 << 31))
+        });
+
+        assert!(test_bed.received_word(ALTITUDE, 0).is_none());
+    }
+
+    #[test]
+    fn word_becomes_stale_without_refresh() {
+        let mut test_bed = test_bed()
+            .with_word(ALTITUDE, 10000., SignStatus::NormalOperation)
+            .and_run();
+        test_bed.command(|a| a.bus_is_transmitting = false);
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.received_word(ALTITUDE, 0).is_some());
+
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.received_word(ALTITUDE, 0).is_none());
+    }
+
+    #[test]
+    fn labels_are_transmitted_at_their_interval() {
+        let mut test_bed = test_bed()
+            .with_word(DATE, 1., SignStatus::NormalOperation)
+            .and_run();
+
+        assert_eq!(test_bed.query(|a| a.bus.transmitted_words().len()), 1);
+
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.query(|a| a.bus.transmitted_words().is_empty()));
+    }
+
+    #[test]
+    fn bus_load_depends_on_transmitted_labels() {
+        let mut test_bed = test_bed()
+            .with_word(ALTITUDE, 10000., SignStatus::NormalOperation)
+            .and_run();
+
+        // 20 words per second of 36 bit times on a 12.5 kbit/s bus
+        let bus_load: Ratio = test_bed.read_by_name("TEST_BUS_BUS_LOAD");
+        assert_about_eq!(bus_load.get::<percent>(), 5.76);
+        assert!(!test_bed.query(|a| a.bus.is_overloaded()));
+    }
+
+    #[test]
+    fn transmitted_word_is_written_to_the_simulator() {
+        let mut test_bed = test_bed()
+            .with_word(ALTITUDE, 10000., SignStatus::NormalOperation)
+            .and_run();
+
+        let bits: f64 = test_bed.read_by_name("TEST_BUS_203_0");
+        assert_eq!(Arinc429RawWord::from_bits(bits as u32).label(), ALTITUDE);
+    }
+}
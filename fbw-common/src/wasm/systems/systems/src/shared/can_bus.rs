@@ -1,19 +1,96 @@
 use crate::{
+    failures::{Failure, FailureType},
     shared::arinc825::Arinc825Word,
     simulation::{
-        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
-        Write,
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, VariableMetadata, Write,
     },
 };
 use std::collections::VecDeque;
+use std::time::Duration;
 use std::vec::Vec;
 
 const TRANSMISSION_BUFFER_SIZE: usize = 12;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CanNodeState {
+    ErrorActive = 0,
+    ErrorPassive = 1,
+    BusOff = 2,
+}
+
+// The fault confinement of a CAN controller as defined by ISO 11898
+#[derive(Default)]
+struct ErrorCounters {
+    transmit: u16,
+    receive: u16,
+    bus_off: bool,
+    bus_off_duration: Duration,
+}
+impl ErrorCounters {
+    const ERROR_PASSIVE_LIMIT: u16 = 128;
+    const BUS_OFF_LIMIT: u16 = 256;
+    const TRANSMIT_ERROR_INCREMENT: u16 = 8;
+    const RECEIVE_COUNTER_MAX: u16 = 255;
+    const BIT_RATE_BITS_PER_SECOND: f64 = 500_000.;
+    // A node leaves bus-off after detecting 128 times 11 consecutive recessive bits
+    const BUS_OFF_RECOVERY_BITS: f64 = 128. * 11.;
+
+    fn state(&self) -> CanNodeState {
+        if self.bus_off {
+            CanNodeState::BusOff
+        } else if self.transmit >= Self::ERROR_PASSIVE_LIMIT
+            || self.receive >= Self::ERROR_PASSIVE_LIMIT
+        {
+            CanNodeState::ErrorPassive
+        } else {
+            CanNodeState::ErrorActive
+        }
+    }
+
+    fn transmission_failed(&mut self) {
+        self.transmit += Self::TRANSMIT_ERROR_INCREMENT;
+        if self.transmit >= Self::BUS_OFF_LIMIT {
+            self.bus_off = true;
+            self.bus_off_duration = Duration::ZERO;
+        }
+    }
+
+    fn transmission_succeeded(&mut self) {
+        self.transmit = self.transmit.saturating_sub(1);
+    }
+
+    fn reception_failed(&mut self) {
+        self.receive = (self.receive + 1).min(Self::RECEIVE_COUNTER_MAX);
+    }
+
+    fn reception_succeeded(&mut self) {
+        self.receive = self.receive.saturating_sub(1);
+    }
+
+    fn recover_from_bus_off(&mut self, delta: Duration) {
+        if self.bus_off {
+            self.bus_off_duration += delta;
+            if self.bus_off_duration.as_secs_f64()
+                >= Self::BUS_OFF_RECOVERY_BITS / Self::BIT_RATE_BITS_PER_SECOND
+            {
+                *self = ErrorCounters::default();
+            }
+        }
+    }
+}
+
 pub struct CanBus<const N: usize> {
     attached_systems: [u8; N],
-    next_transmitting_system: usize,
     transmission_buffers: Vec<VecDeque<Arinc825Word<f64>>>,
+    transmission_buffer_size: usize,
+    lost_messages: [usize; N],
+    error_counters: [ErrorCounters; N],
+    error_state_ids: Vec<VariableIdentifier>,
+    // every frame on the bus is destroyed by an error frame
+    corruption: Failure,
+    // the transceiver of the attached system corrupts its own frames
+    transmitter_failures: [Failure; N],
     message_received_by_systems_ids: Vec<VariableIdentifier>,
     // first bool is the received-state and the second bool describes a dirty flag to skip read-calls, if needed
     message_received_by_systems: Vec<[bool; 2]>,
@@ -28,18 +105,31 @@ pub struct CanBus<const N: usize> {
 }
 
 impl<const N: usize> CanBus<N> {
-    pub fn new(context: &mut InitContext, bus_name: &str, systems: [u8; N]) -> Self {
+    pub fn new(context: &mut InitContext, bus_name: &str, bus_id: usize, systems: [u8; N]) -> Self {
         Self {
             attached_systems: systems,
-            next_transmitting_system: 0,
             transmission_buffers: (1..=N).map(|_| VecDeque::new()).collect(),
+            transmission_buffer_size: TRANSMISSION_BUFFER_SIZE,
+            lost_messages: [0; N],
+            error_counters: [(); N].map(|_| ErrorCounters::default()),
+            error_state_ids: systems
+                .iter()
+                .map(|id| context.get_identifier(format!("{}_{}_ERROR_STATE", bus_name, id)))
+                .collect(),
+            corruption: Failure::new(FailureType::CanBusCorruption(bus_id)),
+            transmitter_failures: systems.map(|function_id| {
+                Failure::new(FailureType::CanBusTransmitter(bus_id, function_id))
+            }),
             message_received_by_systems_ids: (0..=N - 1)
                 .map(|id| context.get_identifier(format!("{}_{}_RECEIVED", bus_name, systems[id])))
                 .collect(),
             message_received_by_systems: (1..=N).map(|_| [true, true]).collect(),
             availability_id: context.get_identifier(format!("{}_AVAIL", bus_name)),
             available: false,
-            failure_indication_id: context.get_identifier(format!("{}_FAILURE", bus_name)),
+            failure_indication_id: context.get_identifier_with_metadata(
+                format!("{}_FAILURE", bus_name),
+                VariableMetadata::new().written_externally(),
+            ),
             failure_indication: false,
             databus_id: context.get_identifier(bus_name.to_owned()),
             received_message: Arinc825Word::new_with_status(0.0, 0x04000000),
@@ -48,7 +138,12 @@ impl<const N: usize> CanBus<N> {
         }
     }
 
-    pub fn update(&mut self) {
+    pub fn with_transmission_buffer_size(mut self, size: usize) -> Self {
+        self.transmission_buffer_size = size;
+        self
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
         self.next_output_message_valid = false;
 
         self.message_received_by_systems
@@ -56,44 +151,142 @@ impl<const N: usize> CanBus<N> {
             .for_each(|received| received[1] = false);
 
         if self.available && !self.failure_indication {
-            let mut bus_busy = false;
+            // systems in bus-off do not take part in the communication
+            for (i, counters) in self.error_counters.iter().enumerate() {
+                if counters.bus_off {
+                    self.message_received_by_systems[i] = [true, true];
+                }
+            }
 
             // check if all stations received the last message
-            self.message_received_by_systems
+            let bus_busy = self
+                .message_received_by_systems
                 .iter()
-                .for_each(|received| {
-                    if !received[0] {
-                        bus_busy = true;
-                    }
-                });
+                .any(|received| !received[0]);
 
             if !bus_busy {
-                // search the next sendable message
-                for x in 0..N {
-                    let idx = (self.next_transmitting_system + x) % N;
-                    let message = self.transmission_buffers[idx as usize].get(0);
-                    if message.is_some() {
-                        // reset the received flags to release the bus for the next transmission
-                        self.message_received_by_systems
-                            .iter_mut()
-                            .for_each(|received| *received = [false, true]);
-                        self.message_received_by_systems[idx as usize][0] = true;
-
-                        self.next_output_message =
-                            self.transmission_buffers[idx as usize].pop_front().unwrap();
-                        self.next_output_message_valid = true;
-
-                        break;
+                if let Some(idx) = self.arbitration_winner() {
+                    if self.frame_is_corrupted(idx) {
+                        self.transmission_failed(idx);
+                    } else {
+                        self.transmit(idx);
                     }
                 }
-
-                self.next_transmitting_system = (self.next_transmitting_system + 1) % N;
             }
         } else {
             self.message_received_by_systems
                 .iter_mut()
                 .for_each(|received| *received = [true, true]);
         }
+
+        if !self.has_fault() {
+            self.error_counters
+                .iter_mut()
+                .for_each(|counters| counters.recover_from_bus_off(context.delta()));
+        }
+    }
+
+    // The frame with the lowest identifier wins the arbitration.
+    // Error passive systems suspend their transmission while error active systems transmit.
+    fn arbitration_winner(&self) -> Option<usize> {
+        (0..N)
+            .filter(|&idx| {
+                !self.error_counters[idx].bus_off && !self.transmission_buffers[idx].is_empty()
+            })
+            .min_by_key(|&idx| {
+                (
+                    self.error_counters[idx].state() == CanNodeState::ErrorPassive,
+                    self.transmission_buffers[idx][0].status() & 0x1fffffff,
+                    idx,
+                )
+            })
+    }
+
+    fn has_fault(&self) -> bool {
+        self.corruption.is_active()
+            || self
+                .transmitter_failures
+                .iter()
+                .any(|failure| failure.is_active())
+    }
+
+    fn frame_is_corrupted(&self, idx: usize) -> bool {
+        self.corruption.is_active() || self.transmitter_failures[idx].is_active()
+    }
+
+    // The frame remains in the buffer for the automatic retransmission
+    fn transmission_failed(&mut self, idx: usize) {
+        self.error_counters[idx].transmission_failed();
+
+        if self.error_counters[idx].bus_off {
+            self.lost_messages[idx] += self.transmission_buffers[idx].len();
+            self.transmission_buffers[idx].clear();
+        }
+
+        for (i, counters) in self.error_counters.iter_mut().enumerate() {
+            if i != idx && !counters.bus_off {
+                counters.reception_failed();
+            }
+        }
+    }
+
+    fn transmit(&mut self, idx: usize) {
+        // reset the received flags to release the bus for the next transmission
+        self.message_received_by_systems
+            .iter_mut()
+            .for_each(|received| *received = [false, true]);
+        self.message_received_by_systems[idx][0] = true;
+
+        self.next_output_message = self.transmission_buffers[idx].pop_front().unwrap();
+        self.next_output_message_valid = true;
+
+        self.error_counters[idx].transmission_succeeded();
+        for (i, counters) in self.error_counters.iter_mut().enumerate() {
+            if i != idx && !counters.bus_off {
+                counters.reception_succeeded();
+            }
+        }
+    }
+
+    fn system_index(&self, function_id: u8) -> Option<usize> {
+        self.attached_systems
+            .iter()
+            .position(|&id| id == function_id)
+    }
+
+    pub fn node_state(&self, function_id: u8) -> Option<CanNodeState> {
+        self.system_index(function_id)
+            .map(|idx| self.error_counters[idx].state())
+    }
+
+    pub fn transmit_error_counter(&self, function_id: u8) -> Option<u16> {
+        self.system_index(function_id)
+            .map(|idx| self.error_counters[idx].transmit)
+    }
+
+    pub fn receive_error_counter(&self, function_id: u8) -> Option<u16> {
+        self.system_index(function_id)
+            .map(|idx| self.error_counters[idx].receive)
+    }
+
+    /// The number of messages of the system which were dropped due to a full buffer or bus-off.
+    pub fn lost_messages(&self, function_id: u8) -> usize {
+        self.system_index(function_id)
+            .map_or(0, |idx| self.lost_messages[idx])
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.available && !self.failure_indication
+    }
+
+    /// The message transmitted on the bus during the last update. Systems which only listen to
+    /// the bus traffic use it without taking part in the reception handshake of the stations.
+    pub fn transmitted_message(&self) -> Option<Arinc825Word<f64>> {
+        if self.next_output_message_valid {
+            Some(self.next_output_message)
+        } else {
+            None
+        }
     }
 
     pub fn new_message_received(&self, function_id: u8) -> bool {
@@ -125,8 +318,12 @@ impl<const N: usize> CanBus<N> {
     pub fn send_message(&mut self, message: Arinc825Word<f64>) -> bool {
         for (i, id) in self.attached_systems.iter().enumerate() {
             if *id == message.client_function_id() {
-                // detected an buffer overrun
-                if self.transmission_buffers[i].len() >= TRANSMISSION_BUFFER_SIZE {
+                // a system in bus-off cannot transmit and
+                // a detected buffer overrun drops the message
+                if self.error_counters[i].bus_off
+                    || self.transmission_buffers[i].len() >= self.transmission_buffer_size
+                {
+                    self.lost_messages[i] += 1;
                     return false;
                 }
 
@@ -149,6 +346,13 @@ impl<const N: usize> CanBus<N> {
 }
 
 impl<const N: usize> SimulationElement for CanBus<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.corruption.accept(visitor);
+        accept_iterable!(self.transmitter_failures, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.received_message = reader.read_arinc825(&self.databus_id);
 
@@ -182,12 +386,17 @@ impl<const N: usize> SimulationElement for CanBus<N> {
         for (i, id) in self.message_received_by_systems_ids.iter().enumerate() {
             writer.write(id, self.message_received_by_systems[i][0]);
         }
+
+        for (id, counters) in self.error_state_ids.iter().zip(self.error_counters.iter()) {
+            writer.write(id, counters.state() as u8);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::arinc825::LogicalCommunicationChannel;
     use crate::simulation::{
         test::{ReadByName, SimulationTestBed, TestBed},
         Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
@@ -200,12 +409,12 @@ mod tests {
     impl CanBusTestAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
-                can_bus: CanBus::new(context, "TEST_CAN_BUS", [0, 1, 2, 3, 4]),
+                can_bus: CanBus::new(context, "TEST_CAN_BUS", 1, [0, 1, 2, 3, 4]),
             }
         }
 
-        fn update(&mut self, _: &UpdateContext) {
-            self.can_bus.update();
+        fn update(&mut self, context: &UpdateContext) {
+            self.can_bus.update(context);
         }
 
         fn send_message(&mut self, message: Arinc825Word<f64>) {
@@ -219,6 +428,12 @@ mod tests {
         fn received_message(&mut self, id: u8) -> Arinc825Word<f64> {
             self.can_bus.received_message(id)
         }
+
+        fn receive_all_messages(&mut self) {
+            for id in 0..5 {
+                self.can_bus.received_message(id);
+            }
+        }
     }
     impl Aircraft for CanBusTestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -503,4 +718,216 @@ mod tests {
         assert!(value.status() == second_message.status());
         assert_about_eq!(value.value(), second_message.value());
     }
+
+    fn message_of(function_id: u8, value: f64) -> Arinc825Word<f64> {
+        let mut message =
+            Arinc825Word::new(value, LogicalCommunicationChannel::NormalOperationChannel);
+        message.set_client_function_id(function_id);
+        message
+    }
+
+    fn transmitted_message(test_bed: &mut SimulationTestBed<CanBusTestAircraft>) -> f64 {
+        let message: f64 = test_bed.read_by_name("TEST_CAN_BUS");
+        Arinc825Word::<f64>::from(message).value()
+    }
+
+    #[test]
+    fn lowest_identifier_wins_the_arbitration() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        let mut exception =
+            Arinc825Word::new(30.0, LogicalCommunicationChannel::ExceptionEventChannel);
+        exception.set_client_function_id(4);
+        test_bed.command(|a| a.send_message(message_of(1, 20.0)));
+        test_bed.command(|a| a.send_message(exception));
+        test_bed.run();
+
+        assert_about_eq!(transmitted_message(&mut test_bed), 30.0);
+
+        test_bed.command(|a| a.receive_all_messages());
+        test_bed.run();
+
+        assert_about_eq!(transmitted_message(&mut test_bed), 20.0);
+    }
+
+    #[test]
+    fn buffer_overflow_loses_messages() {
+        let mut test_bed = SimulationTestBed::new(|context| CanBusTestAircraft {
+            can_bus: CanBus::new(context, "TEST_CAN_BUS", 1, [0, 1, 2, 3, 4])
+                .with_transmission_buffer_size(2),
+        });
+
+        for value in 0..3 {
+            test_bed.command(|a| a.send_message(message_of(1, value as f64)));
+        }
+
+        assert_eq!(test_bed.query(|a| a.can_bus.lost_messages(1)), 1);
+    }
+
+    #[test]
+    fn corrupted_frames_increase_the_error_counters() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        test_bed.fail(FailureType::CanBusCorruption(1));
+        test_bed.command(|a| a.send_message(message_of(1, 20.0)));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.transmit_error_counter(1)),
+            Some(8)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.receive_error_counter(0)),
+            Some(1)
+        );
+        assert!(test_bed.query(|a| !a.message_available(0)));
+    }
+
+    #[test]
+    fn frame_is_retransmitted_after_the_fault_is_cleared() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        test_bed.fail(FailureType::CanBusCorruption(1));
+        test_bed.command(|a| a.send_message(message_of(1, 20.0)));
+        test_bed.run();
+        test_bed.unfail(FailureType::CanBusCorruption(1));
+        test_bed.run();
+
+        assert_about_eq!(transmitted_message(&mut test_bed), 20.0);
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.transmit_error_counter(1)),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn failing_transmitter_becomes_error_passive_and_bus_off() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        test_bed.fail(FailureType::CanBusTransmitter(1, 1));
+        test_bed.command(|a| a.send_message(message_of(1, 20.0)));
+        for _ in 0..16 {
+            test_bed.run();
+        }
+
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.node_state(1)),
+            Some(CanNodeState::ErrorPassive)
+        );
+
+        for _ in 0..16 {
+            test_bed.run();
+        }
+
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.node_state(1)),
+            Some(CanNodeState::BusOff)
+        );
+        assert_eq!(test_bed.query(|a| a.can_bus.lost_messages(1)), 1);
+        test_bed.command(|a| assert!(!a.can_bus.send_message(message_of(1, 20.0))));
+
+        let error_state: f64 = test_bed.read_by_name("TEST_CAN_BUS_1_ERROR_STATE");
+        assert_about_eq!(error_state, 2.0);
+    }
+
+    #[test]
+    fn error_passive_system_yields_to_error_active_systems() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        test_bed.fail(FailureType::CanBusTransmitter(1, 1));
+        test_bed.command(|a| a.send_message(message_of(1, 20.0)));
+        for _ in 0..16 {
+            test_bed.run();
+        }
+        test_bed.unfail(FailureType::CanBusTransmitter(1, 1));
+        test_bed.command(|a| a.send_message(message_of(2, 25.0)));
+        test_bed.run();
+
+        assert_about_eq!(transmitted_message(&mut test_bed), 25.0);
+    }
+
+    fn bring_system_1_to_bus_off(test_bed: &mut SimulationTestBed<CanBusTestAircraft>) {
+        test_bed.fail(FailureType::CanBusTransmitter(1, 1));
+        test_bed.command(|a| a.send_message(message_of(1, 20.0)));
+        for _ in 0..32 {
+            test_bed.run();
+        }
+        test_bed.unfail(FailureType::CanBusTransmitter(1, 1));
+    }
+
+    #[test]
+    fn bus_off_system_recovers_without_faults() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        bring_system_1_to_bus_off(&mut test_bed);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.node_state(1)),
+            Some(CanNodeState::BusOff)
+        );
+
+        test_bed.run_with_delta(Duration::from_millis(2));
+
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.node_state(1)),
+            Some(CanNodeState::ErrorActive)
+        );
+    }
+
+    #[test]
+    fn bus_off_recovery_time_does_not_depend_on_the_update_rate() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        bring_system_1_to_bus_off(&mut test_bed);
+        for _ in 0..100 {
+            test_bed.run_with_delta(Duration::from_micros(20));
+        }
+
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.node_state(1)),
+            Some(CanNodeState::BusOff)
+        );
+
+        for _ in 0..50 {
+            test_bed.run_with_delta(Duration::from_micros(20));
+        }
+
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.node_state(1)),
+            Some(CanNodeState::ErrorActive)
+        );
+    }
+
+    #[test]
+    fn bus_off_system_does_not_recover_while_the_fault_persists() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        test_bed.fail(FailureType::CanBusTransmitter(1, 1));
+        test_bed.command(|a| a.send_message(message_of(1, 20.0)));
+        for _ in 0..32 {
+            test_bed.run();
+        }
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.node_state(1)),
+            Some(CanNodeState::BusOff)
+        );
+    }
+
+    #[test]
+    fn failure_of_another_bus_does_not_affect_the_bus() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        test_bed.fail(FailureType::CanBusCorruption(2));
+        test_bed.command(|a| a.send_message(message_of(1, 20.0)));
+        test_bed.run();
+
+        assert_about_eq!(transmitted_message(&mut test_bed), 20.0);
+        assert_eq!(
+            test_bed.query(|a| a.can_bus.transmit_error_counter(1)),
+            Some(0)
+        );
+    }
 }
@@ -26,6 +26,7 @@ mod random;
 pub use random::*;
 
 pub mod arinc429;
+pub mod arinc429_bus;
 pub mod arinc825;
 pub mod can_bus;
 pub mod power_supply_relay;
@@ -148,7 +149,7 @@ pub enum LgciuId {
     Lgciu2 = 1,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ProximityDetectorId {
     UplockGearNose1,
     UplockGearNose2,
@@ -241,6 +242,17 @@ pub trait AdirsMeasurementOutputs {
     fn true_heading(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn vertical_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length>;
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
+}
+
+/// The position and ground speed computed by a GPS receiver, together with their accuracy.
+pub trait GpsReceiverOutputs {
+    fn latitude(&self) -> Arinc429Word<Angle>;
+    fn longitude(&self) -> Arinc429Word<Angle>;
+    fn ground_speed(&self) -> Arinc429Word<Velocity>;
+    fn true_track(&self) -> Arinc429Word<Angle>;
+    fn horizontal_figure_of_merit(&self) -> Arinc429Word<Length>;
+    fn horizontal_integrity_limit(&self) -> Arinc429Word<Length>;
 }
 
 pub trait AdirsDiscreteOutputs {
@@ -250,6 +262,23 @@ pub trait AdirsDiscreteOutputs {
     fn low_speed_warning_4_260kts(&self, adiru_number: usize) -> bool;
 }
 
+/// Ice obstructing the probes which feed an air data reference.
+pub trait AirDataProbeIcing {
+    fn pitot_is_iced(&self, adr_number: usize) -> bool;
+    fn angle_of_attack_sensor_is_iced(&self, adr_number: usize) -> bool;
+    fn total_air_temperature_probe_is_iced(&self, adr_number: usize) -> bool;
+}
+
+pub trait HeatedProbes {
+    /// The heating level of the given probe, from 0 (not heated) to 1 (fully heated).
+    fn probe_heating(&self, probe: HeatedProbe) -> Ratio;
+}
+
+pub trait HeatedWindows {
+    /// The heating level of the given window, from 0 (not heated) to 1 (fully heated).
+    fn window_heating(&self, window: HeatedWindow) -> Ratio;
+}
+
 pub enum GearWheel {
     NOSE = 0,
     LEFT = 1,
@@ -279,7 +308,7 @@ impl Display for HydraulicColor {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AirbusEngineDrivenPumpId {
     Edp1a,
     Edp1b,
@@ -309,7 +338,7 @@ impl Display for AirbusEngineDrivenPumpId {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AirbusElectricPumpId {
     GreenA,
     GreenB,
@@ -335,6 +364,38 @@ impl Display for AirbusElectricPumpId {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeatedProbe {
+    Pitot,
+    StaticPorts,
+    AngleOfAttack,
+    TotalAirTemperature,
+}
+impl Display for HeatedProbe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HeatedProbe::Pitot => write!(f, "PITOT"),
+            HeatedProbe::StaticPorts => write!(f, "STATIC"),
+            HeatedProbe::AngleOfAttack => write!(f, "AOA"),
+            HeatedProbe::TotalAirTemperature => write!(f, "TAT"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeatedWindow {
+    Windshield,
+    SideWindow,
+}
+impl Display for HeatedWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HeatedWindow::Windshield => write!(f, "WINDSHIELD"),
+            HeatedWindow::SideWindow => write!(f, "WINDOW"),
+        }
+    }
+}
+
 /// The common types of electrical buses within Airbus aircraft.
 /// These include types such as AC, DC, AC ESS, etc.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -12,6 +12,17 @@ mod wasm {
     static RAND_INIT: Once = Once::new();
     static mut RAND: MaybeUninit<SmallRng> = MaybeUninit::uninit();
 
+    /// Seeds the random numbers, such that a simulation constructed and run afterwards
+    /// is reproducible.
+    pub fn seed_random(seed: u64) {
+        RAND_INIT.call_once(|| {});
+
+        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
+        unsafe {
+            RAND = MaybeUninit::new(SmallRng::seed_from_u64(seed));
+        }
+    }
+
     pub fn random_number() -> u8 {
         // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
         RAND_INIT.call_once(|| unsafe {
@@ -57,24 +68,49 @@ pub use not_wasm::*;
 
 #[cfg(not(any(target_arch = "wasm32", doc)))]
 mod not_wasm {
-    use rand::Rng;
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
     use rand_distr::{Distribution, Normal};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static RAND: RefCell<SmallRng> = RefCell::new(SmallRng::from_entropy());
+    }
+
+    /// Seeds the random numbers of the current thread, such that a simulation constructed
+    /// and run afterwards on this thread is reproducible.
+    pub fn seed_random(seed: u64) {
+        RAND.with(|rand| *rand.borrow_mut() = SmallRng::seed_from_u64(seed));
+    }
 
     pub fn random_number() -> u8 {
-        rand::thread_rng().gen()
+        RAND.with(|rand| rand.borrow_mut().gen())
     }
 
     pub fn random_from_range(from: f64, to: f64) -> f64 {
-        rand::thread_rng().gen_range(from..to)
+        RAND.with(|rand| rand.borrow_mut().gen_range(from..to))
     }
 
     /// Random value from normal distribution. Output limited to -4 / +4 sigma
     pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
         let normal = Normal::new(mean, std_dev).unwrap();
         let limit_offset = 4. * std_dev;
-        normal
-            .sample(&mut rand::thread_rng())
+        RAND.with(|rand| normal.sample(&mut *rand.borrow_mut()))
             .max(mean - limit_offset)
             .min(mean + limit_offset)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn seeded_random_numbers_are_reproducible() {
+            seed_random(7);
+            let first = (random_number(), random_from_range(0., 1.));
+            seed_random(7);
+            let second = (random_number(), random_from_range(0., 1.));
+
+            assert_eq!(first, second);
+        }
+    }
 }
@@ -0,0 +1,597 @@
+use super::{
+    Aircraft, InitContext, Simulation, SimulationElement, SimulationElementVisitor,
+    SimulatorReader, SimulatorReaderWriter, SimulatorWriter, StartState, VariableIdentifier,
+    VariableRegistry,
+};
+use fxhash::FxHashMap;
+use std::{any::type_name, collections::BTreeSet, fmt::Write};
+
+/// How the value of a variable is encoded into the `f64` exchanged with the simulator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VariableEncoding {
+    #[default]
+    Plain,
+    Arinc429,
+    Arinc825,
+}
+
+impl VariableEncoding {
+    fn name(&self) -> &'static str {
+        match self {
+            VariableEncoding::Plain => "plain",
+            VariableEncoding::Arinc429 => "arinc429",
+            VariableEncoding::Arinc825 => "arinc825",
+        }
+    }
+}
+
+/// Metadata describing a variable, provided when registering it through
+/// [`InitContext::get_identifier_with_metadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VariableMetadata {
+    unit: Option<String>,
+    description: Option<String>,
+    encoding: VariableEncoding,
+    is_written_externally: bool,
+}
+
+impl VariableMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn unit(mut self, unit: &str) -> Self {
+        self.unit = Some(unit.to_owned());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    pub fn arinc429(mut self) -> Self {
+        self.encoding = VariableEncoding::Arinc429;
+        self
+    }
+
+    pub fn arinc825(mut self) -> Self {
+        self.encoding = VariableEncoding::Arinc825;
+        self
+    }
+
+    /// Indicates the variable is written by the simulator, the instruments or
+    /// another host, rather than by the systems simulation.
+    pub fn written_externally(mut self) -> Self {
+        self.is_written_externally = true;
+        self
+    }
+
+    fn merge(&mut self, other: VariableMetadata) {
+        self.unit = other.unit.or_else(|| self.unit.take());
+        self.description = other.description.or_else(|| self.description.take());
+        if other.encoding != VariableEncoding::Plain {
+            self.encoding = other.encoding;
+        }
+        self.is_written_externally |= other.is_written_externally;
+    }
+}
+
+/// The direction in which the systems simulation exchanges a variable with the simulator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VariableDirection {
+    Unused,
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl VariableDirection {
+    fn name(&self) -> &'static str {
+        match self {
+            VariableDirection::Unused => "unused",
+            VariableDirection::Read => "read",
+            VariableDirection::Write => "write",
+            VariableDirection::ReadWrite => "read_write",
+        }
+    }
+}
+
+pub struct CatalogueEntry {
+    name: String,
+    metadata: VariableMetadata,
+    readers: BTreeSet<&'static str>,
+    writers: BTreeSet<&'static str>,
+}
+
+impl CatalogueEntry {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            metadata: Default::default(),
+            readers: BTreeSet::new(),
+            writers: BTreeSet::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn unit(&self) -> Option<&str> {
+        self.metadata.unit.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.metadata.description.as_deref()
+    }
+
+    pub fn encoding(&self) -> VariableEncoding {
+        self.metadata.encoding
+    }
+
+    pub fn is_written_externally(&self) -> bool {
+        self.metadata.is_written_externally
+    }
+
+    /// The type names of the elements reading the variable.
+    pub fn readers(&self) -> impl Iterator<Item = &str> {
+        self.readers.iter().copied()
+    }
+
+    /// The type names of the elements writing the variable.
+    pub fn writers(&self) -> impl Iterator<Item = &str> {
+        self.writers.iter().copied()
+    }
+
+    pub fn direction(&self) -> VariableDirection {
+        match (self.readers.is_empty(), self.writers.is_empty()) {
+            (true, true) => VariableDirection::Unused,
+            (false, true) => VariableDirection::Read,
+            (true, false) => VariableDirection::Write,
+            (false, false) => VariableDirection::ReadWrite,
+        }
+    }
+
+    fn is_read_but_never_written(&self) -> bool {
+        self.direction() == VariableDirection::Read && !self.is_written_externally()
+    }
+}
+
+/// Catalogues all variables of an aircraft: the metadata provided upon registration, and
+/// which elements read and write the variable.
+///
+/// Readers and writers are found by having every element read and write once. Variables
+/// which an element only accesses under certain conditions might therefore be missed.
+#[derive(Default)]
+pub struct VariableCatalogue {
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    next_identifier: VariableIdentifier,
+    entries: Vec<CatalogueEntry>,
+    values: Vec<f64>,
+}
+
+impl VariableCatalogue {
+    /// Creates the catalogue of the aircraft constructed by the given function.
+    pub fn of<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        aircraft_ctor_fn: U,
+    ) -> Self {
+        let mut catalogue = Self::default();
+        let mut simulation = Simulation::new(start_state, aircraft_ctor_fn, &mut catalogue);
+
+        let mut recorder = UsageRecorder::new(&mut catalogue, type_name::<super::UpdateContext>());
+        let delta = simulation.update_context.delta();
+        let simulation_time = simulation.update_context.simulation_time();
+        simulation.update_context.update(
+            &mut SimulatorReader::new(&mut recorder),
+            delta,
+            simulation_time,
+        );
+
+        simulation
+            .aircraft
+            .accept(&mut UsageRecordingVisitor::new(&mut catalogue));
+
+        catalogue
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &CatalogueEntry> {
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        entries.into_iter()
+    }
+
+    pub fn entry(&self, name: &str) -> Option<&CatalogueEntry> {
+        self.name_to_identifier
+            .get(name)
+            .map(|identifier| &self.entries[identifier.identifier_index()])
+    }
+
+    /// Variables which are read by the systems simulation, but neither written by it nor
+    /// declared as written externally.
+    pub fn read_but_never_written(&self) -> impl Iterator<Item = &CatalogueEntry> {
+        self.entries()
+            .filter(|entry| entry.is_read_but_never_written())
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[\n");
+        for (index, entry) in self.entries().enumerate() {
+            if index > 0 {
+                json.push_str(",\n");
+            }
+
+            let _ = write!(
+                json,
+                "  {{\"name\": {}, \"direction\": {}, \"unit\": {}, \"description\": {}, \
+                \"encoding\": {}, \"written_externally\": {}, \"readers\": {}, \"writers\": {}}}",
+                json_string(entry.name()),
+                json_string(entry.direction().name()),
+                entry.unit().map_or("null".into(), json_string),
+                entry.description().map_or("null".into(), json_string),
+                json_string(entry.encoding().name()),
+                entry.is_written_externally(),
+                json_array(entry.readers()),
+                json_array(entry.writers()),
+            );
+        }
+        json.push_str("\n]\n");
+
+        json
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from(
+            "| Name | Direction | Unit | Encoding | Readers | Writers | Description |\n\
+            |------|-----------|------|----------|---------|---------|-------------|\n",
+        );
+        for entry in self.entries() {
+            let _ = writeln!(
+                markdown,
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                markdown_cell(entry.name()),
+                entry.direction().name(),
+                markdown_cell(entry.unit().unwrap_or_default()),
+                entry.encoding().name(),
+                markdown_cell(&entry.readers().collect::<Vec<_>>().join(", ")),
+                markdown_cell(&entry.writers().collect::<Vec<_>>().join(", ")),
+                markdown_cell(entry.description().unwrap_or_default()),
+            );
+        }
+
+        markdown
+    }
+}
+
+impl VariableRegistry for VariableCatalogue {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        match self.name_to_identifier.get(&name) {
+            Some(identifier) => *identifier,
+            None => {
+                let identifier = self.next_identifier;
+                self.next_identifier = identifier.next();
+                self.name_to_identifier.insert(name.clone(), identifier);
+                self.entries.push(CatalogueEntry::new(name));
+                self.values.push(0.);
+
+                identifier
+            }
+        }
+    }
+
+    fn get_with_metadata(
+        &mut self,
+        name: String,
+        metadata: VariableMetadata,
+    ) -> VariableIdentifier {
+        let identifier = self.get(name);
+        self.entries[identifier.identifier_index()]
+            .metadata
+            .merge(metadata);
+
+        identifier
+    }
+}
+
+/// Records the variables read and written by a single element.
+struct UsageRecorder<'a> {
+    catalogue: &'a mut VariableCatalogue,
+    owner: &'static str,
+}
+
+impl<'a> UsageRecorder<'a> {
+    fn new(catalogue: &'a mut VariableCatalogue, owner: &'static str) -> Self {
+        Self { catalogue, owner }
+    }
+}
+
+impl<'a> SimulatorReaderWriter for UsageRecorder<'a> {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        let index = identifier.identifier_index();
+        self.catalogue.entries[index].readers.insert(self.owner);
+
+        self.catalogue.values[index]
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        let index = identifier.identifier_index();
+        self.catalogue.entries[index].writers.insert(self.owner);
+        self.catalogue.values[index] = value;
+    }
+
+    fn read_encoded(&mut self, identifier: &VariableIdentifier, encoding: VariableEncoding) -> f64 {
+        self.catalogue.entries[identifier.identifier_index()]
+            .metadata
+            .encoding = encoding;
+
+        self.read(identifier)
+    }
+
+    fn write_encoded(
+        &mut self,
+        identifier: &VariableIdentifier,
+        value: f64,
+        encoding: VariableEncoding,
+    ) {
+        self.catalogue.entries[identifier.identifier_index()]
+            .metadata
+            .encoding = encoding;
+
+        self.write(identifier, value)
+    }
+}
+
+struct UsageRecordingVisitor<'a> {
+    catalogue: &'a mut VariableCatalogue,
+}
+
+impl<'a> UsageRecordingVisitor<'a> {
+    fn new(catalogue: &'a mut VariableCatalogue) -> Self {
+        Self { catalogue }
+    }
+}
+
+impl<'a> SimulationElementVisitor for UsageRecordingVisitor<'a> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        let mut recorder = UsageRecorder::new(self.catalogue, type_name::<T>());
+        visited.read(&mut SimulatorReader::new(&mut recorder));
+        visited.write(&mut SimulatorWriter::new(&mut recorder));
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+
+    json
+}
+
+fn json_array<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    format!(
+        "[{}]",
+        values.map(json_string).collect::<Vec<_>>().join(", ")
+    )
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        shared::arinc429::SignStatus,
+        simulation::{Read, UpdateContext, Write},
+    };
+
+    struct Sensor {
+        pressure_id: VariableIdentifier,
+        pressure_word_id: VariableIdentifier,
+        pressure: f64,
+    }
+    impl Sensor {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                pressure_id: context.get_identifier_with_metadata(
+                    "PRESSURE".into(),
+                    VariableMetadata::new()
+                        .unit("psi")
+                        .description("Pressure | measured"),
+                ),
+                pressure_word_id: context.get_identifier("PRESSURE_WORD".into()),
+                pressure: 0.,
+            }
+        }
+    }
+    impl SimulationElement for Sensor {
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.pressure_id, self.pressure);
+            writer.write_arinc429(
+                &self.pressure_word_id,
+                self.pressure,
+                SignStatus::NormalOperation,
+            );
+        }
+    }
+
+    struct Computer {
+        pressure_id: VariableIdentifier,
+        selector_id: VariableIdentifier,
+        external_id: VariableIdentifier,
+        word_id: VariableIdentifier,
+        pressure: f64,
+        selector: f64,
+        external: f64,
+    }
+    impl Computer {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                pressure_id: context.get_identifier("PRESSURE".into()),
+                selector_id: context.get_identifier("SELECTOR".into()),
+                external_id: context.get_identifier_with_metadata(
+                    "EXTERNAL".into(),
+                    VariableMetadata::new().written_externally(),
+                ),
+                word_id: context.get_identifier_with_metadata(
+                    "WORD".into(),
+                    VariableMetadata::new().arinc429(),
+                ),
+                pressure: 0.,
+                selector: 0.,
+                external: 0.,
+            }
+        }
+    }
+    impl SimulationElement for Computer {
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.pressure = reader.read(&self.pressure_id);
+            self.selector = reader.read(&self.selector_id);
+            self.external = reader.read(&self.external_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.word_id, self.pressure + self.selector + self.external);
+        }
+    }
+
+    struct TestAircraft {
+        sensor: Sensor,
+        computer: Computer,
+        _unused_id: VariableIdentifier,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                sensor: Sensor::new(context),
+                computer: Computer::new(context),
+                _unused_id: context.get_identifier("UNUSED".into()),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {}
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.sensor.accept(visitor);
+            self.computer.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn catalogue() -> VariableCatalogue {
+        VariableCatalogue::of(StartState::Cruise, TestAircraft::new)
+    }
+
+    #[test]
+    fn records_readers_and_writers() {
+        let catalogue = catalogue();
+        let pressure = catalogue.entry("PRESSURE").unwrap();
+
+        assert_eq!(pressure.direction(), VariableDirection::ReadWrite);
+        assert_eq!(
+            pressure.readers().collect::<Vec<_>>(),
+            vec![type_name::<Computer>()]
+        );
+        assert_eq!(
+            pressure.writers().collect::<Vec<_>>(),
+            vec![type_name::<Sensor>()]
+        );
+        assert_eq!(
+            catalogue.entry("WORD").unwrap().direction(),
+            VariableDirection::Write
+        );
+        assert_eq!(
+            catalogue.entry("UNUSED").unwrap().direction(),
+            VariableDirection::Unused
+        );
+    }
+
+    #[test]
+    fn keeps_metadata_provided_upon_registration() {
+        let catalogue = catalogue();
+        let pressure = catalogue.entry("PRESSURE").unwrap();
+
+        assert_eq!(pressure.unit(), Some("psi"));
+        assert_eq!(pressure.description(), Some("Pressure | measured"));
+        assert_eq!(
+            catalogue.entry("WORD").unwrap().encoding(),
+            VariableEncoding::Arinc429
+        );
+    }
+
+    #[test]
+    fn tags_the_encoding_of_arinc429_words_when_written() {
+        assert_eq!(
+            catalogue().entry("PRESSURE_WORD").unwrap().encoding(),
+            VariableEncoding::Arinc429
+        );
+    }
+
+    #[test]
+    fn records_variables_read_by_the_update_context() {
+        let catalogue = catalogue();
+        let temperature = catalogue
+            .entry(UpdateContext::AMBIENT_TEMPERATURE_KEY)
+            .unwrap();
+
+        assert_eq!(temperature.direction(), VariableDirection::Read);
+        assert!(temperature.is_written_externally());
+    }
+
+    #[test]
+    fn detects_variables_read_but_never_written() {
+        assert_eq!(
+            catalogue()
+                .read_but_never_written()
+                .map(|entry| entry.name())
+                .collect::<Vec<_>>(),
+            vec!["SELECTOR"]
+        );
+    }
+
+    #[test]
+    fn exports_json() {
+        let json = catalogue().to_json();
+
+        assert!(json.starts_with("[\n"));
+        assert!(json.contains(
+            "{\"name\": \"SELECTOR\", \"direction\": \"read\", \"unit\": null, \
+            \"description\": null, \"encoding\": \"plain\", \"written_externally\": false, \
+            \"readers\": [\"systems::simulation::catalogue::tests::Computer\"], \"writers\": []}"
+        ));
+        assert!(json.contains("\"description\": \"Pressure | measured\""));
+    }
+
+    #[test]
+    fn exports_markdown() {
+        let markdown = catalogue().to_markdown();
+
+        assert!(markdown.starts_with("| Name | Direction |"));
+        assert!(markdown.contains(
+            "| WORD | write |  | arinc429 |  | systems::simulation::catalogue::tests::Computer |  |"
+        ));
+        assert!(markdown.contains("Pressure \\| measured"));
+    }
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(
+            json_string("a \"b\"\\\n\t"),
+            "\"a \\\"b\\\"\\\\\\n\\u0009\""
+        );
+    }
+}
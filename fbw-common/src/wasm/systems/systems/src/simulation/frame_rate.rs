@@ -0,0 +1,703 @@
+//! Verifies that systems behave the same regardless of the simulator frame rate.
+//!
+//! A [`FrameRateHarness`] runs one scripted scenario once for every [`FrameSchedule`], records
+//! the configured outputs after every frame and compares them against a reference schedule.
+//! Outputs which deviate more than their tolerance are reported per system.
+use std::{fmt, ops::Range, time::Duration};
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+use super::test::TestBed;
+use crate::shared::seed_random;
+
+/// The sequence of frame times at which a scenario is simulated.
+pub struct FrameSchedule {
+    name: String,
+    kind: FrameScheduleKind,
+}
+
+enum FrameScheduleKind {
+    Fixed(Duration),
+    Spikes {
+        frame_time: Duration,
+        spike: Duration,
+        every: usize,
+    },
+    Jitter {
+        frame_time: Range<Duration>,
+        seed: u64,
+    },
+}
+
+impl FrameSchedule {
+    pub fn fixed_rate(fps: u32) -> Self {
+        Self {
+            name: format!("{} FPS", fps),
+            kind: FrameScheduleKind::Fixed(Duration::from_secs(1) / fps),
+        }
+    }
+
+    /// A fixed frame rate where every nth frame takes the spike duration instead.
+    pub fn with_spikes(fps: u32, spike: Duration, every: usize) -> Self {
+        Self {
+            name: format!("{} FPS with {:?} spike every {} frames", fps, spike, every),
+            kind: FrameScheduleKind::Spikes {
+                frame_time: Duration::from_secs(1) / fps,
+                spike,
+                every: every.max(1),
+            },
+        }
+    }
+
+    /// Random frame times within the given range, deterministic for a seed.
+    pub fn jittered(frame_time: Range<Duration>, seed: u64) -> Self {
+        Self {
+            name: format!("{:?} to {:?} jitter", frame_time.start, frame_time.end),
+            kind: FrameScheduleKind::Jitter { frame_time, seed },
+        }
+    }
+
+    /// The schedules users commonly run at: 20 and 30 FPS, frame spikes and
+    /// 12 to 200 ms jitter (83 to 5 fps).
+    pub fn typical() -> Vec<Self> {
+        vec![
+            Self::fixed_rate(20),
+            Self::fixed_rate(30),
+            Self::with_spikes(30, Duration::from_millis(500), 90),
+            Self::jittered(Duration::from_millis(12)..Duration::from_millis(200), 0),
+        ]
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn frame_times(&self) -> Box<dyn Iterator<Item = Duration>> {
+        match self.kind {
+            FrameScheduleKind::Fixed(frame_time) => Box::new(std::iter::repeat(frame_time)),
+            FrameScheduleKind::Spikes {
+                frame_time,
+                spike,
+                every,
+            } => Box::new((1..).map(move |frame| {
+                if frame % every == 0 {
+                    spike
+                } else {
+                    frame_time
+                }
+            })),
+            FrameScheduleKind::Jitter {
+                ref frame_time,
+                seed,
+            } => {
+                let frame_time = frame_time.clone();
+                let mut rng = SmallRng::seed_from_u64(seed);
+                Box::new(std::iter::repeat_with(move || {
+                    if frame_time.start < frame_time.end {
+                        rng.gen_range(frame_time.clone())
+                    } else {
+                        frame_time.start
+                    }
+                }))
+            }
+        }
+    }
+}
+
+struct Probe<T> {
+    system: &'static str,
+    output: &'static str,
+    tolerance: f64,
+    averaging_window: Duration,
+    read: Box<dyn Fn(&mut T) -> f64>,
+}
+
+/// Output values recorded after every frame of a single run.
+struct Recording {
+    times: Vec<Duration>,
+    values: Vec<Vec<f64>>,
+}
+impl Recording {
+    fn new(probes: usize) -> Self {
+        Self {
+            times: vec![],
+            values: vec![vec![]; probes],
+        }
+    }
+
+    fn record<T>(&mut self, time: Duration, probes: &[Probe<T>], test_bed: &mut T) {
+        self.times.push(time);
+        for (values, probe) in self.values.iter_mut().zip(probes) {
+            values.push((probe.read)(test_bed));
+        }
+    }
+
+    /// Linearly interpolates the value of the probe between the surrounding frames.
+    fn value_at(&self, probe: usize, time: Duration) -> f64 {
+        let values = &self.values[probe];
+        let next = self.times.partition_point(|t| *t < time);
+        if next == 0 {
+            values[0]
+        } else if next >= self.times.len() {
+            values[self.times.len() - 1]
+        } else {
+            let (t0, t1) = (self.times[next - 1], self.times[next]);
+            let ratio = (time - t0).as_secs_f64() / (t1 - t0).as_secs_f64();
+
+            values[next - 1] + (values[next] - values[next - 1]) * ratio
+        }
+    }
+
+    /// The time weighted mean of the interpolated value of the probe over the given window
+    /// ending at the given time.
+    fn mean_at(&self, probe: usize, time: Duration, window: Duration) -> f64 {
+        let start = time.saturating_sub(window);
+        if start == time {
+            return self.value_at(probe, time);
+        }
+
+        let first = self.times.partition_point(|t| *t <= start);
+        let last = self.times.partition_point(|t| *t < time);
+        let points = std::iter::once(start)
+            .chain(self.times[first..last].iter().copied())
+            .chain(std::iter::once(time));
+
+        let mut integral = 0.;
+        let mut previous = (start, self.value_at(probe, start));
+        for t in points.skip(1) {
+            let value = self.value_at(probe, t);
+            integral += (t - previous.0).as_secs_f64() * (value + previous.1) / 2.;
+            previous = (t, value);
+        }
+
+        integral / (time - start).as_secs_f64()
+    }
+}
+
+/// An output which deviated more than its tolerance from the reference schedule.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Divergence {
+    schedule: String,
+    system: &'static str,
+    output: &'static str,
+    time: Duration,
+    reference_value: f64,
+    value: f64,
+    tolerance: f64,
+}
+impl Divergence {
+    pub fn schedule(&self) -> &str {
+        &self.schedule
+    }
+
+    pub fn system(&self) -> &'static str {
+        self.system
+    }
+
+    pub fn output(&self) -> &'static str {
+        self.output
+    }
+
+    /// The time at which the deviation was the largest.
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn deviation(&self) -> f64 {
+        (self.value - self.reference_value).abs()
+    }
+}
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} at {}: {} instead of {} at {:.1}s (deviation {} > tolerance {})",
+            self.system,
+            self.output,
+            self.schedule,
+            self.value,
+            self.reference_value,
+            self.time.as_secs_f64(),
+            self.deviation(),
+            self.tolerance
+        )
+    }
+}
+
+pub struct FrameRateReport {
+    reference: String,
+    divergences: Vec<Divergence>,
+}
+impl FrameRateReport {
+    pub fn is_frame_rate_independent(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    pub fn divergences(&self) -> &[Divergence] {
+        &self.divergences
+    }
+
+    pub fn diverging_systems(&self) -> Vec<&'static str> {
+        let mut systems: Vec<_> = self.divergences.iter().map(|d| d.system).collect();
+        systems.sort_unstable();
+        systems.dedup();
+
+        systems
+    }
+}
+impl fmt::Display for FrameRateReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_frame_rate_independent() {
+            return writeln!(f, "All outputs match the {} reference.", self.reference);
+        }
+
+        writeln!(
+            f,
+            "Systems diverging from the {} reference: {}",
+            self.reference,
+            self.diverging_systems().join(", ")
+        )?;
+        for divergence in &self.divergences {
+            writeln!(f, "    {}", divergence)?;
+        }
+
+        Ok(())
+    }
+}
+
+type Action<T> = Box<dyn Fn(T) -> T>;
+
+/// Runs a scripted scenario at several frame schedules and compares the outputs.
+///
+/// Outputs are compared at every sample interval, interpolating between the frames of each
+/// run. Frames are only split at the times of scripted actions, such that all schedules
+/// apply the actions at the same simulated time. Random numbers are seeded identically
+/// before every run, such that randomised initial conditions are the same for all schedules.
+pub struct FrameRateHarness<T> {
+    duration: Duration,
+    test_bed_ctor: Box<dyn Fn() -> T>,
+    sample_interval: Duration,
+    ignored_until: Duration,
+    actions: Vec<(Duration, Action<T>)>,
+    probes: Vec<Probe<T>>,
+}
+impl<T: TestBed> FrameRateHarness<T> {
+    const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+    const RANDOM_SEED: u64 = 0;
+
+    /// Creates a harness running the scenario for the given duration on test beds
+    /// constructed by the given function.
+    pub fn new<U: Fn() -> T + 'static>(duration: Duration, test_bed_ctor: U) -> Self {
+        Self {
+            duration,
+            test_bed_ctor: Box::new(test_bed_ctor),
+            sample_interval: Self::DEFAULT_SAMPLE_INTERVAL,
+            ignored_until: Duration::ZERO,
+            actions: vec![],
+            probes: vec![],
+        }
+    }
+
+    pub fn sample_every(mut self, sample_interval: Duration) -> Self {
+        self.sample_interval = sample_interval;
+        self
+    }
+
+    /// Only compares outputs from the given time on, e.g. to skip start up transients
+    /// which depend on randomised initial conditions.
+    pub fn ignore_until(mut self, time: Duration) -> Self {
+        self.ignored_until = time;
+        self
+    }
+
+    /// Applies the action to the test bed once the simulated time reaches the given time.
+    pub fn at<U: Fn(T) -> T + 'static>(mut self, time: Duration, action: U) -> Self {
+        let idx = self.actions.partition_point(|(at, _)| *at <= time);
+        self.actions.insert(idx, (time, Box::new(action)));
+        self
+    }
+
+    /// Compares the output read by the given function, allowing for the given absolute tolerance.
+    pub fn probe<U: Fn(&mut T) -> f64 + 'static>(
+        self,
+        system: &'static str,
+        output: &'static str,
+        tolerance: f64,
+        read: U,
+    ) -> Self {
+        self.probe_averaged(system, output, tolerance, Duration::ZERO, read)
+    }
+
+    /// Compares the mean of the output read by the given function over the given window
+    /// preceding every sample, allowing for the given absolute tolerance. Use this for outputs
+    /// which oscillate, such as pressures of cycling pumps, where the phase of the oscillation
+    /// differs between frame rates but the mean should not.
+    pub fn probe_averaged<U: Fn(&mut T) -> f64 + 'static>(
+        mut self,
+        system: &'static str,
+        output: &'static str,
+        tolerance: f64,
+        averaging_window: Duration,
+        read: U,
+    ) -> Self {
+        self.probes.push(Probe {
+            system,
+            output,
+            tolerance,
+            averaging_window,
+            read: Box::new(read),
+        });
+        self
+    }
+
+    /// Runs the scenario on a new test bed for every schedule and reports, per output,
+    /// the largest deviation from the reference schedule exceeding the tolerance.
+    pub fn compare(
+        &self,
+        reference: &FrameSchedule,
+        schedules: &[FrameSchedule],
+    ) -> FrameRateReport {
+        let reference_recording = self.run(reference);
+
+        let mut divergences = vec![];
+        for schedule in schedules {
+            let recording = self.run(schedule);
+
+            for (idx, probe) in self.probes.iter().enumerate() {
+                let worst = self
+                    .sample_times()
+                    .map(|time| {
+                        (
+                            time,
+                            reference_recording.mean_at(idx, time, probe.averaging_window),
+                            recording.mean_at(idx, time, probe.averaging_window),
+                        )
+                    })
+                    .filter(|(_, reference_value, value)| {
+                        (value - reference_value).abs() > probe.tolerance
+                            || value.is_nan() != reference_value.is_nan()
+                    })
+                    .max_by(|(_, r1, v1), (_, r2, v2)| (v1 - r1).abs().total_cmp(&(v2 - r2).abs()));
+
+                if let Some((time, reference_value, value)) = worst {
+                    divergences.push(Divergence {
+                        schedule: schedule.name().to_owned(),
+                        system: probe.system,
+                        output: probe.output,
+                        time,
+                        reference_value,
+                        value,
+                        tolerance: probe.tolerance,
+                    });
+                }
+            }
+        }
+
+        FrameRateReport {
+            reference: reference.name().to_owned(),
+            divergences,
+        }
+    }
+
+    /// Panics with the report when any output diverges from the reference schedule.
+    pub fn assert_frame_rate_independent(
+        &self,
+        reference: &FrameSchedule,
+        schedules: &[FrameSchedule],
+    ) {
+        let report = self.compare(reference, schedules);
+        assert!(report.is_frame_rate_independent(), "{}", report);
+    }
+
+    fn sample_times(&self) -> impl Iterator<Item = Duration> + '_ {
+        (0..)
+            .map(move |n| self.sample_interval * n)
+            .take_while(move |time| *time <= self.duration)
+            .filter(move |time| *time >= self.ignored_until)
+    }
+
+    fn run(&self, schedule: &FrameSchedule) -> Recording {
+        seed_random(Self::RANDOM_SEED);
+        let mut test_bed = (self.test_bed_ctor)();
+        let mut recording = Recording::new(self.probes.len());
+        recording.record(Duration::ZERO, &self.probes, &mut test_bed);
+
+        let mut frame_times = schedule.frame_times();
+        let mut actions = self.actions.iter().peekable();
+        let mut remaining_frame_time = None;
+        let mut time = Duration::ZERO;
+        while time < self.duration {
+            while let Some((_, action)) = actions.next_if(|(at, _)| *at <= time) {
+                test_bed = action(test_bed);
+            }
+
+            let mut delta = remaining_frame_time
+                .take()
+                .or_else(|| frame_times.next())
+                .unwrap_or_default()
+                .min(self.duration - time);
+            if let Some((at, _)) = actions.peek() {
+                if time + delta > *at {
+                    remaining_frame_time = Some(time + delta - *at);
+                    delta = *at - time;
+                }
+            }
+
+            test_bed.run_with_delta(delta);
+            time += delta;
+            recording.record(time, &self.probes, &mut test_bed);
+        }
+
+        recording
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::SimulationTestBed;
+    use crate::simulation::{Aircraft, InitContext, SimulationElement, UpdateContext};
+
+    #[derive(Default)]
+    struct TestAircraftWithIntegrators {
+        rate: f64,
+        time_based: f64,
+        frame_based: f64,
+        frames: usize,
+    }
+    impl TestAircraftWithIntegrators {
+        fn set_rate(&mut self, rate: f64) {
+            self.rate = rate;
+        }
+    }
+    impl Aircraft for TestAircraftWithIntegrators {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.time_based += self.rate * context.delta_as_secs_f64();
+            self.frame_based += self.rate * 0.05;
+            self.frames += 1;
+        }
+    }
+    impl SimulationElement for TestAircraftWithIntegrators {}
+
+    type IntegratorsTestBed = SimulationTestBed<TestAircraftWithIntegrators>;
+
+    fn test_bed() -> IntegratorsTestBed {
+        SimulationTestBed::new(|_: &mut InitContext| TestAircraftWithIntegrators::default())
+    }
+
+    fn harness() -> FrameRateHarness<IntegratorsTestBed> {
+        FrameRateHarness::new(Duration::from_secs(10), test_bed)
+            .at(Duration::from_millis(2500), |mut test_bed| {
+                test_bed.command(|a| a.set_rate(1.));
+                test_bed
+            })
+            .at(Duration::from_millis(7250), |mut test_bed| {
+                test_bed.command(|a| a.set_rate(-2.));
+                test_bed
+            })
+            .probe("TIME", "value", 1e-6, |test_bed| {
+                test_bed.query(|a| a.time_based)
+            })
+    }
+
+    #[test]
+    fn fixed_rate_schedule_gives_frame_time() {
+        let frames: Vec<_> = FrameSchedule::fixed_rate(20)
+            .frame_times()
+            .take(2)
+            .collect();
+
+        assert_eq!(frames, vec![Duration::from_millis(50); 2]);
+    }
+
+    #[test]
+    fn spike_schedule_gives_spike_every_nth_frame() {
+        let frames: Vec<_> = FrameSchedule::with_spikes(20, Duration::from_millis(500), 3)
+            .frame_times()
+            .take(6)
+            .collect();
+
+        assert_eq!(frames[0], Duration::from_millis(50));
+        assert_eq!(frames[2], Duration::from_millis(500));
+        assert_eq!(frames[5], Duration::from_millis(500));
+    }
+
+    #[test]
+    fn jittered_schedule_is_deterministic_and_within_range() {
+        let range = Duration::from_millis(12)..Duration::from_millis(200);
+        let first: Vec<_> = FrameSchedule::jittered(range.clone(), 1)
+            .frame_times()
+            .take(100)
+            .collect();
+        let second: Vec<_> = FrameSchedule::jittered(range.clone(), 1)
+            .frame_times()
+            .take(100)
+            .collect();
+
+        assert_eq!(first, second);
+        assert!(first.iter().all(|frame| range.contains(frame)));
+    }
+
+    #[test]
+    fn recording_interpolates_between_frames() {
+        let mut recording = Recording::new(1);
+        recording.times = vec![Duration::ZERO, Duration::from_secs(2)];
+        recording.values = vec![vec![0., 10.]];
+
+        assert_eq!(recording.value_at(0, Duration::from_secs(1)), 5.);
+        assert_eq!(recording.value_at(0, Duration::from_secs(3)), 10.);
+    }
+
+    #[test]
+    fn recording_averages_over_window() {
+        let mut recording = Recording::new(1);
+        recording.times = vec![
+            Duration::ZERO,
+            Duration::from_secs(1),
+            Duration::from_secs(2),
+            Duration::from_secs(4),
+        ];
+        recording.values = vec![vec![0., 10., 0., 0.]];
+
+        assert_eq!(
+            recording.mean_at(0, Duration::from_secs(2), Duration::from_secs(2)),
+            5.
+        );
+        assert_eq!(
+            recording.mean_at(0, Duration::from_secs(4), Duration::from_secs(4)),
+            2.5
+        );
+        assert_eq!(
+            recording.mean_at(0, Duration::from_millis(500), Duration::ZERO),
+            5.
+        );
+    }
+
+    #[test]
+    fn averaged_probe_ignores_oscillation_phase() {
+        let oscillating_harness = || {
+            FrameRateHarness::new(Duration::from_secs(10), test_bed)
+                .at(Duration::ZERO, |mut test_bed| {
+                    test_bed.command(|a| a.set_rate(1.));
+                    test_bed
+                })
+                .probe_averaged(
+                    "OSCILLATOR",
+                    "value",
+                    0.1,
+                    Duration::from_secs(2),
+                    |test_bed| test_bed.query(|a| if a.frames % 2 == 0 { 1. } else { -1. }),
+                )
+        };
+
+        let report = oscillating_harness().compare(
+            &FrameSchedule::fixed_rate(20),
+            &[FrameSchedule::fixed_rate(30)],
+        );
+
+        assert!(report.is_frame_rate_independent(), "{}", report);
+    }
+
+    #[test]
+    fn actions_are_applied_at_the_same_time_for_every_schedule() {
+        let harness = harness();
+
+        for schedule in FrameSchedule::typical() {
+            let recording = harness.run(&schedule);
+
+            // 4.75s at rate 1 and 2.75s at rate -2.
+            assert!((recording.value_at(0, Duration::from_secs(10)) + 0.75).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn time_based_system_is_frame_rate_independent() {
+        harness().assert_frame_rate_independent(
+            &FrameSchedule::fixed_rate(60),
+            &FrameSchedule::typical(),
+        );
+    }
+
+    #[test]
+    fn frame_based_system_is_reported_as_diverging() {
+        let report = harness()
+            .probe("FRAME", "value", 0.1, |test_bed| {
+                test_bed.query(|a| a.frame_based)
+            })
+            .compare(&FrameSchedule::fixed_rate(60), &FrameSchedule::typical());
+
+        assert!(!report.is_frame_rate_independent());
+        assert_eq!(report.diverging_systems(), vec!["FRAME"]);
+        assert_eq!(report.divergences().len(), FrameSchedule::typical().len());
+        assert!(report.to_string().contains("FRAME value at 20 FPS"));
+    }
+
+    #[test]
+    fn divergence_reports_largest_deviation() {
+        let report = harness()
+            .probe("FRAME", "value", 0.1, |test_bed| {
+                test_bed.query(|a| a.frame_based)
+            })
+            .compare(
+                &FrameSchedule::fixed_rate(20),
+                &[FrameSchedule::fixed_rate(40)],
+            );
+
+        // At 20 FPS the frame based value is correct, at 40 FPS it runs twice as fast
+        // and deviates most at the moment the rate changes sign.
+        let divergence = &report.divergences()[0];
+        assert_eq!(divergence.time(), Duration::from_secs(7));
+        assert!((divergence.deviation() - 4.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn divergence_before_ignored_time_is_not_reported() {
+        let transient_harness = || {
+            FrameRateHarness::new(Duration::from_secs(10), test_bed)
+                .at(Duration::ZERO, |mut test_bed| {
+                    test_bed.command(|a| a.set_rate(1.));
+                    test_bed
+                })
+                .at(Duration::from_millis(2500), |mut test_bed| {
+                    test_bed.command(|a| a.set_rate(0.));
+                    test_bed
+                })
+                .probe("TRANSIENT", "value", 0.1, |test_bed| {
+                    test_bed.query(|a| if a.rate == 0. { 0. } else { a.frame_based })
+                })
+        };
+
+        let report = transient_harness().compare(
+            &FrameSchedule::fixed_rate(20),
+            &[FrameSchedule::fixed_rate(40)],
+        );
+        assert!(!report.is_frame_rate_independent());
+
+        let report = transient_harness()
+            .ignore_until(Duration::from_secs(3))
+            .compare(
+                &FrameSchedule::fixed_rate(20),
+                &[FrameSchedule::fixed_rate(40)],
+            );
+        assert!(report.is_frame_rate_independent());
+    }
+
+    #[test]
+    fn works_with_single_element_test_bed() {
+        FrameRateHarness::new(Duration::from_secs(2), || {
+            SimulationTestBed::from(TestElement(0.)).with_update_after_power_distribution(
+                |element, context| element.0 += context.delta_as_secs_f64(),
+            )
+        })
+        .probe("TIME", "elapsed", 1e-6, |test_bed| {
+            test_bed.query_element(|e| e.0)
+        })
+        .assert_frame_rate_independent(
+            &FrameSchedule::fixed_rate(20),
+            &[FrameSchedule::fixed_rate(30)],
+        );
+    }
+
+    struct TestElement(f64);
+    impl SimulationElement for TestElement {}
+}
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+mod catalogue;
 mod update_context;
 use crate::electrical::{ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
 use crate::shared::{from_bool, ElectricalBusType};
@@ -11,6 +12,7 @@ use crate::{
     shared::{to_bool, ConsumePower, ElectricalBuses, MachNumber, PowerConsumptionReport},
 };
 
+pub use catalogue::*;
 use uom::si::mass_rate::kilogram_per_second;
 use uom::si::{
     acceleration::foot_per_second_squared, angle::degree, angular_velocity::revolution_per_minute,
@@ -21,8 +23,13 @@ use uom::si::{
 };
 pub use update_context::*;
 
+pub mod frame_rate;
+pub mod profiling;
+pub mod property;
 pub mod test;
 
+use profiling::measure;
+
 /// Trait for a type which can read and write simulator data.
 /// Using this trait implementors can abstract away the way the code
 /// interacts with the simulator. This separation of concerns is very important
@@ -32,10 +39,39 @@ pub trait SimulatorReaderWriter {
     fn read(&mut self, identifier: &VariableIdentifier) -> f64;
     /// Writes a variable with the given identifier to the simulator.
     fn write(&mut self, identifier: &VariableIdentifier, value: f64);
+
+    /// Reads a variable holding a value in the given encoding, like [`SimulatorReaderWriter::read`].
+    fn read_encoded(
+        &mut self,
+        identifier: &VariableIdentifier,
+        _encoding: VariableEncoding,
+    ) -> f64 {
+        self.read(identifier)
+    }
+
+    /// Writes a value in the given encoding, like [`SimulatorReaderWriter::write`].
+    fn write_encoded(
+        &mut self,
+        identifier: &VariableIdentifier,
+        value: f64,
+        _encoding: VariableEncoding,
+    ) {
+        self.write(identifier, value)
+    }
 }
 
 pub trait VariableRegistry {
     fn get(&mut self, name: String) -> VariableIdentifier;
+
+    /// Gets the identifier of the variable like [`VariableRegistry::get`], while describing
+    /// the variable. Registries which have no use for the metadata ignore it.
+    fn get_with_metadata(
+        &mut self,
+        name: String,
+        _metadata: VariableMetadata,
+    ) -> VariableIdentifier {
+        self.get(name)
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
@@ -134,6 +170,14 @@ impl<'a> InitContext<'a> {
         self.registry.get(name)
     }
 
+    pub fn get_identifier_with_metadata(
+        &mut self,
+        name: String,
+        metadata: VariableMetadata,
+    ) -> VariableIdentifier {
+        self.registry.get_with_metadata(name, metadata)
+    }
+
     pub fn start_state(&self) -> StartState {
         self.start_state
     }
@@ -434,31 +478,47 @@ impl<T: Aircraft> Simulation<T> {
         simulation_time: f64,
         reader_writer: &mut impl SimulatorReaderWriter,
     ) {
-        self.electricity.pre_tick();
-
-        let mut reader = SimulatorReader::new(reader_writer);
-        self.update_context
-            .update(&mut reader, delta, simulation_time);
-
-        let mut visitor = SimulatorToSimulationVisitor::new(&mut reader);
-        self.aircraft.accept(&mut visitor);
-
-        self.aircraft
-            .update_before_power_distribution(&self.update_context, &mut self.electricity);
-
-        self.aircraft
-            .distribute_electricity(&self.update_context, &self.electricity);
-
-        self.aircraft
-            .update_after_power_distribution(&self.update_context);
-        self.aircraft
-            .consume_electricity(&self.update_context, &mut self.electricity);
-        self.aircraft
-            .report_electricity_consumption(&self.update_context, &self.electricity);
-
-        let mut writer = SimulatorWriter::new(reader_writer);
-        let mut visitor = SimulationToSimulatorVisitor::new(&mut writer);
-        self.aircraft.accept(&mut visitor);
+        measure("tick", || {
+            self.electricity.pre_tick();
+
+            measure("read", || {
+                let mut reader = SimulatorReader::new(reader_writer);
+                self.update_context
+                    .update(&mut reader, delta, simulation_time);
+
+                let mut visitor = SimulatorToSimulationVisitor::new(&mut reader);
+                self.aircraft.accept(&mut visitor);
+            });
+
+            measure("update_before_power_distribution", || {
+                self.aircraft
+                    .update_before_power_distribution(&self.update_context, &mut self.electricity)
+            });
+
+            measure("distribute_electricity", || {
+                self.aircraft
+                    .distribute_electricity(&self.update_context, &self.electricity)
+            });
+
+            measure("update_after_power_distribution", || {
+                self.aircraft
+                    .update_after_power_distribution(&self.update_context)
+            });
+            measure("consume_electricity", || {
+                self.aircraft
+                    .consume_electricity(&self.update_context, &mut self.electricity)
+            });
+            measure("report_electricity_consumption", || {
+                self.aircraft
+                    .report_electricity_consumption(&self.update_context, &self.electricity)
+            });
+
+            measure("write", || {
+                let mut writer = SimulatorWriter::new(reader_writer);
+                let mut visitor = SimulationToSimulatorVisitor::new(&mut writer);
+                self.aircraft.accept(&mut visitor);
+            });
+        });
     }
 
     pub fn activate_failure(&mut self, failure_type: FailureType) {
@@ -549,6 +609,14 @@ impl<'a> SimulationElementVisitor for SimulationToSimulatorVisitor<'a> {
 
 pub trait Reader {
     fn read_f64(&mut self, identifier: &VariableIdentifier) -> f64;
+
+    fn read_encoded_f64(
+        &mut self,
+        identifier: &VariableIdentifier,
+        _encoding: VariableEncoding,
+    ) -> f64 {
+        self.read_f64(identifier)
+    }
 }
 
 /// Reads data from the simulator into the aircraft system simulation.
@@ -566,10 +634,28 @@ impl<'a> Reader for SimulatorReader<'a> {
     fn read_f64(&mut self, identifier: &VariableIdentifier) -> f64 {
         self.simulator_read_writer.read(identifier)
     }
+
+    fn read_encoded_f64(
+        &mut self,
+        identifier: &VariableIdentifier,
+        encoding: VariableEncoding,
+    ) -> f64 {
+        self.simulator_read_writer
+            .read_encoded(identifier, encoding)
+    }
 }
 
 pub trait Writer {
     fn write_f64(&mut self, identifier: &VariableIdentifier, value: f64);
+
+    fn write_encoded_f64(
+        &mut self,
+        identifier: &VariableIdentifier,
+        value: f64,
+        _encoding: VariableEncoding,
+    ) {
+        self.write_f64(identifier, value)
+    }
 }
 
 /// Writes data from the aircraft system simulation into the the simulator.
@@ -587,6 +673,16 @@ impl<'a> Writer for SimulatorWriter<'a> {
     fn write_f64(&mut self, identifier: &VariableIdentifier, value: f64) {
         self.simulator_read_writer.write(identifier, value);
     }
+
+    fn write_encoded_f64(
+        &mut self,
+        identifier: &VariableIdentifier,
+        value: f64,
+        encoding: VariableEncoding,
+    ) {
+        self.simulator_read_writer
+            .write_encoded(identifier, value, encoding);
+    }
 }
 
 pub trait Read<T: Copy> {
@@ -633,7 +729,7 @@ pub trait Read<T: Copy> {
     where
         Self: Sized + Reader,
     {
-        let value = from_arinc429(self.read_f64(identifier));
+        let value = from_arinc429(self.read_encoded_f64(identifier, VariableEncoding::Arinc429));
         Arinc429Word::new(self.convert(value.0), value.1)
     }
 
@@ -641,7 +737,7 @@ pub trait Read<T: Copy> {
     where
         Self: Sized + Reader,
     {
-        let value = from_arinc825(self.read_f64(identifier));
+        let value = from_arinc825(self.read_encoded_f64(identifier, VariableEncoding::Arinc825));
         Arinc825Word::new_with_status(self.convert(value.0), value.1)
     }
 
@@ -695,7 +791,11 @@ pub trait Write<T> {
         Self: Sized + Writer,
     {
         let value = self.convert(value);
-        self.write_f64(identifier, to_arinc429(value, ssm));
+        self.write_encoded_f64(
+            identifier,
+            to_arinc429(value, ssm),
+            VariableEncoding::Arinc429,
+        );
     }
 
     fn write_arinc825(&mut self, identifier: &VariableIdentifier, value: T, status: u32)
@@ -703,7 +803,11 @@ pub trait Write<T> {
         Self: Sized + Writer,
     {
         let value = self.convert(value);
-        self.write_f64(identifier, to_arinc825(value, status));
+        self.write_encoded_f64(
+            identifier,
+            to_arinc825(value, status),
+            VariableEncoding::Arinc825,
+        );
     }
 
     fn convert(&mut self, value: T) -> f64;
@@ -0,0 +1,348 @@
+//! Optional instrumentation of the simulation tick, enabled by the `profiling` feature.
+//!
+//! [`measure`] times the given function and records it in a call tree per thread, such that
+//! the time spent in a system is attributed to the phase of the tick it runs in. Without the
+//! feature, [`measure`] only calls the function and the recorded profile stays empty.
+//!
+//! [`measure`]: fn.measure.html
+use std::{fmt, time::Duration};
+
+#[cfg(feature = "profiling")]
+use std::{cell::RefCell, time::Instant};
+
+#[cfg(not(feature = "profiling"))]
+#[inline(always)]
+pub fn measure<T, U: FnOnce() -> T>(_name: &'static str, func: U) -> T {
+    func()
+}
+
+#[cfg(feature = "profiling")]
+pub fn measure<T, U: FnOnce() -> T>(name: &'static str, func: U) -> T {
+    let idx = PROFILER.with(|profiler| profiler.borrow_mut().enter(name));
+    let start = Instant::now();
+
+    let result = func();
+
+    let elapsed = start.elapsed();
+    PROFILER.with(|profiler| profiler.borrow_mut().exit(idx, elapsed));
+
+    result
+}
+
+pub fn is_enabled() -> bool {
+    cfg!(feature = "profiling")
+}
+
+/// Returns the profile recorded on the current thread since the last [`reset`].
+///
+/// [`reset`]: fn.reset.html
+pub fn profile() -> TickProfile {
+    #[cfg(feature = "profiling")]
+    return PROFILER.with(|profiler| profiler.borrow().profile());
+
+    #[cfg(not(feature = "profiling"))]
+    TickProfile::default()
+}
+
+/// Discards everything recorded on the current thread.
+pub fn reset() {
+    #[cfg(feature = "profiling")]
+    PROFILER.with(|profiler| *profiler.borrow_mut() = Profiler::default());
+}
+
+#[cfg(feature = "profiling")]
+thread_local! {
+    static PROFILER: RefCell<Profiler> = RefCell::new(Profiler::default());
+}
+
+#[cfg(feature = "profiling")]
+#[derive(Default)]
+struct Profiler {
+    nodes: Vec<Node>,
+    roots: Vec<usize>,
+    stack: Vec<usize>,
+}
+#[cfg(feature = "profiling")]
+impl Profiler {
+    fn enter(&mut self, name: &'static str) -> usize {
+        let siblings = match self.stack.last() {
+            Some(&parent) => &self.nodes[parent].children,
+            None => &self.roots,
+        };
+
+        let idx = match siblings
+            .iter()
+            .copied()
+            .find(|&idx| self.nodes[idx].name == name)
+        {
+            Some(idx) => idx,
+            None => {
+                let idx = self.nodes.len();
+                self.nodes.push(Node::new(name));
+                match self.stack.last() {
+                    Some(&parent) => self.nodes[parent].children.push(idx),
+                    None => self.roots.push(idx),
+                }
+
+                idx
+            }
+        };
+
+        self.stack.push(idx);
+        idx
+    }
+
+    fn exit(&mut self, idx: usize, elapsed: Duration) {
+        self.stack.pop();
+
+        let node = &mut self.nodes[idx];
+        node.total += elapsed;
+        node.calls += 1;
+        node.max = node.max.max(elapsed);
+    }
+
+    fn profile(&self) -> TickProfile {
+        let mut entries = vec![];
+        for &root in &self.roots {
+            self.collect(root, 0, &mut entries);
+        }
+
+        TickProfile { entries }
+    }
+
+    fn collect(&self, idx: usize, depth: usize, entries: &mut Vec<ProfileEntry>) {
+        let node = &self.nodes[idx];
+        entries.push(ProfileEntry {
+            name: node.name,
+            depth,
+            total: node.total,
+            calls: node.calls,
+            max: node.max,
+        });
+
+        for &child in &node.children {
+            self.collect(child, depth + 1, entries);
+        }
+    }
+}
+
+#[cfg(feature = "profiling")]
+struct Node {
+    name: &'static str,
+    total: Duration,
+    calls: u64,
+    max: Duration,
+    children: Vec<usize>,
+}
+#[cfg(feature = "profiling")]
+impl Node {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            total: Duration::ZERO,
+            calls: 0,
+            max: Duration::ZERO,
+            children: vec![],
+        }
+    }
+}
+
+/// The time spent in a measured part of the tick.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileEntry {
+    name: &'static str,
+    depth: usize,
+    total: Duration,
+    calls: u64,
+    max: Duration,
+}
+impl ProfileEntry {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The nesting level within the call tree, zero for the tick itself.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    pub fn calls(&self) -> u64 {
+        self.calls
+    }
+
+    /// The longest single call.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+}
+
+/// The recorded call tree in depth first order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TickProfile {
+    entries: Vec<ProfileEntry>,
+}
+impl TickProfile {
+    pub fn entries(&self) -> &[ProfileEntry] {
+        &self.entries
+    }
+
+    /// Finds the entry with the given path of names from the root of the call tree.
+    pub fn entry(&self, path: &[&str]) -> Option<&ProfileEntry> {
+        let mut depth = 0;
+        let mut found = None;
+        for entry in &self.entries {
+            if depth == path.len() || entry.depth < depth {
+                break;
+            }
+
+            if entry.depth == depth && entry.name == path[depth] {
+                found = Some(entry);
+                depth += 1;
+            }
+        }
+
+        if depth == path.len() {
+            found
+        } else {
+            None
+        }
+    }
+
+    /// The number of ticks, being the most calls of any root of the call tree.
+    pub fn ticks(&self) -> u64 {
+        self.entries
+            .iter()
+            .filter(|entry| entry.depth == 0)
+            .map(|entry| entry.calls)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// The mean time spent in the entry per tick.
+    pub fn per_tick(&self, entry: &ProfileEntry) -> Duration {
+        match self.ticks() {
+            0 => Duration::ZERO,
+            ticks => entry.total / ticks as u32,
+        }
+    }
+}
+impl fmt::Display for TickProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total: Duration = self
+            .entries
+            .iter()
+            .filter(|entry| entry.depth == 0)
+            .map(|entry| entry.total)
+            .sum();
+
+        writeln!(
+            f,
+            "{:<50} {:>12} {:>12} {:>8}",
+            format!("{} ticks", self.ticks()),
+            "us/tick",
+            "max us",
+            "% tick"
+        )?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{:<50} {:>12.1} {:>12.1} {:>8.1}",
+                format!("{}{}", "  ".repeat(entry.depth), entry.name),
+                self.per_tick(entry).as_secs_f64() * 1e6,
+                entry.max.as_secs_f64() * 1e6,
+                if total.is_zero() {
+                    0.
+                } else {
+                    entry.total.as_secs_f64() / total.as_secs_f64() * 100.
+                }
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "profiling"))]
+mod tests {
+    use super::*;
+
+    fn busy(duration: Duration) {
+        let start = Instant::now();
+        while start.elapsed() < duration {}
+    }
+
+    #[test]
+    fn measure_returns_result_of_function() {
+        reset();
+
+        assert_eq!(measure("answer", || 42), 42);
+    }
+
+    #[test]
+    fn nested_measurements_form_a_call_tree() {
+        reset();
+        for _ in 0..2 {
+            measure("tick", || {
+                measure("update", || {
+                    measure("hydraulic", || busy(Duration::from_micros(200)));
+                    measure("hydraulic", || busy(Duration::from_micros(200)));
+                });
+                measure("write", || {});
+            });
+        }
+
+        let profile = profile();
+        let names: Vec<_> = profile
+            .entries()
+            .iter()
+            .map(|e| (e.depth(), e.name()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![(0, "tick"), (1, "update"), (2, "hydraulic"), (1, "write")]
+        );
+
+        assert_eq!(profile.ticks(), 2);
+        let hydraulic = profile.entry(&["tick", "update", "hydraulic"]).unwrap();
+        assert_eq!(hydraulic.calls(), 4);
+        assert!(profile.per_tick(hydraulic) >= Duration::from_micros(400));
+        assert!(hydraulic.max() >= Duration::from_micros(200));
+        assert!(profile.entry(&["tick"]).unwrap().total() >= hydraulic.total());
+    }
+
+    #[test]
+    fn same_name_in_different_parents_is_recorded_separately() {
+        reset();
+        measure("tick", || {
+            measure("before", || measure("electrical", || {}));
+            measure("after", || measure("electrical", || {}));
+        });
+
+        let profile = profile();
+        assert!(profile.entry(&["tick", "before", "electrical"]).is_some());
+        assert!(profile.entry(&["tick", "after", "electrical"]).is_some());
+        assert!(profile.entry(&["tick", "electrical"]).is_none());
+    }
+
+    #[test]
+    fn reset_discards_recorded_profile() {
+        measure("tick", || {});
+        reset();
+
+        assert!(profile().entries().is_empty());
+    }
+
+    #[test]
+    fn display_lists_every_entry() {
+        reset();
+        measure("tick", || measure("hydraulic", || {}));
+
+        let table = profile().to_string();
+        assert!(table.contains("1 ticks"));
+        assert!(table.contains("\n  hydraulic"));
+    }
+}
@@ -0,0 +1,737 @@
+//! Property based testing on top of the [`SimulationTestBed`].
+//!
+//! A [`ScenarioGenerator`] produces random but valid sequences of simulation frames, with
+//! frame rate jitter, failure injection and changing flight conditions. A [`PropertyTest`]
+//! runs many of those scenarios, checking an invariant after every frame. When the invariant
+//! is violated, the failing scenario is shrunk to a minimal reproduction before the test panics.
+//!
+//! [`SimulationTestBed`]: ../test/struct.SimulationTestBed.html
+use std::{fmt, ops::Range, thread, time::Duration};
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use uom::si::{
+    f64::*, length::foot, pressure::inch_of_mercury, thermodynamic_temperature::degree_celsius,
+    velocity::foot_per_minute, velocity::knot,
+};
+
+use super::test::TestBed;
+use crate::failures::FailureType;
+
+/// A single step of a [`Scenario`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScenarioStep {
+    Run(Duration),
+    Fail(FailureType),
+    Unfail(FailureType),
+    SetOnGround(bool),
+    SetIndicatedAirspeed(Velocity),
+    SetIndicatedAltitude(Length),
+    SetAmbientTemperature(ThermodynamicTemperature),
+    SetAmbientPressure(Pressure),
+    SetVerticalSpeed(Velocity),
+}
+impl ScenarioStep {
+    fn apply<T: TestBed>(&self, test_bed: &mut T) {
+        match *self {
+            ScenarioStep::Run(delta) => test_bed.run_with_delta(delta),
+            ScenarioStep::Fail(failure_type) => test_bed.fail(failure_type),
+            ScenarioStep::Unfail(failure_type) => test_bed.unfail(failure_type),
+            ScenarioStep::SetOnGround(on_ground) => test_bed.set_on_ground(on_ground),
+            ScenarioStep::SetIndicatedAirspeed(airspeed) => {
+                test_bed.set_indicated_airspeed(airspeed)
+            }
+            ScenarioStep::SetIndicatedAltitude(altitude) => {
+                test_bed.set_indicated_altitude(altitude)
+            }
+            ScenarioStep::SetAmbientTemperature(temperature) => {
+                test_bed.set_ambient_temperature(temperature)
+            }
+            ScenarioStep::SetAmbientPressure(pressure) => test_bed.set_ambient_pressure(pressure),
+            ScenarioStep::SetVerticalSpeed(vertical_speed) => {
+                test_bed.set_vertical_speed(vertical_speed)
+            }
+        }
+    }
+}
+
+/// Describes at which step of a [`Scenario`] an invariant was violated, and why.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvariantViolation {
+    step: usize,
+    message: String,
+}
+impl InvariantViolation {
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// A sequence of steps which can be replayed on any [`TestBed`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Scenario {
+    steps: Vec<ScenarioStep>,
+}
+impl Scenario {
+    pub fn new(steps: Vec<ScenarioStep>) -> Self {
+        Self { steps }
+    }
+
+    pub fn steps(&self) -> &[ScenarioStep] {
+        &self.steps
+    }
+
+    pub fn simulated_time(&self) -> Duration {
+        self.steps
+            .iter()
+            .map(|step| match step {
+                ScenarioStep::Run(delta) => *delta,
+                _ => Duration::ZERO,
+            })
+            .sum()
+    }
+
+    /// Applies all steps to the test bed, checking the invariant after every simulated frame.
+    pub fn run<T: TestBed, U: Fn(&mut T) -> Result<(), String>>(
+        &self,
+        test_bed: &mut T,
+        invariant: U,
+    ) -> Result<(), InvariantViolation> {
+        for (step, scenario_step) in self.steps.iter().enumerate() {
+            scenario_step.apply(test_bed);
+
+            if let ScenarioStep::Run(_) = scenario_step {
+                invariant(test_bed).map_err(|message| InvariantViolation { step, message })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+impl fmt::Display for Scenario {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "    {:?},", step)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Generates random but valid scenarios.
+///
+/// By default a scenario runs for the configured duration with frame times between 12 and
+/// 200 ms (83 to 5 fps). Failures and changing flight conditions are opt-in.
+pub struct ScenarioGenerator {
+    duration: Duration,
+    frame_time: Range<Duration>,
+    failures: Vec<FailureType>,
+    failure_toggle_probability: f64,
+    flight_condition_change_probability: f64,
+}
+impl ScenarioGenerator {
+    const DEFAULT_FRAME_TIME: Range<Duration> =
+        Duration::from_millis(12)..Duration::from_millis(200);
+
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            frame_time: Self::DEFAULT_FRAME_TIME,
+            failures: vec![],
+            failure_toggle_probability: 0.,
+            flight_condition_change_probability: 0.,
+        }
+    }
+
+    /// Sets the range of frame times. A range with identical bounds gives a fixed frame rate.
+    pub fn with_frame_time(mut self, frame_time: Range<Duration>) -> Self {
+        self.frame_time = frame_time;
+        self
+    }
+
+    /// Every frame, with the given probability, one of the failures is activated when inactive
+    /// or deactivated when active.
+    pub fn with_failures(mut self, failures: &[FailureType], toggle_probability: f64) -> Self {
+        self.failures = failures.to_vec();
+        self.failure_toggle_probability = toggle_probability;
+        self
+    }
+
+    /// Every frame, with the given probability, one of the flight conditions read by the
+    /// [`UpdateContext`] changes to a random value within its normal operating range.
+    ///
+    /// [`UpdateContext`]: ../struct.UpdateContext.html
+    pub fn with_flight_conditions(mut self, change_probability: f64) -> Self {
+        self.flight_condition_change_probability = change_probability;
+        self
+    }
+
+    pub fn generate(&self, seed: u64) -> Scenario {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let mut active_failures: Vec<FailureType> = vec![];
+        let mut steps = vec![];
+
+        let mut simulated_time = Duration::ZERO;
+        while simulated_time < self.duration {
+            if !self.failures.is_empty() && rng.gen_bool(self.failure_toggle_probability) {
+                let failure_type = self.failures[rng.gen_range(0..self.failures.len())];
+                match active_failures.iter().position(|f| *f == failure_type) {
+                    Some(idx) => {
+                        active_failures.remove(idx);
+                        steps.push(ScenarioStep::Unfail(failure_type));
+                    }
+                    None => {
+                        active_failures.push(failure_type);
+                        steps.push(ScenarioStep::Fail(failure_type));
+                    }
+                }
+            }
+
+            if rng.gen_bool(self.flight_condition_change_probability) {
+                steps.push(Self::random_flight_condition(&mut rng));
+            }
+
+            let delta = if self.frame_time.start < self.frame_time.end {
+                rng.gen_range(self.frame_time.clone())
+            } else {
+                self.frame_time.start
+            }
+            .min(self.duration - simulated_time);
+
+            steps.push(ScenarioStep::Run(delta));
+            simulated_time += delta;
+        }
+
+        Scenario::new(steps)
+    }
+
+    fn random_flight_condition(rng: &mut SmallRng) -> ScenarioStep {
+        match rng.gen_range(0..6) {
+            0 => ScenarioStep::SetOnGround(rng.gen_bool(0.5)),
+            1 => {
+                ScenarioStep::SetIndicatedAirspeed(Velocity::new::<knot>(rng.gen_range(0.0..350.)))
+            }
+            2 => ScenarioStep::SetIndicatedAltitude(Length::new::<foot>(
+                rng.gen_range(-1000.0..41000.),
+            )),
+            3 => ScenarioStep::SetAmbientTemperature(
+                ThermodynamicTemperature::new::<degree_celsius>(rng.gen_range(-56.5..50.)),
+            ),
+            4 => ScenarioStep::SetAmbientPressure(Pressure::new::<inch_of_mercury>(
+                rng.gen_range(5.0..31.5),
+            )),
+            _ => ScenarioStep::SetVerticalSpeed(Velocity::new::<foot_per_minute>(
+                rng.gen_range(-6000.0..6000.),
+            )),
+        }
+    }
+}
+
+/// Runs generated scenarios against a freshly constructed test bed and checks an invariant
+/// after every frame.
+///
+/// Cases are spread over multiple threads. Each thread constructs its own test beds, thus
+/// only the constructor and invariant need to be shareable between threads. Runs are
+/// deterministic for a given seed, which is printed on failure together with the shrunk
+/// scenario.
+pub struct PropertyTest {
+    generator: ScenarioGenerator,
+    cases: u64,
+    seed: u64,
+    threads: usize,
+}
+impl PropertyTest {
+    const DEFAULT_CASES: u64 = 32;
+    const DEFAULT_SEED: u64 = 0x5eed_a320_a380;
+
+    pub fn new(generator: ScenarioGenerator) -> Self {
+        Self {
+            generator,
+            cases: Self::DEFAULT_CASES,
+            seed: Self::DEFAULT_SEED,
+            threads: thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+        }
+    }
+
+    pub fn with_cases(mut self, cases: u64) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Checks the invariant on all cases and panics with a minimal failing scenario
+    /// when it is violated.
+    pub fn check<T, U, V>(&self, test_bed_ctor: U, invariant: V)
+    where
+        T: TestBed,
+        U: Fn() -> T + Sync,
+        V: Fn(&mut T) -> Result<(), String> + Sync,
+    {
+        if let Some(failure) = self.find_failure(&test_bed_ctor, &invariant) {
+            panic!("{}", failure);
+        }
+    }
+
+    /// Checks the invariant on all cases and returns the shrunk failure, if any.
+    pub fn find_failure<T, U, V>(&self, test_bed_ctor: &U, invariant: &V) -> Option<PropertyFailure>
+    where
+        T: TestBed,
+        U: Fn() -> T + Sync,
+        V: Fn(&mut T) -> Result<(), String> + Sync,
+    {
+        let run_case = |case: u64| {
+            let seed = self.seed.wrapping_add(case);
+            let scenario = self.generator.generate(seed);
+            scenario
+                .run(&mut test_bed_ctor(), invariant)
+                .err()
+                .map(|violation| (seed, scenario, violation))
+        };
+
+        let threads = (self.threads as u64).min(self.cases).max(1);
+        let first_failure = if threads == 1 {
+            (0..self.cases).find_map(run_case)
+        } else {
+            thread::scope(|scope| {
+                let handles: Vec<_> = (0..threads)
+                    .map(|offset| {
+                        let run_case = &run_case;
+                        scope.spawn(move || {
+                            (offset..self.cases)
+                                .step_by(threads as usize)
+                                .find_map(run_case)
+                        })
+                    })
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .filter_map(|handle| handle.join().unwrap())
+                    .min_by_key(|(seed, _, _)| *seed)
+            })
+        };
+
+        first_failure.map(|(seed, scenario, violation)| {
+            let (shrunk, violation) = shrink(
+                scenario,
+                violation,
+                self.generator.frame_time.end,
+                |candidate| candidate.run(&mut test_bed_ctor(), invariant).err(),
+            );
+
+            PropertyFailure {
+                seed,
+                scenario: shrunk,
+                violation,
+            }
+        })
+    }
+}
+
+/// A failing case of a [`PropertyTest`], after shrinking.
+#[derive(Debug)]
+pub struct PropertyFailure {
+    seed: u64,
+    scenario: Scenario,
+    violation: InvariantViolation,
+}
+impl PropertyFailure {
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn scenario(&self) -> &Scenario {
+        &self.scenario
+    }
+
+    pub fn violation(&self) -> &InvariantViolation {
+        &self.violation
+    }
+}
+impl fmt::Display for PropertyFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Invariant violated at step {}: {}",
+            self.violation.step, self.violation.message
+        )?;
+        writeln!(
+            f,
+            "Generated with seed {}, minimal scenario of {} steps ({:?} simulated):",
+            self.seed,
+            self.scenario.steps.len(),
+            self.scenario.simulated_time()
+        )?;
+        write!(f, "{}", self.scenario)
+    }
+}
+
+/// Shrinks a failing scenario by truncating it after the failing step, removing chunks of
+/// steps and finally merging consecutive frames, for as long as the invariant is still violated.
+/// Merged frames don't exceed the maximum frame time, such that the shrunk scenario stays
+/// within the frame rates the generator was configured for.
+fn shrink<T: FnMut(&Scenario) -> Option<InvariantViolation>>(
+    scenario: Scenario,
+    violation: InvariantViolation,
+    max_frame_time: Duration,
+    mut fails: T,
+) -> (Scenario, InvariantViolation) {
+    let mut steps = scenario.steps;
+    let mut violation = violation;
+    steps.truncate(violation.step + 1);
+
+    loop {
+        let mut progress = false;
+
+        let mut chunk_size = steps.len() / 2;
+        while chunk_size > 0 {
+            let mut start = 0;
+            while start + chunk_size <= steps.len() {
+                let mut candidate = steps.clone();
+                candidate.drain(start..start + chunk_size);
+
+                match fails(&Scenario::new(candidate.clone())) {
+                    Some(candidate_violation) => {
+                        candidate.truncate(candidate_violation.step + 1);
+                        steps = candidate;
+                        violation = candidate_violation;
+                        progress = true;
+                    }
+                    None => start += chunk_size,
+                }
+            }
+
+            chunk_size /= 2;
+        }
+
+        let mut idx = 0;
+        while idx + 1 < steps.len() {
+            if let (ScenarioStep::Run(first), ScenarioStep::Run(second)) =
+                (steps[idx], steps[idx + 1])
+            {
+                if first + second <= max_frame_time {
+                    let mut candidate = steps.clone();
+                    candidate.splice(idx..idx + 2, [ScenarioStep::Run(first + second)]);
+
+                    if let Some(candidate_violation) = fails(&Scenario::new(candidate.clone())) {
+                        candidate.truncate(candidate_violation.step + 1);
+                        steps = candidate;
+                        violation = candidate_violation;
+                        progress = true;
+                        continue;
+                    }
+                }
+            }
+
+            idx += 1;
+        }
+
+        if !progress {
+            return (Scenario::new(steps), violation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{SimulationTestBed, TestAircraft};
+    use crate::simulation::{
+        Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+    };
+    use crate::{failures::Failure, shared::HydraulicColor};
+
+    /// Integrates time while its failure is active, forgetting it otherwise.
+    struct TestElement {
+        failure: Failure,
+        failed_time: Duration,
+        frames: usize,
+    }
+    impl TestElement {
+        fn new() -> Self {
+            Self {
+                failure: Failure::new(FailureType::ReservoirLeak(HydraulicColor::Green)),
+                failed_time: Duration::ZERO,
+                frames: 0,
+            }
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            self.frames += 1;
+            if self.failure.is_active() {
+                self.failed_time += context.delta();
+            } else {
+                self.failed_time = Duration::ZERO;
+            }
+        }
+    }
+    impl SimulationElement for TestElement {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct TestAircraftWithElement {
+        element: TestElement,
+    }
+    impl Aircraft for TestAircraftWithElement {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.element.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraftWithElement {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.element.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraftWithElement> {
+        SimulationTestBed::new(|_: &mut InitContext| TestAircraftWithElement {
+            element: TestElement::new(),
+        })
+    }
+
+    fn failures() -> [FailureType; 2] {
+        [
+            FailureType::ReservoirLeak(HydraulicColor::Green),
+            FailureType::ReservoirLeak(HydraulicColor::Blue),
+        ]
+    }
+
+    fn failed_less_than(
+        limit: Duration,
+    ) -> impl Fn(&mut SimulationTestBed<TestAircraftWithElement>) -> Result<(), String> {
+        move |test_bed| {
+            let failed_time = test_bed.query(|a| a.element.failed_time);
+            if failed_time < limit {
+                Ok(())
+            } else {
+                Err(format!("failed for {:?}", failed_time))
+            }
+        }
+    }
+
+    #[test]
+    fn generated_scenario_covers_requested_duration() {
+        let scenario = ScenarioGenerator::new(Duration::from_secs(10)).generate(1);
+
+        assert_eq!(scenario.simulated_time(), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn generated_frame_times_stay_within_range() {
+        let scenario = ScenarioGenerator::new(Duration::from_secs(10))
+            .with_frame_time(Duration::from_millis(20)..Duration::from_millis(40))
+            .generate(2);
+
+        // The last frame is cut to the remaining duration.
+        let frames = &scenario.steps()[..scenario.steps().len() - 1];
+        assert!(frames.iter().all(|step| match step {
+            ScenarioStep::Run(delta) =>
+                *delta >= Duration::from_millis(20) && *delta < Duration::from_millis(40),
+            _ => false,
+        }));
+    }
+
+    #[test]
+    fn generation_is_deterministic_for_a_seed() {
+        let generator = ScenarioGenerator::new(Duration::from_secs(10))
+            .with_failures(&failures(), 0.1)
+            .with_flight_conditions(0.1);
+
+        assert_eq!(generator.generate(42), generator.generate(42));
+        assert_ne!(generator.generate(42), generator.generate(43));
+    }
+
+    #[test]
+    fn generated_failures_alternate_between_fail_and_unfail() {
+        let scenario = ScenarioGenerator::new(Duration::from_secs(60))
+            .with_failures(&failures(), 0.2)
+            .generate(3);
+
+        for failure_type in failures() {
+            let mut active = false;
+            for step in scenario.steps() {
+                match step {
+                    ScenarioStep::Fail(f) if *f == failure_type => {
+                        assert!(!active);
+                        active = true;
+                    }
+                    ScenarioStep::Unfail(f) if *f == failure_type => {
+                        assert!(active);
+                        active = false;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scenario_checks_invariant_every_frame() {
+        let scenario = ScenarioGenerator::new(Duration::from_secs(1))
+            .with_frame_time(Duration::from_millis(100)..Duration::from_millis(100))
+            .generate(4);
+        let mut test_bed = test_bed();
+        let checks = std::cell::Cell::new(0);
+
+        let result = scenario.run(&mut test_bed, |_| {
+            checks.set(checks.get() + 1);
+            Ok(())
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(checks.get(), 10);
+        assert_eq!(test_bed.query(|a| a.element.frames), 10);
+    }
+
+    #[test]
+    fn holding_invariant_finds_no_failure() {
+        let property = PropertyTest::new(
+            ScenarioGenerator::new(Duration::from_secs(10))
+                .with_failures(&failures(), 0.05)
+                .with_flight_conditions(0.05),
+        )
+        .with_cases(8);
+
+        assert!(property
+            .find_failure(&test_bed, &|test_bed| {
+                if test_bed.query(|a| a.element.frames) > 0 {
+                    Ok(())
+                } else {
+                    Err("no frame ran".to_owned())
+                }
+            })
+            .is_none());
+    }
+
+    #[test]
+    fn violated_invariant_shrinks_to_minimal_scenario() {
+        let property = PropertyTest::new(
+            ScenarioGenerator::new(Duration::from_secs(30))
+                .with_failures(&failures(), 0.05)
+                .with_flight_conditions(0.1),
+        )
+        .with_cases(8);
+
+        let failure = property
+            .find_failure(&test_bed, &failed_less_than(Duration::from_secs(2)))
+            .expect("a green leak lasting two seconds within 30 seconds of scenario");
+
+        // Only the relevant failure remains, followed by frames merged up to the maximum
+        // frame time of the generator.
+        let steps = failure.scenario().steps();
+        assert_eq!(
+            steps[0],
+            ScenarioStep::Fail(FailureType::ReservoirLeak(HydraulicColor::Green))
+        );
+        let frames: Vec<Duration> = steps[1..]
+            .iter()
+            .map(|step| match step {
+                ScenarioStep::Run(delta) => *delta,
+                _ => panic!("only frames may follow the failure"),
+            })
+            .collect();
+        assert!(frames
+            .iter()
+            .all(|delta| *delta <= Duration::from_millis(200)));
+        assert!(frames
+            .windows(2)
+            .all(|pair| pair[0] + pair[1] > Duration::from_millis(200)));
+        assert!(failure.scenario().simulated_time() >= Duration::from_secs(2));
+        assert_eq!(failure.violation().step(), steps.len() - 1);
+    }
+
+    #[test]
+    fn shrinking_merges_frames_up_to_the_maximum_frame_time() {
+        let scenario = Scenario::new(vec![ScenarioStep::Run(Duration::from_millis(50)); 20]);
+        let violation = InvariantViolation {
+            step: 19,
+            message: "".to_owned(),
+        };
+
+        let (shrunk, violation) = shrink(
+            scenario,
+            violation,
+            Duration::from_millis(100),
+            |candidate| {
+                if candidate.simulated_time() >= Duration::from_secs(1) {
+                    Some(InvariantViolation {
+                        step: candidate.steps().len() - 1,
+                        message: "".to_owned(),
+                    })
+                } else {
+                    None
+                }
+            },
+        );
+
+        assert_eq!(
+            shrunk.steps(),
+            vec![ScenarioStep::Run(Duration::from_millis(100)); 10]
+        );
+        assert_eq!(violation.step(), 9);
+    }
+
+    #[test]
+    fn parallel_and_sequential_runs_report_the_same_failure() {
+        let generator =
+            || ScenarioGenerator::new(Duration::from_secs(20)).with_failures(&failures(), 0.05);
+        let invariant = failed_less_than(Duration::from_secs(3));
+
+        let sequential = PropertyTest::new(generator())
+            .with_threads(1)
+            .find_failure(&test_bed, &invariant)
+            .unwrap();
+        let parallel = PropertyTest::new(generator())
+            .with_threads(4)
+            .find_failure(&test_bed, &invariant)
+            .unwrap();
+
+        assert_eq!(sequential.seed(), parallel.seed());
+        assert_eq!(sequential.scenario(), parallel.scenario());
+    }
+
+    #[test]
+    #[should_panic(expected = "minimal scenario")]
+    fn check_panics_with_the_shrunk_scenario() {
+        PropertyTest::new(
+            ScenarioGenerator::new(Duration::from_secs(30)).with_failures(&failures(), 0.05),
+        )
+        .check(test_bed, failed_less_than(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn works_with_single_element_test_bed() {
+        PropertyTest::new(ScenarioGenerator::new(Duration::from_secs(5)))
+            .with_cases(2)
+            .check(
+                || SimulationTestBed::<TestAircraft<TestElement>>::from(TestElement::new()),
+                |test_bed| {
+                    if test_bed.query_element(|e| e.failed_time) == Duration::ZERO {
+                        Ok(())
+                    } else {
+                        Err("failure was never injected".to_owned())
+                    }
+                },
+            );
+    }
+}
@@ -13,7 +13,7 @@ use uom::si::{
 use super::{Read, SimulatorReader};
 use crate::{
     shared::{low_pass_filter::LowPassFilter, MachNumber},
-    simulation::{InitContext, VariableIdentifier},
+    simulation::{InitContext, VariableIdentifier, VariableMetadata},
 };
 use nalgebra::{Rotation3, Vector3};
 
@@ -346,36 +346,87 @@ impl UpdateContext {
     }
 
     pub(super) fn new_for_simulation(context: &mut InitContext) -> UpdateContext {
+        fn simulator_variable(
+            context: &mut InitContext,
+            name: &str,
+            unit: &str,
+        ) -> VariableIdentifier {
+            context.get_identifier_with_metadata(
+                name.to_owned(),
+                VariableMetadata::new().unit(unit).written_externally(),
+            )
+        }
+
         UpdateContext {
-            is_ready_id: context.get_identifier("IS_READY".to_owned()),
-            ambient_temperature_id: context.get_identifier("AMBIENT TEMPERATURE".to_owned()),
-            indicated_airspeed_id: context.get_identifier("AIRSPEED INDICATED".to_owned()),
-            true_airspeed_id: context.get_identifier("AIRSPEED TRUE".to_owned()),
-            indicated_altitude_id: context.get_identifier("INDICATED ALTITUDE".to_owned()),
-            pressure_altitude_id: context.get_identifier("PRESSURE ALTITUDE".to_owned()),
-            is_on_ground_id: context.get_identifier("SIM ON GROUND".to_owned()),
-            ambient_pressure_id: context.get_identifier("AMBIENT PRESSURE".to_owned()),
-            ambient_density_id: context.get_identifier("AMBIENT DENSITY".to_owned()),
-            vertical_speed_id: context.get_identifier("VELOCITY WORLD Y".to_owned()),
-            local_longitudinal_speed_id: context.get_identifier("VELOCITY BODY Z".to_owned()),
-            local_lateral_speed_id: context.get_identifier("VELOCITY BODY X".to_owned()),
-            local_vertical_speed_id: context.get_identifier("VELOCITY BODY Y".to_owned()),
-            accel_body_x_id: context.get_identifier("ACCELERATION BODY X".to_owned()),
-            accel_body_y_id: context.get_identifier("ACCELERATION BODY Y".to_owned()),
-            accel_body_z_id: context.get_identifier("ACCELERATION BODY Z".to_owned()),
-            wind_velocity_x_id: context.get_identifier("AMBIENT WIND X".to_owned()),
-            wind_velocity_y_id: context.get_identifier("AMBIENT WIND Y".to_owned()),
-            wind_velocity_z_id: context.get_identifier("AMBIENT WIND Z".to_owned()),
-            plane_pitch_id: context.get_identifier("PLANE PITCH DEGREES".to_owned()),
-            plane_bank_id: context.get_identifier("PLANE BANK DEGREES".to_owned()),
-            plane_true_heading_id: context.get_identifier("PLANE HEADING DEGREES TRUE".to_owned()),
-            mach_number_id: context.get_identifier("AIRSPEED MACH".to_owned()),
-            plane_height_id: context.get_identifier("PLANE ALT ABOVE GROUND".to_owned()),
-            latitude_id: context.get_identifier("PLANE LATITUDE".to_owned()),
-            total_weight_id: context.get_identifier("TOTAL WEIGHT".to_owned()),
-            total_yaw_inertia_id: context.get_identifier("TOTAL WEIGHT YAW MOI".to_owned()),
-            precipitation_rate_id: context.get_identifier("AMBIENT PRECIP RATE".to_owned()),
-            in_cloud_id: context.get_identifier("AMBIENT IN CLOUD".to_owned()),
+            is_ready_id: simulator_variable(context, "IS_READY", "bool"),
+            ambient_temperature_id: simulator_variable(context, "AMBIENT TEMPERATURE", "celsius"),
+            indicated_airspeed_id: simulator_variable(context, "AIRSPEED INDICATED", "knots"),
+            true_airspeed_id: simulator_variable(context, "AIRSPEED TRUE", "knots"),
+            indicated_altitude_id: simulator_variable(context, "INDICATED ALTITUDE", "feet"),
+            pressure_altitude_id: simulator_variable(context, "PRESSURE ALTITUDE", "feet"),
+            is_on_ground_id: simulator_variable(context, "SIM ON GROUND", "bool"),
+            ambient_pressure_id: simulator_variable(context, "AMBIENT PRESSURE", "inHg"),
+            ambient_density_id: simulator_variable(
+                context,
+                "AMBIENT DENSITY",
+                "slugs per cubic feet",
+            ),
+            vertical_speed_id: simulator_variable(context, "VELOCITY WORLD Y", "feet per minute"),
+            local_longitudinal_speed_id: simulator_variable(
+                context,
+                "VELOCITY BODY Z",
+                "feet per second",
+            ),
+            local_lateral_speed_id: simulator_variable(
+                context,
+                "VELOCITY BODY X",
+                "feet per second",
+            ),
+            local_vertical_speed_id: simulator_variable(
+                context,
+                "VELOCITY BODY Y",
+                "feet per second",
+            ),
+            accel_body_x_id: simulator_variable(
+                context,
+                "ACCELERATION BODY X",
+                "feet per second squared",
+            ),
+            accel_body_y_id: simulator_variable(
+                context,
+                "ACCELERATION BODY Y",
+                "feet per second squared",
+            ),
+            accel_body_z_id: simulator_variable(
+                context,
+                "ACCELERATION BODY Z",
+                "feet per second squared",
+            ),
+            wind_velocity_x_id: simulator_variable(context, "AMBIENT WIND X", "meters per second"),
+            wind_velocity_y_id: simulator_variable(context, "AMBIENT WIND Y", "meters per second"),
+            wind_velocity_z_id: simulator_variable(context, "AMBIENT WIND Z", "meters per second"),
+            plane_pitch_id: simulator_variable(context, "PLANE PITCH DEGREES", "degrees"),
+            plane_bank_id: simulator_variable(context, "PLANE BANK DEGREES", "degrees"),
+            plane_true_heading_id: simulator_variable(
+                context,
+                "PLANE HEADING DEGREES TRUE",
+                "degrees",
+            ),
+            mach_number_id: simulator_variable(context, "AIRSPEED MACH", "mach"),
+            plane_height_id: simulator_variable(context, "PLANE ALT ABOVE GROUND", "feet"),
+            latitude_id: simulator_variable(context, "PLANE LATITUDE", "degrees"),
+            total_weight_id: simulator_variable(context, "TOTAL WEIGHT", "pounds"),
+            total_yaw_inertia_id: simulator_variable(
+                context,
+                "TOTAL WEIGHT YAW MOI",
+                "slugs feet squared",
+            ),
+            precipitation_rate_id: simulator_variable(
+                context,
+                "AMBIENT PRECIP RATE",
+                "millimeters",
+            ),
+            in_cloud_id: simulator_variable(context, "AMBIENT IN CLOUD", "bool"),
 
             delta: Default::default(),
             simulation_time: Default::default(),
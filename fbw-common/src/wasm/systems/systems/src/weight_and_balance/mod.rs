@@ -0,0 +1,524 @@
+use crate::{
+    shared::interpolation,
+    simulation::{InitContext, SimulationElement, SimulatorWriter, VariableIdentifier, Write},
+};
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length, Mass},
+    length::meter,
+    mass::kilogram,
+};
+
+/// A mass located at a longitudinal arm from the aircraft's reference datum.
+/// The arm is positive aft of the datum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BalanceItem {
+    mass: Mass,
+    arm: Length,
+}
+impl BalanceItem {
+    pub fn new(mass: Mass, arm: Length) -> Self {
+        Self { mass, arm }
+    }
+
+    pub fn mass(&self) -> Mass {
+        self.mass
+    }
+
+    pub fn arm(&self) -> Length {
+        self.arm
+    }
+
+    pub fn total_mass(items: &[BalanceItem]) -> Mass {
+        items
+            .iter()
+            .fold(Mass::default(), |total, item| total + item.mass)
+    }
+
+    /// Combines the items into one item with their total mass located at their common
+    /// center of gravity.
+    pub fn combined(items: &[BalanceItem]) -> Option<BalanceItem> {
+        let mass = Self::total_mass(items);
+        if mass <= Mass::new::<kilogram>(0.) {
+            return None;
+        }
+
+        let moment: f64 = items
+            .iter()
+            .map(|item| item.mass.get::<kilogram>() * item.arm.get::<meter>())
+            .sum();
+
+        Some(BalanceItem::new(
+            mass,
+            Length::new::<meter>(moment / mass.get::<kilogram>()),
+        ))
+    }
+}
+
+/// The mean aerodynamic chord (MAC) of the wing, which the center of gravity is expressed in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeanAerodynamicChord {
+    leading_edge_arm: Length,
+    length: Length,
+}
+impl MeanAerodynamicChord {
+    pub fn new(leading_edge_arm: Length, length: Length) -> Self {
+        Self {
+            leading_edge_arm,
+            length,
+        }
+    }
+
+    /// Returns the position of the given arm in percent of the MAC.
+    pub fn percent_mac(&self, arm: Length) -> f64 {
+        ((arm - self.leading_edge_arm) / self.length).value * 100.
+    }
+
+    /// Returns the arm of the given position in percent of the MAC.
+    pub fn arm(&self, percent_mac: f64) -> Length {
+        self.leading_edge_arm + self.length * (percent_mac / 100.)
+    }
+}
+
+/// The certified center of gravity envelope for a weight range. The forward and aft limits
+/// are given in percent of the MAC at each of the weight breakpoints and interpolated in
+/// between.
+pub struct CenterOfGravityEnvelope {
+    weights_kg: Vec<f64>,
+    forward_limits: Vec<f64>,
+    aft_limits: Vec<f64>,
+}
+impl CenterOfGravityEnvelope {
+    pub fn new(weights_kg: Vec<f64>, forward_limits: Vec<f64>, aft_limits: Vec<f64>) -> Self {
+        assert!(weights_kg.len() >= 2, "The envelope requires two weights.");
+        assert!(
+            weights_kg.len() == forward_limits.len() && weights_kg.len() == aft_limits.len(),
+            "The envelope requires a forward and aft limit for every weight."
+        );
+
+        Self {
+            weights_kg,
+            forward_limits,
+            aft_limits,
+        }
+    }
+
+    pub fn max_weight(&self) -> Mass {
+        Mass::new::<kilogram>(*self.weights_kg.last().unwrap())
+    }
+
+    pub fn contains(&self, weight: Mass, percent_mac: f64) -> bool {
+        let weight = weight.get::<kilogram>();
+        if weight < self.weights_kg[0] || weight > *self.weights_kg.last().unwrap() {
+            return false;
+        }
+
+        let forward_limit = interpolation(&self.weights_kg, &self.forward_limits, weight);
+        let aft_limit = interpolation(&self.weights_kg, &self.aft_limits, weight);
+
+        (forward_limit..=aft_limit).contains(&percent_mac)
+    }
+}
+
+/// Stabilizer trim settings for takeoff in degrees (positive nose up), as a function
+/// of the center of gravity in percent of the MAC.
+pub struct TakeoffTrimSchedule {
+    percent_mac: Vec<f64>,
+    trim_degrees: Vec<f64>,
+}
+impl TakeoffTrimSchedule {
+    pub fn new(percent_mac: Vec<f64>, trim_degrees: Vec<f64>) -> Self {
+        assert!(percent_mac.len() >= 2, "The schedule requires two points.");
+        assert_eq!(percent_mac.len(), trim_degrees.len());
+
+        Self {
+            percent_mac,
+            trim_degrees,
+        }
+    }
+
+    pub fn trim(&self, percent_mac: f64) -> Angle {
+        Angle::new::<degree>(interpolation(
+            &self.percent_mac,
+            &self.trim_degrees,
+            percent_mac,
+        ))
+    }
+}
+
+/// The result of a weight and balance computation in the form of a load sheet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadSheet {
+    pub operating_empty_weight: Mass,
+    pub payload: Mass,
+    pub zero_fuel_weight: Mass,
+    pub zero_fuel_weight_cg_percent_mac: f64,
+    pub fuel: Mass,
+    pub gross_weight: Mass,
+    pub gross_weight_cg_percent_mac: f64,
+    pub takeoff_trim: Angle,
+    pub zero_fuel_weight_within_limits: bool,
+    pub gross_weight_within_limits: bool,
+}
+impl LoadSheet {
+    /// Serialises the load sheet into JSON, for hosts able to transfer strings. Within the
+    /// simulator the load sheet is only written as the computer's numeric variables.
+    /// Masses are in kilograms and the trim in degrees, positive nose up.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"oew\":{:.0},\"payload\":{:.0},\"zfw\":{:.0},\"zfwCg\":{:.2},\"fuel\":{:.0},\
+             \"gw\":{:.0},\"gwCg\":{:.2},\"takeoffTrim\":{:.2},\"zfwWithinLimits\":{},\
+             \"gwWithinLimits\":{}}}",
+            self.operating_empty_weight.get::<kilogram>(),
+            self.payload.get::<kilogram>(),
+            self.zero_fuel_weight.get::<kilogram>(),
+            self.zero_fuel_weight_cg_percent_mac,
+            self.fuel.get::<kilogram>(),
+            self.gross_weight.get::<kilogram>(),
+            self.gross_weight_cg_percent_mac,
+            self.takeoff_trim.get::<degree>(),
+            self.zero_fuel_weight_within_limits,
+            self.gross_weight_within_limits,
+        )
+    }
+}
+
+/// Computes the zero fuel weight (ZFW), gross weight (GW), their centers of gravity and the
+/// takeoff trim setting from the aircraft's load. The aircraft provides the operating empty
+/// weight, payload and fuel as items at their station arms.
+pub struct WeightAndBalanceComputer {
+    zero_fuel_weight_id: VariableIdentifier,
+    zero_fuel_weight_cg_id: VariableIdentifier,
+    gross_weight_id: VariableIdentifier,
+    gross_weight_cg_id: VariableIdentifier,
+    takeoff_trim_id: VariableIdentifier,
+    zero_fuel_weight_within_limits_id: VariableIdentifier,
+    gross_weight_within_limits_id: VariableIdentifier,
+
+    mac: MeanAerodynamicChord,
+    zero_fuel_envelope: CenterOfGravityEnvelope,
+    gross_weight_envelope: CenterOfGravityEnvelope,
+    takeoff_trim_schedule: TakeoffTrimSchedule,
+
+    load_sheet: LoadSheet,
+}
+impl WeightAndBalanceComputer {
+    pub fn new(
+        context: &mut InitContext,
+        mac: MeanAerodynamicChord,
+        zero_fuel_envelope: CenterOfGravityEnvelope,
+        gross_weight_envelope: CenterOfGravityEnvelope,
+        takeoff_trim_schedule: TakeoffTrimSchedule,
+    ) -> Self {
+        Self {
+            zero_fuel_weight_id: context.get_identifier("WB_ZFW".to_owned()),
+            zero_fuel_weight_cg_id: context.get_identifier("WB_ZFW_CG".to_owned()),
+            gross_weight_id: context.get_identifier("WB_GW".to_owned()),
+            gross_weight_cg_id: context.get_identifier("WB_GW_CG".to_owned()),
+            takeoff_trim_id: context.get_identifier("WB_TAKEOFF_TRIM".to_owned()),
+            zero_fuel_weight_within_limits_id: context
+                .get_identifier("WB_ZFW_WITHIN_LIMITS".to_owned()),
+            gross_weight_within_limits_id: context.get_identifier("WB_GW_WITHIN_LIMITS".to_owned()),
+
+            mac,
+            zero_fuel_envelope,
+            gross_weight_envelope,
+            takeoff_trim_schedule,
+
+            load_sheet: LoadSheet {
+                operating_empty_weight: Mass::default(),
+                payload: Mass::default(),
+                zero_fuel_weight: Mass::default(),
+                zero_fuel_weight_cg_percent_mac: 0.,
+                fuel: Mass::default(),
+                gross_weight: Mass::default(),
+                gross_weight_cg_percent_mac: 0.,
+                takeoff_trim: Angle::default(),
+                zero_fuel_weight_within_limits: false,
+                gross_weight_within_limits: false,
+            },
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        operating_empty: BalanceItem,
+        payload: &[BalanceItem],
+        fuel: &[BalanceItem],
+    ) {
+        let zero_fuel_items: Vec<BalanceItem> =
+            [operating_empty].iter().chain(payload).copied().collect();
+        let gross_weight_items: Vec<BalanceItem> =
+            zero_fuel_items.iter().chain(fuel).copied().collect();
+
+        let zero_fuel = BalanceItem::combined(&zero_fuel_items).unwrap_or(operating_empty);
+        let gross_weight = BalanceItem::combined(&gross_weight_items).unwrap_or(zero_fuel);
+
+        let zero_fuel_cg = self.mac.percent_mac(zero_fuel.arm());
+        let gross_weight_cg = self.mac.percent_mac(gross_weight.arm());
+
+        self.load_sheet = LoadSheet {
+            operating_empty_weight: operating_empty.mass(),
+            payload: BalanceItem::total_mass(payload),
+            zero_fuel_weight: zero_fuel.mass(),
+            zero_fuel_weight_cg_percent_mac: zero_fuel_cg,
+            fuel: BalanceItem::total_mass(fuel),
+            gross_weight: gross_weight.mass(),
+            gross_weight_cg_percent_mac: gross_weight_cg,
+            takeoff_trim: self.takeoff_trim_schedule.trim(gross_weight_cg),
+            zero_fuel_weight_within_limits: self
+                .zero_fuel_envelope
+                .contains(zero_fuel.mass(), zero_fuel_cg),
+            gross_weight_within_limits: self
+                .gross_weight_envelope
+                .contains(gross_weight.mass(), gross_weight_cg),
+        };
+    }
+
+    pub fn zero_fuel_weight(&self) -> Mass {
+        self.load_sheet.zero_fuel_weight
+    }
+
+    pub fn zero_fuel_weight_cg_percent_mac(&self) -> f64 {
+        self.load_sheet.zero_fuel_weight_cg_percent_mac
+    }
+
+    pub fn gross_weight(&self) -> Mass {
+        self.load_sheet.gross_weight
+    }
+
+    pub fn gross_weight_cg_percent_mac(&self) -> f64 {
+        self.load_sheet.gross_weight_cg_percent_mac
+    }
+
+    pub fn takeoff_trim(&self) -> Angle {
+        self.load_sheet.takeoff_trim
+    }
+
+    pub fn is_within_limits(&self) -> bool {
+        self.load_sheet.zero_fuel_weight_within_limits && self.load_sheet.gross_weight_within_limits
+    }
+
+    pub fn load_sheet(&self) -> &LoadSheet {
+        &self.load_sheet
+    }
+}
+impl SimulationElement for WeightAndBalanceComputer {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.zero_fuel_weight_id,
+            self.load_sheet.zero_fuel_weight.get::<kilogram>(),
+        );
+        writer.write(
+            &self.zero_fuel_weight_cg_id,
+            self.load_sheet.zero_fuel_weight_cg_percent_mac,
+        );
+        writer.write(
+            &self.gross_weight_id,
+            self.load_sheet.gross_weight.get::<kilogram>(),
+        );
+        writer.write(
+            &self.gross_weight_cg_id,
+            self.load_sheet.gross_weight_cg_percent_mac,
+        );
+        writer.write(&self.takeoff_trim_id, self.load_sheet.takeoff_trim);
+        writer.write(
+            &self.zero_fuel_weight_within_limits_id,
+            self.load_sheet.zero_fuel_weight_within_limits,
+        );
+        writer.write(
+            &self.gross_weight_within_limits_id,
+            self.load_sheet.gross_weight_within_limits,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::Electricity;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed};
+    use crate::simulation::{Aircraft, SimulationElementVisitor, UpdateContext};
+    use ntest::assert_about_eq;
+
+    fn mac() -> MeanAerodynamicChord {
+        MeanAerodynamicChord::new(Length::new::<meter>(20.), Length::new::<meter>(4.))
+    }
+
+    fn envelope() -> CenterOfGravityEnvelope {
+        CenterOfGravityEnvelope::new(
+            vec![40000., 60000., 80000.],
+            vec![15., 17., 20.],
+            vec![40., 40., 35.],
+        )
+    }
+
+    struct TestAircraft {
+        computer: WeightAndBalanceComputer,
+        operating_empty: BalanceItem,
+        payload: Vec<BalanceItem>,
+        fuel: Vec<BalanceItem>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                computer: WeightAndBalanceComputer::new(
+                    context,
+                    mac(),
+                    envelope(),
+                    envelope(),
+                    TakeoffTrimSchedule::new(vec![15., 40.], vec![3., -2.]),
+                ),
+                operating_empty: BalanceItem::new(Mass::new::<kilogram>(40000.), mac().arm(25.)),
+                payload: vec![],
+                fuel: vec![],
+            }
+        }
+
+        fn load_payload(&mut self, payload: Vec<BalanceItem>) {
+            self.payload = payload;
+        }
+
+        fn load_fuel(&mut self, fuel: Vec<BalanceItem>) {
+            self.fuel = fuel;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(&mut self, _: &UpdateContext, _: &mut Electricity) {
+            self.computer
+                .update(self.operating_empty, &self.payload, &self.fuel);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.computer.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn percent_mac_is_relative_to_the_leading_edge() {
+        assert_about_eq!(mac().percent_mac(Length::new::<meter>(21.)), 25.);
+        assert_about_eq!(mac().arm(50.).get::<meter>(), 22.);
+    }
+
+    #[test]
+    fn combined_item_is_located_at_the_common_center_of_gravity() {
+        let item = BalanceItem::combined(&[
+            BalanceItem::new(Mass::new::<kilogram>(1000.), Length::new::<meter>(10.)),
+            BalanceItem::new(Mass::new::<kilogram>(3000.), Length::new::<meter>(30.)),
+        ])
+        .unwrap();
+
+        assert_about_eq!(item.mass().get::<kilogram>(), 4000.);
+        assert_about_eq!(item.arm().get::<meter>(), 25.);
+    }
+
+    #[test]
+    fn envelope_interpolates_limits_between_weights() {
+        let envelope = envelope();
+
+        assert!(envelope.contains(Mass::new::<kilogram>(70000.), 37.));
+        assert!(!envelope.contains(Mass::new::<kilogram>(70000.), 38.));
+        assert!(!envelope.contains(Mass::new::<kilogram>(50000.), 15.5));
+        assert!(!envelope.contains(Mass::new::<kilogram>(90000.), 25.));
+    }
+
+    #[test]
+    fn empty_aircraft_has_the_operating_empty_weight_and_cg() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let zfw: f64 = test_bed.read_by_name("WB_ZFW");
+        let zfw_cg: f64 = test_bed.read_by_name("WB_ZFW_CG");
+        let gw: f64 = test_bed.read_by_name("WB_GW");
+        assert_about_eq!(zfw, 40000.);
+        assert_about_eq!(zfw_cg, 25.);
+        assert_about_eq!(gw, 40000.);
+    }
+
+    #[test]
+    fn payload_aft_of_the_cg_moves_the_zfw_cg_aft() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.load_payload(vec![BalanceItem::new(
+                Mass::new::<kilogram>(10000.),
+                mac().arm(50.),
+            )])
+        });
+        test_bed.run();
+
+        let zfw: f64 = test_bed.read_by_name("WB_ZFW");
+        let zfw_cg: f64 = test_bed.read_by_name("WB_ZFW_CG");
+        assert_about_eq!(zfw, 50000.);
+        assert_about_eq!(zfw_cg, 30.);
+    }
+
+    #[test]
+    fn fuel_only_affects_the_gross_weight() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.load_fuel(vec![BalanceItem::new(
+                Mass::new::<kilogram>(10000.),
+                mac().arm(0.),
+            )])
+        });
+        test_bed.run();
+
+        let zfw_cg: f64 = test_bed.read_by_name("WB_ZFW_CG");
+        let gw: f64 = test_bed.read_by_name("WB_GW");
+        let gw_cg: f64 = test_bed.read_by_name("WB_GW_CG");
+        assert_about_eq!(zfw_cg, 25.);
+        assert_about_eq!(gw, 50000.);
+        assert_about_eq!(gw_cg, 20.);
+    }
+
+    #[test]
+    fn takeoff_trim_follows_the_gross_weight_cg() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let trim: f64 = test_bed.read_by_name("WB_TAKEOFF_TRIM");
+        assert_about_eq!(trim, 1.);
+    }
+
+    #[test]
+    fn indicates_when_the_cg_leaves_the_envelope() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let within_limits: bool = test_bed.read_by_name("WB_GW_WITHIN_LIMITS");
+        assert!(within_limits);
+
+        test_bed.command(|a| {
+            a.load_payload(vec![BalanceItem::new(
+                Mass::new::<kilogram>(20000.),
+                mac().arm(80.),
+            )])
+        });
+        test_bed.run();
+
+        let within_limits: bool = test_bed.read_by_name("WB_GW_WITHIN_LIMITS");
+        assert!(!within_limits);
+        assert!(!test_bed.query(|a| a.computer.is_within_limits()));
+    }
+
+    #[test]
+    fn load_sheet_is_serialised_to_json() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let json = test_bed.query(|a| a.computer.load_sheet().to_json());
+        assert_eq!(
+            json,
+            "{\"oew\":40000,\"payload\":0,\"zfw\":40000,\"zfwCg\":25.00,\"fuel\":0,\
+             \"gw\":40000,\"gwCg\":25.00,\"takeoffTrim\":1.00,\"zfwWithinLimits\":true,\
+             \"gwWithinLimits\":true}"
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! A client for the bridge protocol. It can stand in for a host when testing, or be
+//! used by hosts written in Rust.
+use crate::protocol::{ProtocolError, Request, Response};
+use std::{
+    error::Error,
+    io::{BufRead, BufReader, Write},
+    net::{TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+pub struct BridgeClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl BridgeClient {
+    pub fn connect(address: impl ToSocketAddrs) -> Result<Self, Box<dyn Error>> {
+        let writer = TcpStream::connect(address)?;
+
+        Ok(Self {
+            reader: BufReader::new(writer.try_clone()?),
+            writer,
+        })
+    }
+
+    pub fn read(&mut self, name: &str) -> Result<f64, Box<dyn Error>> {
+        self.request(&Request::Read(name.into()))?
+            .into_iter()
+            .find_map(|(value_name, value)| (value_name == name).then_some(value))
+            .ok_or_else(|| ProtocolError::new(format!("no value for '{}'", name)).into())
+    }
+
+    pub fn write(&mut self, name: &str, value: f64) -> Result<(), Box<dyn Error>> {
+        self.request(&Request::Write(name.into(), value))?;
+        Ok(())
+    }
+
+    pub fn subscribe(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
+        self.request(&Request::Subscribe(name.into()))?;
+        Ok(())
+    }
+
+    pub fn unsubscribe(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
+        self.request(&Request::Unsubscribe(name.into()))?;
+        Ok(())
+    }
+
+    pub fn activate_failure(&mut self, identifier: u64) -> Result<(), Box<dyn Error>> {
+        self.request(&Request::ActivateFailure(identifier))?;
+        Ok(())
+    }
+
+    pub fn deactivate_failure(&mut self, identifier: u64) -> Result<(), Box<dyn Error>> {
+        self.request(&Request::DeactivateFailure(identifier))?;
+        Ok(())
+    }
+
+    /// Steps the simulation, returning the values of all subscribed variables.
+    pub fn step(&mut self, delta: Duration) -> Result<Vec<(String, f64)>, Box<dyn Error>> {
+        self.request(&Request::Step(delta))
+    }
+
+    pub fn quit(mut self) -> Result<(), Box<dyn Error>> {
+        self.request(&Request::Quit)?;
+        Ok(())
+    }
+
+    fn request(&mut self, request: &Request) -> Result<Vec<(String, f64)>, Box<dyn Error>> {
+        writeln!(self.writer, "{}", request)?;
+        self.writer.flush()?;
+
+        let mut values = vec![];
+        loop {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Err(ProtocolError::new("connection closed".into()).into());
+            }
+
+            match line.parse::<Response>()? {
+                Response::Value(name, value) => values.push((name, value)),
+                Response::Ok => return Ok(values),
+                Response::Err(reason) => return Err(ProtocolError::new(reason).into()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::test_bridge, BridgeServer};
+    use std::thread;
+
+    fn connect_to_test_bridge() -> (BridgeClient, thread::JoinHandle<()>) {
+        let server = BridgeServer::bind("127.0.0.1:0").unwrap();
+        let address = server.local_addr().unwrap();
+        let handle = thread::spawn(move || {
+            let mut bridge = test_bridge();
+            server.serve_client(&mut bridge).unwrap();
+        });
+
+        (BridgeClient::connect(address).unwrap(), handle)
+    }
+
+    #[test]
+    fn drives_the_simulation_over_tcp() -> Result<(), Box<dyn Error>> {
+        let (mut client, server) = connect_to_test_bridge();
+
+        client.subscribe("OUTPUT")?;
+        client.write("INPUT", 4.)?;
+        assert_eq!(
+            client.step(Duration::from_millis(50))?,
+            vec![("OUTPUT".to_owned(), 8.)]
+        );
+
+        client.activate_failure(24_020)?;
+        client.step(Duration::from_millis(50))?;
+        assert_eq!(client.read("IS_FAILED")?, 1.);
+
+        client.quit()?;
+        server.join().unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    fn surfaces_bridge_errors() -> Result<(), Box<dyn Error>> {
+        let (mut client, server) = connect_to_test_bridge();
+
+        assert!(client.read("UNKNOWN").is_err());
+        assert!(client.activate_failure(1).is_err());
+        assert_eq!(client.read("OUTPUT")?, 0.);
+
+        client.quit()?;
+        server.join().unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,370 @@
+//! Bridges a simulation to hosts other than Microsoft Flight Simulator, such as other
+//! simulators, cockpit hardware software or test harnesses.
+//!
+//! The bridge exposes the simulation over the local protocol described in [protocol].
+//! Hosts subscribe to variables, write variables, activate failures and step the
+//! simulation. A [client::BridgeClient] is provided for hosts written in Rust and
+//! for testing.
+pub mod client;
+pub mod protocol;
+mod server;
+
+pub use server::BridgeServer;
+
+use fxhash::FxHashMap;
+use protocol::{Request, Response};
+use std::time::Duration;
+use systems::{
+    failures::FailureType,
+    simulation::{
+        Aircraft, InitContext, Simulation, SimulatorReaderWriter, StartState, VariableIdentifier,
+        VariableRegistry,
+    },
+};
+
+/// Type used to configure and build a [Bridge].
+#[derive(Default)]
+pub struct BridgeBuilder {
+    start_state: StartState,
+    failures: FxHashMap<u64, FailureType>,
+}
+
+impl BridgeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_start_state(mut self, start_state: StartState) -> Self {
+        self.start_state = start_state;
+        self
+    }
+
+    /// Declares the identifiers by which hosts activate and deactivate failures.
+    /// The aircraft crates provide their tables, e.g. `a380_systems::failures::FAILURES`.
+    pub fn with_failures(mut self, failures: Vec<(u64, FailureType)>) -> Self {
+        self.failures.extend(failures);
+        self
+    }
+
+    pub fn build<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+        self,
+        aircraft_ctor_fn: U,
+    ) -> Bridge<T> {
+        let mut variables = BridgeVariables::default();
+        let simulation = Simulation::new(self.start_state, aircraft_ctor_fn, &mut variables);
+
+        Bridge {
+            simulation,
+            variables,
+            failures: self.failures,
+            subscriptions: vec![],
+            simulation_time: 0.,
+        }
+    }
+}
+
+/// Handles protocol requests for a simulation, independent of the transport used.
+pub struct Bridge<T: Aircraft> {
+    simulation: Simulation<T>,
+    variables: BridgeVariables,
+    failures: FxHashMap<u64, FailureType>,
+    subscriptions: Vec<(String, VariableIdentifier)>,
+    simulation_time: f64,
+}
+
+impl<T: Aircraft> Bridge<T> {
+    pub fn handle(&mut self, request: &Request) -> Vec<Response> {
+        match request {
+            Request::Read(name) => match self.variables.find(name) {
+                Some(identifier) => vec![
+                    Response::Value(name.clone(), self.variables.read(&identifier)),
+                    Response::Ok,
+                ],
+                None => vec![Self::unknown_variable(name)],
+            },
+            Request::Write(name, value) => {
+                let identifier = self.variables.get(name.clone());
+                self.variables.write(&identifier, *value);
+                vec![Response::Ok]
+            }
+            Request::Subscribe(name) => match self.variables.find(name) {
+                Some(identifier) => {
+                    if !self.subscriptions.iter().any(|(n, _)| n == name) {
+                        self.subscriptions.push((name.clone(), identifier));
+                    }
+                    vec![Response::Ok]
+                }
+                None => vec![Self::unknown_variable(name)],
+            },
+            Request::Unsubscribe(name) => {
+                self.subscriptions.retain(|(n, _)| n != name);
+                vec![Response::Ok]
+            }
+            Request::ActivateFailure(identifier) | Request::DeactivateFailure(identifier) => {
+                match self.failures.get(identifier) {
+                    Some(&failure_type) => {
+                        if matches!(request, Request::ActivateFailure(_)) {
+                            self.simulation.activate_failure(failure_type);
+                        } else {
+                            self.simulation.deactivate_failure(failure_type);
+                        }
+                        vec![Response::Ok]
+                    }
+                    None => vec![Response::Err(format!(
+                        "unknown failure identifier '{}'",
+                        identifier
+                    ))],
+                }
+            }
+            Request::Step(delta) => self.step(*delta),
+            Request::Quit => vec![Response::Ok],
+        }
+    }
+
+    fn step(&mut self, delta: Duration) -> Vec<Response> {
+        self.simulation_time += delta.as_secs_f64();
+        self.simulation
+            .tick(delta, self.simulation_time, &mut self.variables);
+
+        self.subscriptions
+            .iter()
+            .map(|(name, identifier)| {
+                Response::Value(name.clone(), self.variables.read(identifier))
+            })
+            .chain(std::iter::once(Response::Ok))
+            .collect()
+    }
+
+    fn unknown_variable(name: &str) -> Response {
+        Response::Err(format!("unknown variable '{}'", name))
+    }
+}
+
+/// Stores all variables read and written by the simulation and the host.
+#[derive(Default)]
+struct BridgeVariables {
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    next_identifier: VariableIdentifier,
+    values: Vec<f64>,
+}
+
+impl BridgeVariables {
+    fn find(&self, name: &str) -> Option<VariableIdentifier> {
+        self.name_to_identifier.get(name).copied()
+    }
+}
+
+impl VariableRegistry for BridgeVariables {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        match self.name_to_identifier.get(&name) {
+            Some(identifier) => *identifier,
+            None => {
+                let identifier = self.next_identifier;
+                self.next_identifier = identifier.next();
+                self.name_to_identifier.insert(name, identifier);
+                self.values.push(0.);
+
+                identifier
+            }
+        }
+    }
+}
+
+impl SimulatorReaderWriter for BridgeVariables {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        self.values[identifier.identifier_index()]
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.values[identifier.identifier_index()] = value;
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use systems::{
+        failures::Failure,
+        simulation::{
+            Read, SimulationElement, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
+            UpdateContext, Write,
+        },
+    };
+
+    /// Doubles its input and reports the time passed and whether its failure is active.
+    pub(crate) struct TestAircraft {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+        delta_id: VariableIdentifier,
+        is_failed_id: VariableIdentifier,
+        input: f64,
+        delta: f64,
+        failure: Failure,
+    }
+    impl TestAircraft {
+        pub(crate) fn new(context: &mut InitContext) -> Self {
+            Self {
+                input_id: context.get_identifier("INPUT".into()),
+                output_id: context.get_identifier("OUTPUT".into()),
+                delta_id: context.get_identifier("DELTA".into()),
+                is_failed_id: context.get_identifier("IS_FAILED".into()),
+                input: 0.,
+                delta: 0.,
+                failure: Failure::new(FailureType::Generator(1)),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.delta = context.delta_as_secs_f64();
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.input * 2.);
+            writer.write(&self.delta_id, self.delta);
+            writer.write(&self.is_failed_id, self.failure.is_active());
+        }
+    }
+
+    pub(crate) fn test_bridge() -> Bridge<TestAircraft> {
+        BridgeBuilder::new()
+            .with_failures(vec![(24_020, FailureType::Generator(1))])
+            .build(TestAircraft::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test::test_bridge;
+    use super::*;
+
+    #[test]
+    fn written_variables_are_read_by_the_simulation() {
+        let mut bridge = test_bridge();
+
+        bridge.handle(&Request::Write("INPUT".into(), 2.));
+        bridge.handle(&Request::Step(Duration::from_millis(50)));
+
+        assert_eq!(
+            bridge.handle(&Request::Read("OUTPUT".into())),
+            vec![Response::Value("OUTPUT".into(), 4.), Response::Ok]
+        );
+    }
+
+    #[test]
+    fn step_advances_the_simulation_by_the_given_delta() {
+        let mut bridge = test_bridge();
+
+        bridge.handle(&Request::Step(Duration::from_millis(50)));
+
+        assert_eq!(
+            bridge.handle(&Request::Read("DELTA".into())),
+            vec![Response::Value("DELTA".into(), 0.05), Response::Ok]
+        );
+    }
+
+    #[test]
+    fn step_publishes_subscribed_variables() {
+        let mut bridge = test_bridge();
+
+        bridge.handle(&Request::Subscribe("OUTPUT".into()));
+        bridge.handle(&Request::Subscribe("IS_FAILED".into()));
+        bridge.handle(&Request::Write("INPUT".into(), 3.));
+
+        assert_eq!(
+            bridge.handle(&Request::Step(Duration::from_millis(50))),
+            vec![
+                Response::Value("OUTPUT".into(), 6.),
+                Response::Value("IS_FAILED".into(), 0.),
+                Response::Ok
+            ]
+        );
+
+        bridge.handle(&Request::Unsubscribe("IS_FAILED".into()));
+        assert_eq!(
+            bridge.handle(&Request::Step(Duration::from_millis(50))),
+            vec![Response::Value("OUTPUT".into(), 6.), Response::Ok]
+        );
+    }
+
+    #[test]
+    fn subscribing_twice_publishes_once() {
+        let mut bridge = test_bridge();
+
+        bridge.handle(&Request::Subscribe("OUTPUT".into()));
+        bridge.handle(&Request::Subscribe("OUTPUT".into()));
+
+        assert_eq!(
+            bridge
+                .handle(&Request::Step(Duration::from_millis(50)))
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn unknown_variables_cannot_be_read_or_subscribed() {
+        let mut bridge = test_bridge();
+
+        assert!(matches!(
+            bridge.handle(&Request::Read("UNKNOWN".into()))[..],
+            [Response::Err(_)]
+        ));
+        assert!(matches!(
+            bridge.handle(&Request::Subscribe("UNKNOWN".into()))[..],
+            [Response::Err(_)]
+        ));
+    }
+
+    #[test]
+    fn failures_are_activated_and_deactivated_by_identifier() {
+        let mut bridge = test_bridge();
+
+        bridge.handle(&Request::ActivateFailure(24_020));
+        bridge.handle(&Request::Step(Duration::from_millis(50)));
+        assert_eq!(
+            bridge.handle(&Request::Read("IS_FAILED".into()))[0],
+            Response::Value("IS_FAILED".into(), 1.)
+        );
+
+        bridge.handle(&Request::DeactivateFailure(24_020));
+        bridge.handle(&Request::Step(Duration::from_millis(50)));
+        assert_eq!(
+            bridge.handle(&Request::Read("IS_FAILED".into()))[0],
+            Response::Value("IS_FAILED".into(), 0.)
+        );
+    }
+
+    #[test]
+    fn failures_are_activated_by_the_identifiers_of_the_aircraft_failure_table() {
+        let mut bridge = BridgeBuilder::new()
+            .with_failures(a380_systems::failures::FAILURES.to_vec())
+            .build(test::TestAircraft::new);
+
+        assert_eq!(
+            bridge.handle(&Request::ActivateFailure(31_000)),
+            vec![Response::Ok]
+        );
+    }
+
+    #[test]
+    fn unknown_failures_are_rejected() {
+        let mut bridge = test_bridge();
+
+        assert!(matches!(
+            bridge.handle(&Request::ActivateFailure(1))[..],
+            [Response::Err(_)]
+        ));
+    }
+}
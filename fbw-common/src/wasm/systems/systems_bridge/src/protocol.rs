@@ -0,0 +1,242 @@
+//! The bridge protocol is a line based text protocol spoken over a local TCP connection.
+//!
+//! A client sends one request per line. The bridge answers each request with zero or
+//! more `VALUE` lines, followed by a single `OK` or `ERR <reason>` line. Numbers are
+//! written as decimal floating point values, durations in seconds. A single step lasts
+//! at most an hour.
+//!
+//! Variable names are those used by the simulation, e.g. `ELEC_AC_1_BUS_IS_POWERED` or
+//! `AMBIENT TEMPERATURE`. As names may contain spaces, they are always the last
+//! element of a line.
+//!
+//! | Request                   | Response                                              |
+//! |---------------------------|-------------------------------------------------------|
+//! | `READ <name>`             | `VALUE <value> <name>`, `OK`                          |
+//! | `WRITE <value> <name>`    | `OK`                                                  |
+//! | `SUBSCRIBE <name>`        | `OK`                                                  |
+//! | `UNSUBSCRIBE <name>`      | `OK`                                                  |
+//! | `ACTIVATE <failure id>`   | `OK`                                                  |
+//! | `DEACTIVATE <failure id>` | `OK`                                                  |
+//! | `STEP <delta>`            | `VALUE <value> <name>` per subscribed variable, `OK`  |
+//! | `QUIT`                    | `OK`, after which the bridge closes the connection    |
+//!
+//! Written variables keep their value until the simulation or the client writes them
+//! again. Failure identifiers are those of the aircraft's failure table, identical to
+//! the identifiers used within MSFS.
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+    time::Duration,
+};
+
+const MAX_STEP_SECONDS: f64 = 3600.;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Request {
+    Read(String),
+    Write(String, f64),
+    Subscribe(String),
+    Unsubscribe(String),
+    ActivateFailure(u64),
+    DeactivateFailure(u64),
+    Step(Duration),
+    Quit,
+}
+
+impl FromStr for Request {
+    type Err = ProtocolError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (command, arguments) = split_first(line);
+        match command {
+            "READ" => Ok(Self::Read(name(arguments)?)),
+            "WRITE" => {
+                let (value, name_argument) = split_first(arguments);
+                Ok(Self::Write(name(name_argument)?, number(value)?))
+            }
+            "SUBSCRIBE" => Ok(Self::Subscribe(name(arguments)?)),
+            "UNSUBSCRIBE" => Ok(Self::Unsubscribe(name(arguments)?)),
+            "ACTIVATE" => Ok(Self::ActivateFailure(failure_identifier(arguments)?)),
+            "DEACTIVATE" => Ok(Self::DeactivateFailure(failure_identifier(arguments)?)),
+            "STEP" => {
+                let delta = number(arguments)?;
+                // Larger values would overflow the step's duration.
+                if (0. ..=MAX_STEP_SECONDS).contains(&delta) {
+                    Ok(Self::Step(Duration::from_secs_f64(delta)))
+                } else {
+                    Err(ProtocolError::new(format!(
+                        "invalid step duration '{}'",
+                        delta
+                    )))
+                }
+            }
+            "QUIT" => Ok(Self::Quit),
+            _ => Err(ProtocolError::new(format!("unknown request '{}'", command))),
+        }
+    }
+}
+
+impl Display for Request {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(name) => write!(f, "READ {}", name),
+            Self::Write(name, value) => write!(f, "WRITE {} {}", value, name),
+            Self::Subscribe(name) => write!(f, "SUBSCRIBE {}", name),
+            Self::Unsubscribe(name) => write!(f, "UNSUBSCRIBE {}", name),
+            Self::ActivateFailure(identifier) => write!(f, "ACTIVATE {}", identifier),
+            Self::DeactivateFailure(identifier) => write!(f, "DEACTIVATE {}", identifier),
+            Self::Step(delta) => write!(f, "STEP {}", delta.as_secs_f64()),
+            Self::Quit => write!(f, "QUIT"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Response {
+    Value(String, f64),
+    Ok,
+    Err(String),
+}
+
+impl FromStr for Response {
+    type Err = ProtocolError;
+
+    fn from_str(line: &str) -> Result<Self, <Self as FromStr>::Err> {
+        let (command, arguments) = split_first(line);
+        match command {
+            "VALUE" => {
+                let (value, name_argument) = split_first(arguments);
+                Ok(Self::Value(name(name_argument)?, number(value)?))
+            }
+            "OK" => Ok(Self::Ok),
+            "ERR" => Ok(Self::Err(arguments.to_owned())),
+            _ => Err(ProtocolError::new(format!(
+                "unknown response '{}'",
+                command
+            ))),
+        }
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Value(name, value) => write!(f, "VALUE {} {}", value, name),
+            Self::Ok => write!(f, "OK"),
+            Self::Err(reason) => write!(f, "ERR {}", reason),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProtocolError(String);
+
+impl ProtocolError {
+    pub(crate) fn new(reason: String) -> Self {
+        Self(reason)
+    }
+}
+
+impl Display for ProtocolError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for ProtocolError {}
+
+fn split_first(line: &str) -> (&str, &str) {
+    let line = line.trim();
+    match line.split_once(' ') {
+        Some((first, rest)) => (first, rest.trim_start()),
+        None => (line, ""),
+    }
+}
+
+fn name(argument: &str) -> Result<String, ProtocolError> {
+    if argument.is_empty() {
+        Err(ProtocolError::new("missing variable name".into()))
+    } else {
+        Ok(argument.to_owned())
+    }
+}
+
+fn number(argument: &str) -> Result<f64, ProtocolError> {
+    argument
+        .parse()
+        .map_err(|_| ProtocolError::new(format!("invalid number '{}'", argument)))
+}
+
+fn failure_identifier(argument: &str) -> Result<u64, ProtocolError> {
+    argument
+        .parse()
+        .map_err(|_| ProtocolError::new(format!("invalid failure identifier '{}'", argument)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_requests() {
+        assert_eq!(
+            "READ AMBIENT TEMPERATURE".parse(),
+            Ok(Request::Read("AMBIENT TEMPERATURE".into()))
+        );
+        assert_eq!(
+            "WRITE -12.5 AMBIENT TEMPERATURE".parse(),
+            Ok(Request::Write("AMBIENT TEMPERATURE".into(), -12.5))
+        );
+        assert_eq!(
+            "SUBSCRIBE ELEC_AC_1_BUS_IS_POWERED".parse(),
+            Ok(Request::Subscribe("ELEC_AC_1_BUS_IS_POWERED".into()))
+        );
+        assert_eq!(
+            "ACTIVATE 24020".parse(),
+            Ok(Request::ActivateFailure(24_020))
+        );
+        assert_eq!(
+            "STEP 0.05".parse(),
+            Ok(Request::Step(Duration::from_millis(50)))
+        );
+        assert_eq!("QUIT".parse(), Ok(Request::Quit));
+    }
+
+    #[test]
+    fn rejects_malformed_requests() {
+        assert!("JUMP 1".parse::<Request>().is_err());
+        assert!("READ".parse::<Request>().is_err());
+        assert!("WRITE one NAME".parse::<Request>().is_err());
+        assert!("WRITE 1".parse::<Request>().is_err());
+        assert!("ACTIVATE -1".parse::<Request>().is_err());
+        assert!("STEP -0.1".parse::<Request>().is_err());
+        assert!("STEP NaN".parse::<Request>().is_err());
+        assert!("STEP inf".parse::<Request>().is_err());
+        assert!("STEP 1e20".parse::<Request>().is_err());
+    }
+
+    #[test]
+    fn requests_round_trip() {
+        for request in [
+            Request::Read("GENERAL ENG THROTTLE LEVER POSITION:1".into()),
+            Request::Write("PARK_BRAKE_LEVER_POS".into(), 1.),
+            Request::Unsubscribe("NAME".into()),
+            Request::DeactivateFailure(32_250),
+            Request::Step(Duration::from_millis(33)),
+        ] {
+            assert_eq!(request.to_string().parse(), Ok(request));
+        }
+    }
+
+    #[test]
+    fn responses_round_trip() {
+        for response in [
+            Response::Value("AMBIENT TEMPERATURE".into(), 15.),
+            Response::Ok,
+            Response::Err("unknown variable 'X'".into()),
+        ] {
+            assert_eq!(response.to_string().parse(), Ok(response));
+        }
+    }
+}
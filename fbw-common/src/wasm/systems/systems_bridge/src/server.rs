@@ -0,0 +1,59 @@
+use crate::{
+    protocol::{Request, Response},
+    Bridge,
+};
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+};
+use systems::simulation::Aircraft;
+
+/// Serves a [Bridge] over TCP. Clients are served one at a time, as the
+/// simulation only advances when the connected client steps it.
+pub struct BridgeServer {
+    listener: TcpListener,
+}
+
+impl BridgeServer {
+    pub fn bind(address: impl ToSocketAddrs) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(address)?,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Accepts the next client and serves it until it quits or disconnects.
+    pub fn serve_client<T: Aircraft>(&self, bridge: &mut Bridge<T>) -> io::Result<()> {
+        let (stream, _) = self.listener.accept()?;
+        Self::serve(stream, bridge)
+    }
+
+    fn serve<T: Aircraft>(stream: TcpStream, bridge: &mut Bridge<T>) -> io::Result<()> {
+        let mut writer = stream.try_clone()?;
+        for line in BufReader::new(stream).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (responses, quit) = match line.parse::<Request>() {
+                Ok(request) => (bridge.handle(&request), request == Request::Quit),
+                Err(error) => (vec![Response::Err(error.to_string())], false),
+            };
+
+            for response in responses {
+                writeln!(writer, "{}", response)?;
+            }
+            writer.flush()?;
+
+            if quit {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
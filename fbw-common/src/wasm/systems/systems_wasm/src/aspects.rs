@@ -1,10 +1,10 @@
+use crate::msfs::sim_connect::{SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER};
+use crate::msfs::sys;
 use crate::{
     f64_to_sim_connect_32k_pos, sim_connect_32k_pos_inv_to_f64, sim_connect_32k_pos_to_f64,
     MsfsVariableRegistry, Variable,
 };
 use enum_dispatch::enum_dispatch;
-use msfs::sim_connect::{SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER};
-use msfs::sys;
 use std::error::Error;
 use std::time::{Duration, Instant};
 use systems::simulation::VariableIdentifier;
@@ -565,6 +565,43 @@ macro_rules! set_data_on_sim_object {
     };
 }
 
+/// Declares a struct which can be read from and written to a sim object.
+///
+/// In MSFS this applies SimConnect's `data_definition` attribute. On other targets the
+/// `name` and `unit` attributes are dropped, such that the simulated SimConnect can
+/// store the struct.
+#[macro_export]
+macro_rules! data_definition {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                #[name = $sim_name:literal]
+                #[unit = $sim_unit:literal]
+                $field_vis:vis $field:ident: $field_type:ty
+            ),* $(,)?
+        }
+    ) => {
+        #[cfg(target_arch = "wasm32")]
+        $(#[$meta])*
+        #[$crate::msfs::sim_connect::data_definition]
+        $vis struct $name {
+            $(
+                #[name = $sim_name]
+                #[unit = $sim_unit]
+                $field_vis $field: $field_type,
+            )*
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        $(#[$meta])*
+        #[derive(Clone, Debug)]
+        $vis struct $name {
+            $($field_vis $field: $field_type,)*
+        }
+    };
+}
+
 #[enum_dispatch]
 enum Debounce {
     None(NoDebounce),
@@ -764,7 +801,7 @@ impl EventToVariable {
 
     fn map_to_value(
         &self,
-        e: &msfs::sys::SIMCONNECT_RECV_EVENT,
+        e: &sys::SIMCONNECT_RECV_EVENT,
         variables: &mut MsfsVariableRegistry,
     ) -> f64 {
         match self.mapping {
@@ -976,3 +1013,405 @@ impl ExecutableVariableAction for OnChange {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msfs::legacy::NamedVariable;
+    use std::{cell::RefCell, rc::Rc};
+
+    struct TestAspect {
+        sim_connect: SimConnect<'static>,
+        variables: MsfsVariableRegistry,
+        aspect: MsfsAspect,
+    }
+    impl TestAspect {
+        fn new(
+            configure: impl FnOnce(&mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>>,
+        ) -> Self {
+            let mut sim_connect = SimConnect::default();
+            let mut variables = MsfsVariableRegistry::new("TEST_".into());
+
+            let mut builder = MsfsAspectBuilder::new(&mut sim_connect, &mut variables);
+            configure(&mut builder).unwrap();
+            let aspect = builder.build();
+
+            Self {
+                sim_connect,
+                variables,
+                aspect,
+            }
+        }
+
+        fn pre_tick(&mut self, delta: Duration) {
+            self.aspect
+                .pre_tick(&mut self.variables, &mut self.sim_connect, delta)
+                .unwrap();
+        }
+
+        fn post_tick(&mut self) {
+            self.aspect
+                .post_tick(&mut self.variables, &mut self.sim_connect)
+                .unwrap();
+        }
+
+        fn tick(&mut self) {
+            self.pre_tick(Duration::from_millis(100));
+            self.post_tick();
+        }
+
+        fn receive_event(&mut self, event_name: &str, data: sys::DWORD) -> bool {
+            let event = self.sim_connect.event(event_name, data);
+            self.aspect
+                .handle_message(&SimConnectRecv::Event(&event), &mut self.variables)
+        }
+
+        fn set_named(&mut self, name: &str, value: f64) {
+            NamedVariable::from(&format!("TEST_{}", name)).set_value(value);
+        }
+
+        fn read(&mut self, variable: &Variable) -> f64 {
+            let identifier = self.variables.register(variable);
+            self.variables.read(&identifier)
+        }
+
+        fn transmitted_event_data(&mut self) -> Vec<sys::DWORD> {
+            self.sim_connect
+                .take_transmitted_events()
+                .iter()
+                .map(|event| event.data)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn init_variable_sets_the_initial_value() {
+        let mut test = TestAspect::new(|builder| {
+            builder.init_variable(Variable::named("INIT"), 5.);
+            Ok(())
+        });
+
+        assert_eq!(test.read(&Variable::named("INIT")), 5.);
+    }
+
+    #[test]
+    fn copy_copies_before_tick() {
+        let mut test = TestAspect::new(|builder| {
+            builder.copy(Variable::named("INPUT"), Variable::aspect("OUTPUT"));
+            Ok(())
+        });
+
+        test.set_named("INPUT", 3.);
+        test.post_tick();
+        assert_eq!(test.read(&Variable::aspect("OUTPUT")), 0.);
+
+        test.pre_tick(Duration::from_millis(100));
+        assert_eq!(test.read(&Variable::aspect("OUTPUT")), 3.);
+    }
+
+    #[test]
+    fn map_executes_at_the_declared_moment() {
+        let mut test = TestAspect::new(|builder| {
+            builder.map(
+                ExecuteOn::PostTick,
+                Variable::named("INPUT"),
+                |value| value * 2.,
+                Variable::aspect("OUTPUT"),
+            );
+            Ok(())
+        });
+
+        test.set_named("INPUT", 3.);
+        test.pre_tick(Duration::from_millis(100));
+        assert_eq!(test.read(&Variable::aspect("OUTPUT")), 0.);
+
+        test.post_tick();
+        assert_eq!(test.read(&Variable::aspect("OUTPUT")), 6.);
+    }
+
+    #[test]
+    fn map_many_passes_all_inputs() {
+        let mut test = TestAspect::new(|builder| {
+            builder.map_many(
+                ExecuteOn::PreTick,
+                vec![Variable::named("FIRST"), Variable::named("SECOND")],
+                |values| values[0] - values[1],
+                Variable::aspect("OUTPUT"),
+            );
+            Ok(())
+        });
+
+        test.set_named("FIRST", 5.);
+        test.set_named("SECOND", 2.);
+        test.tick();
+
+        assert_eq!(test.read(&Variable::aspect("OUTPUT")), 3.);
+    }
+
+    #[test]
+    fn reduce_folds_inputs_from_the_initial_value() {
+        let mut test = TestAspect::new(|builder| {
+            builder.reduce(
+                ExecuteOn::PreTick,
+                vec![Variable::named("FIRST"), Variable::named("SECOND")],
+                f64::NEG_INFINITY,
+                max,
+                Variable::aspect("OUTPUT"),
+            );
+            Ok(())
+        });
+
+        test.set_named("FIRST", -5.);
+        test.set_named("SECOND", -2.);
+        test.tick();
+
+        assert_eq!(test.read(&Variable::aspect("OUTPUT")), -2.);
+    }
+
+    #[test]
+    fn event_to_variable_maps_the_event_and_masks_when_requested() {
+        let mut test = TestAspect::new(|builder| {
+            builder.event_to_variable(
+                "PARKING_BRAKES",
+                EventToVariableMapping::Value(1.),
+                Variable::aspect("PARK"),
+                |options| options.mask(),
+            )?;
+            builder.event_to_variable(
+                "AXIS_LEFT_BRAKE_SET",
+                EventToVariableMapping::EventData32kPosition,
+                Variable::aspect("LEFT_BRAKE"),
+                |options| options,
+            )?;
+            Ok(())
+        });
+
+        assert!(test.sim_connect.is_event_masked("PARKING_BRAKES"));
+        assert!(!test.sim_connect.is_event_masked("AXIS_LEFT_BRAKE_SET"));
+
+        assert!(test.receive_event("PARKING_BRAKES", 0));
+        assert!(test.receive_event("AXIS_LEFT_BRAKE_SET", 0));
+
+        assert_eq!(test.read(&Variable::aspect("PARK")), 1.);
+        assert_eq!(test.read(&Variable::aspect("LEFT_BRAKE")), 0.5);
+    }
+
+    #[test]
+    fn unrelated_messages_are_not_handled() {
+        let mut test = TestAspect::new(|builder| {
+            builder.event_to_variable(
+                "PARKING_BRAKES",
+                EventToVariableMapping::Value(1.),
+                Variable::aspect("PARK"),
+                |options| options,
+            )?;
+            Ok(())
+        });
+
+        assert!(!test
+            .aspect
+            .handle_message(&SimConnectRecv::Null, &mut test.variables));
+        assert_eq!(test.read(&Variable::aspect("PARK")), 0.);
+    }
+
+    #[test]
+    fn event_to_variable_resets_after_tick() {
+        let mut test = TestAspect::new(|builder| {
+            builder.event_to_variable(
+                "TOGGLE",
+                EventToVariableMapping::Value(1.),
+                Variable::aspect("PRESSED"),
+                |options| options.afterwards_reset_to(0.),
+            )?;
+            Ok(())
+        });
+
+        test.receive_event("TOGGLE", 0);
+        test.pre_tick(Duration::from_millis(100));
+        assert_eq!(test.read(&Variable::aspect("PRESSED")), 1.);
+
+        test.post_tick();
+        assert_eq!(test.read(&Variable::aspect("PRESSED")), 0.);
+    }
+
+    #[test]
+    fn leading_debounce_ignores_repeated_events() {
+        let mut test = TestAspect::new(|builder| {
+            builder.event_to_variable(
+                "INCREMENT",
+                EventToVariableMapping::CurrentValueToValue(|value| value + 1.),
+                Variable::aspect("COUNT"),
+                |options| options.leading_debounce(Duration::from_secs(60)),
+            )?;
+            Ok(())
+        });
+
+        assert!(test.receive_event("INCREMENT", 0));
+        test.tick();
+        assert!(test.receive_event("INCREMENT", 0));
+
+        assert_eq!(test.read(&Variable::aspect("COUNT")), 1.);
+    }
+
+    #[test]
+    fn smooth_press_rises_while_pressed_and_decays_when_released() {
+        let mut test = TestAspect::new(|builder| {
+            builder.event_to_variable(
+                "BRAKES",
+                EventToVariableMapping::SmoothPress(2., 1.),
+                Variable::aspect("BRAKE"),
+                |options| options,
+            )?;
+            Ok(())
+        });
+
+        test.receive_event("BRAKES", 0);
+        test.tick();
+        assert!((test.read(&Variable::aspect("BRAKE")) - 0.2).abs() < f64::EPSILON);
+
+        test.tick();
+        assert!((test.read(&Variable::aspect("BRAKE")) - 0.1).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn variable_to_event_on_change_only_transmits_changes() {
+        let mut test = TestAspect::new(|builder| {
+            builder.variable_to_event(
+                Variable::named("INPUT"),
+                VariableToEventMapping::EventDataRaw,
+                VariableToEventWriteOn::Change,
+                "GEAR_SET",
+            )?;
+            Ok(())
+        });
+
+        test.set_named("INPUT", 1.);
+        test.tick();
+        test.tick();
+        assert_eq!(test.transmitted_event_data(), vec![1]);
+
+        test.set_named("INPUT", 0.);
+        test.tick();
+        assert_eq!(test.transmitted_event_data(), vec![0]);
+    }
+
+    #[test]
+    fn variable_to_event_every_tick_transmits_each_tick() {
+        let mut test = TestAspect::new(|builder| {
+            builder.variable_to_event(
+                Variable::named("INPUT"),
+                VariableToEventMapping::EventData32kPosition,
+                VariableToEventWriteOn::EveryTick,
+                "AXIS_SET",
+            )?;
+            Ok(())
+        });
+
+        test.set_named("INPUT", 1.);
+        test.tick();
+        test.tick();
+
+        assert_eq!(test.transmitted_event_data(), vec![16384, 16384]);
+    }
+
+    #[test]
+    fn variable_to_event_id_transmits_to_the_mapped_event() {
+        let mut test = TestAspect::new(|builder| {
+            let event_id = builder.event_to_variable(
+                "FLAPS_SET",
+                EventToVariableMapping::EventDataRaw,
+                Variable::aspect("FLAPS"),
+                |options| options.mask(),
+            )?;
+            builder.variable_to_event_id(
+                Variable::aspect("FLAPS"),
+                VariableToEventMapping::EventDataRaw,
+                VariableToEventWriteOn::Change,
+                event_id,
+            );
+            Ok(())
+        });
+
+        test.receive_event("FLAPS_SET", 3);
+        test.tick();
+
+        let events = test.sim_connect.take_transmitted_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].event_id,
+            test.sim_connect.event_id("FLAPS_SET").unwrap()
+        );
+        assert_eq!(events[0].data, 3);
+    }
+
+    #[test]
+    fn on_change_passes_previous_and_current_values() {
+        type Change = (Vec<f64>, Vec<f64>);
+        let changes: Rc<RefCell<Vec<Change>>> = Default::default();
+        let recorded_changes = changes.clone();
+        let mut test = TestAspect::new(move |builder| {
+            builder.on_change(
+                ExecuteOn::PostTick,
+                vec![Variable::named("OBSERVED")],
+                Box::new(move |previous, current| {
+                    recorded_changes
+                        .borrow_mut()
+                        .push((previous.to_vec(), current.to_vec()))
+                }),
+            );
+            Ok(())
+        });
+
+        test.tick();
+        test.set_named("OBSERVED", 2.);
+        test.tick();
+        test.tick();
+
+        assert_eq!(*changes.borrow(), vec![(vec![0.], vec![2.])]);
+    }
+
+    data_definition! {
+        struct TestObject {
+            #[name = "TEST VALUE"]
+            #[unit = "Number"]
+            value: f64,
+        }
+    }
+
+    impl VariablesToObject for TestObject {
+        fn variables(&self) -> Vec<Variable> {
+            vec![Variable::named("VALUE")]
+        }
+
+        fn write(&mut self, values: Vec<f64>) -> ObjectWrite {
+            self.value = values[0];
+            ObjectWrite::on(self.value > 0.)
+        }
+
+        set_data_on_sim_object!();
+    }
+
+    #[test]
+    fn variables_to_object_sets_data_when_requested() {
+        let mut test = TestAspect::new(|builder| {
+            builder.variables_to_object(Box::new(TestObject { value: 0. }));
+            Ok(())
+        });
+
+        test.tick();
+        assert!(test
+            .sim_connect
+            .last_sim_object_data::<TestObject>()
+            .is_none());
+
+        test.set_named("VALUE", 2.);
+        test.tick();
+        assert_eq!(
+            test.sim_connect
+                .last_sim_object_data::<TestObject>()
+                .unwrap()
+                .value,
+            2.
+        );
+    }
+}
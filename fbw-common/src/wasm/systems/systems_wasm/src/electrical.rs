@@ -1,8 +1,4 @@
-#[cfg(not(target_arch = "wasm32"))]
 use crate::msfs::legacy::execute_calculator_code;
-#[cfg(target_arch = "wasm32")]
-use msfs::legacy::execute_calculator_code;
-
 use crate::{ExecuteOn, MsfsAspectBuilder, Variable};
 use std::error::Error;
 use systems::shared::{to_bool, ElectricalBusType};
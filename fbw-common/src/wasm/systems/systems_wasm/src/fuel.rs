@@ -0,0 +1,78 @@
+use crate::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
+use crate::msfs::sim_connect::{SimConnect, SIMCONNECT_OBJECT_ID_USER};
+use crate::Variable;
+use std::error::Error;
+
+/// The systems burn the APU's fuel from the left main tank, of which the quantity is owned by
+/// MSFS. The quantity requested by the systems is written back to the tank whenever it is lower
+/// than the tank's quantity.
+pub(super) fn left_main_fuel_tank(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    builder.variables_to_object(Box::new(LeftMainFuelTank { quantity: 0. }));
+
+    Ok(())
+}
+
+data_definition! {
+    struct LeftMainFuelTank {
+        #[name = "FUEL TANK LEFT MAIN QUANTITY"]
+        #[unit = "Pounds"]
+        quantity: f64,
+    }
+}
+
+impl VariablesToObject for LeftMainFuelTank {
+    fn variables(&self) -> Vec<Variable> {
+        vec![
+            Variable::aircraft("FUEL TANK LEFT MAIN QUANTITY", "Pounds", 0),
+            Variable::aspect("FUEL_TANK_LEFT_MAIN_QUANTITY_REQ"),
+        ]
+    }
+
+    fn write(&mut self, values: Vec<f64>) -> ObjectWrite {
+        let quantity = values[0];
+        let requested_quantity = values[1];
+        self.quantity = requested_quantity;
+
+        ObjectWrite::on(requested_quantity < quantity)
+    }
+
+    set_data_on_sim_object!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aspects::Aspect;
+    use crate::msfs::legacy::AircraftVariable;
+    use crate::MsfsVariableRegistry;
+
+    fn post_tick_with(quantity: f64, requested_quantity: f64) -> Option<f64> {
+        let mut sim_connect = SimConnect::default();
+        let mut variables = MsfsVariableRegistry::new("TEST_".into());
+        let mut builder = MsfsAspectBuilder::new(&mut sim_connect, &mut variables);
+        left_main_fuel_tank(&mut builder).unwrap();
+        let mut aspect = builder.build();
+
+        AircraftVariable::from("FUEL TANK LEFT MAIN QUANTITY", "Pounds", 0)
+            .unwrap()
+            .set(quantity);
+        let requested_quantity_id =
+            variables.register(&Variable::aspect("FUEL_TANK_LEFT_MAIN_QUANTITY_REQ"));
+        variables.write(&requested_quantity_id, requested_quantity);
+        aspect.post_tick(&mut variables, &mut sim_connect).unwrap();
+
+        sim_connect
+            .last_sim_object_data::<LeftMainFuelTank>()
+            .map(|tank| tank.quantity)
+    }
+
+    #[test]
+    fn burnt_fuel_is_written_to_the_tank() {
+        assert_eq!(post_tick_with(1000., 999.5), Some(999.5));
+    }
+
+    #[test]
+    fn tank_is_not_written_when_no_fuel_was_burnt() {
+        assert_eq!(post_tick_with(1000., 1000.), None);
+    }
+}
@@ -2,20 +2,18 @@
 pub mod aspects;
 mod electrical;
 mod failures;
-mod msfs;
-
-#[cfg(not(target_arch = "wasm32"))]
-use crate::msfs::legacy::{AircraftVariable, NamedVariable};
-#[cfg(target_arch = "wasm32")]
-use ::msfs::legacy::{AircraftVariable, NamedVariable};
+mod fuel;
+pub mod msfs;
 
 use crate::aspects::{Aspect, ExecuteOn, MsfsAspectBuilder};
 use crate::electrical::{auxiliary_power_unit, electrical_buses};
-use ::msfs::{
-    sim_connect::{data_definition, Period, SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER},
+use crate::msfs::{
+    legacy::{AircraftVariable, NamedVariable},
+    sim_connect::{Period, SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER},
     sys, MSFSEvent,
 };
 use failures::Failures;
+use fuel::left_main_fuel_tank;
 use fxhash::FxHashMap;
 use std::fmt::{Display, Formatter};
 use std::{error::Error, time::Duration};
@@ -109,6 +107,11 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         ))
     }
 
+    /// Writes the fuel burnt from the left main tank by the simulation back to the tank.
+    pub fn with_left_main_fuel_tank(self) -> Result<Self, Box<dyn Error>> {
+        self.with_aspect(left_main_fuel_tank)
+    }
+
     pub fn with_failures(mut self, failures: Vec<(u64, FailureType)>) -> Self {
         let mut f = Failures::new(
             NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_ACTIVATE")),
@@ -248,6 +251,34 @@ impl MsfsHandler {
         Ok(())
     }
 
+    /// Runs a frame on the simulated SimConnect, as MSFS does after the given
+    /// amount of simulation time passed.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run_frame<T: Aircraft>(
+        &mut self,
+        delta: Duration,
+        simulation: &mut Simulation<T>,
+        sim_connect: &mut SimConnect,
+    ) -> Result<(), Box<dyn Error>> {
+        let simulation_time = sys::SIMCONNECT_RECV_SIMOBJECT_DATA::new(
+            SimulationTime::REQUEST_ID,
+            SimulationTime {
+                value: self.time.simulation_time() + delta.as_secs_f64(),
+            },
+        );
+        self.handle(
+            MSFSEvent::SimConnect(SimConnectRecv::SimObjectData(&simulation_time)),
+            simulation,
+            sim_connect,
+        )?;
+
+        self.handle(
+            MSFSEvent::PreDraw(&Default::default()),
+            simulation,
+            sim_connect,
+        )
+    }
+
     fn read_failures_into_simulation<T: Aircraft>(
         failures: &Failures,
         simulation: &mut Simulation<T>,
@@ -539,11 +570,12 @@ impl VariableRegistry for MsfsVariableRegistry {
     }
 }
 
-#[data_definition]
-struct SimulationTime {
-    #[name = "SIMULATION TIME"]
-    #[unit = "Number"]
-    value: f64,
+data_definition! {
+    struct SimulationTime {
+        #[name = "SIMULATION TIME"]
+        #[unit = "Number"]
+        value: f64,
+    }
 }
 
 impl SimulationTime {
@@ -623,3 +655,214 @@ pub fn f64_to_sim_connect_32k_pos(scaled_axis_value: f64) -> sys::DWORD {
 
     to_i32 as sys::DWORD
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msfs::legacy;
+    use systems::failures::Failure;
+    use systems::simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, Write,
+    };
+
+    struct TestAircraft {
+        delta_id: VariableIdentifier,
+        bus_is_powered_id: VariableIdentifier,
+        generator_is_failed_id: VariableIdentifier,
+        delta: f64,
+        bus_is_powered: bool,
+        generator_failure: Failure,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                delta_id: context.get_identifier("DELTA".into()),
+                bus_is_powered_id: context.get_identifier("ELEC_DC_1_BUS_IS_POWERED".into()),
+                generator_is_failed_id: context.get_identifier("GENERATOR_IS_FAILED".into()),
+                delta: 0.,
+                bus_is_powered: true,
+                generator_failure: Failure::new(FailureType::Generator(1)),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.delta = context.delta_as_secs_f64();
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.generator_failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.bus_is_powered = reader.read(&self.bus_is_powered_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.delta_id, self.delta);
+            writer.write(&self.bus_is_powered_id, self.bus_is_powered);
+            writer.write(
+                &self.generator_is_failed_id,
+                self.generator_failure.is_active(),
+            );
+        }
+    }
+
+    fn build(
+        sim_connect: &mut SimConnect,
+    ) -> Result<(Simulation<TestAircraft>, MsfsHandler), Box<dyn Error>> {
+        MsfsSimulationBuilder::new("TEST_", Variable::named("START_STATE"), sim_connect)
+            .with_electrical_buses([(ElectricalBusType::DirectCurrent(1), 7)])?
+            .with_failures(vec![(24_020, FailureType::Generator(1))])
+            .build(TestAircraft::new)
+    }
+
+    fn named_variable_value(name: &str) -> f64 {
+        NamedVariable::from(&format!("TEST_{}", name)).get_value()
+    }
+
+    #[test]
+    fn requests_simulation_time_every_visual_frame() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        build(&mut sim_connect)?;
+
+        assert!(sim_connect.has_data_request(SimulationTime::REQUEST_ID, Period::VisualFrame));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ticks_with_the_simulation_time_passed_since_the_previous_frame() -> Result<(), Box<dyn Error>>
+    {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = build(&mut sim_connect)?;
+
+        handler.run_frame(
+            Duration::from_millis(100),
+            &mut simulation,
+            &mut sim_connect,
+        )?;
+        assert!((named_variable_value("DELTA") - 0.1).abs() < f64::EPSILON);
+
+        handler.run_frame(Duration::from_millis(50), &mut simulation, &mut sim_connect)?;
+        assert!((named_variable_value("DELTA") - 0.05).abs() < f64::EPSILON);
+
+        Ok(())
+    }
+
+    #[test]
+    fn does_not_tick_while_simulation_time_is_paused() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = build(&mut sim_connect)?;
+
+        handler.run_frame(
+            Duration::from_millis(100),
+            &mut simulation,
+            &mut sim_connect,
+        )?;
+        NamedVariable::from("TEST_DELTA").set_value(0.);
+        handler.run_frame(Duration::ZERO, &mut simulation, &mut sim_connect)?;
+
+        assert_eq!(named_variable_value("DELTA"), 0.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn caps_abnormal_delta_time() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = build(&mut sim_connect)?;
+
+        handler.run_frame(Duration::from_secs(3), &mut simulation, &mut sim_connect)?;
+
+        assert!((named_variable_value("DELTA") - 0.5).abs() < f64::EPSILON);
+
+        Ok(())
+    }
+
+    #[test]
+    fn activates_and_deactivates_failures_requested_by_the_simulator() -> Result<(), Box<dyn Error>>
+    {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = build(&mut sim_connect)?;
+
+        NamedVariable::from("TEST_FAILURE_ACTIVATE").set_value(24_020.);
+        handler.run_frame(
+            Duration::from_millis(100),
+            &mut simulation,
+            &mut sim_connect,
+        )?;
+        assert_eq!(named_variable_value("GENERATOR_IS_FAILED"), 1.);
+        assert_eq!(named_variable_value("FAILURE_ACTIVATE"), 0.);
+
+        NamedVariable::from("TEST_FAILURE_DEACTIVATE").set_value(24_020.);
+        handler.run_frame(
+            Duration::from_millis(100),
+            &mut simulation,
+            &mut sim_connect,
+        )?;
+        assert_eq!(named_variable_value("GENERATOR_IS_FAILED"), 0.);
+        assert_eq!(named_variable_value("FAILURE_DEACTIVATE"), 0.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ignores_unknown_failures() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = build(&mut sim_connect)?;
+
+        NamedVariable::from("TEST_FAILURE_ACTIVATE").set_value(99_999.);
+        handler.run_frame(
+            Duration::from_millis(100),
+            &mut simulation,
+            &mut sim_connect,
+        )?;
+
+        assert_eq!(named_variable_value("GENERATOR_IS_FAILED"), 0.);
+        assert_eq!(named_variable_value("FAILURE_ACTIVATE"), 99_999.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn toggles_msfs_bus_connection_when_bus_power_changes() -> Result<(), Box<dyn Error>> {
+        let mut sim_connect = SimConnect::default();
+        let (mut simulation, mut handler) = build(&mut sim_connect)?;
+
+        handler.run_frame(
+            Duration::from_millis(100),
+            &mut simulation,
+            &mut sim_connect,
+        )?;
+        assert!(legacy::take_executed_calculator_code().is_empty());
+
+        NamedVariable::from("TEST_ELEC_DC_1_BUS_IS_POWERED").set_value(0.);
+        handler.run_frame(
+            Duration::from_millis(100),
+            &mut simulation,
+            &mut sim_connect,
+        )?;
+
+        assert_eq!(
+            legacy::take_executed_calculator_code(),
+            vec!["1 7 (>K:2:ELECTRICAL_BUS_TO_BUS_CONNECTION_TOGGLE)".to_owned()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_32k_positions() {
+        assert_eq!(sim_connect_32k_pos_to_f64(-16384_i32 as sys::DWORD), 0.);
+        assert_eq!(sim_connect_32k_pos_to_f64(0), 0.5);
+        assert_eq!(sim_connect_32k_pos_to_f64(16384), 1.);
+        assert_eq!(sim_connect_32k_pos_inv_to_f64(16384), 0.);
+        assert_eq!(f64_to_sim_connect_32k_pos(1.), 16384);
+        assert_eq!(f64_to_sim_connect_32k_pos(0.) as i32, -16384);
+    }
+}
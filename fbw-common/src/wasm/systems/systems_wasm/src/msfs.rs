@@ -1,47 +1,293 @@
-///! Module declared to be able to compile documentation.
-///! Does not provide any meaningful functionality.
+//! Provides the parts of the MSFS SDK used by the bridging layer.
+//!
+//! When compiling for MSFS these are re-exported from the `msfs` crate. On other targets
+//! a simulated SimConnect is provided instead, which records executed calculator code,
+//! mapped and transmitted events and the data set on sim objects, and stores named and
+//! aircraft variables in memory. This allows aspects to be tested with `cargo test`.
+//!
+//! The simulated state which has no SimConnect handle to live in, such as calculator code
+//! and variables, is stored per thread. Each test thus starts with a clean simulator.
+#[cfg(target_arch = "wasm32")]
+pub use ::msfs::{legacy, sim_connect, sys, MSFSEvent};
+
+/// Events as they are received by a gauge.
+#[cfg(not(target_arch = "wasm32"))]
+pub enum MSFSEvent<'a> {
+    PreDraw(&'a sys::sGaugeDrawData),
+    PostDraw(&'a sys::sGaugeDrawData),
+    SimConnect(sim_connect::SimConnectRecv<'a>),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod sys {
+    #![allow(non_camel_case_types)]
+    use std::any::Any;
+
+    pub type DWORD = u32;
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct sGaugeDrawData {
+        pub dt: f64,
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct SIMCONNECT_RECV_EVENT {
+        id: DWORD,
+        data: DWORD,
+    }
+
+    impl SIMCONNECT_RECV_EVENT {
+        pub fn new(id: DWORD, data: DWORD) -> Self {
+            Self { id, data }
+        }
+
+        pub fn id(&self) -> DWORD {
+            self.id
+        }
+
+        pub fn data(&self) -> DWORD {
+            self.data
+        }
+    }
+
+    pub struct SIMCONNECT_RECV_SIMOBJECT_DATA {
+        request_id: DWORD,
+        data: Box<dyn Any>,
+    }
+
+    impl SIMCONNECT_RECV_SIMOBJECT_DATA {
+        pub fn new<T: 'static>(request_id: DWORD, data: T) -> Self {
+            Self {
+                request_id,
+                data: Box::new(data),
+            }
+        }
+
+        pub fn id(&self) -> DWORD {
+            self.request_id
+        }
+
+        pub fn into<T: 'static>(
+            &self,
+            _sim_connect: &super::sim_connect::SimConnect,
+        ) -> Option<&T> {
+            self.data.downcast_ref()
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod sim_connect {
+    use super::sys::{self, DWORD};
+    use std::{any::Any, error::Error, marker::PhantomData};
+
+    pub const SIMCONNECT_OBJECT_ID_USER: DWORD = 0;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Period {
+        Never,
+        Once,
+        VisualFrame,
+        SimFrame,
+        Second,
+    }
+
+    pub enum SimConnectRecv<'a> {
+        Null,
+        Event(&'a sys::SIMCONNECT_RECV_EVENT),
+        SimObjectData(&'a sys::SIMCONNECT_RECV_SIMOBJECT_DATA),
+    }
+
+    /// A client event transmitted to the simulator.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct TransmittedEvent {
+        pub object_id: DWORD,
+        pub event_id: DWORD,
+        pub data: DWORD,
+    }
+
+    /// Simulated SimConnect handle, recording everything that is sent to it.
+    #[derive(Default)]
+    pub struct SimConnect<'a> {
+        mapped_events: Vec<(String, bool)>,
+        transmitted_events: Vec<TransmittedEvent>,
+        data_requests: Vec<(DWORD, DWORD, Period)>,
+        sim_object_data: Vec<Box<dyn Any>>,
+        _lifetime: PhantomData<&'a ()>,
+    }
+
+    impl<'a> SimConnect<'a> {
+        pub fn map_client_event_to_sim_event(
+            &mut self,
+            event_name: &str,
+            mask: bool,
+        ) -> Result<DWORD, Box<dyn Error>> {
+            self.mapped_events.push((event_name.to_owned(), mask));
+
+            Ok((self.mapped_events.len() - 1) as DWORD)
+        }
+
+        pub fn transmit_client_event(
+            &mut self,
+            object_id: DWORD,
+            event_id: DWORD,
+            data: DWORD,
+        ) -> Result<(), Box<dyn Error>> {
+            self.transmitted_events.push(TransmittedEvent {
+                object_id,
+                event_id,
+                data,
+            });
+
+            Ok(())
+        }
+
+        pub fn request_data_on_sim_object<T>(
+            &mut self,
+            request_id: DWORD,
+            object_id: DWORD,
+            period: Period,
+        ) -> Result<(), Box<dyn Error>> {
+            self.data_requests.push((request_id, object_id, period));
+
+            Ok(())
+        }
+
+        pub fn set_data_on_sim_object<T: Clone + 'static>(
+            &mut self,
+            _object_id: DWORD,
+            data: &T,
+        ) -> Result<(), Box<dyn Error>> {
+            self.sim_object_data.push(Box::new(data.clone()));
+
+            Ok(())
+        }
+
+        /// The identifier under which the event with the given name was mapped.
+        pub fn event_id(&self, event_name: &str) -> Option<DWORD> {
+            self.mapped_events
+                .iter()
+                .position(|(name, _)| name == event_name)
+                .map(|index| index as DWORD)
+        }
+
+        /// Whether the event with the given name was mapped and masked.
+        pub fn is_event_masked(&self, event_name: &str) -> bool {
+            self.mapped_events
+                .iter()
+                .any(|(name, mask)| name == event_name && *mask)
+        }
+
+        /// Creates the data the simulator sends when the event with the given name occurs.
+        /// Panics when no such event was mapped.
+        pub fn event(&self, event_name: &str, data: DWORD) -> sys::SIMCONNECT_RECV_EVENT {
+            let id = self
+                .event_id(event_name)
+                .unwrap_or_else(|| panic!("Event '{}' was never mapped.", event_name));
+
+            sys::SIMCONNECT_RECV_EVENT::new(id, data)
+        }
+
+        /// Returns and clears the events transmitted since the previous call.
+        pub fn take_transmitted_events(&mut self) -> Vec<TransmittedEvent> {
+            std::mem::take(&mut self.transmitted_events)
+        }
+
+        /// Whether data was requested with the given identifier and period.
+        pub fn has_data_request(&self, request_id: DWORD, period: Period) -> bool {
+            self.data_requests
+                .iter()
+                .any(|request| request.0 == request_id && request.2 == period)
+        }
+
+        /// The data of the given type which was most recently set on a sim object.
+        pub fn last_sim_object_data<T: 'static>(&self) -> Option<&T> {
+            self.sim_object_data
+                .iter()
+                .rev()
+                .find_map(|data| data.downcast_ref())
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
-pub(crate) mod legacy {
-    use std::cell::Cell;
-    use std::rc::Rc;
+pub mod legacy {
+    use fxhash::FxHashMap;
+    use std::cell::RefCell;
 
-    pub fn execute_calculator_code<T>(_code: &str) {}
+    thread_local! {
+        static EXECUTED_CALCULATOR_CODE: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        static NAMED_VARIABLES: RefCell<FxHashMap<String, f64>> = RefCell::new(FxHashMap::default());
+        static AIRCRAFT_VARIABLES: RefCell<FxHashMap<(String, usize), f64>> =
+            RefCell::new(FxHashMap::default());
+    }
+
+    pub fn execute_calculator_code<T>(code: &str) {
+        EXECUTED_CALCULATOR_CODE.with(|executed| executed.borrow_mut().push(code.to_owned()));
+    }
+
+    /// Returns and clears the calculator code executed since the previous call.
+    pub fn take_executed_calculator_code() -> Vec<String> {
+        EXECUTED_CALCULATOR_CODE.with(|executed| std::mem::take(&mut *executed.borrow_mut()))
+    }
 
     #[derive(Debug)]
-    pub struct AircraftVariable {}
+    pub struct AircraftVariable {
+        name: String,
+        index: usize,
+    }
 
     impl AircraftVariable {
         pub fn from(
-            _name: &str,
+            name: &str,
             _units: &str,
-            _index: usize,
+            index: usize,
         ) -> Result<Self, Box<dyn std::error::Error>> {
-            Ok(Self {})
+            Ok(Self {
+                name: name.to_owned(),
+                index,
+            })
         }
 
         pub fn get(&self) -> f64 {
-            0.
+            AIRCRAFT_VARIABLES.with(|variables| {
+                *variables
+                    .borrow()
+                    .get(&(self.name.clone(), self.index))
+                    .unwrap_or(&0.)
+            })
+        }
+
+        /// Sets the value as the simulator would. Aircraft variables are
+        /// read only for the bridging layer.
+        pub fn set(&self, value: f64) {
+            AIRCRAFT_VARIABLES.with(|variables| {
+                variables
+                    .borrow_mut()
+                    .insert((self.name.clone(), self.index), value)
+            });
         }
     }
 
     #[derive(Debug)]
     pub struct NamedVariable {
-        value: Rc<Cell<f64>>,
+        name: String,
     }
 
     impl NamedVariable {
-        pub fn from(_name: &str) -> Self {
+        pub fn from(name: &str) -> Self {
             Self {
-                value: Rc::new(Cell::new(0.)),
+                name: name.to_owned(),
             }
         }
 
         pub fn get_value(&self) -> f64 {
-            self.value.get()
+            NAMED_VARIABLES.with(|variables| *variables.borrow().get(&self.name).unwrap_or(&0.))
         }
 
         pub fn set_value(&self, value: f64) {
-            self.value.set(value);
+            NAMED_VARIABLES
+                .with(|variables| variables.borrow_mut().insert(self.name.clone(), value));
         }
     }
 }